@@ -21,12 +21,25 @@ use leptos::{
     ssr::render_to_stream_with_prefix_undisposed_with_context_and_block_replacement,
     *,
 };
-use leptos_integration_utils::{build_async_response, html_parts_separated};
+use leptos_integration_utils::{
+    build_async_response, html_head_metadata, html_head_start,
+    html_parts_separated,
+};
+/// Renders your app to static HTML files. Actix runs each worker, and `main`, in a local task
+/// set, so these can be awaited directly.
+pub use leptos_integration_utils::{
+    regenerate_static_route, render_static_routes, StaticManifest, StaticPage,
+};
 use leptos_meta::*;
 use leptos_router::*;
+use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
 use regex::Regex;
-use std::{fmt::Display, future::Future, sync::Arc};
+use std::{fmt::Display, future::Future, sync::Arc, time::Duration};
+use tokio::{
+    sync::{OwnedSemaphorePermit, Semaphore},
+    time::Instant,
+};
 use tracing::instrument;
 /// This struct lets you define headers and override the status of the Response from an Element or a Server Function
 /// Typically contained inside of a ResponseOptions. Setting this is useful for cookies and custom responses.
@@ -110,6 +123,150 @@ pub fn redirect(cx: leptos::Scope, path: &str) {
     }
 }
 
+/// Sets the number of disposed reactive runtimes each thread keeps, so that later
+/// requests can reuse their allocations instead of creating a new runtime from
+/// scratch. A runtime is cleared before it is reused, so nothing from one request
+/// is visible to the next. Defaults to `0`, which means runtimes are never reused.
+/// ```ignore
+/// leptos_actix::configure_runtime_pool(16);
+/// ```
+pub fn configure_runtime_pool(capacity: usize) {
+    leptos::set_runtime_pool_capacity(capacity);
+}
+
+/// Limits the number of pages rendered at the same time, and how long each of them can take.
+///
+/// Each page is rendered on the Actix worker that handles its request, so the number of
+/// workers is set with [HttpServer::workers], and the number of runtimes they keep with
+/// [configure_runtime_pool].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SsrPoolOptions {
+    /// The maximum number of pages that can be rendered at the same time, across all the
+    /// workers. Further requests wait in a queue until a render finishes. Defaults to no limit.
+    pub max_concurrent_renders: Option<usize>,
+    /// How long a single render can take, not counting time spent in the queue.
+    /// Renders that take longer are cancelled: if nothing has been sent yet, the
+    /// response is a `503 Service Unavailable`, otherwise the HTML stream ends early.
+    /// Defaults to no timeout.
+    pub render_timeout: Option<Duration>,
+}
+
+static SSR_POOL: OnceCell<SsrPool> = OnceCell::new();
+
+/// Sets the [SsrPoolOptions] used by all the rendering functions in this crate. This must be
+/// called before the first request is handled; if a page has already been rendered, the
+/// options are returned as an error.
+/// ```ignore
+/// leptos_actix::configure_ssr_pool(SsrPoolOptions {
+///     max_concurrent_renders: Some(64),
+///     render_timeout: Some(Duration::from_secs(5)),
+/// })
+/// .expect("no page should have been rendered yet");
+/// ```
+pub fn configure_ssr_pool(
+    options: SsrPoolOptions,
+) -> Result<(), SsrPoolOptions> {
+    let mut options = Some(options);
+    SSR_POOL.get_or_init(|| SsrPool::new(options.take().unwrap()));
+    match options {
+        None => Ok(()),
+        Some(options) => Err(options),
+    }
+}
+
+struct SsrPool {
+    renders: Option<Arc<Semaphore>>,
+    render_timeout: Option<Duration>,
+}
+
+impl SsrPool {
+    fn new(options: SsrPoolOptions) -> Self {
+        Self {
+            renders: options
+                .max_concurrent_renders
+                .map(|renders| Arc::new(Semaphore::new(renders))),
+            render_timeout: options.render_timeout,
+        }
+    }
+}
+
+/// A place in the [SsrPool] for a single render. The runtime registered with
+/// [RenderSlot::set_runtime] is disposed when the slot is dropped, so a render that is
+/// cancelled doesn't leak it.
+struct RenderSlot {
+    _permit: Option<OwnedSemaphorePermit>,
+    deadline: Option<Instant>,
+    runtime: Option<RuntimeId>,
+}
+
+impl RenderSlot {
+    /// Waits for a free render slot, if the number of renders is limited.
+    async fn acquire() -> Self {
+        let pool = SSR_POOL.get_or_init(|| SsrPool::new(Default::default()));
+        let permit = match &pool.renders {
+            Some(renders) => Some(
+                Arc::clone(renders)
+                    .acquire_owned()
+                    .await
+                    .expect("the render semaphore is never closed"),
+            ),
+            None => None,
+        };
+        Self {
+            _permit: permit,
+            deadline: pool
+                .render_timeout
+                .map(|timeout| Instant::now() + timeout),
+            runtime: None,
+        }
+    }
+
+    fn set_runtime(&mut self, runtime: RuntimeId) {
+        self.runtime = Some(runtime);
+    }
+
+    /// Runs part of the render, returning `None` if it times out first.
+    async fn run<T>(&self, fut: impl Future<Output = T>) -> Option<T> {
+        match self.deadline {
+            Some(deadline) => tokio::time::timeout_at(deadline, fut).await.ok(),
+            None => Some(fut.await),
+        }
+    }
+
+    /// Ends the stream when the render times out, and holds on to the slot until the
+    /// stream is dropped.
+    fn limit_stream<S>(self, stream: S) -> impl Stream<Item = S::Item>
+    where
+        S: Stream,
+    {
+        let deadline = self.deadline;
+        let expired = async move {
+            match deadline {
+                Some(deadline) => tokio::time::sleep_until(deadline).await,
+                None => futures::future::pending().await,
+            }
+        };
+        stream.take_until(expired).map(move |chunk| {
+            let _slot = &self;
+            chunk
+        })
+    }
+}
+
+impl Drop for RenderSlot {
+    // Disposing a runtime twice does nothing, so this is safe even if the
+    // render has already disposed it.
+    fn drop(&mut self) {
+        if let Some(runtime) = self.runtime.take() {
+            runtime.dispose();
+        }
+    }
+}
+
+fn render_timed_out() -> HttpResponse {
+    HttpResponse::ServiceUnavailable().finish()
+}
+
 /// An Actix [Route](actix_web::Route) that listens for a `POST` request with
 /// Leptos server function arguments in the body, runs the server function if found,
 /// and returns the resulting [HttpResponse].
@@ -710,6 +867,127 @@ where
     }
 }
 
+/// Returns an Actix [Route](actix_web::Route) that listens for a `GET` request and tries
+/// to route it using [leptos_router], rendering your application with the [SsrMode] that
+/// `select_mode` chooses for that request.
+///
+/// This lets each response use a different mode: for example, in-order streaming for crawlers
+/// and clients without JavaScript, which can't swap streamed `<Suspense/>` fragments into the
+/// page, and out-of-order streaming for everyone else.
+/// ```ignore
+/// let route = leptos_actix::render_app_with_ssr_mode(
+///     leptos_options,
+///     |_| {},
+///     |cx| view! { cx, <App/> },
+///     Method::Get,
+///     |req: &HttpRequest| {
+///         let is_bot = req
+///             .headers()
+///             .get(header::USER_AGENT)
+///             .and_then(|agent| agent.to_str().ok())
+///             .map(|agent| agent.to_lowercase().contains("bot"))
+///             .unwrap_or(false);
+///         if is_bot {
+///             SsrMode::InOrder
+///         } else {
+///             SsrMode::OutOfOrder
+///         }
+///     },
+/// );
+/// ```
+///
+/// ## Provided Context Types
+/// This function always provides context values including the following types:
+/// - [ResponseOptions]
+/// - [HttpRequest](actix_web::HttpRequest)
+/// - [MetaContext](leptos_meta::MetaContext)
+/// - [RouterIntegrationContext](leptos_router::RouterIntegrationContext)
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub fn render_app_with_ssr_mode<IV>(
+    options: LeptosOptions,
+    additional_context: impl Fn(leptos::Scope) + 'static + Clone + Send,
+    app_fn: impl Fn(leptos::Scope) -> IV + Clone + 'static,
+    method: Method,
+    select_mode: impl Fn(&HttpRequest) -> SsrMode + Clone + 'static,
+) -> Route
+where
+    IV: IntoView,
+{
+    let handler = move |req: HttpRequest| {
+        let options = options.clone();
+        let app_fn = app_fn.clone();
+        let additional_context = additional_context.clone();
+        let res_options = ResponseOptions::default();
+        let mode = select_mode(&req);
+
+        async move {
+            let app = {
+                let app_fn = app_fn.clone();
+                let res_options = res_options.clone();
+                move |cx| {
+                    provide_contexts(cx, &req, res_options);
+                    (app_fn)(cx).into_view(cx)
+                }
+            };
+
+            match mode {
+                SsrMode::OutOfOrder | SsrMode::PartiallyBlocked => {
+                    stream_app(
+                        &options,
+                        app,
+                        res_options,
+                        additional_context,
+                        mode == SsrMode::PartiallyBlocked,
+                    )
+                    .await
+                }
+                SsrMode::InOrder => {
+                    stream_app_in_order(
+                        &options,
+                        app,
+                        res_options,
+                        additional_context,
+                    )
+                    .await
+                }
+                SsrMode::Async => {
+                    render_app_async_helper(
+                        &options,
+                        app,
+                        res_options,
+                        additional_context,
+                    )
+                    .await
+                }
+            }
+        }
+    };
+    match method {
+        Method::Get => web::get().to(handler),
+        Method::Post => web::post().to(handler),
+        Method::Put => web::put().to(handler),
+        Method::Delete => web::delete().to(handler),
+        Method::Patch => web::patch().to(handler),
+    }
+}
+
+// Wraps the app so that it sends the `Cache-Control` header declared by a route.
+fn with_cache_control<IV>(
+    cache_control: Option<&'static str>,
+    app_fn: impl Fn(leptos::Scope) -> IV + Clone + Send + 'static,
+) -> impl Fn(leptos::Scope) -> IV + Clone + Send + 'static {
+    move |cx| {
+        let value = cache_control
+            .and_then(|value| header::HeaderValue::from_str(value).ok());
+        if let (Some(value), Some(res_options)) =
+            (value, use_context::<ResponseOptions>(cx))
+        {
+            res_options.insert_header(header::CACHE_CONTROL, value);
+        }
+        app_fn(cx)
+    }
+}
+
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 fn provide_contexts(
     cx: leptos::Scope,
@@ -724,6 +1002,14 @@ fn provide_contexts(
     provide_context(cx, res_options);
     provide_context(cx, req.clone());
     provide_server_redirect(cx, move |path| redirect(cx, path));
+    provide_server_status(cx, move |status| {
+        if let (Some(res_options), Ok(status)) = (
+            use_context::<ResponseOptions>(cx),
+            StatusCode::from_u16(status),
+        ) {
+            res_options.set_status(status);
+        }
+    });
 }
 
 fn leptos_corrected_path(req: &HttpRequest) -> String {
@@ -743,6 +1029,7 @@ async fn stream_app(
     additional_context: impl Fn(leptos::Scope) + 'static + Clone + Send,
     replace_blocks: bool,
 ) -> HttpResponse<BoxBody> {
+    let mut slot = RenderSlot::acquire().await;
     let (stream, runtime, scope) =
         render_to_stream_with_prefix_undisposed_with_context_and_block_replacement(
             app,
@@ -750,8 +1037,10 @@ async fn stream_app(
             additional_context,
             replace_blocks
         );
+    slot.set_runtime(runtime);
 
-    build_stream_response(options, res_options, stream, runtime, scope).await
+    build_stream_response(options, res_options, stream, runtime, scope, slot)
+        .await
 }
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
@@ -763,6 +1052,7 @@ async fn stream_app_in_order(
     res_options: ResponseOptions,
     additional_context: impl Fn(leptos::Scope) + 'static + Clone + Send,
 ) -> HttpResponse<BoxBody> {
+    let mut slot = RenderSlot::acquire().await;
     let (stream, runtime, scope) =
         leptos::ssr::render_to_stream_in_order_with_prefix_undisposed_with_context(
             app,
@@ -771,8 +1061,10 @@ async fn stream_app_in_order(
             },
             additional_context,
         );
+    slot.set_runtime(runtime);
 
-    build_stream_response(options, res_options, stream, runtime, scope).await
+    build_stream_response(options, res_options, stream, runtime, scope, slot)
+        .await
 }
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 async fn build_stream_response(
@@ -781,41 +1073,80 @@ async fn build_stream_response(
     stream: impl Stream<Item = String> + 'static,
     runtime: RuntimeId,
     scope: ScopeId,
+    slot: RenderSlot,
 ) -> HttpResponse {
     let cx = leptos::Scope { runtime, id: scope };
     let mut stream = Box::pin(stream);
 
-    // wait for any blocking resources to load before pulling metadata
-    let first_app_chunk = stream.next().await.unwrap_or_default();
-
-    let (head, tail) =
-        html_parts_separated(options, use_context::<MetaContext>(cx).as_ref());
+    let complete_stream = if options.flush_head_early {
+        let head_start =
+            html_head_start(options, use_context::<MetaContext>(cx).as_ref());
+        let options = options.clone();
 
-    let mut stream = Box::pin(
-        futures::stream::once(async move { head.clone() })
-            .chain(
-                futures::stream::once(async move { first_app_chunk })
-                    .chain(stream),
+        // the response is sent with the start of the head, while blocking
+        // resources are still loading
+        let rest = async move {
+            let first_app_chunk = stream.next().await.unwrap_or_default();
+            let head = html_head_metadata(
+                &options,
+                use_context::<MetaContext>(cx).as_ref(),
+            );
+            futures::stream::once(
+                async move { format!("{head}{first_app_chunk}") },
             )
+            .chain(stream)
+        };
+
+        let complete_stream = futures::stream::once(async move { head_start })
+            .chain(futures::stream::once(rest).flatten())
             .chain(futures::stream::once(async move {
                 runtime.dispose();
-                tail.to_string()
+                "</body></html>".to_string()
             }))
-            .map(|html| Ok(web::Bytes::from(html)) as Result<web::Bytes>),
-    );
+            .map(|html| Ok(web::Bytes::from(html)) as Result<web::Bytes>);
+        slot.limit_stream(complete_stream).boxed_local()
+    } else {
+        // wait for any blocking resources to load before pulling metadata
+        let Some(first_app_chunk) = slot.run(stream.next()).await else {
+            return render_timed_out();
+        };
+        let first_app_chunk = first_app_chunk.unwrap_or_default();
+
+        let (head, tail) = html_parts_separated(
+            options,
+            use_context::<MetaContext>(cx).as_ref(),
+        );
 
-    // Get the first and second in the stream, which renders the app shell, and thus allows Resources to run
-    let first_chunk = stream.next().await;
-    let second_chunk = stream.next().await;
+        let mut stream = Box::pin(
+            futures::stream::once(async move { head.clone() })
+                .chain(
+                    futures::stream::once(async move { first_app_chunk })
+                        .chain(stream),
+                )
+                .chain(futures::stream::once(async move {
+                    runtime.dispose();
+                    tail.to_string()
+                }))
+                .map(|html| Ok(web::Bytes::from(html)) as Result<web::Bytes>),
+        );
+
+        // Get the first and second in the stream, which renders the app shell, and thus allows Resources to run
+        let first_chunk = stream.next().await;
+        let second_chunk = stream.next().await;
+
+        let complete_stream = futures::stream::iter([
+            first_chunk.unwrap(),
+            second_chunk.unwrap(),
+        ])
+        .chain(stream);
+        slot.limit_stream(complete_stream).boxed_local()
+    };
 
     let res_options = res_options.0.read();
 
     let (status, headers) = (res_options.status, res_options.headers.clone());
     let status = status.unwrap_or_default();
 
-    let complete_stream =
-        futures::stream::iter([first_chunk.unwrap(), second_chunk.unwrap()])
-            .chain(stream);
     let mut res = HttpResponse::Ok()
         .content_type("text/html")
         .streaming(complete_stream);
@@ -838,14 +1169,21 @@ async fn render_app_async_helper(
     res_options: ResponseOptions,
     additional_context: impl Fn(leptos::Scope) + 'static + Clone + Send,
 ) -> HttpResponse<BoxBody> {
+    let mut slot = RenderSlot::acquire().await;
     let (stream, runtime, scope) =
         leptos::ssr::render_to_stream_in_order_with_prefix_undisposed_with_context(
             app,
             move |_| "".into(),
             additional_context,
         );
+    slot.set_runtime(runtime);
 
-    let html = build_async_response(stream, options, runtime, scope).await;
+    let Some(html) = slot
+        .run(build_async_response(stream, options, runtime, scope))
+        .await
+    else {
+        return render_timed_out();
+    };
 
     let res_options = res_options.0.read();
 
@@ -901,9 +1239,11 @@ where
                     "/".to_string(),
                     listing.mode(),
                     listing.methods(),
-                );
+                )
+                .with_cache_control(listing.cache_control());
             }
             RouteListing::new(listing.path(), listing.mode(), listing.methods())
+                .with_cache_control(listing.cache_control())
         })
         .collect();
 
@@ -921,6 +1261,7 @@ where
                 .to_string();
             let path = capture_re.replace_all(&path, "{$1}").to_string();
             RouteListing::new(path, listing.mode(), listing.methods())
+                .with_cache_control(listing.cache_control())
         })
         .collect::<Vec<_>>();
 
@@ -961,6 +1302,17 @@ pub trait LeptosRoutes {
     ) -> Self
     where
         IV: IntoView + 'static;
+
+    fn leptos_routes_with_ssr_mode<IV>(
+        self,
+        options: LeptosOptions,
+        paths: Vec<RouteListing>,
+        additional_context: impl Fn(leptos::Scope) + 'static + Clone + Send,
+        app_fn: impl Fn(leptos::Scope) -> IV + Clone + Send + 'static,
+        select_mode: impl Fn(&HttpRequest, SsrMode) -> SsrMode + Clone + 'static,
+    ) -> Self
+    where
+        IV: IntoView + 'static;
 }
 
 /// The default implementation of `LeptosRoutes` which takes in a list of paths, and dispatches GET requests
@@ -995,6 +1347,30 @@ where
         additional_context: impl Fn(leptos::Scope) + 'static + Clone + Send,
         app_fn: impl Fn(leptos::Scope) -> IV + Clone + Send + 'static,
     ) -> Self
+    where
+        IV: IntoView + 'static,
+    {
+        self.leptos_routes_with_ssr_mode(
+            options,
+            paths,
+            additional_context,
+            app_fn,
+            |_, mode| mode,
+        )
+    }
+
+    /// Like [`leptos_routes_with_context`](LeptosRoutes::leptos_routes_with_context), but
+    /// `select_mode` chooses the [SsrMode] of each response, given the request and the mode
+    /// of the route that matched it.
+    #[tracing::instrument(level = "trace", fields(error), skip_all)]
+    fn leptos_routes_with_ssr_mode<IV>(
+        self,
+        options: LeptosOptions,
+        paths: Vec<RouteListing>,
+        additional_context: impl Fn(leptos::Scope) + 'static + Clone + Send,
+        app_fn: impl Fn(leptos::Scope) -> IV + Clone + Send + 'static,
+        select_mode: impl Fn(&HttpRequest, SsrMode) -> SsrMode + Clone + 'static,
+    ) -> Self
     where
         IV: IntoView + 'static,
     {
@@ -1002,43 +1378,20 @@ where
         for listing in paths.iter() {
             let path = listing.path();
             let mode = listing.mode();
+            let app_fn =
+                with_cache_control(listing.cache_control(), app_fn.clone());
 
             for method in listing.methods() {
+                let select_mode = select_mode.clone();
                 router = router.route(
                     path,
-                    match mode {
-                        SsrMode::OutOfOrder => {
-                            render_app_to_stream_with_context(
-                                options.clone(),
-                                additional_context.clone(),
-                                app_fn.clone(),
-                                method,
-                            )
-                        }
-                        SsrMode::PartiallyBlocked => {
-                            render_app_to_stream_with_context_and_replace_blocks(
-                                options.clone(),
-                                additional_context.clone(),
-                                app_fn.clone(),
-                                method,
-                                true,
-                            )
-                        }
-                        SsrMode::InOrder => {
-                            render_app_to_stream_in_order_with_context(
-                                options.clone(),
-                                additional_context.clone(),
-                                app_fn.clone(),
-                                method,
-                            )
-                        }
-                        SsrMode::Async => render_app_async_with_context(
-                            options.clone(),
-                            additional_context.clone(),
-                            app_fn.clone(),
-                            method,
-                        ),
-                    },
+                    render_app_with_ssr_mode(
+                        options.clone(),
+                        additional_context.clone(),
+                        app_fn.clone(),
+                        method,
+                        move |req| select_mode(req, mode),
+                    ),
                 );
             }
         }
@@ -30,15 +30,29 @@ use leptos::{
     ssr::*,
     *,
 };
-use leptos_integration_utils::{build_async_response, html_parts_separated};
+use leptos_integration_utils::{
+    build_async_response, html_head_metadata, html_head_start,
+    html_parts_separated,
+};
+pub use leptos_integration_utils::{StaticManifest, StaticPage};
 use leptos_meta::{generate_head_metadata_separated, MetaContext};
 use leptos_router::*;
 use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
-use std::{io, pin::Pin, sync::Arc, thread::available_parallelism};
-use tokio::task::LocalSet;
+use std::{
+    cell::Cell, io, pin::Pin, rc::Rc, sync::Arc, thread::available_parallelism,
+    time::Duration,
+};
+use tokio::{
+    sync::Semaphore,
+    task::{JoinHandle, LocalSet},
+};
 use tokio_util::task::LocalPoolHandle;
 use tracing::Instrument;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
 /// A struct to hold the parts of the incoming Request. Since `http::Request` isn't cloneable, we're forced
 /// to construct this for Leptos to use in Axum
 #[derive(Debug, Clone)]
@@ -236,7 +250,7 @@ async fn handle_server_fns_inner(
 
     let (tx, rx) = futures::channel::oneshot::channel();
     let pool_handle = get_leptos_pool();
-    pool_handle.spawn_pinned(move || {
+    pool_handle.handle.spawn_pinned(move || {
         async move {
             let res = if let Some(server_fn) =
                 server_fn_by_path(fn_name.as_str())
@@ -598,12 +612,13 @@ where
             let default_res_options = ResponseOptions::default();
             let res_options2 = default_res_options.clone();
             let res_options3 = default_res_options.clone();
+            let flush_head_early = options.flush_head_early;
             let local_pool = get_leptos_pool();
             let (tx, rx) = futures::channel::mpsc::channel(8);
             let (runtime_tx, runtime_rx) = futures::channel::oneshot::channel();
 
             let current_span = tracing::Span::current();
-            local_pool.spawn_pinned(move || async move {
+            local_pool.spawn_render(move |render| async move {
                 let app = {
                     // Need to get the path and query string of the Request
                     // For reasons that escape me, if the incoming URI protocol is https, it provides the absolute URI
@@ -625,16 +640,29 @@ where
                         replace_blocks
                     );
 
+                    render.set_runtime(runtime);
                     runtime_tx.send(runtime).expect("should be able to send runtime");
 
                     forward_stream(&options, res_options2, bundle, runtime, scope, tx).await;
             }.instrument(current_span));
 
             async move {
-                let runtime = runtime_rx
-                    .await
-                    .expect("runtime should be sent by renderer");
-                generate_response(res_options3, rx, runtime).await
+                match runtime_rx.await {
+                    Ok(runtime) => {
+                        generate_response(
+                            res_options3,
+                            rx,
+                            runtime,
+                            flush_head_early,
+                        )
+                        .await
+                    }
+                    // the render timed out before it started streaming
+                    Err(_) => render_timed_out(StreamBody::new(Box::pin(
+                        futures::stream::empty(),
+                    )
+                        as PinnedHtmlStream)),
+                }
             }
         })
     }
@@ -645,18 +673,25 @@ async fn generate_response(
     res_options: ResponseOptions,
     rx: Receiver<String>,
     runtime: RuntimeId,
+    flush_head_early: bool,
 ) -> Response<StreamBody<PinnedHtmlStream>> {
     let mut stream = Box::pin(rx.map(|html| Ok(Bytes::from(html))));
 
     // Get the first and second chunks in the stream, which renders the app shell, and thus allows Resources to run
     let first_chunk = stream.next().await;
-    let second_chunk = stream.next().await;
+    // unless the start of the head is sent before the shell
+    let second_chunk = if flush_head_early {
+        None
+    } else {
+        stream.next().await
+    };
 
     // Extract the resources now that they've been rendered
     let res_options = res_options.0.read();
 
+    // if the render timed out, the stream may have ended early
     let complete_stream =
-        futures::stream::iter([first_chunk.unwrap(), second_chunk.unwrap()])
+        futures::stream::iter(first_chunk.into_iter().chain(second_chunk))
             .chain(stream)
             .chain(futures::stream::once(async move {
                 runtime.dispose();
@@ -686,10 +721,20 @@ async fn forward_stream(
 ) {
     let cx = Scope { runtime, id: scope };
     let mut shell = Box::pin(bundle);
+    if options.flush_head_early {
+        let head_start =
+            html_head_start(options, use_context::<MetaContext>(cx).as_ref());
+        _ = tx.send(head_start).await;
+    }
     let first_app_chunk = shell.next().await.unwrap_or_default();
 
-    let (head, tail) =
-        html_parts_separated(options, use_context::<MetaContext>(cx).as_ref());
+    let meta = use_context::<MetaContext>(cx);
+    let (head, tail) = html_parts_separated(options, meta.as_ref());
+    let head = if options.flush_head_early {
+        html_head_metadata(options, meta.as_ref())
+    } else {
+        head
+    };
 
     _ = tx.send(head).await;
     _ = tx.send(first_app_chunk).await;
@@ -772,12 +817,13 @@ where
 
                 let full_path = format!("http://leptos.dev{path}");
 
+                let flush_head_early = options.flush_head_early;
                 let (tx, rx) = futures::channel::mpsc::channel(8);
                 let (runtime_tx, runtime_rx) =
                     futures::channel::oneshot::channel();
                 let local_pool = get_leptos_pool();
                 let current_span = tracing::Span::current();
-                local_pool.spawn_pinned(|| async move {
+                local_pool.spawn_render(|render| async move {
                     let app = {
                         let full_path = full_path.clone();
                         let (req, req_parts) = generate_request_and_parts(req).await;
@@ -794,20 +840,50 @@ where
                             add_context,
                         );
 
+                    render.set_runtime(runtime);
                     runtime_tx.send(runtime).expect("should be able to send runtime");
 
                     forward_stream(&options, res_options2, bundle, runtime, scope, tx).await;
                 }.instrument(current_span));
 
-                let runtime = runtime_rx
-                    .await
-                    .expect("runtime should be sent by renderer");
-                generate_response(res_options3, rx, runtime).await
+                match runtime_rx.await {
+                    Ok(runtime) => {
+                        generate_response(
+                            res_options3,
+                            rx,
+                            runtime,
+                            flush_head_early,
+                        )
+                        .await
+                    }
+                    // the render timed out before it started streaming
+                    Err(_) => render_timed_out(StreamBody::new(Box::pin(
+                        futures::stream::empty(),
+                    )
+                        as PinnedHtmlStream)),
+                }
             }
         })
     }
 }
 
+// Wraps the app so that it sends the `Cache-Control` header declared by a route.
+fn with_cache_control<IV>(
+    cache_control: Option<&'static str>,
+    app_fn: impl Fn(leptos::Scope) -> IV + Clone + Send + 'static,
+) -> impl Fn(leptos::Scope) -> IV + Clone + Send + 'static {
+    move |cx| {
+        let value =
+            cache_control.and_then(|value| HeaderValue::from_str(value).ok());
+        if let (Some(value), Some(res_options)) =
+            (value, use_context::<ResponseOptions>(cx))
+        {
+            res_options.insert_header(header::CACHE_CONTROL, value);
+        }
+        app_fn(cx)
+    }
+}
+
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 fn provide_contexts(
     cx: Scope,
@@ -823,6 +899,14 @@ fn provide_contexts(
     provide_context(cx, extractor);
     provide_context(cx, default_res_options);
     provide_server_redirect(cx, move |path| redirect(cx, path));
+    provide_server_status(cx, move |status| {
+        if let (Some(res_options), Ok(status)) = (
+            use_context::<ResponseOptions>(cx),
+            StatusCode::from_u16(status),
+        ) {
+            res_options.set_status(status);
+        }
+    });
 }
 
 /// Returns an Axum [Handler](axum::handler::Handler) that listens for a `GET` request and tries
@@ -951,7 +1035,7 @@ where
 
                 let (tx, rx) = futures::channel::oneshot::channel();
                 let local_pool = get_leptos_pool();
-                local_pool.spawn_pinned(move || {
+                local_pool.spawn_render(move |render| {
                     async move {
                         let app = {
                             let full_path = full_path.clone();
@@ -969,6 +1053,8 @@ where
                                 add_context,
                             );
 
+                        render.set_runtime(runtime);
+
                         // Extract the value of ResponseOptions from here
                         let cx = leptos::Scope { runtime, id: scope };
                         let res_options =
@@ -985,7 +1071,9 @@ where
                     }
                 });
 
-                let html = rx.await.expect("to complete HTML rendering");
+                let Ok(html) = rx.await else {
+                    return render_timed_out(String::new());
+                };
 
                 let mut res = Response::new(html);
 
@@ -1003,6 +1091,97 @@ where
     }
 }
 
+/// Returns an Axum [Handler](axum::handler::Handler) that listens for a `GET` request and tries
+/// to route it using [leptos_router], rendering your application with the [SsrMode] that
+/// `select_mode` chooses for that request.
+///
+/// This lets each response use a different mode: for example, in-order streaming for crawlers
+/// and clients without JavaScript, which can't swap streamed `<Suspense/>` fragments into the
+/// page, and out-of-order streaming for everyone else.
+/// ```ignore
+/// let handler = leptos_axum::render_app_with_ssr_mode(
+///     leptos_options,
+///     |_| {},
+///     |cx| view! { cx, <App/> },
+///     |req: &Request<Body>| {
+///         let is_bot = req
+///             .headers()
+///             .get(header::USER_AGENT)
+///             .and_then(|agent| agent.to_str().ok())
+///             .map(|agent| agent.to_lowercase().contains("bot"))
+///             .unwrap_or(false);
+///         if is_bot {
+///             SsrMode::InOrder
+///         } else {
+///             SsrMode::OutOfOrder
+///         }
+///     },
+/// );
+/// ```
+/// Otherwise, this function is identical to [render_app_to_stream_with_context].
+///
+/// ## Provided Context Types
+/// This function always provides context values including the following types:
+/// - [RequestParts]
+/// - [ResponseOptions]
+/// - [MetaContext](leptos_meta::MetaContext)
+/// - [RouterIntegrationContext](leptos_router::RouterIntegrationContext)
+#[tracing::instrument(level = "info", fields(error), skip_all)]
+pub fn render_app_with_ssr_mode<IV>(
+    options: LeptosOptions,
+    additional_context: impl Fn(leptos::Scope) + 'static + Clone + Send,
+    app_fn: impl Fn(leptos::Scope) -> IV + Clone + Send + 'static,
+    select_mode: impl Fn(&Request<Body>) -> SsrMode + Clone + Send + 'static,
+) -> impl Fn(
+    Request<Body>,
+) -> Pin<
+    Box<dyn Future<Output = axum::response::Response> + Send + 'static>,
+> + Clone
+       + Send
+       + 'static
+where
+    IV: IntoView,
+{
+    let out_of_order = render_app_to_stream_with_context(
+        options.clone(),
+        additional_context.clone(),
+        app_fn.clone(),
+    );
+    let partially_blocked =
+        render_app_to_stream_with_context_and_replace_blocks(
+            options.clone(),
+            additional_context.clone(),
+            app_fn.clone(),
+            true,
+        );
+    let in_order = render_app_to_stream_in_order_with_context(
+        options.clone(),
+        additional_context.clone(),
+        app_fn.clone(),
+    );
+    let async_ =
+        render_app_async_with_context(options, additional_context, app_fn);
+
+    move |req: Request<Body>| match select_mode(&req) {
+        SsrMode::OutOfOrder => {
+            let res = out_of_order(req);
+            Box::pin(async move { res.await.into_response() })
+        }
+        SsrMode::PartiallyBlocked => {
+            let res = partially_blocked(req);
+            Box::pin(async move { res.await.into_response() })
+        }
+        SsrMode::InOrder => {
+            let res = in_order(req);
+            Box::pin(async move { res.await.into_response() })
+        }
+        SsrMode::Async => {
+            let res = async_(req);
+            Box::pin(async move { res.await.into_response() })
+        }
+    }
+}
+
 /// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
 /// create routes in Axum's Router without having to use wildcard matching or fallbacks. Takes in your root app Element
 /// as an argument so it can walk you app tree. This version is tailored to generate Axum compatible paths.
@@ -1061,6 +1240,7 @@ where
                     listing.mode(),
                     listing.methods(),
                 )
+                .with_cache_control(listing.cache_control())
             } else {
                 listing
             }
@@ -1082,6 +1262,87 @@ where
     }
 }
 
+/// Renders every `GET` route of your app to a static HTML file in `out_dir`, after all of its
+/// resources have loaded, and writes a [StaticManifest] that lists them. For routes with params,
+/// like `/posts/:id`, `params` is called with the route and returns the values of the params for
+/// each page that should be rendered.
+///
+/// The files can be served with any file server, e.g., `tower_http::services::ServeDir`, which
+/// finds `posts/1/index.html` for `/posts/1`. See
+/// [leptos_integration_utils::render_static_routes] for the details.
+/// ```ignore
+/// let manifest = leptos_axum::render_static_routes(
+///     leptos_options,
+///     |cx| view! { cx, <App/> },
+///     |_| {},
+///     |route| async move {
+///         match route.as_str() {
+///             "/posts/:id" => list_post_ids()
+///                 .await
+///                 .into_iter()
+///                 .map(|id| {
+///                     let mut params = ParamsMap::new();
+///                     params.insert("id".to_string(), id.to_string());
+///                     params
+///                 })
+///                 .collect(),
+///             _ => vec![],
+///         }
+///     },
+///     "target/site",
+/// )
+/// .await?;
+/// ```
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub async fn render_static_routes<IV, P, Fut>(
+    options: LeptosOptions,
+    app_fn: impl Fn(Scope) -> IV + Clone + 'static,
+    additional_context: impl Fn(Scope) + Clone + 'static,
+    params: P,
+    out_dir: impl AsRef<std::path::Path>,
+) -> io::Result<StaticManifest>
+where
+    IV: IntoView + 'static,
+    P: Fn(String) -> Fut,
+    Fut: Future<Output = Vec<ParamsMap>>,
+{
+    // resources are loaded with `spawn_local`
+    LocalSet::new()
+        .run_until(leptos_integration_utils::render_static_routes(
+            &options,
+            app_fn,
+            additional_context,
+            params,
+            out_dir,
+        ))
+        .await
+}
+
+/// Renders the page at `path` again and replaces its file in `out_dir`, which was created by
+/// [render_static_routes]. Call this when the data a page shows has changed, or from a task that
+/// regenerates pages every so often, to keep them up to date without building the whole site.
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub async fn regenerate_static_route<IV>(
+    options: LeptosOptions,
+    app_fn: impl FnOnce(Scope) -> IV + 'static,
+    additional_context: impl FnOnce(Scope) + 'static,
+    path: &str,
+    out_dir: impl AsRef<std::path::Path>,
+) -> io::Result<StaticPage>
+where
+    IV: IntoView + 'static,
+{
+    LocalSet::new()
+        .run_until(leptos_integration_utils::regenerate_static_route(
+            &options,
+            app_fn,
+            additional_context,
+            path,
+            out_dir,
+        ))
+        .await
+}
+
 /// This trait allows one to pass a list of routes and a render function to Axum's router, letting us avoid
 /// having to use wildcards or manually define all routes in multiple places.
 pub trait LeptosRoutes<S>
@@ -1108,6 +1369,21 @@ where
     where
         IV: IntoView + 'static;
 
+    fn leptos_routes_with_ssr_mode<IV>(
+        self,
+        options: &S,
+        paths: Vec<RouteListing>,
+        additional_context: impl Fn(leptos::Scope) + 'static + Clone + Send,
+        app_fn: impl Fn(leptos::Scope) -> IV + Clone + Send + 'static,
+        select_mode: impl Fn(&Request<Body>, SsrMode) -> SsrMode
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self
+    where
+        IV: IntoView + 'static;
+
     fn leptos_routes_with_handler<H, T>(
         self,
         paths: Vec<RouteListing>,
@@ -1146,74 +1422,60 @@ where
         additional_context: impl Fn(leptos::Scope) + 'static + Clone + Send,
         app_fn: impl Fn(leptos::Scope) -> IV + Clone + Send + 'static,
     ) -> Self
+    where
+        IV: IntoView + 'static,
+    {
+        self.leptos_routes_with_ssr_mode(
+            options,
+            paths,
+            additional_context,
+            app_fn,
+            |_, mode| mode,
+        )
+    }
+
+    /// Like [`leptos_routes_with_context`](LeptosRoutes::leptos_routes_with_context), but
+    /// `select_mode` chooses the [SsrMode] of each response, given the request and the mode
+    /// of the route that matched it.
+    #[tracing::instrument(level = "trace", fields(error), skip_all)]
+    fn leptos_routes_with_ssr_mode<IV>(
+        self,
+        options: &S,
+        paths: Vec<RouteListing>,
+        additional_context: impl Fn(leptos::Scope) + 'static + Clone + Send,
+        app_fn: impl Fn(leptos::Scope) -> IV + Clone + Send + 'static,
+        select_mode: impl Fn(&Request<Body>, SsrMode) -> SsrMode
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self
     where
         IV: IntoView + 'static,
     {
         let mut router = self;
         for listing in paths.iter() {
             let path = listing.path();
+            let mode = listing.mode();
+            let app_fn =
+                with_cache_control(listing.cache_control(), app_fn.clone());
+            let select_mode = select_mode.clone();
+            let s = render_app_with_ssr_mode(
+                LeptosOptions::from_ref(options),
+                additional_context.clone(),
+                app_fn,
+                move |req| select_mode(req, mode),
+            );
 
             for method in listing.methods() {
                 router = router.route(
                     path,
-                    match listing.mode() {
-                        SsrMode::OutOfOrder => {
-                            let s = render_app_to_stream_with_context(
-                                LeptosOptions::from_ref(options),
-                                additional_context.clone(),
-                                app_fn.clone(),
-                            );
-                            match method {
-                                leptos_router::Method::Get => get(s),
-                                leptos_router::Method::Post => post(s),
-                                leptos_router::Method::Put => put(s),
-                                leptos_router::Method::Delete => delete(s),
-                                leptos_router::Method::Patch => patch(s),
-                            }
-                        }
-                        SsrMode::PartiallyBlocked => {
-                            let s = render_app_to_stream_with_context_and_replace_blocks(
-                                LeptosOptions::from_ref(options),
-                                additional_context.clone(),
-                                app_fn.clone(),
-                                true
-                            );
-                            match method {
-                                leptos_router::Method::Get => get(s),
-                                leptos_router::Method::Post => post(s),
-                                leptos_router::Method::Put => put(s),
-                                leptos_router::Method::Delete => delete(s),
-                                leptos_router::Method::Patch => patch(s),
-                            }
-                        }
-                        SsrMode::InOrder => {
-                            let s = render_app_to_stream_in_order_with_context(
-                                LeptosOptions::from_ref(options),
-                                additional_context.clone(),
-                                app_fn.clone(),
-                            );
-                            match method {
-                                leptos_router::Method::Get => get(s),
-                                leptos_router::Method::Post => post(s),
-                                leptos_router::Method::Put => put(s),
-                                leptos_router::Method::Delete => delete(s),
-                                leptos_router::Method::Patch => patch(s),
-                            }
-                        }
-                        SsrMode::Async => {
-                            let s = render_app_async_with_context(
-                                LeptosOptions::from_ref(options),
-                                additional_context.clone(),
-                                app_fn.clone(),
-                            );
-                            match method {
-                                leptos_router::Method::Get => get(s),
-                                leptos_router::Method::Post => post(s),
-                                leptos_router::Method::Put => put(s),
-                                leptos_router::Method::Delete => delete(s),
-                                leptos_router::Method::Patch => patch(s),
-                            }
-                        }
+                    match method {
+                        leptos_router::Method::Get => get(s.clone()),
+                        leptos_router::Method::Post => post(s.clone()),
+                        leptos_router::Method::Put => put(s.clone()),
+                        leptos_router::Method::Delete => delete(s.clone()),
+                        leptos_router::Method::Patch => patch(s.clone()),
                     },
                 );
             }
@@ -1252,18 +1514,152 @@ where
     }
 }
 
-#[tracing::instrument(level = "trace", fields(error), skip_all)]
-fn get_leptos_pool() -> LocalPoolHandle {
-    static LOCAL_POOL: OnceCell<LocalPoolHandle> = OnceCell::new();
-    LOCAL_POOL
-        .get_or_init(|| {
-            tokio_util::task::LocalPoolHandle::new(
-                available_parallelism().map(Into::into).unwrap_or(1),
-            )
+/// Configures the pool of worker threads used to render pages and run server functions.
+///
+/// Each request is rendered on one of the workers, with its own reactive runtime.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SsrPoolOptions {
+    /// The number of worker threads. Defaults to the available parallelism.
+    pub workers: usize,
+    /// The maximum number of pages that can be rendered at the same time. Further
+    /// requests wait in a queue until a render finishes. Defaults to no limit.
+    pub max_concurrent_renders: Option<usize>,
+    /// How long a single render can take, not counting time spent in the queue.
+    /// Renders that take longer are cancelled: if nothing has been sent yet, the
+    /// response is a `503 Service Unavailable`, otherwise the HTML stream ends early.
+    /// Defaults to no timeout.
+    pub render_timeout: Option<Duration>,
+    /// The number of disposed reactive runtimes each worker keeps, so that later
+    /// requests can reuse their allocations. A runtime is cleared before it is
+    /// reused, so nothing from one request is visible to the next. Defaults to `0`.
+    pub runtime_pool_size: usize,
+}
+
+impl Default for SsrPoolOptions {
+    fn default() -> Self {
+        Self {
+            workers: available_parallelism().map(Into::into).unwrap_or(1),
+            max_concurrent_renders: None,
+            render_timeout: None,
+            runtime_pool_size: 0,
+        }
+    }
+}
+
+static LEPTOS_POOL: OnceCell<LeptosPool> = OnceCell::new();
+
+/// Sets the [SsrPoolOptions] used by all the rendering functions in this crate. This must be
+/// called before the first request is handled; if the pool has already been started, the
+/// options are returned as an error.
+/// ```ignore
+/// leptos_axum::configure_ssr_pool(SsrPoolOptions {
+///     max_concurrent_renders: Some(64),
+///     render_timeout: Some(Duration::from_secs(5)),
+///     runtime_pool_size: 16,
+///     ..Default::default()
+/// })
+/// .expect("SSR pool should not have started yet");
+/// ```
+pub fn configure_ssr_pool(
+    options: SsrPoolOptions,
+) -> Result<(), SsrPoolOptions> {
+    let mut options = Some(options);
+    LEPTOS_POOL.get_or_init(|| LeptosPool::new(options.take().unwrap()));
+    match options {
+        None => Ok(()),
+        Some(options) => Err(options),
+    }
+}
+
+#[derive(Clone)]
+struct LeptosPool {
+    handle: LocalPoolHandle,
+    renders: Option<Arc<Semaphore>>,
+    render_timeout: Option<Duration>,
+}
+
+impl LeptosPool {
+    fn new(options: SsrPoolOptions) -> Self {
+        leptos::set_runtime_pool_capacity(options.runtime_pool_size);
+        Self {
+            handle: LocalPoolHandle::new(options.workers.max(1)),
+            renders: options
+                .max_concurrent_renders
+                .map(|renders| Arc::new(Semaphore::new(renders))),
+            render_timeout: options.render_timeout,
+        }
+    }
+
+    /// Renders on one of the workers, waiting for a free render slot and
+    /// cancelling the render if it exceeds the timeout. The runtime the render
+    /// registers with [RenderRuntime::set_runtime] is disposed if it is cancelled.
+    fn spawn_render<F, Fut>(&self, create_task: F) -> JoinHandle<Option<()>>
+    where
+        F: FnOnce(RenderRuntime) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let renders = self.renders.clone();
+        let render_timeout = self.render_timeout;
+        self.handle.spawn_pinned(move || async move {
+            let _permit = match renders {
+                Some(renders) => Some(renders.acquire_owned().await.ok()?),
+                None => None,
+            };
+            let render = RenderRuntime::default();
+            match render_timeout {
+                Some(duration) => {
+                    let task = create_task(render.clone());
+                    tokio::pin!(task);
+                    let res = tokio::time::timeout(duration, task.as_mut())
+                        .await
+                        .ok();
+                    // dispose the runtime before the task is dropped, so that it
+                    // is gone by the time the response is sent
+                    if res.is_none() {
+                        render.dispose();
+                    }
+                    res
+                }
+                None => {
+                    create_task(render).await;
+                    Some(())
+                }
+            }
         })
+    }
+}
+
+/// The reactive runtime created by a render running in the [LeptosPool].
+#[derive(Clone, Default)]
+struct RenderRuntime(Rc<Cell<Option<RuntimeId>>>);
+
+impl RenderRuntime {
+    fn set_runtime(&self, runtime: RuntimeId) {
+        self.0.set(Some(runtime));
+    }
+
+    // Disposing a runtime twice does nothing, so this is safe even if the
+    // response stream has already disposed it.
+    fn dispose(&self) {
+        if let Some(runtime) = self.0.take() {
+            runtime.dispose();
+        }
+    }
+}
+
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+fn get_leptos_pool() -> LeptosPool {
+    LEPTOS_POOL
+        .get_or_init(|| LeptosPool::new(SsrPoolOptions::default()))
         .clone()
 }
 
+fn render_timed_out<B>(body: B) -> Response<B> {
+    let mut res = Response::new(body);
+    *res.status_mut() = StatusCode::SERVICE_UNAVAILABLE;
+    res
+}
+
 #[derive(Clone, Debug)]
 struct ExtractorHelper {
     parts: Arc<tokio::sync::Mutex<Parts>>,
@@ -0,0 +1,279 @@
+//! Utilities for testing server functions over HTTP, without a browser.
+//!
+//! A [`TestServer`] serves an Axum [`Router`] on an ephemeral local port, and
+//! calls server functions on it the same way the client would: the arguments
+//! are encoded with the server function’s [`Encoding`], and sent to its URL.
+//! The [`TestResponse`] that comes back can then be checked for its status,
+//! headers and encoding, and decoded into the server function’s output.
+//!
+//! This module is only available with the `testing` feature.
+//!
+//! ```ignore
+//! use leptos::*;
+//! use leptos_axum::testing::TestServer;
+//!
+//! #[server(Add, "/api")]
+//! pub async fn add(a: i32, b: i32) -> Result<i32, ServerFnError> {
+//!     Ok(a + b)
+//! }
+//!
+//! # tokio::runtime::Runtime::new().unwrap().block_on(async {
+//! let server = TestServer::for_server_fns().await;
+//!
+//! let response = server.call(Add { a: 1, b: 2 }).await;
+//! response
+//!     .assert_status(http::StatusCode::OK)
+//!     .assert_encoding(Add::encoding());
+//! assert_eq!(response.output::<Add>().unwrap(), 3);
+//! # });
+//! ```
+
+use crate::handle_server_fns;
+use axum::{
+    body::{Body, Bytes},
+    http::{header, HeaderMap, HeaderValue, Method, Request, StatusCode},
+    routing::get,
+    Router,
+};
+use futures::channel::oneshot;
+use hyper::client::HttpConnector;
+use leptos::{
+    server_fn::{Encoding, ServerFn},
+    Scope, ServerFnError,
+};
+use serde::de::DeserializeOwned;
+use std::net::{SocketAddr, TcpListener};
+
+/// A server that serves an Axum [`Router`] on an ephemeral local port, for as
+/// long as it is alive.
+#[derive(Debug)]
+pub struct TestServer {
+    addr: SocketAddr,
+    client: hyper::Client<HttpConnector>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl TestServer {
+    /// Starts serving the given router on a free local port.
+    ///
+    /// This needs to be called from within a Tokio runtime. Any layers the
+    /// router has run for every request, so their behavior can be tested too.
+    ///
+    /// # Panics
+    /// Panics if no local port can be bound.
+    pub async fn new(router: Router) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .expect("could not bind a local port for the test server");
+        let addr = listener
+            .local_addr()
+            .expect("could not read the address of the test server");
+        let (shutdown, shutdown_rx) = oneshot::channel();
+        let server = axum::Server::from_tcp(listener)
+            .expect("could not start the test server")
+            .serve(router.into_make_service())
+            .with_graceful_shutdown(async {
+                _ = shutdown_rx.await;
+            });
+        tokio::spawn(server);
+
+        Self {
+            addr,
+            client: hyper::Client::new(),
+            shutdown: Some(shutdown),
+        }
+    }
+
+    /// Starts serving every registered server function with the default
+    /// `/api` prefix, using [`handle_server_fns`].
+    pub async fn for_server_fns() -> Self {
+        Self::new(Router::new().route(
+            "/api/*fn_name",
+            get(handle_server_fns).post(handle_server_fns),
+        ))
+        .await
+    }
+
+    /// The address the server is listening on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// The full URL of the given path on the server.
+    pub fn url(&self, path: &str) -> String {
+        format!("http://{}{path}", self.addr)
+    }
+
+    /// Builds the request the client would send to call the server function
+    /// with these arguments, so that it can be changed before it is
+    /// [sent](TestServer::send).
+    ///
+    /// # Panics
+    /// Panics if the arguments cannot be encoded.
+    pub fn server_fn_request<F>(&self, args: F) -> Request<Body>
+    where
+        F: ServerFn<Scope>,
+    {
+        let url = self.url(&format!("{}/{}", F::prefix(), F::url()));
+        let encoding = F::encoding();
+        let request = Request::builder()
+            .header(
+                header::CONTENT_TYPE,
+                if encoding == Encoding::Cbor {
+                    content_type(Encoding::Cbor)
+                } else {
+                    content_type(Encoding::Url)
+                },
+            )
+            .header(header::ACCEPT, content_type(encoding));
+        match encoding {
+            Encoding::Url => request
+                .method(Method::POST)
+                .uri(url)
+                .body(Body::from(encode_query(&args))),
+            Encoding::Cbor => {
+                let mut body = Vec::new();
+                ciborium::ser::into_writer(&args, &mut body)
+                    .expect("could not encode the server function arguments");
+                request.method(Method::POST).uri(url).body(Body::from(body))
+            }
+            Encoding::GetJSON | Encoding::GetCBOR => request
+                .method(Method::GET)
+                .uri(format!("{url}?{}", encode_query(&args)))
+                .body(Body::empty()),
+        }
+        .expect("could not build the server function request")
+    }
+
+    /// Calls the server function with these arguments.
+    pub async fn call<F>(&self, args: F) -> TestResponse
+    where
+        F: ServerFn<Scope>,
+    {
+        self.send(self.server_fn_request(args)).await
+    }
+
+    /// Sends a request to the server. If its URI is only a path, it is sent to
+    /// that path on this server.
+    ///
+    /// # Panics
+    /// Panics if the request cannot be sent, or its response read.
+    pub async fn send(&self, mut request: Request<Body>) -> TestResponse {
+        if request.uri().authority().is_none() {
+            *request.uri_mut() = self
+                .url(&request.uri().to_string())
+                .parse()
+                .expect("could not build the request URL");
+        }
+        let response = self
+            .client
+            .request(request)
+            .await
+            .expect("could not send the request to the test server");
+        let (parts, body) = response.into_parts();
+        let body = hyper::body::to_bytes(body)
+            .await
+            .expect("could not read the response from the test server");
+        TestResponse {
+            status: parts.status,
+            headers: parts.headers,
+            body,
+        }
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            _ = shutdown.send(());
+        }
+    }
+}
+
+/// The response to a request sent to a [`TestServer`].
+#[derive(Debug, Clone)]
+pub struct TestResponse {
+    /// The status code.
+    pub status: StatusCode,
+    /// The response headers.
+    pub headers: HeaderMap,
+    /// The body of the response.
+    pub body: Bytes,
+}
+
+impl TestResponse {
+    /// The body of the response, as text.
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Decodes the body of the response as the output of the server function
+    /// `F`, or as the error it returned if the response is a server error.
+    pub fn output<F>(&self) -> Result<F::Output, ServerFnError>
+    where
+        F: ServerFn<Scope>,
+        F::Output: DeserializeOwned,
+    {
+        if self.status.is_server_error() {
+            return Err(serde_json::from_slice(&self.body)
+                .unwrap_or_else(|_| ServerFnError::ServerError(self.text())));
+        }
+        match F::encoding() {
+            Encoding::Url | Encoding::GetJSON => {
+                serde_json::from_slice(&self.body)
+                    .map_err(|e| ServerFnError::Deserialization(e.to_string()))
+            }
+            Encoding::Cbor | Encoding::GetCBOR => {
+                ciborium::de::from_reader(self.body.as_ref())
+                    .map_err(|e| ServerFnError::Deserialization(e.to_string()))
+            }
+        }
+    }
+
+    /// Asserts that the response has this status code.
+    #[track_caller]
+    pub fn assert_status(&self, status: StatusCode) -> &Self {
+        assert_eq!(
+            self.status,
+            status,
+            "unexpected status, with body {:?}",
+            self.text()
+        );
+        self
+    }
+
+    /// Asserts that the response has a header with this value.
+    #[track_caller]
+    pub fn assert_header(&self, name: &str, value: &str) -> &Self {
+        assert_eq!(
+            self.headers.get(name),
+            Some(&HeaderValue::from_str(value).expect("invalid header value")),
+            "unexpected value for the {name:?} header"
+        );
+        self
+    }
+
+    /// Asserts that the body of the response is in the format a server
+    /// function with this encoding returns.
+    #[track_caller]
+    pub fn assert_encoding(&self, encoding: Encoding) -> &Self {
+        self.assert_header(
+            header::CONTENT_TYPE.as_str(),
+            content_type(encoding),
+        )
+    }
+}
+
+/// The content type of the data sent with, and returned for, an encoding.
+fn content_type(encoding: Encoding) -> &'static str {
+    match encoding {
+        Encoding::Url | Encoding::GetJSON => {
+            "application/x-www-form-urlencoded"
+        }
+        Encoding::Cbor | Encoding::GetCBOR => "application/cbor",
+    }
+}
+
+fn encode_query(args: &impl serde::Serialize) -> String {
+    serde_qs::to_string(args)
+        .expect("could not encode the server function arguments")
+}
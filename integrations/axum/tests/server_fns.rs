@@ -0,0 +1,150 @@
+use axum::{
+    http::{HeaderValue, Request, StatusCode},
+    middleware::map_response,
+    response::Response,
+    routing::post,
+    Router,
+};
+use leptos::{server_fn::Encoding, Scope, ServerFn, ServerFnError};
+use leptos_axum::{handle_server_fns, testing::TestServer};
+use serde::{Deserialize, Serialize};
+use std::{future::Future, pin::Pin, sync::Once};
+
+// `#[server]` only generates the server side of a server function when the
+// crate using it has an `ssr` feature, so these are implemented by hand
+macro_rules! server_fn {
+    ($name:ident, $url:literal, $encoding:expr, |$args:ident| -> $output:ty $body:block) => {
+        #[derive(Clone, Debug, Serialize, Deserialize)]
+        pub struct $name {
+            a: i32,
+            b: i32,
+        }
+
+        impl leptos::server_fn::ServerFn<Scope> for $name {
+            type Output = $output;
+
+            fn prefix() -> &'static str {
+                "/api"
+            }
+
+            fn url() -> &'static str {
+                $url
+            }
+
+            fn encoding() -> Encoding {
+                $encoding
+            }
+
+            fn call_fn(
+                self,
+                _cx: Scope,
+            ) -> Pin<Box<dyn Future<Output = Result<$output, ServerFnError>>>>
+            {
+                let $args = self;
+                Box::pin(async move { $body })
+            }
+        }
+    };
+}
+
+server_fn!(AddUrl, "add_url", Encoding::Url, |args| -> i32 {
+    Ok(args.a + args.b)
+});
+server_fn!(AddCbor, "add_cbor", Encoding::Cbor, |args| -> i32 {
+    Ok(args.a + args.b)
+});
+server_fn!(
+    AddGetJson,
+    "add_get_json",
+    Encoding::GetJSON,
+    |args| -> i32 { Ok(args.a + args.b) }
+);
+server_fn!(Fail, "fail", Encoding::Url, |_args| -> () {
+    Err(ServerFnError::ServerError(
+        "something went wrong".to_string(),
+    ))
+});
+
+fn register() {
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| {
+        AddUrl::register_explicit().unwrap();
+        AddCbor::register_explicit().unwrap();
+        AddGetJson::register_explicit().unwrap();
+        Fail::register_explicit().unwrap();
+    });
+}
+
+#[tokio::test]
+async fn server_fns_round_trip_in_each_encoding() {
+    register();
+    let server = TestServer::for_server_fns().await;
+
+    let response = server.call(AddUrl { a: 1, b: 2 }).await;
+    response
+        .assert_status(StatusCode::OK)
+        .assert_encoding(Encoding::Url);
+    assert_eq!(response.output::<AddUrl>().unwrap(), 3);
+
+    let response = server.call(AddCbor { a: 3, b: 4 }).await;
+    response
+        .assert_status(StatusCode::OK)
+        .assert_encoding(Encoding::Cbor);
+    assert_eq!(response.output::<AddCbor>().unwrap(), 7);
+
+    let response = server.call(AddGetJson { a: 5, b: 6 }).await;
+    response
+        .assert_status(StatusCode::OK)
+        .assert_encoding(Encoding::GetJSON);
+    assert_eq!(response.output::<AddGetJson>().unwrap(), 11);
+}
+
+#[tokio::test]
+async fn server_fn_errors_are_returned_as_server_errors() {
+    register();
+    let server = TestServer::for_server_fns().await;
+
+    let response = server.call(Fail { a: 0, b: 0 }).await;
+    response.assert_status(StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(matches!(
+        response.output::<Fail>(),
+        Err(ServerFnError::ServerError(message))
+            if message == "something went wrong"
+    ));
+}
+
+#[tokio::test]
+async fn unknown_server_fns_are_bad_requests() {
+    register();
+    let server = TestServer::for_server_fns().await;
+
+    let request = Request::post("/api/missing").body(Default::default());
+    server
+        .send(request.unwrap())
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn router_layers_run_for_server_fns() {
+    async fn tag(mut response: Response) -> Response {
+        response
+            .headers_mut()
+            .insert("x-tagged", HeaderValue::from_static("yes"));
+        response
+    }
+
+    register();
+    let server = TestServer::new(
+        Router::new()
+            .route("/api/*fn_name", post(handle_server_fns))
+            .layer(map_response(tag)),
+    )
+    .await;
+
+    server
+        .call(AddUrl { a: 1, b: 1 })
+        .await
+        .assert_status(StatusCode::OK)
+        .assert_header("x-tagged", "yes");
+}
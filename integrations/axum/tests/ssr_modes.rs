@@ -0,0 +1,129 @@
+use axum::{
+    http::{header, Request, StatusCode},
+    routing::get,
+    Router,
+};
+use leptos::*;
+use leptos_axum::{
+    render_app_to_stream, render_app_with_ssr_mode, testing::TestServer,
+};
+use leptos_meta::Title;
+use leptos_router::SsrMode;
+
+#[component]
+fn App(cx: Scope) -> impl IntoView {
+    let data = create_resource(cx, || (), |_| async { "Loaded".to_string() });
+    view! { cx,
+        <main>
+            <Suspense fallback=|| "Loading">
+                <p>{move || data.read(cx)}</p>
+            </Suspense>
+        </main>
+    }
+}
+
+// Whether the app comes before the script that sets up streamed resources.
+fn app_is_before_resources(html: &str) -> bool {
+    let app = html.find("<main").expect("app is rendered");
+    let resources = html
+        .find("__LEPTOS_PENDING_RESOURCES")
+        .expect("resources are set up");
+    app < resources
+}
+
+#[tokio::test]
+async fn ssr_mode_is_chosen_for_each_request() {
+    let handler = render_app_with_ssr_mode(
+        LeptosOptions::builder().output_name("test").build(),
+        |_| {},
+        |cx| view! { cx, <App/> },
+        |req: &Request<_>| {
+            let is_bot = req
+                .headers()
+                .get(header::USER_AGENT)
+                .map(|agent| agent == "bot")
+                .unwrap_or(false);
+            if is_bot {
+                SsrMode::InOrder
+            } else {
+                SsrMode::OutOfOrder
+            }
+        },
+    );
+    let server = TestServer::new(Router::new().route("/", get(handler))).await;
+
+    // out-of-order streaming sends the whole shell first
+    let response = server
+        .send(Request::get("/").body(Default::default()).unwrap())
+        .await;
+    response.assert_status(StatusCode::OK);
+    assert!(app_is_before_resources(&response.text()));
+
+    // in-order streaming sends the resources ahead of the app, which then
+    // waits for them
+    let response = server
+        .send(
+            Request::get("/")
+                .header(header::USER_AGENT, "bot")
+                .body(Default::default())
+                .unwrap(),
+        )
+        .await;
+    response.assert_status(StatusCode::OK);
+    assert!(!app_is_before_resources(&response.text()));
+}
+
+#[tokio::test]
+async fn metadata_follows_early_head() {
+    #[component]
+    fn Page(cx: Scope) -> impl IntoView {
+        view! { cx,
+            <Title text="Early"/>
+            <App/>
+        }
+    }
+
+    let options = LeptosOptions::builder()
+        .output_name("test")
+        .flush_head_early(true)
+        .build();
+    let handler = render_app_to_stream(options, |cx| view! { cx, <Page/> });
+    let server = TestServer::new(Router::new().route("/", get(handler))).await;
+
+    let response = server
+        .send(Request::get("/").body(Default::default()).unwrap())
+        .await;
+    response.assert_status(StatusCode::OK);
+    let html = response.text();
+    let preload = html.find("rel=\"modulepreload\"").unwrap();
+    let title = html.find("<title>Early</title>").unwrap();
+    let app = html.find("<main").unwrap();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(preload < title && title < app);
+    assert!(html.ends_with("</body></html>"));
+}
+
+#[tokio::test]
+async fn events_are_recorded_before_the_app() {
+    for flush_head_early in [false, true] {
+        let options = LeptosOptions::builder()
+            .output_name("test")
+            .flush_head_early(flush_head_early)
+            .build();
+        let handler = render_app_to_stream(options, |cx| view! { cx, <App/> });
+        let server =
+            TestServer::new(Router::new().route("/", get(handler))).await;
+
+        let response = server
+            .send(Request::get("/").body(Default::default()).unwrap())
+            .await;
+        response.assert_status(StatusCode::OK);
+        let html = response.text();
+        let recorder = html
+            .find(leptos::leptos_dom::HYDRATION_EVENT_RECORDER)
+            .expect("events are recorded");
+        let hydration = html.find("init(").unwrap();
+        let app = html.find("<main").unwrap();
+        assert!(recorder < hydration && recorder < app);
+    }
+}
@@ -0,0 +1,125 @@
+use axum::{
+    http::{Request, StatusCode},
+    routing::get,
+    Router,
+};
+use leptos::*;
+use leptos_axum::{
+    configure_ssr_pool, render_app_async, testing::TestServer, SsrPoolOptions,
+};
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+// Every test in this file shares the same pool, which can only be configured once.
+fn configure() {
+    _ = configure_ssr_pool(SsrPoolOptions {
+        max_concurrent_renders: Some(1),
+        render_timeout: Some(Duration::from_millis(300)),
+        ..Default::default()
+    });
+}
+
+fn request() -> Request<axum::body::Body> {
+    Request::get("/").body(Default::default()).unwrap()
+}
+
+#[tokio::test]
+async fn renders_wait_for_a_free_slot() {
+    configure();
+
+    static RENDERING: AtomicUsize = AtomicUsize::new(0);
+    static MAX_RENDERING: AtomicUsize = AtomicUsize::new(0);
+
+    #[component]
+    fn Slow(cx: Scope) -> impl IntoView {
+        let data = create_resource(
+            cx,
+            || (),
+            |_| async {
+                let rendering = RENDERING.fetch_add(1, Ordering::SeqCst) + 1;
+                MAX_RENDERING.fetch_max(rendering, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                RENDERING.fetch_sub(1, Ordering::SeqCst);
+                "Loaded".to_string()
+            },
+        );
+        view! { cx,
+            <Suspense fallback=|| "Loading">
+                <p>{move || data.read(cx)}</p>
+            </Suspense>
+        }
+    }
+
+    let handler = render_app_async(
+        LeptosOptions::builder().output_name("test").build(),
+        |cx| view! { cx, <Slow/> },
+    );
+    let server = TestServer::new(Router::new().route("/", get(handler))).await;
+
+    let responses =
+        futures::future::join_all((0..3).map(|_| server.send(request()))).await;
+    for response in responses {
+        response.assert_status(StatusCode::OK);
+        assert!(response.text().contains("Loaded"));
+    }
+    assert_eq!(MAX_RENDERING.load(Ordering::SeqCst), 1);
+}
+
+struct DropFlag(Arc<AtomicBool>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+// Runtimes are only freed when they are disposed if `leptos` is built without the
+// `csr` feature, which testing the whole workspace enables.
+fn disposing_frees_runtimes() -> bool {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let runtime = create_runtime();
+    let flag = DropFlag(Arc::clone(&dropped));
+    _ = create_scope(runtime, move |cx| {
+        store_value(cx, flag);
+    });
+    runtime.dispose();
+    dropped.load(Ordering::SeqCst)
+}
+
+#[tokio::test]
+async fn renders_that_time_out_are_disposed() {
+    configure();
+
+    let disposed = Arc::new(AtomicBool::new(false));
+    let handler = render_app_async(
+        LeptosOptions::builder().output_name("test").build(),
+        {
+            let disposed = Arc::clone(&disposed);
+            move |cx| {
+                store_value(cx, DropFlag(Arc::clone(&disposed)));
+                let never = create_resource(
+                    cx,
+                    || (),
+                    |_| futures::future::pending::<String>(),
+                );
+                view! { cx,
+                    <Suspense fallback=|| "Loading">
+                        <p>{move || never.read(cx)}</p>
+                    </Suspense>
+                }
+            }
+        },
+    );
+    let server = TestServer::new(Router::new().route("/", get(handler))).await;
+
+    let response = server.send(request()).await;
+    response.assert_status(StatusCode::SERVICE_UNAVAILABLE);
+    if disposing_frees_runtimes() {
+        assert!(disposed.load(Ordering::SeqCst));
+    }
+}
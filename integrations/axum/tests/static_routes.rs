@@ -0,0 +1,131 @@
+use leptos::*;
+use leptos_axum::{
+    regenerate_static_route, render_static_routes, StaticManifest,
+};
+use leptos_meta::{provide_meta_context, Title};
+use leptos_router::*;
+use std::{
+    cell::Cell,
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+#[component]
+fn App(cx: Scope) -> impl IntoView {
+    provide_meta_context(cx);
+    view! { cx,
+        <Router>
+            <Routes>
+                <Route path="" view=|cx| view! { cx, <Title text="Home"/> }/>
+                <Route path="posts/:id" view=Post/>
+            </Routes>
+        </Router>
+    }
+}
+
+#[component]
+fn Post(cx: Scope) -> impl IntoView {
+    let params = use_params_map(cx);
+    let title = create_resource(
+        cx,
+        move || params.with(|params| params.get("id").cloned()),
+        |id| async move { format!("Post {}", id.unwrap_or_default()) },
+    );
+    view! { cx,
+        <Suspense fallback=|| "Loading">
+            <h1>{move || title.read(cx)}</h1>
+        </Suspense>
+    }
+}
+
+fn out_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir()
+        .join(format!("leptos-static-{name}-{}", std::process::id()));
+    _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn read(dir: &Path, file: &str) -> String {
+    fs::read_to_string(dir.join(file)).unwrap()
+}
+
+#[tokio::test]
+async fn renders_every_page_with_resolved_resources() {
+    let dir = out_dir("pages");
+    let routes_with_params = Rc::new(Cell::new(0));
+    let manifest = render_static_routes(
+        LeptosOptions::builder().output_name("test").build(),
+        |cx| view! { cx, <App/> },
+        |_| {},
+        {
+            let routes_with_params = Rc::clone(&routes_with_params);
+            move |route| {
+                routes_with_params.set(routes_with_params.get() + 1);
+                assert_eq!(route, "/posts/:id");
+                async {
+                    ["1", "2"]
+                        .into_iter()
+                        .map(|id| {
+                            let mut params = ParamsMap::new();
+                            params.insert("id".to_string(), id.to_string());
+                            params
+                        })
+                        .collect()
+                }
+            }
+        },
+        &dir,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(routes_with_params.get(), 1);
+    let mut paths = manifest
+        .pages
+        .iter()
+        .map(|page| page.path.as_str())
+        .collect::<Vec<_>>();
+    paths.sort();
+    assert_eq!(paths, ["/", "/posts/1", "/posts/2"]);
+    assert_eq!(manifest.page("/posts/2/").unwrap().route, "/posts/:id");
+    assert_eq!(StaticManifest::read(&dir).unwrap(), manifest);
+
+    let home = read(&dir, "index.html");
+    assert!(home.starts_with("<!DOCTYPE html>"));
+    assert!(home.contains("<title>Home</title>"));
+    let post = read(&dir, "posts/1/index.html");
+    assert!(post.contains("Post 1"));
+    assert!(read(&dir, "posts/2/index.html").contains("Post 2"));
+
+    _ = fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn regenerates_a_single_page() {
+    let dir = out_dir("regenerate");
+    let options = LeptosOptions::builder().output_name("test").build();
+    let page = regenerate_static_route(
+        options.clone(),
+        |cx| view! { cx, <App/> },
+        |_| {},
+        "/posts/3",
+        &dir,
+    )
+    .await
+    .unwrap();
+    assert_eq!(page.file, Path::new("posts/3/index.html"));
+    assert!(read(&dir, "posts/3/index.html").contains("Post 3"));
+
+    let escaped = regenerate_static_route(
+        options,
+        |cx| view! { cx, <App/> },
+        |_| {},
+        "/../x",
+        &dir,
+    )
+    .await;
+    assert!(escaped.is_err());
+
+    _ = fs::remove_dir_all(&dir);
+}
@@ -5,6 +5,10 @@ use leptos_meta::MetaContext;
 
 extern crate tracing;
 
+mod static_routes;
+
+pub use static_routes::*;
+
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 fn autoreload(options: &LeptosOptions) -> String {
     let site_ip = &options.site_addr.ip().to_string();
@@ -13,31 +17,16 @@ fn autoreload(options: &LeptosOptions) -> String {
         true => format!(
             r#"
                 <script crossorigin="">(function () {{
+                    {}
                     {}
                     var ws = new WebSocket('ws://{site_ip}:{reload_port}/live_reload');
-                    ws.onmessage = (ev) => {{
-                        let msg = JSON.parse(ev.data);
-                        if (msg.all) window.location.reload();
-                        if (msg.css) {{
-                            let found = false;
-                            document.querySelectorAll("link").forEach((link) => {{
-                                if (link.getAttribute('href').includes(msg.css)) {{
-                                    let newHref = '/' + msg.css + '?version=' + new Date().getMilliseconds();
-                                    link.setAttribute('href', newHref);
-                                    found = true;
-                                }}
-                            }});
-                            if (!found) console.warn(`CSS hot-reload: Could not find a <link href=/\"${{msg.css}}\"> element`);
-                        }};
-                        if(msg.view) {{
-                            patch(msg.view);
-                        }}
-                    }};
+                    ws.onmessage = (ev) => window.__LEPTOS_DEV__.handle(ev.data);
                     ws.onclose = () => console.warn('Live-reload stopped. Manual reload necessary.');
                 }})()
                 </script>
                 "#,
-            leptos_hot_reload::HOT_RELOAD_JS
+            leptos_hot_reload::HOT_RELOAD_JS,
+            leptos_hot_reload::DEV_CLIENT_JS
         ),
         false => "".to_string(),
     }
@@ -59,6 +48,8 @@ pub fn html_parts(
     }
 
     let leptos_autoreload = autoreload(options);
+    let event_recorder = leptos::leptos_dom::HYDRATION_EVENT_RECORDER;
+    let hydration_script = hydration_script(options, &wasm_output_name);
 
     let html_metadata =
         meta.and_then(|mc| mc.html.as_string()).unwrap_or_default();
@@ -70,7 +61,8 @@ pub fn html_parts(
                     <meta name="viewport" content="width=device-width, initial-scale=1"/>
                     <link rel="modulepreload" href="/{pkg_path}/{output_name}.js">
                     <link rel="preload" href="/{pkg_path}/{wasm_output_name}.wasm" as="fetch" type="application/wasm" crossorigin="">
-                    <script type="module">import init, {{ hydrate }} from '/{pkg_path}/{output_name}.js'; init('/{pkg_path}/{wasm_output_name}.wasm').then(hydrate);</script>
+                    <script>{event_recorder}</script>
+                    {hydration_script}
                     {leptos_autoreload}
                     "#
     );
@@ -85,16 +77,11 @@ pub fn html_parts_separated(
 ) -> (String, &'static str) {
     let pkg_path = &options.site_pkg_dir;
     let output_name = &options.output_name;
-
-    // Because wasm-pack adds _bg to the end of the WASM filename, and we want to mantain compatibility with it's default options
-    // we add _bg to the wasm files if cargo-leptos doesn't set the env var LEPTOS_OUTPUT_NAME
-    // Otherwise we need to add _bg because wasm_pack always does. This is not the same as options.output_name, which is set regardless
-    let mut wasm_output_name = output_name.clone();
-    if std::env::var("LEPTOS_OUTPUT_NAME").is_err() {
-        wasm_output_name.push_str("_bg");
-    }
+    let wasm_output_name = wasm_output_name(options);
 
     let leptos_autoreload = autoreload(options);
+    let event_recorder = leptos::leptos_dom::HYDRATION_EVENT_RECORDER;
+    let hydration_script = hydration_script(options, &wasm_output_name);
 
     let html_metadata =
         meta.and_then(|mc| mc.html.as_string()).unwrap_or_default();
@@ -111,7 +98,8 @@ pub fn html_parts_separated(
                     {head}
                     <link rel="modulepreload" href="/{pkg_path}/{output_name}.js">
                     <link rel="preload" href="/{pkg_path}/{wasm_output_name}.wasm" as="fetch" type="application/wasm" crossorigin="">
-                    <script type="module">import init, {{ hydrate }} from '/{pkg_path}/{output_name}.js'; init('/{pkg_path}/{wasm_output_name}.wasm').then(hydrate);</script>
+                    <script>{event_recorder}</script>
+                    {hydration_script}
                     {leptos_autoreload}
                     "#
     );
@@ -119,6 +107,83 @@ pub fn html_parts_separated(
     (head, tail)
 }
 
+// Because wasm-pack adds _bg to the end of the WASM filename, and we want to mantain compatibility with it's default options
+// we add _bg to the wasm files if cargo-leptos doesn't set the env var LEPTOS_OUTPUT_NAME
+// Otherwise we need to add _bg because wasm_pack always does. This is not the same as options.output_name, which is set regardless
+fn wasm_output_name(options: &LeptosOptions) -> String {
+    let mut wasm_output_name = options.output_name.clone();
+    if std::env::var("LEPTOS_OUTPUT_NAME").is_err() {
+        wasm_output_name.push_str("_bg");
+    }
+    wasm_output_name
+}
+
+// Loads the WASM bundle, then hydrates the whole app, or, in islands mode,
+// each of the outermost islands on the page, since the ones inside them are
+// hydrated along with them.
+fn hydration_script(options: &LeptosOptions, wasm_output_name: &str) -> String {
+    let pkg_path = &options.site_pkg_dir;
+    let output_name = &options.output_name;
+    if options.islands {
+        format!(
+            r#"<script type="module">import init, * as islands from '/{pkg_path}/{output_name}.js'; init('/{pkg_path}/{wasm_output_name}.wasm').then(() => document.querySelectorAll('leptos-island').forEach((el) => el.parentElement.closest('leptos-island') || islands['_island_' + el.dataset.component](el)));</script>"#
+        )
+    } else {
+        format!(
+            r#"<script type="module">import init, {{ hydrate }} from '/{pkg_path}/{output_name}.js'; init('/{pkg_path}/{wasm_output_name}.wasm').then(hydrate);</script>"#
+        )
+    }
+}
+
+/// The start of the `<head>` for [`LeptosOptions::flush_head_early`], with
+/// the preload hints for the JS and WASM bundles and the script that
+/// hydrates the app, so that the browser starts to load them before any
+/// `<Suspense/>` has resolved. It is followed by [`html_head_metadata`].
+///
+/// Only the attributes of `<html>` that have been set when this is called
+/// are included.
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub fn html_head_start(
+    options: &LeptosOptions,
+    meta: Option<&MetaContext>,
+) -> String {
+    let pkg_path = &options.site_pkg_dir;
+    let output_name = &options.output_name;
+    let wasm_output_name = wasm_output_name(options);
+    let event_recorder = leptos::leptos_dom::HYDRATION_EVENT_RECORDER;
+    let hydration_script = hydration_script(options, &wasm_output_name);
+
+    let html_metadata =
+        meta.and_then(|mc| mc.html.as_string()).unwrap_or_default();
+    format!(
+        r#"<!DOCTYPE html>
+            <html{html_metadata}>
+                <head>
+                    <meta charset="utf-8"/>
+                    <meta name="viewport" content="width=device-width, initial-scale=1"/>
+                    <link rel="modulepreload" href="/{pkg_path}/{output_name}.js">
+                    <link rel="preload" href="/{pkg_path}/{wasm_output_name}.wasm" as="fetch" type="application/wasm" crossorigin="">
+                    <script>{event_recorder}</script>
+                    {hydration_script}
+                    "#
+    )
+}
+
+/// The rest of the `<head>` after [`html_head_start`]: the metadata, like
+/// `<title>` and stylesheets, that the app has added with `leptos_meta`.
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub fn html_head_metadata(
+    options: &LeptosOptions,
+    meta: Option<&MetaContext>,
+) -> String {
+    let head = meta
+        .as_ref()
+        .map(|meta| meta.dehydrate())
+        .unwrap_or_default();
+    let leptos_autoreload = autoreload(options);
+    format!("{head}{leptos_autoreload}")
+}
+
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub async fn build_async_response(
     stream: impl Stream<Item = String> + 'static,
@@ -151,3 +216,33 @@ pub async fn build_async_response(
 
     format!("{head}{head_meta}</head><body{body_meta}>{buf}{tail}")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Whether events are recorded from the start of the page, before the
+    // script that hydrates it is loaded.
+    fn records_events_before_hydrating(head: &str) -> bool {
+        let recorder = head
+            .find(leptos::leptos_dom::HYDRATION_EVENT_RECORDER)
+            .expect("events are recorded");
+        let hydration = head.find("init(").expect("page is hydrated");
+        recorder < hydration
+    }
+
+    #[test]
+    fn event_recorder_is_in_each_head() {
+        let options = LeptosOptions::builder().output_name("test").build();
+
+        assert!(records_events_before_hydrating(
+            &html_parts(&options, None).0
+        ));
+        assert!(records_events_before_hydrating(
+            &html_parts_separated(&options, None).0
+        ));
+        assert!(records_events_before_hydrating(&html_head_start(
+            &options, None
+        )));
+    }
+}
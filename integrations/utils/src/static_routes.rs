@@ -0,0 +1,237 @@
+use crate::build_async_response;
+use futures::Future;
+use leptos::{
+    provide_context,
+    ssr::render_to_stream_in_order_with_prefix_undisposed_with_context,
+    IntoView, Scope,
+};
+use leptos_config::LeptosOptions;
+use leptos_meta::MetaContext;
+use leptos_router::{
+    generate_route_list_inner, Method, ParamsMap, RouteListing,
+    RouterIntegrationContext, ServerIntegration,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The name of the manifest that [`render_static_routes`] writes to the
+/// output directory.
+pub const STATIC_MANIFEST_FILE: &str = "leptos-static.json";
+
+/// A page that has been rendered to a static HTML file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaticPage {
+    /// The path the page is served at, like `/posts/1`.
+    pub path: String,
+    /// The route it was rendered from, like `/posts/:id`.
+    pub route: String,
+    /// The HTML file, relative to the output directory.
+    pub file: PathBuf,
+}
+
+/// Lists the pages written by [`render_static_routes`], so that a server or
+/// a deployment script can find them.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaticManifest {
+    /// The pages that have been rendered.
+    pub pages: Vec<StaticPage>,
+}
+
+impl StaticManifest {
+    /// Reads the manifest that was written to the output directory.
+    pub fn read(out_dir: impl AsRef<Path>) -> io::Result<Self> {
+        let json = fs::read(out_dir.as_ref().join(STATIC_MANIFEST_FILE))?;
+        serde_json::from_slice(&json)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// The page that is served at `path`, if it was rendered.
+    pub fn page(&self, path: &str) -> Option<&StaticPage> {
+        let path = normalize_path(path);
+        self.pages.iter().find(|page| page.path == path)
+    }
+}
+
+/// Renders every `GET` route of the app to an HTML file in `out_dir`, after
+/// all of its resources have loaded, and writes a [`StaticManifest`] listing
+/// them to [`STATIC_MANIFEST_FILE`].
+///
+/// The page for `/posts/1` is written to `posts/1/index.html`. For routes
+/// with params, like `/posts/:id` or `/docs/*path`, `params` is called with
+/// the route and returns the values of the params for each page that should
+/// be rendered; a route with no values isn't rendered.
+///
+/// This has to run inside a [`LocalSet`](https://docs.rs/tokio/latest/tokio/task/struct.LocalSet.html),
+/// since resources are loaded with `spawn_local`.
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub async fn render_static_routes<IV, P, Fut>(
+    options: &LeptosOptions,
+    app_fn: impl Fn(Scope) -> IV + Clone + 'static,
+    additional_context: impl Fn(Scope) + Clone + 'static,
+    params: P,
+    out_dir: impl AsRef<Path>,
+) -> io::Result<StaticManifest>
+where
+    IV: IntoView + 'static,
+    P: Fn(String) -> Fut,
+    Fut: Future<Output = Vec<ParamsMap>>,
+{
+    let out_dir = out_dir.as_ref();
+    let routes = generate_route_list_inner(app_fn.clone());
+
+    let mut manifest = StaticManifest::default();
+    for route in routes.iter().filter(|route| is_static(route)) {
+        let paths = if has_params(route.path()) {
+            static_paths(route.path(), &params(route.path().to_string()).await)
+        } else {
+            vec![normalize_path(route.path())]
+        };
+        for path in paths {
+            let page = regenerate_static_route(
+                options,
+                app_fn.clone(),
+                additional_context.clone(),
+                &path,
+                out_dir,
+            )
+            .await?;
+            manifest.pages.push(StaticPage {
+                route: normalize_path(route.path()),
+                ..page
+            });
+        }
+    }
+
+    let json = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    fs::create_dir_all(out_dir)?;
+    fs::write(out_dir.join(STATIC_MANIFEST_FILE), json)?;
+    Ok(manifest)
+}
+
+/// Renders the page at `path` again and replaces its file in `out_dir`, e.g.,
+/// when the data it shows has changed, or after it has been served for a
+/// while. The manifest isn’t changed, so the `route` of the returned page is
+/// the same as its `path`.
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub async fn regenerate_static_route<IV>(
+    options: &LeptosOptions,
+    app_fn: impl FnOnce(Scope) -> IV + 'static,
+    additional_context: impl FnOnce(Scope) + 'static,
+    path: &str,
+    out_dir: impl AsRef<Path>,
+) -> io::Result<StaticPage>
+where
+    IV: IntoView + 'static,
+{
+    let path = normalize_path(path);
+    let file = static_file(&path).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{path:?} can't be written to a static file"),
+        )
+    })?;
+    let html =
+        render_static_route(options, app_fn, additional_context, &path).await;
+
+    let full_file = out_dir.as_ref().join(&file);
+    if let Some(dir) = full_file.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(full_file, html)?;
+    Ok(StaticPage {
+        route: path.clone(),
+        path,
+        file,
+    })
+}
+
+/// Renders the page at `path` to HTML, after all of its resources have
+/// loaded.
+#[tracing::instrument(level = "trace", fields(error), skip_all)]
+pub async fn render_static_route<IV>(
+    options: &LeptosOptions,
+    app_fn: impl FnOnce(Scope) -> IV + 'static,
+    additional_context: impl FnOnce(Scope) + 'static,
+    path: &str,
+) -> String
+where
+    IV: IntoView + 'static,
+{
+    let full_path = format!("http://leptos.dev{path}");
+    let app = move |cx| {
+        let integration = ServerIntegration { path: full_path };
+        provide_context(cx, RouterIntegrationContext::new(integration));
+        provide_context(cx, MetaContext::new());
+        app_fn(cx).into_view(cx)
+    };
+    let (stream, runtime, scope) =
+        render_to_stream_in_order_with_prefix_undisposed_with_context(
+            app,
+            |_| "".into(),
+            additional_context,
+        );
+    build_async_response(stream, options, runtime, scope).await
+}
+
+/// The paths of the pages for a route like `/posts/:id` or `/docs/*path`,
+/// with each of `params` filled in. Values that are missing, or that would
+/// leave the output directory, are skipped.
+pub fn static_paths(route: &str, params: &[ParamsMap]) -> Vec<String> {
+    params
+        .iter()
+        .filter_map(|params| {
+            let mut path = String::new();
+            for segment in route.split('/').filter(|s| !s.is_empty()) {
+                let value = match segment.strip_prefix([':', '*']) {
+                    Some(name) => params.get(name).map(String::as_str),
+                    None => Some(segment),
+                };
+                let Some(value) = value else {
+                    tracing::warn!("no value for {segment:?} in {route:?}");
+                    return None;
+                };
+                path.push('/');
+                path.push_str(value.trim_matches('/'));
+            }
+            let path = normalize_path(&path);
+            if static_file(&path).is_none() {
+                tracing::warn!("{path:?} can't be written to a static file");
+                return None;
+            }
+            Some(path)
+        })
+        .collect()
+}
+
+// a file server can only respond to `GET` requests
+fn is_static(route: &RouteListing) -> bool {
+    route.methods().any(|method| method == Method::Get)
+}
+
+fn has_params(route: &str) -> bool {
+    route
+        .split('/')
+        .any(|segment| segment.starts_with(':') || segment.starts_with('*'))
+}
+
+fn normalize_path(path: &str) -> String {
+    format!("/{}", path.trim_matches('/'))
+}
+
+// `/posts/1` is written to `posts/1/index.html`, so that file servers find
+// it for both `/posts/1` and `/posts/1/`.
+fn static_file(path: &str) -> Option<PathBuf> {
+    let mut file = PathBuf::new();
+    for segment in path.split('/').filter(|s| !s.is_empty()) {
+        if segment == "." || segment == ".." || segment.contains('\\') {
+            return None;
+        }
+        file.push(segment);
+    }
+    file.push("index.html");
+    Some(file)
+}
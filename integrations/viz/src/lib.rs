@@ -105,6 +105,17 @@ pub fn redirect(cx: leptos::Scope, path: &str) {
     }
 }
 
+/// Sets the number of disposed reactive runtimes each thread keeps, so that later
+/// requests can reuse their allocations instead of creating a new runtime from
+/// scratch. A runtime is cleared before it is reused, so nothing from one request
+/// is visible to the next. Defaults to `0`, which means runtimes are never reused.
+/// ```ignore
+/// leptos_viz::configure_runtime_pool(16);
+/// ```
+pub fn configure_runtime_pool(capacity: usize) {
+    leptos::set_runtime_pool_capacity(capacity);
+}
+
 /// Decomposes an HTTP request into its parts, allowing you to read its headers
 /// and other data without consuming the body.
 pub async fn generate_request_parts(req: Request) -> RequestParts {
@@ -774,6 +785,23 @@ where
     }
 }
 
+// Wraps the app so that it sends the `Cache-Control` header declared by a route.
+fn with_cache_control<IV>(
+    cache_control: Option<&'static str>,
+    app_fn: impl Fn(leptos::Scope) -> IV + Clone + Send + Sync + 'static,
+) -> impl Fn(leptos::Scope) -> IV + Clone + Send + Sync + 'static {
+    move |cx| {
+        let value =
+            cache_control.and_then(|value| HeaderValue::from_str(value).ok());
+        if let (Some(value), Some(res_options)) =
+            (value, use_context::<ResponseOptions>(cx))
+        {
+            res_options.insert_header(header::CACHE_CONTROL, value);
+        }
+        app_fn(cx)
+    }
+}
+
 fn provide_contexts(
     cx: Scope,
     path: String,
@@ -786,6 +814,14 @@ fn provide_contexts(
     provide_context(cx, req_parts);
     provide_context(cx, default_res_options);
     provide_server_redirect(cx, move |path| redirect(cx, path));
+    provide_server_status(cx, move |status| {
+        if let (Some(res_options), Ok(status)) = (
+            use_context::<ResponseOptions>(cx),
+            StatusCode::from_u16(status),
+        ) {
+            res_options.set_status(status);
+        }
+    });
 }
 
 /// Returns a Viz [Handler](viz::Handler) that listens for a `GET` request and tries
@@ -1038,6 +1074,7 @@ where
                     listing.mode(),
                     listing.methods(),
                 )
+                .with_cache_control(listing.cache_control())
             } else {
                 listing
             }
@@ -1117,6 +1154,8 @@ impl LeptosRoutes for Router {
         paths.iter().fold(self, |router, listing| {
             let path = listing.path();
             let mode = listing.mode();
+            let app_fn =
+                with_cache_control(listing.cache_control(), app_fn.clone());
 
             listing.methods().fold(router, |router, method| match mode {
                 SsrMode::OutOfOrder => {
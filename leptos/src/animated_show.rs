@@ -0,0 +1,207 @@
+use crate::ChildrenFn;
+use cfg_if::cfg_if;
+use leptos::component;
+use leptos_dom::{create_node_ref, ev, html, IntoView, NodeRef};
+use leptos_reactive::{
+    create_effect, create_memo, create_signal, signal_prelude::*, Scope,
+    ScopeDisposer,
+};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::Duration,
+};
+
+/// A component that shows its children when the `when` condition is `true`,
+/// like [`Show`](crate::Show), but lets them animate out before they are
+/// removed.
+///
+/// The children are wrapped in a `<div>`, which has the `enter_class` while
+/// they are shown, so a CSS animation can play as they appear. When `when`
+/// becomes `false`, the `enter_class` is replaced with the `leave_class`, and
+/// the children are only removed once the transition or animation it starts
+/// has finished. If `when` becomes `true` again before then, the children stay
+/// where they are and get the `enter_class` back.
+///
+/// If the `leave_class` doesn’t start a transition or an animation, the
+/// children are removed right away. Set `leave_timeout` to remove them after
+/// at most that long, in case the `transitionend` or `animationend` event
+/// never fires.
+///
+/// ```css
+/// .fade-in { animation: fade-in 0.3s; }
+/// .fade-out { opacity: 0; transition: opacity 0.3s; }
+/// @keyframes fade-in { from { opacity: 0; } }
+/// ```
+///
+/// ```rust
+/// # use leptos::*;
+/// #[component]
+/// fn SavedToast(cx: Scope) -> impl IntoView {
+///   let (visible, set_visible) = create_signal(cx, true);
+///
+///   view! { cx,
+///     <button on:click=move |_| set_visible.update(|v| *v = !*v)>
+///       "Toggle"
+///     </button>
+///     <AnimatedShow
+///       when=move || visible.get()
+///       enter_class="fade-in"
+///       leave_class="fade-out"
+///     >
+///       <p>"Saved!"</p>
+///     </AnimatedShow>
+///   }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component]
+pub fn AnimatedShow<W>(
+    /// The scope the component is running in
+    cx: Scope,
+    /// The components AnimatedShow wraps
+    children: ChildrenFn,
+    /// A closure that returns a bool that determines whether the children are shown
+    when: W,
+    /// The classes the wrapper has while the children are shown.
+    #[prop(optional)]
+    enter_class: &'static str,
+    /// The classes the wrapper has while the children are being removed.
+    #[prop(optional)]
+    leave_class: &'static str,
+    /// The longest the children will wait for their leave transition or
+    /// animation to finish before they are removed.
+    #[prop(optional)]
+    leave_timeout: Option<Duration>,
+) -> impl IntoView
+where
+    W: Fn() -> bool + 'static,
+{
+    let memoized_when = create_memo(cx, move |_| when());
+    let (mounted, set_mounted) =
+        create_signal(cx, memoized_when.get_untracked());
+    let (leaving, set_leaving) = create_signal(cx, false);
+    let wrapper = create_node_ref::<html::Div>(cx);
+
+    let finish_leaving = move || {
+        if leaving.get_untracked() {
+            set_leaving.set(false);
+            set_mounted.set(false);
+        }
+    };
+
+    // bumped every time the children start to leave, so that the timeout
+    // for an earlier leave can't remove them
+    let leave_id = Rc::new(Cell::new(0_usize));
+    create_effect(cx, move |prev: Option<bool>| {
+        let show = memoized_when.get();
+        if show {
+            if leaving.get_untracked() {
+                set_classes(wrapper, leave_class, enter_class);
+                set_leaving.set(false);
+            } else if !mounted.get_untracked() {
+                set_mounted.set(true);
+            }
+        } else if prev.is_some()
+            && mounted.get_untracked()
+            && !leaving.get_untracked()
+        {
+            set_leaving.set(true);
+            let id = leave_id.get().wrapping_add(1);
+            leave_id.set(id);
+            if !set_classes(wrapper, enter_class, leave_class) {
+                finish_leaving();
+            } else if let Some(leave_timeout) = leave_timeout {
+                let leave_id = Rc::clone(&leave_id);
+                leptos_dom::helpers::set_timeout(
+                    move || {
+                        if leave_id.get() == id {
+                            finish_leaving();
+                        }
+                    },
+                    leave_timeout,
+                );
+            }
+        }
+        show
+    });
+
+    let prev_disposer = Rc::new(RefCell::new(None::<ScopeDisposer>));
+    move || {
+        if let Some(disposer) = prev_disposer.take() {
+            disposer.dispose();
+        }
+        mounted.get().then(|| {
+            let (view, disposer) = cx.run_child_scope(|cx| {
+                html::div(cx)
+                    .attr("class", enter_class)
+                    .node_ref(wrapper)
+                    .on(ev::transitionend, move |ev| {
+                        if ev.target() == ev.current_target() {
+                            finish_leaving();
+                        }
+                    })
+                    .on(ev::animationend, move |ev| {
+                        if ev.target() == ev.current_target() {
+                            finish_leaving();
+                        }
+                    })
+                    .child(children(cx))
+                    .into_view(cx)
+            });
+            *prev_disposer.borrow_mut() = Some(disposer);
+            view
+        })
+    }
+}
+
+/// Replaces the `from` classes of the wrapper with the `to` classes, and
+/// returns whether that started a transition or an animation.
+fn set_classes(
+    wrapper: NodeRef<html::Div>,
+    from: &'static str,
+    to: &'static str,
+) -> bool {
+    cfg_if! {
+        if #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        ))] {
+            let Some(el) = wrapper.get_untracked() else {
+                return false;
+            };
+            let class_list = el.class_list();
+            for class in from.split_whitespace() {
+                _ = class_list.remove_1(class);
+            }
+            for class in to.split_whitespace() {
+                _ = class_list.add_1(class);
+            }
+
+            let Ok(Some(style)) = leptos_dom::window().get_computed_style(&el)
+            else {
+                return false;
+            };
+            let property = |name| style.get_property_value(name).unwrap_or_default();
+            let is_running = |durations: String| {
+                durations.split(',').any(|duration| {
+                    let duration = duration.trim();
+                    let seconds = match duration.strip_suffix("ms") {
+                        Some(ms) => ms.parse::<f64>().map(|ms| ms / 1000.0),
+                        None => duration.trim_end_matches('s').parse::<f64>(),
+                    };
+                    seconds.map(|seconds| seconds > 0.0).unwrap_or(false)
+                })
+            };
+            is_running(property("transition-duration"))
+                || (property("animation-name") != "none"
+                    && is_running(property("animation-duration")))
+        } else {
+            _ = (wrapper, from, to);
+            false
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::Children;
+use cfg_if::cfg_if;
+use leptos_dom::{HydrationKey, IntoView};
+use leptos_macro::component;
+use leptos_reactive::{provide_context, use_context, Scope};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+/// Stores the server-rendered HTML of the fragments wrapped in [`Cached`], so
+/// that they are only rendered once and reused by later requests, until
+/// they are invalidated.
+///
+/// A cache can be shared by all the requests a server handles: create one
+/// when the server starts, keep a clone of it to invalidate fragments when
+/// their data changes, and provide it to the app with
+/// [`provide_fragment_cache`].
+///
+/// ```
+/// # use leptos::*;
+/// let cache = FragmentCache::new();
+/// # let runtime = create_runtime();
+/// # run_scope(runtime, {
+/// # let cache = cache.clone();
+/// # move |cx| {
+/// // in the app, or in the integration's `additional_context`
+/// provide_fragment_cache(cx, cache);
+/// # }});
+/// # runtime.dispose();
+///
+/// // e.g., in the server function that updates the header links
+/// cache.invalidate("header");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FragmentCache {
+    fragments: Arc<RwLock<HashMap<String, HashMap<HydrationKey, Fragment>>>>,
+}
+
+// The hydration keys in the HTML of a fragment depend on where it is in the
+// page, so it is cached separately for each position it is rendered at, along
+// with the key that comes after it, so that the rest of the page can carry on
+// from there when the HTML is reused.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")),
+    allow(dead_code)
+)]
+struct Fragment {
+    html: String,
+    end: HydrationKey,
+}
+
+impl FragmentCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the fragment with this key has been rendered since it was last
+    /// invalidated.
+    pub fn contains(&self, key: &str) -> bool {
+        self.fragments.read().unwrap().contains_key(key)
+    }
+
+    /// Removes the fragment with this key, so that it is rendered again the
+    /// next time it is needed.
+    pub fn invalidate(&self, key: &str) {
+        self.fragments.write().unwrap().remove(key);
+    }
+
+    /// Removes all the fragments.
+    pub fn clear(&self) {
+        self.fragments.write().unwrap().clear();
+    }
+
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    fn get(&self, key: &str, start: HydrationKey) -> Option<Fragment> {
+        self.fragments
+            .read()
+            .unwrap()
+            .get(key)
+            .and_then(|fragments| fragments.get(&start))
+            .cloned()
+    }
+
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    )))]
+    fn insert(&self, key: String, start: HydrationKey, fragment: Fragment) {
+        self.fragments
+            .write()
+            .unwrap()
+            .entry(key)
+            .or_default()
+            .insert(start, fragment);
+    }
+}
+
+/// Provides a [`FragmentCache`] to every [`Cached`] below this scope that
+/// isn’t given one of its own.
+pub fn provide_fragment_cache(cx: Scope, cache: FragmentCache) {
+    provide_context(cx, cache);
+}
+
+/// Renders its children on the server once, and reuses their HTML for every
+/// later request, until its `key` is invalidated in the [`FragmentCache`].
+///
+/// Use it for fragments that are expensive to render but rarely change, like
+/// a header or a product card, with a key for each different version of the
+/// fragment, e.g., `format!("product-{id}")`. The children should not read
+/// [Resources](leptos_reactive::Resource) or contain a
+/// [`Suspense`](crate::Suspense), because their HTML is rendered straight
+/// away, without waiting for anything to load. Any reactive state in them is
+/// only read the first time they are rendered.
+///
+/// In the browser, the children are rendered and hydrated as usual.
+///
+/// ```
+/// # use leptos::*;
+/// # #[component] fn ProductCard(cx: Scope, id: u32) -> impl IntoView { id }
+/// # let runtime = create_runtime();
+/// # run_scope(runtime, |cx| {
+/// # provide_fragment_cache(cx, FragmentCache::new());
+/// let id = 42;
+/// view! { cx,
+///   <Cached key=format!("product-{id}")>
+///     <ProductCard id/>
+///   </Cached>
+/// }
+/// # ;
+/// # });
+/// # runtime.dispose();
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component]
+pub fn Cached(
+    cx: Scope,
+    /// Identifies the fragment in the cache.
+    #[prop(into)]
+    key: String,
+    /// The cache to use, instead of the one provided with
+    /// [`provide_fragment_cache`].
+    #[prop(optional)]
+    cache: Option<FragmentCache>,
+    /// The fragment to cache.
+    children: Children,
+) -> impl IntoView {
+    let cache = cache.or_else(|| use_context::<FragmentCache>(cx));
+
+    cfg_if! {
+        if #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        ))] {
+            _ = (key, cache);
+            children(cx).into_view(cx)
+        } else {
+            use leptos_dom::{
+                html::{self, StringOrView},
+                HtmlElement, HydrationCtx,
+            };
+
+            let Some(cache) = cache else {
+                leptos_dom::debug_warn!(
+                    "<Cached/> was used without a FragmentCache, so {key:?} \
+                     isn't cached"
+                );
+                return children(cx).into_view(cx);
+            };
+
+            let start = HydrationCtx::peek();
+            let fragment = match cache.get(&key, start) {
+                Some(fragment) => fragment,
+                None => {
+                    let view = children(cx).into_view(cx);
+                    let fragment = Fragment {
+                        end: HydrationCtx::peek(),
+                        html: view.render_to_string(cx).into_owned(),
+                    };
+                    cache.insert(key, start, fragment.clone());
+                    fragment
+                }
+            };
+
+            // an element built from chunks is rendered as just the chunks
+            let view = HtmlElement::from_chunks(
+                cx,
+                html::Custom::new("leptos-cached"),
+                [StringOrView::String(fragment.html.into())],
+            )
+            .into_view(cx);
+            // carry on after the fragment, whether or not it was rendered
+            HydrationCtx::continue_from(fragment.end);
+            view
+        }
+    }
+}
@@ -0,0 +1,96 @@
+/// Registers a component as a [custom element](https://developer.mozilla.org/en-US/docs/Web/API/Web_components/Using_custom_elements),
+/// so that it can be used in pages that aren’t built with Leptos.
+///
+/// The props listed after the component are read from the element’s
+/// attributes, which are written in kebab case, and parsed with
+/// [`FromStr`](std::str::FromStr). A prop falls back to its default, or to
+/// [`Default::default`] if it has none, when the attribute is missing or
+/// can’t be parsed. The component is rendered again whenever one of these
+/// attributes changes.
+///
+/// If `children` is given after the props, the component’s children are a
+/// `<slot>`, which shows the children of the element.
+///
+/// The component is rendered into the shadow root of the element when it is
+/// connected to the document, and its reactive scope is disposed when it is
+/// disconnected. See [`define_custom_element`](crate::define_custom_element).
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn Counter(
+///     cx: Scope,
+///     initial_value: i32,
+///     step: i32,
+///     children: Children,
+/// ) -> impl IntoView {
+///     let (count, set_count) = create_signal(cx, initial_value);
+///     view! { cx,
+///         <button on:click=move |_| set_count.update(|n| *n += step)>
+///             {children(cx)} {move || count.get()}
+///         </button>
+///     }
+/// }
+///
+/// # if false {
+/// // <my-counter initial-value="10" step="2">"Count: "</my-counter>
+/// custom_element!(
+///     "my-counter",
+///     Counter { initial_value: i32, step: i32 = 1 },
+///     children
+/// );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! custom_element {
+    (
+        @define $name:literal,
+        $component:ident,
+        { $($prop:ident: $ty:ty $(= $default:expr)?),* $(,)? },
+        $($children:tt)*
+    ) => {
+        $crate::define_custom_element(
+            $name,
+            [$(stringify!($prop)),*],
+            |cx, _attributes| {
+                $crate::component_view(
+                    &$component,
+                    cx,
+                    $crate::component_props_builder(&$component)
+                        $(
+                            .$prop(
+                                _attributes
+                                    .parse::<$ty>(stringify!($prop))
+                                    .unwrap_or_else(|| $crate::custom_element!(
+                                        @default $($default)?
+                                    ))
+                            )
+                        )*
+                        $($children)*
+                        .build(),
+                )
+            },
+        )
+    };
+    (@default) => {
+        ::core::default::Default::default()
+    };
+    (@default $default:expr) => {
+        $default
+    };
+    ($name:literal, $component:ident $({ $($props:tt)* })? $(,)?) => {
+        $crate::custom_element!(
+            @define $name, $component, { $($($props)*)? },
+        )
+    };
+    ($name:literal, $component:ident $({ $($props:tt)* })?, children $(,)?) => {
+        $crate::custom_element!(
+            @define $name, $component, { $($($props)*)? },
+            .children(::std::boxed::Box::new(|cx| {
+                $crate::Fragment::new(::std::vec![$crate::IntoView::into_view(
+                    $crate::html::slot(cx),
+                    cx,
+                )])
+            }))
+        )
+    };
+}
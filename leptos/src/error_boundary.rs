@@ -1,9 +1,11 @@
-use crate::Children;
+use crate::ChildrenFn;
 use leptos_dom::{Errors, IntoView};
 use leptos_macro::{component, view};
 use leptos_reactive::{
-    create_rw_signal, provide_context, signal_prelude::*, RwSignal, Scope,
+    create_isomorphic_effect, create_rw_signal, provide_context,
+    signal_prelude::*, ResourceErrorCollector, RwSignal, Scope, SignalSetter,
 };
+use std::{cell::RefCell, rc::Rc};
 
 /// When you render a `Result<_, _>` in your view, in the `Err` case it will
 /// render nothing, and search up through the view tree for an `<ErrorBoundary/>`.
@@ -28,37 +30,135 @@ use leptos_reactive::{
 /// }
 /// # });
 /// ```
+///
+/// The fallback and the children can reach the nearest boundary with
+/// `use_context::<ErrorBoundaryReset>(cx)`, to clear its errors and render
+/// its children again, for example to retry after a failed request.
+///
+/// ```
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// # let (value, set_value) = create_signal(cx, Ok::<_, std::num::ParseIntError>(0));
+/// view! { cx,
+///   <ErrorBoundary
+///     fallback=|cx, _| {
+///       let reset = use_context::<ErrorBoundaryReset>(cx).unwrap();
+///       view! { cx,
+///         <button on:click=move |_: ev::MouseEvent| reset.reset()>"Try again"</button>
+///       }
+///     }
+///     on_error=SignalSetter::map(cx, |errors: Errors| {
+///       for (_, error) in errors {
+///         log!("error: {error}");
+///       }
+///     })
+///   >
+///     <p>"Value is: " {value}</p>
+///   </ErrorBoundary>
+/// }
+/// # });
+/// ```
 #[component(transparent)]
 pub fn ErrorBoundary<F, IV>(
     cx: Scope,
     /// The components inside the tag which will get rendered
-    children: Children,
+    children: ChildrenFn,
     /// A fallback that will be shown if an error occurs.
     fallback: F,
+    /// Called with all of the errors caught by the boundary whenever they
+    /// change, as long as there are any, e.g., to log them.
+    #[prop(optional)]
+    on_error: Option<SignalSetter<Errors>>,
 ) -> impl IntoView
 where
     F: Fn(Scope, RwSignal<Errors>) -> IV + 'static,
     IV: IntoView,
 {
     let errors: RwSignal<Errors> = create_rw_signal(cx, Errors::default());
+    let generation = create_rw_signal(cx, 0);
 
     provide_context(cx, errors);
+    provide_context(
+        cx,
+        ErrorBoundaryReset {
+            cx,
+            errors,
+            generation,
+        },
+    );
+    // errors from resources created with `create_fallible_resource`
+    provide_context(
+        cx,
+        ResourceErrorCollector::new(move |id, error| {
+            let key = format!("{id:?}").into();
+            errors.update(|errors| match error {
+                Some(error) => errors.insert(key, error),
+                None => _ = errors.remove(&key),
+            });
+        }),
+    );
+
+    if let Some(on_error) = on_error {
+        create_isomorphic_effect(cx, move |_| {
+            let errors = errors.get();
+            if !errors.is_empty() {
+                on_error.set(errors);
+            }
+        });
+    }
 
     // Run children so that they render and execute resources
-    let children = children(cx).into_view(cx);
+    let render_children =
+        move || cx.run_child_scope(|cx| children(cx).into_view(cx));
+    let (children, disposer) = render_children();
+    let rendered = Rc::new(RefCell::new((0, children, disposer)));
     let errors_empty = create_memo(cx, move |_| errors.with(Errors::is_empty));
 
     move || {
+        let generation = generation.get();
+        if rendered.borrow().0 != generation {
+            // the boundary was reset, so the children are rendered again
+            let (children, disposer) = render_children();
+            let (_, _, prev_disposer) = std::mem::replace(
+                &mut *rendered.borrow_mut(),
+                (generation, children, disposer),
+            );
+            prev_disposer.dispose();
+        }
+        let children = rendered.borrow().1.clone();
+
         if errors_empty.get() {
-            children.clone().into_view(cx)
+            children.into_view(cx)
         } else {
             view! { cx,
                 <>
                     {fallback(cx, errors)}
-                    <leptos-error-boundary style="display: none">{children.clone()}</leptos-error-boundary>
+                    <leptos-error-boundary style="display: none">{children}</leptos-error-boundary>
                 </>
             }
             .into_view(cx)
         }
     }
 }
+
+/// Clears the errors caught by an [`ErrorBoundary`], and renders its
+/// children again.
+///
+/// It is provided as context to the fallback and children of each boundary.
+#[derive(Clone, Copy, Debug)]
+pub struct ErrorBoundaryReset {
+    cx: Scope,
+    errors: RwSignal<Errors>,
+    generation: RwSignal<usize>,
+}
+
+impl ErrorBoundaryReset {
+    /// Clears the errors, and renders the children of the boundary again in
+    /// a new scope, disposing the old one.
+    pub fn reset(&self) {
+        self.cx.batch(|| {
+            self.errors.set(Errors::default());
+            self.generation.update(|generation| *generation += 1);
+        });
+    }
+}
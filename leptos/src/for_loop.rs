@@ -1,7 +1,16 @@
-use leptos_dom::IntoView;
+use cfg_if::cfg_if;
+#[cfg(all(
+    target_arch = "wasm32",
+    any(feature = "csr", feature = "hydrate")
+))]
+use leptos_dom::HydrationCtx;
+use leptos_dom::{helpers::request_animation_frame, EachAnimations, IntoView};
 use leptos_macro::component;
-use leptos_reactive::Scope;
-use std::hash::Hash;
+use leptos_reactive::{
+    create_effect, create_signal, Scope, SignalGet, SignalSet, SignalSetter,
+    SignalUpdate,
+};
+use std::{cell::Cell, hash::Hash, rc::Rc};
 
 /// Iterates over children and displays them, keyed by the `key` function given.
 ///
@@ -41,6 +50,99 @@ use std::hash::Hash;
 ///   }
 /// }
 /// ```
+///
+/// Instead of passing a `view` function, you can give the view for each item as
+/// the children of `<For/>`, naming the item with `let:`.
+///
+/// ```
+/// # use leptos::*;
+/// # #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// # struct Counter {
+/// #   id: usize,
+/// #   count: RwSignal<i32>
+/// # }
+/// #[component]
+/// fn Counters(cx: Scope) -> impl IntoView {
+///   let (counters, set_counters) = create_signal::<Vec<Counter>>(cx, vec![]);
+///
+///   view! {
+///     cx,
+///     <div>
+///       <For each=counters key=|counter| counter.id let:counter>
+///         <button>"Value: " {move || counter.count.get()}</button>
+///       </For>
+///     </div>
+///   }
+/// }
+/// ```
+///
+/// Rendering thousands of items at once can block the main thread for long enough
+/// to freeze the page. Setting `chunk_size` renders the items in chunks of that
+/// size, one chunk per animation frame, so that the browser can paint and respond
+/// to input in between. `on_progress` is called with the number of items rendered
+/// so far and the total after each chunk. Only the first render is split up, and
+/// only in the browser: on the server, and when hydrating, every item is rendered
+/// at once.
+///
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn Dashboard(cx: Scope, rows: Vec<usize>) -> impl IntoView {
+///   let (progress, set_progress) = create_signal(cx, (0, 0));
+///
+///   view! {
+///     cx,
+///     <progress max=move || progress.get().1 value=move || progress.get().0/>
+///     <For
+///       each=move || rows.clone()
+///       key=|row| *row
+///       chunk_size=500
+///       on_progress=set_progress.into()
+///       let:row
+///     >
+///       <p>{row}</p>
+///     </For>
+///   }
+/// }
+/// ```
+///
+/// Items are kept when the list is reordered, and only the fewest items needed
+/// are moved in the DOM. To animate items as they enter, leave or move, pass
+/// [`EachAnimations`] hooks. Each hook gets the item's element, so it only
+/// applies to items whose view is a single element.
+///
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn Todos(cx: Scope, todos: ReadSignal<Vec<(usize, String)>>) -> impl IntoView {
+///   let animations = EachAnimations::new()
+///     .on_enter(|el| {
+///       _ = el.class_list().add_1("fade-in");
+///     })
+///     .on_exit(|el, done| {
+///       _ = el.class_list().add_1("fade-out");
+///       set_timeout(done, std::time::Duration::from_millis(200));
+///     });
+///
+///   view! {
+///     cx,
+///     <ul>
+///       <For
+///         each=move || todos.get()
+///         key=|todo| todo.0
+///         animations=animations
+///         let:todo
+///       >
+///         <li>{todo.1}</li>
+///       </For>
+///     </ul>
+///   }
+/// }
+/// ```
+///
+/// To animate moves, [`animate_layout`](crate::animate_layout) measures and
+/// animates a whole list at once, or [`EachAnimations::on_move`] can be used
+/// to run a FLIP animation for each item that moved.
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     tracing::instrument(level = "info", skip_all)
@@ -53,7 +155,24 @@ pub fn For<IF, I, T, EF, N, KF, K>(
     /// A key function that will be applied to each item.
     key: KF,
     /// The view that will be displayed for each item.
-    view: EF,
+    #[prop(optional)]
+    view: Option<EF>,
+    /// The view that will be displayed for each item, given as the children of
+    /// the component, with the item bound using `let:`.
+    #[prop(optional)]
+    children: Option<EF>,
+    /// If set, the first render in the browser renders this many items per
+    /// animation frame, instead of rendering every item at once.
+    #[prop(optional)]
+    chunk_size: Option<usize>,
+    /// Called with the number of items rendered so far and the total number of
+    /// items, after each chunk is rendered when `chunk_size` is set.
+    #[prop(optional)]
+    on_progress: Option<SignalSetter<(usize, usize)>>,
+    /// Hooks that are called as items are added, removed and moved, to
+    /// animate them.
+    #[prop(optional)]
+    animations: EachAnimations,
 ) -> impl IntoView
 where
     IF: Fn() -> I + 'static,
@@ -64,5 +183,62 @@ where
     K: Eq + Hash + 'static,
     T: 'static,
 {
-    leptos_dom::Each::new(each, key, view).into_view(cx)
+    let children = children
+        .or(view)
+        .expect("<For/> needs either a `view` prop or children");
+
+    let chunk_size = chunk_size.filter(|chunk_size| {
+        cfg_if! {
+            if #[cfg(all(
+                target_arch = "wasm32",
+                any(feature = "csr", feature = "hydrate")
+            ))] {
+                *chunk_size > 0 && !HydrationCtx::is_hydrating()
+            } else {
+                _ = chunk_size;
+                false
+            }
+        }
+    });
+    let Some(chunk_size) = chunk_size else {
+        return leptos_dom::Each::new(each, key, children)
+            .animations(animations)
+            .into_view(cx);
+    };
+
+    // once every item has been rendered, `limit` is `usize::MAX`
+    let (limit, set_limit) = create_signal(cx, chunk_size);
+    let total = Rc::new(Cell::new(0));
+    let each = {
+        let total = Rc::clone(&total);
+        move || {
+            let items = each().into_iter().collect::<Vec<_>>();
+            total.set(items.len());
+            items.into_iter().take(limit.get())
+        }
+    };
+    let view = leptos_dom::Each::new(each, key, children)
+        .animations(animations)
+        .into_view(cx);
+
+    create_effect(cx, move |_| {
+        let rendered = limit.get();
+        if rendered == usize::MAX {
+            return;
+        }
+        let total = total.get();
+        if let Some(on_progress) = &on_progress {
+            on_progress.set((rendered.min(total), total));
+        }
+        if rendered < total {
+            request_animation_frame(move || {
+                _ = set_limit.try_update(|limit| *limit += chunk_size);
+            });
+        } else {
+            // later changes to `each` are rendered all at once
+            set_limit.set(usize::MAX);
+        }
+    });
+
+    view
 }
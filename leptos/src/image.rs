@@ -0,0 +1,277 @@
+use crate::{SpreadAttributes, TextProp};
+use leptos_dom::{ev, html, IntoView};
+use leptos_macro::component;
+use leptos_reactive::{
+    create_signal, provide_context, signal_prelude::*, use_context, Scope,
+};
+use std::{borrow::Cow, fmt::Write, rc::Rc};
+
+/// The widths that images are resized to when [`Image`] is given `sizes`,
+/// which cover the most common device widths.
+pub const DEFAULT_IMAGE_WIDTHS: [u32; 8] =
+    [640, 750, 828, 1080, 1200, 1920, 2048, 3840];
+
+/// The quality that images are resized with when [`Image`] is not given one.
+pub const DEFAULT_IMAGE_QUALITY: u8 = 75;
+
+/// Builds the URL of a resized copy of an image, so that [`Image`] can offer
+/// the browser a version of it for each screen size.
+///
+/// Provide one to every `<Image/>` with [`provide_image_loader`], or to a
+/// single image with its `loader` prop. Without a loader, images are not
+/// resized.
+///
+/// ```
+/// # use leptos::*;
+/// // an image CDN
+/// let cdn = ImageLoader::new(|src, width, quality| {
+///     format!("https://cdn.example.com{src}?width={width}&quality={quality}")
+/// });
+/// assert_eq!(
+///     cdn.url("/hero.jpg", 640, 75),
+///     "https://cdn.example.com/hero.jpg?width=640&quality=75"
+/// );
+///
+/// // an image-resizing endpoint on your own server
+/// let endpoint = ImageLoader::endpoint("/_image");
+/// assert_eq!(
+///     endpoint.url("/hero.jpg", 640, 75),
+///     "/_image?url=%2Fhero.jpg&w=640&q=75"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct ImageLoader(Rc<dyn Fn(&str, u32, u8) -> String>);
+
+impl ImageLoader {
+    /// Creates a loader from a function that takes the URL of the original
+    /// image, the width to resize it to, and the quality to encode it with.
+    pub fn new(loader: impl Fn(&str, u32, u8) -> String + 'static) -> Self {
+        Self(Rc::new(loader))
+    }
+
+    /// Creates a loader for an image-resizing endpoint, like one added to the
+    /// server by an integration, that takes the URL of the original image as
+    /// `url`, the width as `w` and the quality as `q` in the query string.
+    pub fn endpoint(path: impl Into<Cow<'static, str>>) -> Self {
+        let path = path.into();
+        Self::new(move |src, width, quality| {
+            format!("{path}?url={}&w={width}&q={quality}", encode(src))
+        })
+    }
+
+    /// Returns the URL of `src`, resized to `width` pixels wide.
+    pub fn url(&self, src: &str, width: u32, quality: u8) -> String {
+        (self.0)(src, width, quality)
+    }
+}
+
+impl std::fmt::Debug for ImageLoader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ImageLoader").finish()
+    }
+}
+
+/// Provides an [`ImageLoader`] to every [`Image`] beneath this scope.
+pub fn provide_image_loader(cx: Scope, loader: ImageLoader) {
+    provide_context(cx, loader);
+}
+
+/// An `<img>` that only loads what it needs to.
+///
+/// - With an [`ImageLoader`], it generates a `srcset` so that the browser
+///   picks an image no larger than it will show. If it is given `sizes`, that
+///   is one image for each of the `widths`; otherwise, images at one and two
+///   times its `width`, for high-density screens.
+/// - Unless it is a `priority` image, it isn’t loaded until it is close to
+///   being scrolled into view, with an `IntersectionObserver` in the browser
+///   and `loading="lazy"` in server-rendered HTML.
+/// - Its `width` and `height` reserve space for it in the layout while it
+///   loads, so that the page doesn’t shift around it. A `placeholder`, like a
+///   small blurred version of the image as a data URL, can be shown in that
+///   space until it has loaded.
+///
+/// Any other attributes can be given with `attrs`.
+///
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn Hero(cx: Scope) -> impl IntoView {
+///     provide_image_loader(cx, ImageLoader::endpoint("/_image"));
+///
+///     view! { cx,
+///         <Image
+///             src="/images/hero.jpg"
+///             alt="A crab on a beach"
+///             width=1200
+///             height=600
+///             sizes="100vw"
+///             priority=true
+///         />
+///         <Image
+///             src="/images/avatar.png"
+///             alt="Ferris"
+///             width=64
+///             height=64
+///             attrs=[("class", "avatar")]
+///         />
+///     }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component]
+pub fn Image(
+    cx: Scope,
+    /// The URL of the original image.
+    #[prop(into)]
+    src: String,
+    /// A description of the image, for screen readers and for when it fails
+    /// to load. Use an empty string for purely decorative images.
+    #[prop(into)]
+    alt: TextProp,
+    /// The width the image is shown at, in CSS pixels.
+    width: u32,
+    /// The height the image is shown at, in CSS pixels.
+    height: u32,
+    /// The width the image will be shown at for different screen sizes, like
+    /// `(max-width: 768px) 100vw, 50vw`, which lets the browser choose from
+    /// the `widths`.
+    #[prop(optional, into)]
+    sizes: Option<String>,
+    /// The widths to resize the image to when it has `sizes`. Defaults to
+    /// [`DEFAULT_IMAGE_WIDTHS`].
+    #[prop(optional)]
+    widths: Option<Vec<u32>>,
+    /// The quality to resize the image with, from 1 to 100. Defaults to
+    /// [`DEFAULT_IMAGE_QUALITY`].
+    #[prop(optional)]
+    quality: Option<u8>,
+    /// Loads the image straight away, with a high priority. Use this for
+    /// large images that are visible when the page loads.
+    #[prop(optional)]
+    priority: bool,
+    /// A CSS `background-image` value, like `url(data:image/jpeg;base64,…)`,
+    /// that is shown until the image has loaded.
+    #[prop(optional, into)]
+    placeholder: Option<String>,
+    /// Builds the URLs of the resized images, instead of the one provided
+    /// with [`provide_image_loader`].
+    #[prop(optional)]
+    loader: Option<ImageLoader>,
+    /// Any other attributes to add to the `<img>`.
+    #[prop(optional, into)]
+    attrs: SpreadAttributes,
+) -> impl IntoView {
+    let loader = loader.or_else(|| use_context::<ImageLoader>(cx));
+    let quality = quality.unwrap_or(DEFAULT_IMAGE_QUALITY);
+    let widths = widths.as_deref().unwrap_or(&DEFAULT_IMAGE_WIDTHS);
+    let (src, srcset) = match &loader {
+        Some(loader) => {
+            let (src, srcset) = image_srcset(
+                loader,
+                &src,
+                width,
+                sizes.is_some().then_some(widths),
+                quality,
+            );
+            (src, Some(srcset))
+        }
+        None => (src, None),
+    };
+
+    let (loaded, set_loaded) = create_signal(cx, false);
+    let style = move || {
+        let mut style = format!("aspect-ratio: {width} / {height};");
+        if let Some(placeholder) = &placeholder {
+            if !loaded.get() {
+                _ = write!(
+                    style,
+                    " background-image: {placeholder}; background-size: \
+                     cover; background-position: center;"
+                );
+            }
+        }
+        style
+    };
+
+    let img = html::img(cx)
+        .attr("alt", move || alt.get())
+        .attr("width", width)
+        .attr("height", height)
+        .attr("sizes", sizes)
+        .attr("decoding", "async")
+        .attr("loading", (!priority).then_some("lazy"))
+        .attr("fetchpriority", priority.then_some("high"))
+        .attr("style", style)
+        .on(ev::load, move |_| set_loaded.set(true))
+        .spread(attrs);
+
+    // an image that was rendered on the server is already loading lazily,
+    // so only images created in the browser need to wait until they are
+    // visible before they start to load
+    #[cfg(all(
+        target_arch = "wasm32",
+        any(feature = "csr", feature = "hydrate")
+    ))]
+    if !priority && !leptos_dom::HydrationCtx::is_hydrating() {
+        let el = img.clone();
+        leptos_dom::helpers::when_visible(cx, &img, "200px", move || {
+            if let Some(srcset) = &srcset {
+                el.set_srcset(srcset);
+            }
+            el.set_src(&src);
+        });
+        return img;
+    }
+
+    img.attr("srcset", srcset).attr("src", src)
+}
+
+/// Returns the `src` and `srcset` of an image resized by `loader`: one image
+/// for each of the `widths` if there are any, or at one and two times
+/// `width` if there aren’t.
+fn image_srcset(
+    loader: &ImageLoader,
+    src: &str,
+    width: u32,
+    widths: Option<&[u32]>,
+    quality: u8,
+) -> (String, String) {
+    match widths {
+        Some(widths) => {
+            let srcset = widths
+                .iter()
+                .map(|w| format!("{} {w}w", loader.url(src, *w, quality)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let largest = widths.iter().copied().max().unwrap_or(width);
+            (loader.url(src, largest, quality), srcset)
+        }
+        None => {
+            let srcset = [1, 2]
+                .iter()
+                .map(|density| {
+                    let url = loader.url(src, width * density, quality);
+                    format!("{url} {density}x")
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            (loader.url(src, width, quality), srcset)
+        }
+    }
+}
+
+// percent-encodes everything but unreserved characters, for a query string
+fn encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+    encoded
+}
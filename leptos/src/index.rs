@@ -0,0 +1,107 @@
+use leptos_dom::IntoView;
+use leptos_macro::component;
+use leptos_reactive::{
+    create_signal, ReadSignal, Scope, SignalSet, WriteSignal,
+};
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+/// Iterates over children and displays them, keyed by their position in the
+/// list.
+///
+/// Unlike [`For`](crate::For), `<Index/>` does not need a key function: the
+/// view for each position is created once and given a signal that holds the
+/// item at that position. When the list changes, the signals are updated in
+/// place, and views are only created or removed when the list grows or
+/// shrinks. This is the better choice for lists whose items change more often
+/// than they are reordered, like the rows of a table that is being edited.
+///
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn Scores(cx: Scope) -> impl IntoView {
+///   let (scores, set_scores) = create_signal(cx, vec![3, 5, 8]);
+///
+///   view! {
+///     cx,
+///     <button on:click=move |_| set_scores.update(|scores| scores[0] += 1)>
+///       "Increment first"
+///     </button>
+///     <ul>
+///       <Index each=move || scores.get() let:score>
+///         // only this text node is updated when the score changes
+///         <li>{move || score.get()}</li>
+///       </Index>
+///     </ul>
+///   }
+/// }
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component(transparent)]
+pub fn Index<IF, I, T, EF, N>(
+    cx: Scope,
+    /// Items over which the component should iterate.
+    each: IF,
+    /// The view that will be displayed for each position, given a signal that
+    /// holds the item at that position.
+    #[prop(optional)]
+    view: Option<EF>,
+    /// The view that will be displayed for each position, given as the
+    /// children of the component, with the item's signal bound using `let:`.
+    #[prop(optional)]
+    children: Option<EF>,
+) -> impl IntoView
+where
+    IF: Fn() -> I + 'static,
+    I: IntoIterator<Item = T>,
+    EF: Fn(Scope, ReadSignal<T>) -> N + 'static,
+    N: IntoView,
+    T: 'static,
+{
+    let children = children
+        .or(view)
+        .expect("<Index/> needs either a `view` prop or children");
+
+    // the setter for the item at each position that has a view
+    let rows = Rc::new(RefCell::new(Vec::<WriteSignal<T>>::new()));
+    // the items for positions that don't have a view yet, in order
+    let added = Rc::new(RefCell::new(VecDeque::new()));
+
+    let positions = {
+        let rows = Rc::clone(&rows);
+        let added = Rc::clone(&added);
+        move || {
+            let mut rows = rows.borrow_mut();
+            let mut added = added.borrow_mut();
+            added.clear();
+
+            let mut len = 0;
+            for item in each() {
+                match rows.get(len) {
+                    Some(row) => row.set(item),
+                    None => added.push_back(item),
+                }
+                len += 1;
+            }
+            // the views for the removed positions are disposed by `Each`
+            rows.truncate(len);
+            0..len
+        }
+    };
+
+    // `Each` creates the views for new positions in order, after the ones
+    // that are kept
+    let view = move |cx, _| {
+        let item = added
+            .borrow_mut()
+            .pop_front()
+            .expect("<Index/> to have an item for each new position");
+        let (item, set_item) = create_signal(cx, item);
+        rows.borrow_mut().push(set_item);
+        children(cx, item)
+    };
+
+    leptos_dom::Each::new(positions, |position| *position, view).into_view(cx)
+}
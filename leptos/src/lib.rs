@@ -96,9 +96,15 @@
 //!   from the server to the client.
 //! - `miniserde` In SSR/hydrate mode, uses [miniserde](https://docs.rs/miniserde/latest/miniserde/) to serialize resources and send them
 //!   from the server to the client.
+//! - `tracing` Emits [tracing](https://docs.rs/tracing/latest/tracing/) spans for components, signal writes,
+//!   effect and memo runs, and resource loads in release builds, as debug builds already do. Spans include where each signal,
+//!   effect or memo was defined, so you can find out which one is running more often than expected.
 //!
 //! **Important Note:** You must enable one of `csr`, `hydrate`, or `ssr` to tell Leptos
 //! which mode your app is operating in.
+//! Only the code needed for that mode is compiled: a `csr` build contains none of the code
+//! that hydrates server-rendered HTML, and an `ssr` build contains none of the code that
+//! creates or updates DOM nodes.
 //!
 //! # A Simple Counter
 //!
@@ -147,8 +153,13 @@
 
 mod additional_attributes;
 pub use additional_attributes::*;
+mod animated_show;
+pub use animated_show::*;
 mod await_;
 pub use await_::*;
+mod cached;
+pub use cached::*;
+mod custom_element;
 pub use leptos_config::{self, get_configuration, LeptosOptions};
 #[cfg(not(all(
     target_arch = "wasm32",
@@ -159,21 +170,30 @@ pub mod ssr {
     pub use leptos_dom::{ssr::*, ssr_in_order::*};
 }
 pub use leptos_dom::{
-    self, create_node_ref, debug_warn, document, error, ev,
+    self, create_node_ref, debug_warn, define_custom_element, document, em,
+    error, ev,
     helpers::{
-        event_target, event_target_checked, event_target_value,
+        animate_layout, event_target, event_target_checked, event_target_value,
         request_animation_frame, request_animation_frame_with_handle,
         request_idle_callback, request_idle_callback_with_handle, set_interval,
         set_interval_with_handle, set_timeout, set_timeout_with_handle,
-        window_event_listener, window_event_listener_untyped,
+        when_visible, window_event_listener, window_event_listener_untyped,
     },
-    html, log, math, mount_to, mount_to_body, svg, warn, window, Attribute,
-    Class, CollectView, Errors, Fragment, HtmlElement, IntoAttribute,
-    IntoClass, IntoProperty, IntoStyle, IntoView, NodeRef, Property, View,
+    html,
+    intl::{
+        self, provide_locale, use_date_format, use_locale, use_number_format,
+    },
+    log, math, mount_to, mount_to_body, percent, px, rem, set_effect_flush,
+    set_html_sanitizer, svg, vh, vw, warn, window, Attribute, Class, ClassList,
+    CollectView, CustomElementAttributes, EachAnimations, EffectFlush, Errors,
+    Fragment, HtmlElement, HtmlSanitizer, IntoAttribute, IntoClass,
+    IntoClassAttribute, IntoProperty, IntoStyle, IntoStyleAttribute, IntoView,
+    Length, LiquidMount, NodeRef, Property, ShadowRoot, ShadowRootMode,
+    SpreadAttributes, Style, StyleList, Transparent, View,
 };
 #[cfg(not(any(target_arch = "wasm32", feature = "template_macro")))]
 pub use leptos_macro::view as template;
-pub use leptos_macro::{component, server, slot, view, Params};
+pub use leptos_macro::{component, island, server, slot, view, Params};
 pub use leptos_reactive::*;
 pub use leptos_server::{
     self, create_action, create_multi_action, create_server_action,
@@ -186,14 +206,22 @@ pub use {leptos_macro::template, wasm_bindgen, web_sys};
 mod error_boundary;
 pub use error_boundary::*;
 mod for_loop;
+mod image;
+mod index;
 mod show;
 pub use for_loop::*;
+pub use image::*;
+pub use index::*;
 pub use show::*;
 mod suspense_component;
 pub use suspense_component::*;
+mod switch;
+pub use switch::*;
 mod text_prop;
+mod theme;
 mod transition;
 pub use text_prop::TextProp;
+pub use theme::*;
 #[cfg(any(debug_assertions, feature = "ssr"))]
 #[doc(hidden)]
 pub use tracing;
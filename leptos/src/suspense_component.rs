@@ -1,7 +1,9 @@
-use leptos_dom::{DynChild, HydrationCtx, IntoView};
+use leptos_dom::{DynChild, HydrationCtx, IntoView, View};
 use leptos_macro::component;
 use leptos_reactive::{provide_context, Scope, SuspenseContext};
 use std::rc::Rc;
+#[cfg(any(feature = "csr", feature = "hydrate"))]
+use std::{cell::Cell, time::Duration};
 
 /// If any [Resources](leptos_reactive::Resource) are read in the `children` of this
 /// component, it will show the `fallback` while they are loading. Once all are resolved,
@@ -11,6 +13,15 @@ use std::rc::Rc;
 /// those resources are read under the suspense), so you cannot assume that resources have
 /// `Some` value in `children`.
 ///
+/// If resources begin loading during a transition started with
+/// [`start_transition`](leptos_reactive::start_transition), a `Suspense` that has already
+/// shown its `children` will keep showing them until the transition is no longer pending.
+///
+/// In the browser, `delay_ms` avoids a flash of the fallback for resources that load
+/// quickly: nothing new is shown until they have been loading for that long. If they are
+/// still loading after `timeout_ms`, the `timeout_fallback` is shown instead of the
+/// `fallback`, e.g., to say that loading is taking longer than usual.
+///
 /// ```
 /// # use leptos_reactive::*;
 /// # use leptos_macro::*;
@@ -57,6 +68,15 @@ pub fn Suspense<F, E, V>(
     fallback: F,
     /// Children will be displayed once all `async` [Resources](leptos_reactive::Resource) have resolved.
     children: Box<dyn Fn(Scope) -> V>,
+    /// How long resources can load in the browser before the fallback is shown.
+    #[prop(optional)]
+    delay_ms: Option<u64>,
+    /// How long resources can load in the browser before the `timeout_fallback` is shown.
+    #[prop(optional)]
+    timeout_ms: Option<u64>,
+    /// Replaces the `fallback` once resources have been loading for `timeout_ms`.
+    #[prop(optional)]
+    timeout_fallback: Option<Box<dyn Fn() -> View>>,
 ) -> impl IntoView
 where
     F: Fn() -> E + 'static,
@@ -76,15 +96,39 @@ where
         let current_id = current_id.clone();
 
         let children = Rc::new(orig_children(cx).into_view(cx));
+        #[cfg(any(feature = "csr", feature = "hydrate"))]
+        let shown = Cell::new(false);
+        #[cfg(any(feature = "csr", feature = "hydrate"))]
+        let timer = LoadingTimer::new(cx, delay_ms, timeout_ms);
+        #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+        let _ = (delay_ms, timeout_ms, &timeout_fallback);
         #[cfg(not(any(feature = "csr", feature = "hydrate")))]
         let orig_children = Rc::clone(&orig_children);
         move || {
             #[cfg(any(feature = "csr", feature = "hydrate"))]
             {
-                if context.ready() {
+                // during a transition, keep showing the stale children
+                // rather than falling back, once they have been shown
+                if context.ready()
+                    || (shown.get()
+                        && leptos_reactive::pending_transition(cx).is_some())
+                {
+                    timer.stop();
+                    shown.set(true);
                     (*children).clone()
                 } else {
-                    fallback().into_view(cx)
+                    match timer.phase() {
+                        // keep showing whatever was there before
+                        LoadingPhase::Delayed if shown.get() => {
+                            (*children).clone()
+                        }
+                        LoadingPhase::Delayed => ().into_view(cx),
+                        LoadingPhase::Fallback => fallback().into_view(cx),
+                        LoadingPhase::TimedOut => match &timeout_fallback {
+                            Some(timeout_fallback) => timeout_fallback(),
+                            None => fallback().into_view(cx),
+                        },
+                    }
                 }
             }
             #[cfg(not(any(feature = "csr", feature = "hydrate")))]
@@ -166,3 +210,92 @@ where
 
     leptos_dom::View::Suspense(current_id, core_component)
 }
+
+#[cfg(any(feature = "csr", feature = "hydrate"))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LoadingPhase {
+    /// Resources have been loading for less than `delay_ms`.
+    Delayed,
+    Fallback,
+    /// Resources have been loading for longer than `timeout_ms`.
+    TimedOut,
+}
+
+/// Keeps track of how long the resources under a `Suspense` have been loading.
+#[cfg(any(feature = "csr", feature = "hydrate"))]
+struct LoadingTimer {
+    delay: Option<Duration>,
+    timeout: Option<Duration>,
+    loading: Cell<bool>,
+    phase: Rc<Cell<LoadingPhase>>,
+    // incremented whenever loading starts or stops, so that the timeouts
+    // from earlier loads can be ignored
+    generation: Rc<Cell<usize>>,
+    trigger: leptos_reactive::Trigger,
+}
+
+#[cfg(any(feature = "csr", feature = "hydrate"))]
+impl LoadingTimer {
+    fn new(cx: Scope, delay_ms: Option<u64>, timeout_ms: Option<u64>) -> Self {
+        Self {
+            delay: delay_ms.map(Duration::from_millis),
+            timeout: timeout_ms.map(Duration::from_millis),
+            loading: Cell::new(false),
+            phase: Rc::new(Cell::new(LoadingPhase::Fallback)),
+            generation: Rc::new(Cell::new(0)),
+            trigger: leptos_reactive::create_trigger(cx),
+        }
+    }
+
+    /// Returns how long resources have been loading, starting the timers if
+    /// they have just started loading.
+    fn phase(&self) -> LoadingPhase {
+        self.trigger.track();
+        if !self.loading.replace(true) {
+            let generation = self.generation.get() + 1;
+            self.generation.set(generation);
+            // the server-rendered fallback is already showing
+            #[cfg(target_arch = "wasm32")]
+            let hydrating = HydrationCtx::is_hydrating();
+            #[cfg(not(target_arch = "wasm32"))]
+            let hydrating = false;
+            let phase = if self.delay.is_some() && !hydrating {
+                LoadingPhase::Delayed
+            } else {
+                LoadingPhase::Fallback
+            };
+            self.phase.set(phase);
+
+            for (duration, phase) in [
+                (self.delay, LoadingPhase::Fallback),
+                (self.timeout, LoadingPhase::TimedOut),
+            ] {
+                let Some(duration) = duration else {
+                    continue;
+                };
+                let current_phase = Rc::clone(&self.phase);
+                let current_generation = Rc::clone(&self.generation);
+                let trigger = self.trigger;
+                leptos_dom::helpers::set_timeout(
+                    move || {
+                        let expired = current_generation.get() == generation
+                            && current_phase.get() != LoadingPhase::TimedOut;
+                        if expired {
+                            current_phase.set(phase);
+                            trigger.notify();
+                        }
+                    },
+                    duration,
+                );
+            }
+        }
+        self.phase.get()
+    }
+
+    /// Stops the timers, once the resources have loaded.
+    fn stop(&self) {
+        if self.loading.replace(false) {
+            self.generation.set(self.generation.get() + 1);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::ChildrenFn;
+use leptos::{component, slot};
+use leptos_dom::IntoView;
+use leptos_reactive::{
+    create_memo, signal_prelude::*, MaybeSignal, Scope, ScopeDisposer,
+};
+use std::{cell::RefCell, rc::Rc};
+
+/// A branch of a [`Switch`], which is rendered if `when` is `true` and no
+/// earlier branch matches.
+#[slot]
+pub struct Match {
+    /// Whether this branch matches.
+    #[prop(into)]
+    when: MaybeSignal<bool>,
+    /// What is rendered while this branch is the first that matches.
+    children: ChildrenFn,
+}
+
+/// A component that renders the first of its [`Match`] branches whose `when`
+/// condition is `true`, or the fallback if none of them are.
+///
+/// Like [`Show`](crate::Show), it only renders again when a different branch
+/// matches. Each branch is rendered in its own reactive scope, which is
+/// disposed when another branch starts to match.
+///
+/// ```rust
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// let (count, set_count) = create_signal(cx, 0);
+/// let is_zero = create_memo(cx, move |_| count.get() == 0);
+/// let is_small = create_memo(cx, move |_| count.get() < 10);
+///
+/// view! { cx,
+///   <Switch fallback=|cx| view! { cx, "Big number!" }>
+///     <Match slot when=is_zero>"Zero"</Match>
+///     <Match slot when=is_small>"Small number"</Match>
+///   </Switch>
+/// }
+/// # });
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component]
+pub fn Switch<F, IV>(
+    /// The scope the component is running in
+    cx: Scope,
+    /// The branches to choose from, in order
+    #[prop(default = vec![])]
+    r#match: Vec<Match>,
+    /// A closure that returns what gets rendered if no branch matches
+    fallback: F,
+) -> impl IntoView
+where
+    F: Fn(Scope) -> IV + 'static,
+    IV: IntoView,
+{
+    let branches = r#match;
+    let matched = create_memo(cx, {
+        let whens = branches
+            .iter()
+            .map(|branch| branch.when)
+            .collect::<Vec<_>>();
+        move |_| whens.iter().position(|when| when.get())
+    });
+    let prev_disposer = Rc::new(RefCell::new(None::<ScopeDisposer>));
+
+    move || {
+        if let Some(disposer) = prev_disposer.take() {
+            disposer.dispose();
+        }
+        let (view, disposer) = cx.run_child_scope(|cx| match matched.get() {
+            Some(index) => (branches[index].children)(cx).into_view(cx),
+            None => fallback(cx).into_view(cx),
+        });
+        *prev_disposer.borrow_mut() = Some(disposer);
+        view
+    }
+}
@@ -0,0 +1,149 @@
+use crate::Children;
+use leptos_dom::{
+    html::{custom, Custom},
+    IntoView,
+};
+use leptos_macro::component;
+use leptos_reactive::{
+    create_memo, provide_context, signal_prelude::*, use_context, Scope, Signal,
+};
+use std::{borrow::Cow, fmt::Write};
+
+/// A typed theme for a component library or design system, which can be provided
+/// to every component beneath a [`ThemeProvider`] and read with [`use_theme`].
+///
+/// The theme is also emitted as a set of
+/// [CSS custom properties](https://developer.mozilla.org/en-US/docs/Web/CSS/Using_CSS_custom_properties),
+/// so that stylesheets can use it without any Rust code at all.
+///
+/// ```
+/// # use leptos::*;
+/// # use std::borrow::Cow;
+/// #[derive(Clone, PartialEq)]
+/// struct Palette {
+///     primary: String,
+///     spacing: u32,
+/// }
+///
+/// impl Theme for Palette {
+///     fn css_variables(&self) -> Vec<(Cow<'static, str>, String)> {
+///         vec![
+///             ("primary".into(), self.primary.clone()),
+///             ("spacing".into(), format!("{}px", self.spacing)),
+///         ]
+///     }
+/// }
+/// ```
+pub trait Theme: Clone + PartialEq + 'static {
+    /// The CSS custom properties that describe this theme, as pairs of names
+    /// (without the leading `--`) and values.
+    fn css_variables(&self) -> Vec<(Cow<'static, str>, String)>;
+}
+
+/// Provides a [`Theme`] to its children, and sets each of its
+/// [`css_variables`](Theme::css_variables) as a CSS custom property on a
+/// `<leptos-theme>` element that wraps them.
+///
+/// The wrapper is styled with `display: contents`, so that it does not affect
+/// the layout of the children. The theme is given as a function, so it can
+/// depend on signals: the custom properties, and the theme returned by
+/// [`use_theme`], are updated whenever it changes. A `<ThemeProvider/>` nested
+/// inside another overrides the outer theme for its own children.
+///
+/// ```
+/// # use leptos::*;
+/// # use std::borrow::Cow;
+/// # #[derive(Clone, PartialEq)]
+/// # struct Palette { primary: String }
+/// # impl Theme for Palette {
+/// #     fn css_variables(&self) -> Vec<(Cow<'static, str>, String)> {
+/// #         vec![("primary".into(), self.primary.clone())]
+/// #     }
+/// # }
+/// # run_scope(create_runtime(), |cx| {
+/// let (palette, set_palette) = create_signal(
+///     cx,
+///     Palette {
+///         primary: "rebeccapurple".to_string(),
+///     },
+/// );
+///
+/// #[component]
+/// fn Button(cx: Scope) -> impl IntoView {
+///     // components can read the theme directly...
+///     let palette = use_theme::<Palette>(cx);
+///     view! { cx,
+///         // ...or use the custom properties in CSS
+///         <button style="color: var(--primary)">
+///             {move || palette.with(|palette| palette.primary.clone())}
+///         </button>
+///     }
+/// }
+///
+/// view! { cx,
+///     <ThemeProvider theme=move || palette.get()>
+///         <Button/>
+///     </ThemeProvider>
+/// }
+/// # ;
+/// # });
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all)
+)]
+#[component]
+pub fn ThemeProvider<T, F>(
+    cx: Scope,
+    /// Returns the theme. This is rerun whenever any signals it reads change.
+    theme: F,
+    /// The components that can use the theme.
+    children: Children,
+) -> impl IntoView
+where
+    T: Theme,
+    F: Fn() -> T + 'static,
+{
+    let theme = create_memo(cx, move |_| theme());
+    provide_context(cx, ThemeContext(theme.into()));
+
+    let style = move || {
+        theme.with(|theme| {
+            let mut style = String::from("display: contents;");
+            for (name, value) in theme.css_variables() {
+                _ = write!(style, " --{name}: {value};");
+            }
+            style
+        })
+    };
+
+    custom(cx, Custom::new("leptos-theme"))
+        .attr("style", style)
+        .child(children(cx))
+}
+
+/// Returns the [`Theme`] of type `T` provided by the nearest [`ThemeProvider`]
+/// above this component, or `None` if there isn't one.
+pub fn try_use_theme<T: Theme>(cx: Scope) -> Option<Signal<T>> {
+    use_context::<ThemeContext<T>>(cx).map(|theme| theme.0)
+}
+
+/// Returns the [`Theme`] of type `T` provided by the nearest [`ThemeProvider`]
+/// above this component.
+///
+/// ## Panics
+/// Panics if there is no [`ThemeProvider`] for a `T` above this component.
+/// Use [`try_use_theme`] if the theme is optional.
+#[track_caller]
+pub fn use_theme<T: Theme>(cx: Scope) -> Signal<T> {
+    try_use_theme(cx).unwrap_or_else(|| {
+        panic!(
+            "use_theme::<{}>() was called without a <ThemeProvider/> for that \
+             theme above it",
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+#[derive(Clone)]
+struct ThemeContext<T: 'static>(Signal<T>);
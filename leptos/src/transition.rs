@@ -15,6 +15,10 @@ use std::{
 /// those resources are read under the suspense), so you cannot assume that resources have
 /// `Some` value in `children`.
 ///
+/// Like [`Suspense`](crate::Suspense), it can wait for `delay_ms` before showing the
+/// `fallback`, and show the `timeout_fallback` after `timeout_ms`, while loading for the
+/// first time.
+///
 /// ```
 /// # use leptos_reactive::*;
 /// # use leptos_macro::*;
@@ -73,6 +77,15 @@ pub fn Transition<F, E>(
     set_pending: Option<SignalSetter<bool>>,
     /// Will be displayed once all resources have resolved.
     children: Box<dyn Fn(Scope) -> Fragment>,
+    /// How long resources can load in the browser before the fallback is shown.
+    #[prop(optional)]
+    delay_ms: Option<u64>,
+    /// How long resources can load in the browser before the `timeout_fallback` is shown.
+    #[prop(optional)]
+    timeout_ms: Option<u64>,
+    /// Replaces the `fallback` once resources have been loading for `timeout_ms`.
+    #[prop(optional)]
+    timeout_fallback: Option<Box<dyn Fn() -> View>>,
 ) -> impl IntoView
 where
     F: Fn() -> E + 'static,
@@ -85,8 +98,8 @@ where
 
     crate::Suspense(
         cx,
-        crate::SuspenseProps::builder()
-            .fallback({
+        crate::SuspenseProps {
+            fallback: {
                 let prev_child = Rc::clone(&prev_children);
                 let first_run = Rc::clone(&first_run);
                 move || {
@@ -110,8 +123,8 @@ where
                         fallback().into_view(cx)
                     }
                 }
-            })
-            .children(Box::new(move |cx| {
+            },
+            children: Box::new(move |cx| {
                 let frag = children(cx).into_view(cx);
 
                 let suspense_context = use_context::<SuspenseContext>(cx)
@@ -136,8 +149,11 @@ where
                     set_pending.set(false);
                 }
                 frag
-            }))
-            .build(),
+            }),
+            delay_ms,
+            timeout_ms,
+            timeout_fallback,
+        },
     )
 }
 
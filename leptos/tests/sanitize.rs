@@ -0,0 +1,42 @@
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn inner_html_is_sanitized() {
+    use leptos::*;
+    use std::borrow::Cow;
+
+    // the sanitizer is shared by the whole process, so it is only set here
+    set_html_sanitizer(|html: Cow<'static, str>| -> Cow<'static, str> {
+        html.replace("<script>", "").replace("</script>", "").into()
+    });
+
+    _ = create_scope(create_runtime(), |cx| {
+        let comment = "<script>alert(1)</script><b>hi</b>";
+        let rendered = view! {
+            cx,
+            <div inner_html=comment></div>
+            <div dangerously_set_inner_html="<script>init()</script>"></div>
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert!(rendered.contains(r#"<div id="_0-2">alert(1)<b>hi</b></div>"#));
+        assert!(rendered
+            .contains(r#"<div id="_0-3"><script>init()</script></div>"#));
+
+        let rendered = html::p(cx)
+            .inner_html(comment)
+            .into_view(cx)
+            .render_to_string(cx);
+        assert!(rendered.contains(">alert(1)<b>hi</b></p>"));
+
+        let rendered = html::p(cx)
+            .attr("inner_html", comment)
+            .into_view(cx)
+            .render_to_string(cx);
+        assert!(rendered.contains(">alert(1)<b>hi</b></p>"));
+
+        let rendered = html::p(cx)
+            .dangerously_set_inner_html(comment)
+            .into_view(cx)
+            .render_to_string(cx);
+        assert!(rendered.contains(comment));
+    });
+}
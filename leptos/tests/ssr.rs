@@ -117,6 +117,37 @@ fn test_classes() {
     });
 }
 
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_class_list() {
+    use leptos::*;
+    use std::collections::HashMap;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let (value, _set_value) = create_signal(cx, 5);
+        let classes = ClassList::new()
+            .add("big", move || value.get() > 10)
+            .add("red", true)
+            .add("car", move || value.get() > 1);
+        let rendered = view! {
+            cx,
+            <div class=classes class:blue=true></div>
+            <p class=HashMap::from([("small", value.get() < 10)])></p>
+            <span class=move || "plain"></span>
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert!(rendered.contains(r#"<div id="_0-2" class=" red car blue">"#));
+        assert!(rendered.contains(r#"<p id="_0-3" class=" small">"#));
+        assert!(rendered.contains(r#"<span id="_0-4" class=" plain">"#));
+
+        let rendered = html::div(cx)
+            .toggle_classes([("red", true), ("big", false)])
+            .into_view(cx)
+            .render_to_string(cx);
+        assert!(rendered.contains(r#"class="red""#));
+    });
+}
+
 #[cfg(not(any(feature = "csr", feature = "hydrate")))]
 #[test]
 fn ssr_with_styles() {
@@ -139,6 +170,37 @@ fn ssr_with_styles() {
     });
 }
 
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_style_list() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let (width, _) = create_signal(cx, 200);
+        let styles = StyleList::new()
+            .width(move || px(width.get()))
+            .height(rem(1.5))
+            .opacity(0.5)
+            .set("--accent", None::<String>);
+        let rendered = view! {
+            cx,
+            <div style=styles style:color="red"></div>
+            <p style=move || "margin: 0"></p>
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert!(rendered.contains(
+            r#"<div id="_0-2" style=" width: 200px; height: 1.5rem; opacity: 0.5; color: red;">"#
+        ));
+        assert!(rendered.contains(r#"<p id="_0-3" style=" margin: 0;">"#));
+
+        let rendered = html::div(cx)
+            .styles([("top", percent(50)), ("left", vw(10))])
+            .into_view(cx)
+            .render_to_string(cx);
+        assert!(rendered.contains(r#"style="top: 50%; left: 10vw;""#));
+    });
+}
+
 #[cfg(not(any(feature = "csr", feature = "hydrate")))]
 #[test]
 fn ssr_option() {
@@ -157,3 +219,1026 @@ fn ssr_option() {
             .contains("<option id=\"_0-1\"></option>"));
     });
 }
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_merges_adjacent_text() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let (name, _) = create_signal(cx, "Alice");
+        let (count, _) = create_signal(cx, 3);
+        let rendered = view! {
+            cx,
+            <p>"Hello " {move || name.get()} ", you have " {move || count.get()} " items"</p>
+        };
+
+        assert!(rendered.into_view(cx).render_to_string(cx).contains(
+            "<p id=\"_0-1\"><!--hk=_0-2o|leptos-dyn-child-start-->Hello \
+             Alice, you have 3 items<!--hk=_0-2c|leptos-dyn-child-end--></p>"
+        ));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_keeps_adjacent_views_separate() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let (count, _) = create_signal(cx, 3);
+        let rendered = view! {
+            cx,
+            <p>{move || count.get()} " " {view! { cx, <b>"items"</b> }}</p>
+        };
+
+        assert!(rendered.into_view(cx).render_to_string(cx).contains(
+            "<p id=\"_0-1\"><!--hk=_0-3o|leptos-dyn-child-start-->3<!\
+             --hk=_0-3c|leptos-dyn-child-end--> <b id=\"_0-2\">items</b></p>"
+        ));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_with_custom_attribute_types() {
+    use leptos::*;
+
+    #[derive(Clone)]
+    struct Color(u8, u8, u8);
+
+    impl IntoAttribute for Color {
+        fn into_attribute(self, _cx: Scope) -> Attribute {
+            let Color(r, g, b) = self;
+            Attribute::String(format!("#{r:02x}{g:02x}{b:02x}").into())
+        }
+    }
+
+    impl IntoStyle for Color {
+        fn into_style(self, _cx: Scope) -> Style {
+            let Color(r, g, b) = self;
+            Style::Value(format!("rgb({r}, {g}, {b})").into())
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct Hidden(bool);
+
+    impl IntoClass for Hidden {
+        fn into_class(self, _cx: Scope) -> Class {
+            Class::Value(self.0)
+        }
+    }
+
+    _ = create_scope(create_runtime(), |cx| {
+        let (color, _) = create_signal(cx, Color(255, 0, 0));
+        let rendered = view! {
+            cx,
+            <p
+                data-color=Color(0, 128, 255)
+                style:color=move || color.get()
+                class:hidden=move || Hidden(true)
+            >
+                "Hello"
+            </p>
+        };
+
+        assert!(rendered.into_view(cx).render_to_string(cx).contains(
+            "<p data-color=\"#0080ff\" id=\"_0-1\" class=\" hidden\" \
+             style=\" color: rgb(255, 0, 0);\">Hello</p>"
+        ));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_with_let_bindings() {
+    use leptos::*;
+
+    #[component]
+    fn Greeting<F, IV>(cx: Scope, children: F) -> impl IntoView
+    where
+        F: Fn(Scope, &'static str) -> IV,
+        IV: IntoView,
+    {
+        children(cx, "Hello")
+    }
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = view! {
+            cx,
+            <Greeting let:greeting>
+                <p>{greeting} ", world!"</p>
+            </Greeting>
+        };
+        assert!(rendered
+            .into_view(cx)
+            .render_to_string(cx)
+            .contains("<p id=\"_0-3\">Hello, world!</p>"));
+
+        let rendered = view! {
+            cx,
+            <ul>
+                <For each=|| vec![1, 2] key=|n| *n let:n>
+                    <li>{n}</li>
+                </For>
+            </ul>
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert!(rendered.contains("<li id=\"_0-7\">1</li>"));
+        assert!(rendered.contains("<li id=\"_0-10\">2</li>"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_into_existing_template() {
+    use leptos::*;
+
+    let mount = LiquidMount::new()
+        .component("greeting", |cx| view! { cx, <p>"Hello"</p> })
+        .component("count", |cx| {
+            let (count, _) = create_signal(cx, 3);
+            view! { cx, <span>{move || count.get()}</span> }
+        });
+
+    let html = mount.render_into_template(
+        "<main><h1>Legacy page</h1>\
+         <div class=\"a\" data-leptos-mount=\"greeting\"></div>\
+         <div data-leptos-mount=\"unknown\"></div>\
+         <aside data-leptos-mount=\"count\"></aside></main>",
+    );
+
+    assert_eq!(
+        html,
+        "<main><h1>Legacy page</h1><div class=\"a\" \
+         data-leptos-mount=\"greeting\"><p id=\"_0-1\">Hello</p></div><div \
+         data-leptos-mount=\"unknown\"></div><aside \
+         data-leptos-mount=\"count\"><span id=\"_0-2\"><!\
+         --hk=_0-3o|leptos-dyn-child-start-->3<!\
+         --hk=_0-3c|leptos-dyn-child-end--></span></aside></main>"
+    );
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_for_with_chunk_size_renders_every_item() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = view! {
+            cx,
+            <ul>
+                <For each=|| vec![1, 2, 3] key=|n| *n chunk_size=1 let:n>
+                    <li>{n}</li>
+                </For>
+            </ul>
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert_eq!(rendered.matches("<li").count(), 3);
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_index_renders_each_position() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = view! {
+            cx,
+            <ul>
+                <Index each=|| vec!["a", "b", "c"] let:letter>
+                    <li>{move || letter.get()}</li>
+                </Index>
+            </ul>
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert_eq!(rendered.matches("<li").count(), 3);
+        assert!(rendered.find(">a<") < rendered.find(">b<"));
+        assert!(rendered.find(">b<") < rendered.find(">c<"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_bind_renders_initial_values() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let name = create_rw_signal(cx, "Ferris \"the crab\"".to_string());
+        let bio = create_rw_signal(cx, "<b>hi</b>".to_string());
+        let subscribed = create_rw_signal(cx, true);
+        let color = create_rw_signal(cx, "blue".to_string());
+        let toppings = create_rw_signal(cx, vec!["cheese".to_string()]);
+
+        let rendered = view! {
+            cx,
+            <form>
+                <input bind:value=name/>
+                <textarea bind:value=bio></textarea>
+                <input type="checkbox" bind:checked=subscribed/>
+                <input type="radio" value="red" bind:group=color/>
+                <input type="radio" value="blue" bind:group=color/>
+                <input type="checkbox" value="cheese" bind:group=toppings/>
+                <input type="checkbox" value="ham" bind:group=toppings/>
+            </form>
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert!(rendered.contains(r#"value="Ferris &quot;the crab&quot;""#));
+        assert!(rendered.contains("&lt;b&gt;hi&lt;/b&gt;</textarea>"));
+        assert_eq!(rendered.matches(" checked").count(), 3);
+        assert!(rendered.contains(r#"value="blue" checked"#));
+        assert!(rendered.contains(r#"value="cheese" checked"#));
+        assert!(!rendered.contains(r#"value="red" checked"#));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_animated_show_wraps_children_in_enter_class() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = view! {
+            cx,
+            <AnimatedShow when=|| true enter_class="fade-in" leave_class="fade-out">
+                <p>"Saved!"</p>
+            </AnimatedShow>
+            <AnimatedShow when=|| false enter_class="fade-in">
+                <p>"Hidden"</p>
+            </AnimatedShow>
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert!(rendered.contains("class=\"fade-in\""));
+        assert!(rendered.contains("Saved!"));
+        assert!(!rendered.contains("fade-out"));
+        assert!(!rendered.contains("Hidden"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_theme_provider_emits_css_variables() {
+    use leptos::*;
+    use std::borrow::Cow;
+
+    #[derive(Clone, PartialEq)]
+    struct Palette {
+        primary: &'static str,
+    }
+
+    impl Theme for Palette {
+        fn css_variables(&self) -> Vec<(Cow<'static, str>, String)> {
+            vec![("primary".into(), self.primary.to_string())]
+        }
+    }
+
+    _ = create_scope(create_runtime(), |cx| {
+        #[component]
+        fn Label(cx: Scope) -> impl IntoView {
+            let palette = use_theme::<Palette>(cx);
+            view! { cx, <span>{move || palette.with(|p| p.primary)}</span> }
+        }
+
+        let rendered = view! {
+            cx,
+            <ThemeProvider theme=|| Palette { primary: "red" }>
+                <Label/>
+            </ThemeProvider>
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert!(rendered.contains(
+            "<leptos-theme style=\"display: contents; --primary: red;\""
+        ));
+        assert!(rendered.contains(">red<"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_accessibility_snapshot_of_rendered_view() {
+    use leptos::{leptos_dom::a11y::accessibility_snapshot, *};
+
+    _ = create_scope(create_runtime(), |cx| {
+        let (count, _) = create_signal(cx, 3);
+        let rendered = view! {
+            cx,
+            <main>
+                <h2>"Cart"</h2>
+                <label for="qty">"Quantity"</label>
+                <input id="qty" type="number" value=count/>
+                <button aria-label="Remove item">"×"</button>
+            </main>
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert_eq!(
+            accessibility_snapshot(&rendered),
+            "- main\n  - heading \"Cart\" [level=2]\n  - text: Quantity\n  - \
+             spinbutton \"Quantity\"\n  - button \"Remove item\"\n    - text: \
+             ×\n"
+        );
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_directives_do_not_run_or_render() {
+    use leptos::*;
+    use std::{cell::Cell, rc::Rc};
+
+    fn mark_ran<E>(_cx: Scope, _el: E, ran: Rc<Cell<bool>>) {
+        ran.set(true);
+    }
+
+    fn noop<E>(_cx: Scope, _el: E, _: ()) {}
+
+    _ = create_scope(create_runtime(), |cx| {
+        let ran = Rc::new(Cell::new(false));
+        let rendered = view! {
+            cx,
+            <div use:mark_ran=Rc::clone(&ran) use:noop>"directive"</div>
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert!(!ran.get());
+        assert!(!rendered.contains("use:"));
+        assert!(!rendered.contains("mark_ran"));
+        assert!(rendered.contains(">directive</div>"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_spread_attributes() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let (label, _) = create_signal(cx, "Say \"hi\"");
+        let attrs = SpreadAttributes::new()
+            .attr("aria-label", move || label.get())
+            .attr("hidden", false)
+            .attr("disabled", true)
+            .prop("value", "ignored")
+            .on(ev::click, |_| ());
+        let rendered = view! {
+            cx,
+            <button type="button" {..attrs} {..[("data-kind", "primary")]}>"hi"</button>
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert!(rendered.starts_with(
+            "<button type=\"button\" aria-label=\"Say &quot;hi&quot;\" \
+             disabled data-kind=\"primary\""
+        ));
+        assert!(!rendered.contains("hidden"));
+        assert!(!rendered.contains("ignored"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_event_listener_options() {
+    use leptos::*;
+
+    #[component]
+    fn Scroller(cx: Scope) -> impl IntoView {
+        view! { cx, <div class="scroller"/> }
+    }
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = view! {
+            cx,
+            <div on:click:capture=|_| () on:touchmove:passive:undelegated=|_| ()>
+                <Scroller on:wheel:passive=|_| ()/>
+            </div>
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert!(!rendered.contains("on:"));
+
+        // listeners with options can be built on the server without panicking
+        let rendered = html::div(cx)
+            .on(ev::capture(ev::click), |_| ())
+            .on(
+                ev::WithOptions::new(ev::wheel).passive(false).once(),
+                |_| (),
+            )
+            .into_view(cx)
+            .render_to_string(cx);
+        assert!(rendered.starts_with("<div"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_image() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = view! {
+            cx,
+            <Image src="/cat.jpg" alt="A cat" width=300 height=200/>
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert!(rendered.contains("src=\"/cat.jpg\""));
+        assert!(!rendered.contains("srcset"));
+        assert!(rendered.contains("loading=\"lazy\""));
+        assert!(rendered.contains("style=\"aspect-ratio: 300 / 200;\""));
+
+        provide_image_loader(cx, ImageLoader::endpoint("/_image"));
+        let rendered = view! {
+            cx,
+            <Image src="/cat.jpg" alt="A cat" width=300 height=200 quality=50/>
+            <Image
+                src="/hero.jpg"
+                alt=""
+                width=1200
+                height=600
+                sizes="100vw"
+                widths=vec![640, 1280]
+                priority=true
+            />
+        };
+        let rendered = rendered.into_view(cx).render_to_string(cx);
+        assert!(rendered.contains(
+            "srcset=\"/_image?url=%2Fcat.jpg&amp;w=300&amp;q=50 1x, \
+             /_image?url=%2Fcat.jpg&amp;w=600&amp;q=50 2x\""
+        ));
+        assert!(rendered
+            .contains("src=\"/_image?url=%2Fcat.jpg&amp;w=300&amp;q=50\""));
+        assert!(rendered.contains(
+            "srcset=\"/_image?url=%2Fhero.jpg&amp;w=640&amp;q=75 640w, \
+             /_image?url=%2Fhero.jpg&amp;w=1280&amp;q=75 1280w\""
+        ));
+        assert!(rendered.contains("sizes=\"100vw\""));
+        assert!(rendered.contains("fetchpriority=\"high\""));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_svg_and_math() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = svg::svg(cx)
+            .attr("viewbox", "0 0 24 24")
+            .child(svg::use_(cx).attr("xlink:href", "#icon"))
+            .into_view(cx)
+            .render_to_string(cx);
+        assert!(rendered.starts_with("<svg"));
+        assert!(rendered.contains("viewBox=\"0 0 24 24\""));
+        assert!(rendered.contains("<use"));
+        assert!(rendered.contains("xlink:href=\"#icon\""));
+
+        let rendered = math::semantics(cx)
+            .child(math::annotation_xml(cx).attr("encoding", "text/html"))
+            .into_view(cx)
+            .render_to_string(cx);
+        assert!(rendered.contains("<annotation-xml"));
+        assert!(rendered.contains("</annotation-xml>"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_shadow_root() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = view! { cx,
+            <div>
+                {ShadowRoot::new(|cx| view! { cx, <p>"Hello"</p> }.into_view(cx))
+                    .mode(ShadowRootMode::Closed)
+                    .style("p > b { color: red; }")}
+            </div>
+        }
+        .into_view(cx)
+        .render_to_string(cx);
+        assert!(rendered.contains("<leptos-shadow"));
+        assert!(rendered.contains("data-hk-end=\""));
+        assert!(rendered.contains("<template shadowrootmode=\"closed\""));
+        assert!(rendered.contains("<style"));
+        assert!(rendered.contains(">p > b { color: red; }</style>"));
+        assert!(rendered.contains(">Hello</p></template></leptos-shadow>"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_switch() {
+    use leptos::*;
+
+    _ = create_scope(create_runtime(), |cx| {
+        let render = |count: i32| {
+            view! { cx,
+                <Switch fallback=|cx| view! { cx, <p>"Big"</p> }>
+                    <Match slot when={count == 0}><p>"Zero"</p></Match>
+                    <Match slot when={count < 10}><p>"Small"</p></Match>
+                </Switch>
+            }
+            .into_view(cx)
+            .render_to_string(cx)
+        };
+
+        let rendered = render(0);
+        assert!(rendered.contains(">Zero</p>"));
+        assert!(!rendered.contains(">Small</p>"));
+
+        let rendered = render(5);
+        assert!(rendered.contains(">Small</p>"));
+
+        let rendered = render(50);
+        assert!(rendered.contains(">Big</p>"));
+        assert!(!rendered.contains(">Small</p>"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_error_boundary_reset() {
+    use leptos::*;
+    use std::{cell::Cell, rc::Rc};
+
+    _ = create_scope(create_runtime(), |cx| {
+        let renders = Rc::new(Cell::new(0));
+        let reported = Rc::new(Cell::new(0));
+        let reset = Rc::new(Cell::new(None::<ErrorBoundaryReset>));
+        let errors = Rc::new(Cell::new(None::<RwSignal<Errors>>));
+
+        let child_renders = Rc::clone(&renders);
+        let view = view! { cx,
+            <ErrorBoundary
+                fallback={
+                    let reset = Rc::clone(&reset);
+                    let errors = Rc::clone(&errors);
+                    move |cx, caught| {
+                        reset.set(use_context::<ErrorBoundaryReset>(cx));
+                        errors.set(Some(caught));
+                        view! { cx, <p>"Failed"</p> }
+                    }
+                }
+                on_error={
+                    let reported = Rc::clone(&reported);
+                    SignalSetter::map(cx, move |_: Errors| {
+                        reported.set(reported.get() + 1)
+                    })
+                }
+            >
+                {
+                    child_renders.set(child_renders.get() + 1);
+                    "x".parse::<i32>()
+                }
+            </ErrorBoundary>
+        }
+        .into_view(cx);
+        let rendered = view.render_to_string(cx);
+        assert!(rendered.contains(">Failed</p>"));
+        assert_eq!(renders.get(), 1);
+        assert!(reported.get() > 0);
+
+        let errors = errors.take().unwrap();
+        assert!(!errors.with(Errors::is_empty));
+        reset
+            .take()
+            .expect("reset is provided to the fallback")
+            .reset();
+        assert!(errors.with(Errors::is_empty));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_render_to_writer_matches_render_to_string() {
+    use leptos::*;
+
+    #[component]
+    fn Item(cx: Scope, label: &'static str) -> impl IntoView {
+        view! { cx, <li class:even=label.len() % 2 == 0>{label}</li> }
+    }
+
+    fn app(cx: Scope) -> impl IntoView {
+        let (items, _) = create_signal(cx, vec!["a", "bb", "<c>"]);
+        view! { cx,
+            <ul>
+                <For
+                    each=move || items.get()
+                    key=|item| *item
+                    view=move |cx, label| view! { cx, <Item label/> }
+                />
+            </ul>
+            <p>{move || items.with(Vec::len)} " items"</p>
+        }
+    }
+
+    let expected = leptos::ssr::render_to_string(app);
+    let mut written = String::new();
+    leptos::ssr::render_to_writer(app, &mut written).unwrap();
+    assert_eq!(written, expected);
+    assert!(written.contains("&lt;c&gt;"));
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_cached_fragment_is_reused() {
+    use leptos::*;
+    use std::{cell::Cell, rc::Rc};
+
+    #[component]
+    fn Nav(cx: Scope, renders: Rc<Cell<usize>>) -> impl IntoView {
+        renders.set(renders.get() + 1);
+        view! { cx, <nav><a href="/">"Home"</a>{move || "Blog"}</nav> }
+    }
+
+    fn app(
+        cache: Option<FragmentCache>,
+        renders: Rc<Cell<usize>>,
+    ) -> impl FnOnce(Scope) -> View + 'static {
+        move |cx| {
+            if let Some(cache) = cache {
+                provide_fragment_cache(cx, cache);
+            }
+            view! { cx,
+                <header>
+                    <Cached key="nav"><Nav renders=renders.clone()/></Cached>
+                </header>
+                <p>{move || "after"}</p>
+            }
+            .into_view(cx)
+        }
+    }
+
+    let renders = Rc::new(Cell::new(0));
+    let uncached = leptos::ssr::render_to_string(app(None, renders.clone()));
+    assert_eq!(renders.get(), 1);
+
+    let cache = FragmentCache::new();
+    let render = || {
+        leptos::ssr::render_to_string(app(Some(cache.clone()), renders.clone()))
+    };
+    assert_eq!(render(), uncached);
+    assert_eq!(render(), uncached);
+    assert_eq!(renders.get(), 2);
+    assert!(cache.contains("nav"));
+
+    cache.invalidate("nav");
+    assert!(!cache.contains("nav"));
+    assert_eq!(render(), uncached);
+    assert_eq!(renders.get(), 3);
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_island_records_its_boundary_and_props() {
+    use leptos::*;
+
+    #[island]
+    fn Counter(cx: Scope, initial: u32, label: String) -> impl IntoView {
+        let (count, set_count) = create_signal(cx, initial);
+        view! { cx,
+            <button on:click=move |_| set_count.update(|n| *n += 1)>
+                {label} {count}
+            </button>
+        }
+    }
+
+    let html = leptos::ssr::render_to_string(|cx| {
+        view! { cx,
+            <article>
+                <p>"Static"</p>
+                <Counter initial=3 label="Count: ".to_string()/>
+            </article>
+        }
+    });
+    let island = html.find("<leptos-island").unwrap();
+    // the island is hydrated starting from its wrapper, `_0-3`
+    assert!(html[island..].starts_with(
+        "<leptos-island style=\"display: contents\" \
+         data-component=\"Counter\" data-hk=\"0-2\""
+    ));
+    assert!(html[island..].contains("id=\"_0-3\""));
+    assert!(html.contains("data-prop-initial=\"3\""));
+    assert!(html.contains("data-prop-label=\"&quot;Count: &quot;\""));
+    assert!(html[island..].contains("<button"));
+    assert!(html.find("Static").unwrap() < island);
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_with_native_control_flow() {
+    use leptos::*;
+
+    #[derive(Clone)]
+    enum Status {
+        Draft,
+        Published { views: u32 },
+    }
+
+    let html = leptos::ssr::render_to_string(|cx| {
+        let (logged_in, _) = create_signal(cx, false);
+        let (status, _) = create_signal(cx, Status::Published { views: 3 });
+        let (tags, _) = create_signal(cx, vec![(1, "rust"), (2, "web")]);
+        view! { cx,
+            <nav>
+                if logged_in.get() {
+                    <a href="/logout">"Log out"</a>
+                } else if let Status::Draft = status.get() {
+                    "Draft"
+                } else {
+                    <a href="/login">"Log in"</a>
+                }
+            </nav>
+            match status.get() {
+                Status::Draft => { <p>"Not published"</p> }
+                Status::Published { views } if views > 0 => {
+                    <p>{views} " views"</p>
+                }
+                Status::Published { .. } => { <p>"No views"</p> }
+            }
+            <ul>
+                for (id, tag) in tags.get() key=*id {
+                    <li>{tag}</li>
+                }
+            </ul>
+        }
+    });
+
+    assert!(html.contains(">Log in</a>"));
+    assert!(!html.contains("Log out"));
+    assert!(html.contains(">3 views</p>"));
+    assert!(html.contains(">rust</li>") && html.contains(">web</li>"));
+    assert!(html.find(">rust</li>").unwrap() < html.find(">web</li>").unwrap());
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_with_named_slots() {
+    use leptos::*;
+
+    #[slot]
+    struct CardHeader {
+        children: Children,
+    }
+
+    #[slot]
+    struct CardAction {
+        label: &'static str,
+    }
+
+    #[slot]
+    struct CardFooter {
+        children: Children,
+    }
+
+    #[component]
+    fn Card(
+        cx: Scope,
+        card_header: CardHeader,
+        #[prop(optional)] card_action: Vec<CardAction>,
+        #[prop(optional)] card_footer: Option<CardFooter>,
+        children: Children,
+    ) -> impl IntoView {
+        view! { cx,
+            <article>
+                <header>{(card_header.children)(cx)}</header>
+                {children(cx)}
+                {card_action
+                    .into_iter()
+                    .map(|action| view! { cx, <button>{action.label}</button> })
+                    .collect_view(cx)}
+                {card_footer.map(|footer| {
+                    view! { cx, <footer>{(footer.children)(cx)}</footer> }
+                })}
+            </article>
+        }
+    }
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = view! { cx,
+            <Card>
+                <CardHeader slot><h2>"Title"</h2></CardHeader>
+                <p>"Body"</p>
+                <CardAction slot label="Save"/>
+                <CardAction slot label="Share"/>
+                <CardFooter slot>"Footer"</CardFooter>
+            </Card>
+        }
+        .into_view(cx)
+        .render_to_string(cx);
+        assert!(rendered.contains(">Title</h2>"));
+        assert!(rendered.contains(">Body</p>"));
+        assert!(rendered.find(">Save<") < rendered.find(">Share<"));
+        assert!(rendered.contains(">Footer<!--hk="));
+
+        let rendered = view! { cx,
+            <Card>
+                <CardHeader slot>"Title"</CardHeader>
+                "Body"
+            </Card>
+        }
+        .into_view(cx)
+        .render_to_string(cx);
+        assert!(!rendered.contains("<button"));
+        assert!(!rendered.contains("<footer"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_with_default_props() {
+    use leptos::*;
+
+    #[component]
+    fn Greeting(
+        cx: Scope,
+        #[prop(into, default = "World".to_string())] name: String,
+        #[prop(default = name.len() + 1)] times: usize,
+        #[prop(strip_option)] punctuation: Option<char>,
+    ) -> impl IntoView {
+        let greeting = format!("Hello, {name}{}", punctuation.unwrap_or('.'));
+        view! { cx, <p>{greeting.repeat(times)}</p> }
+    }
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = view! { cx, <Greeting punctuation='!'/> }
+            .into_view(cx)
+            .render_to_string(cx);
+        // a default can use the props before it
+        assert_eq!(rendered.matches("Hello, World!").count(), 6);
+
+        let rendered =
+            view! { cx, <Greeting name="Ferris" times=1 punctuation='?'/> }
+                .into_view(cx)
+                .render_to_string(cx);
+        assert!(rendered.contains(">Hello, Ferris?</p>"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_with_generic_components() {
+    use leptos::*;
+    use std::fmt::Display;
+
+    #[component]
+    fn TypedSelect<T: Display + Clone + 'static>(
+        cx: Scope,
+        options: Vec<T>,
+        #[prop(optional)] selected: Option<T>,
+    ) -> impl IntoView {
+        let selected = selected.map(|selected| selected.to_string());
+        view! { cx,
+            <select>
+                {options
+                    .into_iter()
+                    .map(|option| {
+                        let option = option.to_string();
+                        let is_selected = selected.as_ref() == Some(&option);
+                        view! { cx,
+                            <option selected=is_selected>{option}</option>
+                        }
+                    })
+                    .collect_view(cx)}
+            </select>
+        }
+    }
+
+    _ = create_scope(create_runtime(), |cx| {
+        let rendered = view! { cx,
+            <TypedSelect options=vec![1, 2] selected=2/>
+            <TypedSelect<String> options=vec![]/>
+            <TypedSelect::<f32> options=vec![0.5]/>
+        }
+        .into_view(cx)
+        .render_to_string(cx);
+        assert!(rendered.contains(">1</option>"));
+        assert!(rendered.contains(">2</option>"));
+        assert_eq!(rendered.matches(" selected").count(), 1);
+        assert!(rendered.contains(">0.5</option>"));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_with_props_and_node_refs() {
+    use leptos::{html::Input, *};
+
+    _ = create_scope(create_runtime(), |cx| {
+        let (checked, _) = create_signal(cx, true);
+        let input_ref = create_node_ref::<Input>(cx);
+        let circle_ref = create_node_ref(cx);
+        let rendered = view! { cx,
+            <input type="checkbox" prop:checked=checked node_ref=input_ref/>
+            <svg><circle r="1" node_ref=circle_ref/></svg>
+        }
+        .into_view(cx)
+        .render_to_string(cx);
+        // properties aren't rendered, and refs are never loaded on the server
+        assert!(!rendered.contains("checked"));
+        assert!(input_ref.get().is_none());
+        assert!(circle_ref.get().is_none());
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_with_transparent_components_that_return_data() {
+    use leptos::*;
+
+    #[derive(Clone)]
+    struct MenuEntry {
+        label: &'static str,
+        href: &'static str,
+    }
+
+    #[component(transparent)]
+    fn MenuItem(
+        cx: Scope,
+        label: &'static str,
+        href: &'static str,
+    ) -> Transparent {
+        _ = cx;
+        Transparent::new(MenuEntry { label, href })
+    }
+
+    #[component]
+    fn Menu(cx: Scope, children: Children) -> impl IntoView {
+        let entries = children(cx)
+            .transparent_children::<MenuEntry>()
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        view! { cx,
+            <nav>
+                {entries
+                    .into_iter()
+                    .map(|entry| view! { cx, <a href=entry.href>{entry.label}</a> })
+                    .collect_view(cx)}
+            </nav>
+        }
+    }
+
+    _ = create_scope(create_runtime(), |cx| {
+        let more = vec![("Blog", "/blog"), ("Contact", "/contact")];
+        let rendered = view! { cx,
+            <Menu>
+                <MenuItem label="Home" href="/"/>
+                <p>"not an entry"</p>
+                {more
+                    .into_iter()
+                    .map(|(label, href)| view! { cx, <MenuItem label href/> })
+                    .collect_view(cx)}
+            </Menu>
+        }
+        .into_view(cx)
+        .render_to_string(cx);
+        assert!(!rendered.contains("not an entry"));
+        let home = rendered.find(">Home</a>").unwrap();
+        let blog = rendered.find(">Blog</a>").unwrap();
+        let contact = rendered.find(">Contact</a>").unwrap();
+        assert!(home < blog && blog < contact);
+        assert!(rendered.contains("href=\"/contact\""));
+    });
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_control_flow_with_non_copy_captures() {
+    use leptos::*;
+
+    let html = leptos::ssr::render_to_string(|cx| {
+        let name = String::new();
+        let greeting = String::from("Hello");
+        let (user, _) = create_signal(cx, Some(String::from("Ferris")));
+        view! { cx,
+            <div>
+                if name.is_empty() {
+                    <p>{greeting.clone()}</p>
+                } else {
+                    <p>"Named"</p>
+                }
+                match user.get() {
+                    Some(user) => { <p>{user}</p> }
+                    None => { <p>"Nobody"</p> }
+                }
+            </div>
+        }
+    });
+
+    assert!(html.contains(">Hello</p>"));
+    assert!(html.contains(">Ferris</p>"));
+    assert!(!html.contains("Named") && !html.contains("Nobody"));
+}
+
+#[cfg(not(any(feature = "csr", feature = "hydrate")))]
+#[test]
+fn ssr_text_that_starts_with_a_keyword_is_not_control_flow() {
+    use leptos::*;
+
+    // this only has to compile: the `if` isn't followed by a condition and a
+    // block before the next tag
+    let html = leptos::ssr::render_to_string(|cx| {
+        let x = 1;
+        view! { cx, <div><p>if you want</p><span>{x}</span></div> }
+    });
+
+    assert!(html.contains(">1</span>"));
+}
@@ -1,4 +1,4 @@
-use std::{net::AddrParseError, num::ParseIntError};
+use std::{net::AddrParseError, num::ParseIntError, str::ParseBoolError};
 use thiserror::Error;
 
 #[derive(Debug, Error, Clone)]
@@ -31,3 +31,9 @@ impl From<AddrParseError> for LeptosConfigError {
         Self::ConfigError(e.to_string())
     }
 }
+
+impl From<ParseBoolError> for LeptosConfigError {
+    fn from(e: ParseBoolError) -> Self {
+        Self::ConfigError(e.to_string())
+    }
+}
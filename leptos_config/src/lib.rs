@@ -49,6 +49,25 @@ pub struct LeptosOptions {
     /// Defaults to `3001`
     #[builder(default = 3001)]
     pub reload_port: u32,
+    /// Whether streamed responses send the start of the `<head>`, which tells the browser to
+    /// start loading the JS and WASM bundles, as soon as the app shell has rendered, instead
+    /// of waiting until blocking resources have loaded. The rest of the `<head>` follows once
+    /// they have.
+    ///
+    /// The status and headers of the response are sent along with it, so they can't be set
+    /// while blocking resources load, and attributes added to `<html>` under a `<Suspense/>`
+    /// are left out.
+    /// Defaults to `false`
+    #[builder(default)]
+    #[serde(default)]
+    pub flush_head_early: bool,
+    /// Whether the app is rendered in islands mode: instead of hydrating the whole page, the
+    /// script that loads the WASM bundle only hydrates the components marked with `#[island]`,
+    /// each on its own, and leaves the rest of the page as static HTML.
+    /// Defaults to `false`
+    #[builder(default)]
+    #[serde(default)]
+    pub islands: bool,
 }
 
 impl LeptosOptions {
@@ -77,6 +96,12 @@ impl LeptosOptions {
                 .parse()?,
             reload_port: env_w_default("LEPTOS_RELOAD_PORT", "3001")?
                 .parse()?,
+            flush_head_early: env_w_default(
+                "LEPTOS_FLUSH_HEAD_EARLY",
+                "false",
+            )?
+            .parse()?,
+            islands: env_w_default("LEPTOS_ISLANDS", "false")?.parse()?,
         })
     }
 }
@@ -210,6 +235,39 @@ pub fn get_config_from_env() -> Result<ConfFile, LeptosConfigError> {
     })
 }
 
+/// Loads your own typed app config from an optional TOML file, with layered overrides from
+/// environment variables that start with `env_prefix`. Nested keys are separated by `__` in
+/// environment variables, so with the prefix `APP`, `APP_API_URL` overrides `api_url` and
+/// `APP_DATABASE__URL` overrides `url` in the `[database]` table.
+///
+/// The config can then be passed to `provide_config`, which sends its client-safe part to the
+/// browser, so that it can be read with `use_config` on both the server and the client.
+pub fn get_app_config<T>(
+    path: Option<&str>,
+    env_prefix: &str,
+) -> Result<T, LeptosConfigError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut settings = Config::builder();
+    if let Some(path) = path {
+        let text = fs::read_to_string(path).map_err(|e| {
+            LeptosConfigError::ConfigError(format!("{path}: {e}"))
+        })?;
+        settings = settings.add_source(File::from_str(&text, FileFormat::Toml));
+    }
+    settings
+        .add_source(
+            config::Environment::with_prefix(env_prefix)
+                .prefix_separator("_")
+                .separator("__")
+                .try_parsing(true),
+        )
+        .build()?
+        .try_deserialize()
+        .map_err(|e| LeptosConfigError::ConfigError(e.to_string()))
+}
+
 #[path = "tests.rs"]
 #[cfg(test)]
 mod tests;
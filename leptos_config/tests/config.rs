@@ -1,6 +1,6 @@
 use leptos_config::{
-    get_config_from_file, get_config_from_str, get_configuration, Env,
-    LeptosOptions,
+    get_app_config, get_config_from_file, get_config_from_str,
+    get_configuration, Env, LeptosOptions,
 };
 use std::{fs::File, io::Write, net::SocketAddr, path::Path, str::FromStr};
 use tempfile::NamedTempFile;
@@ -138,6 +138,25 @@ fn get_config_from_str_content() {
     assert_eq!(config.reload_port, 8080);
 }
 
+#[test]
+fn get_config_from_str_flush_head_early() {
+    let config = get_config_from_str(CARGO_TOML_CONTENT_OK)
+        .unwrap()
+        .leptos_options;
+    assert!(!config.flush_head_early);
+
+    let content = format!("{CARGO_TOML_CONTENT_OK}flush-head-early = true\n");
+    let config = get_config_from_str(&content).unwrap().leptos_options;
+    assert!(config.flush_head_early);
+}
+
+#[test]
+fn get_config_from_str_islands() {
+    let content = format!("{CARGO_TOML_CONTENT_OK}islands = true\n");
+    let config = get_config_from_str(&content).unwrap().leptos_options;
+    assert!(config.islands);
+}
+
 #[tokio::test]
 async fn get_config_from_env() {
     // Test config values from environment variables
@@ -186,4 +205,58 @@ fn leptos_options_builder_default() {
         SocketAddr::from_str("127.0.0.1:3000").unwrap()
     );
     assert_eq!(conf.reload_port, 3001);
+    assert!(!conf.flush_head_early);
+    assert!(!conf.islands);
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct AppConfig {
+    api_url: String,
+    max_items: u32,
+    database: DatabaseConfig,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct DatabaseConfig {
+    url: String,
+}
+
+#[test]
+fn get_app_config_from_file_and_env() {
+    let app_toml = NamedTempFile::new().unwrap();
+    {
+        let mut output = File::create(&app_toml).unwrap();
+        write!(
+            output,
+            r#"
+api_url = "https://api.example.com"
+max_items = 10
+
+[database]
+url = "postgres://localhost/app"
+"#
+        )
+        .unwrap();
+    }
+
+    std::env::set_var("APPCONFIGTEST_MAX_ITEMS", "20");
+    std::env::set_var("APPCONFIGTEST_DATABASE__URL", "postgres://db/app");
+
+    let config: AppConfig = get_app_config(
+        Some(app_toml.path().to_str().unwrap()),
+        "APPCONFIGTEST",
+    )
+    .unwrap();
+    assert_eq!(config.api_url, "https://api.example.com");
+    assert_eq!(config.max_items, 20);
+    assert_eq!(config.database.url, "postgres://db/app");
+
+    std::env::remove_var("APPCONFIGTEST_MAX_ITEMS");
+    std::env::remove_var("APPCONFIGTEST_DATABASE__URL");
+
+    assert!(get_app_config::<AppConfig>(
+        Some("does/not/exist.toml"),
+        "APPCONFIGTEST"
+    )
+    .is_err());
 }
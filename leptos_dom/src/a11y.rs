@@ -0,0 +1,722 @@
+//! Snapshots of the accessibility tree of rendered HTML.
+//!
+//! [`accessibility_tree`] reads HTML, like the output of server-side
+//! rendering, and returns the tree an assistive technology would see: only
+//! the elements that have a role, with their accessible names and states.
+//! Its [`Display`](std::fmt::Display) output is stable and easy to read, so it
+//! can be compared against a snapshot in a test to catch changes to the ARIA
+//! semantics of a component, without depending on its exact markup.
+//!
+//! ```
+//! use leptos_dom::a11y::accessibility_snapshot;
+//!
+//! let html = r#"
+//!   <nav aria-label="Main">
+//!     <ul>
+//!       <li><a href="/">Home</a></li>
+//!       <li><a href="/about" aria-current="page">About</a></li>
+//!     </ul>
+//!   </nav>
+//!   <main>
+//!     <h1>About us</h1>
+//!     <label for="news">Newsletter</label>
+//!     <input id="news" type="checkbox" checked>
+//!     <button disabled>Save</button>
+//!   </main>
+//! "#;
+//!
+//! assert_eq!(
+//!     accessibility_snapshot(html),
+//!     r#"- navigation "Main"
+//!   - list
+//!     - listitem
+//!       - link "Home"
+//!     - listitem
+//!       - link "About" [current=page]
+//! - main
+//!   - heading "About us" [level=1]
+//!   - text: Newsletter
+//!   - checkbox "Newsletter" [checked]
+//!   - button "Save" [disabled]
+//! "#
+//! );
+//! ```
+//!
+//! This is a close approximation of the tree browsers build, not a complete
+//! implementation of the HTML-AAM and accessible name specifications: roles
+//! are taken from the `role` attribute or the element, names from
+//! `aria-labelledby`, `aria-label`, `<label>`s, `alt`, the element’s text
+//! content where its role allows it, and `title`, and elements that are
+//! `hidden` or `aria-hidden` are left out. It doesn’t apply CSS.
+
+use rustc_hash::FxHashMap;
+use std::{cell::RefCell, fmt};
+
+/// A node in the accessibility tree, created with [`accessibility_tree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessibilityNode {
+    /// An element with a role.
+    Element {
+        /// The ARIA role, like `button` or `heading`.
+        role: String,
+        /// The accessible name, if it has one.
+        name: Option<String>,
+        /// The states and properties that matter for this role, like
+        /// `("level", "1")` for a heading or `("checked", "true")` for a
+        /// checkbox, in a fixed order.
+        states: Vec<(String, String)>,
+        /// The accessible children.
+        children: Vec<AccessibilityNode>,
+    },
+    /// Text that isn’t already the name of its parent.
+    Text(String),
+}
+
+/// Returns the accessibility tree for a fragment or document of HTML.
+pub fn accessibility_tree(html: &str) -> Vec<AccessibilityNode> {
+    let nodes = parse(html);
+    let mut ids = FxHashMap::default();
+    let mut labels = FxHashMap::default();
+    index(&nodes, &mut ids, &mut labels);
+    let doc = Document {
+        ids,
+        labels,
+        key: None,
+        roles: Default::default(),
+    };
+    nodes
+        .iter()
+        .flat_map(|node| doc.accessible(node, None))
+        .collect()
+}
+
+/// The role and accessible name of an element, found by [`element_roles`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementRole {
+    /// The value of the element’s key attribute.
+    pub key: String,
+    /// The ARIA role, like `button` or `heading`.
+    pub role: String,
+    /// The accessible name, if it has one.
+    pub name: Option<String>,
+}
+
+/// Returns the role and accessible name of each element in the
+/// [`accessibility_tree`] of some HTML that has the attribute `key`, so that
+/// they can be matched up with the elements of another tree, like an
+/// in-memory DOM that was rendered to the HTML.
+pub fn element_roles(html: &str, key: &str) -> Vec<ElementRole> {
+    let nodes = parse(html);
+    let mut ids = FxHashMap::default();
+    let mut labels = FxHashMap::default();
+    index(&nodes, &mut ids, &mut labels);
+    let doc = Document {
+        ids,
+        labels,
+        key: Some(key),
+        roles: Default::default(),
+    };
+    for node in &nodes {
+        doc.accessible(node, None);
+    }
+    doc.roles.into_inner()
+}
+
+/// Parses HTML into a tree, leniently, the way [`accessibility_tree`] reads
+/// it.
+#[doc(hidden)]
+pub fn parse_html(html: &str) -> Vec<Node> {
+    parse(html)
+}
+
+/// Returns the [`accessibility_tree`] for some HTML, formatted as an indented
+/// list with one node per line, for snapshot tests.
+pub fn accessibility_snapshot(html: &str) -> String {
+    accessibility_tree(html)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+impl fmt::Display for AccessibilityNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+impl AccessibilityNode {
+    fn fmt_indented(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        depth: usize,
+    ) -> fmt::Result {
+        let indent = "  ".repeat(depth);
+        match self {
+            AccessibilityNode::Text(text) => {
+                writeln!(f, "{indent}- text: {text}")
+            }
+            AccessibilityNode::Element {
+                role,
+                name,
+                states,
+                children,
+            } => {
+                write!(f, "{indent}- {role}")?;
+                if let Some(name) = name {
+                    write!(f, " {name:?}")?;
+                }
+                for (state, value) in states {
+                    if value == "true" {
+                        write!(f, " [{state}]")?;
+                    } else {
+                        write!(f, " [{state}={value}]")?;
+                    }
+                }
+                writeln!(f)?;
+                for child in children {
+                    child.fmt_indented(f, depth + 1)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub enum Node {
+    Element {
+        name: String,
+        attrs: Vec<(String, String)>,
+        children: Vec<Node>,
+    },
+    Text(String),
+}
+
+impl Node {
+    fn attr(&self, name: &str) -> Option<&str> {
+        match self {
+            Node::Element { attrs, .. } => attrs
+                .iter()
+                .find(|(attr, _)| attr == name)
+                .map(|(_, value)| value.as_str()),
+            Node::Text(_) => None,
+        }
+    }
+
+    fn has_attr(&self, name: &str) -> bool {
+        self.attr(name).is_some()
+    }
+
+    fn text_content(&self, text: &mut String) {
+        match self {
+            Node::Text(content) => text.push_str(content),
+            Node::Element { children, .. } if !is_hidden(self) => {
+                for child in children {
+                    child.text_content(text);
+                }
+            }
+            Node::Element { .. } => {}
+        }
+    }
+}
+
+#[derive(Default)]
+struct OpenElement {
+    name: String,
+    attrs: Vec<(String, String)>,
+    children: Vec<Node>,
+}
+
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta",
+    "param", "source", "track", "wbr",
+];
+
+// Parses HTML into a tree, leniently: unknown closing tags are ignored and
+// unclosed elements are closed by their parent.
+fn parse(html: &str) -> Vec<Node> {
+    // the elements that are still open, after the root
+    let mut stack = vec![OpenElement::default()];
+    let mut rest = html;
+
+    fn close(stack: &mut Vec<OpenElement>) {
+        let OpenElement {
+            name,
+            attrs,
+            children,
+        } = stack.pop().unwrap();
+        stack.last_mut().unwrap().children.push(Node::Element {
+            name,
+            attrs,
+            children,
+        });
+    }
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            push_text(&mut stack.last_mut().unwrap().children, rest);
+            break;
+        };
+        push_text(&mut stack.last_mut().unwrap().children, &rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else if let Some(tag) = rest.strip_prefix("</") {
+            let end = tag.find('>').unwrap_or(tag.len());
+            let name = tag[..end].trim().to_ascii_lowercase();
+            rest = tag.get(end + 1..).unwrap_or("");
+            if stack[1..].iter().any(|open| open.name == name) {
+                while stack.last().unwrap().name != name {
+                    close(&mut stack);
+                }
+                close(&mut stack);
+            }
+        } else {
+            let (name, attrs, self_closing, remaining) = parse_tag(&rest[1..]);
+            rest = remaining;
+            if name.is_empty() {
+                push_text(&mut stack.last_mut().unwrap().children, "<");
+                continue;
+            }
+            if self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+                stack.last_mut().unwrap().children.push(Node::Element {
+                    name,
+                    attrs,
+                    children: Vec::new(),
+                });
+            } else if matches!(
+                name.as_str(),
+                "script" | "style" | "textarea" | "title"
+            ) {
+                // raw text, up to the closing tag
+                let closing = format!("</{name}");
+                let end = rest.to_ascii_lowercase().find(&closing);
+                let (text, remaining) = match end {
+                    Some(end) => (&rest[..end], &rest[end..]),
+                    None => (rest, ""),
+                };
+                let mut children = Vec::new();
+                if name == "textarea" || name == "title" {
+                    push_text(&mut children, text);
+                }
+                stack.last_mut().unwrap().children.push(Node::Element {
+                    name,
+                    attrs,
+                    children,
+                });
+                rest =
+                    remaining.find('>').map_or("", |end| &remaining[end + 1..]);
+            } else {
+                stack.push(OpenElement {
+                    name,
+                    attrs,
+                    children: Vec::new(),
+                });
+            }
+        }
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+    stack.pop().unwrap().children
+}
+
+// Parses the rest of an opening tag, after the `<`.
+fn parse_tag(tag: &str) -> (String, Vec<(String, String)>, bool, &str) {
+    let name_end = tag
+        .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .unwrap_or(tag.len());
+    let name = tag[..name_end].to_ascii_lowercase();
+    let mut rest = &tag[name_end..];
+    let mut attrs = Vec::new();
+    loop {
+        rest = rest.trim_start();
+        if let Some(remaining) = rest.strip_prefix("/>") {
+            return (name, attrs, true, remaining);
+        } else if let Some(remaining) = rest.strip_prefix('>') {
+            return (name, attrs, false, remaining);
+        } else if let Some(remaining) = rest.strip_prefix('/') {
+            rest = remaining;
+            continue;
+        } else if rest.is_empty() {
+            return (name, attrs, false, rest);
+        }
+
+        let key_end = rest
+            .find(|c: char| {
+                c.is_whitespace() || c == '=' || c == '>' || c == '/'
+            })
+            .unwrap_or(rest.len());
+        let key = rest[..key_end].to_ascii_lowercase();
+        rest = rest[key_end..].trim_start();
+        let value = if let Some(value) = rest.strip_prefix('=') {
+            let value = value.trim_start();
+            let (value, remaining) = match value.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let value = &value[1..];
+                    let end = value.find(quote).unwrap_or(value.len());
+                    (&value[..end], value.get(end + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = value
+                        .find(|c: char| c.is_whitespace() || c == '>')
+                        .unwrap_or(value.len());
+                    (&value[..end], &value[end..])
+                }
+            };
+            rest = remaining;
+            html_escape::decode_html_entities(value).into_owned()
+        } else {
+            String::new()
+        };
+        attrs.push((key, value));
+    }
+}
+
+fn push_text(children: &mut Vec<Node>, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    let text = html_escape::decode_html_entities(text);
+    if let Some(Node::Text(prev)) = children.last_mut() {
+        prev.push_str(&text);
+    } else {
+        children.push(Node::Text(text.into_owned()));
+    }
+}
+
+// Collects the elements with an `id`, and the text of the `<label>`s for
+// each `id`.
+fn index<'a>(
+    nodes: &'a [Node],
+    ids: &mut FxHashMap<&'a str, &'a Node>,
+    labels: &mut FxHashMap<&'a str, String>,
+) {
+    for node in nodes {
+        if let Node::Element { name, children, .. } = node {
+            if let Some(id) = node.attr("id") {
+                ids.insert(id, node);
+            }
+            if let (true, Some(target)) = (name == "label", node.attr("for")) {
+                let mut text = String::new();
+                node.text_content(&mut text);
+                labels.insert(target, text);
+            }
+            index(children, ids, labels);
+        }
+    }
+}
+
+fn is_hidden(node: &Node) -> bool {
+    match node {
+        Node::Element { name, .. } => {
+            node.has_attr("hidden")
+                || node.attr("aria-hidden") == Some("true")
+                || (name == "input" && node.attr("type") == Some("hidden"))
+                || matches!(
+                    name.as_str(),
+                    "head"
+                        | "link"
+                        | "meta"
+                        | "script"
+                        | "style"
+                        | "template"
+                        | "title"
+                )
+        }
+        Node::Text(_) => false,
+    }
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+struct Document<'a> {
+    ids: FxHashMap<&'a str, &'a Node>,
+    labels: FxHashMap<&'a str, String>,
+    // the attribute that identifies the elements whose roles are recorded
+    key: Option<&'a str>,
+    roles: RefCell<Vec<ElementRole>>,
+}
+
+impl Document<'_> {
+    // `label` is the text of the `<label>` this node is in, if any
+    fn accessible(
+        &self,
+        node: &Node,
+        label: Option<&str>,
+    ) -> Vec<AccessibilityNode> {
+        let (name, children) = match node {
+            Node::Text(text) => {
+                let text = collapse_whitespace(text);
+                return if text.is_empty() {
+                    Vec::new()
+                } else {
+                    vec![AccessibilityNode::Text(text)]
+                };
+            }
+            _ if is_hidden(node) => return Vec::new(),
+            Node::Element { name, children, .. } => (name, children),
+        };
+
+        let own_label;
+        let label = if name == "label" {
+            let mut text = String::new();
+            node.text_content(&mut text);
+            own_label = collapse_whitespace(&text);
+            Some(own_label.as_str())
+        } else {
+            label
+        };
+        let mut children = children
+            .iter()
+            .flat_map(|child| self.accessible(child, label))
+            .collect::<Vec<_>>();
+
+        let Some(role) = role(node) else {
+            return children;
+        };
+        let (name, from_content) = self.name(node, &role, label);
+        if from_content {
+            children
+                .retain(|child| !matches!(child, AccessibilityNode::Text(_)));
+        }
+        // a region is only a landmark if it has a name
+        if role == "region" && name.is_none() && node.attr("role").is_none() {
+            return children;
+        }
+        if let Some(key) = self.key.and_then(|key| node.attr(key)) {
+            self.roles.borrow_mut().push(ElementRole {
+                key: key.to_string(),
+                role: role.clone(),
+                name: name.clone(),
+            });
+        }
+        vec![AccessibilityNode::Element {
+            states: states(node, &role),
+            role,
+            name,
+            children,
+        }]
+    }
+
+    // returns the accessible name, and whether it was taken from the content
+    fn name(
+        &self,
+        node: &Node,
+        role: &str,
+        label: Option<&str>,
+    ) -> (Option<String>, bool) {
+        let non_empty = |text: String| {
+            let text = collapse_whitespace(&text);
+            (!text.is_empty()).then_some(text)
+        };
+        let Node::Element { name: tag, .. } = node else {
+            return (None, false);
+        };
+
+        if let Some(ids) = node.attr("aria-labelledby") {
+            let mut text = String::new();
+            for id in ids.split_whitespace() {
+                if let Some(labelled_by) = self.ids.get(id) {
+                    if !text.is_empty() {
+                        text.push(' ');
+                    }
+                    labelled_by.text_content(&mut text);
+                }
+            }
+            if let Some(name) = non_empty(text) {
+                return (Some(name), false);
+            }
+        }
+        if let Some(name) = node.attr("aria-label").map(str::to_string) {
+            if let Some(name) = non_empty(name) {
+                return (Some(name), false);
+            }
+        }
+        if matches!(
+            tag.as_str(),
+            "input" | "meter" | "progress" | "select" | "textarea"
+        ) {
+            let labelled = node
+                .attr("id")
+                .and_then(|id| self.labels.get(id).cloned())
+                .or_else(|| label.map(str::to_string));
+            if let Some(name) = labelled.and_then(non_empty) {
+                return (Some(name), false);
+            }
+            if tag == "input" {
+                let default = match node.attr("type") {
+                    Some("submit") => Some("Submit"),
+                    Some("reset") => Some("Reset"),
+                    _ => None,
+                };
+                let value = match node.attr("type") {
+                    Some("button" | "submit" | "reset") => node.attr("value"),
+                    Some("image") => node.attr("alt"),
+                    _ => None,
+                };
+                if let Some(name) = value.or(default).map(str::to_string) {
+                    return (non_empty(name), false);
+                }
+            }
+        }
+        if matches!(tag.as_str(), "img" | "area") {
+            if let Some(alt) = node.attr("alt").map(str::to_string) {
+                return (non_empty(alt), false);
+            }
+        }
+        if NAME_FROM_CONTENT.contains(&role) {
+            let mut text = String::new();
+            node.text_content(&mut text);
+            if let Some(name) = non_empty(text) {
+                return (Some(name), true);
+            }
+        }
+        (
+            node.attr("title").map(str::to_string).and_then(non_empty),
+            false,
+        )
+    }
+}
+
+// roles whose name can come from their text content
+const NAME_FROM_CONTENT: [&str; 17] = [
+    "button",
+    "cell",
+    "checkbox",
+    "columnheader",
+    "heading",
+    "link",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "option",
+    "radio",
+    "row",
+    "rowheader",
+    "switch",
+    "tab",
+    "tooltip",
+    "treeitem",
+];
+
+fn role(node: &Node) -> Option<String> {
+    let Node::Element { name, .. } = node else {
+        return None;
+    };
+    if let Some(role) = node.attr("role").and_then(|role| {
+        role.split_whitespace().next().map(str::to_ascii_lowercase)
+    }) {
+        return (role != "none" && role != "presentation").then_some(role);
+    }
+
+    let role = match name.as_str() {
+        "a" | "area" if node.has_attr("href") => "link",
+        "article" => "article",
+        "aside" => "complementary",
+        "button" | "summary" => "button",
+        "datalist" => "listbox",
+        "details" | "fieldset" | "optgroup" => "group",
+        "dialog" => "dialog",
+        "figure" => "figure",
+        "footer" => "contentinfo",
+        "form" => "form",
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+        "header" => "banner",
+        "hr" => "separator",
+        "img" if node.attr("alt") == Some("") => return None,
+        "img" => "img",
+        "input" => match node.attr("type").unwrap_or("text") {
+            "checkbox" => "checkbox",
+            "radio" => "radio",
+            "button" | "image" | "reset" | "submit" => "button",
+            "range" => "slider",
+            "number" => "spinbutton",
+            "search" => "searchbox",
+            _ => "textbox",
+        },
+        "li" => "listitem",
+        "main" => "main",
+        "math" => "math",
+        "menu" | "ol" | "ul" => "list",
+        "meter" => "meter",
+        "nav" => "navigation",
+        "option" => "option",
+        "output" => "status",
+        "p" => "paragraph",
+        "progress" => "progressbar",
+        "section" => "region",
+        "select"
+            if node.has_attr("multiple")
+                || node.attr("size").and_then(|s| s.parse::<u32>().ok())
+                    > Some(1) =>
+        {
+            "listbox"
+        }
+        "select" => "combobox",
+        "table" => "table",
+        "tbody" | "tfoot" | "thead" => "rowgroup",
+        "td" => "cell",
+        "textarea" => "textbox",
+        "th" => "columnheader",
+        "tr" => "row",
+        _ => return None,
+    };
+    Some(role.to_string())
+}
+
+fn states(node: &Node, role: &str) -> Vec<(String, String)> {
+    let Node::Element { name, .. } = node else {
+        return Vec::new();
+    };
+    let mut states = Vec::new();
+    let mut push = |state: &str, value: &str| {
+        states.push((state.to_string(), value.to_string()))
+    };
+
+    if role == "heading" {
+        let level = node.attr("aria-level").map(str::to_string).or_else(|| {
+            name.strip_prefix('h')
+                .filter(|level| level.len() == 1)
+                .map(str::to_string)
+        });
+        push("level", level.as_deref().unwrap_or("2"));
+    }
+    if let Some(checked) = node.attr("aria-checked") {
+        push("checked", checked);
+    } else if matches!(role, "checkbox" | "radio") && node.has_attr("checked") {
+        push("checked", "true");
+    }
+    if let Some(selected) = node.attr("aria-selected") {
+        push("selected", selected);
+    } else if name == "option" && node.has_attr("selected") {
+        push("selected", "true");
+    }
+    if let Some(expanded) = node.attr("aria-expanded") {
+        push("expanded", expanded);
+    }
+    for aria in ["pressed", "current"] {
+        if let Some(value) =
+            node.attr(&format!("aria-{aria}")).filter(|v| *v != "false")
+        {
+            push(aria, value);
+        }
+    }
+    if node.has_attr("disabled") || node.attr("aria-disabled") == Some("true") {
+        push("disabled", "true");
+    }
+    if node.has_attr("required") || node.attr("aria-required") == Some("true") {
+        push("required", "true");
+    }
+    if node.attr("aria-invalid").is_some_and(|v| v != "false") {
+        push("invalid", "true");
+    }
+    states
+}
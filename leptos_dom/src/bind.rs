@@ -0,0 +1,156 @@
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+use crate::{
+    ev,
+    helpers::{event_target_checked, event_target_value},
+};
+use crate::{html::ElementDescriptor, HtmlElement};
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+use leptos_reactive::SignalGet;
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+use leptos_reactive::SignalGetUntracked;
+use leptos_reactive::{RwSignal, SignalSet, SignalUpdate, SignalWith};
+
+/// A signal that a group of radio buttons or checkboxes can be bound to with
+/// [`HtmlElement::bind_group`], or `bind:group` in the `view` macro.
+///
+/// A `RwSignal<String>` holds the value of the one radio button that is
+/// checked, and a `RwSignal<Vec<String>>` holds the values of all the
+/// checkboxes that are checked.
+pub trait BindGroup: Copy + 'static {
+    /// Whether the input with this value should be checked.
+    fn is_checked(&self, value: &str) -> bool;
+
+    /// Updates the signal after the input with this value was checked or
+    /// unchecked.
+    fn set_checked(&self, value: String, checked: bool);
+}
+
+impl BindGroup for RwSignal<String> {
+    fn is_checked(&self, value: &str) -> bool {
+        self.with(|selected| selected == value)
+    }
+
+    fn set_checked(&self, value: String, checked: bool) {
+        if checked {
+            self.set(value);
+        }
+    }
+}
+
+impl BindGroup for RwSignal<Vec<String>> {
+    fn is_checked(&self, value: &str) -> bool {
+        self.with(|selected| selected.iter().any(|selected| selected == value))
+    }
+
+    fn set_checked(&self, value: String, checked: bool) {
+        self.update(|selected| {
+            let position = selected.iter().position(|v| *v == value);
+            match (checked, position) {
+                (true, None) => selected.push(value),
+                (false, Some(position)) => {
+                    selected.remove(position);
+                }
+                _ => {}
+            }
+        });
+    }
+}
+
+impl<El: ElementDescriptor + 'static> HtmlElement<El> {
+    /// Binds the `value` of an `<input>`, `<textarea>` or `<select>` to a
+    /// signal, in both directions: the element shows the signal’s value, and
+    /// the signal is set whenever the user changes it.
+    ///
+    /// This is what `bind:value` does in the `view` macro.
+    ///
+    /// **Note**: A `<select>` ignores a `value` that doesn’t match any of its
+    /// options yet, so this should be called after its options have been
+    /// added. The `view` macro takes care of this. When rendered on the
+    /// server, the selected option is only shown once the page is hydrated.
+    #[track_caller]
+    pub fn bind_value(self, value: RwSignal<String>) -> Self {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            let this = self.prop("value", move || value.get());
+            if this.element.name() == "select" {
+                this.on(ev::change, move |ev| {
+                    value.set(event_target_value(&ev))
+                })
+            } else {
+                this.on(ev::input, move |ev| value.set(event_target_value(&ev)))
+            }
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            match self.element.name().as_ref() {
+                "textarea" => {
+                    let text = value.get_untracked();
+                    self.child(text)
+                }
+                "select" => self,
+                _ => self.attr("value", value.get_untracked()),
+            }
+        }
+    }
+
+    /// Binds whether a checkbox is checked to a signal, in both directions:
+    /// the checkbox shows the signal’s value, and the signal is set whenever
+    /// the user checks or unchecks it.
+    ///
+    /// This is what `bind:checked` does in the `view` macro.
+    #[track_caller]
+    pub fn bind_checked(self, checked: RwSignal<bool>) -> Self {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            self.prop("checked", move || checked.get())
+                .on(ev::change, move |ev| {
+                    checked.set(event_target_checked(&ev))
+                })
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            self.attr("checked", checked.get_untracked())
+        }
+    }
+
+    /// Binds a radio button or checkbox to a [`BindGroup`] signal shared by
+    /// every input in its group, in both directions: the input is checked if
+    /// the signal holds its `value`, and the signal is updated whenever the
+    /// user checks or unchecks it.
+    ///
+    /// This is what `bind:group` does in the `view` macro. The input’s `value`
+    /// should be set before this is called.
+    #[track_caller]
+    pub fn bind_group(self, group: impl BindGroup) -> Self {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            use wasm_bindgen::JsCast;
+
+            let input = self
+                .element
+                .as_ref()
+                .clone()
+                .unchecked_into::<web_sys::HtmlInputElement>();
+            self.prop("checked", move || group.is_checked(&input.value()))
+                .on(ev::change, move |ev| {
+                    group.set_checked(
+                        event_target_value(&ev),
+                        event_target_checked(&ev),
+                    )
+                })
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            let checked = self
+                .attrs
+                .iter()
+                .find(|(name, _)| name == "value")
+                .map(|(_, value)| group.is_checked(value))
+                .unwrap_or(false);
+            self.attr("checked", checked)
+        }
+    }
+}
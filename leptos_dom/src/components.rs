@@ -1,7 +1,11 @@
+mod deferred_hydration;
 mod dyn_child;
 mod each;
 mod errors;
 mod fragment;
+mod island;
+mod memo;
+mod shadow_root;
 mod unit;
 
 use crate::{
@@ -10,13 +14,17 @@ use crate::{
 };
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use crate::{mount_child, prepare_to_move, MountKind, Mountable};
+pub use deferred_hydration::*;
 pub use dyn_child::*;
 pub use each::*;
 pub use errors::*;
 pub use fragment::*;
+pub use island::*;
 use leptos_reactive::Scope;
+pub use memo::*;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use once_cell::unsync::OnceCell;
+pub use shadow_root::*;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use std::rc::Rc;
 use std::{borrow::Cow, fmt};
@@ -264,9 +272,26 @@ where
 
         let mut repr = ComponentRepr::new_with_id(name, id);
 
-        // disposed automatically when the parent scope is disposed
-        let (child, _) = cx
-            .run_child_scope(|cx| cx.untrack(|| children_fn(cx).into_view(cx)));
+        let create_child = || {
+            // disposed automatically when the parent scope is disposed
+            let (child, _) = cx.run_child_scope(|cx| {
+                cx.untrack(|| children_fn(cx).into_view(cx))
+            });
+            child
+        };
+        #[cfg(all(
+            debug_assertions,
+            target_arch = "wasm32",
+            feature = "hydrate"
+        ))]
+        let child =
+            crate::hydration::in_component(repr.name.clone(), create_child);
+        #[cfg(not(all(
+            debug_assertions,
+            target_arch = "wasm32",
+            feature = "hydrate"
+        )))]
+        let child = create_child();
 
         repr.children.push(child);
 
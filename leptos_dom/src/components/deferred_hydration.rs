@@ -0,0 +1,211 @@
+use crate::{
+    html::{Custom, HtmlElement},
+    IntoView, View,
+};
+use cfg_if::cfg_if;
+use leptos_reactive::Scope;
+
+cfg_if! {
+  if #[cfg(all(target_arch = "wasm32", feature = "hydrate"))] {
+    use crate::hydration::{HydrationCtx, HydrationKey};
+    use leptos_reactive::on_cleanup;
+    use std::{cell::Cell, rc::Rc};
+    use wasm_bindgen::{prelude::Closure, JsCast, JsValue, UnwrapThrowExt};
+
+    // The events that cause a component with `HydrationStrategy::Interaction`
+    // to hydrate.
+    const INTERACTION_EVENTS: [&str; 3] = ["pointerdown", "focusin", "keydown"];
+
+    fn on_idle(hydrate: Rc<dyn Fn() -> bool>) {
+        crate::helpers::request_idle_callback(move || {
+            hydrate();
+        });
+    }
+
+    fn on_visible(el: &web_sys::HtmlElement, hydrate: Rc<dyn Fn() -> bool>) {
+        // the wrapper is `display: contents`, so it has no box of its own
+        let children = el.children();
+        if children.length() == 0 {
+            on_idle(hydrate);
+            return;
+        }
+
+        let on_intersect = Closure::wrap(Box::new(
+            move |entries: js_sys::Array, observer: web_sys::IntersectionObserver| {
+                let visible = entries.iter().any(|entry| {
+                    entry
+                        .unchecked_into::<web_sys::IntersectionObserverEntry>()
+                        .is_intersecting()
+                });
+                if visible {
+                    observer.disconnect();
+                    hydrate();
+                }
+            },
+        )
+            as Box<dyn FnMut(js_sys::Array, web_sys::IntersectionObserver)>)
+        .into_js_value();
+        let observer =
+            web_sys::IntersectionObserver::new(on_intersect.unchecked_ref())
+                .unwrap_throw();
+        for i in 0..children.length() {
+            if let Some(child) = children.item(i) {
+                observer.observe(&child);
+            }
+        }
+    }
+
+    fn on_interaction(
+        el: &web_sys::HtmlElement,
+        hydrate: Rc<dyn Fn() -> bool>,
+    ) {
+        let listener = Rc::new(Cell::new(None::<JsValue>));
+        let on_event = Closure::wrap(Box::new({
+            let el = el.clone();
+            let listener = Rc::clone(&listener);
+            move |ev: web_sys::Event| {
+                if let Some(listener) = listener.take() {
+                    for name in INTERACTION_EVENTS {
+                        _ = el.remove_event_listener_with_callback(
+                            name,
+                            listener.unchecked_ref(),
+                        );
+                    }
+                }
+                if hydrate() {
+                    crate::events::replay_event(&ev);
+                }
+            }
+        }) as Box<dyn FnMut(web_sys::Event)>)
+        .into_js_value();
+        for name in INTERACTION_EVENTS {
+            _ = el.add_event_listener_with_callback(
+                name,
+                on_event.unchecked_ref(),
+            );
+        }
+        listener.set(Some(on_event));
+    }
+  } else if #[cfg(not(all(target_arch = "wasm32", feature = "web")))] {
+    use crate::hydration::HydrationCtx;
+  }
+}
+
+/// When a server-rendered component created with `#[component(hydrate = "...")]`
+/// is hydrated in the browser.
+///
+/// Until it is hydrated, the server-rendered HTML is shown as it is, but none
+/// of the component’s event listeners or reactive updates are attached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HydrationStrategy {
+    /// Hydrates once any part of the component scrolls into the viewport, using
+    /// an [`IntersectionObserver`](https://developer.mozilla.org/en-US/docs/Web/API/Intersection_Observer_API).
+    Visible,
+    /// Hydrates when the browser is idle, using
+    /// [`requestIdleCallback`](https://developer.mozilla.org/en-US/docs/Web/API/Window/requestIdleCallback).
+    Idle,
+    /// Hydrates when the user first presses a pointer, focuses or types inside
+    /// the component. The event that triggered hydration is then passed on to
+    /// the component’s own event listeners.
+    Interaction,
+}
+
+/// A component created with `#[component(hydrate = "...")]`, which defers its
+/// hydration until the [`HydrationStrategy`] says it is needed.
+///
+/// On the server, the component is rendered as usual, inside a
+/// `<leptos-hydrate>` element that records the hydration IDs it used. In the
+/// browser, hydration skips over those IDs, and comes back to hydrate the
+/// component later. Components that are rendered in the browser without being
+/// hydrated are rendered immediately.
+#[doc(hidden)]
+pub struct DeferredHydration<F>
+where
+    F: FnOnce(Scope) -> View + 'static,
+{
+    strategy: HydrationStrategy,
+    render: F,
+}
+
+impl<F> DeferredHydration<F>
+where
+    F: FnOnce(Scope) -> View + 'static,
+{
+    /// Creates a new component with deferred hydration.
+    pub fn new(strategy: HydrationStrategy, render: F) -> Self {
+        Self { strategy, render }
+    }
+}
+
+impl<F> IntoView for DeferredHydration<F>
+where
+    F: FnOnce(Scope) -> View + 'static,
+{
+    fn into_view(self, cx: Scope) -> View {
+        let Self { strategy, render } = self;
+        let wrapper = HtmlElement::new(cx, Custom::new("leptos-hydrate"))
+            .attr("style", "display: contents");
+
+        cfg_if! {
+          if #[cfg(all(target_arch = "wasm32", feature = "hydrate"))] {
+            let el = wrapper.element.as_ref().clone();
+            let key = |name| {
+                el.get_attribute(name)?.parse::<HydrationKey>().ok()
+            };
+            let (Some(start), Some(end), true) = (
+                key("data-hk-start"),
+                key("data-hk-end"),
+                HydrationCtx::is_hydrating(),
+            ) else {
+                return wrapper.child(render(cx)).into_view(cx);
+            };
+            // pick up hydrating the rest of the page after this component
+            HydrationCtx::continue_from(end);
+
+            let disposed = Rc::new(Cell::new(false));
+            on_cleanup(cx, {
+                let disposed = Rc::clone(&disposed);
+                move || disposed.set(true)
+            });
+
+            // returns `false` if the component had already been hydrated
+            let render = Cell::new(Some(render));
+            let hydrate: Rc<dyn Fn() -> bool> = Rc::new(move || {
+                let Some(render) = render.take() else {
+                    return false;
+                };
+                if disposed.get() {
+                    return false;
+                }
+                let view = HydrationCtx::hydrate_from(start, || render(cx));
+                // the view's nodes are already in the DOM, inside the wrapper
+                std::mem::forget(view);
+                true
+            });
+
+            match strategy {
+                HydrationStrategy::Idle => on_idle(hydrate),
+                HydrationStrategy::Visible => on_visible(&el, hydrate),
+                HydrationStrategy::Interaction => on_interaction(&el, hydrate),
+            }
+
+            wrapper.into_view(cx)
+          } else if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+            // there is never any server-rendered HTML to hydrate
+            _ = strategy;
+            wrapper.child(render(cx)).into_view(cx)
+          } else {
+            _ = strategy;
+            let start = HydrationCtx::peek();
+            let view = render(cx);
+            let end = HydrationCtx::peek();
+
+            wrapper
+                .attr("data-hk-start", start.to_string())
+                .attr("data-hk-end", end.to_string())
+                .child(view)
+                .into_view(cx)
+          }
+        }
+    }
+}
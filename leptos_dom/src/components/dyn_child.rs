@@ -7,7 +7,11 @@ use leptos_reactive::Scope;
 use std::{borrow::Cow, cell::RefCell, fmt, ops::Deref, rc::Rc};
 cfg_if! {
   if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
-    use crate::{mount_child, prepare_to_move, unmount_child, MountKind, Mountable};
+    use crate::{
+      mount_child, prepare_to_move,
+      renderer::{Dom, Renderer},
+      unmount_child, MountKind, Mountable,
+    };
     use leptos_reactive::{create_effect, ScopeDisposer};
     use wasm_bindgen::JsCast;
   }
@@ -247,11 +251,11 @@ where
                             else {
                                 if !was_child_moved && child != new_child {
                                     // Remove the text
-                                    closing
-                                        .previous_non_view_marker_sibling()
-                                        .unwrap()
-                                        .unchecked_into::<web_sys::Element>()
-                                        .remove();
+                                    Dom.remove(
+                                        &closing
+                                            .previous_non_view_marker_sibling()
+                                            .unwrap(),
+                                    );
                                 }
 
                                 // Mount the new child, and we're done
@@ -337,17 +341,27 @@ where
                                 .unwrap()
                                 .unchecked_into::<web_sys::Element>();
 
+                            #[cfg(all(debug_assertions, feature = "hydrate"))]
+                            if t.node_type() == web_sys::Node::TEXT_NODE {
+                                let server =
+                                    t.text_content().unwrap_or_default();
+                                let client =
+                                    &new_child.get_text().unwrap().content;
+                                if &server != client {
+                                    crate::hydration::text_mismatch(
+                                        &server, client,
+                                    );
+                                }
+                            }
+
                             // See note on ssr.rs when matching on `DynChild`
                             // for more details on why we need to do this for
                             // release
                             if !cfg!(debug_assertions) {
-                                t.previous_sibling()
-                                    .unwrap()
-                                    .unchecked_into::<web_sys::Element>()
-                                    .remove();
+                                Dom.remove(&t.previous_sibling().unwrap());
                             }
 
-                            t.remove();
+                            Dom.remove(&t);
 
                             mount_child(
                                 MountKind::Before(&closing),
@@ -375,7 +389,25 @@ where
             );
 
             #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
-            {
+            if crate::renderer::reactive_views() {
+                use leptos_reactive::{
+                    create_isomorphic_effect, ScopeDisposer,
+                };
+
+                // the child is kept up to date so it can be rendered again
+                create_isomorphic_effect(
+                    cx,
+                    move |prev_disposer: Option<ScopeDisposer>| {
+                        let (new_child, disposer) =
+                            cx.run_child_scope(|cx| child_fn().into_view(cx));
+                        **child.borrow_mut() = Some(new_child);
+                        if let Some(prev_disposer) = prev_disposer {
+                            prev_disposer.dispose();
+                        }
+                        disposer
+                    },
+                );
+            } else {
                 let new_child = child_fn().into_view(cx);
 
                 **child.borrow_mut() = Some(new_child);
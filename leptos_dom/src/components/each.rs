@@ -9,41 +9,19 @@ use web::*;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 mod web {
     pub(crate) use crate::{
-        mount_child, prepare_to_move, MountKind, Mountable, RANGE,
+        mount_child, prepare_to_move,
+        renderer::{Dom, Renderer},
+        MountKind, Mountable, RANGE,
     };
-    pub use drain_filter_polyfill::VecExt as VecDrainFilterExt;
     pub use leptos_reactive::create_effect;
     pub use std::cell::OnceCell;
     pub use wasm_bindgen::JsCast;
 }
 
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
+#[cfg(any(test, all(target_arch = "wasm32", feature = "web")))]
 type FxIndexSet<T> =
     indexmap::IndexSet<T, std::hash::BuildHasherDefault<rustc_hash::FxHasher>>;
 
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
-trait VecExt {
-    fn get_next_closest_mounted_sibling(
-        &self,
-        start_at: usize,
-        or: web_sys::Node,
-    ) -> web_sys::Node;
-}
-
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
-impl VecExt for Vec<Option<EachItem>> {
-    fn get_next_closest_mounted_sibling(
-        &self,
-        start_at: usize,
-        or: web_sys::Node,
-    ) -> web_sys::Node {
-        self[start_at..]
-            .iter()
-            .find_map(|s| s.as_ref().map(|s| s.get_opening_node()))
-            .unwrap_or(or)
-    }
-}
-
 /// The internal representation of the [`Each`] core-component.
 #[derive(Clone, PartialEq, Eq)]
 pub struct EachRepr {
@@ -304,7 +282,16 @@ impl EachItem {
             fragment.append_with_node_1(end).unwrap();
         } else {
             let node = self.child.get_mountable_node();
-            node.unchecked_into::<web_sys::Element>().remove();
+            Dom.remove(&node);
+        }
+    }
+
+    /// The element this item renders, if its view is a single element.
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    fn element(&self) -> Option<&web_sys::HtmlElement> {
+        match &self.child {
+            View::Element(el) => Some(&el.element),
+            _ => None,
         }
     }
 }
@@ -323,6 +310,7 @@ where
     pub(crate) items_fn: IF,
     pub(crate) each_fn: EF,
     key_fn: KF,
+    animations: EachAnimations,
 }
 
 impl<IF, I, T, EF, N, KF, K> Each<IF, I, T, EF, N, KF, K>
@@ -342,8 +330,90 @@ where
             items_fn,
             each_fn,
             key_fn,
+            animations: EachAnimations::new(),
+        }
+    }
+
+    /// Sets the hooks that are called as items are added, removed and moved.
+    #[inline(always)]
+    pub fn animations(mut self, animations: EachAnimations) -> Self {
+        self.animations = animations;
+        self
+    }
+}
+
+/// Hooks that are called as the items of an [`Each`] are added, removed and
+/// moved, so that they can be animated, for example with the
+/// [Web Animations API](https://developer.mozilla.org/en-US/docs/Web/API/Web_Animations_API)
+/// or by toggling classes.
+///
+/// The hooks are only called for items whose view is a single element, and
+/// only in the browser. They are not called for the items of the first render.
+#[derive(Clone, Default)]
+pub struct EachAnimations {
+    enter: Option<EnterHook>,
+    exit: Option<ExitHook>,
+    moved: Option<MoveHook>,
+}
+
+type EnterHook = Rc<dyn Fn(&web_sys::HtmlElement)>;
+type ExitHook = Rc<dyn Fn(&web_sys::HtmlElement, Box<dyn FnOnce()>)>;
+type MoveHook = Rc<dyn Fn(&web_sys::HtmlElement, web_sys::DomRect)>;
+
+impl EachAnimations {
+    /// Creates a set of hooks that do nothing.
+    pub const fn new() -> Self {
+        Self {
+            enter: None,
+            exit: None,
+            moved: None,
         }
     }
+
+    /// Called with the element of each item that is added, once it has been
+    /// inserted into the DOM.
+    pub fn on_enter(
+        mut self,
+        f: impl Fn(&web_sys::HtmlElement) + 'static,
+    ) -> Self {
+        self.enter = Some(Rc::new(f));
+        self
+    }
+
+    /// Called with the element of each item that is removed, instead of
+    /// removing it from the DOM right away. The item's scope is disposed, but
+    /// its element stays where it was until the function passed as the second
+    /// argument is called, so that it can be animated out first.
+    pub fn on_exit(
+        mut self,
+        f: impl Fn(&web_sys::HtmlElement, Box<dyn FnOnce()>) + 'static,
+    ) -> Self {
+        self.exit = Some(Rc::new(f));
+        self
+    }
+
+    /// Called with the element of each item that was kept, but has changed
+    /// position on the page, along with where it was before the list changed.
+    /// This is everything needed for a
+    /// [FLIP](https://aerotwist.com/blog/flip-your-animations/) animation:
+    /// the new position can be measured with `get_bounding_client_rect()`.
+    pub fn on_move(
+        mut self,
+        f: impl Fn(&web_sys::HtmlElement, web_sys::DomRect) + 'static,
+    ) -> Self {
+        self.moved = Some(Rc::new(f));
+        self
+    }
+}
+
+impl fmt::Debug for EachAnimations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EachAnimations")
+            .field("on_enter", &self.enter.is_some())
+            .field("on_exit", &self.exit.is_some())
+            .field("on_move", &self.moved.is_some())
+            .finish()
+    }
 }
 
 impl<IF, I, T, EF, N, KF, K> IntoView for Each<IF, I, T, EF, N, KF, K>
@@ -365,10 +435,11 @@ where
             items_fn,
             each_fn,
             key_fn,
+            animations,
         } = self;
 
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
-        let _ = key_fn;
+        let _ = animations;
 
         let component = EachRepr::default();
 
@@ -410,6 +481,7 @@ where
                     Default::default(),
                 );
 
+                let is_first_run = prev_hash_run.is_none();
                 if let Some(HashRun(prev_hash_run)) = prev_hash_run {
                     if !prev_hash_run.is_empty() {
                         let mut items = Vec::with_capacity(capacity);
@@ -436,6 +508,7 @@ where
                             &mut children_borrow,
                             items,
                             &each_fn,
+                            &animations,
                         );
                         return HashRun(hashed_items);
                     }
@@ -466,12 +539,62 @@ where
                     .before_with_node_1(&fragment)
                     .expect("before to not err");
 
+                if let (false, Some(enter)) = (is_first_run, &animations.enter)
+                {
+                    for el in children_borrow
+                        .iter()
+                        .filter_map(|child| child.as_ref()?.element())
+                    {
+                        enter(el);
+                    }
+                }
+
                 HashRun(hashed_items)
             },
         );
 
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
-        {
+        if crate::renderer::reactive_views() {
+            let children = component.children.clone();
+            // the list is kept up to date so it can be rendered again, and
+            // items whose key is still in it keep their state
+            leptos_reactive::create_isomorphic_effect(
+                cx,
+                move |prev_keys: Option<HashRun<Vec<K>>>| {
+                    let mut children = children.borrow_mut();
+                    let mut kept = rustc_hash::FxHashMap::default();
+                    if let Some(HashRun(prev_keys)) = prev_keys {
+                        kept.extend(
+                            prev_keys
+                                .into_iter()
+                                .zip(children.drain(..))
+                                .filter_map(|(key, item)| Some((key, item?))),
+                        );
+                    }
+                    let mut keys = Vec::new();
+                    *children = items_fn()
+                        .into_iter()
+                        .map(|item| {
+                            let key = key_fn(&item);
+                            let each_item =
+                                kept.remove(&key).unwrap_or_else(|| {
+                                    cx.run_child_scope(|cx| {
+                                        EachItem::new(
+                                            cx,
+                                            each_fn(cx, item).into_view(cx),
+                                        )
+                                    })
+                                    .0
+                                });
+                            keys.push(key);
+                            Some(each_item)
+                        })
+                        .collect();
+                    HashRun(keys)
+                },
+            );
+        } else {
+            _ = key_fn;
             *component.children.borrow_mut() = (items_fn)()
                 .into_iter()
                 .map(|child| {
@@ -494,295 +617,162 @@ where
 #[educe(Debug)]
 struct HashRun<T>(#[educe(Debug(ignore))] T);
 
-/// Calculates the operations need to get from `a` to `b`.
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
-fn diff<K: Eq + Hash>(from: &FxIndexSet<K>, to: &FxIndexSet<K>) -> Diff {
-    if from.is_empty() && to.is_empty() {
-        return Diff::default();
-    } else if to.is_empty() {
-        return Diff {
-            clear: true,
-            ..Default::default()
-        };
-    } else if from.is_empty() {
-        return Diff {
-            added: to
-                .iter()
-                .enumerate()
-                .map(|(at, _)| DiffOpAdd {
-                    at,
-                    mode: DiffOpAddMode::Append,
-                })
-                .collect(),
-            ..Default::default()
-        };
-    }
-
-    // Get removed items
-    let removed = from.difference(to);
-
-    let remove_cmds = removed
-        .clone()
-        .map(|k| from.get_full(k).unwrap().0)
-        .map(|idx| DiffOpRemove { at: idx });
-
-    // Get added items
-    let added = to.difference(from);
-
-    let add_cmds =
-        added
-            .clone()
-            .map(|k| to.get_full(k).unwrap().0)
-            .map(|idx| DiffOpAdd {
-                at: idx,
-                mode: Default::default(),
-            });
-
-    // Get items that might have moved
-    let from_moved = from.intersection(&to).collect::<FxIndexSet<_>>();
-    let to_moved = to.intersection(&from).collect::<FxIndexSet<_>>();
-
-    let move_cmds = find_ranges(from_moved, to_moved, from, to);
-
-    let mut diff = Diff {
-        removed: remove_cmds.collect(),
-        items_to_move: move_cmds.iter().map(|range| range.len).sum(),
-        moved: move_cmds,
-        added: add_cmds.collect(),
-        clear: false,
-    };
-
-    apply_opts(from, to, &mut diff);
-
-    #[cfg(test)]
-    {
-        let mut adds_sorted = diff.added.clone();
-        adds_sorted.sort_unstable_by_key(|add| add.at);
-
-        assert_eq!(diff.added, adds_sorted, "adds must be sorted");
-
-        let mut moves_sorted = diff.moved.clone();
-        moves_sorted.sort_unstable_by_key(|move_| move_.to);
+/// Statistics about the keyed diffs [`Each`] has performed on this thread,
+/// which can be used to check how much DOM work a list update caused.
+/// Only available in debug builds.
+#[cfg(debug_assertions)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct EachDiffStats {
+    /// The number of times a list was diffed.
+    pub diffs: usize,
+    /// The number of items that were created.
+    pub added: usize,
+    /// The number of items that were removed.
+    pub removed: usize,
+    /// The number of items that were kept, but had to be moved in the DOM.
+    pub moved: usize,
+    /// The number of items that were kept without being moved in the DOM.
+    pub unmoved: usize,
+    /// The number of times every item was removed at once.
+    pub cleared: usize,
+}
 
-        assert_eq!(diff.moved, moves_sorted, "moves must be sorted by `to`");
-    }
+#[cfg(debug_assertions)]
+thread_local! {
+    static DIFF_STATS: std::cell::Cell<EachDiffStats> = Default::default();
+}
 
-    diff
+/// Returns the [`EachDiffStats`] collected on this thread since the last call,
+/// and resets them. Only available in debug builds.
+#[cfg(debug_assertions)]
+pub fn take_each_diff_stats() -> EachDiffStats {
+    DIFF_STATS.with(|stats| stats.take())
 }
 
-/// Builds and returns the ranges of items that need to
-/// move sorted by `to`.
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
-fn find_ranges<K: Eq + Hash>(
-    from_moved: FxIndexSet<&K>,
-    to_moved: FxIndexSet<&K>,
-    from: &FxIndexSet<K>,
-    to: &FxIndexSet<K>,
-) -> Vec<DiffOpMove> {
-    let mut ranges = Vec::with_capacity(from.len());
-    let mut prev_to_moved_index = 0;
-    let mut range = DiffOpMove::default();
-
-    for (i, k) in from_moved.into_iter().enumerate() {
-        let to_moved_index = to_moved.get_index_of(k).unwrap();
-
-        if i == 0 {
-            range.from = from.get_index_of(k).unwrap();
-            range.to = to.get_index_of(k).unwrap();
-        }
-        // The range continues
-        else if to_moved_index == prev_to_moved_index + 1 {
-            range.len += 1;
-        }
-        // We're done with this range, start a new one
-        else {
-            ranges.push(std::mem::take(&mut range));
+/// Calculates the operations needed to get from `from` to `to`.
+///
+/// Items that are kept are only moved in the DOM if they are not part of the
+/// longest run of kept items whose relative order has not changed, which is
+/// the fewest moves needed to reorder them.
+#[cfg(any(test, all(target_arch = "wasm32", feature = "web")))]
+fn diff<K: Eq + Hash>(from: &FxIndexSet<K>, to: &FxIndexSet<K>) -> Diff {
+    let removed = from
+        .iter()
+        .enumerate()
+        .filter(|(_, k)| !to.contains(*k))
+        .map(|(at, _)| DiffOpRemove { at })
+        .collect::<Vec<_>>();
 
-            range.from = from.get_index_of(k).unwrap();
-            range.to = to.get_index_of(k).unwrap();
+    let mut moved = Vec::with_capacity(to.len());
+    let mut added = Vec::new();
+    for (at, k) in to.iter().enumerate() {
+        match from.get_index_of(k) {
+            Some(from) => moved.push(DiffOpMove {
+                from,
+                to: at,
+                move_in_dom: true,
+            }),
+            None => added.push(DiffOpAdd { at }),
         }
-
-        prev_to_moved_index = to_moved_index;
     }
 
-    ranges.push(std::mem::take(&mut range));
-
-    // We need to remove ranges that didn't move relative to each other
-    // as well as marking items that don't need to move in the DOM
-    let mut to_ranges = ranges.clone();
-    to_ranges.sort_unstable_by_key(|range| range.to);
-
-    let mut filtered_ranges = vec![];
-
-    let to_ranges_len = to_ranges.len();
-
-    for (i, range) in to_ranges.into_iter().enumerate() {
-        if range != ranges[i] {
-            filtered_ranges.push(range);
-        }
-        // The item did move, just not in the DOM
-        else if range.from != range.to {
-            filtered_ranges.push(DiffOpMove {
-                move_in_dom: false,
-                ..range
-            });
-        } else if to_ranges_len > 2 {
-            // TODO: Remove this else case...this is one of the biggest
-            // optimizations we can do, but we're skipping this right now
-            // until we figure out a way to handle moving around ranges
-            // that did not move
-            filtered_ranges.push(range);
-        }
+    let sources = moved.iter().map(|move_| move_.from).collect::<Vec<_>>();
+    for i in longest_increasing_subsequence(&sources) {
+        moved[i].move_in_dom = false;
     }
 
-    filtered_ranges
-}
-
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
-fn apply_opts<K: Eq + Hash>(
-    from: &FxIndexSet<K>,
-    to: &FxIndexSet<K>,
-    cmds: &mut Diff,
-) {
-    optimize_moves(&mut cmds.moved);
-
-    // We can optimize the case of replacing all items
-    if !from.is_empty()
-        && !to.is_empty()
-        && cmds.removed.len() == from.len()
-        && cmds.moved.is_empty()
-    {
-        cmds.clear = true;
-
-        cmds.added
-            .iter_mut()
-            .for_each(|op| op.mode = DiffOpAddMode::Append);
+    let diff = Diff {
+        clear: !from.is_empty() && moved.is_empty(),
+        removed,
+        moved,
+        added,
+    };
 
-        return;
-    }
+    #[cfg(debug_assertions)]
+    DIFF_STATS.with(|stats| {
+        let mut total = stats.get();
+        let moved_in_dom =
+            diff.moved.iter().filter(|move_| move_.move_in_dom).count();
+        total.diffs += 1;
+        total.added += diff.added.len();
+        total.removed += diff.removed.len();
+        total.moved += moved_in_dom;
+        total.unmoved += diff.moved.len() - moved_in_dom;
+        total.cleared += usize::from(diff.clear);
+        stats.set(total);
+    });
 
-    // We can optimize appends.
-    if !cmds.added.is_empty()
-        && cmds.moved.is_empty()
-        && cmds.removed.is_empty()
-        && cmds.added[0].at >= from.len()
-    {
-        cmds.added
-            .iter_mut()
-            .for_each(|op| op.mode = DiffOpAddMode::Append);
-    }
+    diff
 }
 
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
-fn optimize_moves(moves: &mut Vec<DiffOpMove>) {
-    if moves.is_empty() || moves.len() == 1 {
-        // Do nothing
-    }
-    // This is the easiest optimal move case, which is to
-    // simply swap the 2 ranges. We only need to move the range
-    // that is smallest.
-    else if moves.len() == 2 {
-        if moves[1].len < moves[0].len {
-            moves[0].move_in_dom = false;
+/// Returns the indices of one of the longest strictly increasing subsequences
+/// of `values`, in order.
+#[cfg(any(test, all(target_arch = "wasm32", feature = "web")))]
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<usize> {
+    // `tails[n]` is the index of the smallest value that ends an increasing
+    // subsequence of length `n + 1`
+    let mut tails: Vec<usize> = Vec::new();
+    let mut prev = vec![None; values.len()];
+
+    for (i, value) in values.iter().enumerate() {
+        let len = tails.partition_point(|tail| values[*tail] < *value);
+        if len > 0 {
+            prev[i] = Some(tails[len - 1]);
+        }
+        if len == tails.len() {
+            tails.push(i);
         } else {
-            moves[1].move_in_dom = false;
+            tails[len] = i;
         }
     }
-    // Interestingly enoughs, there are NO configuration that are possible
-    // for ranges of 3.
-    //
-    // For example, take A, B, C. Here are all possible configurations and
-    // reasons for why they are impossible:
-    // - A B C  # identity, would be removed by ranges that didn't move
-    // - A C B  # `A` would be removed, thus it's a case of length 2
-    // - B A C  # `C` would be removed, thus it's a case of length 2
-    // - B C A  # `B C` are congiguous, so this is would have been a single range
-    // - C A B  # `A B` are congiguous, so this is would have been a single range
-    // - C B A  # `B` would be removed, thus it's a case of length 2
-    //
-    // We can add more pre-computed tables here if benchmarking or
-    // user demand needs it...nevertheless, it is unlikely for us
-    // to implement this algorithm to handle N ranges, because this
-    // becomes exponentially more expensive to compute. It's faster,
-    // for the most part, to assume the ranges are random and move
-    // all the ranges around than to try and figure out the best way
-    // to move them
-    else {
-        // The idea here is that for N ranges, we never need to
-        // move the largest range, rather, have all ranges move
-        // around it.
-        let move_ = moves.iter_mut().max_by_key(|move_| move_.len).unwrap();
-
-        move_.move_in_dom = false;
+
+    let mut subsequence = Vec::with_capacity(tails.len());
+    let mut next = tails.last().copied();
+    while let Some(i) = next {
+        subsequence.push(i);
+        next = prev[i];
     }
+    subsequence.reverse();
+    subsequence
 }
 
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
+#[cfg(any(test, all(target_arch = "wasm32", feature = "web")))]
 #[derive(Debug, Default, PartialEq, Eq)]
 struct Diff {
     removed: Vec<DiffOpRemove>,
+    /// Every item that is kept, sorted by `to`.
     moved: Vec<DiffOpMove>,
-    items_to_move: usize,
+    /// Sorted by `at`.
     added: Vec<DiffOpAdd>,
     clear: bool,
 }
 
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
+#[cfg(any(test, all(target_arch = "wasm32", feature = "web")))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct DiffOpMove {
-    /// The index this range is starting relative to `from`.
+    /// The index of the item relative to `from`.
     from: usize,
-    /// The number of elements included in this range.
-    len: usize,
-    /// The starting index this range will be moved to relative to `to`.
+    /// The index the item will be moved to relative to `to`.
     to: usize,
     /// Marks this move to be applied to the DOM, or just to the underlying
     /// storage
     move_in_dom: bool,
 }
 
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
-impl Default for DiffOpMove {
-    fn default() -> Self {
-        Self {
-            from: 0,
-            to: 0,
-            len: 1,
-            move_in_dom: true,
-        }
-    }
-}
-
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg(any(test, all(target_arch = "wasm32", feature = "web")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 struct DiffOpAdd {
     at: usize,
-    mode: DiffOpAddMode,
 }
 
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
+#[cfg(any(test, all(target_arch = "wasm32", feature = "web")))]
 #[derive(Debug, PartialEq, Eq)]
 struct DiffOpRemove {
     at: usize,
 }
 
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-enum DiffOpAddMode {
-    Normal,
-    Append,
-    // Todo
-    _Prepend,
-}
-
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
-impl Default for DiffOpAddMode {
-    fn default() -> Self {
-        Self::Normal
-    }
-}
-
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
+#[allow(clippy::too_many_arguments)]
 fn apply_diff<T, EF, V>(
     cx: Scope,
     opening: &web_sys::Node,
@@ -791,18 +781,41 @@ fn apply_diff<T, EF, V>(
     children: &mut Vec<Option<EachItem>>,
     mut items: Vec<Option<T>>,
     each_fn: &EF,
+    animations: &EachAnimations,
 ) where
     EF: Fn(Scope, T) -> V,
     V: IntoView,
 {
     let range = RANGE.with(|range| (*range).clone());
 
+    // where each item was before anything changed, to animate moves from
+    let first_rects = animations.moved.as_ref().map(|_| {
+        children
+            .iter()
+            .map(|child| {
+                let el = child.as_ref()?.element()?;
+                Some(el.get_bounding_client_rect())
+            })
+            .collect::<Vec<_>>()
+    });
+    let kept = animations.moved.as_ref().map(|_| {
+        diff.moved
+            .iter()
+            .map(|m| (m.from, m.to))
+            .collect::<Vec<_>>()
+    });
+    let added = animations
+        .enter
+        .as_ref()
+        .map(|_| diff.added.iter().map(|a| a.at).collect::<Vec<_>>());
+
     // The order of cmds needs to be:
     // 1. Clear
     // 2. Removals
-    // 3. Remove holes left from removals
-    // 4. Moves + Add
-    if diff.clear {
+    // 3. Moves + Add, mounted from the end of the list to the start
+    //
+    // Clearing everything at once would skip the exit hook.
+    if diff.clear && animations.exit.is_none() {
         if opening.previous_sibling().is_none()
             && closing.next_sibling().is_none()
         {
@@ -824,550 +837,187 @@ fn apply_diff<T, EF, V>(
             range.delete_contents().unwrap();
         }
 
-        return;
-    }
-
-    for DiffOpRemove { at } in &diff.removed {
-        let item_to_remove = std::mem::take(&mut children[*at]).unwrap();
-
-        item_to_remove.prepare_for_move();
-    }
-
-    // Now, remove the holes that might have been left from removing
-    // items
-    #[allow(unstable_name_collisions)]
-    children.drain_filter(|c| c.is_none());
-
-    // Resize children if needed
-    if let Some(added) = diff.added.len().checked_sub(diff.removed.len()) {
-        let target_size = children.len() + added;
-        children.resize_with(target_size, || None);
-    }
-
-    let (move_cmds, add_cmds) = unpack_moves(&diff);
+        children.clear();
+    } else {
+        for DiffOpRemove { at } in &diff.removed {
+            let item_to_remove = children[*at].take().unwrap();
 
-    let mut moved_children = move_cmds
-        .iter()
-        .map(|move_| {
-            let each_item = children[move_.from].take().unwrap();
-
-            if move_.move_in_dom {
-                each_item.prepare_for_move();
+            match (&animations.exit, item_to_remove.element()) {
+                (Some(exit), Some(el)) => {
+                    let el = el.clone();
+                    exit(&el.clone(), Box::new(move || Dom.remove(&el)));
+                }
+                _ => item_to_remove.prepare_for_move(),
             }
-
-            Some(each_item)
-        })
-        .collect::<Vec<_>>();
-
-    for (i, DiffOpMove { to, .. }) in move_cmds
-        .iter()
-        .enumerate()
-        .filter(|(_, move_)| !move_.move_in_dom)
-    {
-        children[*to] = moved_children[i].take();
+        }
     }
 
-    for (i, DiffOpMove { to, .. }) in move_cmds
-        .into_iter()
-        .enumerate()
-        .filter(|(_, move_)| move_.move_in_dom)
-    {
-        let each_item = moved_children[i].take().unwrap();
+    let mut prev_children = std::mem::take(children);
+    let len = diff.moved.len() + diff.added.len();
+    children.resize_with(len, || None);
+    let mut needs_mount = vec![false; len];
 
-        let sibling_node =
-            children.get_next_closest_mounted_sibling(to, closing.to_owned());
+    for DiffOpMove {
+        from,
+        to,
+        move_in_dom,
+    } in diff.moved
+    {
+        let each_item = prev_children[from].take().unwrap();
 
-        mount_child(MountKind::Before(&sibling_node), &each_item);
+        if move_in_dom {
+            each_item.prepare_for_move();
+            needs_mount[to] = true;
+        }
 
         children[to] = Some(each_item);
     }
 
-    for DiffOpAdd { at, mode } in add_cmds {
+    for DiffOpAdd { at } in diff.added {
         let (each_item, _) = cx.run_child_scope(|cx| {
             let view = each_fn(cx, items[at].take().unwrap()).into_view(cx);
 
             EachItem::new(cx, view)
         });
 
-        match mode {
-            DiffOpAddMode::Normal => {
-                let sibling_node = children
-                    .get_next_closest_mounted_sibling(at, closing.to_owned());
+        needs_mount[at] = true;
+        children[at] = Some(each_item);
+    }
 
-                mount_child(MountKind::Before(&sibling_node), &each_item);
-            }
-            DiffOpAddMode::Append => {
-                mount_child(MountKind::Before(closing), &each_item);
-            }
-            DiffOpAddMode::_Prepend => {
-                todo!("Prepends are not yet implemented")
+    // Every item after the current one is already in place, so each item
+    // can be mounted right before the one that follows it
+    let mut sibling_node = closing.to_owned();
+    for (each_item, needs_mount) in children.iter().zip(needs_mount).rev() {
+        let each_item = each_item.as_ref().unwrap();
+
+        if needs_mount {
+            mount_child(MountKind::Before(&sibling_node), each_item);
+        }
+
+        sibling_node = each_item.get_opening_node();
+    }
+
+    if let (Some(enter), Some(added)) = (&animations.enter, added) {
+        for at in added {
+            if let Some(el) = children[at].as_ref().and_then(EachItem::element)
+            {
+                enter(el);
             }
         }
+    }
 
-        children[at] = Some(each_item);
+    if let (Some(moved), Some(first_rects), Some(kept)) =
+        (&animations.moved, first_rects, kept)
+    {
+        for (from, to) in kept {
+            let (Some(first), Some(el)) = (
+                &first_rects[from],
+                children[to].as_ref().and_then(EachItem::element),
+            ) else {
+                continue;
+            };
+            let last = el.get_bounding_client_rect();
+            if first.left() != last.left() || first.top() != last.top() {
+                moved(el, first.clone());
+            }
+        }
     }
 }
 
-/// Unpacks adds and moves into a sequence of interleaved
-/// add and move commands. Move commands will always return
-/// with a `len == 1` and `is_dense = true`.
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
-fn unpack_moves(diff: &Diff) -> (Vec<DiffOpMove>, Vec<DiffOpAdd>) {
-    let mut moves = Vec::with_capacity(diff.items_to_move);
-    let mut adds = Vec::with_capacity(diff.added.len());
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let mut adds_iter = diff.added.iter();
-    let mut moves_iter = diff.moved.iter();
+    fn set(items: &[usize]) -> FxIndexSet<usize> {
+        items.iter().copied().collect()
+    }
 
-    let mut adds_next = adds_iter.next();
-    let mut moves_next = moves_iter.next().copied();
+    fn dom_moves(diff: &Diff) -> Vec<usize> {
+        diff.moved
+            .iter()
+            .filter(|move_| move_.move_in_dom)
+            .map(|move_| move_.to)
+            .collect()
+    }
+
+    #[test]
+    fn longest_increasing_subsequence_indices() {
+        assert_eq!(longest_increasing_subsequence(&[]), Vec::<usize>::new());
+        assert_eq!(longest_increasing_subsequence(&[0, 1, 2]), vec![0, 1, 2]);
+        assert_eq!(longest_increasing_subsequence(&[2, 1, 0]).len(), 1);
+        assert_eq!(
+            longest_increasing_subsequence(&[3, 0, 1, 4, 2, 5]),
+            vec![1, 2, 4, 5]
+        );
+    }
 
-    for i in 0..diff.items_to_move + diff.added.len() {
-        match (adds_next, &mut moves_next) {
-            (Some(add), Some(move_)) => {
-                if add.at == i {
-                    adds.push(*add);
+    #[test]
+    fn prepend_moves_nothing() {
+        let diff = diff(&set(&[1, 2, 3]), &set(&[4, 5, 1, 2, 3]));
 
-                    adds_next = adds_iter.next();
-                } else {
-                    let mut single_move = *move_;
-                    single_move.len = 1;
+        assert_eq!(diff.added, vec![DiffOpAdd { at: 0 }, DiffOpAdd { at: 1 }]);
+        assert!(diff.removed.is_empty());
+        assert!(dom_moves(&diff).is_empty());
+    }
 
-                    moves.push(single_move);
+    #[test]
+    fn reverse_moves_all_but_one() {
+        let diff = diff(&set(&[1, 2, 3, 4, 5]), &set(&[5, 4, 3, 2, 1]));
 
-                    move_.len -= 1;
-                    move_.from += 1;
-                    move_.to += 1;
+        assert_eq!(dom_moves(&diff).len(), 4);
+    }
 
-                    if move_.len == 0 {
-                        moves_next = moves_iter.next().copied();
-                    }
-                }
-            }
-            (Some(add), None) => {
-                adds.push(*add);
+    #[test]
+    fn swap_moves_two() {
+        let diff = diff(&set(&[1, 2, 3, 4, 5]), &set(&[1, 4, 3, 2, 5]));
 
-                adds_next = adds_iter.next();
-            }
-            (None, Some(move_)) => {
-                let mut single_move = *move_;
-                single_move.len = 1;
+        assert_eq!(dom_moves(&diff).len(), 2);
+    }
 
-                moves.push(single_move);
+    #[test]
+    fn move_to_end_moves_one() {
+        let diff = diff(&set(&[1, 2, 3, 4, 5]), &set(&[2, 3, 4, 5, 1]));
 
-                move_.len -= 1;
-                move_.from += 1;
-                move_.to += 1;
+        assert_eq!(dom_moves(&diff), vec![4]);
+    }
 
-                if move_.len == 0 {
-                    moves_next = moves_iter.next().copied();
-                }
-            }
-            (None, None) => break,
-        }
+    #[test]
+    fn removals_and_adds() {
+        let diff = diff(&set(&[1, 2, 3, 4]), &set(&[2, 5, 4]));
+
+        assert_eq!(
+            diff.removed,
+            vec![DiffOpRemove { at: 0 }, DiffOpRemove { at: 2 }]
+        );
+        assert_eq!(diff.added, vec![DiffOpAdd { at: 1 }]);
+        assert!(dom_moves(&diff).is_empty());
+        assert!(!diff.clear);
     }
 
-    (moves, adds)
-}
+    #[test]
+    fn replacing_everything_clears() {
+        assert!(diff(&set(&[1, 2]), &set(&[3, 4])).clear);
+        assert!(diff(&set(&[1, 2]), &set(&[])).clear);
+        assert!(!diff(&set(&[]), &set(&[1])).clear);
+    }
 
-// #[cfg(test)]
-// mod test_utils {
-//     use super::*;
-
-//     pub trait IntoFxIndexSet<K> {
-//         fn into_fx_index_set(self) -> FxIndexSet<K>;
-//     }
-
-//     impl<T, K> IntoFxIndexSet<K> for T
-//     where
-//         T: IntoIterator<Item = K>,
-//         K: Eq + Hash,
-//     {
-//         fn into_fx_index_set(self) -> FxIndexSet<K> {
-//             self.into_iter().collect()
-//         }
-//     }
-// }
-
-// #[cfg(test)]
-// use test_utils::*;
-
-// #[cfg(test)]
-// mod find_ranges {
-//     use super::*;
-
-//     // Single range tests will be empty because of removing ranges
-//     // that didn't move
-//     #[test]
-//     fn single_range() {
-//         let ranges = find_ranges(
-//             [1, 2, 3, 4].iter().into_fx_index_set(),
-//             [1, 2, 3, 4].iter().into_fx_index_set(),
-//             &[1, 2, 3, 4].into_fx_index_set(),
-//             &[1, 2, 3, 4].into_fx_index_set(),
-//         );
-
-//         assert_eq!(ranges, vec![]);
-//     }
-
-//     #[test]
-//     fn single_range_with_adds() {
-//         let ranges = find_ranges(
-//             [1, 2, 3, 4].iter().into_fx_index_set(),
-//             [1, 2, 3, 4].iter().into_fx_index_set(),
-//             &[1, 2, 3, 4].into_fx_index_set(),
-//             &[1, 2, 5, 3, 4].into_fx_index_set(),
-//         );
-
-//         assert_eq!(ranges, vec![]);
-//     }
-
-//     #[test]
-//     fn single_range_with_removals() {
-//         let ranges = find_ranges(
-//             [1, 2, 3, 4].iter().into_fx_index_set(),
-//             [1, 2, 3, 4].iter().into_fx_index_set(),
-//             &[1, 2, 5, 3, 4].into_fx_index_set(),
-//             &[1, 2, 3, 4].into_fx_index_set(),
-//         );
-
-//         assert_eq!(ranges, vec![]);
-//     }
-
-//     #[test]
-//     fn two_ranges() {
-//         let ranges = find_ranges(
-//             [1, 2, 3, 4].iter().into_fx_index_set(),
-//             [3, 4, 1, 2].iter().into_fx_index_set(),
-//             &[1, 2, 3, 4].into_fx_index_set(),
-//             &[3, 4, 1, 2].into_fx_index_set(),
-//         );
-
-//         assert_eq!(
-//             ranges,
-//             vec![
-//                 DiffOpMove {
-//                     from: 2,
-//                     to: 0,
-//                     len: 2,
-//                     move_in_dom: true,
-//                 },
-//                 DiffOpMove {
-//                     from: 0,
-//                     to: 2,
-//                     len: 2,
-//                     move_in_dom: true,
-//                 },
-//             ]
-//         );
-//     }
-
-//     #[test]
-//     fn two_ranges_with_adds() {
-//         let ranges = find_ranges(
-//             [1, 2, 3, 4].iter().into_fx_index_set(),
-//             [3, 4, 1, 2].iter().into_fx_index_set(),
-//             &[1, 2, 3, 4].into_fx_index_set(),
-//             &[3, 4, 5, 1, 6, 2].into_fx_index_set(),
-//         );
-
-//         assert_eq!(
-//             ranges,
-//             vec![
-//                 DiffOpMove {
-//                     from: 2,
-//                     to: 0,
-//                     len: 2,
-//                 },
-//                 DiffOpMove {
-//                     from: 0,
-//                     to: 3,
-//                     len: 2,
-//                 },
-//             ]
-//         );
-//     }
-//     #[test]
-//     fn two_ranges_with_removals() {
-//         let ranges = find_ranges(
-//             [1, 2, 3, 4].iter().into_fx_index_set(),
-//             [3, 4, 1, 2].iter().into_fx_index_set(),
-//             &[1, 5, 2, 6, 3, 4].into_fx_index_set(),
-//             &[3, 4, 1, 2].into_fx_index_set(),
-//         );
-
-//         assert_eq!(
-//             ranges,
-//             vec![
-//                 DiffOpMove {
-//                     from: 4,
-//                     to: 0,
-//                     len: 2,
-//                 },
-//                 DiffOpMove {
-//                     from: 0,
-//                     to: 2,
-//                     len: 2,
-//                 },
-//             ]
-//         );
-//     }
-
-//     #[test]
-//     fn remove_ranges_that_did_not_move() {
-//         // Here, 'C' doesn't change
-//         let ranges = find_ranges(
-//             ['A', 'B', 'C', 'D'].iter().into_fx_index_set(),
-//             ['B', 'D', 'C', 'A'].iter().into_fx_index_set(),
-//             &['A', 'B', 'C', 'D'].into_fx_index_set(),
-//             &['B', 'D', 'C', 'A'].into_fx_index_set(),
-//         );
-
-//         assert_eq!(
-//             ranges,
-//             vec![
-//                 DiffOpMove {
-//                     from: 1,
-//                     to: 0,
-//                     len: 1,
-//                 },
-//                 DiffOpMove {
-//                     from: 3,
-//                     to: 1,
-//                     len: 1,
-//                 },
-//                 DiffOpMove {
-//                     from: 0,
-//                     to: 3,
-//                     len: 1,
-//                 },
-//             ]
-//         );
-
-//         // Now we're going to to the same as above, just with more items
-//         //
-//         // A = 1
-//         // B = 2, 3
-//         // C = 4, 5, 6
-//         // D = 7, 8, 9, 0
-
-//         let ranges = find_ranges(
-//             //A B     C        D
-//             [1, 2, 3, 4, 5, 6, 7, 8, 9, 0].iter().into_fx_index_set(),
-//             //B    D           C        A
-//             [2, 3, 7, 8, 9, 0, 4, 5, 6, 1].iter().into_fx_index_set(),
-//             //A  B     C        D
-//             &[1, 2, 3, 4, 5, 6, 7, 8, 9, 0].into_fx_index_set(),
-//             //B     D           C        A
-//             &[2, 3, 7, 8, 9, 0, 4, 5, 6, 1].into_fx_index_set(),
-//         );
-
-//         assert_eq!(
-//             ranges,
-//             vec![
-//                 DiffOpMove {
-//                     from: 1,
-//                     to: 0,
-//                     len: 2,
-//                 },
-//                 DiffOpMove {
-//                     from: 6,
-//                     to: 2,
-//                     len: 4,
-//                 },
-//                 DiffOpMove {
-//                     from: 0,
-//                     to: 9,
-//                     len: 1,
-//                 },
-//             ]
-//         );
-//     }
-// }
-
-// #[cfg(test)]
-// mod optimize_moves {
-//     use super::*;
-
-//     #[test]
-//     fn swap() {
-//         let mut moves = vec![
-//             DiffOpMove {
-//                 from: 0,
-//                 to: 6,
-//                 len: 2,
-//                 ..Default::default()
-//             },
-//             DiffOpMove {
-//                 from: 6,
-//                 to: 0,
-//                 len: 7,
-//                 ..Default::default()
-//             },
-//         ];
-
-//         optimize_moves(&mut moves);
-
-//         assert_eq!(
-//             moves,
-//             vec![DiffOpMove {
-//                 from: 0,
-//                 to: 6,
-//                 len: 2,
-//                 ..Default::default()
-//             }]
-//         );
-//     }
-// }
-
-// #[cfg(test)]
-// mod add_or_move {
-//     use super::*;
-
-//     #[test]
-//     fn simple_range() {
-//         let cmds = AddOrMove::from_diff(&Diff {
-//             moved: vec![DiffOpMove {
-//                 from: 0,
-//                 to: 0,
-//                 len: 3,
-//             }],
-//             ..Default::default()
-//         });
-
-//         assert_eq!(
-//             cmds,
-//             vec![
-//                 DiffOpMove {
-//                     from: 0,
-//                     to: 0,
-//                     len: 1,
-//                 },
-//                 DiffOpMove {
-//                     from: 1,
-//                     to: 1,
-//                     len: 1,
-//                 },
-//                 DiffOpMove {
-//                     from: 2,
-//                     to: 2,
-//                     len: 1,
-//                 },
-//             ]
-//         );
-//     }
-
-//     #[test]
-//     fn range_with_add() {
-//         let cmds = AddOrMove::from_diff(&Diff {
-//             moved: vec![DiffOpMove {
-//                 from: 0,
-//                 to: 0,
-//                 len: 3,
-//                 move_in_dom: true,
-//             }],
-//             added: vec![DiffOpAdd {
-//                 at: 2,
-//                 ..Default::default()
-//             }],
-//             ..Default::default()
-//         });
-
-//         assert_eq!(
-//             cmds,
-//             vec![
-//                 AddOrMove::Move(DiffOpMove {
-//                     from: 0,
-//                     to: 0,
-//                     len: 1,
-//                     move_in_dom: true,
-//                 }),
-//                 AddOrMove::Move(DiffOpMove {
-//                     from: 1,
-//                     to: 1,
-//                     len: 1,
-//                     move_in_dom: true,
-//                 }),
-//                 AddOrMove::Add(DiffOpAdd {
-//                     at: 2,
-//                     ..Default::default()
-//                 }),
-//                 AddOrMove::Move(DiffOpMove {
-//                     from: 3,
-//                     to: 3,
-//                     len: 1,
-//                     move_in_dom: true,
-//                 }),
-//             ]
-//         );
-//     }
-// }
-
-// #[cfg(test)]
-// mod diff {
-//     use super::*;
-
-//     #[test]
-//     fn only_adds() {
-//         let diff =
-//             diff(&[].into_fx_index_set(), &[1, 2, 3].into_fx_index_set());
-
-//         assert_eq!(
-//             diff,
-//             Diff {
-//                 added: vec![
-//                     DiffOpAdd {
-//                         at: 0,
-//                         mode: DiffOpAddMode::Append
-//                     },
-//                     DiffOpAdd {
-//                         at: 1,
-//                         mode: DiffOpAddMode::Append
-//                     },
-//                     DiffOpAdd {
-//                         at: 2,
-//                         mode: DiffOpAddMode::Append
-//                     },
-//                 ],
-//                 ..Default::default()
-//             }
-//         );
-//     }
-
-//     #[test]
-//     fn only_removes() {
-//         let diff =
-//             diff(&[1, 2, 3].into_fx_index_set(), &[3].into_fx_index_set());
-
-//         assert_eq!(
-//             diff,
-//             Diff {
-//                 removed: vec![DiffOpRemove { at: 0 }, DiffOpRemove { at: 1 }],
-//                 ..Default::default()
-//             }
-//         );
-//     }
-
-//     #[test]
-//     fn adds_with_no_move() {
-//         let diff =
-//             diff(&[3].into_fx_index_set(), &[1, 2, 3].into_fx_index_set());
-
-//         assert_eq!(
-//             diff,
-//             Diff {
-//                 added: vec![
-//                     DiffOpAdd {
-//                         at: 0,
-//                         ..Default::default()
-//                     },
-//                     DiffOpAdd {
-//                         at: 1,
-//                         ..Default::default()
-//                     },
-//                 ],
-//                 ..Default::default()
-//             }
-//         );
-//     }
-// }
+    #[cfg(debug_assertions)]
+    #[test]
+    fn collects_stats() {
+        take_each_diff_stats();
+        diff(&set(&[1, 2, 3, 4]), &set(&[4, 3, 5, 1]));
+
+        assert_eq!(
+            take_each_diff_stats(),
+            EachDiffStats {
+                diffs: 1,
+                added: 1,
+                removed: 1,
+                moved: 2,
+                unmoved: 1,
+                cleared: 0,
+            }
+        );
+        assert_eq!(take_each_diff_stats(), EachDiffStats::default());
+    }
+}
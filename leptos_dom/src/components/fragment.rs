@@ -1,3 +1,5 @@
+#[cfg(all(debug_assertions, target_arch = "wasm32", feature = "web"))]
+use crate::Mountable;
 use crate::{
     hydration::HydrationKey, ComponentRepr, HydrationCtx, IntoView, View,
 };
@@ -56,7 +58,33 @@ impl From<Fragment> for View {
 
         frag.children = value.nodes;
 
-        frag.into()
+        let view = frag.into();
+        #[cfg(all(debug_assertions, target_arch = "wasm32", feature = "web"))]
+        if let View::Component(frag) = &view {
+            mark_view(frag);
+        }
+        view
+    }
+}
+
+// Marks the children of a view rendered in the browser with the same comments
+// the server renders around them, so that hot reloading can patch it too.
+#[cfg(all(debug_assertions, target_arch = "wasm32", feature = "web"))]
+fn mark_view(frag: &ComponentRepr) {
+    let Some(id) = &frag.view_marker else {
+        return;
+    };
+    if HydrationCtx::is_hydrating() {
+        return;
+    }
+    let document = crate::document();
+    let open = document.create_comment(&format!("leptos-view|{id}|open"));
+    let close = document.create_comment(&format!("leptos-view|{id}|close"));
+    // inside the fragment's own markers, so that they move along with it
+    let (opening, closing) = (frag.get_opening_node(), frag.get_closing_node());
+    if let Some(parent) = closing.parent_node() {
+        _ = parent.insert_before(&open, opening.next_sibling().as_ref());
+        _ = parent.insert_before(&close, Some(&closing));
     }
 }
 
@@ -90,6 +118,31 @@ impl Fragment {
         &self.nodes
     }
 
+    /// Returns the data of type `T` passed through the fragment by its
+    /// [`Transparent`](crate::Transparent) children, in order, including
+    /// those inside nested fragments. Other children are skipped.
+    ///
+    /// This is how a component reads the data returned by the
+    /// `#[component(transparent)]` components passed as its children.
+    pub fn transparent_children<T: 'static>(&self) -> Vec<&T> {
+        fn collect<'a, T: 'static>(nodes: &'a [View], data: &mut Vec<&'a T>) {
+            for node in nodes {
+                match node {
+                    View::Transparent(t) => data.extend(t.downcast_ref::<T>()),
+                    // a fragment is turned into a component without a name
+                    View::Component(frag) if frag.name.is_empty() => {
+                        collect(&frag.children, data)
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut data = Vec::new();
+        collect(&self.nodes, &mut data);
+        data
+    }
+
     /// Returns the fragment's hydration ID.
     #[inline(always)]
     pub fn id(&self) -> &HydrationKey {
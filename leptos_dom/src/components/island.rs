@@ -0,0 +1,159 @@
+use crate::{
+    html::{Custom, HtmlElement},
+    HydrationCtx, IntoView, View,
+};
+use cfg_if::cfg_if;
+use leptos_reactive::Scope;
+#[cfg(target_arch = "wasm32")]
+use leptos_reactive::Serializable;
+
+/// A component created with `#[island]`, which is hydrated on its own in
+/// islands mode, rather than as part of the whole page.
+///
+/// On the server, the component is rendered inside a `<leptos-island>`
+/// element that records its name, the hydration ID it starts at, and its
+/// props, serialized with [`Serializable`](leptos_reactive::Serializable).
+/// In islands mode, the client bootstrap passes each of those elements to
+/// the function that `#[island]` exports for the component, which calls
+/// [`hydrate_island`]. Otherwise, the wrapper is hydrated or rendered in the
+/// browser like any other element.
+#[doc(hidden)]
+pub struct Island<F>
+where
+    F: FnOnce(Scope) -> View + 'static,
+{
+    name: &'static str,
+    props: Vec<(&'static str, String)>,
+    render: F,
+}
+
+impl<F> Island<F>
+where
+    F: FnOnce(Scope) -> View + 'static,
+{
+    /// Creates a new island, with the serialized values of its props, which
+    /// are only needed on the server.
+    pub fn new(
+        name: &'static str,
+        props: Vec<(&'static str, String)>,
+        render: F,
+    ) -> Self {
+        Self {
+            name,
+            props,
+            render,
+        }
+    }
+}
+
+impl<F> IntoView for Island<F>
+where
+    F: FnOnce(Scope) -> View + 'static,
+{
+    fn into_view(self, cx: Scope) -> View {
+        let Self {
+            name,
+            props,
+            render,
+        } = self;
+        // the island is hydrated starting from the wrapper
+        let start = HydrationCtx::peek();
+        let wrapper = HtmlElement::new(cx, Custom::new("leptos-island"))
+            .attr("style", "display: contents");
+
+        cfg_if! {
+          if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+            // everything the client needs is already in the DOM
+            _ = (name, props, start);
+            wrapper.child(render(cx)).into_view(cx)
+          } else {
+            let wrapper = props.into_iter().fold(
+                wrapper
+                    .attr("data-component", name)
+                    .attr("data-hk", start.to_string()),
+                |wrapper, (prop, value)| {
+                    wrapper.attr(format!("data-prop-{prop}"), value)
+                },
+            );
+            wrapper.child(render(cx)).into_view(cx)
+          }
+        }
+    }
+}
+
+/// Hydrates a server-rendered `<leptos-island>` element with the view
+/// created by `f`, in a reactive scope of its own that is never disposed.
+/// `f` should render the island’s component, with props read from the
+/// element by [`island_prop`].
+///
+/// This is called by the functions that `#[island]` exports, so that each
+/// island on the page can be hydrated independently. Without the `hydrate`
+/// feature, the element is replaced by the view, rendered in the browser.
+#[cfg(target_arch = "wasm32")]
+#[doc(hidden)]
+pub fn hydrate_island<F, N>(el: web_sys::HtmlElement, f: F)
+where
+    F: FnOnce(Scope, &web_sys::HtmlElement) -> N + 'static,
+    N: IntoView,
+{
+    cfg_if! {
+      if #[cfg(feature = "hydrate")] {
+        use crate::hydration::HydrationKey;
+        use wasm_bindgen::UnwrapThrowExt;
+
+        let start = el
+            .get_attribute("data-hk")
+            .and_then(|key| key.parse::<HydrationKey>().ok())
+            .expect_throw("<leptos-island> should have a `data-hk`");
+        // the island's scope lives as long as the page
+        _ = leptos_reactive::create_scope(
+            leptos_reactive::create_runtime(),
+            move |cx| {
+                let view = HydrationCtx::hydrate_from(start, || {
+                    f(cx, &el).into_view(cx)
+                });
+                // the view's nodes are already in the DOM
+                std::mem::forget(view);
+            },
+        );
+      } else if #[cfg(feature = "web")] {
+        use crate::Mountable;
+
+        // the island's scope lives as long as the page
+        _ = leptos_reactive::create_scope(
+            leptos_reactive::create_runtime(),
+            move |cx| {
+                let view = f(cx, &el).into_view(cx);
+                _ = el.replace_with_with_node_1(&view.get_mountable_node());
+                std::mem::forget(view);
+            },
+        );
+      } else {
+        _ = (el, f);
+        crate::debug_warn!(
+            "islands can only be hydrated with the `hydrate` or `csr` \
+             feature enabled"
+        );
+      }
+    }
+}
+
+/// Reads the value of the prop `name` that the server serialized on a
+/// `<leptos-island>` element.
+///
+/// # Panics
+/// Panics if the prop is missing, or can’t be deserialized.
+#[cfg(target_arch = "wasm32")]
+#[doc(hidden)]
+pub fn island_prop<T: Serializable>(
+    el: &web_sys::HtmlElement,
+    name: &str,
+) -> T {
+    let value = el.get_attribute(&format!("data-prop-{name}"));
+    let Some(value) = value else {
+        panic!("<leptos-island> is missing the prop `{name}`");
+    };
+    T::de(&value).unwrap_or_else(|e| {
+        panic!("couldn’t deserialize the island prop `{name}`: {e}")
+    })
+}
@@ -0,0 +1,202 @@
+use crate::{IntoView, View};
+use cfg_if::cfg_if;
+use leptos_reactive::Scope;
+use std::hash::Hash;
+
+cfg_if! {
+  if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+    use leptos_reactive::{on_cleanup, ScopeDisposer, ScopeId};
+    use rustc_hash::FxHashMap;
+    use std::{any::Any, cell::RefCell};
+
+    struct MemoizedView {
+        view: View,
+        // the props the view was rendered with
+        props: Box<dyn Any>,
+        // the scope of the most recent render that used this view
+        owner: ScopeId,
+        // disposes of the scope the view was originally rendered in
+        disposer: ScopeDisposer,
+    }
+
+    type MemoKey = (ScopeId, &'static str);
+
+    thread_local! {
+        static MEMOIZED_VIEWS: RefCell<FxHashMap<MemoKey, Vec<MemoizedView>>> =
+            Default::default();
+    }
+  } else {
+    use crate::{
+        html::{Custom, HtmlElement, StringOrView},
+        hydration::{HydrationCtx, HydrationKey},
+    };
+    use once_cell::sync::Lazy;
+    use std::{
+        collections::{hash_map::DefaultHasher, HashMap},
+        hash::Hasher,
+        sync::Mutex,
+    };
+
+    /// The most fragments that will be held in the process-wide cache before it
+    /// is cleared.
+    const MEMOIZED_HTML_CAPACITY: usize = 1024;
+
+    #[derive(Clone)]
+    struct MemoizedHtml {
+        html: String,
+        // where the hydration IDs continue after this fragment
+        end: HydrationKey,
+    }
+
+    type MemoKey = (&'static str, u64, HydrationKey);
+
+    static MEMOIZED_HTML: Lazy<Mutex<HashMap<MemoKey, MemoizedHtml>>> =
+        Lazy::new(Default::default);
+  }
+}
+
+/// A component created with `#[component(memo)]`, which reuses a previous render
+/// of the same component if it is given the same props.
+///
+/// On the server, the rendered HTML is cached for the whole process, keyed by the
+/// hash of the props and the component’s hydration ID, so that it can be reused by
+/// later requests that render the same page. In the browser, if the component is
+/// recreated because the reactive block that contains it has rerun, the view
+/// from the previous run is moved into place rather than running the body again,
+/// as long as its props are equal to the new ones.
+#[doc(hidden)]
+pub struct MemoComponent<P, F>
+where
+    P: Hash + PartialEq + 'static,
+    F: FnOnce(Scope) -> View,
+{
+    name: &'static str,
+    props: P,
+    render: F,
+}
+
+impl<P, F> MemoComponent<P, F>
+where
+    P: Hash + PartialEq + 'static,
+    F: FnOnce(Scope) -> View,
+{
+    /// Creates a new memoized component, which is rendered by `render` if there
+    /// is no previous render with the same `props`.
+    pub fn new(name: &'static str, props: P, render: F) -> Self {
+        Self {
+            name,
+            props,
+            render,
+        }
+    }
+}
+
+impl<P, F> IntoView for MemoComponent<P, F>
+where
+    P: Hash + PartialEq + 'static,
+    F: FnOnce(Scope) -> View,
+{
+    fn into_view(self, cx: Scope) -> View {
+        let Self {
+            name,
+            props,
+            render,
+        } = self;
+
+        cfg_if! {
+          if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+            // without a parent scope, the component can never be recreated
+            let Some(parent) = cx.parent() else {
+                return render(cx);
+            };
+            let key = (parent.id(), name);
+            let owner = cx.id();
+
+            // views that have already been claimed during this render belong to
+            // siblings with the same props
+            let reused = MEMOIZED_VIEWS.with(|memoized| {
+                let mut memoized = memoized.borrow_mut();
+                let entry = memoized.get_mut(&key)?.iter_mut().find(|entry| {
+                    entry.owner != owner
+                        && entry.props.downcast_ref::<P>() == Some(&props)
+                })?;
+                entry.owner = owner;
+                Some(entry.view.clone())
+            });
+
+            let view = reused.unwrap_or_else(|| {
+                // render in the parent scope, so the view outlives this render
+                let (view, disposer) = parent.run_child_scope(render);
+                MEMOIZED_VIEWS.with(|memoized| {
+                    memoized.borrow_mut().entry(key).or_default().push(
+                        MemoizedView {
+                            view: view.clone(),
+                            props: Box::new(props),
+                            owner,
+                            disposer,
+                        },
+                    )
+                });
+                view
+            });
+
+            // once this render is gone, drop any views the next one didn't reuse
+            on_cleanup(cx, move || {
+                let evicted = MEMOIZED_VIEWS.with(|memoized| {
+                    let mut memoized = memoized.borrow_mut();
+                    let Some(entries) = memoized.get_mut(&key) else {
+                        return Vec::new();
+                    };
+                    let (evicted, kept) = std::mem::take(entries)
+                        .into_iter()
+                        .partition::<Vec<_>, _>(|entry| entry.owner == owner);
+                    if kept.is_empty() {
+                        memoized.remove(&key);
+                    } else {
+                        *entries = kept;
+                    }
+                    evicted
+                });
+                for entry in evicted {
+                    entry.disposer.dispose();
+                }
+            });
+
+            view
+          } else {
+            let mut hasher = DefaultHasher::new();
+            props.hash(&mut hasher);
+            let key = (name, hasher.finish(), HydrationCtx::peek());
+
+            let cached = MEMOIZED_HTML
+                .lock()
+                .ok()
+                .and_then(|memoized| memoized.get(&key).cloned());
+            let memoized = cached.unwrap_or_else(|| {
+                let html = render(cx).render_to_string_helper(false).into_owned();
+                let memoized = MemoizedHtml {
+                    html,
+                    end: HydrationCtx::peek(),
+                };
+                if let Ok(mut cache) = MEMOIZED_HTML.lock() {
+                    if cache.len() >= MEMOIZED_HTML_CAPACITY {
+                        cache.clear();
+                    }
+                    cache.insert(key, memoized.clone());
+                }
+                memoized
+            });
+
+            let view = HtmlElement::from_chunks(
+                cx,
+                Custom::new("leptos-memo"),
+                [StringOrView::String(memoized.html.into())],
+            )
+            .into_view(cx);
+            // skip over the IDs the component used when it was rendered
+            HydrationCtx::continue_from(memoized.end);
+            view
+          }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    html::{Custom, HtmlElement},
+    IntoView, View,
+};
+use cfg_if::cfg_if;
+use leptos_reactive::Scope;
+use std::borrow::Cow;
+
+cfg_if! {
+  if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+    use crate::Mountable;
+    use wasm_bindgen::UnwrapThrowExt;
+  } else {
+    use crate::hydration::HydrationCtx;
+  }
+}
+
+/// Whether the shadow root of a [`ShadowRoot`] can be reached from
+/// JavaScript outside of it, through the host element’s `shadowRoot`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ShadowRootMode {
+    /// The shadow root is available as `host.shadowRoot`.
+    #[default]
+    Open,
+    /// `host.shadowRoot` is `null`.
+    Closed,
+}
+
+impl ShadowRootMode {
+    /// The value of the `mode` option of `attachShadow`, and of the
+    /// `shadowrootmode` attribute.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Closed => "closed",
+        }
+    }
+}
+
+/// Renders a view into the [shadow root](https://developer.mozilla.org/en-US/docs/Web/API/Web_components/Using_shadow_DOM)
+/// of a `<leptos-shadow>` host element, so that the page’s styles don’t apply
+/// to it, and the styles added with [`ShadowRoot::style`] don’t apply to the
+/// rest of the page.
+///
+/// On the server, the shadow root is rendered as declarative shadow DOM, a
+/// `<template shadowrootmode>` inside the host, which the browser turns into
+/// a real shadow root as it parses the page. In the browser, a shadow root is
+/// attached to the host, and the view is mounted in it.
+///
+/// When hydrating, the server-rendered shadow root is reused, but its
+/// contents are created again rather than hydrated, because hydration finds
+/// the server-rendered nodes in the document, which doesn’t include the
+/// nodes inside shadow roots.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// # create_scope(runtime, |cx| {
+/// ShadowRoot::new(|cx| view! { cx, <p>"Only I am red."</p> }.into_view(cx))
+///     .style("p { color: red; }")
+///     .style(":host { display: block; }")
+///     .into_view(cx);
+/// # });
+/// # runtime.dispose();
+/// ```
+pub struct ShadowRoot<F>
+where
+    F: FnOnce(Scope) -> View + 'static,
+{
+    mode: ShadowRootMode,
+    styles: Vec<Cow<'static, str>>,
+    render: F,
+}
+
+impl<F> ShadowRoot<F>
+where
+    F: FnOnce(Scope) -> View + 'static,
+{
+    /// Creates an open shadow root, which renders the view created by
+    /// `render`.
+    pub fn new(render: F) -> Self {
+        Self {
+            mode: ShadowRootMode::Open,
+            styles: Vec::new(),
+            render,
+        }
+    }
+
+    /// Sets the [`ShadowRootMode`].
+    pub fn mode(mut self, mode: ShadowRootMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Adds a stylesheet to the shadow root, which applies only to the view
+    /// inside it. The host element can be styled with `:host`.
+    pub fn style(mut self, css: impl Into<Cow<'static, str>>) -> Self {
+        self.styles.push(css.into());
+        self
+    }
+}
+
+impl<F> IntoView for ShadowRoot<F>
+where
+    F: FnOnce(Scope) -> View + 'static,
+{
+    fn into_view(self, cx: Scope) -> View {
+        let Self {
+            mode,
+            styles,
+            render,
+        } = self;
+        let host = HtmlElement::new(cx, Custom::new("leptos-shadow"));
+
+        cfg_if! {
+          if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+            let el: &web_sys::Element = host.element.as_ref();
+
+            // a declarative shadow root from the server is emptied and
+            // returned by `attachShadow`, if it has the same mode
+            let shadow_root = el
+                .attach_shadow(&web_sys::ShadowRootInit::new(match mode {
+                    ShadowRootMode::Open => web_sys::ShadowRootMode::Open,
+                    ShadowRootMode::Closed => web_sys::ShadowRootMode::Closed,
+                }))
+                .unwrap_throw();
+
+            for css in styles {
+                let style =
+                    crate::document().create_element("style").unwrap_throw();
+                style.set_text_content(Some(&css));
+                shadow_root.append_child(&style).unwrap_throw();
+            }
+
+            #[cfg(feature = "hydrate")]
+            let view = match el
+                .get_attribute("data-hk-end")
+                .and_then(|key| key.parse().ok())
+                .filter(|_| crate::HydrationCtx::is_hydrating())
+            {
+                Some(end) => {
+                    let view = crate::HydrationCtx::render_without_hydrating(
+                        || render(cx),
+                    );
+                    // carry on hydrating the page after the shadow root
+                    crate::HydrationCtx::continue_from(end);
+                    view
+                }
+                None => render(cx),
+            };
+            #[cfg(not(feature = "hydrate"))]
+            let view = render(cx);
+
+            shadow_root.append_child(&view.get_mountable_node()).unwrap_throw();
+            // the view's nodes are owned by the shadow root now
+            std::mem::forget(view);
+
+            host.into_view(cx)
+          } else {
+            let template = crate::html::template(cx)
+                .attr("shadowrootmode", mode.as_str())
+                .child(
+                    styles
+                        .into_iter()
+                        .map(|css| {
+                            crate::html::style(cx)
+                                .dangerously_set_inner_html(css)
+                        })
+                        .collect::<Vec<_>>(),
+                );
+            let view = render(cx);
+            let end = HydrationCtx::peek();
+
+            host.attr("data-hk-end", end.to_string())
+                .child(template.child(view))
+                .into_view(cx)
+          }
+        }
+    }
+}
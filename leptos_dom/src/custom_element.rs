@@ -0,0 +1,259 @@
+use crate::IntoView;
+use cfg_if::cfg_if;
+use leptos_reactive::{RwSignal, Scope, SignalGet};
+use std::{collections::HashMap, rc::Rc, str::FromStr};
+
+cfg_if! {
+  if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+    use crate::{HydrationCtx, Mountable};
+    use leptos_reactive::{
+        create_runtime, create_rw_signal, create_scope, RuntimeId,
+        ScopeDisposer, SignalGetUntracked, SignalSet,
+    };
+    use std::cell::{Cell, RefCell};
+    use wasm_bindgen::prelude::*;
+
+    // The property of a custom element that holds the key of its instance.
+    const INSTANCE_KEY: &str = "__leptosCustomElement";
+
+    #[wasm_bindgen(inline_js = "
+      export function define_custom_element_class(
+        name, observed, connected, disconnected, changed
+      ) {
+        if (customElements.get(name)) {
+          return false;
+        }
+        customElements.define(name, class extends HTMLElement {
+          static get observedAttributes() { return observed; }
+          connectedCallback() { connected(this); }
+          disconnectedCallback() { disconnected(this); }
+          attributeChangedCallback(attr, _, value) { changed(this, attr, value); }
+        });
+        return true;
+      }
+    ")]
+    extern "C" {
+        fn define_custom_element_class(
+            name: &str,
+            observed: js_sys::Array,
+            connected: &JsValue,
+            disconnected: &JsValue,
+            changed: &JsValue,
+        ) -> bool;
+    }
+
+    struct Instance {
+        attributes: CustomElementAttributes,
+        disposer: ScopeDisposer,
+    }
+
+    thread_local! {
+        // All custom elements share one runtime, with a scope for each
+        // connected element.
+        static RUNTIME: RuntimeId = create_runtime();
+        static INSTANCES: RefCell<HashMap<u32, Instance>> = Default::default();
+        static NEXT_INSTANCE: Cell<u32> = const { Cell::new(0) };
+    }
+
+    fn instance_key(el: &web_sys::HtmlElement) -> Option<u32> {
+        js_sys::Reflect::get(el, &JsValue::from_str(INSTANCE_KEY))
+            .ok()?
+            .as_f64()
+            .map(|key| key as u32)
+    }
+  }
+}
+
+/// The observed attributes of a custom element defined with
+/// [`define_custom_element`], which can be read reactively.
+///
+/// Attribute names are written in kebab case, but names with underscores are
+/// accepted as well, so `initial_value` reads the `initial-value` attribute.
+#[derive(Clone, Debug)]
+pub struct CustomElementAttributes {
+    attributes: Rc<HashMap<String, RwSignal<Option<String>>>>,
+}
+
+impl CustomElementAttributes {
+    /// Returns the value of the attribute, or `None` if it isn’t set or isn’t
+    /// observed. Reading it subscribes to changes to the attribute.
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.attributes
+            .get(&name.replace('_', "-"))
+            .and_then(|value| value.get())
+    }
+
+    /// Parses the value of the attribute, returning `None` if it isn’t set or
+    /// can’t be parsed. Reading it subscribes to changes to the attribute.
+    pub fn parse<T: FromStr>(&self, name: &str) -> Option<T> {
+        let value = self.get(name)?;
+        let parsed = value.parse().ok();
+        if parsed.is_none() {
+            crate::debug_warn!(
+                "could not parse the value {value:?} of the attribute {name:?}"
+            );
+        }
+        parsed
+    }
+}
+
+/// Registers a [custom element](https://developer.mozilla.org/en-US/docs/Web/API/Web_components/Using_custom_elements)
+/// that renders the view created by `render`, so that Leptos widgets can be
+/// used in pages that aren’t built with Leptos.
+///
+/// The view is rendered into an open shadow root of the element when it is
+/// connected to the document, with its own reactive [`Scope`], which is
+/// disposed when it is disconnected. The children of the element are shown
+/// wherever the view has a `<slot>`. The view is created again whenever one
+/// of the `observed_attributes` it reads from [`CustomElementAttributes`]
+/// changes.
+///
+/// This only has an effect in the browser. The `custom_element!` macro in
+/// `leptos` defines an element for a component.
+/// ```
+/// # use leptos::*;
+/// # if false {
+/// define_custom_element("hello-name", ["name"], |cx, attributes| {
+///     let name = attributes.get("name").unwrap_or_default();
+///     view! { cx, <p>"Hello, " {name} "!"</p> <slot/> }
+/// });
+/// # }
+/// ```
+pub fn define_custom_element<F, V>(
+    name: &'static str,
+    observed_attributes: impl IntoIterator<Item = &'static str>,
+    render: F,
+) where
+    F: Fn(Scope, CustomElementAttributes) -> V + 'static,
+    V: IntoView,
+{
+    let observed_attributes = observed_attributes
+        .into_iter()
+        .map(|name| name.replace('_', "-"))
+        .collect::<Vec<_>>();
+
+    cfg_if! {
+      if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+        let render = Rc::new(render);
+        let observed_attributes = Rc::new(observed_attributes);
+        let observed = observed_attributes
+            .iter()
+            .map(|name| JsValue::from_str(name))
+            .collect::<js_sys::Array>();
+
+        let connected = Closure::<dyn Fn(web_sys::HtmlElement)>::new(
+            move |el: web_sys::HtmlElement| {
+                let shadow_root = el.shadow_root().unwrap_or_else(|| {
+                    el.attach_shadow(&web_sys::ShadowRootInit::new(
+                        web_sys::ShadowRootMode::Open,
+                    ))
+                    .unwrap_throw()
+                });
+                shadow_root.set_inner_html("");
+
+                // the element isn't part of the view being hydrated, so it
+                // must not use up any of its hydration IDs
+                let id = HydrationCtx::peek();
+                let attributes = Rc::new(RefCell::new(None));
+                let mount = {
+                    let el = el.clone();
+                    let observed_attributes = Rc::clone(&observed_attributes);
+                    let attributes = Rc::clone(&attributes);
+                    let render = Rc::clone(&render);
+                    move |cx| {
+                        let attrs = CustomElementAttributes {
+                            attributes: Rc::new(
+                                observed_attributes
+                                    .iter()
+                                    .map(|name| {
+                                        let value = el.get_attribute(name);
+                                        let value = create_rw_signal(cx, value);
+                                        (name.clone(), value)
+                                    })
+                                    .collect(),
+                            ),
+                        };
+                        *attributes.borrow_mut() = Some(attrs.clone());
+
+                        let view =
+                            (move || render(cx, attrs.clone()).into_view(cx))
+                                .into_view(cx);
+                        shadow_root
+                            .append_child(&view.get_mountable_node())
+                            .unwrap_throw();
+                        // the view's nodes are owned by the shadow root now
+                        std::mem::forget(view);
+                    }
+                };
+                #[cfg(feature = "hydrate")]
+                let disposer = HydrationCtx::render_without_hydrating(|| {
+                    RUNTIME.with(|runtime| create_scope(*runtime, mount))
+                });
+                #[cfg(not(feature = "hydrate"))]
+                let disposer =
+                    RUNTIME.with(|runtime| create_scope(*runtime, mount));
+                HydrationCtx::continue_from(id);
+
+                let key =
+                    NEXT_INSTANCE.with(|next| next.replace(next.get() + 1));
+                _ = js_sys::Reflect::set(
+                    &el,
+                    &JsValue::from_str(INSTANCE_KEY),
+                    &JsValue::from_f64(key.into()),
+                );
+                let attributes = attributes.take().unwrap();
+                INSTANCES.with(|instances| {
+                    instances
+                        .borrow_mut()
+                        .insert(key, Instance { attributes, disposer })
+                });
+            },
+        );
+
+        let disconnected = Closure::<dyn Fn(web_sys::HtmlElement)>::new(
+            |el: web_sys::HtmlElement| {
+                let instance = instance_key(&el).and_then(|key| {
+                    INSTANCES
+                        .with(|instances| instances.borrow_mut().remove(&key))
+                });
+                if let Some(instance) = instance {
+                    instance.disposer.dispose();
+                }
+            },
+        );
+
+        let changed =
+            Closure::<dyn Fn(web_sys::HtmlElement, String, JsValue)>::new(
+                |el: web_sys::HtmlElement, attr: String, value: JsValue| {
+                    let Some(key) = instance_key(&el) else {
+                        return;
+                    };
+                    let signal = INSTANCES.with(|instances| {
+                        instances.borrow().get(&key).and_then(|instance| {
+                            instance.attributes.attributes.get(&attr).copied()
+                        })
+                    });
+                    let value = value.as_string();
+                    if let Some(signal) = signal {
+                        if signal.get_untracked() != value {
+                            signal.set(value);
+                        }
+                    }
+                },
+            );
+
+        let defined = define_custom_element_class(
+            name,
+            observed,
+            &connected.into_js_value(),
+            &disconnected.into_js_value(),
+            &changed.into_js_value(),
+        );
+        if !defined {
+            crate::warn!("the custom element {name:?} is already defined");
+        }
+      } else {
+        _ = (name, observed_attributes, render);
+      }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{html::ElementDescriptor, HtmlElement};
+use leptos_reactive::Scope;
+
+impl<El: ElementDescriptor + 'static> HtmlElement<El> {
+    /// Runs a directive on this element: a function that is given the
+    /// element once it has been created, along with a parameter, so that it
+    /// can add behavior to it, like trapping focus, detecting clicks outside
+    /// of it, or handing it to a third-party JavaScript widget.
+    ///
+    /// This is what `use:directive=param` does in the `view` macro. A
+    /// directive without a value, like `use:directive`, is given `()`.
+    ///
+    /// Anything the directive sets up should be cleaned up with
+    /// [`on_cleanup`](leptos_reactive::on_cleanup) on the [`Scope`] it is
+    /// given, which is disposed of along with the element.
+    ///
+    /// Directives only run in the browser: when rendering on the server, this
+    /// does nothing.
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// fn autofocus(cx: Scope, el: web_sys::Element, select: bool) {
+    ///     use wasm_bindgen::JsCast;
+    ///
+    ///     let input = el.unchecked_into::<web_sys::HtmlInputElement>();
+    ///     request_animation_frame(move || {
+    ///         _ = input.focus();
+    ///         if select {
+    ///             input.select();
+    ///         }
+    ///     });
+    ///     on_cleanup(cx, || log!("autofocused input removed"));
+    /// }
+    ///
+    /// # let runtime = create_runtime();
+    /// # create_scope(runtime, |cx| {
+    /// let input = view! { cx, <input value="Ferris" use:autofocus=true/> };
+    /// # });
+    /// # runtime.dispose();
+    /// ```
+    #[track_caller]
+    pub fn directive<P: 'static>(
+        self,
+        handler: impl Fn(Scope, web_sys::Element, P) + 'static,
+        param: P,
+    ) -> Self {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            let el: &web_sys::Element = self.element.as_ref();
+            handler(self.cx, el.clone(), param);
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            _ = (handler, param);
+        }
+
+        self
+    }
+}
@@ -1,6 +1,11 @@
+mod detail;
 pub mod typed;
 
-use std::{borrow::Cow, cell::RefCell, collections::HashSet};
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    collections::HashSet,
+};
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use wasm_bindgen::{
     convert::FromWasmAbi, intern, prelude::Closure, JsCast, JsValue,
@@ -11,6 +16,134 @@ thread_local! {
     pub(crate) static GLOBAL_EVENTS: RefCell<HashSet<Cow<'static, str>>> = RefCell::new(HashSet::new());
 }
 
+/// A script that records the user events (clicks, input, key presses, etc.) that happen
+/// after server-rendered HTML has been displayed, but before the app has hydrated.
+///
+/// Once hydration finishes, the script stops recording and removes its listeners, and the
+/// recorded events are replayed in the order they happened against the hydrated event
+/// handlers, so that early interactions on slow connections aren’t lost. This should be
+/// included in a `<script>` in the `<head>` of the page, before the body is parsed. The
+/// server integrations do this automatically.
+///
+/// Only delegated handlers, which are the default for events that bubble, are run for a
+/// replayed event. Listeners added with [`undelegated`](crate::ev::undelegated) miss the
+/// events from before hydration, since the only way to reach them would be to dispatch the
+/// event again, which would also repeat what the browser already did for it, like toggling
+/// a checkbox.
+pub const HYDRATION_EVENT_RECORDER: &str = r#"(function () {
+    var types = ["click", "dblclick", "input", "change", "keydown", "keyup"];
+    var events = [];
+    function record(ev) {
+        events.push(ev);
+    }
+    types.forEach(function (type) {
+        document.addEventListener(type, record, true);
+    });
+    window.__LEPTOS_TAKE_PENDING_EVENTS = function () {
+        types.forEach(function (type) {
+            document.removeEventListener(type, record, true);
+        });
+        delete window.__LEPTOS_TAKE_PENDING_EVENTS;
+        return events;
+    };
+})();"#;
+
+/// When the effects caused by updating signals in an event handler run.
+///
+/// Set this for the whole app with [`set_effect_flush`]. Either way,
+/// [`flush_sync`](leptos_reactive::flush_sync) can be called in a handler to
+/// run the effects so far straight away, for example to measure the DOM after
+/// an update.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EffectFlush {
+    /// Effects run as soon as a signal they depend on is updated, so the
+    /// rest of the handler sees the updated DOM. This is the default.
+    #[default]
+    Immediate,
+    /// Effects wait until the handler returns, and then each runs once, so
+    /// the handler sees the DOM as it was before the event, however many
+    /// signals it updates.
+    AfterHandler,
+}
+
+thread_local! {
+    static EFFECT_FLUSH: Cell<EffectFlush> =
+        const { Cell::new(EffectFlush::Immediate) };
+}
+
+/// Sets when the effects caused by updating signals in event handlers run.
+/// See [`EffectFlush`].
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// # create_scope(runtime, |cx| {
+/// set_effect_flush(EffectFlush::AfterHandler);
+///
+/// let (items, set_items) = create_signal(cx, vec![1, 2, 3]);
+/// let list = create_node_ref::<html::Ul>(cx);
+/// let add_item = move |_: ev::MouseEvent| {
+///     // the list still has its old height here
+///     set_items.update(|items| items.push(items.len() + 1));
+///     // render the new item now, so the list can be scrolled to it
+///     flush_sync(cx, || ());
+///     if let Some(list) = list.get() {
+///         list.set_scroll_top(list.scroll_height());
+///     }
+/// };
+/// # });
+/// # runtime.dispose();
+/// ```
+pub fn set_effect_flush(flush: EffectFlush) {
+    EFFECT_FLUSH.with(|current| current.set(flush));
+}
+
+// Runs an event handler, holding back the effects it causes until it returns
+// if the app has asked for that.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+pub(crate) fn run_event_handler<T>(
+    cx: leptos_reactive::Scope,
+    handler: impl FnOnce() -> T,
+) -> T {
+    match EFFECT_FLUSH.with(Cell::get) {
+        EffectFlush::Immediate => handler(),
+        EffectFlush::AfterHandler => cx.batch(handler),
+    }
+}
+
+// Stops recording events with `HYDRATION_EVENT_RECORDER`, and runs the delegated
+// handlers for each event recorded before hydration finished, in order.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+pub(crate) fn replay_hydration_events() {
+    let key = JsValue::from_str("__LEPTOS_TAKE_PENDING_EVENTS");
+    let take_events = js_sys::Reflect::get(&crate::window(), &key)
+        .unwrap_or(JsValue::UNDEFINED);
+    let Some(take_events) = take_events.dyn_ref::<js_sys::Function>() else {
+        return;
+    };
+    let events = take_events.call0(&JsValue::UNDEFINED).unwrap_or_default();
+    let Some(events) = events.dyn_ref::<js_sys::Array>() else {
+        return;
+    };
+
+    for ev in events.iter() {
+        replay_event(&ev.unchecked_into::<web_sys::Event>());
+    }
+}
+
+// Runs the delegated handlers for an event that was dispatched before they
+// were attached.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+pub(crate) fn replay_event(ev: &web_sys::Event) {
+    let event_name = ev.type_();
+    let is_delegated = GLOBAL_EVENTS
+        .with(|events| events.borrow().contains(event_name.as_str()));
+    if is_delegated {
+        let key = JsValue::from_str(&format!("$$${event_name}"));
+        run_delegated_handlers(&key, ev);
+    }
+}
+
 // Used in template macro
 #[doc(hidden)]
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
@@ -30,14 +163,14 @@ pub fn add_event_helper<E: crate::ev::EventDescriptor + 'static>(
             event.event_delegation_key(),
             event_name,
             event_handler,
-            &None,
+            event.options(),
         );
     } else {
         add_event_listener_undelegated(
             target,
             &event_name,
             event_handler,
-            &None,
+            event.options(),
         );
     }
 }
@@ -123,58 +256,8 @@ pub(crate) fn add_delegated_event_listener(
         if !events.contains(&event_name) {
             // create global handler
             let key = JsValue::from_str(&key);
-            let handler = move |ev: web_sys::Event| {
-                let target = ev.target();
-                let node = ev.composed_path().get(0);
-                let mut node = if node.is_undefined() || node.is_null() {
-                    JsValue::from(target)
-                } else {
-                    node
-                };
-
-                // TODO reverse Shadow DOM retargetting
-
-                // TODO simulate currentTarget
-
-                while !node.is_null() {
-                    let node_is_disabled = js_sys::Reflect::get(
-                        &node,
-                        &JsValue::from_str("disabled"),
-                    )
-                    .unwrap_throw()
-                    .is_truthy();
-                    if !node_is_disabled {
-                        let maybe_handler =
-                            js_sys::Reflect::get(&node, &key).unwrap_throw();
-                        if !maybe_handler.is_undefined() {
-                            let f = maybe_handler
-                                .unchecked_ref::<js_sys::Function>();
-                            let _ = f.call1(&node, &ev);
-
-                            if ev.cancel_bubble() {
-                                return;
-                            }
-                        }
-                    }
-
-                    // navigate up tree
-                    let host =
-                        js_sys::Reflect::get(&node, &JsValue::from_str("host"))
-                            .unwrap_throw();
-                    if host.is_truthy()
-                        && host != node
-                        && host.dyn_ref::<web_sys::Node>().is_some()
-                    {
-                        node = host;
-                    } else if let Some(parent) =
-                        node.unchecked_into::<web_sys::Node>().parent_node()
-                    {
-                        node = parent.into()
-                    } else {
-                        node = JsValue::null()
-                    }
-                }
-            };
+            let handler =
+                move |ev: web_sys::Event| run_delegated_handlers(&key, &ev);
 
             cfg_if::cfg_if! {
               if #[cfg(debug_assertions)] {
@@ -207,3 +290,116 @@ pub(crate) fn add_delegated_event_listener(
         }
     })
 }
+
+// Walks up the tree from the event’s target, calling the handler stored under `key`
+// on each node, as if the event had been dispatched to it directly.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn run_delegated_handlers(key: &JsValue, ev: &web_sys::Event) {
+    let target = ev.target();
+    let node = ev.composed_path().get(0);
+    let mut node = if node.is_undefined() || node.is_null() {
+        JsValue::from(target)
+    } else {
+        node
+    };
+
+    // TODO reverse Shadow DOM retargetting
+
+    // TODO simulate currentTarget
+
+    while !node.is_null() {
+        let node_is_disabled =
+            js_sys::Reflect::get(&node, &JsValue::from_str("disabled"))
+                .unwrap_throw()
+                .is_truthy();
+        if !node_is_disabled {
+            let maybe_handler = js_sys::Reflect::get(&node, key).unwrap_throw();
+            if !maybe_handler.is_undefined() {
+                let f = maybe_handler.unchecked_ref::<js_sys::Function>();
+                let _ = f.call1(&node, ev);
+
+                if ev.cancel_bubble() {
+                    return;
+                }
+            }
+        }
+
+        // navigate up tree
+        let host = js_sys::Reflect::get(&node, &JsValue::from_str("host"))
+            .unwrap_throw();
+        if host.is_truthy()
+            && host != node
+            && host.dyn_ref::<web_sys::Node>().is_some()
+        {
+            node = host;
+        } else if let Some(parent) =
+            node.unchecked_into::<web_sys::Node>().parent_node()
+        {
+            node = parent.into()
+        } else {
+            node = JsValue::null()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HYDRATION_EVENT_RECORDER;
+    use std::process::Command;
+
+    // Runs the recorder against a stand-in `document` in Node, which logs
+    // the events that are taken once hydration finishes.
+    #[test]
+    fn recorder_keeps_events_in_order_until_they_are_taken() {
+        let script = format!(
+            r#"
+            var listeners = [];
+            globalThis.window = globalThis;
+            globalThis.document = {{
+                addEventListener: function (type, f, capture) {{
+                    listeners.push({{ type: type, f: f, capture: capture }});
+                }},
+                removeEventListener: function (type, f, capture) {{
+                    listeners = listeners.filter(function (l) {{
+                        return !(l.type === type && l.f === f && l.capture === capture);
+                    }});
+                }},
+            }};
+            function dispatch(type, id) {{
+                listeners.forEach(function (l) {{
+                    if (l.type === type) l.f({{ type: type, id: id }});
+                }});
+            }}
+            {HYDRATION_EVENT_RECORDER}
+            var capturing = listeners.every(function (l) {{ return l.capture; }});
+            dispatch("input", 1);
+            dispatch("click", 2);
+            dispatch("scroll", 3);
+            dispatch("keydown", 4);
+            var events = window.__LEPTOS_TAKE_PENDING_EVENTS();
+            dispatch("click", 5);
+            console.log(JSON.stringify({{
+                capturing: capturing,
+                events: events.map(function (ev) {{ return ev.type + ev.id; }}),
+                listeners: listeners.length,
+                taken: window.__LEPTOS_TAKE_PENDING_EVENTS === undefined,
+            }}));
+            "#
+        );
+        let Ok(output) = Command::new("node").arg("-e").arg(script).output()
+        else {
+            eprintln!("skipping: `node` is needed to run the recorder");
+            return;
+        };
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).trim(),
+            r#"{"capturing":true,"events":["input1","click2","keydown4"],"listeners":0,"taken":true}"#
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::{JsCast, JsValue, UnwrapThrowExt};
+
+/// A value that can be sent as the `detail` of a
+/// [`CustomEvent`](web_sys::CustomEvent), and read back out of it.
+///
+/// This is implemented for JavaScript primitives and [`JsValue`]. Wrap other
+/// values in [`Json`] to send them as plain JavaScript objects with
+/// [`serde`], or in [`Js`] to cast them to a `web_sys` type.
+pub trait EventDetail: Sized {
+    /// Reads the value out of an event’s `detail`, returning `None` if it is
+    /// not the right type.
+    fn from_detail(detail: JsValue) -> Option<Self>;
+
+    /// Converts the value into an event’s `detail`.
+    fn into_detail(self) -> JsValue;
+}
+
+impl EventDetail for JsValue {
+    fn from_detail(detail: JsValue) -> Option<Self> {
+        Some(detail)
+    }
+
+    fn into_detail(self) -> JsValue {
+        self
+    }
+}
+
+impl EventDetail for () {
+    fn from_detail(_detail: JsValue) -> Option<Self> {
+        Some(())
+    }
+
+    fn into_detail(self) -> JsValue {
+        JsValue::NULL
+    }
+}
+
+impl EventDetail for String {
+    fn from_detail(detail: JsValue) -> Option<Self> {
+        detail.as_string()
+    }
+
+    fn into_detail(self) -> JsValue {
+        JsValue::from(self)
+    }
+}
+
+impl EventDetail for bool {
+    fn from_detail(detail: JsValue) -> Option<Self> {
+        detail.as_bool()
+    }
+
+    fn into_detail(self) -> JsValue {
+        JsValue::from(self)
+    }
+}
+
+macro_rules! number_event_detail {
+    ($($ty:ty),*) => {
+        $(
+            impl EventDetail for $ty {
+                fn from_detail(detail: JsValue) -> Option<Self> {
+                    detail.as_f64().map(|n| n as $ty)
+                }
+
+                fn into_detail(self) -> JsValue {
+                    JsValue::from(self)
+                }
+            }
+        )*
+    };
+}
+
+number_event_detail!(f64, f32, i32, u32, i16, u16, i8, u8);
+
+impl<T: EventDetail> EventDetail for Option<T> {
+    fn from_detail(detail: JsValue) -> Option<Self> {
+        if detail.is_null() || detail.is_undefined() {
+            Some(None)
+        } else {
+            T::from_detail(detail).map(Some)
+        }
+    }
+
+    fn into_detail(self) -> JsValue {
+        self.map(T::into_detail).unwrap_or(JsValue::NULL)
+    }
+}
+
+/// An event detail that is converted to and from a plain JavaScript object
+/// with [`serde`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Json<T>(pub T);
+
+impl<T: Serialize + DeserializeOwned> EventDetail for Json<T> {
+    fn from_detail(detail: JsValue) -> Option<Self> {
+        serde_wasm_bindgen::from_value(detail).ok().map(Json)
+    }
+
+    fn into_detail(self) -> JsValue {
+        self.0
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .expect_throw("could not serialize custom event detail")
+    }
+}
+
+/// An event detail that is a JavaScript object, cast to a `web_sys` type
+/// with [`JsCast::dyn_into`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Js<T>(pub T);
+
+impl<T: JsCast> EventDetail for Js<T> {
+    fn from_detail(detail: JsValue) -> Option<Self> {
+        detail.dyn_into().ok().map(Js)
+    }
+
+    fn into_detail(self) -> JsValue {
+        self.0.into()
+    }
+}
+
+/// Reads the `detail` of a [`CustomEvent`](web_sys::CustomEvent).
+///
+/// Returns `None` if the event is not a `CustomEvent`, or its detail is not a
+/// `T`.
+pub fn event_detail<T: EventDetail>(event: &web_sys::Event) -> Option<T> {
+    event
+        .dyn_ref::<web_sys::CustomEvent>()
+        .and_then(|event| T::from_detail(event.detail()))
+}
+
+/// Wraps an event handler that takes the `detail` of a custom event, so that
+/// it can be used with `on:` for an event that isn’t in the [`ev`](crate::ev)
+/// module.
+///
+/// Events whose detail is not a `T` are ignored, with a warning in debug
+/// builds.
+///
+/// ```rust
+/// # use leptos::*;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Rating {
+///     stars: u8,
+/// }
+///
+/// #[component]
+/// fn StarRating(cx: Scope) -> impl IntoView {
+///     let click = move |ev: ev::MouseEvent| {
+///         let button = event_target::<web_sys::EventTarget>(&ev);
+///         ev::dispatch_custom_event(
+///             &button,
+///             "rating-change",
+///             ev::Json(Rating { stars: 5 }),
+///         );
+///     };
+///     view! { cx, <button on:click=click>"★★★★★"</button> }
+/// }
+///
+/// # let runtime = create_runtime();
+/// # create_scope(runtime, |cx| {
+/// let (stars, set_stars) = create_signal(cx, 0);
+/// view! { cx,
+///     <div on:rating-change=ev::with_detail(move |ev::Json(rating): ev::Json<Rating>| {
+///         set_stars.set(rating.stars)
+///     })>
+///         <StarRating/>
+///     </div>
+///     <p>{stars} " stars"</p>
+///     <sl-input on:sl-input=ev::with_detail(move |value: String| log!("{value}"))/>
+/// };
+/// # });
+/// # runtime.dispose();
+/// ```
+pub fn with_detail<T: EventDetail>(
+    mut event_handler: impl FnMut(T) + 'static,
+) -> impl FnMut(web_sys::CustomEvent) + 'static {
+    move |ev: web_sys::CustomEvent| match T::from_detail(ev.detail()) {
+        Some(detail) => event_handler(detail),
+        None => crate::debug_warn!(
+            "the detail of the `{}` event could not be read as a {}",
+            ev.type_(),
+            std::any::type_name::<T>()
+        ),
+    }
+}
+
+/// Dispatches a [`CustomEvent`](web_sys::CustomEvent) with the given name and
+/// `detail` from `target`, which can be listened to with `on:` and
+/// [`with_detail`].
+///
+/// The event bubbles and can be cancelled. Returns `false` if a listener
+/// called `prevent_default()` on it.
+pub fn dispatch_custom_event(
+    target: &web_sys::EventTarget,
+    name: &str,
+    detail: impl EventDetail,
+) -> bool {
+    let init = web_sys::CustomEventInit::new();
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    init.set_detail(&detail.into_detail());
+    let event = web_sys::CustomEvent::new_with_event_init_dict(name, &init)
+        .unwrap_throw();
+    target.dispatch_event(&event).unwrap_throw()
+}
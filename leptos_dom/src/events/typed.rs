@@ -21,8 +21,8 @@ pub trait EventDescriptor: Clone {
     /// The key used for event delegation.
     fn event_delegation_key(&self) -> Cow<'static, str>;
 
-    /// Return the options for this type. This is only used when you create a [`Custom`] event
-    /// handler.
+    /// Return the options for this type. This is only used for events that are
+    /// not delegated, like a [`Custom`] event or one wrapped in [`WithOptions`].
     #[inline(always)]
     fn options(&self) -> &Option<web_sys::AddEventListenerOptions> {
         &None
@@ -49,6 +49,151 @@ impl<Ev: EventDescriptor> EventDescriptor for undelegated<Ev> {
     }
 
     const BUBBLES: bool = false;
+
+    #[inline(always)]
+    fn options(&self) -> &Option<web_sys::AddEventListenerOptions> {
+        self.0.options()
+    }
+}
+
+/// An event listener with [`AddEventListenerOptions`], like listening in
+/// the capture phase or being passive. Create one with [`capture`] or
+/// [`passive`], or with [`WithOptions::new`].
+///
+/// These are the same as `on:event:capture` and `on:event:passive` in the
+/// `view` macro, which can be combined with each other and with
+/// `:undelegated`.
+///
+/// A listener with options is attached directly to the element, rather than
+/// being delegated, so that the options apply to it.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// # create_scope(runtime, |cx| {
+/// // runs before any `click` listener on the children of the `<div>`
+/// let on_click = move |_| log!("clicked inside");
+/// // tells the browser it can scroll without waiting for the handler
+/// let on_touchmove = move |_| log!("touch moved");
+///
+/// view! { cx,
+///     <div on:click:capture=on_click on:touchmove:passive=on_touchmove>
+///         <button>"Click me"</button>
+///     </div>
+/// };
+///
+/// // without the `view` macro
+/// html::div(cx)
+///     .on(ev::capture(ev::click), on_click)
+///     .on(ev::passive(ev::touchmove), on_touchmove)
+///     // lets the handler call `prevent_default()` to stop the page scrolling
+///     .on(ev::WithOptions::new(ev::wheel).passive(false), |ev| {
+///         ev.prevent_default()
+///     });
+/// # });
+/// # runtime.dispose();
+/// ```
+///
+/// [`AddEventListenerOptions`]: web_sys::AddEventListenerOptions
+#[derive(Clone)]
+pub struct WithOptions<Ev: EventDescriptor> {
+    event: Ev,
+    options: Option<web_sys::AddEventListenerOptions>,
+}
+
+impl<Ev: EventDescriptor> WithOptions<Ev> {
+    /// Wraps an event, keeping any options it already has.
+    pub fn new(event: Ev) -> Self {
+        let options = event.options().clone();
+        Self { event, options }
+    }
+
+    /// Listens to the event in the capture phase, before any listeners on
+    /// the children of the element.
+    pub fn capture(self) -> Self {
+        self.with_options_mut(|options| {
+            options.set_capture(true);
+        })
+    }
+
+    /// Marks the listener as passive, which means it will never call
+    /// `prevent_default()`, so the browser can scroll the page without
+    /// waiting for it.
+    ///
+    /// Browsers already treat `touchstart`, `touchmove`, `wheel` and
+    /// `mousewheel` listeners on the window, document and body as passive,
+    /// so `passive(false)` is needed for those to call `prevent_default()`.
+    pub fn passive(self, passive: bool) -> Self {
+        self.with_options_mut(|options| {
+            options.set_passive(passive);
+        })
+    }
+
+    /// Removes the listener after it has run once.
+    pub fn once(self) -> Self {
+        self.with_options_mut(|options| {
+            options.set_once(true);
+        })
+    }
+
+    /// Modify the [`AddEventListenerOptions`] used for this event listener.
+    ///
+    /// [`AddEventListenerOptions`]: web_sys::AddEventListenerOptions
+    pub fn options_mut(&mut self) -> &mut web_sys::AddEventListenerOptions {
+        self.options
+            .get_or_insert_with(web_sys::AddEventListenerOptions::new)
+    }
+
+    // listeners are never added on the server, where the options can't be
+    // created
+    fn with_options_mut(
+        self,
+        f: impl FnOnce(&mut web_sys::AddEventListenerOptions),
+    ) -> Self {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            let mut this = self;
+            f(this.options_mut());
+            this
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            _ = f;
+            self
+        }
+    }
+}
+
+impl<Ev: EventDescriptor> EventDescriptor for WithOptions<Ev> {
+    type EventType = Ev::EventType;
+
+    #[inline(always)]
+    fn name(&self) -> Cow<'static, str> {
+        self.event.name()
+    }
+
+    #[inline(always)]
+    fn event_delegation_key(&self) -> Cow<'static, str> {
+        self.event.event_delegation_key()
+    }
+
+    const BUBBLES: bool = false;
+
+    #[inline(always)]
+    fn options(&self) -> &Option<web_sys::AddEventListenerOptions> {
+        &self.options
+    }
+}
+
+/// Listens to an event in the capture phase. See [`WithOptions::capture`].
+pub fn capture<Ev: EventDescriptor>(event: Ev) -> WithOptions<Ev> {
+    WithOptions::new(event).capture()
+}
+
+/// Marks an event listener as passive. See [`WithOptions::passive`].
+pub fn passive<Ev: EventDescriptor>(event: Ev) -> WithOptions<Ev> {
+    WithOptions::new(event).passive(true)
 }
 
 /// A custom event.
@@ -91,6 +236,9 @@ impl<E: FromWasmAbi> Custom<E> {
     /// Creates a custom event type that can be used within
     /// [`HtmlElement::on`](crate::HtmlElement::on), for events
     /// which are not covered in the [`ev`](crate::ev) module.
+    ///
+    /// The `detail` of a [`CustomEvent`] can be read with [`event_detail`],
+    /// or by wrapping the handler in [`with_detail`].
     pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
         Self {
             name: name.into(),
@@ -366,6 +514,9 @@ generate_event_types! {
   visibilitychange: Event,
 }
 
+pub use super::detail::{
+    dispatch_custom_event, event_detail, with_detail, EventDetail, Js, Json,
+};
 // Export `web_sys` event types
 pub use web_sys::{
     AnimationEvent, BeforeUnloadEvent, CompositionEvent, CustomEvent,
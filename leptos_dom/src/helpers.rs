@@ -1,8 +1,10 @@
 //! A variety of DOM utility functions.
 
-use crate::{events::typed as ev, is_server, window};
+use crate::{
+    events::typed as ev, html::ElementDescriptor, is_server, window, NodeRef,
+};
 use leptos_reactive::{on_cleanup, Scope};
-use std::time::Duration;
+use std::{borrow::Cow, time::Duration};
 use wasm_bindgen::{prelude::Closure, JsCast, JsValue, UnwrapThrowExt};
 
 /// Sets a property on a DOM element.
@@ -59,10 +61,19 @@ where
 /// Helper function to extract `event.target.value` from an event.
 ///
 /// This is useful in the `on:input` or `on:change` listeners for an `<input>` element.
+///
+/// With the `testing` feature, outside the browser, this is the value of the
+/// target that a [`Renderer`](crate::renderer::Renderer) dispatches the event
+/// with, if any.
 pub fn event_target_value<T>(event: &T) -> String
 where
     T: JsCast,
 {
+    #[cfg(all(feature = "testing", not(target_arch = "wasm32")))]
+    if let Some(target) = crate::renderer::event_target() {
+        return target.value;
+    }
+
     event
         .unchecked_ref::<web_sys::Event>()
         .target()
@@ -74,7 +85,16 @@ where
 /// Helper function to extract `event.target.checked` from an event.
 ///
 /// This is useful in the `on:change` listeners for an `<input type="checkbox">` element.
+///
+/// With the `testing` feature, outside the browser, this is whether the
+/// target that a [`Renderer`](crate::renderer::Renderer) dispatches the event
+/// with is checked, if any.
 pub fn event_target_checked(ev: &web_sys::Event) -> bool {
+    #[cfg(all(feature = "testing", not(target_arch = "wasm32")))]
+    if let Some(target) = crate::renderer::event_target() {
+        return target.checked;
+    }
+
     ev.target()
         .unwrap()
         .unchecked_into::<web_sys::HtmlInputElement>()
@@ -388,6 +408,20 @@ pub fn window_event_listener_untyped(
     event_name: &str,
     cb: impl Fn(web_sys::Event) + 'static,
 ) {
+    _ = window_event_listener_untyped_with_handle(event_name, cb);
+}
+
+/// Adds an event listener to the `Window`, typed as a generic `Event`,
+/// returning a handle that can be used to remove it.
+#[cfg_attr(
+  debug_assertions,
+  instrument(level = "trace", skip_all, fields(event_name = %event_name))
+)]
+#[inline(always)]
+pub fn window_event_listener_untyped_with_handle(
+    event_name: &str,
+    cb: impl Fn(web_sys::Event) + 'static,
+) -> WindowListenerHandle {
     cfg_if::cfg_if! {
       if #[cfg(debug_assertions)] {
         let span = ::tracing::Span::current();
@@ -402,15 +436,27 @@ pub fn window_event_listener_untyped(
 
     if !is_server() {
         #[inline(never)]
-        fn wel(cb: Box<dyn FnMut(web_sys::Event)>, event_name: &str) {
+        fn wel(
+            cb: Box<dyn FnMut(web_sys::Event)>,
+            event_name: &str,
+        ) -> WindowListenerHandle {
             let cb = Closure::wrap(cb).into_js_value();
             _ = window().add_event_listener_with_callback(
                 event_name,
                 cb.unchecked_ref(),
             );
+            WindowListenerHandle {
+                event_name: event_name.to_string(),
+                cb,
+            }
         }
 
-        wel(Box::new(cb), event_name);
+        wel(Box::new(cb), event_name)
+    } else {
+        WindowListenerHandle {
+            event_name: event_name.to_string(),
+            cb: JsValue::UNDEFINED,
+        }
     }
 }
 
@@ -434,9 +480,270 @@ pub fn window_event_listener<E: ev::EventDescriptor + 'static>(
 ) where
     E::EventType: JsCast,
 {
-    window_event_listener_untyped(&event.name(), move |e| {
+    _ = window_event_listener_with_handle(event, cb);
+}
+
+/// Creates a window event listener from a typed event, returning a handle
+/// that can be used to remove it.
+///
+/// This is useful for listeners that are only needed for a while, like the
+/// `pointermove` listener during a drag.
+/// ```
+/// use leptos::{
+///     leptos_dom::helpers::{
+///         window_event_listener_with_handle, WindowListenerHandle,
+///     },
+///     *,
+/// };
+/// use std::{cell::RefCell, rc::Rc};
+///
+/// #[component]
+/// fn Draggable(cx: Scope) -> impl IntoView {
+///     let (offset, set_offset) = create_signal(cx, 0);
+///     let dragging = Rc::new(RefCell::new(None::<WindowListenerHandle>));
+///
+///     let on_pointerdown = {
+///         let dragging = Rc::clone(&dragging);
+///         move |_| {
+///             let on_move = window_event_listener_with_handle(
+///                 ev::pointermove,
+///                 move |ev| set_offset.set(ev.client_x()),
+///             );
+///             *dragging.borrow_mut() = Some(on_move);
+///         }
+///     };
+///     let on_pointerup = move |_| {
+///         if let Some(on_move) = dragging.borrow_mut().take() {
+///             on_move.remove();
+///         }
+///     };
+///
+///     view! { cx,
+///         <div
+///             on:pointerdown=on_pointerdown
+///             on:pointerup=on_pointerup
+///             style:left=move || format!("{}px", offset.get())
+///         />
+///     }
+/// }
+/// ```
+pub fn window_event_listener_with_handle<E: ev::EventDescriptor + 'static>(
+    event: E,
+    cb: impl Fn(E::EventType) + 'static,
+) -> WindowListenerHandle
+where
+    E::EventType: JsCast,
+{
+    window_event_listener_untyped_with_handle(&event.name(), move |e| {
         cb(e.unchecked_into::<E::EventType>())
-    });
+    })
+}
+
+/// Handle that is generated by [`window_event_listener_with_handle`] and can
+/// be used to remove the listener.
+#[derive(Clone, Debug)]
+pub struct WindowListenerHandle {
+    event_name: String,
+    cb: JsValue,
+}
+
+impl WindowListenerHandle {
+    /// Removes the event listener to which this refers.
+    /// See [`removeEventListener()`](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/removeEventListener)
+    pub fn remove(self) {
+        if !is_server() {
+            _ = window().remove_event_listener_with_callback(
+                &self.event_name,
+                self.cb.unchecked_ref(),
+            );
+        }
+    }
+}
+
+/// Options for [`animate_layout`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnimateLayoutOptions {
+    /// How long each element takes to slide into its new position.
+    pub duration: Duration,
+    /// The CSS [`transition-timing-function`](https://developer.mozilla.org/en-US/docs/Web/CSS/transition-timing-function)
+    /// used for the animation.
+    pub easing: Cow<'static, str>,
+}
+
+impl Default for AnimateLayoutOptions {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(250),
+            easing: "ease".into(),
+        }
+    }
+}
+
+/// Animates elements from their old positions to their new ones whenever a
+/// reactive update moves them around, using the
+/// [FLIP](https://aerotwist.com/blog/flip-your-animations/) technique.
+///
+/// `node_refs` is tracked: whenever a signal it reads changes, the position of each
+/// element is measured *before* the DOM is patched, measured again once the update
+/// has been applied, and the difference is animated with a CSS transform.
+///
+/// For the first measurement to happen before the DOM is patched, this needs to run
+/// before the effects that render the elements, so call it before creating the view
+/// that contains them.
+///
+/// ```
+/// use leptos::{leptos_dom::helpers::animate_layout, *};
+///
+/// #[component]
+/// fn ShuffledList(cx: Scope) -> impl IntoView {
+///     let (items, set_items) = create_signal(cx, vec![1, 2, 3]);
+///     let refs = store_value(cx, std::collections::HashMap::new());
+///     let node_ref = move |id: i32| {
+///         refs.update_value(|refs| {
+///             refs.entry(id).or_insert_with(|| create_node_ref::<html::Li>(cx));
+///         });
+///         refs.with_value(|refs| refs[&id])
+///     };
+///
+///     animate_layout(
+///         cx,
+///         move || items.get().into_iter().map(node_ref).collect(),
+///         Default::default(),
+///     );
+///
+///     view! { cx,
+///         <button on:click=move |_| set_items.update(|items| items.reverse())>
+///             "Reverse"
+///         </button>
+///         <ul>
+///             <For
+///                 each=move || items.get()
+///                 key=|id| *id
+///                 view=move |cx, id| {
+///                     let li_ref = node_ref(id);
+///                     view! { cx, <li _ref=li_ref>{id}</li> }
+///                 }
+///             />
+///         </ul>
+///     }
+/// }
+/// ```
+pub fn animate_layout<T>(
+    cx: Scope,
+    node_refs: impl Fn() -> Vec<NodeRef<T>> + 'static,
+    options: AnimateLayoutOptions,
+) where
+    T: ElementDescriptor + Clone + 'static,
+{
+    cfg_if::cfg_if! {
+      if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+        use leptos_reactive::{create_effect, queue_microtask};
+
+        create_effect(cx, move |_| {
+            // First: measure every element before the DOM is patched
+            let first = node_refs()
+                .into_iter()
+                .filter_map(|node_ref| node_ref.get_untracked())
+                .map(|el| {
+                    let el: web_sys::HtmlElement = el.element.as_ref().clone();
+                    let rect = el.get_bounding_client_rect();
+                    (el, rect)
+                })
+                .collect::<Vec<_>>();
+            if first.is_empty() {
+                return;
+            }
+
+            let options = options.clone();
+            // runs once the effects that patch the DOM have finished
+            queue_microtask(move || {
+                let transition = format!(
+                    "transform {}ms {}",
+                    options.duration.as_millis(),
+                    options.easing
+                );
+                for (el, first) in first {
+                    if !el.is_connected() {
+                        continue;
+                    }
+
+                    // Last and Invert
+                    let last = el.get_bounding_client_rect();
+                    let dx = first.left() - last.left();
+                    let dy = first.top() - last.top();
+                    if dx == 0.0 && dy == 0.0 {
+                        continue;
+                    }
+                    let style = el.style();
+                    _ = style.set_property("transition", "none");
+                    _ = style.set_property(
+                        "transform",
+                        &format!("translate({dx}px, {dy}px)"),
+                    );
+
+                    // Play: force a reflow so the inverted position is applied
+                    // before transitioning back to the new one
+                    _ = el.offset_width();
+                    _ = style.set_property("transition", &transition);
+                    _ = style.remove_property("transform");
+                }
+            });
+        });
+      } else {
+        _ = cx;
+        _ = node_refs;
+        _ = options;
+      }
+    }
+}
+
+/// Runs `cb` once, the first time `el` comes within `root_margin` (like
+/// `"200px"`) of the viewport, using an
+/// [`IntersectionObserver`](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver).
+///
+/// This is useful for loading images or data only when they are about to be
+/// seen. The observer is disconnected when the scope is disposed. When
+/// rendering on the server, this does nothing.
+pub fn when_visible(
+    cx: Scope,
+    el: &web_sys::Element,
+    root_margin: &str,
+    cb: impl FnOnce() + 'static,
+) {
+    cfg_if::cfg_if! {
+      if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+        let mut cb = Some(cb);
+        let on_intersect = Closure::wrap(Box::new(
+            move |entries: js_sys::Array,
+                  observer: web_sys::IntersectionObserver| {
+                let visible = entries.iter().any(|entry| {
+                    entry
+                        .unchecked_into::<web_sys::IntersectionObserverEntry>()
+                        .is_intersecting()
+                });
+                if visible {
+                    observer.disconnect();
+                    if let Some(cb) = cb.take() {
+                        cb();
+                    }
+                }
+            },
+        )
+            as Box<dyn FnMut(js_sys::Array, web_sys::IntersectionObserver)>)
+        .into_js_value();
+        let options = web_sys::IntersectionObserverInit::new();
+        options.set_root_margin(root_margin);
+        let observer = web_sys::IntersectionObserver::new_with_options(
+            on_intersect.unchecked_ref(),
+            &options,
+        )
+        .unwrap_throw();
+        observer.observe(el);
+        on_cleanup(cx, move || observer.disconnect());
+      } else {
+        _ = (cx, el, root_margin, cb);
+      }
+    }
 }
 
 #[doc(hidden)]
@@ -448,3 +755,29 @@ pub fn ssr_event_listener<E: crate::ev::EventDescriptor + 'static>(
     _ = event;
     _ = event_handler;
 }
+
+#[doc(hidden)]
+/// This exists only to enable type inference on directives when in SSR mode.
+pub fn ssr_directive<P: 'static>(
+    handler: impl Fn(leptos_reactive::Scope, web_sys::Element, P) + 'static,
+    param: P,
+) {
+    _ = handler;
+    _ = param;
+}
+
+#[doc(hidden)]
+/// This exists only to enable type inference on properties when in SSR mode.
+pub fn ssr_property(value: impl crate::IntoProperty) {
+    _ = value;
+}
+
+#[doc(hidden)]
+/// This exists only to enable type inference on node refs when in SSR mode.
+pub fn ssr_node_ref<El: ElementDescriptor + 'static>(
+    element: fn(Scope) -> crate::HtmlElement<El>,
+    node_ref: NodeRef<El>,
+) {
+    _ = element;
+    _ = node_ref;
+}
@@ -6,6 +6,7 @@ cfg_if! {
   if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
     use crate::events::*;
     use crate::macro_helpers::*;
+    use crate::renderer::{Dom, Renderer};
     use crate::{mount_child, MountKind};
     use once_cell::unsync::Lazy as LazyCell;
     use std::cell::Cell;
@@ -38,8 +39,9 @@ cfg_if! {
         IS_META.with(|m| m.get())
     }
   } else {
-    use crate::hydration::HydrationKey;
+    use crate::{hydration::HydrationKey, macro_helpers::Attribute};
     use smallvec::{smallvec, SmallVec};
+    use std::{any::Any, cell::RefCell, rc::Rc};
 
     pub(crate) const HTML_ELEMENT_DEREF_UNIMPLEMENTED_MSG: &str =
       "`Deref<Target = web_sys::HtmlElement>` and `AsRef<web_sys::HtmlElement>` \
@@ -63,7 +65,11 @@ cfg_if! {
 use crate::{
     ev::EventDescriptor,
     hydration::HydrationCtx,
-    macro_helpers::{IntoAttribute, IntoClass, IntoProperty, IntoStyle},
+    macro_helpers::{
+        ClassAttribute, ClassList, IntoAttribute, IntoClass,
+        IntoClassAttribute, IntoProperty, IntoStyle, IntoStyleAttribute,
+        StyleAttribute, StyleList,
+    },
     Element, Fragment, IntoView, NodeRef, Text, View,
 };
 use leptos_reactive::Scope;
@@ -80,6 +86,15 @@ pub trait ElementDescriptor: ElementDescriptorBounds {
         false
     }
 
+    /// Returns the attribute name as it should be set on this element. SVG
+    /// and MathML elements use it to restore the case of attributes that
+    /// were written in lowercase, like `viewbox` for `viewBox`.
+    #[doc(hidden)]
+    #[inline(always)]
+    fn attribute_name(&self, name: Cow<'static, str>) -> Cow<'static, str> {
+        name
+    }
+
     /// A unique `id` that should be generated for each new instance of
     /// this element, and be consistent for both SSR and CSR.
     #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
@@ -196,20 +211,22 @@ impl Custom {
         let id = HydrationCtx::id();
 
         #[cfg(all(target_arch = "wasm32", feature = "web"))]
-        let element = if HydrationCtx::is_hydrating() {
+        let element: web_sys::Element = if HydrationCtx::is_hydrating() {
             if let Some(el) =
                 crate::document().get_element_by_id(&format!("_{id}"))
             {
-                #[cfg(debug_assertions)]
-                assert_eq!(
-                    el.node_name().to_ascii_uppercase(),
-                    name.to_ascii_uppercase(),
-                    "SSR and CSR elements have the same `TopoId` but \
-                     different node kinds. This is either a discrepancy \
-                     between SSR and CSR rendering
-                    logic, which is considered a bug, or it can also be a \
-                     leptos hydration issue."
-                );
+                #[cfg(all(debug_assertions, feature = "hydrate"))]
+                if !el.node_name().eq_ignore_ascii_case(&name) {
+                    return Self {
+                        element: crate::hydration::element_mismatch(
+                            id,
+                            el,
+                            || crate::document().create_element(&name).unwrap(),
+                        )
+                        .unchecked_into(),
+                        name,
+                    };
+                }
 
                 el.remove_attribute("id").unwrap();
 
@@ -217,32 +234,40 @@ impl Custom {
             } else if let Ok(Some(el)) =
                 crate::document().query_selector(&format!("[leptos-hk=_{id}]"))
             {
-                #[cfg(debug_assertions)]
-                assert_eq!(
-                    el.node_name().to_ascii_uppercase(),
-                    name.to_ascii_uppercase(),
-                    "SSR and CSR elements have the same `TopoId` but \
-                     different node kinds. This is either a discrepancy \
-                     between SSR and CSR rendering
-                    logic, which is considered a bug, or it can also be a \
-                     leptos hydration issue."
-                );
+                #[cfg(all(debug_assertions, feature = "hydrate"))]
+                if !el.node_name().eq_ignore_ascii_case(&name) {
+                    return Self {
+                        element: crate::hydration::element_mismatch(
+                            id,
+                            el,
+                            || crate::document().create_element(&name).unwrap(),
+                        )
+                        .unchecked_into(),
+                        name,
+                    };
+                }
 
                 el.remove_attribute("leptos-hk").unwrap();
 
                 el.unchecked_into()
             } else {
                 if !is_meta_tag() {
+                    #[cfg(all(debug_assertions, feature = "hydrate"))]
+                    crate::hydration::missing_node(
+                        &format!("_{id}"),
+                        &format!("<{name}>"),
+                    );
+                    #[cfg(not(all(debug_assertions, feature = "hydrate")))]
                     crate::warn!(
                         "element with id {id} not found, ignoring it for \
                          hydration"
                     );
                 }
 
-                crate::document().create_element(&name).unwrap()
+                Dom.create_element(&name).unchecked_into()
             }
         } else {
-            crate::document().create_element(&name).unwrap()
+            Dom.create_element(&name).unchecked_into()
         };
 
         Self {
@@ -308,10 +333,77 @@ cfg_if! {
       pub(crate) attrs: SmallVec<[(Cow<'static, str>, Cow<'static, str>); 4]>,
       #[educe(Debug(ignore))]
       pub(crate) children: ElementChildren,
+      #[educe(Debug(ignore))]
+      pub(crate) bindings: ElementBindings,
       #[cfg(debug_assertions)]
       pub(crate) view_marker: Option<String>
     }
 
+    /// The parts of an element that aren't in its HTML, which are kept so
+    /// that it can be rendered with a [`Renderer`](crate::renderer::Renderer):
+    /// its event listeners, and the attributes that are set with a closure.
+    ///
+    /// These are only kept after
+    /// [`set_reactive_views`](crate::renderer::set_reactive_views), since
+    /// server-side rendering never uses them.
+    #[derive(Clone, Default)]
+    pub(crate) struct ElementBindings {
+        pub(crate) listeners: Vec<(Cow<'static, str>, ElementListener)>,
+        pub(crate) attrs: Vec<(Cow<'static, str>, Attribute)>,
+    }
+
+    /// An event listener kept in [`ElementBindings`], which is a
+    /// `RefCell<Box<dyn FnMut(E::EventType)>>` for the event type of the
+    /// [`EventDescriptor`] it was added with.
+    pub(crate) type ElementListener = Rc<dyn Any>;
+
+    impl ElementBindings {
+        pub(crate) fn is_empty(&self) -> bool {
+            self.listeners.is_empty() && self.attrs.is_empty()
+        }
+    }
+
+    // closures can't be compared, so bindings are only equal if they share
+    // the same ones
+    impl PartialEq for ElementBindings {
+        fn eq(&self, other: &Self) -> bool {
+            fn same_listener(
+                (a_name, a): &(Cow<'static, str>, ElementListener),
+                (b_name, b): &(Cow<'static, str>, ElementListener),
+            ) -> bool {
+                a_name == b_name && Rc::ptr_eq(a, b)
+            }
+
+            fn same_attr(
+                (a_name, a): &(Cow<'static, str>, Attribute),
+                (b_name, b): &(Cow<'static, str>, Attribute),
+            ) -> bool {
+                a_name == b_name
+                    && match (a, b) {
+                        (Attribute::Fn(_, a), Attribute::Fn(_, b)) => {
+                            Rc::ptr_eq(a, b)
+                        }
+                        _ => a == b,
+                    }
+            }
+
+            self.listeners.len() == other.listeners.len()
+                && self.attrs.len() == other.attrs.len()
+                && self
+                    .listeners
+                    .iter()
+                    .zip(&other.listeners)
+                    .all(|(a, b)| same_listener(a, b))
+                && self
+                    .attrs
+                    .iter()
+                    .zip(&other.attrs)
+                    .all(|(a, b)| same_attr(a, b))
+        }
+    }
+
+    impl Eq for ElementBindings {}
+
     #[derive(Clone, educe::Educe, PartialEq, Eq)]
     #[educe(Default)]
     pub(crate) enum ElementChildren {
@@ -374,6 +466,7 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
               cx,
               attrs: smallvec![],
               children: Default::default(),
+              bindings: Default::default(),
               element,
               #[cfg(debug_assertions)]
               view_marker: None
@@ -393,6 +486,7 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
             cx,
             attrs: smallvec![],
             children: ElementChildren::Chunks(chunks.into_iter().collect()),
+            bindings: Default::default(),
             element,
             #[cfg(debug_assertions)]
             view_marker: None,
@@ -437,6 +531,7 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
               cx,
               attrs,
               children,
+              bindings,
               element,
               #[cfg(debug_assertions)]
               view_marker
@@ -446,6 +541,7 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
               cx,
               attrs,
               children,
+              bindings,
               element: AnyElement {
                 name: element.name(),
                 is_void: element.is_void(),
@@ -468,7 +564,7 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         {
             #[inline(never)]
             fn id_inner(el: &web_sys::HtmlElement, id: &str) {
-                el.set_attribute(wasm_bindgen::intern("id"), id).unwrap()
+                Dom.set_attribute(el, wasm_bindgen::intern("id"), id)
             }
 
             id_inner(self.element.as_ref(), &id);
@@ -594,7 +690,7 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         name: impl Into<Cow<'static, str>>,
         attr: impl IntoAttribute,
     ) -> Self {
-        let name = name.into();
+        let name = self.element.attribute_name(name.into());
 
         #[cfg(all(target_arch = "wasm32", feature = "web"))]
         {
@@ -613,6 +709,11 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
             let mut this = self;
 
             let mut attr = attr.into_attribute(this.cx);
+            if let Attribute::Fn(..) = attr {
+                if crate::renderer::reactive_views() {
+                    this.bindings.attrs.push((name.clone(), attr.clone()));
+                }
+            }
             while let Attribute::Fn(_, f) = attr {
                 attr = f();
             }
@@ -706,6 +807,28 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         self.classes_inner(&classes.into())
     }
 
+    /// Adds each class in a [`ClassList`], toggling it on and off as its own
+    /// value changes, as if each had been added with [`HtmlElement::class`].
+    #[track_caller]
+    pub fn toggle_classes(self, classes: impl Into<ClassList>) -> Self {
+        let cx = self.cx;
+        classes
+            .into()
+            .into_classes(cx)
+            .fold(self, |this, (name, value)| this.class(name, value))
+    }
+
+    /// Sets the `class` attribute to the value of `class=` in the `view`
+    /// macro, which is either a single value or a [`ClassList`].
+    #[doc(hidden)]
+    #[track_caller]
+    pub fn class_attribute(self, value: impl IntoClassAttribute) -> Self {
+        match value.into_class_attribute(self.cx) {
+            ClassAttribute::Attribute(attr) => self.attr("class", attr),
+            ClassAttribute::List(classes) => self.toggle_classes(classes),
+        }
+    }
+
     /// Sets the class on the element as the class signal changes.
     #[track_caller]
     pub fn dyn_classes<I, C>(
@@ -804,6 +927,28 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         }
     }
 
+    /// Sets each property in a [`StyleList`], updating it as its own value
+    /// changes, as if each had been set with [`HtmlElement::style`].
+    #[track_caller]
+    pub fn styles(self, styles: impl Into<StyleList>) -> Self {
+        let cx = self.cx;
+        styles
+            .into()
+            .into_styles(cx)
+            .fold(self, |this, (name, value)| this.style(name, value))
+    }
+
+    /// Sets the `style` attribute to the value of `style=` in the `view`
+    /// macro, which is either a single value or a [`StyleList`].
+    #[doc(hidden)]
+    #[track_caller]
+    pub fn style_attribute(self, value: impl IntoStyleAttribute) -> Self {
+        match value.into_style_attribute(self.cx) {
+            StyleAttribute::Attribute(attr) => self.attr("style", attr),
+            StyleAttribute::List(styles) => self.styles(styles),
+        }
+    }
+
     /// Sets a style on an element.
     ///
     /// **Note**: In the builder syntax, this will be overwritten by the `style`
@@ -916,7 +1061,9 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
             let event_name = event.name();
 
             let key = event.event_delegation_key();
-            let event_handler = Box::new(event_handler);
+            let cx = self.cx;
+            let event_handler =
+                Box::new(move |ev| run_event_handler(cx, || event_handler(ev)));
 
             if E::BUBBLES {
                 add_event_listener(
@@ -940,10 +1087,19 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
 
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
         {
-            _ = event;
-            _ = event_handler;
+            let mut this = self;
+            if crate::renderer::reactive_views() {
+                let listener: Box<dyn FnMut(E::EventType)> =
+                    Box::new(event_handler);
+                this.bindings
+                    .listeners
+                    .push((event.name(), Rc::new(RefCell::new(listener))));
+            } else {
+                _ = event;
+                _ = event_handler;
+            }
 
-            self
+            this
         }
     }
 
@@ -981,6 +1137,118 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
         }
     }
 
+    /// Adds an event listener to this element that is only attached while
+    /// `enabled` returns `true`.
+    ///
+    /// `enabled` is reactive: whenever a signal it reads changes, the listener
+    /// is attached or removed, without re-creating the element. This is useful
+    /// for listeners that are only needed for a while, like `pointermove`
+    /// during a drag, which would otherwise run on every movement.
+    ///
+    /// The listener is attached directly to the element, rather than being
+    /// delegated, so it can be given options like [`ev::passive`](crate::ev::passive).
+    ///
+    /// ```rust
+    /// # use leptos::*;
+    /// # let runtime = create_runtime();
+    /// # create_scope(runtime, |cx| {
+    /// let (dragging, set_dragging) = create_signal(cx, false);
+    /// let (x, set_x) = create_signal(cx, 0);
+    ///
+    /// html::div(cx)
+    ///     .on(ev::pointerdown, move |_| set_dragging.set(true))
+    ///     .on(ev::pointerup, move |_| set_dragging.set(false))
+    ///     .on_when(
+    ///         ev::pointermove,
+    ///         move || dragging.get(),
+    ///         move |ev| set_x.set(ev.client_x()),
+    ///     )
+    ///     .child(move || x.get());
+    /// # });
+    /// # runtime.dispose();
+    /// ```
+    #[track_caller]
+    pub fn on_when<E: EventDescriptor + 'static>(
+        self,
+        event: E,
+        enabled: impl Fn() -> bool + 'static,
+        event_handler: impl FnMut(E::EventType) + 'static,
+    ) -> Self {
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        {
+            use std::rc::Rc;
+            use wasm_bindgen::prelude::Closure;
+
+            let target: web_sys::EventTarget =
+                self.element.as_ref().clone().unchecked_into();
+            let name = event.name();
+            let options = event.options().clone();
+            let capture = options
+                .as_ref()
+                .and_then(|options| options.get_capture())
+                .unwrap_or(false);
+            let cx = self.cx;
+            let mut event_handler = event_handler;
+            let cb = Closure::wrap(Box::new(move |ev| {
+                run_event_handler(cx, || event_handler(ev))
+            })
+                as Box<dyn FnMut(E::EventType)>)
+            .into_js_value();
+
+            let attached = Rc::new(Cell::new(false));
+            let set_attached = {
+                let attached = Rc::clone(&attached);
+                move |attach: bool| {
+                    if attach == attached.replace(attach) {
+                        return;
+                    }
+                    if attach {
+                        _ = match &options {
+                            Some(options) => target
+                                .add_event_listener_with_callback_and_add_event_listener_options(
+                                    &name,
+                                    cb.unchecked_ref(),
+                                    options,
+                                ),
+                            None => target.add_event_listener_with_callback(
+                                &name,
+                                cb.unchecked_ref(),
+                            ),
+                        };
+                    } else {
+                        _ = target
+                            .remove_event_listener_with_callback_and_bool(
+                                &name,
+                                cb.unchecked_ref(),
+                                capture,
+                            );
+                    }
+                }
+            };
+            let set_attached = Rc::new(set_attached);
+
+            leptos_reactive::create_effect(self.cx, {
+                let set_attached = Rc::clone(&set_attached);
+                move |_| set_attached(enabled())
+            });
+            leptos_reactive::on_cleanup(self.cx, move || set_attached(false));
+        }
+
+        #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+        {
+            _ = (event, enabled, event_handler);
+        }
+
+        self
+    }
+
+    /// Adds each of the views as a separate child of this element, in order.
+    #[doc(hidden)]
+    #[track_caller]
+    pub fn child_views(self, views: Vec<View>) -> Self {
+        views.into_iter().fold(self, Self::child)
+    }
+
     /// Adds a child to this element.
     #[track_caller]
     pub fn child(self, child: impl IntoView) -> Self {
@@ -1028,16 +1296,32 @@ impl<El: ElementDescriptor + 'static> HtmlElement<El> {
     /// string slice.
     ///
     /// # Security
-    /// Be very careful when using this method. Always remember to
-    /// sanitize the input to avoid a cross-site scripting (XSS)
-    /// vulnerability.
+    /// The HTML is cleaned by the [`HtmlSanitizer`](crate::HtmlSanitizer) set
+    /// with [`set_html_sanitizer`](crate::set_html_sanitizer), or by `ammonia`
+    /// if the `sanitize` feature is enabled. Without either, it is set as it
+    /// is, so always remember to sanitize the input to avoid a cross-site
+    /// scripting (XSS) vulnerability.
     #[inline(always)]
     pub fn inner_html(self, html: impl Into<Cow<'static, str>>) -> Self {
+        self.dangerously_set_inner_html(crate::sanitize_html(html))
+    }
+
+    /// Sets the inner HTML of this element from the provided string slice,
+    /// without sanitizing it.
+    ///
+    /// # Security
+    /// Only use this for HTML that you trust completely, like HTML that is
+    /// part of your app, and never for anything a user could have written.
+    #[inline(always)]
+    pub fn dangerously_set_inner_html(
+        self,
+        html: impl Into<Cow<'static, str>>,
+    ) -> Self {
         let html = html.into();
 
         #[cfg(all(target_arch = "wasm32", feature = "web"))]
         {
-            self.element.as_ref().set_inner_html(&html);
+            Dom.set_inner_html(self.element.as_ref(), &html);
 
             self
         }
@@ -1059,6 +1343,22 @@ impl<El: ElementDescriptor> IntoView for HtmlElement<El> {
     fn into_view(self, _: Scope) -> View {
         #[cfg(all(target_arch = "wasm32", feature = "web"))]
         {
+            // if this replaced a mismatched element, hydration carries on
+            #[cfg(all(debug_assertions, feature = "hydrate"))]
+            crate::hydration::finish_client_rendering(self.element.as_ref());
+
+            // the server marks the view with comments around the element, so
+            // that hot reloading can find it, but it can't have siblings yet
+            #[cfg(debug_assertions)]
+            if let Some(id) = &self.view_marker {
+                if !HydrationCtx::is_hydrating() {
+                    _ = self
+                        .element
+                        .as_ref()
+                        .set_attribute("data-leptos-view", id);
+                }
+            }
+
             View::Element(Element::new(self.element))
         }
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
@@ -1067,6 +1367,7 @@ impl<El: ElementDescriptor> IntoView for HtmlElement<El> {
                 element,
                 mut attrs,
                 children,
+                bindings,
                 #[cfg(debug_assertions)]
                 view_marker,
                 ..
@@ -1085,6 +1386,8 @@ impl<El: ElementDescriptor> IntoView for HtmlElement<El> {
 
             element.attrs = attrs;
             element.children = children;
+            element.bindings =
+                (!bindings.is_empty()).then(|| Box::new(bindings));
 
             #[cfg(debug_assertions)]
             {
@@ -1127,6 +1430,108 @@ pub fn text(text: impl Into<Cow<'static, str>>) -> Text {
     Text::new(text.into())
 }
 
+/// An element, with its attributes and children, which is created by cloning
+/// a `<template>` that is parsed from its HTML once, rather than an element
+/// at a time.
+///
+/// The `view` macro creates one for each element of a view that can be
+/// written out as HTML, with the `csr` feature. What can't be, like a dynamic
+/// attribute or a child that is a block, is added to the copy afterwards,
+/// with [`element`](Self::element) and [`mount_child`](Self::mount_child).
+#[doc(hidden)]
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+pub struct StaticTemplate {
+    #[cfg(debug_assertions)]
+    name: &'static str,
+    template: web_sys::HtmlTemplateElement,
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+impl StaticTemplate {
+    /// Parses the HTML of an element named `name`.
+    pub fn new(name: &'static str, html: &'static str) -> Self {
+        use wasm_bindgen::JsCast;
+
+        _ = name;
+        let template: web_sys::HtmlTemplateElement =
+            Dom.create_element("template").unchecked_into();
+        Dom.set_inner_html(&template, html);
+
+        Self {
+            #[cfg(debug_assertions)]
+            name,
+            template,
+        }
+    }
+
+    /// Creates a copy of the element.
+    pub fn instantiate(&self) -> web_sys::HtmlElement {
+        use wasm_bindgen::JsCast;
+
+        self.template
+            .content()
+            .first_child()
+            .unwrap()
+            .clone_node_with_deep(true)
+            .unwrap()
+            .unchecked_into()
+    }
+
+    /// Returns the view for a copy of the element.
+    pub fn view(&self, root: web_sys::HtmlElement) -> View {
+        View::Element(Element {
+            #[cfg(debug_assertions)]
+            name: self.name.into(),
+            element: root,
+            #[cfg(debug_assertions)]
+            view_marker: None,
+        })
+    }
+
+    /// Returns the element named `tag` at `path` in a copy, to add the
+    /// attributes to it that aren't in the template.
+    pub fn element(
+        cx: Scope,
+        tag: &'static str,
+        root: &web_sys::HtmlElement,
+        path: &[u32],
+    ) -> HtmlElement<Custom> {
+        use wasm_bindgen::JsCast;
+
+        HtmlElement::new(
+            cx,
+            Custom {
+                name: tag.into(),
+                element: Self::node(root, path).unchecked_into(),
+            },
+        )
+    }
+
+    /// Mounts `child` in place of the comment at `path` in a copy.
+    pub fn mount_child(
+        cx: Scope,
+        root: &web_sys::HtmlElement,
+        path: &[u32],
+        child: impl IntoView,
+    ) {
+        let marker = Self::node(root, path);
+        let child = child.into_view(cx);
+        mount_child(MountKind::Before(&marker), &child);
+        Dom.remove(&marker);
+    }
+
+    // the node at `path`, given as the index of each child node on the way
+    // there from `root`
+    fn node(root: &web_sys::HtmlElement, path: &[u32]) -> web_sys::Node {
+        path.iter()
+            .fold(root.clone().into(), |node: web_sys::Node, &index| {
+                node.child_nodes()
+                    .item(index)
+                    .expect("node to be in the template")
+            })
+    }
+}
+
 macro_rules! generate_html_tags {
   ($(
     #[$meta:meta]
@@ -1138,10 +1543,7 @@ macro_rules! generate_html_tags {
         #[cfg(all(target_arch = "wasm32", feature = "web"))]
         thread_local! {
           static [<$tag:upper>]: LazyCell<web_sys::HtmlElement> = LazyCell::new(|| {
-            crate::document()
-              .create_element(stringify!($tag))
-              .unwrap()
-              .unchecked_into()
+            Dom.create_element(stringify!($tag)).unchecked_into()
           });
         }
 
@@ -1256,7 +1658,7 @@ fn create_leptos_element(
     id: crate::HydrationKey,
     clone_element: fn() -> web_sys::HtmlElement,
 ) -> web_sys::HtmlElement {
-    #[cfg(not(debug_assertions))]
+    #[cfg(not(all(debug_assertions, feature = "hydrate")))]
     {
         _ = tag;
     }
@@ -1264,16 +1666,13 @@ fn create_leptos_element(
     if HydrationCtx::is_hydrating() {
         if let Some(el) = crate::document().get_element_by_id(&format!("_{id}"))
         {
-            #[cfg(debug_assertions)]
-            assert_eq!(
-                &el.node_name().to_ascii_uppercase(),
-                tag,
-                "SSR and CSR elements have the same `TopoId` but different \
-                 node kinds. This is either a discrepancy between SSR and CSR \
-                 rendering
-            logic, which is considered a bug, or it can also be a leptos \
-                 hydration issue."
-            );
+            #[cfg(all(debug_assertions, feature = "hydrate"))]
+            if !el.node_name().eq_ignore_ascii_case(tag) {
+                return crate::hydration::element_mismatch(id, el, || {
+                    clone_element().unchecked_into()
+                })
+                .unchecked_into();
+            }
 
             el.remove_attribute("id").unwrap();
 
@@ -1281,22 +1680,25 @@ fn create_leptos_element(
         } else if let Ok(Some(el)) =
             crate::document().query_selector(&format!("[leptos-hk=_{id}]"))
         {
-            #[cfg(debug_assertions)]
-            assert_eq!(
-                el.node_name().to_ascii_uppercase(),
-                tag,
-                "SSR and CSR elements have the same `TopoId` but different \
-                 node kinds. This is either a discrepancy between SSR and CSR \
-                 rendering
-            logic, which is considered a bug, or it can also be a leptos \
-                 hydration issue."
-            );
+            #[cfg(all(debug_assertions, feature = "hydrate"))]
+            if !el.node_name().eq_ignore_ascii_case(tag) {
+                return crate::hydration::element_mismatch(id, el, || {
+                    clone_element().unchecked_into()
+                })
+                .unchecked_into();
+            }
 
             el.remove_attribute("leptos-hk").unwrap();
 
             el.unchecked_into()
         } else {
             if !is_meta_tag() {
+                #[cfg(all(debug_assertions, feature = "hydrate"))]
+                crate::hydration::missing_node(
+                    &format!("_{id}"),
+                    &format!("<{}>", tag.to_ascii_lowercase()),
+                );
+                #[cfg(not(all(debug_assertions, feature = "hydrate")))]
                 crate::warn!(
                     "element with id {id} not found, ignoring it for hydration"
                 );
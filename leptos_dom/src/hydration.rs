@@ -2,7 +2,9 @@ use cfg_if::cfg_if;
 use std::{cell::RefCell, fmt::Display};
 
 cfg_if! {
-  if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+  // Without the `hydrate` feature the app never starts out hydrating, so none of
+  // the DOM scanning below is compiled in.
+  if #[cfg(all(target_arch = "wasm32", feature = "hydrate"))] {
     use once_cell::unsync::Lazy as LazyCell;
     use std::collections::HashMap;
     use wasm_bindgen::JsCast;
@@ -63,6 +65,172 @@ cfg_if! {
     pub(crate) fn get_marker(id: &str) -> Option<web_sys::Comment> {
       HYDRATION_COMMENTS.with(|comments| comments.get(id).cloned())
     }
+  } else if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+    pub(crate) fn get_marker(_id: &str) -> Option<web_sys::Comment> {
+      None
+    }
+  }
+}
+
+cfg_if! {
+  // Hydration mismatches are only looked for in debug builds.
+  if #[cfg(all(debug_assertions, target_arch = "wasm32", feature = "hydrate"))] {
+    use std::{borrow::Cow, cell::Cell};
+
+    thread_local! {
+      // The components whose views are being created, outermost first.
+      static COMPONENT_PATH: RefCell<Vec<Cow<'static, str>>> =
+        Default::default();
+      static MISMATCH_FALLBACK: Cell<bool> = const { Cell::new(false) };
+      // The element that replaced a mismatched server-rendered element, whose
+      // children are being created on the client, and the last hydration key
+      // in the server-rendered element, which hydration carries on after.
+      static CLIENT_RENDERED: RefCell<Option<(web_sys::Node, HydrationKey)>> =
+        Default::default();
+    }
+
+    /// Creates the view of the component `name` with `f`, so that hydration
+    /// mismatches in it can be traced back to it.
+    pub(crate) fn in_component<T>(
+      name: Cow<'static, str>,
+      f: impl FnOnce() -> T,
+    ) -> T {
+      COMPONENT_PATH.with(|path| path.borrow_mut().push(name));
+      let value = f();
+      COMPONENT_PATH.with(|path| path.borrow_mut().pop());
+      value
+    }
+
+    fn component_path() -> String {
+      COMPONENT_PATH.with(|path| {
+        let path = path.borrow();
+        if path.is_empty() {
+          return "the root view".to_string();
+        }
+        path
+          .iter()
+          .map(|name| format!("<{name}/>"))
+          .collect::<Vec<_>>()
+          .join(" > ")
+      })
+    }
+
+    fn serialize(node: &web_sys::Node) -> String {
+      match node.dyn_ref::<web_sys::Element>() {
+        Some(el) => el.outer_html(),
+        None => format!("{:?}", node.text_content().unwrap_or_default()),
+      }
+    }
+
+    // The last hydration key used in the server-rendered `el`, by it or by
+    // the elements and components inside it.
+    fn last_key(el: &web_sys::Element, id: HydrationKey) -> HydrationKey {
+      let mut last = id;
+      let mut update = |key: Option<HydrationKey>| {
+        if let Some(key) = key {
+          if (key.fragment, key.id) > (last.fragment, last.id) {
+            last = key;
+          }
+        }
+      };
+
+      let elements = el.query_selector_all("[id^='_'], [leptos-hk^='_']");
+      if let Ok(elements) = elements {
+        for i in 0..elements.length() {
+          let Some(el) = elements.item(i) else { continue };
+          let el = el.unchecked_into::<web_sys::Element>();
+          let key = el.get_attribute("leptos-hk").unwrap_or_else(|| el.id());
+          update(key[1..].parse().ok());
+        }
+      }
+      let walker = crate::document()
+        .create_tree_walker_with_what_to_show(el, 128)
+        .unwrap();
+      while let Ok(Some(node)) = walker.next_node() {
+        update(node.text_content().and_then(|content| {
+          let key = content.trim().strip_prefix("hk=_")?;
+          key.split('|').next()?.trim_end_matches(['o', 'c']).parse().ok()
+        }));
+      }
+      last
+    }
+
+    /// Reports that the server rendered a different element than the client
+    /// expected at `id`. Returns the element to use instead: the client's
+    /// element if the fallback is enabled, after it has replaced the server's.
+    pub(crate) fn element_mismatch(
+      id: HydrationKey,
+      server: web_sys::Element,
+      client: impl FnOnce() -> web_sys::Element,
+    ) -> web_sys::Element {
+      let client = client();
+      crate::error!(
+        "Hydration mismatch at _{id} in {}: the client expected \
+         <{}>, but the server rendered <{}>.\n  server: {}\n  client: {}",
+        component_path(),
+        client.node_name().to_ascii_lowercase(),
+        server.node_name().to_ascii_lowercase(),
+        serialize(&server),
+        serialize(&client),
+      );
+      if !MISMATCH_FALLBACK.with(Cell::get) {
+        panic!(
+          "hydration mismatch at _{id}, see the error above. Call \
+           `HydrationCtx::set_mismatch_fallback(true)` to render mismatched \
+           elements on the client instead."
+        );
+      }
+
+      let end = last_key(&server, id);
+      server.replace_with_with_node_1(&client).unwrap();
+      CLIENT_RENDERED.with(|rendered| {
+        *rendered.borrow_mut() = Some((client.clone().unchecked_into(), end))
+      });
+      client
+    }
+
+    /// Reports that the server didn't render the node the client expected at
+    /// `id`, e.g., `<div>` or `<Component/>`.
+    pub(crate) fn missing_node(id: &str, expected: &str) {
+      crate::warn!(
+        "Hydration mismatch at {id} in {}: the client expected {expected}, \
+         but the server didn't render it, so it is ignored for hydration.",
+        component_path(),
+      );
+    }
+
+    /// Reports that the server rendered different text than the client.
+    pub(crate) fn text_mismatch(server: &str, client: &str) {
+      crate::warn!(
+        "Hydration mismatch in {}: the server rendered the text {server:?}, \
+         but the client rendered {client:?}, which replaces it.",
+        component_path(),
+      );
+    }
+
+    /// Finishes rendering the client's replacement for a mismatched element,
+    /// once `el` is done if it is that element, and carries on hydrating the
+    /// rest of the page after the server-rendered element.
+    pub(crate) fn finish_client_rendering(el: &web_sys::Node) {
+      let end = CLIENT_RENDERED.with(|rendered| {
+        let mut rendered = rendered.borrow_mut();
+        match &*rendered {
+          Some((root, end)) if root.is_same_node(Some(el)) => {
+            let end = *end;
+            *rendered = None;
+            Some(end)
+          }
+          _ => None,
+        }
+      });
+      if let Some(end) = end {
+        HydrationCtx::continue_from(end);
+      }
+    }
+
+    fn is_client_rendering() -> bool {
+      CLIENT_RENDERED.with(|rendered| rendered.borrow().is_some())
+    }
   }
 }
 
@@ -81,6 +249,18 @@ impl Display for HydrationKey {
     }
 }
 
+impl std::str::FromStr for HydrationKey {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (fragment, id) = s.split_once('-').ok_or(())?;
+        Ok(HydrationKey {
+            fragment: fragment.parse().map_err(|_| ())?,
+            id: id.parse().map_err(|_| ())?,
+        })
+    }
+}
+
 thread_local!(static ID: RefCell<HydrationKey> = Default::default());
 
 /// Control and utility methods for hydration.
@@ -123,16 +303,100 @@ impl HydrationCtx {
         ID.with(|i| *i.borrow_mut() = id);
     }
 
+    /// Sets what happens, in debug builds, when an element that is being
+    /// hydrated doesn’t match the server-rendered HTML. The mismatch is
+    /// always logged, with the components it is in and both versions of the
+    /// element. By default, hydration then panics. With the fallback, the
+    /// server-rendered element is replaced by one rendered on the client,
+    /// along with everything inside it, and hydration carries on after it.
+    ///
+    /// Mismatches aren’t looked for in release builds, so this has no effect
+    /// there.
+    pub fn set_mismatch_fallback(enabled: bool) {
+        #[cfg(all(
+            debug_assertions,
+            target_arch = "wasm32",
+            feature = "hydrate"
+        ))]
+        MISMATCH_FALLBACK.with(|fallback| fallback.set(enabled));
+
+        #[cfg(not(all(
+            debug_assertions,
+            target_arch = "wasm32",
+            feature = "hydrate"
+        )))]
+        let _ = enabled;
+    }
+
     #[cfg(all(target_arch = "wasm32", feature = "web"))]
     pub(crate) fn stop_hydrating() {
+        #[cfg(feature = "hydrate")]
         IS_HYDRATING.with(|is_hydrating| {
             std::mem::take(&mut *is_hydrating.borrow_mut());
         })
     }
 
+    /// Whether the view that is being created will hydrate existing,
+    /// server-rendered HTML, rather than creating new DOM nodes.
+    ///
+    /// This is always `false` unless the `hydrate` feature is enabled, so
+    /// that client-side rendered apps don't include any of the code that
+    /// hydrates the DOM.
     #[cfg(all(target_arch = "wasm32", feature = "web"))]
-    pub(crate) fn is_hydrating() -> bool {
-        IS_HYDRATING.with(|is_hydrating| **is_hydrating.borrow())
+    #[inline(always)]
+    pub fn is_hydrating() -> bool {
+        #[cfg(all(debug_assertions, feature = "hydrate"))]
+        if is_client_rendering() {
+            return false;
+        }
+
+        #[cfg(feature = "hydrate")]
+        return IS_HYDRATING.with(|is_hydrating| **is_hydrating.borrow());
+
+        #[cfg(not(feature = "hydrate"))]
+        return false;
+    }
+
+    /// Hydrates the view created by `f` from the server-rendered HTML, starting
+    /// at the given `id`, even if hydration of the rest of the page has finished.
+    #[cfg(all(target_arch = "wasm32", feature = "hydrate"))]
+    pub(crate) fn hydrate_from<T>(
+        id: HydrationKey,
+        f: impl FnOnce() -> T,
+    ) -> T {
+        let prev_id = Self::peek();
+        let was_hydrating = IS_HYDRATING.with(|is_hydrating| {
+            std::mem::replace(
+                &mut *is_hydrating.borrow_mut(),
+                LazyCell::new(|| true),
+            )
+        });
+        Self::continue_from(id);
+
+        let value = f();
+
+        Self::continue_from(prev_id);
+        IS_HYDRATING
+            .with(|is_hydrating| *is_hydrating.borrow_mut() = was_hydrating);
+        value
+    }
+
+    /// Creates the view with `f` as new DOM nodes, even while the rest of the
+    /// page is being hydrated.
+    #[cfg(all(target_arch = "wasm32", feature = "hydrate"))]
+    pub(crate) fn render_without_hydrating<T>(f: impl FnOnce() -> T) -> T {
+        let was_hydrating = IS_HYDRATING.with(|is_hydrating| {
+            std::mem::replace(
+                &mut *is_hydrating.borrow_mut(),
+                LazyCell::new(|| false),
+            )
+        });
+
+        let value = f();
+
+        IS_HYDRATING
+            .with(|is_hydrating| *is_hydrating.borrow_mut() = was_hydrating);
+        value
     }
 
     pub(crate) fn to_string(id: &HydrationKey, closing: bool) -> String {
@@ -0,0 +1,895 @@
+//! Locale-aware formatting of numbers and dates, which updates whenever the
+//! locale changes.
+//!
+//! In the browser, values are formatted with the
+//! [`Intl`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl)
+//! API. The server can’t use it, so it formats them with a fallback written
+//! in Rust, which knows the conventions of English (`en`, `en-GB`), German
+//! (`de`), French (`fr`), Spanish (`es`) and Japanese (`ja`), and formats
+//! every other locale as `en-US`. While the page is being hydrated, the
+//! browser uses the same fallback, so the text it creates always matches the
+//! HTML rendered on the server.
+//!
+//! The locale is a signal provided with [`provide_locale`], which defaults to
+//! `en-US`.
+//!
+//! ```rust
+//! # use leptos::*;
+//! use leptos::intl::{DateFormatOptions, DateStyle, NumberFormatOptions, NumberStyle};
+//!
+//! # let runtime = create_runtime();
+//! # create_scope(runtime, |cx| {
+//! let (locale, set_locale) = create_signal(cx, "de-DE".to_string());
+//! provide_locale(cx, locale);
+//!
+//! let price = use_number_format(
+//!     cx,
+//!     NumberFormatOptions {
+//!         style: NumberStyle::Currency("EUR".into()),
+//!         ..Default::default()
+//!     },
+//! );
+//! let date = use_date_format(
+//!     cx,
+//!     DateFormatOptions {
+//!         date_style: Some(DateStyle::Long),
+//!         ..Default::default()
+//!     },
+//! );
+//!
+//! # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+//! assert_eq!(price.format(1234.5), "1.234,50\u{a0}€");
+//! assert_eq!(date.format(1_706_713_509_000.0), "31. Januar 2024");
+//!
+//! set_locale.set("en-US".to_string());
+//! assert_eq!(price.format(1234.5), "€1,234.50");
+//! assert_eq!(date.format(1_706_713_509_000.0), "January 31, 2024");
+//! # }
+//! # });
+//! # runtime.dispose();
+//! ```
+
+use leptos_reactive::{
+    provide_context, store_value, use_context, MaybeSignal, Scope, Signal,
+    SignalWith, StoredValue,
+};
+use std::borrow::Cow;
+
+/// The locale used when none has been provided with [`provide_locale`].
+pub const DEFAULT_LOCALE: &str = "en-US";
+
+#[derive(Clone, Copy)]
+struct LocaleContext(Signal<String>);
+
+/// Provides the locale, as a [BCP 47 language tag](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl#locales_argument)
+/// like `"en-US"`, for the formatters created in this scope and its
+/// children.
+pub fn provide_locale(cx: Scope, locale: impl Into<MaybeSignal<String>>) {
+    let locale = match locale.into() {
+        MaybeSignal::Static(locale) => {
+            Signal::derive(cx, move || locale.clone())
+        }
+        MaybeSignal::Dynamic(locale) => locale,
+    };
+    provide_context(cx, LocaleContext(locale));
+}
+
+/// The locale provided with [`provide_locale`], or [`DEFAULT_LOCALE`] if
+/// there isn’t one.
+pub fn use_locale(cx: Scope) -> Signal<String> {
+    use_context::<LocaleContext>(cx)
+        .map(|LocaleContext(locale)| locale)
+        .unwrap_or_else(|| Signal::derive(cx, || DEFAULT_LOCALE.to_string()))
+}
+
+/// How a number is formatted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum NumberStyle {
+    /// A plain number.
+    #[default]
+    Decimal,
+    /// A percentage, where `1.0` is `100%`.
+    Percent,
+    /// An amount of money in the currency with this
+    /// [ISO 4217 code](https://en.wikipedia.org/wiki/ISO_4217), like `"EUR"`.
+    Currency(Cow<'static, str>),
+}
+
+/// Options for [`use_number_format`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberFormatOptions {
+    /// How the number is formatted.
+    pub style: NumberStyle,
+    /// The fewest digits shown after the decimal separator. Defaults to `0`,
+    /// or the number of minor units of a currency.
+    pub minimum_fraction_digits: Option<usize>,
+    /// The most digits shown after the decimal separator. Defaults to `3`
+    /// for decimals, `0` for percentages, and the number of minor units of a
+    /// currency.
+    pub maximum_fraction_digits: Option<usize>,
+    /// Whether thousands are separated, like `1,000`. Defaults to `true`.
+    pub use_grouping: bool,
+}
+
+impl Default for NumberFormatOptions {
+    fn default() -> Self {
+        Self {
+            style: NumberStyle::Decimal,
+            minimum_fraction_digits: None,
+            maximum_fraction_digits: None,
+            use_grouping: true,
+        }
+    }
+}
+
+impl NumberFormatOptions {
+    // the fraction digits to use, filled in the same way `Intl` does, so that
+    // both formatters are always given the same ones
+    fn fraction_digits(&self) -> (usize, usize) {
+        let (min, max) = match &self.style {
+            NumberStyle::Decimal => (0, 3),
+            NumberStyle::Percent => (0, 0),
+            NumberStyle::Currency(code) => {
+                let digits = currency_digits(code);
+                (digits, digits)
+            }
+        };
+        match (self.minimum_fraction_digits, self.maximum_fraction_digits) {
+            (None, None) => (min, max),
+            (Some(min), None) => (min, min.max(max)),
+            (None, Some(max)) => (min.min(max), max),
+            (Some(min), Some(max)) => (min, min.max(max)),
+        }
+    }
+}
+
+/// Formats numbers in the current locale. Created with [`use_number_format`].
+#[derive(Clone, Copy)]
+pub struct NumberFormat {
+    locale: Signal<String>,
+    options: StoredValue<NumberFormatOptions>,
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    intl: StoredValue<Option<(String, js_sys::Function)>>,
+}
+
+/// Creates a formatter for numbers in the locale provided with
+/// [`provide_locale`].
+///
+/// Calling [`NumberFormat::format`] inside a reactive closure formats the
+/// number again whenever the locale changes.
+pub fn use_number_format(
+    cx: Scope,
+    options: NumberFormatOptions,
+) -> NumberFormat {
+    NumberFormat {
+        locale: use_locale(cx),
+        options: store_value(cx, options),
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        intl: store_value(cx, None),
+    }
+}
+
+impl NumberFormat {
+    /// Formats the number, subscribing to the locale.
+    pub fn format(&self, value: f64) -> String {
+        self.locale.with(|locale| {
+            let locale = valid_locale(locale);
+
+            #[cfg(all(target_arch = "wasm32", feature = "web"))]
+            {
+                if !crate::HydrationCtx::is_hydrating() {
+                    let format = cached_intl_format(self.intl, locale, || {
+                        let options =
+                            self.options.with_value(intl_number_options);
+                        js_sys::Intl::NumberFormat::new(
+                            &js_sys::Array::of1(&locale.into()),
+                            &options,
+                        )
+                        .format()
+                    });
+                    return call_intl_format(&format, &value.into());
+                }
+            }
+
+            self.options.with_value(|options| {
+                format_number(Locale::from_tag(locale), options, value)
+            })
+        })
+    }
+}
+
+/// The amount of detail a date is shown with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DateStyle {
+    /// Only numbers, like `1/31/24`.
+    Short,
+    /// An abbreviated month, like `Jan 31, 2024`.
+    Medium,
+    /// The full month, like `January 31, 2024`.
+    Long,
+}
+
+/// The amount of detail a time is shown with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeStyle {
+    /// Hours and minutes, like `3:05 PM`.
+    Short,
+    /// Hours, minutes and seconds, like `3:05:09 PM`.
+    Medium,
+}
+
+/// Options for [`use_date_format`].
+///
+/// Dates are always shown in UTC, since the server can’t know the user’s time
+/// zone.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DateFormatOptions {
+    /// How the date is shown, if at all. Defaults to [`DateStyle::Medium`].
+    pub date_style: Option<DateStyle>,
+    /// How the time is shown, if at all. Defaults to not showing it.
+    pub time_style: Option<TimeStyle>,
+}
+
+impl Default for DateFormatOptions {
+    fn default() -> Self {
+        Self {
+            date_style: Some(DateStyle::Medium),
+            time_style: None,
+        }
+    }
+}
+
+/// Formats dates in the current locale. Created with [`use_date_format`].
+#[derive(Clone, Copy)]
+pub struct DateFormat {
+    locale: Signal<String>,
+    options: StoredValue<DateFormatOptions>,
+    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+    intl: StoredValue<Option<(String, js_sys::Function)>>,
+}
+
+/// Creates a formatter for dates in the locale provided with
+/// [`provide_locale`].
+///
+/// Calling [`DateFormat::format`] inside a reactive closure formats the date
+/// again whenever the locale changes.
+pub fn use_date_format(cx: Scope, options: DateFormatOptions) -> DateFormat {
+    let options =
+        if options.date_style.is_none() && options.time_style.is_none() {
+            DateFormatOptions::default()
+        } else {
+            options
+        };
+    DateFormat {
+        locale: use_locale(cx),
+        options: store_value(cx, options),
+        #[cfg(all(target_arch = "wasm32", feature = "web"))]
+        intl: store_value(cx, None),
+    }
+}
+
+impl DateFormat {
+    /// Formats the date, given as the number of milliseconds since the Unix
+    /// epoch (like a JavaScript `Date`), subscribing to the locale.
+    pub fn format(&self, millis_since_epoch: f64) -> String {
+        self.locale.with(|locale| {
+            let locale = valid_locale(locale);
+
+            #[cfg(all(target_arch = "wasm32", feature = "web"))]
+            {
+                if !crate::HydrationCtx::is_hydrating() {
+                    let format = cached_intl_format(self.intl, locale, || {
+                        let options =
+                            self.options.with_value(intl_date_options);
+                        js_sys::Intl::DateTimeFormat::new(
+                            &js_sys::Array::of1(&locale.into()),
+                            &options,
+                        )
+                        .format()
+                    });
+                    let date = js_sys::Date::new(&millis_since_epoch.into());
+                    return call_intl_format(&format, &date);
+                }
+            }
+
+            self.options.with_value(|options| {
+                format_date(
+                    Locale::from_tag(locale),
+                    options,
+                    millis_since_epoch,
+                )
+            })
+        })
+    }
+}
+
+// `Intl` throws if it is given a malformed language tag
+fn valid_locale(locale: &str) -> &str {
+    let is_valid = !locale.is_empty()
+        && locale.split('-').all(|subtag| {
+            (1..=8).contains(&subtag.len())
+                && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+        });
+    if is_valid {
+        locale
+    } else {
+        DEFAULT_LOCALE
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn cached_intl_format(
+    cache: StoredValue<Option<(String, js_sys::Function)>>,
+    locale: &str,
+    create: impl FnOnce() -> js_sys::Function,
+) -> js_sys::Function {
+    let cached = cache.with_value(|cached| {
+        cached
+            .as_ref()
+            .filter(|(cached_locale, _)| cached_locale == locale)
+            .map(|(_, format)| format.clone())
+    });
+    cached.unwrap_or_else(|| {
+        let format = create();
+        cache.set_value(Some((locale.to_string(), format.clone())));
+        format
+    })
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn call_intl_format(
+    format: &js_sys::Function,
+    value: &wasm_bindgen::JsValue,
+) -> String {
+    format
+        .call1(&wasm_bindgen::JsValue::UNDEFINED, value)
+        .ok()
+        .and_then(|formatted| formatted.as_string())
+        .unwrap_or_default()
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn intl_number_options(options: &NumberFormatOptions) -> js_sys::Object {
+    let object = js_sys::Object::new();
+    let set = |key: &str, value: wasm_bindgen::JsValue| {
+        _ = js_sys::Reflect::set(&object, &key.into(), &value);
+    };
+    let (min, max) = options.fraction_digits();
+    match &options.style {
+        NumberStyle::Decimal => set("style", "decimal".into()),
+        NumberStyle::Percent => set("style", "percent".into()),
+        NumberStyle::Currency(code) => {
+            set("style", "currency".into());
+            set("currency", code.as_ref().into());
+        }
+    }
+    set("minimumFractionDigits", (min as u32).into());
+    set("maximumFractionDigits", (max as u32).into());
+    set("useGrouping", options.use_grouping.into());
+    object
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn intl_date_options(options: &DateFormatOptions) -> js_sys::Object {
+    let object = js_sys::Object::new();
+    let set = |key: &str, value: &str| {
+        _ = js_sys::Reflect::set(&object, &key.into(), &value.into());
+    };
+    if let Some(style) = options.date_style {
+        set(
+            "dateStyle",
+            match style {
+                DateStyle::Short => "short",
+                DateStyle::Medium => "medium",
+                DateStyle::Long => "long",
+            },
+        );
+    }
+    if let Some(style) = options.time_style {
+        set(
+            "timeStyle",
+            match style {
+                TimeStyle::Short => "short",
+                TimeStyle::Medium => "medium",
+            },
+        );
+    }
+    set("timeZone", "UTC");
+    object
+}
+
+// the locales the fallback formatter knows the conventions of
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Locale {
+    EnUs,
+    EnGb,
+    De,
+    Fr,
+    Es,
+    Ja,
+}
+
+impl Locale {
+    fn from_tag(tag: &str) -> Self {
+        let mut subtags = tag.split(['-', '_']);
+        let language = subtags.next().unwrap_or_default().to_ascii_lowercase();
+        match language.as_str() {
+            "en" if subtags.any(|subtag| subtag.eq_ignore_ascii_case("gb")) => {
+                Self::EnGb
+            }
+            "de" => Self::De,
+            "fr" => Self::Fr,
+            "es" => Self::Es,
+            "ja" => Self::Ja,
+            _ => Self::EnUs,
+        }
+    }
+
+    fn decimal_separator(self) -> &'static str {
+        match self {
+            Self::EnUs | Self::EnGb | Self::Ja => ".",
+            Self::De | Self::Fr | Self::Es => ",",
+        }
+    }
+
+    fn group_separator(self) -> &'static str {
+        match self {
+            Self::EnUs | Self::EnGb | Self::Ja => ",",
+            Self::De | Self::Es => ".",
+            Self::Fr => "\u{202f}",
+        }
+    }
+
+    // how many digits the integer part needs before it is grouped
+    fn minimum_grouping_digits(self) -> usize {
+        match self {
+            Self::Es => 5,
+            _ => 4,
+        }
+    }
+
+    // the space between a number and a percent sign or currency after it
+    fn unit_space(self) -> &'static str {
+        match self {
+            Self::EnUs | Self::EnGb | Self::Ja => "",
+            Self::De | Self::Es => "\u{a0}",
+            Self::Fr => "\u{202f}",
+        }
+    }
+
+    fn currency_symbol(self, code: &str) -> Cow<'static, str> {
+        match (self, code) {
+            (Self::EnGb, "USD") => "US$".into(),
+            (Self::EnGb, "JPY") => "JP¥".into(),
+            (Self::Ja, "JPY") => "￥".into(),
+            (_, "USD") => "$".into(),
+            (_, "EUR") => "€".into(),
+            (_, "GBP") => "£".into(),
+            (_, "JPY") => "¥".into(),
+            _ => code.to_string().into(),
+        }
+    }
+
+    fn months(self) -> &'static [&'static str; 12] {
+        match self {
+            Self::EnUs | Self::EnGb | Self::Ja => &[
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            Self::De => &[
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+            Self::Fr => &[
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+            Self::Es => &[
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ],
+        }
+    }
+
+    fn short_months(self) -> &'static [&'static str; 12] {
+        match self {
+            Self::Fr => &[
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.",
+                "août", "sept.", "oct.", "nov.", "déc.",
+            ],
+            Self::Es => &[
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept",
+                "oct", "nov", "dic",
+            ],
+            _ => &[
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep",
+                "Oct", "Nov", "Dec",
+            ],
+        }
+    }
+}
+
+fn currency_digits(code: &str) -> usize {
+    match code {
+        "JPY" | "KRW" | "VND" | "CLP" | "ISK" | "HUF" | "TWD" => 0,
+        "BHD" | "KWD" | "OMR" | "JOD" | "TND" => 3,
+        _ => 2,
+    }
+}
+
+fn format_number(
+    locale: Locale,
+    options: &NumberFormatOptions,
+    value: f64,
+) -> String {
+    let value = match options.style {
+        NumberStyle::Percent => value * 100.0,
+        _ => value,
+    };
+    let number = if value.is_nan() {
+        return "NaN".to_string();
+    } else if value.is_infinite() {
+        "∞".to_string()
+    } else {
+        let (min, max) = options.fraction_digits();
+        let (int, mut fraction) = round_half_expand(value.abs(), max);
+        while fraction.len() < min {
+            fraction.push('0');
+        }
+        while fraction.len() > min && fraction.ends_with('0') {
+            fraction.pop();
+        }
+        let int = int.as_str();
+
+        let mut number = String::new();
+        if options.use_grouping && int.len() >= locale.minimum_grouping_digits()
+        {
+            for (i, digit) in int.chars().enumerate() {
+                if i > 0 && (int.len() - i) % 3 == 0 {
+                    number.push_str(locale.group_separator());
+                }
+                number.push(digit);
+            }
+        } else {
+            number.push_str(int);
+        }
+        if !fraction.is_empty() {
+            number.push_str(locale.decimal_separator());
+            number.push_str(&fraction);
+        }
+        number
+    };
+
+    // -0 and values that round to zero have no sign
+    let is_negative = value < 0.0
+        && number.chars().any(|c| ('1'..='9').contains(&c) || c == '∞');
+    let sign = if is_negative { "-" } else { "" };
+    match &options.style {
+        NumberStyle::Decimal => format!("{sign}{number}"),
+        NumberStyle::Percent => {
+            format!("{sign}{number}{}%", locale.unit_space())
+        }
+        NumberStyle::Currency(code) => {
+            let symbol = locale.currency_symbol(code);
+            match locale {
+                Locale::De | Locale::Fr | Locale::Es => {
+                    format!("{sign}{number}\u{a0}{symbol}")
+                }
+                _ if symbol.chars().all(|c| c.is_ascii_alphabetic()) => {
+                    format!("{sign}{symbol}\u{a0}{number}")
+                }
+                _ => format!("{sign}{symbol}{number}"),
+            }
+        }
+    }
+}
+
+// rounds the shortest decimal representation of a non-negative number to at
+// most `digits` fraction digits, with ties going up, like `Intl` does, and
+// returns its integer and fraction digits
+fn round_half_expand(value: f64, digits: usize) -> (String, String) {
+    let shortest = value.to_string();
+    let (int, fraction) =
+        shortest.split_once('.').unwrap_or((shortest.as_str(), ""));
+    if fraction.len() <= digits {
+        return (int.to_string(), fraction.to_string());
+    }
+
+    let mut kept = format!("{int}{}", &fraction[..digits]).into_bytes();
+    if fraction.as_bytes()[digits] >= b'5' {
+        let mut carry = true;
+        for digit in kept.iter_mut().rev() {
+            if *digit == b'9' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            kept.insert(0, b'1');
+        }
+    }
+    let kept = String::from_utf8(kept).expect("digits are ASCII");
+    let (int, fraction) = kept.split_at(kept.len() - digits);
+    (int.to_string(), fraction.to_string())
+}
+
+// the UTC date and time of a timestamp: (year, month, day, hour, minute, second)
+fn civil_from_millis(millis: f64) -> (i64, usize, u32, u32, u32, u32) {
+    let seconds = (millis / 1000.0).floor() as i64;
+    let (days, seconds) =
+        (seconds.div_euclid(86_400), seconds.rem_euclid(86_400));
+    let seconds = seconds as u32;
+
+    // Howard Hinnant's `civil_from_days`
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as usize;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60,
+    )
+}
+
+fn format_date(
+    locale: Locale,
+    options: &DateFormatOptions,
+    millis_since_epoch: f64,
+) -> String {
+    if !millis_since_epoch.is_finite() {
+        return "Invalid Date".to_string();
+    }
+    let (year, month, day, hour, minute, second) =
+        civil_from_millis(millis_since_epoch);
+    let short_year = year.rem_euclid(100);
+    let month_name = locale.months()[month - 1];
+    let short_month_name = locale.short_months()[month - 1];
+
+    let date = options.date_style.map(|style| match (locale, style) {
+        (Locale::EnUs, DateStyle::Short) => {
+            format!("{month}/{day}/{short_year:02}")
+        }
+        (Locale::EnUs, DateStyle::Medium) => {
+            format!("{short_month_name} {day}, {year}")
+        }
+        (Locale::EnUs, DateStyle::Long) => {
+            format!("{month_name} {day}, {year}")
+        }
+        (Locale::EnGb | Locale::Fr, DateStyle::Short) => {
+            format!("{day:02}/{month:02}/{year}")
+        }
+        (Locale::EnGb | Locale::Fr, DateStyle::Medium) => {
+            format!("{day} {short_month_name} {year}")
+        }
+        (Locale::EnGb | Locale::Fr, DateStyle::Long) => {
+            format!("{day} {month_name} {year}")
+        }
+        (Locale::De, DateStyle::Short) => {
+            format!("{day:02}.{month:02}.{short_year:02}")
+        }
+        (Locale::De, DateStyle::Medium) => {
+            format!("{day:02}.{month:02}.{year}")
+        }
+        (Locale::De, DateStyle::Long) => format!("{day}. {month_name} {year}"),
+        (Locale::Es, DateStyle::Short) => {
+            format!("{day}/{month}/{short_year:02}")
+        }
+        (Locale::Es, DateStyle::Medium) => {
+            format!("{day} {short_month_name} {year}")
+        }
+        (Locale::Es, DateStyle::Long) => {
+            format!("{day} de {month_name} de {year}")
+        }
+        (Locale::Ja, DateStyle::Short | DateStyle::Medium) => {
+            format!("{year}/{month:02}/{day:02}")
+        }
+        (Locale::Ja, DateStyle::Long) => format!("{year}年{month}月{day}日"),
+    });
+
+    let time = options.time_style.map(|style| {
+        let time = if locale == Locale::EnUs {
+            let (hour, period) = match hour {
+                0 => (12, "AM"),
+                1..=11 => (hour, "AM"),
+                12 => (12, "PM"),
+                _ => (hour - 12, "PM"),
+            };
+            match style {
+                TimeStyle::Short => {
+                    format!("{hour}:{minute:02}\u{202f}{period}")
+                }
+                TimeStyle::Medium => {
+                    format!("{hour}:{minute:02}:{second:02}\u{202f}{period}")
+                }
+            }
+        } else {
+            match style {
+                TimeStyle::Short => format!("{hour:02}:{minute:02}"),
+                TimeStyle::Medium => {
+                    format!("{hour:02}:{minute:02}:{second:02}")
+                }
+            }
+        };
+        // Japanese doesn't pad the hour
+        if locale == Locale::Ja && time.starts_with('0') {
+            time[1..].to_string()
+        } else {
+            time
+        }
+    });
+
+    match (date, time) {
+        (Some(date), Some(time)) => {
+            let separator = if locale == Locale::Ja { " " } else { ", " };
+            format!("{date}{separator}{time}")
+        }
+        (Some(date), None) => date,
+        (None, Some(time)) => time,
+        (None, None) => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(locale: &str, style: NumberStyle, value: f64) -> String {
+        format_number(
+            Locale::from_tag(locale),
+            &NumberFormatOptions {
+                style,
+                ..Default::default()
+            },
+            value,
+        )
+    }
+
+    fn date(
+        locale: &str,
+        date_style: Option<DateStyle>,
+        time_style: Option<TimeStyle>,
+    ) -> String {
+        // 2024-01-31T15:05:09Z
+        format_date(
+            Locale::from_tag(locale),
+            &DateFormatOptions {
+                date_style,
+                time_style,
+            },
+            1_706_713_509_000.0,
+        )
+    }
+
+    #[test]
+    fn numbers_use_the_locale_separators() {
+        let n = 1_234_567.891_2;
+        assert_eq!(number("en-US", NumberStyle::Decimal, n), "1,234,567.891");
+        assert_eq!(number("de-DE", NumberStyle::Decimal, n), "1.234.567,891");
+        assert_eq!(
+            number("fr-FR", NumberStyle::Decimal, n),
+            "1\u{202f}234\u{202f}567,891"
+        );
+        assert_eq!(number("es", NumberStyle::Decimal, 1234.0), "1234");
+        assert_eq!(number("es", NumberStyle::Decimal, 12345.0), "12.345");
+        assert_eq!(number("xx-YY", NumberStyle::Decimal, -0.5), "-0.5");
+        assert_eq!(number("en", NumberStyle::Decimal, -0.0001), "0");
+    }
+
+    #[test]
+    fn percentages_and_currencies() {
+        assert_eq!(number("en-US", NumberStyle::Percent, 0.256), "26%");
+        assert_eq!(number("de", NumberStyle::Percent, 0.256), "26\u{a0}%");
+        let eur = || NumberStyle::Currency("EUR".into());
+        assert_eq!(number("en-US", eur(), -1234.5), "-€1,234.50");
+        assert_eq!(number("de-DE", eur(), 1234.5), "1.234,50\u{a0}€");
+        assert_eq!(
+            number("ja-JP", NumberStyle::Currency("JPY".into()), 1234.5),
+            "￥1,235"
+        );
+        assert_eq!(
+            number("en-US", NumberStyle::Currency("CHF".into()), 3.0),
+            "CHF\u{a0}3.00"
+        );
+    }
+
+    #[test]
+    fn fraction_digits_follow_intl_defaults() {
+        let options = |min, max| NumberFormatOptions {
+            minimum_fraction_digits: min,
+            maximum_fraction_digits: max,
+            ..Default::default()
+        };
+        assert_eq!(options(None, None).fraction_digits(), (0, 3));
+        assert_eq!(options(Some(5), None).fraction_digits(), (5, 5));
+        assert_eq!(options(None, Some(1)).fraction_digits(), (0, 1));
+        assert_eq!(options(Some(2), Some(1)).fraction_digits(), (2, 2));
+    }
+
+    #[test]
+    fn dates_use_the_locale_conventions() {
+        use DateStyle::*;
+
+        assert_eq!(date("en-US", Some(Short), None), "1/31/24");
+        assert_eq!(date("en-US", Some(Medium), None), "Jan 31, 2024");
+        assert_eq!(date("en-GB", Some(Short), None), "31/01/2024");
+        assert_eq!(date("de", Some(Long), None), "31. Januar 2024");
+        assert_eq!(date("fr", Some(Medium), None), "31 janv. 2024");
+        assert_eq!(date("es", Some(Long), None), "31 de enero de 2024");
+        assert_eq!(date("ja", Some(Long), None), "2024年1月31日");
+        assert_eq!(
+            date("en-US", Some(Medium), Some(TimeStyle::Short)),
+            "Jan 31, 2024, 3:05\u{202f}PM"
+        );
+        assert_eq!(date("de", None, Some(TimeStyle::Medium)), "15:05:09");
+    }
+
+    #[test]
+    fn dates_before_the_epoch() {
+        assert_eq!(civil_from_millis(0.0), (1970, 1, 1, 0, 0, 0));
+        assert_eq!(civil_from_millis(-1000.0), (1969, 12, 31, 23, 59, 59));
+        assert_eq!(
+            civil_from_millis(951_782_400_000.0),
+            (2000, 2, 29, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn malformed_locales_fall_back_to_the_default() {
+        assert_eq!(valid_locale("de-CH"), "de-CH");
+        assert_eq!(valid_locale(""), DEFAULT_LOCALE);
+        assert_eq!(valid_locale("en US"), DEFAULT_LOCALE);
+    }
+}
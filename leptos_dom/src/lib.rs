@@ -8,27 +8,43 @@
 #[doc(hidden)]
 #[cfg_attr(any(debug_assertions, feature = "ssr"), macro_use)]
 pub extern crate tracing;
+// used by the functions `#[island]` exports
+#[doc(hidden)]
+pub use {wasm_bindgen, web_sys};
 
+pub mod a11y;
+mod bind;
 mod components;
+mod custom_element;
+mod directive;
 mod events;
 pub mod helpers;
 pub mod html;
 mod hydration;
+pub mod intl;
+mod liquid_mount;
 mod logging;
 mod macro_helpers;
 pub mod math;
 mod node_ref;
+pub mod renderer;
+mod sanitize;
+mod spread;
 pub mod ssr;
 pub mod ssr_in_order;
 pub mod svg;
 mod transparent;
+pub use bind::*;
 use cfg_if::cfg_if;
 pub use components::*;
+pub use custom_element::*;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 pub use events::add_event_helper;
-pub use events::typed as ev;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use events::{add_event_listener, add_event_listener_undelegated};
+pub use events::{
+    set_effect_flush, typed as ev, EffectFlush, HYDRATION_EVENT_RECORDER,
+};
 pub use html::HtmlElement;
 use html::{AnyElement, ElementDescriptor};
 pub use hydration::{HydrationCtx, HydrationKey};
@@ -37,13 +53,18 @@ use leptos_reactive::Scope;
 use leptos_reactive::{
     MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalGet,
 };
+pub use liquid_mount::*;
 pub use logging::*;
 pub use macro_helpers::*;
 pub use node_ref::*;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use once_cell::unsync::Lazy as LazyCell;
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+use renderer::{Dom, Renderer};
+pub use sanitize::*;
 #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
 use smallvec::SmallVec;
+pub use spread::*;
 use std::{borrow::Cow, fmt};
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use std::{cell::RefCell, rc::Rc};
@@ -251,7 +272,7 @@ cfg_if! {
       }
     }
   } else {
-    use crate::html::ElementChildren;
+    use crate::html::{ElementBindings, ElementChildren};
 
     /// HTML element.
     #[derive(Clone, PartialEq, Eq)]
@@ -260,6 +281,7 @@ cfg_if! {
       is_void: bool,
       attrs: SmallVec<[(Cow<'static, str>, Cow<'static, str>); 4]>,
       children: ElementChildren,
+      bindings: Option<Box<ElementBindings>>,
       id: HydrationKey,
       #[cfg(debug_assertions)]
       /// Optional marker for the view macro source, in debug mode.
@@ -331,6 +353,7 @@ impl Element {
                 is_void,
                 attrs,
                 children,
+                bindings,
                 id,
                 #[cfg(debug_assertions)]
                 view_marker,
@@ -343,6 +366,9 @@ impl Element {
                 element,
                 attrs,
                 children,
+                bindings: bindings
+                    .map(|bindings| *bindings)
+                    .unwrap_or_default(),
                 #[cfg(debug_assertions)]
                 view_marker,
             }
@@ -376,6 +402,7 @@ impl Element {
               is_void: el.is_void(),
               attrs: Default::default(),
               children: Default::default(),
+              bindings: None,
               id: *el.hydration_id(),
               #[cfg(debug_assertions)]
               view_marker: None
@@ -427,6 +454,12 @@ impl Comment {
 
                         marker.remove();
                     } else {
+                        #[cfg(all(debug_assertions, feature = "hydrate"))]
+                        hydration::missing_node(
+                            &id,
+                            &format!("the {content} marker"),
+                        );
+                        #[cfg(not(all(debug_assertions, feature = "hydrate")))]
                         crate::warn!(
                             "component with id {id} not found, ignoring it for \
                              hydration"
@@ -473,9 +506,7 @@ impl Text {
     pub fn new(content: Cow<'static, str>) -> Self {
         Self {
             #[cfg(all(target_arch = "wasm32", feature = "web"))]
-            node: crate::document()
-                .create_text_node(&content)
-                .unchecked_into::<web_sys::Node>(),
+            node: Dom.create_text_node(&content),
             content,
         }
     }
@@ -760,15 +791,11 @@ pub fn mount_child<GWSN: Mountable + fmt::Debug>(
     let child = child.get_mountable_node();
 
     match kind {
-        MountKind::Append(el) => {
-            el.append_child(&child)
-                .expect("append operation to not err");
-        }
+        MountKind::Append(el) => Dom.append_child(el, &child),
         MountKind::Before(closing) => {
-            closing
-                .unchecked_ref::<web_sys::Element>()
-                .before_with_node_1(&child)
-                .expect("before to not err");
+            if let Some(parent) = closing.parent_node() {
+                Dom.insert_before(&parent, &child, Some(closing));
+            }
         }
     }
 }
@@ -780,7 +807,7 @@ fn unmount_child(start: &web_sys::Node, end: &web_sys::Node) {
 
     while sibling != *end {
         if let Some(next_sibling) = sibling.next_sibling() {
-            sibling.unchecked_ref::<web_sys::Element>().remove();
+            Dom.remove(&sibling);
 
             sibling = next_sibling;
         } else {
@@ -864,11 +891,17 @@ where
         let disposer = leptos_reactive::create_scope(
           leptos_reactive::create_runtime(),
           move |cx| {
+            let was_hydrating = HydrationCtx::is_hydrating();
             let node = f(cx).into_view(cx);
 
             HydrationCtx::stop_hydrating();
 
-            parent.append_child(&node.get_mountable_node()).unwrap();
+            Dom.append_child(&parent, &node.get_mountable_node());
+
+            // run the handlers for anything the user did before hydration
+            if was_hydrating {
+                events::replay_hydration_events();
+            }
 
             std::mem::forget(node);
           },
@@ -0,0 +1,185 @@
+use crate::{HydrationCtx, IntoView, View};
+use cfg_if::cfg_if;
+use leptos_reactive::Scope;
+
+/// The attribute that marks an element in an existing HTML template as the
+/// place to render a component registered with [`LiquidMount::component`].
+pub const LIQUID_MOUNT_ATTRIBUTE: &str = "data-leptos-mount";
+
+/// Renders components into placeholders in an existing, non-Leptos HTML
+/// template, so that a server-rendered app can be moved to Leptos one piece
+/// at a time.
+///
+/// Each placeholder is an empty element with a `data-leptos-mount` attribute
+/// naming the component to render inside it:
+/// ```html
+/// <aside>
+///   <div data-leptos-mount="Counter"></div>
+/// </aside>
+/// ```
+///
+/// On the server, [`LiquidMount::render_into_template`] injects the HTML for each
+/// component into its placeholder. In the browser, [`LiquidMount::mount`]
+/// hydrates the components in those placeholders, or renders them if the page
+/// was not rendered by [`LiquidMount::render_into_template`]. The same components
+/// must be registered in both places.
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn Counter(cx: Scope) -> impl IntoView {
+///     let (count, set_count) = create_signal(cx, 0);
+///     view! { cx,
+///         <button on:click=move |_| set_count.update(|n| *n += 1)>
+///             {move || count.get()}
+///         </button>
+///     }
+/// }
+///
+/// fn app() -> LiquidMount {
+///     LiquidMount::new().component("Counter", |cx| view! { cx, <Counter/> })
+/// }
+///
+/// # if false {
+/// // on the server
+/// let html = app().render_into_template(
+///     r#"<aside><div data-leptos-mount="Counter"></div></aside>"#,
+/// );
+///
+/// // in the browser
+/// app().mount();
+/// # }
+/// ```
+#[derive(Default)]
+pub struct LiquidMount {
+    components: Vec<(&'static str, RenderComponent)>,
+}
+
+// Renders a registered component into a placeholder.
+type RenderComponent = Box<dyn Fn(Scope) -> View>;
+
+impl LiquidMount {
+    /// Creates a set of components with nothing registered yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the component to render into each placeholder whose
+    /// `data-leptos-mount` attribute is `name`.
+    pub fn component<F, N>(mut self, name: &'static str, render: F) -> Self
+    where
+        F: Fn(Scope) -> N + 'static,
+        N: IntoView,
+    {
+        self.components
+            .push((name, Box::new(move |cx| render(cx).into_view(cx))));
+        self
+    }
+
+    fn get(&self, name: &str) -> Option<&dyn Fn(Scope) -> View> {
+        self.components
+            .iter()
+            .find(|(registered, _)| *registered == name)
+            .map(|(_, render)| &**render)
+    }
+
+    /// Renders each registered component into its placeholders in `template`,
+    /// returning the resulting HTML. Placeholders that name a component that
+    /// has not been registered are left as they are.
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    pub fn render_into_template(&self, template: &str) -> String {
+        let runtime = leptos_reactive::create_runtime();
+        HydrationCtx::reset_id();
+
+        let (cx, disposer) = leptos_reactive::raw_scope_and_disposer(runtime);
+
+        let marker = format!("{LIQUID_MOUNT_ATTRIBUTE}=\"");
+        let mut html = String::with_capacity(template.len());
+        let mut rest = template;
+
+        while let Some(start) = rest.find(&marker) {
+            let after_marker = &rest[start + marker.len()..];
+            let Some(name_len) = after_marker.find('"') else {
+                break;
+            };
+            let name = &after_marker[..name_len];
+            let Some(tag_end) = after_marker[name_len..].find('>') else {
+                break;
+            };
+            // everything up to and including the end of the opening tag
+            let split = start + marker.len() + name_len + tag_end + 1;
+            html.push_str(&rest[..split]);
+            rest = &rest[split..];
+
+            match self.get(name) {
+                Some(render) => html.push_str(&render(cx).render_to_string(cx)),
+                None => crate::warn!(
+                    "LiquidMount: no component is registered as {name:?}"
+                ),
+            }
+        }
+
+        html.push_str(rest);
+
+        disposer.dispose();
+        runtime.dispose();
+        html
+    }
+
+    /// Mounts each registered component into its placeholders in the current
+    /// document, in document order, hydrating the HTML rendered by
+    /// [`LiquidMount::render_into_template`] if it is there.
+    pub fn mount(self) {
+        cfg_if! {
+          if #[cfg(all(target_arch = "wasm32", feature = "web"))] {
+            use crate::Mountable;
+            use wasm_bindgen::JsCast;
+
+            let placeholders = crate::document()
+                .query_selector_all(&format!("[{LIQUID_MOUNT_ATTRIBUTE}]"))
+                .expect("placeholder selector to be valid");
+
+            let disposer = leptos_reactive::create_scope(
+              leptos_reactive::create_runtime(),
+              move |cx| {
+                let was_hydrating = HydrationCtx::is_hydrating();
+
+                for i in 0..placeholders.length() {
+                    let Some(placeholder) = placeholders
+                        .item(i)
+                        .and_then(|node| node.dyn_into::<web_sys::Element>().ok())
+                    else {
+                        continue;
+                    };
+                    let name = placeholder
+                        .get_attribute(LIQUID_MOUNT_ATTRIBUTE)
+                        .unwrap_or_default();
+                    let Some(render) = self.get(&name) else {
+                        crate::warn!(
+                            "LiquidMount: no component is registered as \
+                             {name:?}"
+                        );
+                        continue;
+                    };
+
+                    let node = render(cx);
+                    placeholder.append_child(&node.get_mountable_node()).unwrap();
+                    std::mem::forget(node);
+                }
+
+                HydrationCtx::stop_hydrating();
+
+                // run the handlers for anything the user did before hydration
+                if was_hydrating {
+                    crate::events::replay_hydration_events();
+                }
+              },
+            );
+
+            std::mem::forget(disposer);
+          } else {
+            _ = self;
+            crate::warn!("`LiquidMount::mount` should not be called outside the browser.");
+          }
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use leptos_reactive::Scope;
+#[cfg(feature = "stable")]
+use leptos_reactive::{
+    MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalGet,
+};
 use std::{borrow::Cow, rc::Rc};
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
-use wasm_bindgen::UnwrapThrowExt;
 
 /// Represents the different possible values an attribute node could have.
 ///
@@ -99,14 +101,50 @@ impl std::fmt::Debug for Attribute {
 
 /// Converts some type into an [Attribute].
 ///
-/// This is implemented by default for Rust primitive and string types.
-pub trait IntoAttribute {
+/// This is implemented by default for Rust primitive and string types,
+/// for closures and signals that return any of them, and for `Option`s of them.
+///
+/// Implement it for your own types so that they can be used as attribute
+/// values directly, or returned from a closure or signal.
+/// ```
+/// # use leptos::*;
+/// #[derive(Clone)]
+/// struct Color(u8, u8, u8);
+///
+/// impl IntoAttribute for Color {
+///     fn into_attribute(self, _cx: Scope) -> Attribute {
+///         let Color(r, g, b) = self;
+///         Attribute::String(format!("#{r:02x}{g:02x}{b:02x}").into())
+///     }
+/// }
+///
+/// # let runtime = create_runtime();
+/// # create_scope(runtime, |cx| {
+/// let (color, set_color) = create_signal(cx, Color(255, 0, 0));
+/// view! { cx, <input type="color" value=color/> };
+/// # });
+/// # runtime.dispose();
+/// ```
+pub trait IntoAttribute: IntoAttributeBoxed {
     /// Converts the object into an [Attribute].
     fn into_attribute(self, cx: Scope) -> Attribute;
-    /// Helper function for dealing with `Box<dyn IntoAttribute>`.
+}
+
+/// Helper trait for dealing with `Box<dyn IntoAttribute>`, which is implemented
+/// for every [`IntoAttribute`] type.
+#[doc(hidden)]
+pub trait IntoAttributeBoxed {
+    /// Converts the boxed object into an [Attribute].
     fn into_attribute_boxed(self: Box<Self>, cx: Scope) -> Attribute;
 }
 
+impl<T: IntoAttribute> IntoAttributeBoxed for T {
+    #[inline(always)]
+    fn into_attribute_boxed(self: Box<Self>, cx: Scope) -> Attribute {
+        (*self).into_attribute(cx)
+    }
+}
+
 impl<T: IntoAttribute + 'static> From<T> for Box<dyn IntoAttribute> {
     #[inline(always)]
     fn from(value: T) -> Self {
@@ -119,20 +157,6 @@ impl IntoAttribute for Attribute {
     fn into_attribute(self, _: Scope) -> Attribute {
         self
     }
-
-    #[inline(always)]
-    fn into_attribute_boxed(self: Box<Self>, _: Scope) -> Attribute {
-        *self
-    }
-}
-
-macro_rules! impl_into_attr_boxed {
-    () => {
-        #[inline(always)]
-        fn into_attribute_boxed(self: Box<Self>, cx: Scope) -> Attribute {
-            self.into_attribute(cx)
-        }
-    };
 }
 
 impl IntoAttribute for Option<Attribute> {
@@ -140,8 +164,6 @@ impl IntoAttribute for Option<Attribute> {
     fn into_attribute(self, cx: Scope) -> Attribute {
         self.unwrap_or(Attribute::Option(cx, None))
     }
-
-    impl_into_attr_boxed! {}
 }
 
 impl IntoAttribute for String {
@@ -149,8 +171,6 @@ impl IntoAttribute for String {
     fn into_attribute(self, _: Scope) -> Attribute {
         Attribute::String(Cow::Owned(self))
     }
-
-    impl_into_attr_boxed! {}
 }
 
 impl IntoAttribute for &'static str {
@@ -158,8 +178,6 @@ impl IntoAttribute for &'static str {
     fn into_attribute(self, _: Scope) -> Attribute {
         Attribute::String(Cow::Borrowed(self))
     }
-
-    impl_into_attr_boxed! {}
 }
 
 impl IntoAttribute for Cow<'static, str> {
@@ -167,8 +185,6 @@ impl IntoAttribute for Cow<'static, str> {
     fn into_attribute(self, _: Scope) -> Attribute {
         Attribute::String(self)
     }
-
-    impl_into_attr_boxed! {}
 }
 
 impl IntoAttribute for bool {
@@ -176,8 +192,6 @@ impl IntoAttribute for bool {
     fn into_attribute(self, _: Scope) -> Attribute {
         Attribute::Bool(self)
     }
-
-    impl_into_attr_boxed! {}
 }
 
 impl IntoAttribute for Option<String> {
@@ -185,8 +199,6 @@ impl IntoAttribute for Option<String> {
     fn into_attribute(self, cx: Scope) -> Attribute {
         Attribute::Option(cx, self.map(Cow::Owned))
     }
-
-    impl_into_attr_boxed! {}
 }
 
 impl IntoAttribute for Option<&'static str> {
@@ -194,8 +206,6 @@ impl IntoAttribute for Option<&'static str> {
     fn into_attribute(self, cx: Scope) -> Attribute {
         Attribute::Option(cx, self.map(Cow::Borrowed))
     }
-
-    impl_into_attr_boxed! {}
 }
 
 impl IntoAttribute for Option<Cow<'static, str>> {
@@ -203,8 +213,6 @@ impl IntoAttribute for Option<Cow<'static, str>> {
     fn into_attribute(self, cx: Scope) -> Attribute {
         Attribute::Option(cx, self)
     }
-
-    impl_into_attr_boxed! {}
 }
 
 impl<T, U> IntoAttribute for T
@@ -216,17 +224,34 @@ where
         let modified_fn = Rc::new(move || (self)().into_attribute(cx));
         Attribute::Fn(cx, modified_fn)
     }
+}
 
-    impl_into_attr_boxed! {}
+macro_rules! attr_signal_type {
+    ($signal_type:ident) => {
+        #[cfg(feature = "stable")]
+        impl<T> IntoAttribute for $signal_type<T>
+        where
+            T: IntoAttribute + Clone + 'static,
+        {
+            #[inline(always)]
+            fn into_attribute(self, cx: Scope) -> Attribute {
+                (move || self.get()).into_attribute(cx)
+            }
+        }
+    };
 }
 
+attr_signal_type!(ReadSignal);
+attr_signal_type!(RwSignal);
+attr_signal_type!(Memo);
+attr_signal_type!(Signal);
+attr_signal_type!(MaybeSignal);
+
 impl<T: IntoAttribute> IntoAttribute for (Scope, T) {
     #[inline(always)]
     fn into_attribute(self, _: Scope) -> Attribute {
         self.1.into_attribute(self.0)
     }
-
-    impl_into_attr_boxed! {}
 }
 
 impl IntoAttribute for (Scope, Option<Box<dyn IntoAttribute>>) {
@@ -236,8 +261,6 @@ impl IntoAttribute for (Scope, Option<Box<dyn IntoAttribute>>) {
             None => Attribute::Option(self.0, None),
         }
     }
-
-    impl_into_attr_boxed! {}
 }
 
 impl IntoAttribute for (Scope, Box<dyn IntoAttribute>) {
@@ -245,8 +268,6 @@ impl IntoAttribute for (Scope, Box<dyn IntoAttribute>) {
     fn into_attribute(self, _: Scope) -> Attribute {
         self.1.into_attribute_boxed(self.0)
     }
-
-    impl_into_attr_boxed! {}
 }
 
 macro_rules! attr_type {
@@ -255,22 +276,12 @@ macro_rules! attr_type {
             fn into_attribute(self, _: Scope) -> Attribute {
                 Attribute::String(self.to_string().into())
             }
-
-            #[inline]
-            fn into_attribute_boxed(self: Box<Self>, cx: Scope) -> Attribute {
-                self.into_attribute(cx)
-            }
         }
 
         impl IntoAttribute for Option<$attr_type> {
             fn into_attribute(self, cx: Scope) -> Attribute {
                 Attribute::Option(cx, self.map(|n| n.to_string().into()))
             }
-
-            #[inline]
-            fn into_attribute_boxed(self: Box<Self>, cx: Scope) -> Attribute {
-                self.into_attribute(cx)
-            }
         }
     };
 }
@@ -324,39 +335,52 @@ pub(crate) fn attribute_expression(
     value: Attribute,
     force: bool,
 ) {
-    use crate::HydrationCtx;
+    use crate::{
+        renderer::{Dom, Renderer},
+        HydrationCtx,
+    };
 
     if force || !HydrationCtx::is_hydrating() {
         match value {
             Attribute::String(value) => {
-                let value = wasm_bindgen::intern(&value);
                 if attr_name == "inner_html" {
-                    el.set_inner_html(value);
+                    Dom.set_inner_html(el, &crate::sanitize_html(value));
+                } else if attr_name == "dangerously_set_inner_html" {
+                    Dom.set_inner_html(el, &value);
                 } else {
-                    let attr_name = wasm_bindgen::intern(attr_name);
-                    el.set_attribute(attr_name, value).unwrap_throw();
+                    let value = wasm_bindgen::intern(&value);
+                    Dom.set_attribute(
+                        el,
+                        wasm_bindgen::intern(attr_name),
+                        value,
+                    );
                 }
             }
             Attribute::Option(_, value) => {
                 if attr_name == "inner_html" {
-                    el.set_inner_html(&value.unwrap_or_default());
+                    Dom.set_inner_html(
+                        el,
+                        &crate::sanitize_html(value.unwrap_or_default()),
+                    );
+                } else if attr_name == "dangerously_set_inner_html" {
+                    Dom.set_inner_html(el, &value.unwrap_or_default());
                 } else {
                     let attr_name = wasm_bindgen::intern(attr_name);
                     match value {
                         Some(value) => {
                             let value = wasm_bindgen::intern(&value);
-                            el.set_attribute(attr_name, value).unwrap_throw();
+                            Dom.set_attribute(el, attr_name, value);
                         }
-                        None => el.remove_attribute(attr_name).unwrap_throw(),
+                        None => Dom.remove_attribute(el, attr_name),
                     }
                 }
             }
             Attribute::Bool(value) => {
                 let attr_name = wasm_bindgen::intern(attr_name);
                 if value {
-                    el.set_attribute(attr_name, attr_name).unwrap_throw();
+                    Dom.set_attribute(el, attr_name, attr_name);
                 } else {
-                    el.remove_attribute(attr_name).unwrap_throw();
+                    Dom.remove_attribute(el, attr_name);
                 }
             }
             _ => panic!("Remove nested Fn in Attribute"),
@@ -1,4 +1,10 @@
+use crate::{Attribute, IntoAttribute};
 use leptos_reactive::Scope;
+#[cfg(feature = "stable")]
+use leptos_reactive::{
+    MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalGet,
+};
+use std::{borrow::Cow, collections::HashMap, rc::Rc};
 
 /// Represents the different possible values a single class on an element could have,
 /// allowing you to do fine-grained updates to single items
@@ -15,6 +21,31 @@ pub enum Class {
 }
 
 /// Converts some type into a [Class].
+///
+/// This is implemented by default for `bool`, and for closures and signals
+/// that return any type that implements it, so you can implement it for your
+/// own types to use them with `class:` directly.
+/// ```
+/// # use leptos::*;
+/// #[derive(Clone, PartialEq)]
+/// enum Visibility {
+///     Shown,
+///     Hidden,
+/// }
+///
+/// impl IntoClass for Visibility {
+///     fn into_class(self, _cx: Scope) -> Class {
+///         Class::Value(self == Visibility::Hidden)
+///     }
+/// }
+///
+/// # let runtime = create_runtime();
+/// # create_scope(runtime, |cx| {
+/// let (visibility, set_visibility) = create_signal(cx, Visibility::Shown);
+/// view! { cx, <div class:hidden=move || visibility.get()/> };
+/// # });
+/// # runtime.dispose();
+/// ```
 pub trait IntoClass {
     /// Converts the object into a [Class].
     fn into_class(self, cx: Scope) -> Class;
@@ -27,17 +58,49 @@ impl IntoClass for bool {
     }
 }
 
-impl<T> IntoClass for T
+impl IntoClass for Class {
+    #[inline(always)]
+    fn into_class(self, _cx: Scope) -> Class {
+        self
+    }
+}
+
+impl<T, U> IntoClass for T
 where
-    T: Fn() -> bool + 'static,
+    T: Fn() -> U + 'static,
+    U: IntoClass,
 {
     #[inline(always)]
     fn into_class(self, cx: Scope) -> Class {
-        let modified_fn = Box::new(self);
+        let modified_fn = Box::new(move || match self().into_class(cx) {
+            Class::Value(value) => value,
+            Class::Fn(_, f) => f(),
+        });
         Class::Fn(cx, modified_fn)
     }
 }
 
+macro_rules! class_signal_type {
+    ($signal_type:ident) => {
+        #[cfg(feature = "stable")]
+        impl<T> IntoClass for $signal_type<T>
+        where
+            T: IntoClass + Clone + 'static,
+        {
+            #[inline(always)]
+            fn into_class(self, cx: Scope) -> Class {
+                (move || self.get()).into_class(cx)
+            }
+        }
+    };
+}
+
+class_signal_type!(ReadSignal);
+class_signal_type!(RwSignal);
+class_signal_type!(Memo);
+class_signal_type!(Signal);
+class_signal_type!(MaybeSignal);
+
 impl Class {
     /// Converts the class to its HTML value at that moment so it can be rendered on the server.
     pub fn as_value_string(&self, class_name: &'static str) -> &'static str {
@@ -68,8 +131,167 @@ impl<T: IntoClass> IntoClass for (Scope, T) {
     }
 }
 
-#[cfg(all(target_arch = "wasm32", feature = "web"))]
-use std::borrow::Cow;
+/// A set of classes that are each toggled on and off by their own value, so
+/// that they can be given to an element as a single `class` value.
+///
+/// Like `class:name=value`, each class is added and removed with the
+/// element’s `classList` as its value changes, without touching the others.
+/// A `HashMap` or a list of pairs can be used as a `class` value directly.
+///
+/// ```rust
+/// # use leptos::*;
+/// # use std::collections::HashMap;
+/// # let runtime = create_runtime();
+/// # create_scope(runtime, |cx| {
+/// let (active, set_active) = create_signal(cx, true);
+/// let (disabled, set_disabled) = create_signal(cx, false);
+///
+/// let classes = ClassList::new()
+///     .add("button", true)
+///     .add("active", active)
+///     .add("disabled", move || disabled.get());
+/// view! { cx, <button class=classes>"Click me"</button> };
+///
+/// let classes = HashMap::from([("active", active), ("disabled", disabled)]);
+/// view! { cx, <button class=classes>"Click me"</button> };
+/// # });
+/// # runtime.dispose();
+/// ```
+#[derive(Default, Clone)]
+#[allow(clippy::type_complexity)]
+pub struct ClassList(Vec<(Cow<'static, str>, Rc<dyn Fn(Scope) -> Class>)>);
+
+impl ClassList {
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the list has no classes.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Adds a class, which is on whenever `value` is `true`.
+    pub fn add(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl IntoClass + Clone + 'static,
+    ) -> Self {
+        self.0.push((
+            name.into(),
+            Rc::new(move |cx| value.clone().into_class(cx)),
+        ));
+        self
+    }
+
+    /// The name and value of each class, in order.
+    pub(crate) fn into_classes(
+        self,
+        cx: Scope,
+    ) -> impl Iterator<Item = (Cow<'static, str>, Class)> {
+        self.0
+            .into_iter()
+            .map(move |(name, value)| (name, value(cx)))
+    }
+
+    /// Converts the list to the classes that are on at that moment, separated
+    /// by spaces, so it can be rendered on the server.
+    pub fn as_value_string(&self, cx: Scope) -> String {
+        self.0
+            .iter()
+            .filter(|(_, value)| match value(cx) {
+                Class::Value(value) => value,
+                Class::Fn(_, f) => f(),
+            })
+            .map(|(name, _)| name.as_ref())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl<I, K, V> From<I> for ClassList
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<Cow<'static, str>>,
+    V: IntoClass + Clone + 'static,
+{
+    fn from(value: I) -> Self {
+        value
+            .into_iter()
+            .fold(Self::new(), |classes, (name, value)| {
+                classes.add(name, value)
+            })
+    }
+}
+
+/// The value of a `class` attribute: either an ordinary attribute value, which
+/// sets every class at once, or a [`ClassList`].
+#[doc(hidden)]
+pub enum ClassAttribute {
+    Attribute(Attribute),
+    List(ClassList),
+}
+
+impl ClassAttribute {
+    /// Converts the classes to their HTML value at that moment, so they can
+    /// be rendered on the server.
+    pub fn as_nameless_value_string(
+        &self,
+        cx: Scope,
+    ) -> Option<Cow<'static, str>> {
+        match self {
+            ClassAttribute::Attribute(attr) => attr.as_nameless_value_string(),
+            ClassAttribute::List(list) => Some(list.as_value_string(cx).into()),
+        }
+    }
+}
+
+/// Converts the value given to a `class` attribute in the `view` macro into
+/// a [`ClassAttribute`].
+#[doc(hidden)]
+pub trait IntoClassAttribute {
+    fn into_class_attribute(self, cx: Scope) -> ClassAttribute;
+}
+
+impl<T: IntoAttribute> IntoClassAttribute for (Scope, T) {
+    #[inline(always)]
+    fn into_class_attribute(self, _: Scope) -> ClassAttribute {
+        ClassAttribute::Attribute(self.1.into_attribute(self.0))
+    }
+}
+
+impl IntoClassAttribute for (Scope, ClassList) {
+    #[inline(always)]
+    fn into_class_attribute(self, _: Scope) -> ClassAttribute {
+        ClassAttribute::List(self.1)
+    }
+}
+
+impl IntoClassAttribute for (Scope, Option<Box<dyn IntoAttribute>>) {
+    #[inline(always)]
+    fn into_class_attribute(self, cx: Scope) -> ClassAttribute {
+        ClassAttribute::Attribute(self.into_attribute(cx))
+    }
+}
+
+impl IntoClassAttribute for (Scope, Box<dyn IntoAttribute>) {
+    #[inline(always)]
+    fn into_class_attribute(self, cx: Scope) -> ClassAttribute {
+        ClassAttribute::Attribute(self.into_attribute(cx))
+    }
+}
+
+impl<K, V, S> IntoClassAttribute for (Scope, HashMap<K, V, S>)
+where
+    K: Into<Cow<'static, str>>,
+    V: IntoClass + Clone + 'static,
+{
+    #[inline(always)]
+    fn into_class_attribute(self, _: Scope) -> ClassAttribute {
+        ClassAttribute::List(self.1.into())
+    }
+}
 
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 #[doc(hidden)]
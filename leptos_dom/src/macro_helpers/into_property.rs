@@ -1,4 +1,8 @@
 use leptos_reactive::Scope;
+#[cfg(feature = "stable")]
+use leptos_reactive::{
+    MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalGet,
+};
 use wasm_bindgen::JsValue;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use wasm_bindgen::UnwrapThrowExt;
@@ -18,7 +22,12 @@ pub enum Property {
 
 /// Converts some type into a [Property].
 ///
-/// This is implemented by default for Rust primitive types, [String] and friends, and [JsValue].
+/// This is implemented by default for Rust primitive types, [String] and friends, and [JsValue],
+/// and for closures and signals that return any type that can be converted into a [JsValue].
+///
+/// To use your own type as a property value, implement this trait for it, along
+/// with `From<YourType> for JsValue` so that it can also be returned from
+/// closures and signals.
 pub trait IntoProperty {
     /// Converts the object into a [Property].
     fn into_property(self, cx: Scope) -> Property;
@@ -35,6 +44,27 @@ where
     }
 }
 
+macro_rules! property_signal_type {
+    ($signal_type:ident) => {
+        #[cfg(feature = "stable")]
+        impl<T> IntoProperty for $signal_type<T>
+        where
+            T: Into<JsValue> + Clone + 'static,
+        {
+            #[inline(always)]
+            fn into_property(self, cx: Scope) -> Property {
+                (move || self.get()).into_property(cx)
+            }
+        }
+    };
+}
+
+property_signal_type!(ReadSignal);
+property_signal_type!(RwSignal);
+property_signal_type!(Memo);
+property_signal_type!(Signal);
+property_signal_type!(MaybeSignal);
+
 impl<T: IntoProperty> IntoProperty for (Scope, T) {
     #[inline(always)]
     fn into_property(self, _: Scope) -> Property {
@@ -42,6 +72,13 @@ impl<T: IntoProperty> IntoProperty for (Scope, T) {
     }
 }
 
+impl IntoProperty for Property {
+    #[inline(always)]
+    fn into_property(self, _: Scope) -> Property {
+        self
+    }
+}
+
 macro_rules! prop_type {
     ($prop_type:ty) => {
         impl IntoProperty for $prop_type {
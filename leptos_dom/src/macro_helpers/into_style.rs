@@ -1,7 +1,18 @@
+use crate::{Attribute, IntoAttribute};
 use leptos_reactive::Scope;
-use std::{borrow::Cow, rc::Rc};
+#[cfg(feature = "stable")]
+use leptos_reactive::{
+    MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalGet,
+};
+use std::{borrow::Cow, collections::HashMap, fmt, rc::Rc};
 
-/// todo docs
+/// Represents the different possible values a single style property on an element
+/// could have, allowing you to do fine-grained updates to single properties
+/// in [`Element.style`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/style).
+///
+/// This mostly exists for the [`view`](https://docs.rs/leptos_macro/latest/leptos_macro/macro.view.html)
+/// macro’s use. You usually won't need to interact with it directly, but it can be useful for defining
+/// permissive APIs for certain components.
 #[derive(Clone)]
 pub enum Style {
     /// A plain string value.
@@ -34,6 +45,29 @@ impl std::fmt::Debug for Style {
 }
 
 /// Converts some type into a [Style].
+///
+/// This is implemented by default for string types, and for closures and signals
+/// that return any type that implements it. Implement it for your own types
+/// so they can be used with `style:` directly.
+/// ```
+/// # use leptos::*;
+/// #[derive(Clone)]
+/// struct Color(u8, u8, u8);
+///
+/// impl IntoStyle for Color {
+///     fn into_style(self, _cx: Scope) -> Style {
+///         let Color(r, g, b) = self;
+///         Style::Value(format!("rgb({r}, {g}, {b})").into())
+///     }
+/// }
+///
+/// # let runtime = create_runtime();
+/// # create_scope(runtime, |cx| {
+/// let (color, set_color) = create_signal(cx, Color(255, 0, 0));
+/// view! { cx, <p style:color=color>"Hello"</p> };
+/// # });
+/// # runtime.dispose();
+/// ```
 pub trait IntoStyle {
     /// Converts the object into a [Style].
     fn into_style(self, cx: Scope) -> Style;
@@ -79,6 +113,27 @@ where
     }
 }
 
+macro_rules! style_signal_type {
+    ($signal_type:ident) => {
+        #[cfg(feature = "stable")]
+        impl<T> IntoStyle for $signal_type<T>
+        where
+            T: IntoStyle + Clone + 'static,
+        {
+            #[inline(always)]
+            fn into_style(self, cx: Scope) -> Style {
+                (move || self.get()).into_style(cx)
+            }
+        }
+    };
+}
+
+style_signal_type!(ReadSignal);
+style_signal_type!(RwSignal);
+style_signal_type!(Memo);
+style_signal_type!(Signal);
+style_signal_type!(MaybeSignal);
+
 impl Style {
     /// Converts the style to its HTML value at that moment so it can be rendered on the server.
     pub fn as_value_string(
@@ -110,6 +165,261 @@ impl<T: IntoStyle> IntoStyle for (Scope, T) {
     }
 }
 
+impl IntoStyle for Style {
+    #[inline(always)]
+    fn into_style(self, _cx: Scope) -> Style {
+        self
+    }
+}
+
+/// A CSS length, like `200px` or `1.5rem`, created with [`px`], [`em`],
+/// [`rem`], [`percent`], [`vw`] or [`vh`].
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Length {
+    value: f64,
+    unit: &'static str,
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit)
+    }
+}
+
+macro_rules! length_unit {
+    ($($(#[$meta:meta])* $name:ident => $unit:literal),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            #[inline(always)]
+            pub fn $name(value: impl Into<f64>) -> Length {
+                Length {
+                    value: value.into(),
+                    unit: $unit,
+                }
+            }
+        )*
+    };
+}
+
+length_unit! {
+    /// A length in pixels.
+    px => "px",
+    /// A length relative to the element’s font size.
+    em => "em",
+    /// A length relative to the root element’s font size.
+    rem => "rem",
+    /// A length relative to the same length of the parent element.
+    percent => "%",
+    /// A length relative to 1% of the viewport’s width.
+    vw => "vw",
+    /// A length relative to 1% of the viewport’s height.
+    vh => "vh",
+}
+
+/// A set of style properties that are each updated by their own value, so
+/// that they can be given to an element as a single `style` value.
+///
+/// Like `style:name=value`, each property is set and removed with the
+/// element’s `style` as its value changes, without touching the others. On
+/// the server, they are rendered straight into the `style` attribute. A
+/// `HashMap` or a list of pairs can be used as a `style` value directly.
+///
+/// ```rust
+/// # use leptos::*;
+/// # let runtime = create_runtime();
+/// # create_scope(runtime, |cx| {
+/// let (width, set_width) = create_signal(cx, 200);
+/// let (hidden, set_hidden) = create_signal(cx, false);
+///
+/// let styles = StyleList::new()
+///     .width(move || px(width.get()))
+///     .height(rem(2.5))
+///     .opacity(move || if hidden.get() { 0.0 } else { 1.0 })
+///     .set("--accent", "rebeccapurple");
+/// view! { cx, <div style=styles/> };
+/// # });
+/// # runtime.dispose();
+/// ```
+#[derive(Default, Clone)]
+#[allow(clippy::type_complexity)]
+pub struct StyleList(Vec<(Cow<'static, str>, Rc<dyn Fn(Scope) -> Style>)>);
+
+macro_rules! style_property {
+    ($($name:ident => $property:literal),* $(,)?) => {
+        $(
+            #[doc = concat!("Sets `", $property, "`.")]
+            #[inline(always)]
+            pub fn $name(self, value: impl IntoStyle + Clone + 'static) -> Self {
+                self.set($property, value)
+            }
+        )*
+    };
+}
+
+impl StyleList {
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the list has no properties.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Sets any property, including custom properties like `--accent`. A
+    /// `None` value removes it.
+    pub fn set(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl IntoStyle + Clone + 'static,
+    ) -> Self {
+        self.0.push((
+            name.into(),
+            Rc::new(move |cx| value.clone().into_style(cx)),
+        ));
+        self
+    }
+
+    style_property! {
+        width => "width",
+        height => "height",
+        min_width => "min-width",
+        min_height => "min-height",
+        max_width => "max-width",
+        max_height => "max-height",
+        top => "top",
+        right => "right",
+        bottom => "bottom",
+        left => "left",
+        margin => "margin",
+        padding => "padding",
+        gap => "gap",
+        font_size => "font-size",
+        line_height => "line-height",
+        opacity => "opacity",
+        z_index => "z-index",
+        color => "color",
+        background_color => "background-color",
+        display => "display",
+        position => "position",
+        transform => "transform",
+    }
+
+    /// The name and value of each property, in order.
+    pub(crate) fn into_styles(
+        self,
+        cx: Scope,
+    ) -> impl Iterator<Item = (Cow<'static, str>, Style)> {
+        self.0
+            .into_iter()
+            .map(move |(name, value)| (name, value(cx)))
+    }
+
+    /// Converts the list to the properties that are set at that moment, so
+    /// it can be rendered on the server.
+    pub fn as_value_string(&self, cx: Scope) -> String {
+        self.0
+            .iter()
+            .filter_map(|(name, value)| {
+                let mut value = value(cx);
+                while let Style::Fn(_, f) = value {
+                    value = f();
+                }
+                match value {
+                    Style::Value(value) => Some(format!("{name}: {value}")),
+                    Style::Option(value) => {
+                        value.map(|value| format!("{name}: {value}"))
+                    }
+                    Style::Fn(_, _) => unreachable!(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+impl<I, K, V> From<I> for StyleList
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<Cow<'static, str>>,
+    V: IntoStyle + Clone + 'static,
+{
+    fn from(value: I) -> Self {
+        value
+            .into_iter()
+            .fold(Self::new(), |styles, (name, value)| styles.set(name, value))
+    }
+}
+
+/// The value of a `style` attribute: either an ordinary attribute value,
+/// which sets every property at once, or a [`StyleList`].
+#[doc(hidden)]
+pub enum StyleAttribute {
+    Attribute(Attribute),
+    List(StyleList),
+}
+
+impl StyleAttribute {
+    /// Converts the styles to their HTML value at that moment, so they can
+    /// be rendered on the server.
+    pub fn as_nameless_value_string(
+        &self,
+        cx: Scope,
+    ) -> Option<Cow<'static, str>> {
+        match self {
+            StyleAttribute::Attribute(attr) => attr.as_nameless_value_string(),
+            StyleAttribute::List(list) => Some(list.as_value_string(cx).into()),
+        }
+    }
+}
+
+/// Converts the value given to a `style` attribute in the `view` macro into
+/// a [`StyleAttribute`].
+#[doc(hidden)]
+pub trait IntoStyleAttribute {
+    fn into_style_attribute(self, cx: Scope) -> StyleAttribute;
+}
+
+impl<T: IntoAttribute> IntoStyleAttribute for (Scope, T) {
+    #[inline(always)]
+    fn into_style_attribute(self, _: Scope) -> StyleAttribute {
+        StyleAttribute::Attribute(self.1.into_attribute(self.0))
+    }
+}
+
+impl IntoStyleAttribute for (Scope, Option<Box<dyn IntoAttribute>>) {
+    #[inline(always)]
+    fn into_style_attribute(self, cx: Scope) -> StyleAttribute {
+        StyleAttribute::Attribute(self.into_attribute(cx))
+    }
+}
+
+impl IntoStyleAttribute for (Scope, Box<dyn IntoAttribute>) {
+    #[inline(always)]
+    fn into_style_attribute(self, cx: Scope) -> StyleAttribute {
+        StyleAttribute::Attribute(self.into_attribute(cx))
+    }
+}
+
+impl IntoStyleAttribute for (Scope, StyleList) {
+    #[inline(always)]
+    fn into_style_attribute(self, _: Scope) -> StyleAttribute {
+        StyleAttribute::List(self.1)
+    }
+}
+
+impl<K, V, S> IntoStyleAttribute for (Scope, HashMap<K, V, S>)
+where
+    K: Into<Cow<'static, str>>,
+    V: IntoStyle + Clone + 'static,
+{
+    #[inline(always)]
+    fn into_style_attribute(self, _: Scope) -> StyleAttribute {
+        StyleAttribute::List(self.1.into())
+    }
+}
+
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 #[doc(hidden)]
 #[inline(never)]
@@ -192,6 +502,7 @@ macro_rules! style_type {
 }
 
 style_type!(&String);
+style_type!(Length);
 style_type!(usize);
 style_type!(u8);
 style_type!(u16);
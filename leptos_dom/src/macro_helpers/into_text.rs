@@ -0,0 +1,210 @@
+use crate::{DynChild, IntoView, View};
+use leptos_reactive::Scope;
+#[cfg(feature = "stable")]
+use leptos_reactive::{
+    MaybeSignal, Memo, ReadSignal, RwSignal, Signal, SignalWith,
+};
+use std::{borrow::Cow, fmt::Write};
+
+/// Converts some type into text that the
+/// [`view`](https://docs.rs/leptos_macro/latest/leptos_macro/macro.view.html)
+/// macro can merge with the text and blocks next to it.
+///
+/// When every block in a run of adjacent text and blocks inside an element
+/// implements this trait, the whole run is rendered as a single text node,
+/// which is updated by one effect, rather than as one text node (and one
+/// effect) per block.
+pub trait IntoText: 'static {
+    /// Appends the current value of the text to `text`.
+    fn write_text(&self, text: &mut String);
+}
+
+impl IntoText for &'static str {
+    #[inline(always)]
+    fn write_text(&self, text: &mut String) {
+        text.push_str(self);
+    }
+}
+
+impl IntoText for String {
+    #[inline(always)]
+    fn write_text(&self, text: &mut String) {
+        text.push_str(self);
+    }
+}
+
+impl IntoText for Cow<'static, str> {
+    #[inline(always)]
+    fn write_text(&self, text: &mut String) {
+        text.push_str(self);
+    }
+}
+
+impl<F, T> IntoText for F
+where
+    F: Fn() -> T + 'static,
+    T: IntoText,
+{
+    #[inline(always)]
+    fn write_text(&self, text: &mut String) {
+        self().write_text(text);
+    }
+}
+
+macro_rules! text_signal_type {
+    ($signal_type:ident) => {
+        #[cfg(feature = "stable")]
+        impl<T> IntoText for $signal_type<T>
+        where
+            T: IntoText,
+        {
+            #[inline(always)]
+            fn write_text(&self, text: &mut String) {
+                self.with(|value| value.write_text(text));
+            }
+        }
+    };
+}
+
+text_signal_type!(ReadSignal);
+text_signal_type!(RwSignal);
+text_signal_type!(Memo);
+text_signal_type!(Signal);
+text_signal_type!(MaybeSignal);
+
+macro_rules! text_primitive {
+  ($($child_type:ty),* $(,)?) => {
+    $(
+      impl IntoText for $child_type {
+        #[inline(always)]
+        fn write_text(&self, text: &mut String) {
+          _ = write!(text, "{self}");
+        }
+      }
+    )*
+  };
+}
+
+text_primitive![
+    usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128, f32, f64,
+    char, bool,
+];
+
+/// A run of adjacent text and blocks inside an element, created by the
+/// [`view`](https://docs.rs/leptos_macro/latest/leptos_macro/macro.view.html)
+/// macro, which is merged into a single text node if every item is [`IntoText`].
+///
+/// ```ignore
+/// let run = TextRun(("Hello, ", name, "! You have ", count, " messages."));
+/// let views = (&run).kind().into_views(cx, run);
+/// ```
+#[doc(hidden)]
+pub struct TextRun<T>(pub T);
+
+#[doc(hidden)]
+pub struct MergedText;
+
+#[doc(hidden)]
+pub struct SeparateViews;
+
+#[doc(hidden)]
+pub trait MergedTextKind {
+    /// Selected when every item in the run is [`IntoText`].
+    #[inline(always)]
+    fn kind(&self) -> MergedText {
+        MergedText
+    }
+}
+
+impl<T: IntoText> MergedTextKind for TextRun<T> {}
+
+#[doc(hidden)]
+pub trait SeparateViewsKind {
+    /// Selected when some item in the run is only [`IntoView`].
+    #[inline(always)]
+    fn kind(&self) -> SeparateViews {
+        SeparateViews
+    }
+}
+
+impl<T> SeparateViewsKind for &TextRun<T> {}
+
+impl MergedText {
+    /// Renders the whole run as one dynamic text node.
+    pub fn into_views<T: IntoText>(
+        self,
+        cx: Scope,
+        run: TextRun<T>,
+    ) -> Vec<View> {
+        let TextRun(items) = run;
+        let text = move || {
+            let mut text = String::new();
+            items.write_text(&mut text);
+            text
+        };
+        vec![DynChild::new(text).into_view(cx)]
+    }
+}
+
+impl SeparateViews {
+    /// Renders each item in the run as its own view, just as if it had not been
+    /// part of a run.
+    pub fn into_views<T: IntoViews>(
+        self,
+        cx: Scope,
+        run: TextRun<T>,
+    ) -> Vec<View> {
+        run.0.into_views(cx)
+    }
+}
+
+/// Converts each item of a tuple into a separate [`View`].
+#[doc(hidden)]
+pub trait IntoViews {
+    /// Converts each item into a [`View`].
+    fn into_views(self, cx: Scope) -> Vec<View>;
+}
+
+macro_rules! impl_text_run_for_tuples {
+  ($($ty:ident),* $(,)?) => {
+    impl<$($ty),*> IntoText for ($($ty,)*)
+    where
+      $($ty: IntoText),*
+    {
+      #[inline]
+      fn write_text(&self, text: &mut String) {
+        paste::paste! {
+          let ($([<$ty:lower>],)*) = self;
+          $([<$ty:lower>].write_text(text);)*
+        }
+      }
+    }
+
+    impl<$($ty),*> IntoViews for ($($ty,)*)
+    where
+      $($ty: IntoView),*
+    {
+      #[inline]
+      fn into_views(self, cx: Scope) -> Vec<View> {
+        paste::paste! {
+          let ($([<$ty:lower>],)*) = self;
+          vec![
+            $([<$ty:lower>].into_view(cx)),*
+          ]
+        }
+      }
+    }
+  };
+}
+
+impl_text_run_for_tuples!(A, B);
+impl_text_run_for_tuples!(A, B, C);
+impl_text_run_for_tuples!(A, B, C, D);
+impl_text_run_for_tuples!(A, B, C, D, E);
+impl_text_run_for_tuples!(A, B, C, D, E, F);
+impl_text_run_for_tuples!(A, B, C, D, E, F, G);
+impl_text_run_for_tuples!(A, B, C, D, E, F, G, H);
+impl_text_run_for_tuples!(A, B, C, D, E, F, G, H, I);
+impl_text_run_for_tuples!(A, B, C, D, E, F, G, H, I, J);
+impl_text_run_for_tuples!(A, B, C, D, E, F, G, H, I, J, K);
+impl_text_run_for_tuples!(A, B, C, D, E, F, G, H, I, J, K, L);
@@ -2,7 +2,9 @@ mod into_attribute;
 mod into_class;
 mod into_property;
 mod into_style;
+mod into_text;
 pub use into_attribute::*;
 pub use into_class::*;
 pub use into_property::*;
 pub use into_style::*;
+pub use into_text::*;
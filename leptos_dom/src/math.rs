@@ -14,6 +14,16 @@ cfg_if! {
   }
 }
 
+/// Restores the case of MathML attributes that were written in lowercase,
+/// like the HTML parser does for MathML in an HTML document.
+fn adjust_attribute_name(name: Cow<'static, str>) -> Cow<'static, str> {
+    if name == "definitionurl" {
+        "definitionURL".into()
+    } else {
+        name
+    }
+}
+
 macro_rules! generate_math_tags {
   (
     $(
@@ -65,8 +75,16 @@ macro_rules! generate_math_tags {
               ) {
                 #[cfg(debug_assertions)]
                 assert_eq!(
-                  el.node_name().to_ascii_uppercase(),
-                  stringify!([<$tag:upper $(_ $second:upper $(_ $third:upper)?)?>]),
+                  el.node_name().to_ascii_lowercase(),
+                  concat![
+                    stringify!($tag),
+                    $(
+                      "-", stringify!($second),
+                      $(
+                        "-", stringify!($third)
+                      )?
+                    )?
+                  ],
                   "SSR and CSR elements have the same `TopoId` \
                     but different node kinds. This is either a \
                     discrepancy between SSR and CSR rendering
@@ -82,8 +100,16 @@ macro_rules! generate_math_tags {
               ) {
                 #[cfg(debug_assertions)]
                 assert_eq!(
-                  el.node_name().to_ascii_uppercase(),
-                  stringify!([<$tag:upper $(_ $second:upper $(_ $third:upper)?)?>]),
+                  el.node_name().to_ascii_lowercase(),
+                  concat![
+                    stringify!($tag),
+                    $(
+                      "-", stringify!($second),
+                      $(
+                        "-", stringify!($third)
+                      )?
+                    )?
+                  ],
                   "SSR and CSR elements have the same `TopoId` \
                     but different node kinds. This is either a \
                     discrepancy between SSR and CSR rendering
@@ -125,14 +151,11 @@ macro_rules! generate_math_tags {
         }
 
         impl std::ops::Deref for [<$tag:camel $($second:camel $($third:camel)?)?>] {
-          type Target = web_sys::Element;
+          type Target = web_sys::MathMlElement;
 
           fn deref(&self) -> &Self::Target {
             #[cfg(all(target_arch = "wasm32", feature = "web"))]
-            {
-              use wasm_bindgen::JsCast;
-              return &self.element.unchecked_ref();
-            }
+            return self.element.unchecked_ref();
 
             #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
             unimplemented!("{HTML_ELEMENT_DEREF_UNIMPLEMENTED_MSG}");
@@ -151,7 +174,20 @@ macro_rules! generate_math_tags {
 
         impl ElementDescriptor for [<$tag:camel $($second:camel $($third:camel)?)?>] {
           fn name(&self) -> Cow<'static, str> {
-            stringify!($tag).into()
+            concat![
+              stringify!($tag),
+              $(
+                "-", stringify!($second),
+                $(
+                  "-", stringify!($third)
+                )?
+              )?
+            ]
+            .into()
+          }
+
+          fn attribute_name(&self, name: Cow<'static, str>) -> Cow<'static, str> {
+            adjust_attribute_name(name)
           }
 
           #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
@@ -129,6 +129,27 @@ impl<T: ElementDescriptor + 'static> NodeRef<T> {
 
     /// Runs the provided closure when the `NodeRef` has been connected
     /// with it's [`HtmlElement`].
+    ///
+    /// The closure runs once, with the first element loaded into the
+    /// reference, and then stops tracking it. Elements are only loaded into
+    /// references in the browser, so it never runs on the server.
+    ///
+    /// ```
+    /// # use leptos::*;
+    /// #[component]
+    /// pub fn SearchBox(cx: Scope) -> impl IntoView {
+    ///     let input_ref = create_node_ref::<html::Input>(cx);
+    ///
+    ///     // `input` is an `HtmlElement<Input>`, which derefs to an
+    ///     // `HtmlInputElement`
+    ///     input_ref.on_load(cx, |input| {
+    ///         _ = input.focus();
+    ///         input.select();
+    ///     });
+    ///
+    ///     view! { cx, <input type="search" _ref=input_ref/> }
+    /// }
+    /// ```
     #[inline(always)]
     pub fn on_load<F>(self, cx: Scope, f: F)
     where
@@ -137,9 +158,20 @@ impl<T: ElementDescriptor + 'static> NodeRef<T> {
     {
         let f = Cell::new(Some(f));
 
-        create_effect(cx, move |_| {
-            if let Some(node_ref) = self.get() {
-                f.take().unwrap()(node_ref);
+        create_effect(cx, move |loaded: Option<bool>| {
+            // once it has run, the effect stops reading the reference, so it
+            // no longer depends on it
+            if loaded == Some(true) {
+                return true;
+            }
+            match self.get() {
+                Some(node) => {
+                    if let Some(f) = f.take() {
+                        f(node);
+                    }
+                    true
+                }
+                None => false,
             }
         });
     }
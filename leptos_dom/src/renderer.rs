@@ -0,0 +1,485 @@
+//! The operations a rendering backend provides, so that views can be rendered
+//! to something other than the browser’s DOM.
+//!
+//! A [`Renderer`] creates and arranges the nodes of a tree: elements, text,
+//! attributes and event listeners. On other targets than the browser, a view
+//! is built as a tree in memory, which [`View::render_with`] hands over to any
+//! renderer, e.g., an in-memory DOM for tests, or an experimental native or
+//! terminal backend.
+//!
+//! The tree is built once, as it would be for server-side rendering. Call
+//! [`set_reactive_views`] before building it to keep it up to date as its
+//! signals change, so that it can be rendered again after an event. The
+//! renderer isn’t told about those changes: it only sees the tree as it is
+//! when it is rendered.
+//!
+//! In the browser, views use [`Dom`], which implements the trait with
+//! `web_sys`, to create, mount and remove their nodes. They aren’t generic
+//! over the renderer, though, and still update attributes, classes,
+//! properties and event listeners with `web_sys` directly, so [`Dom`] is the
+//! only renderer that views can be mounted with.
+//!
+//! ```
+//! use leptos::*;
+//! use leptos_dom::renderer::Renderer;
+//! use std::cell::RefCell;
+//!
+//! // a renderer that records what it is asked to do
+//! #[derive(Default)]
+//! struct Log(RefCell<Vec<String>>);
+//!
+//! impl Renderer for Log {
+//!     type Node = String;
+//!
+//!     fn create_element(&self, tag: &str) -> String {
+//!         tag.to_string()
+//!     }
+//!     fn create_text_node(&self, text: &str) -> String {
+//!         format!("{text:?}")
+//!     }
+//!     fn set_attribute(&self, el: &String, name: &str, value: &str) {
+//!         self.0.borrow_mut().push(format!("{el}[{name}={value}]"));
+//!     }
+//!     fn remove_attribute(&self, _: &String, _: &str) {}
+//!     fn set_inner_html(&self, _: &String, _: &str) {}
+//!     fn insert_before(
+//!         &self,
+//!         parent: &String,
+//!         child: &String,
+//!         _: Option<&String>,
+//!     ) {
+//!         self.0.borrow_mut().push(format!("{parent} > {child}"));
+//!     }
+//!     fn remove(&self, _: &String) {}
+//!     fn add_event_listener(
+//!         &self,
+//!         _: &String,
+//!         _: &str,
+//!         _: Box<dyn FnMut()>,
+//!     ) {
+//!     }
+//! }
+//!
+//! let runtime = create_runtime();
+//! run_scope(runtime, |cx| {
+//!     let log = Log::default();
+//!     view! { cx, <p class="greeting">"Hello"</p> }
+//!         .into_view(cx)
+//!         .render_with(&log, &"root".to_string());
+//!     assert_eq!(
+//!         log.0.into_inner(),
+//!         ["p[class=greeting]", "p > \"Hello\"", "root > p"]
+//!     );
+//! });
+//! runtime.dispose();
+//! ```
+
+/// The operations a rendering backend provides.
+///
+/// Nodes are handles to the backend’s own tree, so they are cheap to clone.
+pub trait Renderer {
+    /// A node in the backend’s tree: an element or a text node.
+    type Node: Clone;
+
+    /// Creates an element with the given tag name, which isn’t in the tree
+    /// yet.
+    fn create_element(&self, tag: &str) -> Self::Node;
+
+    /// Creates a text node, which isn’t in the tree yet.
+    fn create_text_node(&self, text: &str) -> Self::Node;
+
+    /// Sets an attribute of an element.
+    fn set_attribute(&self, el: &Self::Node, name: &str, value: &str);
+
+    /// Removes an attribute from an element.
+    fn remove_attribute(&self, el: &Self::Node, name: &str);
+
+    /// Replaces the children of an element with the given HTML.
+    fn set_inner_html(&self, el: &Self::Node, html: &str);
+
+    /// Inserts `child` into `parent`, before `before`, or at the end if it is
+    /// `None`. A child that was already in the tree is moved.
+    fn insert_before(
+        &self,
+        parent: &Self::Node,
+        child: &Self::Node,
+        before: Option<&Self::Node>,
+    );
+
+    /// Appends `child` to the children of `parent`.
+    fn append_child(&self, parent: &Self::Node, child: &Self::Node) {
+        self.insert_before(parent, child, None);
+    }
+
+    /// Removes a node from the tree.
+    fn remove(&self, node: &Self::Node);
+
+    /// Calls `listener` whenever the event `name` happens on the element.
+    ///
+    /// The listener doesn’t take the event itself, which is specific to the
+    /// backend. Outside the browser, the handlers of the `view` macro are
+    /// called with an event that stands in for it: they can read its target
+    /// with [`event_target_value`](crate::event_target_value) and
+    /// [`event_target_checked`](crate::event_target_checked), with the
+    /// `testing` feature, but calling its methods, like `prevent_default()`,
+    /// panics.
+    fn add_event_listener(
+        &self,
+        el: &Self::Node,
+        name: &str,
+        listener: Box<dyn FnMut()>,
+    );
+}
+
+/// Renders to the browser’s DOM, with `web_sys`.
+///
+/// Views create their elements and text nodes and mount and remove their
+/// nodes through this. Attributes are updated and event listeners are added
+/// directly, since they are typed and may be delegated.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Dom;
+
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+impl Renderer for Dom {
+    type Node = web_sys::Node;
+
+    fn create_element(&self, tag: &str) -> Self::Node {
+        crate::document()
+            .create_element(tag)
+            .expect("element to be created")
+            .into()
+    }
+
+    fn create_text_node(&self, text: &str) -> Self::Node {
+        crate::document().create_text_node(text).into()
+    }
+
+    fn set_attribute(&self, el: &Self::Node, name: &str, value: &str) {
+        use wasm_bindgen::{JsCast, UnwrapThrowExt};
+
+        let el = el.unchecked_ref::<web_sys::Element>();
+        match attribute_namespace(name) {
+            Some(ns) => el.set_attribute_ns(Some(ns), name, value),
+            None => el.set_attribute(name, value),
+        }
+        .unwrap_throw();
+    }
+
+    fn remove_attribute(&self, el: &Self::Node, name: &str) {
+        use wasm_bindgen::{JsCast, UnwrapThrowExt};
+
+        let el = el.unchecked_ref::<web_sys::Element>();
+        match attribute_namespace(name) {
+            Some(ns) => {
+                let local_name =
+                    name.split_once(':').map_or(name, |(_, name)| name);
+                el.remove_attribute_ns(Some(ns), local_name)
+            }
+            None => el.remove_attribute(name),
+        }
+        .unwrap_throw();
+    }
+
+    fn set_inner_html(&self, el: &Self::Node, html: &str) {
+        use wasm_bindgen::JsCast;
+
+        el.unchecked_ref::<web_sys::Element>().set_inner_html(html);
+    }
+
+    fn insert_before(
+        &self,
+        parent: &Self::Node,
+        child: &Self::Node,
+        before: Option<&Self::Node>,
+    ) {
+        _ = parent.insert_before(child, before);
+    }
+
+    fn remove(&self, node: &Self::Node) {
+        if let Some(parent) = node.parent_node() {
+            _ = parent.remove_child(node);
+        }
+    }
+
+    fn add_event_listener(
+        &self,
+        el: &Self::Node,
+        name: &str,
+        mut listener: Box<dyn FnMut()>,
+    ) {
+        use wasm_bindgen::{closure::Closure, JsCast};
+
+        let listener =
+            Closure::wrap(Box::new(move |_: web_sys::Event| listener())
+                as Box<dyn FnMut(web_sys::Event)>)
+            .into_js_value();
+        _ = el.add_event_listener_with_callback(name, listener.unchecked_ref());
+    }
+}
+
+/// Returns the namespace of a prefixed attribute name like `xlink:href`,
+/// which has to be set with `setAttributeNS` to have any effect.
+#[cfg(all(target_arch = "wasm32", feature = "web"))]
+fn attribute_namespace(attr_name: &str) -> Option<&'static str> {
+    if attr_name.starts_with("xlink:") {
+        Some("http://www.w3.org/1999/xlink")
+    } else if attr_name.starts_with("xml:") {
+        Some("http://www.w3.org/XML/1998/namespace")
+    } else if attr_name == "xmlns" || attr_name.starts_with("xmlns:") {
+        Some("http://www.w3.org/2000/xmlns/")
+    } else {
+        None
+    }
+}
+
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+thread_local! {
+    static REACTIVE_VIEWS: std::cell::Cell<bool> = Default::default();
+}
+
+/// Sets whether views that are built in memory, outside the browser, are kept
+/// up to date as the signals they read change, so that they can be rendered
+/// again with [`View::render_with`], e.g., after a test dispatches an event.
+///
+/// This is off by default, since server-side rendering only needs each view
+/// once, and applies to the views that are built after it is set on this
+/// thread.
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+pub fn set_reactive_views(reactive: bool) {
+    REACTIVE_VIEWS.with(|reactive_views| reactive_views.set(reactive));
+}
+
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+pub(crate) fn reactive_views() -> bool {
+    REACTIVE_VIEWS.with(|reactive_views| reactive_views.get())
+}
+
+/// The state of the element that an event is dispatched to, outside the
+/// browser, where the event’s target can’t be read.
+#[cfg(all(feature = "testing", not(target_arch = "wasm32")))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventTarget {
+    /// The value of the element, for
+    /// [`event_target_value`](crate::event_target_value).
+    pub value: String,
+    /// Whether it is checked, for
+    /// [`event_target_checked`](crate::event_target_checked).
+    pub checked: bool,
+}
+
+#[cfg(all(feature = "testing", not(target_arch = "wasm32")))]
+thread_local! {
+    static EVENT_TARGET: std::cell::RefCell<Option<EventTarget>> =
+        Default::default();
+}
+
+/// Calls `f`, which dispatches an event, with `target` as the element that
+/// [`event_target_value`](crate::event_target_value) and
+/// [`event_target_checked`](crate::event_target_checked) read from.
+#[cfg(all(feature = "testing", not(target_arch = "wasm32")))]
+pub fn with_event_target<T>(target: EventTarget, f: impl FnOnce() -> T) -> T {
+    let prev = EVENT_TARGET.with(|current| current.replace(Some(target)));
+    let value = f();
+    EVENT_TARGET.with(|current| *current.borrow_mut() = prev);
+    value
+}
+
+#[cfg(all(feature = "testing", not(target_arch = "wasm32")))]
+pub(crate) fn event_target() -> Option<EventTarget> {
+    EVENT_TARGET.with(|current| current.borrow().clone())
+}
+
+#[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+mod tree {
+    use super::Renderer;
+    use crate::{
+        html::{ElementChildren, StringOrView},
+        CoreComponent, View,
+    };
+    use std::{any::Any, cell::RefCell, rc::Rc};
+    use wasm_bindgen::{JsCast, JsValue};
+
+    impl View {
+        /// Renders the view with the given [`Renderer`], appending its nodes
+        /// to `parent`, and adds its event listeners.
+        ///
+        /// This renders the view as it is now: the nodes aren’t updated when
+        /// signals change. If the view was built after
+        /// [`set_reactive_views`](super::set_reactive_views), its dynamic
+        /// children and the attributes that are set with a closure are kept
+        /// up to date, so it can be rendered again to see the changes.
+        /// Classes, styles and properties are only read when the view is
+        /// built.
+        ///
+        /// # Panics
+        /// Panics if the view contains a [`View::Transparent`], which has no
+        /// nodes to render.
+        pub fn render_with<R: Renderer>(&self, renderer: &R, parent: &R::Node) {
+            match self {
+                View::Text(text) => {
+                    let node = renderer.create_text_node(&text.content);
+                    renderer.append_child(parent, &node);
+                }
+                View::Element(el) => {
+                    let node = renderer.create_element(&el.name);
+                    // the ID that hydration uses isn't part of the view
+                    let hydration_id = format!("_{}", el.id);
+                    let mut inner_html = None;
+                    let bindings = el.bindings.as_deref();
+                    let bound_attrs = bindings
+                        .map(|bindings| bindings.attrs.as_slice())
+                        .unwrap_or_default();
+                    let is_dynamic =
+                        |name| bound_attrs.iter().any(|(n, _)| n == name);
+                    let dynamic_attrs =
+                        bound_attrs.iter().filter_map(|(name, attr)| {
+                            Some((name, attr.as_nameless_value_string()?))
+                        });
+                    let attrs = el
+                        .attrs
+                        .iter()
+                        .filter(|(name, _)| !is_dynamic(name))
+                        .map(|(name, value)| (name, value.clone()))
+                        .chain(dynamic_attrs);
+                    for (name, value) in attrs {
+                        match name.as_ref() {
+                            "leptos-hk" => {}
+                            "id" if value == hydration_id => {}
+                            "inner_html" => {
+                                inner_html = Some(crate::sanitize_html(value));
+                            }
+                            "dangerously_set_inner_html" => {
+                                inner_html = Some(value);
+                            }
+                            _ => renderer.set_attribute(&node, name, &value),
+                        }
+                    }
+                    let listeners = bindings
+                        .map(|bindings| bindings.listeners.as_slice())
+                        .unwrap_or_default();
+                    for (name, listener) in listeners {
+                        let listener = Rc::clone(listener);
+                        let event_name = name.clone();
+                        renderer.add_event_listener(
+                            &node,
+                            name,
+                            Box::new(move || {
+                                call_listener(&event_name, &*listener)
+                            }),
+                        );
+                    }
+                    if let Some(html) = inner_html {
+                        renderer.set_inner_html(&node, &html);
+                    } else {
+                        match &el.children {
+                            ElementChildren::Empty => {}
+                            ElementChildren::Children(children) => {
+                                for child in children {
+                                    child.render_with(renderer, &node);
+                                }
+                            }
+                            ElementChildren::InnerHtml(html) => {
+                                renderer.set_inner_html(&node, html);
+                            }
+                            // rendered ahead of time by the server-side
+                            // `view!` output, which has no listeners, so the
+                            // chunks are joined in order and set as HTML
+                            ElementChildren::Chunks(chunks) => {
+                                let html = chunks
+                                    .iter()
+                                    .map(|chunk| match chunk {
+                                        StringOrView::String(html) => {
+                                            html.clone()
+                                        }
+                                        StringOrView::View(view) => view()
+                                            .render_to_string_helper(false),
+                                    })
+                                    .collect::<String>();
+                                renderer.set_inner_html(&node, &html);
+                            }
+                        }
+                    }
+                    renderer.append_child(parent, &node);
+                }
+                View::Component(component) => {
+                    for child in &component.children {
+                        child.render_with(renderer, parent);
+                    }
+                }
+                View::CoreComponent(component)
+                | View::Suspense(_, component) => match component {
+                    CoreComponent::Unit(_) => {}
+                    CoreComponent::DynChild(dyn_child) => {
+                        if let Some(child) = &**dyn_child.child.borrow() {
+                            child.render_with(renderer, parent);
+                        }
+                    }
+                    CoreComponent::Each(each) => {
+                        for item in each.children.borrow().iter().flatten() {
+                            item.child.render_with(renderer, parent);
+                        }
+                    }
+                },
+                View::Transparent(_) => {
+                    panic!("tried to render a Transparent node.")
+                }
+            }
+        }
+    }
+
+    /// Calls a listener that was kept by
+    /// [`HtmlElement::on`](crate::HtmlElement::on) with an event of the
+    /// `web_sys` type that it takes, which stands in for the event outside
+    /// the browser, where no such event can be created.
+    fn call_listener(name: &str, listener: &dyn Any) {
+        let ev = JsValue::NULL.unchecked_into::<web_sys::Event>();
+        macro_rules! call_as {
+            ($($event_type:ident),* $(,)?) => {
+                $(
+                    if let Some(listener) = listener.downcast_ref::<
+                        RefCell<Box<dyn FnMut(web_sys::$event_type)>>,
+                    >() {
+                        return (*listener.borrow_mut())(ev.unchecked_into());
+                    }
+                )*
+            };
+        }
+
+        call_as!(
+            Event,
+            AnimationEvent,
+            BeforeUnloadEvent,
+            CompositionEvent,
+            CustomEvent,
+            DeviceMotionEvent,
+            DeviceOrientationEvent,
+            DragEvent,
+            ErrorEvent,
+            FocusEvent,
+            GamepadEvent,
+            HashChangeEvent,
+            InputEvent,
+            KeyboardEvent,
+            MessageEvent,
+            MouseEvent,
+            PageTransitionEvent,
+            PointerEvent,
+            PopStateEvent,
+            ProgressEvent,
+            PromiseRejectionEvent,
+            SecurityPolicyViolationEvent,
+            StorageEvent,
+            SubmitEvent,
+            TouchEvent,
+            TransitionEvent,
+            UiEvent,
+            WheelEvent,
+        );
+
+        crate::debug_warn!(
+            "the `{name}` listener takes an event that isn't a `web_sys` \
+             event type, so it can't be called outside the browser"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use std::{
+    borrow::Cow,
+    sync::{Arc, RwLock},
+};
+
+/// Cleans HTML before it is set as the inner HTML of an element with
+/// `inner_html`, so that untrusted content can’t be used for cross-site
+/// scripting (XSS).
+///
+/// Set one for the whole app with [`set_html_sanitizer`]. It is used both
+/// when rendering on the server and in the browser. Closures that take and
+/// return a `Cow<'static, str>` are sanitizers too.
+///
+/// Trusted HTML that must not be changed can be set with
+/// [`HtmlElement::dangerously_set_inner_html`](crate::HtmlElement::dangerously_set_inner_html),
+/// or the `dangerously_set_inner_html` attribute in the `view` macro, which
+/// skip the sanitizer.
+pub trait HtmlSanitizer: Send + Sync {
+    /// Returns a safe version of `html`.
+    fn sanitize(&self, html: Cow<'static, str>) -> Cow<'static, str>;
+}
+
+impl<F> HtmlSanitizer for F
+where
+    F: Fn(Cow<'static, str>) -> Cow<'static, str> + Send + Sync,
+{
+    fn sanitize(&self, html: Cow<'static, str>) -> Cow<'static, str> {
+        self(html)
+    }
+}
+
+/// A sanitizer that uses [`ammonia`] to remove everything but a conservative
+/// set of tags and attributes, like scripts, event handler attributes and
+/// `javascript:` URLs.
+///
+/// This is the default sanitizer when the `sanitize` feature is enabled. Use
+/// an [`ammonia::Builder`] as the sanitizer to choose what is allowed.
+#[cfg(feature = "sanitize")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Ammonia;
+
+#[cfg(feature = "sanitize")]
+impl HtmlSanitizer for Ammonia {
+    fn sanitize(&self, html: Cow<'static, str>) -> Cow<'static, str> {
+        ammonia::clean(&html).into()
+    }
+}
+
+#[cfg(feature = "sanitize")]
+impl HtmlSanitizer for ammonia::Builder<'static> {
+    fn sanitize(&self, html: Cow<'static, str>) -> Cow<'static, str> {
+        self.clean(&html).to_string().into()
+    }
+}
+
+static SANITIZER: RwLock<Option<Arc<dyn HtmlSanitizer>>> = RwLock::new(None);
+
+/// Sets the [`HtmlSanitizer`] that cleans the HTML given to `inner_html`
+/// everywhere in the app.
+///
+/// Without one, [`Ammonia`] is used if the `sanitize` feature is enabled, and
+/// the HTML is not changed if it isn’t.
+///
+/// ```rust
+/// # use leptos::*;
+/// # use std::borrow::Cow;
+/// // a sanitizer that only allows plain text
+/// set_html_sanitizer(|html: Cow<'static, str>| -> Cow<'static, str> {
+///     html.replace('<', "&lt;").into()
+/// });
+/// # let runtime = create_runtime();
+/// # create_scope(runtime, |cx| {
+/// let comment = "<img src=x onerror=alert(1)>";
+/// view! { cx,
+///     // cleaned by the sanitizer
+///     <div inner_html=comment/>
+///     // trusted HTML from the app itself, which isn’t cleaned
+///     <div dangerously_set_inner_html="<b>Hello</b>"/>
+/// };
+/// # });
+/// # runtime.dispose();
+/// ```
+pub fn set_html_sanitizer(sanitizer: impl HtmlSanitizer + 'static) {
+    *SANITIZER.write().unwrap_or_else(|e| e.into_inner()) =
+        Some(Arc::new(sanitizer));
+}
+
+/// Cleans `html` with the sanitizer set with [`set_html_sanitizer`], as
+/// `inner_html` does.
+pub fn sanitize_html(html: impl Into<Cow<'static, str>>) -> Cow<'static, str> {
+    let html = html.into();
+    let sanitizer = SANITIZER.read().unwrap_or_else(|e| e.into_inner()).clone();
+    match sanitizer {
+        Some(sanitizer) => sanitizer.sanitize(html),
+        #[cfg(feature = "sanitize")]
+        None => Ammonia.sanitize(html),
+        #[cfg(not(feature = "sanitize"))]
+        None => html,
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{
+    ev::EventDescriptor, html::ElementDescriptor, Attribute, HtmlElement,
+    IntoAttribute, IntoProperty, Property,
+};
+use leptos_reactive::Scope;
+use std::{borrow::Cow, cell::RefCell, rc::Rc};
+
+/// A collection of attributes, properties and event listeners that can all be
+/// added to an element at once, with [`HtmlElement::spread`] or `{..attrs}`
+/// in the `view` macro.
+///
+/// This lets a component that wraps an element forward any HTML attributes
+/// to it, without listing every attribute it might be given as a prop.
+///
+/// ```rust
+/// # use leptos::*;
+/// #[component]
+/// fn Button(
+///     cx: Scope,
+///     #[prop(optional, into)] attrs: SpreadAttributes,
+///     children: Children,
+/// ) -> impl IntoView {
+///     view! { cx, <button class="button" {..attrs}>{children(cx)}</button> }
+/// }
+///
+/// # let runtime = create_runtime();
+/// # create_scope(runtime, |cx| {
+/// let (count, set_count) = create_signal(cx, 0);
+/// let attrs = SpreadAttributes::new()
+///     .attr("type", "submit")
+///     .attr("aria-label", move || {
+///         format!("Clicked {} times", count.get())
+///     })
+///     .on(ev::click, move |_| set_count.update(|n| *n += 1));
+///
+/// view! { cx, <Button attrs=attrs>"Click me"</Button> };
+///
+/// // a list of pairs can be spread directly too
+/// view! { cx, <Button attrs=[("disabled", "")]>"Can’t click me"</Button> };
+/// # });
+/// # runtime.dispose();
+/// ```
+#[derive(Default, Clone)]
+pub struct SpreadAttributes(Vec<SpreadBinding>);
+
+#[derive(Clone)]
+enum SpreadBinding {
+    Attribute(Cow<'static, str>, Rc<dyn Fn(Scope) -> Attribute>),
+    Property(Cow<'static, str>, Rc<dyn Fn(Scope) -> Property>),
+    Event(Rc<dyn Fn(&web_sys::Element)>),
+}
+
+impl SpreadAttributes {
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the collection has no attributes, properties or listeners.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Adds an attribute.
+    pub fn attr(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl IntoAttribute + Clone + 'static,
+    ) -> Self {
+        self.0.push(SpreadBinding::Attribute(
+            name.into(),
+            Rc::new(move |cx| value.clone().into_attribute(cx)),
+        ));
+        self
+    }
+
+    /// Adds a property, which is only set in the browser.
+    pub fn prop(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        value: impl IntoProperty + Clone + 'static,
+    ) -> Self {
+        self.0.push(SpreadBinding::Property(
+            name.into(),
+            Rc::new(move |cx| value.clone().into_property(cx)),
+        ));
+        self
+    }
+
+    /// Adds an event listener, which is only added in the browser.
+    ///
+    /// If these attributes are spread onto more than one element, they all
+    /// share the same handler.
+    pub fn on<E: EventDescriptor + 'static>(
+        mut self,
+        event: E,
+        event_handler: impl FnMut(E::EventType) + 'static,
+    ) -> Self {
+        let event_handler = Rc::new(RefCell::new(event_handler));
+        self.0.push(SpreadBinding::Event(Rc::new(move |el| {
+            #[cfg(all(target_arch = "wasm32", feature = "web"))]
+            {
+                let event_handler = Rc::clone(&event_handler);
+                let event_handler: Box<dyn FnMut(E::EventType)> =
+                    Box::new(move |ev| (event_handler.borrow_mut())(ev));
+                if E::BUBBLES {
+                    crate::events::add_event_listener(
+                        el,
+                        event.event_delegation_key(),
+                        event.name(),
+                        event_handler,
+                        event.options(),
+                    );
+                } else {
+                    crate::events::add_event_listener_undelegated(
+                        el,
+                        &event.name(),
+                        event_handler,
+                        event.options(),
+                    );
+                }
+            }
+
+            #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+            {
+                _ = (el, &event, &event_handler);
+            }
+        })));
+        self
+    }
+
+    /// Renders the attributes as they would appear in an opening tag, each
+    /// with a leading space, so they can be rendered on the server.
+    #[doc(hidden)]
+    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+    pub fn to_html(&self, cx: Scope) -> String {
+        let mut html = String::new();
+        for binding in &self.0 {
+            if let SpreadBinding::Attribute(name, value) = binding {
+                if let Some(value) = value(cx).as_nameless_value_string() {
+                    html.push(' ');
+                    html.push_str(name);
+                    if !value.is_empty() {
+                        html.push_str("=\"");
+                        html.push_str(&crate::ssr::escape_attr(&value));
+                        html.push('"');
+                    }
+                }
+            }
+        }
+        html
+    }
+}
+
+impl<I, K, V> From<I> for SpreadAttributes
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<Cow<'static, str>>,
+    V: IntoAttribute + Clone + 'static,
+{
+    fn from(value: I) -> Self {
+        value
+            .into_iter()
+            .fold(Self::new(), |attrs, (name, value)| attrs.attr(name, value))
+    }
+}
+
+impl<El: ElementDescriptor + 'static> HtmlElement<El> {
+    /// Adds every attribute, property and event listener in the collection to
+    /// this element, in order.
+    ///
+    /// This is what `{..attrs}` does in the `view` macro.
+    #[track_caller]
+    pub fn spread(self, attrs: impl Into<SpreadAttributes>) -> Self {
+        let SpreadAttributes(bindings) = attrs.into();
+        bindings
+            .into_iter()
+            .fold(self, |this, binding| match binding {
+                SpreadBinding::Attribute(name, value) => {
+                    let value = value(this.cx);
+                    this.attr(name, value)
+                }
+                SpreadBinding::Property(name, value) => {
+                    let value = value(this.cx);
+                    this.prop(name, value)
+                }
+                SpreadBinding::Event(add_listener) => {
+                    #[cfg(all(target_arch = "wasm32", feature = "web"))]
+                    add_listener(this.element.as_ref());
+
+                    #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
+                    let _ = add_listener;
+
+                    this
+                }
+            })
+    }
+}
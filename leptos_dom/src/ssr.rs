@@ -4,13 +4,21 @@
 
 use crate::{
     html::{ElementChildren, StringOrView},
-    CoreComponent, HydrationCtx, IntoView, View,
+    CoreComponent, HydrationCtx, HydrationKey, IntoView, View,
 };
 use cfg_if::cfg_if;
-use futures::{stream::FuturesUnordered, Future, Stream, StreamExt};
-use itertools::Itertools;
+use futures::{
+    stream::FuturesUnordered, AsyncWrite, AsyncWriteExt, Future, Stream,
+    StreamExt,
+};
 use leptos_reactive::*;
-use std::{borrow::Cow, pin::Pin};
+use std::{
+    borrow::Cow,
+    collections::VecDeque,
+    fmt, io,
+    pin::Pin,
+    task::{Context, Poll},
+};
 
 type PinnedFuture<T> = Pin<Box<dyn Future<Output = T>>>;
 
@@ -47,6 +55,46 @@ where
     html.into()
 }
 
+/// Renders the given function to HTML, which is written into `w` as it is
+/// rendered rather than being returned as a single `String`.
+///
+/// ```
+/// # cfg_if::cfg_if! { if #[cfg(not(any(feature = "csr", feature = "hydrate")))] {
+/// # use leptos::*;
+/// let mut html = String::new();
+/// leptos::ssr::render_to_writer(
+///     |cx| view! { cx, <p>"Hello, world!"</p> },
+///     &mut html,
+/// )
+/// .unwrap();
+/// assert!(html.contains("Hello, world!</p>"));
+/// # }}
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    instrument(level = "info", skip_all,)
+)]
+pub fn render_to_writer<F, N, W>(f: F, w: &mut W) -> fmt::Result
+where
+    F: FnOnce(Scope) -> N + 'static,
+    N: IntoView,
+    W: fmt::Write + ?Sized,
+{
+    let runtime = leptos_reactive::create_runtime();
+    HydrationCtx::reset_id();
+
+    // `w` is only borrowed, so the view is rendered outside of the closure,
+    // but before its scope is disposed
+    let (view, id, disposer) =
+        run_scope_undisposed(runtime, |cx| f(cx).into_view(cx));
+    let result = view.render_to_writer(Scope { runtime, id }, w);
+
+    disposer.dispose();
+    runtime.dispose();
+
+    result
+}
+
 /// Renders a function to a stream of HTML strings.
 ///
 /// This renders:
@@ -190,27 +238,30 @@ pub fn render_to_stream_with_prefix_undisposed_with_context_and_block_replacemen
     // create the runtime
     let runtime = create_runtime();
 
-    let ((shell, pending_resources, pending_fragments, serializers), scope, _) =
-        run_scope_undisposed(runtime, {
-            move |cx| {
-                // Add additional context items
-                additional_context(cx);
-                // the actual app body/template code
-                // this does NOT contain any of the data being loaded asynchronously in resources
-                let shell = view(cx).render_to_string(cx);
-
-                let resources = cx.pending_resources();
-                let pending_resources =
-                    serde_json::to_string(&resources).unwrap();
-
-                (
-                    shell,
-                    pending_resources,
-                    cx.pending_fragments(),
-                    cx.serialization_resolvers(),
-                )
-            }
-        });
+    let (
+        (shell, pending_resources, config, pending_fragments, serializers),
+        scope,
+        _,
+    ) = run_scope_undisposed(runtime, {
+        move |cx| {
+            // Add additional context items
+            additional_context(cx);
+            // the actual app body/template code
+            // this does NOT contain any of the data being loaded asynchronously in resources
+            let shell = view(cx).render_to_string(cx);
+
+            let resources = cx.pending_resources();
+            let pending_resources = serde_json::to_string(&resources).unwrap();
+
+            (
+                shell,
+                pending_resources,
+                serialized_config(cx),
+                cx.pending_fragments(),
+                cx.serialization_resolvers(),
+            )
+        }
+    });
     let cx = Scope { runtime, id: scope };
 
     let mut blocking_fragments = FuturesUnordered::new();
@@ -234,11 +285,7 @@ pub fn render_to_stream_with_prefix_undisposed_with_context_and_block_replacemen
 
     // HTML for the view function and script to store resources
     let stream = futures::stream::once(async move {
-        let resolvers = format!(
-            "<script>__LEPTOS_PENDING_RESOURCES = \
-             {pending_resources};__LEPTOS_RESOLVED_RESOURCES = new \
-             Map();__LEPTOS_RESOURCE_RESOLVERS = new Map();</script>"
-        );
+        let resolvers = resolvers(&pending_resources, &config);
 
         if replace_blocks {
             let mut blocks = Vec::with_capacity(blocking_fragments.len());
@@ -283,6 +330,237 @@ pub fn render_to_stream_with_prefix_undisposed_with_context_and_block_replacemen
     (stream, runtime, scope)
 }
 
+/// Renders a function to HTML like [`render_to_stream_with_prefix`], but
+/// writes it into `writer` instead of returning a stream of `String`s.
+///
+/// The view is built first, which runs its components, and the `prefix` is
+/// written as soon as any blocking fragments have resolved. The application
+/// shell is then rendered straight into `writer`, in chunks of about
+/// `chunk_size` bytes that are written and flushed as they are produced, so
+/// the shell is never held in memory as a whole. `<Suspense/>` fragments and
+/// resources are written as soon as they resolve.
+///
+/// Rendering can't wait for the writer, so the chunks that it isn't ready to
+/// accept are kept until it is.
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    instrument(level = "info", skip_all,)
+)]
+pub async fn render_to_async_writer<W>(
+    view: impl FnOnce(Scope) -> View + 'static,
+    prefix: impl FnOnce(Scope) -> Cow<'static, str> + 'static,
+    writer: &mut W,
+    chunk_size: usize,
+) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    HydrationCtx::reset_id();
+
+    let runtime = create_runtime();
+
+    let (
+        (view, pending_resources, config, pending_fragments, serializers),
+        scope,
+        _,
+    ) = run_scope_undisposed(runtime, move |cx| {
+        // components run when the view is built, so everything the prefix
+        // needs from them is known before the view is rendered
+        let view = view(cx);
+
+        let resources = cx.pending_resources();
+        let pending_resources = serde_json::to_string(&resources).unwrap();
+
+        (
+            view,
+            pending_resources,
+            serialized_config(cx),
+            cx.pending_fragments(),
+            cx.serialization_resolvers(),
+        )
+    });
+    let cx = Scope { runtime, id: scope };
+
+    let result = async {
+        let blocking_fragments = FuturesUnordered::new();
+        let fragments = FuturesUnordered::new();
+
+        for (fragment_id, data) in pending_fragments {
+            if data.should_block {
+                blocking_fragments.push(async move {
+                    (fragment_id, data.out_of_order.await)
+                });
+            } else {
+                fragments.push(async move {
+                    (fragment_id, data.out_of_order.await)
+                });
+            }
+        }
+
+        // the prefix can only be rendered once blocking fragments are ready
+        let blocking = fragments_to_chunks(blocking_fragments)
+            .collect::<Vec<_>>()
+            .await;
+        write_and_flush(writer, &prefix(cx)).await?;
+
+        let mut shell = ChunkedWriter::new(writer, chunk_size);
+        let rendered = view.render_to_writer(cx, &mut shell);
+        shell.finish(rendered).await?;
+
+        write_and_flush(writer, &resolvers(&pending_resources, &config))
+            .await?;
+        for fragment in blocking {
+            write_and_flush(writer, &fragment).await?;
+        }
+
+        let mut rest = fragments_to_chunks(fragments)
+            .chain(render_serializers(serializers));
+        while let Some(chunk) = rest.next().await {
+            write_and_flush(writer, &chunk).await?;
+        }
+
+        Ok(())
+    }
+    .await;
+
+    runtime.dispose();
+
+    result
+}
+
+async fn write_and_flush<W>(writer: &mut W, html: &str) -> io::Result<()>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    writer.write_all(html.as_bytes()).await?;
+    writer.flush().await
+}
+
+// Writes HTML into an `AsyncWrite` in chunks of about `size` bytes while it
+// is being rendered. Each chunk is written and flushed as soon as it is full
+// if the writer is ready for it, and kept until `finish` otherwise.
+struct ChunkedWriter<'w, W: ?Sized> {
+    writer: &'w mut W,
+    size: usize,
+    chunk: String,
+    // the chunks the writer wasn't ready for, and how many bytes of the
+    // first one have been written
+    pending: VecDeque<String>,
+    written: usize,
+    error: Option<io::Error>,
+}
+
+impl<'w, W> ChunkedWriter<'w, W>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    fn new(writer: &'w mut W, size: usize) -> Self {
+        let size = size.max(1);
+        Self {
+            writer,
+            size,
+            chunk: String::with_capacity(size),
+            pending: VecDeque::new(),
+            written: 0,
+            error: None,
+        }
+    }
+
+    // writes as much of the pending chunks as the writer takes without
+    // waiting; it will be polled again by `finish` if it isn't ready
+    fn write_ready(&mut self) -> io::Result<()> {
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+        while let Some(chunk) = self.pending.front() {
+            let unwritten = &chunk.as_bytes()[self.written..];
+            match Pin::new(&mut *self.writer).poll_write(&mut cx, unwritten) {
+                Poll::Ready(Ok(0)) => {
+                    return Err(io::ErrorKind::WriteZero.into())
+                }
+                Poll::Ready(Ok(n)) => {
+                    self.written += n;
+                    if self.written == chunk.len() {
+                        self.pending.pop_front();
+                        self.written = 0;
+                        if let Poll::Ready(Err(e)) =
+                            Pin::new(&mut *self.writer).poll_flush(&mut cx)
+                        {
+                            return Err(e);
+                        }
+                    }
+                }
+                Poll::Ready(Err(e)) => return Err(e),
+                Poll::Pending => break,
+            }
+        }
+        Ok(())
+    }
+
+    // writes whatever is left once the view has been rendered
+    async fn finish(mut self, rendered: fmt::Result) -> io::Result<()> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+        if rendered.is_err() {
+            return Err(io::Error::other("the view could not be rendered"));
+        }
+        if !self.chunk.is_empty() {
+            self.pending.push_back(std::mem::take(&mut self.chunk));
+        }
+        while let Some(chunk) = self.pending.pop_front() {
+            self.writer
+                .write_all(&chunk.as_bytes()[self.written..])
+                .await?;
+            self.written = 0;
+        }
+        self.writer.flush().await
+    }
+}
+
+impl<W> fmt::Write for ChunkedWriter<'_, W>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.error.is_some() {
+            return Err(fmt::Error);
+        }
+        self.chunk.push_str(s);
+        if self.chunk.len() >= self.size {
+            let chunk = std::mem::replace(
+                &mut self.chunk,
+                String::with_capacity(self.size),
+            );
+            self.pending.push_back(chunk);
+            if let Err(e) = self.write_ready() {
+                self.error = Some(e);
+                return Err(fmt::Error);
+            }
+        }
+        Ok(())
+    }
+}
+
+// The script that sets up the browser to receive streamed resources.
+fn resolvers(pending_resources: &str, config: &str) -> String {
+    format!(
+        "<script>__LEPTOS_PENDING_RESOURCES = \
+         {pending_resources};__LEPTOS_RESOLVED_RESOURCES = new \
+         Map();__LEPTOS_RESOURCE_RESOLVERS = new \
+         Map();__LEPTOS_RESOURCE_CHUNKS = {{}};{config}</script>"
+    )
+}
+
+// Sends the config from `provide_config` to the browser, as a JSON string literal so that
+// it can't close the `<script>` it's in.
+pub(crate) fn serialized_config(cx: Scope) -> String {
+    cx.serialized_config()
+        .and_then(|config| serde_json::to_string(&config).ok())
+        .map(|config| {
+            format!("__LEPTOS_CONFIG = {};", config.replace('<', "\\u003c"))
+        })
+        .unwrap_or_default()
+}
+
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     instrument(level = "trace", skip_all,)
@@ -325,18 +603,30 @@ impl View {
         instrument(level = "info", skip_all,)
     )]
     pub fn render_to_string(self, _cx: Scope) -> Cow<'static, str> {
-        #[cfg(all(feature = "web", feature = "ssr"))]
-        crate::console_error(
-            "\n[DANGER] You have both `csr` and `ssr` or `hydrate` and `ssr` \
-             enabled as features, which may cause issues like <Suspense/>` \
-             failing to work silently. `csr` is enabled by default on \
-             `leptos`, and can be disabled by adding `default-features = \
-             false` to your `leptos` dependency.\n",
-        );
+        warn_if_web_and_ssr();
 
         self.render_to_string_helper(false)
     }
 
+    /// Consumes the node and writes its HTML into `w`.
+    ///
+    /// Unlike [`render_to_string`](View::render_to_string), this doesn’t
+    /// build up the HTML for each of the node’s children in a string of its
+    /// own, so the HTML for the whole view never has to be held in memory
+    /// at once if `w` sends it on as it is written.
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr"),
+        instrument(level = "info", skip_all,)
+    )]
+    pub fn render_to_writer<W>(self, _cx: Scope, w: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        warn_if_web_and_ssr();
+
+        self.write_html(w, false)
+    }
+
     #[cfg_attr(
         any(debug_assertions, feature = "ssr"),
         instrument(level = "trace", skip_all,)
@@ -345,263 +635,257 @@ impl View {
         self,
         dont_escape_text: bool,
     ) -> Cow<'static, str> {
+        match self {
+            // text that doesn't need escaping doesn't need to be copied
+            View::Text(node) if dont_escape_text => node.content,
+            view => {
+                let mut html = String::new();
+                // writing to a `String` can't fail
+                _ = view.write_html(&mut html, dont_escape_text);
+                html.into()
+            }
+        }
+    }
+
+    fn write_html<W>(self, w: &mut W, dont_escape_text: bool) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
         match self {
             View::Text(node) => {
                 if dont_escape_text {
-                    node.content
+                    w.write_str(&node.content)
                 } else {
-                    html_escape::encode_safe(&node.content).to_string().into()
+                    w.write_str(&html_escape::encode_safe(&node.content))
                 }
             }
             View::Component(node) => {
-                let content = || {
-                    node.children
-                        .into_iter()
-                        .map(|node| {
-                            node.render_to_string_helper(dont_escape_text)
-                        })
-                        .join("")
-                };
                 cfg_if! {
                   if #[cfg(debug_assertions)] {
-                    let content = format!(r#"<!--hk={}|leptos-{name}-start-->{}<!--hk={}|leptos-{name}-end-->"#,
-                      HydrationCtx::to_string(&node.id, false),
-                      content(),
-                      HydrationCtx::to_string(&node.id, true),
-                      name = to_kebab_case(&node.name)
-                    );
-                    if let Some(id) = node.view_marker {
-                        format!("<!--leptos-view|{id}|open-->{content}<!--leptos-view|{id}|close-->").into()
-                    } else {
-                        content.into()
+                    let name = to_kebab_case(&node.name);
+                    if let Some(id) = &node.view_marker {
+                        write!(w, "<!--leptos-view|{id}|open-->")?;
                     }
                   } else {
-                    format!(
-                      r#"{}<!--hk={}-->"#,
-                      content(),
-                      HydrationCtx::to_string(&node.id, true)
-                    ).into()
+                    let name = "";
                   }
                 }
+                write_open_marker(w, &node.id, &name)?;
+                for child in node.children {
+                    child.write_html(w, dont_escape_text)?;
+                }
+                write_close_marker(w, &node.id, &name)?;
+                #[cfg(debug_assertions)]
+                if let Some(id) = &node.view_marker {
+                    write!(w, "<!--leptos-view|{id}|close-->")?;
+                }
+                Ok(())
             }
-            View::Suspense(id, node) => format!(
-                "<!--suspense-open-{id}-->{}<!--suspense-close-{id}-->",
-                View::CoreComponent(node)
-                    .render_to_string_helper(dont_escape_text)
-            )
-            .into(),
-            View::CoreComponent(node) => {
-                let (id, name, wrap, content) = match node {
-                    CoreComponent::Unit(u) => (
-                        u.id,
-                        "",
-                        false,
-                        Box::new(move || {
-                            #[cfg(debug_assertions)]
-                            {
-                                format!(
-                                    "<!--hk={}|leptos-unit-->",
-                                    HydrationCtx::to_string(&u.id, true)
-                                )
-                                .into()
-                            }
-
-                            #[cfg(not(debug_assertions))]
-                            format!(
-                                "<!--hk={}-->",
-                                HydrationCtx::to_string(&u.id, true)
-                            )
-                            .into()
-                        })
-                            as Box<dyn FnOnce() -> Cow<'static, str>>,
-                    ),
-                    CoreComponent::DynChild(node) => {
-                        let child = node.child.take();
-                        (
-                            node.id,
-                            "dyn-child",
-                            true,
-                            Box::new(move || {
-                                if let Some(child) = *child {
-                                    // On debug builds, `DynChild` has two marker nodes,
-                                    // so there is no way for the text to be merged with
-                                    // surrounding text when the browser parses the HTML,
-                                    // but in release, `DynChild` only has a trailing marker,
-                                    // and the browser automatically merges the dynamic text
-                                    // into one single node, so we need to artificially make the
-                                    // browser create the dynamic text as it's own text node
-                                    if let View::Text(t) = child {
-                                        if !cfg!(debug_assertions) {
-                                            format!("<!>{}", t.content).into()
-                                        } else {
-                                            t.content
-                                        }
-                                    } else {
-                                        child.render_to_string_helper(
-                                            dont_escape_text,
-                                        )
-                                    }
-                                } else {
-                                    "".into()
-                                }
-                            })
-                                as Box<dyn FnOnce() -> Cow<'static, str>>,
-                        )
-                    }
-                    CoreComponent::Each(node) => {
-                        let children = node.children.take();
-                        (
-                            node.id,
-                            "each",
-                            true,
-                            Box::new(move || {
-                                children
-                                    .into_iter()
-                                    .flatten()
-                                    .map(|node| {
-                                        let id = node.id;
-
-                                        let content = || {
-                                            node.child.render_to_string_helper(
-                                                dont_escape_text,
-                                            )
-                                        };
-
-                                        #[cfg(debug_assertions)]
-                                        {
-                                            format!(
-                        "<!--hk={}|leptos-each-item-start-->{}<!\
-                         --hk={}|leptos-each-item-end-->",
-                        HydrationCtx::to_string(&id, false),
-                        content(),
-                        HydrationCtx::to_string(&id, true),
-                      )
-                                        }
-
-                                        #[cfg(not(debug_assertions))]
-                                        format!(
-                                            "{}<!--hk={}-->",
-                                            content(),
-                                            HydrationCtx::to_string(&id, true)
-                                        )
-                                    })
-                                    .join("")
-                                    .into()
-                            })
-                                as Box<dyn FnOnce() -> Cow<'static, str>>,
-                        )
+            View::Suspense(id, node) => {
+                write!(w, "<!--suspense-open-{id}-->")?;
+                View::CoreComponent(node).write_html(w, dont_escape_text)?;
+                write!(w, "<!--suspense-close-{id}-->")
+            }
+            View::CoreComponent(CoreComponent::Unit(u)) => {
+                cfg_if! {
+                  if #[cfg(debug_assertions)] {
+                    write!(
+                        w,
+                        "<!--hk={}|leptos-unit-->",
+                        HydrationCtx::to_string(&u.id, true)
+                    )
+                  } else {
+                    write!(
+                        w,
+                        "<!--hk={}-->",
+                        HydrationCtx::to_string(&u.id, true)
+                    )
+                  }
+                }
+            }
+            View::CoreComponent(CoreComponent::DynChild(node)) => {
+                let child = node.child.take();
+                write_open_marker(w, &node.id, "dyn-child")?;
+                if let Some(child) = *child {
+                    // On debug builds, `DynChild` has two marker nodes,
+                    // so there is no way for the text to be merged with
+                    // surrounding text when the browser parses the HTML,
+                    // but in release, `DynChild` only has a trailing marker,
+                    // and the browser automatically merges the dynamic text
+                    // into one single node, so we need to artificially make the
+                    // browser create the dynamic text as it's own text node
+                    if let View::Text(t) = child {
+                        if !cfg!(debug_assertions) {
+                            w.write_str("<!>")?;
+                        }
+                        w.write_str(&t.content)?;
+                    } else {
+                        child.write_html(w, dont_escape_text)?;
                     }
-                };
-
-                if wrap {
+                }
+                write_close_marker(w, &node.id, "dyn-child")
+            }
+            View::CoreComponent(CoreComponent::Each(node)) => {
+                let children = node.children.take();
+                write_open_marker(w, &node.id, "each")?;
+                for node in children.into_iter().flatten() {
+                    #[cfg(debug_assertions)]
+                    write!(
+                        w,
+                        "<!--hk={}|leptos-each-item-start-->",
+                        HydrationCtx::to_string(&node.id, false)
+                    )?;
+                    let id = node.id;
+                    node.child.write_html(w, dont_escape_text)?;
                     cfg_if! {
                       if #[cfg(debug_assertions)] {
-                        format!(
-                          r#"<!--hk={}|leptos-{name}-start-->{}<!--hk={}|leptos-{name}-end-->"#,
-                          HydrationCtx::to_string(&id, false),
-                          content(),
-                          HydrationCtx::to_string(&id, true),
-                        ).into()
+                        write!(
+                            w,
+                            "<!--hk={}|leptos-each-item-end-->",
+                            HydrationCtx::to_string(&id, true)
+                        )?;
                       } else {
-                        let _ = name;
-
-                        format!(
-                          r#"{}<!--hk={}-->"#,
-                          content(),
-                          HydrationCtx::to_string(&id, true)
-                        ).into()
+                        write!(
+                            w,
+                            "<!--hk={}-->",
+                            HydrationCtx::to_string(&id, true)
+                        )?;
                       }
                     }
-                } else {
-                    content()
                 }
+                write_close_marker(w, &node.id, "each")
             }
             View::Element(el) => {
+                #[cfg(debug_assertions)]
+                if let Some(id) = &el.view_marker {
+                    write!(w, "<!--leptos-view|{id}|open-->")?;
+                }
                 let is_script_or_style =
                     el.name == "script" || el.name == "style";
-                let el_html = if let ElementChildren::Chunks(chunks) =
-                    el.children
-                {
-                    chunks
-                        .into_iter()
-                        .map(|chunk| match chunk {
-                            StringOrView::String(string) => string,
-                            StringOrView::View(view) => view()
-                                .render_to_string_helper(is_script_or_style),
-                        })
-                        .join("")
-                        .into()
+                if let ElementChildren::Chunks(chunks) = el.children {
+                    for chunk in chunks {
+                        match chunk {
+                            StringOrView::String(string) => {
+                                w.write_str(&string)?
+                            }
+                            StringOrView::View(view) => {
+                                view().write_html(w, is_script_or_style)?
+                            }
+                        }
+                    }
                 } else {
                     let tag_name = el.name;
 
                     let mut inner_html = None;
 
-                    let attrs = el
-                        .attrs
-                        .into_iter()
-                        .filter_map(
-                            |(name, value)| -> Option<Cow<'static, str>> {
-                                if value.is_empty() {
-                                    Some(format!(" {name}").into())
-                                } else if name == "inner_html" {
-                                    inner_html = Some(value);
-                                    None
-                                } else {
-                                    Some(
-                                        format!(
-                    " {name}=\"{}\"",
-                    html_escape::encode_double_quoted_attribute(&value)
-                  )
-                                        .into(),
-                                    )
-                                }
-                            },
-                        )
-                        .join("");
+                    write!(w, "<{tag_name}")?;
+                    for (name, value) in el.attrs {
+                        if name == "inner_html" {
+                            inner_html = Some(crate::sanitize_html(value));
+                        } else if name == "dangerously_set_inner_html" {
+                            inner_html = Some(value);
+                        } else if value.is_empty() {
+                            write!(w, " {name}")?;
+                        } else {
+                            write!(
+                                w,
+                                " {name}=\"{}\"",
+                                html_escape::encode_double_quoted_attribute(
+                                    &value
+                                )
+                            )?;
+                        }
+                    }
 
                     if el.is_void {
-                        format!("<{tag_name}{attrs}/>").into()
-                    } else if let Some(inner_html) = inner_html {
-                        format!("<{tag_name}{attrs}>{inner_html}</{tag_name}>")
-                            .into()
+                        w.write_str("/>")?;
                     } else {
-                        let children = match el.children {
-                            ElementChildren::Empty => "".into(),
-                            ElementChildren::Children(c) => c
-                                .into_iter()
-                                .map(|v| {
-                                    v.render_to_string_helper(
-                                        is_script_or_style,
-                                    )
-                                })
-                                .join("")
-                                .into(),
-                            ElementChildren::InnerHtml(h) => h,
-                            // already handled this case above
-                            ElementChildren::Chunks(_) => unreachable!(),
-                        };
-
-                        format!("<{tag_name}{attrs}>{children}</{tag_name}>")
-                            .into()
-                    }
-                };
-                cfg_if! {
-                    if #[cfg(debug_assertions)] {
-                        if let Some(id) = el.view_marker {
-                            format!("<!--leptos-view|{id}|open-->{el_html}<!--leptos-view|{id}|close-->").into()
+                        w.write_char('>')?;
+                        if let Some(inner_html) = inner_html {
+                            w.write_str(&inner_html)?;
                         } else {
-                            el_html
+                            match el.children {
+                                ElementChildren::Empty => {}
+                                ElementChildren::Children(c) => {
+                                    for v in c {
+                                        v.write_html(w, is_script_or_style)?;
+                                    }
+                                }
+                                ElementChildren::InnerHtml(h) => {
+                                    w.write_str(&h)?
+                                }
+                                // already handled this case above
+                                ElementChildren::Chunks(_) => unreachable!(),
+                            }
                         }
-                    } else {
-                        el_html
+                        write!(w, "</{tag_name}>")?;
                     }
                 }
+                #[cfg(debug_assertions)]
+                if let Some(id) = &el.view_marker {
+                    write!(w, "<!--leptos-view|{id}|close-->")?;
+                }
+                Ok(())
             }
-            View::Transparent(_) => Default::default(),
+            View::Transparent(_) => Ok(()),
         }
     }
 }
 
+fn warn_if_web_and_ssr() {
+    #[cfg(all(feature = "web", feature = "ssr"))]
+    crate::console_error(
+        "\n[DANGER] You have both `csr` and `ssr` or `hydrate` and `ssr` \
+         enabled as features, which may cause issues like <Suspense/>` \
+         failing to work silently. `csr` is enabled by default on \
+         `leptos`, and can be disabled by adding `default-features = \
+         false` to your `leptos` dependency.\n",
+    );
+}
+
+// Writes the marker before the HTML of a component, which is only rendered in
+// debug builds.
+fn write_open_marker<W>(w: &mut W, id: &HydrationKey, name: &str) -> fmt::Result
+where
+    W: fmt::Write + ?Sized,
+{
+    cfg_if! {
+      if #[cfg(debug_assertions)] {
+        write!(
+            w,
+            "<!--hk={}|leptos-{name}-start-->",
+            HydrationCtx::to_string(id, false)
+        )
+      } else {
+        _ = (w, id, name);
+        Ok(())
+      }
+    }
+}
+
+// Writes the marker after the HTML of a component.
+fn write_close_marker<W>(
+    w: &mut W,
+    id: &HydrationKey,
+    name: &str,
+) -> fmt::Result
+where
+    W: fmt::Write + ?Sized,
+{
+    cfg_if! {
+      if #[cfg(debug_assertions)] {
+        write!(
+            w,
+            "<!--hk={}|leptos-{name}-end-->",
+            HydrationCtx::to_string(id, true)
+        )
+      } else {
+        _ = name;
+        write!(w, "<!--hk={}-->", HydrationCtx::to_string(id, true))
+      }
+    }
+}
+
 #[cfg(debug_assertions)]
 pub(crate) fn to_kebab_case(name: &str) -> String {
     if name.is_empty() {
@@ -637,6 +921,12 @@ pub(crate) fn to_kebab_case(name: &str) -> String {
 
     new_name
 }
+
+// Resources that serialize to more than this many bytes are sent in several
+// `<script>` tags, so that the browser can go on parsing the rest of the page
+// between them instead of waiting for one very large script.
+const RESOURCE_CHUNK_SIZE: usize = 64 * 1024;
+
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     instrument(level = "trace", skip_all,)
@@ -644,22 +934,63 @@ pub(crate) fn to_kebab_case(name: &str) -> String {
 pub(crate) fn render_serializers(
     serializers: FuturesUnordered<PinnedFuture<(ResourceId, String)>>,
 ) -> impl Stream<Item = String> {
-    serializers.map(|(id, json)| {
+    serializers.flat_map(|(id, json)| {
         let id = serde_json::to_string(&id).unwrap();
         let json = json.replace('<', "\\u003c");
-        format!(
+        let mut chunks = split_into_chunks(&json, RESOURCE_CHUNK_SIZE);
+        let last = chunks.pop().unwrap_or_default();
+
+        // every chunk but the last is stored until the whole value has arrived
+        let mut scripts = chunks
+            .into_iter()
+            .map(|chunk| {
+                format!(
+                    r#"<script>
+                  (__LEPTOS_RESOURCE_CHUNKS[{id:?}] = __LEPTOS_RESOURCE_CHUNKS[{id:?}] || []).push({chunk:?});
+              </script>"#
+                )
+            })
+            .collect::<Vec<_>>();
+        let val = if scripts.is_empty() {
+            format!("{last:?}")
+        } else {
+            format!(
+                r#"__LEPTOS_RESOURCE_CHUNKS[{id:?}].join("") + {last:?};
+                  delete __LEPTOS_RESOURCE_CHUNKS[{id:?}]"#
+            )
+        };
+        scripts.push(format!(
             r#"<script>
-                  var val = {json:?};
+                  var val = {val};
                   if(__LEPTOS_RESOURCE_RESOLVERS.get({id})) {{
                       __LEPTOS_RESOURCE_RESOLVERS.get({id})(val)
                   }} else {{
                       __LEPTOS_RESOLVED_RESOURCES.set({id}, val);
                   }}
               </script>"#,
-        )
+        ));
+        futures::stream::iter(scripts)
     })
 }
 
+// Splits the string into pieces of at most `size` bytes, without splitting any
+// characters.
+fn split_into_chunks(s: &str, size: usize) -> Vec<&str> {
+    let mut chunks = Vec::with_capacity(s.len() / size + 1);
+    let mut rest = s;
+    while rest.len() > size {
+        let mut end = size;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    chunks.push(rest);
+    chunks
+}
+
 #[doc(hidden)]
 pub fn escape_attr<T>(value: &T) -> Cow<'_, str>
 where
@@ -667,3 +998,137 @@ where
 {
     html_escape::encode_double_quoted_attribute(value)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    #[test]
+    fn large_resources_are_serialized_in_chunks() {
+        let json = format!("\"{}é\"", "a".repeat(RESOURCE_CHUNK_SIZE * 2));
+        let serializers = FuturesUnordered::new();
+        serializers.push(Box::pin(futures::future::ready((
+            ResourceId::default(),
+            json.clone(),
+        ))) as PinnedFuture<_>);
+
+        let scripts = futures::executor::block_on(
+            render_serializers(serializers).collect::<Vec<_>>(),
+        );
+
+        assert_eq!(scripts.len(), 3);
+        assert!(scripts[..2]
+            .iter()
+            .all(|script| script.contains("__LEPTOS_RESOURCE_CHUNKS")));
+        assert!(scripts[2].contains("__LEPTOS_RESOURCE_RESOLVERS"));
+        assert!(scripts[2].contains("é"));
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn config_cannot_close_script() {
+        use leptos_reactive::{create_runtime, provide_config, run_scope};
+
+        run_scope(create_runtime(), |cx| {
+            assert_eq!(serialized_config(cx), "");
+            provide_config(cx, "</script>".to_string());
+            assert_eq!(
+                serialized_config(cx),
+                r#"__LEPTOS_CONFIG = "\"\u003c/script>\"";"#
+            );
+        });
+    }
+
+    // records each write, and when the view renders the marked chunk
+    struct RecordingWriter(Rc<RefCell<Vec<String>>>);
+
+    impl AsyncWrite for RecordingWriter {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            let html = String::from_utf8_lossy(buf).into_owned();
+            self.0.borrow_mut().push(html);
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(
+            self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+        ) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(
+            self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+        ) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[test]
+    fn async_writer_writes_shell_while_rendering() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut writer = RecordingWriter(Rc::clone(&log));
+        futures::executor::block_on(render_to_async_writer(
+            {
+                let log = Rc::clone(&log);
+                move |cx| {
+                    let element = crate::html::div(cx).element;
+                    let middle = move || {
+                        log.borrow_mut().push("rendering".to_string());
+                        "Hello".into_view(cx)
+                    };
+                    crate::HtmlElement::from_chunks(
+                        cx,
+                        element,
+                        [
+                            StringOrView::String("<div>".into()),
+                            StringOrView::View(Rc::new(middle)),
+                            StringOrView::String("</div>".into()),
+                        ],
+                    )
+                    .into_view(cx)
+                }
+            },
+            |_| "<!DOCTYPE html>".into(),
+            &mut writer,
+            4,
+        ))
+        .unwrap();
+
+        let log = log.borrow();
+        let position = |html: &str| log.iter().position(|w| w == html);
+        assert_eq!(log[0], "<!DOCTYPE html>");
+        // the start of the shell was written before the rest was rendered
+        assert!(position("<div>") < position("rendering"));
+        assert!(position("rendering") < position("Hello"));
+    }
+
+    #[test]
+    fn async_writer_writes_whole_page() {
+        let mut html = Vec::new();
+        futures::executor::block_on(render_to_async_writer(
+            |cx| crate::html::p(cx).child("Hello, world!").into_view(cx),
+            |_| "<!DOCTYPE html>".into(),
+            &mut html,
+            8,
+        ))
+        .unwrap();
+
+        let html = String::from_utf8(html).unwrap();
+        assert!(html.starts_with("<!DOCTYPE html><p"));
+        assert!(html.contains(">Hello, world!</p><script>"));
+        assert!(html.ends_with("</script>"));
+    }
+
+    #[test]
+    fn chunks_do_not_split_characters() {
+        let chunks = split_into_chunks("aé", 2);
+        assert_eq!(chunks, ["a", "é"]);
+        assert_eq!(split_into_chunks("", 2), [""]);
+    }
+}
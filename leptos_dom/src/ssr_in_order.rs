@@ -4,7 +4,7 @@
 
 use crate::{
     html::{ElementChildren, StringOrView},
-    ssr::render_serializers,
+    ssr::{render_serializers, serialized_config},
     CoreComponent, HydrationCtx, View,
 };
 use async_recursion::async_recursion;
@@ -99,6 +99,7 @@ pub fn render_to_stream_in_order_with_prefix_undisposed_with_context(
             chunks,
             prefix,
             pending_resources,
+            config,
             serializers,
         ),
         scope_id,
@@ -115,6 +116,7 @@ pub fn render_to_stream_in_order_with_prefix_undisposed_with_context(
             view.into_stream_chunks(cx),
             prefix,
             serde_json::to_string(&cx.pending_resources()).unwrap(),
+            serialized_config(cx),
             cx.serialization_resolvers(),
         )
     });
@@ -142,6 +144,8 @@ pub fn render_to_stream_in_order_with_prefix_undisposed_with_context(
             __LEPTOS_PENDING_RESOURCES = {pending_resources};
             __LEPTOS_RESOLVED_RESOURCES = new Map();
             __LEPTOS_RESOURCE_RESOLVERS = new Map();
+            __LEPTOS_RESOURCE_CHUNKS = {{}};
+            {config}
         </script>
       "#
         )
@@ -306,11 +310,15 @@ impl View {
                         .into_iter()
                         .filter_map(
                             |(name, value)| -> Option<Cow<'static, str>> {
-                                if value.is_empty() {
-                                    Some(format!(" {name}").into())
-                                } else if name == "inner_html" {
+                                if name == "inner_html" {
+                                    inner_html =
+                                        Some(crate::sanitize_html(value));
+                                    None
+                                } else if name == "dangerously_set_inner_html" {
                                     inner_html = Some(value);
                                     None
+                                } else if value.is_empty() {
+                                    Some(format!(" {name}").into())
                                 } else {
                                     Some(
                                         format!(
@@ -11,12 +11,80 @@ use std::borrow::Cow;
 #[cfg(all(target_arch = "wasm32", feature = "web"))]
 use wasm_bindgen::JsCast;
 
+/// Restores the case of SVG attributes that were written in lowercase, like
+/// `viewbox`, which would otherwise be set as a different attribute. This is
+/// the same adjustment the HTML parser makes for SVG in an HTML document.
+fn adjust_attribute_name(name: Cow<'static, str>) -> Cow<'static, str> {
+    let adjusted = match name.as_ref() {
+        "attributename" => "attributeName",
+        "attributetype" => "attributeType",
+        "basefrequency" => "baseFrequency",
+        "baseprofile" => "baseProfile",
+        "calcmode" => "calcMode",
+        "clippathunits" => "clipPathUnits",
+        "diffuseconstant" => "diffuseConstant",
+        "edgemode" => "edgeMode",
+        "filterunits" => "filterUnits",
+        "glyphref" => "glyphRef",
+        "gradienttransform" => "gradientTransform",
+        "gradientunits" => "gradientUnits",
+        "kernelmatrix" => "kernelMatrix",
+        "kernelunitlength" => "kernelUnitLength",
+        "keypoints" => "keyPoints",
+        "keysplines" => "keySplines",
+        "keytimes" => "keyTimes",
+        "lengthadjust" => "lengthAdjust",
+        "limitingconeangle" => "limitingConeAngle",
+        "markerheight" => "markerHeight",
+        "markerunits" => "markerUnits",
+        "markerwidth" => "markerWidth",
+        "maskcontentunits" => "maskContentUnits",
+        "maskunits" => "maskUnits",
+        "numoctaves" => "numOctaves",
+        "pathlength" => "pathLength",
+        "patterncontentunits" => "patternContentUnits",
+        "patterntransform" => "patternTransform",
+        "patternunits" => "patternUnits",
+        "pointsatx" => "pointsAtX",
+        "pointsaty" => "pointsAtY",
+        "pointsatz" => "pointsAtZ",
+        "preservealpha" => "preserveAlpha",
+        "preserveaspectratio" => "preserveAspectRatio",
+        "primitiveunits" => "primitiveUnits",
+        "refx" => "refX",
+        "refy" => "refY",
+        "repeatcount" => "repeatCount",
+        "repeatdur" => "repeatDur",
+        "requiredextensions" => "requiredExtensions",
+        "requiredfeatures" => "requiredFeatures",
+        "specularconstant" => "specularConstant",
+        "specularexponent" => "specularExponent",
+        "spreadmethod" => "spreadMethod",
+        "startoffset" => "startOffset",
+        "stddeviation" => "stdDeviation",
+        "stitchtiles" => "stitchTiles",
+        "surfacescale" => "surfaceScale",
+        "systemlanguage" => "systemLanguage",
+        "tablevalues" => "tableValues",
+        "targetx" => "targetX",
+        "targety" => "targetY",
+        "textlength" => "textLength",
+        "viewbox" => "viewBox",
+        "viewtarget" => "viewTarget",
+        "xchannelselector" => "xChannelSelector",
+        "ychannelselector" => "yChannelSelector",
+        "zoomandpan" => "zoomAndPan",
+        _ => return name,
+    };
+    adjusted.into()
+}
+
 macro_rules! generate_svg_tags {
   (
     $(
       #[$meta:meta]
       $(#[$void:ident])?
-      $tag:ident $(- $second:ident $(- $third:ident)?)? $(@ $trailing_:pat)?
+      $tag:ident $(- $second:ident $(- $third:ident)?)? $([$trailing_:pat])? $el_type:ident
     ),* $(,)?
   ) => {
     paste::paste! {
@@ -122,13 +190,12 @@ macro_rules! generate_svg_tags {
         }
 
         impl std::ops::Deref for [<$tag:camel $($second:camel $($third:camel)?)?>] {
-          type Target = web_sys::SvgElement;
+          type Target = web_sys::$el_type;
 
           fn deref(&self) -> &Self::Target {
             #[cfg(all(target_arch = "wasm32", feature = "web"))]
             {
-              use wasm_bindgen::JsCast;
-              return &self.element.unchecked_ref();
+              return self.element.unchecked_ref();
             }
 
             #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
@@ -151,6 +218,10 @@ macro_rules! generate_svg_tags {
             stringify!($tag).into()
           }
 
+          fn attribute_name(&self, name: Cow<'static, str>) -> Cow<'static, str> {
+            adjust_attribute_name(name)
+          }
+
           #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
           fn hydration_id(&self) -> &HydrationKey {
             &self.id
@@ -177,135 +248,135 @@ macro_rules! generate_svg_tags {
 
 generate_svg_tags![
   /// SVG Element.
-  a,
+  a SvgaElement,
   /// SVG Element.
-  animate,
+  animate SvgAnimateElement,
   /// SVG Element.
-  animateMotion,
+  animateMotion SvgAnimateMotionElement,
   /// SVG Element.
-  animateTransform,
+  animateTransform SvgAnimateTransformElement,
   /// SVG Element.
-  circle,
+  circle SvgCircleElement,
   /// SVG Element.
-  clipPath,
+  clipPath SvgClipPathElement,
   /// SVG Element.
-  defs,
+  defs SvgDefsElement,
   /// SVG Element.
-  desc,
+  desc SvgDescElement,
   /// SVG Element.
-  discard,
+  discard SvgElement,
   /// SVG Element.
-  ellipse,
+  ellipse SvgEllipseElement,
   /// SVG Element.
-  feBlend,
+  feBlend SvgfeBlendElement,
   /// SVG Element.
-  feColorMatrix,
+  feColorMatrix SvgfeColorMatrixElement,
   /// SVG Element.
-  feComponentTransfer,
+  feComponentTransfer SvgfeComponentTransferElement,
   /// SVG Element.
-  feComposite,
+  feComposite SvgfeCompositeElement,
   /// SVG Element.
-  feConvolveMatrix,
+  feConvolveMatrix SvgfeConvolveMatrixElement,
   /// SVG Element.
-  feDiffuseLighting,
+  feDiffuseLighting SvgfeDiffuseLightingElement,
   /// SVG Element.
-  feDisplacementMap,
+  feDisplacementMap SvgfeDisplacementMapElement,
   /// SVG Element.
-  feDistantLight,
+  feDistantLight SvgfeDistantLightElement,
   /// SVG Element.
-  feDropShadow,
+  feDropShadow SvgfeDropShadowElement,
   /// SVG Element.
-  feFlood,
+  feFlood SvgfeFloodElement,
   /// SVG Element.
-  feFuncA,
+  feFuncA SvgfeFuncAElement,
   /// SVG Element.
-  feFuncB,
+  feFuncB SvgfeFuncBElement,
   /// SVG Element.
-  feFuncG,
+  feFuncG SvgfeFuncGElement,
   /// SVG Element.
-  feFuncR,
+  feFuncR SvgfeFuncRElement,
   /// SVG Element.
-  feGaussianBlur,
+  feGaussianBlur SvgfeGaussianBlurElement,
   /// SVG Element.
-  feImage,
+  feImage SvgfeImageElement,
   /// SVG Element.
-  feMerge,
+  feMerge SvgfeMergeElement,
   /// SVG Element.
-  feMergeNode,
+  feMergeNode SvgfeMergeNodeElement,
   /// SVG Element.
-  feMorphology,
+  feMorphology SvgfeMorphologyElement,
   /// SVG Element.
-  feOffset,
+  feOffset SvgfeOffsetElement,
   /// SVG Element.
-  fePointLight,
+  fePointLight SvgfePointLightElement,
   /// SVG Element.
-  feSpecularLighting,
+  feSpecularLighting SvgfeSpecularLightingElement,
   /// SVG Element.
-  feSpotLight,
+  feSpotLight SvgfeSpotLightElement,
   /// SVG Element.
-  feTile,
+  feTile SvgfeTileElement,
   /// SVG Element.
-  feTurbulence,
+  feTurbulence SvgfeTurbulenceElement,
   /// SVG Element.
-  filter,
+  filter SvgFilterElement,
   /// SVG Element.
-  foreignObject,
+  foreignObject SvgForeignObjectElement,
   /// SVG Element.
-  g,
+  g SvggElement,
   /// SVG Element.
-  hatch,
+  hatch SvgElement,
   /// SVG Element.
-  hatchpath,
+  hatchpath SvgElement,
   /// SVG Element.
-  image,
+  image SvgImageElement,
   /// SVG Element.
-  line,
+  line SvgLineElement,
   /// SVG Element.
-  linearGradient,
+  linearGradient SvgLinearGradientElement,
   /// SVG Element.
-  marker,
+  marker SvgMarkerElement,
   /// SVG Element.
-  mask,
+  mask SvgMaskElement,
   /// SVG Element.
-  metadata,
+  metadata SvgMetadataElement,
   /// SVG Element.
-  mpath,
+  mpath SvgmPathElement,
   /// SVG Element.
-  path,
+  path SvgPathElement,
   /// SVG Element.
-  pattern,
+  pattern SvgPatternElement,
   /// SVG Element.
-  polygon,
+  polygon SvgPolygonElement,
   /// SVG Element.
-  polyline,
+  polyline SvgPolylineElement,
   /// SVG Element.
-  radialGradient,
+  radialGradient SvgRadialGradientElement,
   /// SVG Element.
-  rect,
+  rect SvgRectElement,
   /// SVG Element.
-  script,
+  script SvgScriptElement,
   /// SVG Element.
-  set,
+  set SvgSetElement,
   /// SVG Element.
-  stop,
+  stop SvgStopElement,
   /// SVG Element.
-  style,
+  style SvgStyleElement,
   /// SVG Element.
-  svg,
+  svg SvgsvgElement,
   /// SVG Element.
-  switch,
+  switch SvgSwitchElement,
   /// SVG Element.
-  symbol,
+  symbol SvgSymbolElement,
   /// SVG Element.
-  text,
+  text SvgTextElement,
   /// SVG Element.
-  textPath,
+  textPath SvgTextPathElement,
   /// SVG Element.
-  title,
+  title SvgTitleElement,
   /// SVG Element.
-  tspan,
+  tspan SvgtSpanElement,
   /// SVG Element.
-  use @_,
+  use [_] SvgUseElement,
   /// SVG Element.
-  view,
+  view SvgViewElement,
 ];
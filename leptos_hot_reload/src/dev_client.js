@@ -0,0 +1,78 @@
+(function () {
+	const OVERLAY_ID = "leptos-dev-overlay";
+
+	function showError(error) {
+		let overlay = document.getElementById(OVERLAY_ID);
+		if (!overlay) {
+			overlay = document.createElement("div");
+			overlay.id = OVERLAY_ID;
+			overlay.style.cssText =
+				"position:fixed;inset:0;z-index:2147483647;overflow:auto;padding:2rem;" +
+				"background:rgba(20,20,20,0.92);color:#f4f4f4;font:14px/1.5 monospace;";
+			overlay.addEventListener("click", (ev) => {
+				if (ev.target === overlay) clearError();
+			});
+			document.body.appendChild(overlay);
+		}
+		overlay.textContent = "";
+		const title = document.createElement("h2");
+		title.style.cssText = "color:#ff6b6b;margin:0 0 1rem;font-size:1.2rem;";
+		title.textContent = "Compile error";
+		overlay.appendChild(title);
+		if (error.file) {
+			const location = document.createElement("div");
+			location.style.cssText = "color:#9cdcfe;margin-bottom:1rem;";
+			location.textContent = [error.file, error.line, error.column]
+				.filter((part) => part != null)
+				.join(":");
+			overlay.appendChild(location);
+		}
+		const message = document.createElement("pre");
+		message.style.cssText = "white-space:pre-wrap;margin:0;";
+		message.textContent = error.message;
+		overlay.appendChild(message);
+	}
+
+	function clearError() {
+		const overlay = document.getElementById(OVERLAY_ID);
+		if (overlay) overlay.remove();
+	}
+
+	function reloadCss(path) {
+		let found = false;
+		document.querySelectorAll("link").forEach((link) => {
+			if (link.getAttribute("href").includes(path)) {
+				link.setAttribute("href", "/" + path + "?version=" + new Date().getMilliseconds());
+				found = true;
+			}
+		});
+		if (!found) console.warn(`CSS hot-reload: Could not find a <link href=/"${path}"> element`);
+	}
+
+	function handle(msg) {
+		if (typeof msg === "string") msg = JSON.parse(msg);
+		switch (msg.type) {
+			case "rebuilt":
+				clearError();
+				window.location.reload();
+				break;
+			case "compile_error":
+				showError(msg);
+				break;
+			case "hot_patch":
+				clearError();
+				patch(JSON.stringify(msg.patches));
+				break;
+			case "css":
+				reloadCss(msg.path);
+				break;
+			default:
+				// the messages sent before this protocol existed
+				if (msg.all) window.location.reload();
+				if (msg.css) reloadCss(msg.css);
+				if (msg.view) patch(msg.view);
+		}
+	}
+
+	window.__LEPTOS_DEV__ = { handle, showError, clearError };
+})();
@@ -23,9 +23,15 @@ use walkdir::WalkDir;
 pub mod diff;
 pub mod node;
 pub mod parsing;
+pub mod protocol;
 
 pub const HOT_RELOAD_JS: &str = include_str!("patch.js");
 
+/// Handles the [`DevServerMessage`](protocol::DevServerMessage)s sent by a dev
+/// server: reloading the page, showing compile errors in an overlay, and
+/// applying hot patches. This must be included after [`HOT_RELOAD_JS`].
+pub const DEV_CLIENT_JS: &str = include_str!("dev_client.js");
+
 #[derive(Debug, Clone, Default)]
 pub struct ViewMacros {
     // keyed by original location identifier
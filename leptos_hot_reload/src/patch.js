@@ -14,15 +14,30 @@ function patch(json) {
 					start = walker.currentNode;
 				} else if (walker.currentNode.textContent == close) {
 					end = walker.currentNode;
-                    instances.push([start, end]);
+                    instances.push({ start, end });
                     start = undefined;
                     end = undefined;
 				}
 			}
+            // views with a single element that were rendered in the browser
+            // are marked with an attribute instead
+            document.querySelectorAll("[data-leptos-view]").forEach((element) => {
+                if (element.dataset.leptosView == id) {
+                    instances.push({ element });
+                }
+            });
 
-            for(const [start, end] of instances) {
+            for(const { start, end, element } of instances) {
                 // build tree of current actual children
-                const actualChildren = childrenFromRange(start.parentElement, start, end);
+                const actualChildren = element
+                    ? [{
+                        type: "element",
+                        name: element.nodeName,
+                        number: 0,
+                        node: element,
+                        children: buildActualChildren(element)
+                    }]
+                    : childrenFromRange(start.parentElement, start, end);
                 const actions = [];
 
                 // build up the set of actions
@@ -41,6 +56,10 @@ function patch(json) {
                         actions.push(() => {
                             console.log("[HOT RELOAD] > ReplaceWith", child, action.ReplaceWith);
                             const replacement = fromReplacementNode(action.ReplaceWith, actualChildren);
+                            // keep the view marked, so that it can be patched again
+                            if (element && child.node == element && replacement.nodeType == Node.ELEMENT_NODE) {
+                                replacement.setAttribute("data-leptos-view", id);
+                            }
                             if (child.node) {
                                 child.node.replaceWith(replacement)
                             } else {
@@ -0,0 +1,52 @@
+//! The messages a dev server sends to the browser to drive live reloading.
+//!
+//! The page connects to the dev server over a WebSocket, and runs
+//! [`DEV_CLIENT_JS`](crate::DEV_CLIENT_JS) for each message it receives, which
+//! is a [`DevServerMessage`] serialized as JSON. The same handler is available
+//! in the page as `window.__LEPTOS_DEV__.handle(message)`, so tools that talk to
+//! the page in some other way can drive it too.
+//!
+//! ```json
+//! { "type": "compile_error", "message": "mismatched types", "file": "src/app.rs", "line": 12, "column": 5 }
+//! ```
+
+use crate::diff::Patches;
+use serde::{Deserialize, Serialize};
+
+/// A message from a dev server to the pages it is serving.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DevServerMessage {
+    /// The app was rebuilt, so the page should be reloaded. This also
+    /// dismisses the compile error overlay.
+    Rebuilt,
+    /// The app failed to compile. The page shows the error in an overlay,
+    /// until the next [`Rebuilt`](DevServerMessage::Rebuilt) or
+    /// [`HotPatch`](DevServerMessage::HotPatch) message.
+    CompileError {
+        /// The compiler's error message.
+        message: String,
+        /// The file the error is in, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        file: Option<String>,
+        /// The line the error is on, starting at 1, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        line: Option<u32>,
+        /// The column the error starts at, starting at 1, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        column: Option<u32>,
+    },
+    /// Only the templates of some `view!` macros changed, and the page can be
+    /// patched in place without reloading it. This also dismisses the compile
+    /// error overlay.
+    HotPatch {
+        /// The changes to each `view!` macro.
+        patches: Patches,
+    },
+    /// A stylesheet changed, and should be reloaded without reloading the
+    /// page.
+    Css {
+        /// The path of the stylesheet, as it appears in the `href` of its `<link>`.
+        path: String,
+    },
+}
@@ -15,6 +15,11 @@ use syn::{
 };
 pub struct Model {
     is_transparent: bool,
+    is_memo: bool,
+    budget_ms: Option<f64>,
+    hydrate: Option<Ident>,
+    trace_props: bool,
+    is_island: bool,
     docs: Docs,
     vis: Visibility,
     name: Ident,
@@ -71,17 +76,13 @@ impl Parse for Model {
             }
         });
 
-        // Make sure return type is correct
-        if !is_valid_into_view_return_type(&item.sig.output) {
-            abort!(
-                item.sig,
-                "return type is incorrect";
-                help = "return signature must be `-> impl IntoView`"
-            );
-        }
-
         Ok(Self {
             is_transparent: false,
+            is_memo: false,
+            budget_ms: None,
+            hydrate: None,
+            trace_props: false,
+            is_island: false,
             docs,
             vis: item.vis.clone(),
             name: convert_from_snake_case(&item.sig.ident),
@@ -122,6 +123,11 @@ impl ToTokens for Model {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let Self {
             is_transparent,
+            is_memo,
+            budget_ms,
+            hydrate,
+            trace_props,
+            is_island,
             docs,
             vis,
             name,
@@ -133,6 +139,17 @@ impl ToTokens for Model {
 
         let no_props = props.len() == 1;
 
+        // Make sure return type is correct. A transparent component returns
+        // its value to its parent as it is, so it can be any type that
+        // implements `IntoView`, like `Transparent`.
+        if !is_transparent && !is_valid_into_view_return_type(ret) {
+            abort!(
+                body.sig,
+                "return type is incorrect";
+                help = "return signature must be `-> impl IntoView`"
+            );
+        }
+
         let mut body = body.to_owned();
 
         // check for components that end ;
@@ -200,20 +217,94 @@ impl ToTokens for Model {
                 (quote! {}, quote! {}, quote! {})
             };
 
-        let component = if *is_transparent {
+        let render = |cx: &dyn ToTokens| {
+            let render = match budget_ms {
+                Some(budget_ms) => quote! {
+                    {
+                        #[cfg(debug_assertions)]
+                        {
+                            ::leptos::PerformanceBudget::new(stringify!(#name), #budget_ms)
+                                .measure(move || #body_name(#cx, #prop_names))
+                        }
+                        #[cfg(not(debug_assertions))]
+                        {
+                            #body_name(#cx, #prop_names)
+                        }
+                    }
+                },
+                None => quote! {
+                    #body_name(#cx, #prop_names)
+                },
+            };
+            if !*trace_props {
+                return render;
+            }
+            let traced_props = props
+                .iter()
+                .filter(|Prop { ty, .. }| !is_valid_scope_type(ty))
+                .map(|Prop { name, .. }| &name.ident);
             quote! {
-                #body_name(#scope_name, #prop_names)
+                {
+                    #[cfg(debug_assertions)]
+                    {
+                        use ::leptos::{TraceReactiveProp as _, TraceStaticProp as _};
+                        #(
+                            (&&::leptos::PropTrace::new(
+                                #cx,
+                                stringify!(#name),
+                                stringify!(#traced_props),
+                                &#traced_props,
+                            ))
+                            .trace();
+                        )*
+                    }
+                    #render
+                }
             }
+        };
+
+        let component = if *is_transparent {
+            render(scope_name)
         } else {
-            quote! {
+            let render = render(&quote! { cx });
+            let component = quote! {
                 ::leptos::leptos_dom::Component::new(
                     stringify!(#name),
                     move |cx| {
                         #tracing_guard_expr
 
-                        #body_name(cx, #prop_names)
+                        #render
                     }
                 )
+            };
+            let component = if *is_memo {
+                let memo_props = props
+                    .iter()
+                    .filter(|Prop { ty, .. }| !is_valid_scope_type(ty))
+                    .map(|Prop { name, .. }| &name.ident);
+                quote! {
+                    ::leptos::leptos_dom::MemoComponent::new(
+                        stringify!(#name),
+                        (#(::std::clone::Clone::clone(&#memo_props),)*),
+                        move |cx| ::leptos::IntoView::into_view(#component, cx)
+                    )
+                }
+            } else {
+                component
+            };
+            let component = match hydrate {
+                Some(strategy) => quote! {
+                    ::leptos::leptos_dom::DeferredHydration::new(
+                        ::leptos::leptos_dom::HydrationStrategy::#strategy,
+                        move |cx| ::leptos::IntoView::into_view(#component, cx)
+                    )
+                },
+                None => component,
+            };
+            if *is_island {
+                island(name, props, &component)
+            } else {
+                component
             }
         };
 
@@ -253,6 +344,12 @@ impl ToTokens for Model {
             }
         };
 
+        let island_export = if *is_island {
+            island_export(name, &props_name, props)
+        } else {
+            quote! {}
+        };
+
         let output = quote! {
             #[doc = #builder_name_doc]
             #[doc = ""]
@@ -292,6 +389,8 @@ impl ToTokens for Model {
 
                 #component
             }
+
+            #island_export
         };
 
         tokens.append_all(output)
@@ -305,6 +404,136 @@ impl Model {
 
         self
     }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_memo(mut self, is_memo: bool) -> Self {
+        self.is_memo = is_memo;
+
+        self
+    }
+
+    pub fn budget_ms(mut self, budget_ms: Option<f64>) -> Self {
+        self.budget_ms = budget_ms;
+
+        self
+    }
+
+    pub fn hydrate(mut self, hydrate: Option<Ident>) -> Self {
+        self.hydrate = hydrate;
+
+        self
+    }
+
+    pub fn trace_props(mut self, trace_props: bool) -> Self {
+        self.trace_props = trace_props;
+
+        self
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_island(mut self, is_island: bool) -> Self {
+        if is_island {
+            if !self.body.sig.generics.params.is_empty() {
+                abort!(
+                    self.body.sig.generics,
+                    "an island can't be generic";
+                    help = "its props are deserialized in the browser, so \
+                            their types have to be known"
+                );
+            }
+            if let Some(children) = self
+                .props
+                .iter()
+                .find(|Prop { name, .. }| name.ident == "children")
+            {
+                abort!(
+                    children.name,
+                    "an island can't take `children`";
+                    help = "its props are serialized to be sent to the \
+                            browser, and children can't be"
+                );
+            }
+        }
+        self.is_island = is_island;
+
+        self
+    }
+}
+
+// Renders an island's component inside a `<leptos-island>`, with its props
+// serialized so that the browser can hydrate it on its own.
+fn island(
+    name: &Ident,
+    props: &[Prop],
+    component: &TokenStream,
+) -> TokenStream {
+    let serialized_props = props
+        .iter()
+        .filter(|Prop { ty, .. }| !is_valid_scope_type(ty))
+        .map(|Prop { name: prop, .. }| {
+            let prop = &prop.ident;
+            let error =
+                format!("couldn't serialize the prop `{prop}` of <{name}/>");
+            quote! {
+                (
+                    stringify!(#prop),
+                    ::leptos::Serializable::ser(&#prop).expect(#error),
+                )
+            }
+        });
+    quote! {
+        {
+            #[cfg(not(target_arch = "wasm32"))]
+            let island_props = ::std::vec![#(#serialized_props),*];
+            // only the server needs to send the props
+            #[cfg(target_arch = "wasm32")]
+            let island_props = ::std::vec::Vec::new();
+            ::leptos::leptos_dom::Island::new(
+                stringify!(#name),
+                island_props,
+                move |cx| ::leptos::IntoView::into_view(#component, cx)
+            )
+        }
+    }
+}
+
+// The function the client bootstrap calls to hydrate each `<leptos-island>`
+// rendered by the component.
+fn island_export(
+    name: &Ident,
+    props_name: &Ident,
+    props: &[Prop],
+) -> TokenStream {
+    let export_name = format_ident!("_island_{name}");
+    let props = props
+        .iter()
+        .filter(|Prop { ty, .. }| !is_valid_scope_type(ty))
+        .map(|Prop { name: prop, .. }| &prop.ident)
+        .collect::<Vec<_>>();
+    let (el, render) = if props.is_empty() {
+        (quote! { _ }, quote! { #name(cx) })
+    } else {
+        let render = quote! {
+            #name(cx, #props_name {
+                #(#props: ::leptos::leptos_dom::island_prop(
+                    el,
+                    stringify!(#props),
+                ),)*
+            })
+        };
+        (quote! { el }, render)
+    };
+    quote! {
+        #[cfg(target_arch = "wasm32")]
+        #[::leptos::leptos_dom::wasm_bindgen::prelude::wasm_bindgen(
+            wasm_bindgen = ::leptos::leptos_dom::wasm_bindgen
+        )]
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        pub fn #export_name(el: ::leptos::leptos_dom::web_sys::HtmlElement) {
+            ::leptos::leptos_dom::hydrate_island(el, |cx, #el| #render);
+        }
+    }
 }
 
 struct Prop {
@@ -509,6 +738,13 @@ struct PropOpt {
     into: bool,
 }
 
+impl PropOpt {
+    /// Whether the prop can be left out when the component is used.
+    fn can_be_omitted(&self) -> bool {
+        self.optional || self.optional_no_strip || self.default.is_some()
+    }
+}
+
 struct TypedBuilderOpts {
     default: bool,
     default_with_value: Option<syn::Expr>,
@@ -608,17 +844,13 @@ fn prop_names(props: &[Prop]) -> TokenStream {
 fn generate_component_fn_prop_docs(props: &[Prop]) -> TokenStream {
     let required_prop_docs = props
         .iter()
-        .filter(|Prop { prop_opts, .. }| {
-            !(prop_opts.optional || prop_opts.optional_no_strip)
-        })
+        .filter(|Prop { prop_opts, .. }| !prop_opts.can_be_omitted())
         .map(|p| prop_to_doc(p, PropDocStyle::List))
         .collect::<TokenStream>();
 
     let optional_prop_docs = props
         .iter()
-        .filter(|Prop { prop_opts, .. }| {
-            prop_opts.optional || prop_opts.optional_no_strip
-        })
+        .filter(|Prop { prop_opts, .. }| prop_opts.can_be_omitted())
         .map(|p| prop_to_doc(p, PropDocStyle::List))
         .collect::<TokenStream>();
 
@@ -746,10 +978,21 @@ fn prop_to_doc(
             );
 
             let arg_user_docs = docs.padded();
+            let default_doc = prop_opts.default.as_ref().map(|default| {
+                let default = LitStr::new(
+                    &format!(
+                        "    - Defaults to `{}`.",
+                        default.to_token_stream()
+                    ),
+                    name.ident.span(),
+                );
+                quote! { #[doc = #default] }
+            });
 
             quote! {
                 #[doc = #arg_ty_doc]
                 #arg_user_docs
+                #default_doc
             }
         }
         PropDocStyle::Inline => {
@@ -0,0 +1,501 @@
+//! Native `if`, `match` and `for` in the body of the `view` macro.
+//!
+//! RSX can't contain Rust control flow, so before the body is parsed, each
+//! `if`, `match` or `for` that stands where a node could is replaced with a
+//! block that builds the equivalent reactive view. Their bodies are passed to
+//! nested `view` macros, so they can contain control flow of their own.
+
+use proc_macro2::{
+    Delimiter, Group, Ident, Spacing, Span, TokenStream, TokenTree,
+};
+use quote::quote;
+use syn::{Arm, Expr, Pat};
+
+/// Replaces the control flow among the nodes in `tokens` with blocks.
+pub(crate) fn desugar_control_flow(
+    tokens: TokenStream,
+    cx: &Ident,
+    global_class: Option<&TokenTree>,
+) -> TokenStream {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let mut output = Vec::<TokenTree>::with_capacity(tokens.len());
+    let mut idx = 0;
+    while idx < tokens.len() {
+        let flow = match &tokens[idx] {
+            TokenTree::Ident(keyword) if at_node_position(&output) => {
+                match keyword.to_string().as_str() {
+                    "if" => parse_if(&tokens[idx..]),
+                    "match" => parse_match(&tokens[idx..]),
+                    "for" => parse_for(&tokens[idx..]),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        match flow {
+            Some((flow, len)) => {
+                let block = flow.to_tokens(cx, global_class);
+                output.push(Group::new(Delimiter::Brace, block).into());
+                idx += len;
+            }
+            None => {
+                output.push(tokens[idx].clone());
+                idx += 1;
+            }
+        }
+    }
+    output.into_iter().collect()
+}
+
+enum ControlFlow {
+    /// `if cond { ... } else if cond { ... } else { ... }`, where each
+    /// condition can also be an `if let`
+    If {
+        branches: Vec<Branch>,
+        fallback: Option<TokenStream>,
+    },
+    /// `match expr { pat => { ... }, pat if guard => { ... } }`
+    Match {
+        expr: TokenStream,
+        arms: Vec<Branch>,
+    },
+    /// `for pat in expr key=key { ... }`
+    For {
+        pat: TokenStream,
+        expr: TokenStream,
+        key: Option<TokenStream>,
+        body: TokenStream,
+    },
+}
+
+/// A branch of an `if` or an arm of a `match`.
+struct Branch {
+    /// The condition of the branch, or the pattern and guard of the arm.
+    head: TokenStream,
+    /// The variables that the pattern of an `if let` or an arm binds.
+    bindings: Vec<Ident>,
+    body: TokenStream,
+}
+
+impl ControlFlow {
+    fn to_tokens(
+        &self,
+        cx: &Ident,
+        global_class: Option<&TokenTree>,
+    ) -> TokenStream {
+        let class = global_class.map(|class| quote! { class = #class, });
+        let view = |body: &TokenStream| {
+            quote! {
+                ::leptos::IntoView::into_view(
+                    ::leptos::view! { #cx, #class #body },
+                    #cx
+                )
+            }
+        };
+        let empty = quote! {
+            ::leptos::IntoView::into_view(::leptos::leptos_dom::Unit, #cx)
+        };
+
+        // not visible to the tokens from the view
+        let branch_memo = Ident::new("branch", Span::mixed_site());
+        let generation = Ident::new("generation", Span::mixed_site());
+        let index = Ident::new("index", Span::mixed_site());
+        let item = Ident::new("item", Span::mixed_site());
+
+        // The index of the branch that is taken is memoized, so the view is
+        // only rebuilt when it changes, and is built untracked from that
+        // index. The values that a branch binds are cloned into a slot for
+        // the view, and taking such a branch again bumps the generation, so
+        // the view is rebuilt with the new values.
+        let memoized = |branches: &[Branch],
+                        select: &dyn Fn(Vec<TokenStream>) -> TokenStream,
+                        fallback: TokenStream| {
+            let slots = branches
+                .iter()
+                .enumerate()
+                .map(|(idx, branch)| {
+                    (!branch.bindings.is_empty()).then(|| {
+                        Ident::new(&format!("slot_{idx}"), Span::mixed_site())
+                    })
+                })
+                .collect::<Vec<_>>();
+            let declared_slots = slots.iter().flatten().collect::<Vec<_>>();
+
+            let taken = branches.iter().zip(&slots).enumerate().map(
+                |(idx, (branch, slot))| match slot {
+                    Some(slot) => {
+                        let bindings = &branch.bindings;
+                        quote! {{
+                            *::std::cell::RefCell::borrow_mut(&#slot) =
+                                ::core::option::Option::Some((
+                                    #(::core::clone::Clone::clone(&#bindings),)*
+                                ));
+                            (#idx, #generation)
+                        }}
+                    }
+                    None => quote! { (#idx, 0) },
+                },
+            );
+            let branch = select(taken.collect());
+
+            let indices = 0..branches.len();
+            let views = branches.iter().zip(&slots).map(|(branch, slot)| {
+                let body = view(&branch.body);
+                match slot {
+                    Some(slot) => {
+                        let bindings = &branch.bindings;
+                        quote! {{
+                            #[allow(unused_variables)]
+                            let (#(#bindings,)*) = ::core::option::Option::expect(
+                                ::core::clone::Clone::clone(
+                                    &*::std::cell::RefCell::borrow(&#slot),
+                                ),
+                                "the branch to have stored its bindings",
+                            );
+                            #body
+                        }}
+                    }
+                    None => body,
+                }
+            });
+
+            quote! {
+                #(
+                    let #declared_slots = ::std::rc::Rc::new(
+                        ::std::cell::RefCell::new(::core::option::Option::None),
+                    );
+                )*
+                let #branch_memo = ::leptos::create_memo(#cx, {
+                    #(let #declared_slots = ::std::rc::Rc::clone(&#declared_slots);)*
+                    move |prev: ::core::option::Option<&(usize, usize)>| {
+                        #[allow(unused_variables)]
+                        let #generation = prev.map_or(0, |(_, #generation)| {
+                            #generation.wrapping_add(1)
+                        });
+                        #branch
+                    }
+                });
+                move || {
+                    let (#index, _) = ::leptos::SignalGet::get(&#branch_memo);
+                    #cx.untrack(|| match #index {
+                        #(#indices => #views,)*
+                        _ => #fallback,
+                    })
+                }
+            }
+        };
+
+        match self {
+            ControlFlow::If { branches, fallback } => {
+                let fallback_index = branches.len();
+                let select = |taken: Vec<TokenStream>| {
+                    let conds = branches.iter().map(|branch| &branch.head);
+                    quote! {
+                        #(if #conds { #taken } else)* { (#fallback_index, 0) }
+                    }
+                };
+                let fallback = fallback.as_ref().map(view).unwrap_or(empty);
+                memoized(branches, &select, fallback)
+            }
+            ControlFlow::Match { expr, arms } => {
+                let select = |taken: Vec<TokenStream>| {
+                    let heads = arms.iter().map(|arm| &arm.head);
+                    quote! {
+                        match #expr { #(#heads => #taken,)* }
+                    }
+                };
+                memoized(arms, &select, quote! { ::core::unreachable!() })
+            }
+            ControlFlow::For {
+                pat,
+                expr,
+                key,
+                body,
+            } => {
+                // the key and the body each only use some of the bindings
+                let key = match key {
+                    Some(key) => quote! {
+                        move |#item: &_| {
+                            #[allow(unused_variables)]
+                            let #pat = #item;
+                            #key
+                        }
+                    },
+                    None => quote! {
+                        |#item: &_| ::core::clone::Clone::clone(#item)
+                    },
+                };
+                quote! {
+                    ::leptos::leptos_dom::Each::new(
+                        move || #expr,
+                        #key,
+                        move |#cx, #item| {
+                            #[allow(unused_variables)]
+                            let #pat = #item;
+                            ::leptos::view! { #cx, #class #body }
+                        },
+                    )
+                }
+            }
+        }
+    }
+}
+
+// the variables a pattern binds; constants and unit variants like `None` are
+// parsed as identifiers too, so only lowercase identifiers are bindings
+fn pattern_bindings(pat: &Pat, bindings: &mut Vec<Ident>) {
+    match pat {
+        Pat::Ident(pat) => {
+            if !pat.ident.to_string().starts_with(char::is_uppercase) {
+                bindings.push(pat.ident.clone());
+            }
+            if let Some((_, subpat)) = &pat.subpat {
+                pattern_bindings(subpat, bindings);
+            }
+        }
+        // every case binds the same variables
+        Pat::Or(pat) => {
+            if let Some(case) = pat.cases.first() {
+                pattern_bindings(case, bindings);
+            }
+        }
+        Pat::Paren(pat) => pattern_bindings(&pat.pat, bindings),
+        Pat::Reference(pat) => pattern_bindings(&pat.pat, bindings),
+        Pat::Type(pat) => pattern_bindings(&pat.pat, bindings),
+        Pat::Slice(pat) => pat
+            .elems
+            .iter()
+            .for_each(|pat| pattern_bindings(pat, bindings)),
+        Pat::Tuple(pat) => pat
+            .elems
+            .iter()
+            .for_each(|pat| pattern_bindings(pat, bindings)),
+        Pat::TupleStruct(pat) => pat
+            .elems
+            .iter()
+            .for_each(|pat| pattern_bindings(pat, bindings)),
+        Pat::Struct(pat) => pat
+            .fields
+            .iter()
+            .for_each(|field| pattern_bindings(&field.pat, bindings)),
+        _ => {}
+    }
+}
+
+// control flow can only replace a node: at the start of the view, or after
+// a tag, a text or a block
+pub(crate) fn at_node_position(output: &[TokenTree]) -> bool {
+    match output {
+        [] => true,
+        [.., TokenTree::Literal(_)] => true,
+        [.., TokenTree::Group(group)] => group.delimiter() == Delimiter::Brace,
+        [.., TokenTree::Punct(prev), TokenTree::Punct(close)] => {
+            close.as_char() == '>'
+                && !(prev.spacing() == Spacing::Joint
+                    && matches!(prev.as_char(), '-' | '='))
+        }
+        [.., TokenTree::Punct(close)] => close.as_char() == '>',
+        _ => false,
+    }
+}
+
+fn is_ident(token: Option<&TokenTree>, name: &str) -> bool {
+    matches!(token, Some(TokenTree::Ident(ident)) if ident == name)
+}
+
+fn is_brace(token: Option<&TokenTree>) -> bool {
+    matches!(
+        token,
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace
+    )
+}
+
+fn brace_contents(token: &TokenTree) -> TokenStream {
+    match token {
+        TokenTree::Group(group) => group.stream(),
+        _ => unreachable!("checked with `is_brace`"),
+    }
+}
+
+// a `=` that isn't part of `==`, `=>`, `<=` and so on
+fn is_lone_eq(tokens: &[TokenTree], idx: usize) -> bool {
+    let is_punct = |idx: usize, chars: &[char]| {
+        matches!(
+            tokens.get(idx),
+            Some(TokenTree::Punct(punct)) if chars.contains(&punct.as_char())
+        )
+    };
+    let joined_to_prev = idx > 0
+        && matches!(
+            &tokens[idx - 1],
+            TokenTree::Punct(prev) if prev.spacing() == Spacing::Joint
+        );
+    is_punct(idx, &['=']) && !joined_to_prev && !is_punct(idx + 1, &['=', '>'])
+}
+
+// the next tag starts at a `<`, so control flow has to be complete before
+// it: otherwise, the tokens are text that happens to start with a keyword
+fn tag_boundary(tokens: &[TokenTree], from: usize) -> usize {
+    (from..tokens.len())
+        .find(|idx| {
+            matches!(
+                &tokens[*idx],
+                TokenTree::Punct(punct) if punct.as_char() == '<'
+            )
+        })
+        .unwrap_or(tokens.len())
+}
+
+// the expression of an `if`, `match` or `for` ends at the first block,
+// because struct literals aren't allowed there
+fn find_brace(tokens: &[TokenTree], from: usize) -> Option<usize> {
+    (from..tag_boundary(tokens, from)).find(|idx| is_brace(tokens.get(*idx)))
+}
+
+// text like `if you want` isn't an expression, so it's left alone
+fn is_expr(tokens: &[TokenTree]) -> bool {
+    syn::parse2::<Expr>(stream(tokens)).is_ok()
+}
+
+fn stream(tokens: &[TokenTree]) -> TokenStream {
+    tokens.iter().cloned().collect()
+}
+
+fn parse_if(tokens: &[TokenTree]) -> Option<(ControlFlow, usize)> {
+    let mut branches = Vec::new();
+    let mut idx = 0;
+    loop {
+        // skips `if`, and the pattern of an `if let`, which can contain
+        // braces of its own
+        let mut start = idx + 1;
+        if is_ident(tokens.get(start), "let") {
+            start = (start..tag_boundary(tokens, start))
+                .find(|idx| is_lone_eq(tokens, *idx))?;
+        }
+        let body = find_brace(tokens, start)?;
+        let cond = &tokens[idx + 1..body];
+        if cond.is_empty() || !is_expr(cond) {
+            return None;
+        }
+        let mut bindings = Vec::new();
+        if let Ok(Expr::Let(cond)) = syn::parse2(stream(cond)) {
+            pattern_bindings(&cond.pat, &mut bindings);
+        }
+        branches.push(Branch {
+            head: stream(cond),
+            bindings,
+            body: brace_contents(&tokens[body]),
+        });
+        idx = body + 1;
+
+        if !is_ident(tokens.get(idx), "else") {
+            let flow = ControlFlow::If {
+                branches,
+                fallback: None,
+            };
+            return Some((flow, idx));
+        }
+        if is_ident(tokens.get(idx + 1), "if") {
+            idx += 1;
+        } else if is_brace(tokens.get(idx + 1)) {
+            let flow = ControlFlow::If {
+                branches,
+                fallback: Some(brace_contents(&tokens[idx + 1])),
+            };
+            return Some((flow, idx + 2));
+        } else {
+            abort!(tokens[idx], "expected `if` or a block after `else`");
+        }
+    }
+}
+
+fn parse_match(tokens: &[TokenTree]) -> Option<(ControlFlow, usize)> {
+    let body = find_brace(tokens, 1)?;
+    if body == 1 || !is_expr(&tokens[1..body]) {
+        return None;
+    }
+    let expr = stream(&tokens[1..body]);
+
+    let arm_tokens = brace_contents(&tokens[body])
+        .into_iter()
+        .collect::<Vec<_>>();
+    let mut arms = Vec::new();
+    let mut idx = 0;
+    while idx < arm_tokens.len() {
+        let arrow = (idx..arm_tokens.len().saturating_sub(1)).find(|idx| {
+            matches!(
+                (&arm_tokens[*idx], &arm_tokens[*idx + 1]),
+                (TokenTree::Punct(eq), TokenTree::Punct(gt))
+                    if eq.as_char() == '='
+                        && eq.spacing() == Spacing::Joint
+                        && gt.as_char() == '>'
+            )
+        });
+        let Some(arrow) = arrow else {
+            abort!(
+                arm_tokens[idx],
+                "expected `=>` after the pattern of a match arm"
+            );
+        };
+        let Some(arm_body) =
+            arm_tokens.get(arrow + 2).filter(|t| is_brace(Some(t)))
+        else {
+            abort!(
+                arm_tokens[arrow + 1],
+                "the arms of a `match` in the `view` macro must be blocks";
+                help = "wrap the nodes in braces, e.g. \
+                        `Some(name) => { <p>{name}</p> }`"
+            );
+        };
+        let head = stream(&arm_tokens[idx..arrow]);
+        let mut bindings = Vec::new();
+        if let Ok(arm) = syn::parse2::<Arm>(quote! { #head => {} }) {
+            pattern_bindings(&arm.pat, &mut bindings);
+        }
+        arms.push(Branch {
+            head,
+            bindings,
+            body: brace_contents(arm_body),
+        });
+        idx = arrow + 3;
+        if matches!(
+            arm_tokens.get(idx),
+            Some(TokenTree::Punct(comma)) if comma.as_char() == ','
+        ) {
+            idx += 1;
+        }
+    }
+
+    Some((ControlFlow::Match { expr, arms }, body + 1))
+}
+
+fn parse_for(tokens: &[TokenTree]) -> Option<(ControlFlow, usize)> {
+    let in_ = (1..tag_boundary(tokens, 1))
+        .find(|idx| is_ident(tokens.get(*idx), "in"))?;
+    let body = find_brace(tokens, in_ + 1)?;
+    if in_ == 1 || body == in_ + 1 {
+        return None;
+    }
+    let key = (in_ + 1..body).find(|idx| {
+        is_ident(tokens.get(*idx), "key") && is_lone_eq(tokens, idx + 1)
+    });
+    let expr_end = key.unwrap_or(body);
+    let is_pat = syn::parse::Parser::parse2(
+        Pat::parse_multi_with_leading_vert,
+        stream(&tokens[1..in_]),
+    )
+    .is_ok();
+    if !is_pat || !is_expr(&tokens[in_ + 1..expr_end]) {
+        return None;
+    }
+    let key = key.map(|key| stream(&tokens[key + 2..body]));
+    let expr = stream(&tokens[in_ + 1..expr_end]);
+
+    let flow = ControlFlow::For {
+        pat: stream(&tokens[1..in_]),
+        expr,
+        key,
+        body: brace_contents(&tokens[body]),
+    };
+    Some((flow, body + 1))
+}
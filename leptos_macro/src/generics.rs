@@ -0,0 +1,119 @@
+//! Generic arguments on the tags of components in the body of the `view`
+//! macro, like `<TypedSelect<String> options/>`.
+//!
+//! RSX tag names can't have generic arguments, so before the body is parsed,
+//! they are moved into a `leptos:generics` attribute on the same tag, whose
+//! value is the path to the component with a turbofish.
+
+use crate::control_flow::at_node_position;
+use leptos_hot_reload::parsing::is_component_tag_name;
+use proc_macro2::{
+    Delimiter, Group, Ident, Punct, Spacing, TokenStream, TokenTree,
+};
+
+/// The key of the attribute that holds the generic path to a component.
+pub(crate) const GENERICS_ATTRIBUTE: &str = "leptos:generics";
+
+/// Moves the generic arguments of each component tag in `tokens` into an
+/// attribute.
+pub(crate) fn extract_tag_generics(tokens: TokenStream) -> TokenStream {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let mut output = Vec::<TokenTree>::with_capacity(tokens.len());
+    let mut idx = 0;
+    while idx < tokens.len() {
+        if let Some((name, args, len)) = generic_tag(&tokens[idx..], &output) {
+            let span = name.span();
+            let punct = |c: char, spacing: Spacing| {
+                let mut punct = Punct::new(c, spacing);
+                punct.set_span(span);
+                TokenTree::Punct(punct)
+            };
+
+            let mut path = vec![
+                TokenTree::Ident(name.clone()),
+                punct(':', Spacing::Joint),
+                punct(':', Spacing::Alone),
+                punct('<', Spacing::Alone),
+            ];
+            path.extend(args);
+            path.push(punct('>', Spacing::Alone));
+            let mut path =
+                Group::new(Delimiter::Brace, path.into_iter().collect());
+            path.set_span(span);
+
+            output.extend([
+                tokens[idx].clone(),
+                TokenTree::Ident(name),
+                Ident::new("leptos", span).into(),
+                punct(':', Spacing::Alone),
+                Ident::new("generics", span).into(),
+                punct('=', Spacing::Alone),
+                path.into(),
+            ]);
+            idx += len;
+        } else {
+            output.push(tokens[idx].clone());
+            idx += 1;
+        }
+    }
+    output.into_iter().collect()
+}
+
+// `<Name<Args>` or `<Name::<Args>` at the start of a tag, where `Name` is a
+// component: returns the name, the arguments and the number of tokens
+fn generic_tag(
+    tokens: &[TokenTree],
+    output: &[TokenTree],
+) -> Option<(Ident, Vec<TokenTree>, usize)> {
+    let is_punct = |idx: usize, c: char| {
+        matches!(
+            tokens.get(idx),
+            Some(TokenTree::Punct(punct)) if punct.as_char() == c
+        )
+    };
+    if !is_punct(0, '<') || !at_node_position(output) {
+        return None;
+    }
+    let Some(TokenTree::Ident(name)) = tokens.get(1) else {
+        return None;
+    };
+    if !is_component_tag_name(&name.to_string()) {
+        return None;
+    }
+    let open = if is_punct(2, '<') {
+        2
+    } else if is_punct(2, ':') && is_punct(3, ':') && is_punct(4, '<') {
+        4
+    } else {
+        return None;
+    };
+
+    // finds the matching `>`, skipping the one in `->`
+    let mut depth = 0;
+    for idx in open..tokens.len() {
+        let TokenTree::Punct(punct) = &tokens[idx] else {
+            continue;
+        };
+        match punct.as_char() {
+            '<' => depth += 1,
+            '>' if !is_arrow(tokens, idx) => {
+                depth -= 1;
+                if depth == 0 {
+                    let args = tokens[open + 1..idx].to_vec();
+                    return Some((name.clone(), args, idx + 1));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn is_arrow(tokens: &[TokenTree], idx: usize) -> bool {
+    idx > 0
+        && matches!(
+            &tokens[idx - 1],
+            TokenTree::Punct(prev)
+                if prev.as_char() == '-' && prev.spacing() == Spacing::Joint
+        )
+}
@@ -5,11 +5,14 @@
 extern crate proc_macro_error;
 
 use proc_macro::TokenStream;
-use proc_macro2::{Span, TokenTree};
+use proc_macro2::{Ident, Span, TokenTree};
 use quote::ToTokens;
 use rstml::{node::KeyedAttribute, parse};
 use server_fn_macro::{server_macro_impl, ServerContext};
-use syn::parse_macro_input;
+use syn::{
+    parse_macro_input, punctuated::Punctuated, spanned::Spanned, Expr, ExprLit,
+    Lit, Meta, Token,
+};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub(crate) enum Mode {
@@ -30,7 +33,11 @@ impl Default for Mode {
     }
 }
 
+mod control_flow;
+mod generics;
 mod params;
+mod static_template;
+mod validate;
 mod view;
 use template::render_template;
 use view::render_view;
@@ -94,6 +101,10 @@ mod template;
 ///    Attributes can take a wide variety of primitive types that can be converted to strings. They can also
 ///    take an `Option`, in which case `Some` sets the attribute and `None` removes the attribute.
 ///
+///    When an element contains two or more blocks next to each other (with only text in between), and every
+///    one of them is text (see `IntoText`), they are merged with the text around them into a single text
+///    node, which is updated by a single effect.
+///
 /// ```rust
 /// # use leptos::*;
 /// # run_scope(create_runtime(), |cx| {
@@ -115,7 +126,10 @@ mod template;
 /// ```
 ///
 /// 5. Event handlers can be added with `on:` attributes. In most cases, the events are given the correct type
-///    based on the event name.
+///    based on the event name. The typed `detail` of a custom event can be passed to a handler with
+///    [`ev::with_detail`](leptos_dom::ev::with_detail). The name can be followed by `:capture` to listen in the
+///    capture phase, `:passive` to mark the listener passive, or `:undelegated` to attach it to the element directly,
+///    in any combination, like `on:touchmove:passive:capture`.
 /// ```rust
 /// # use leptos::*;
 /// # run_scope(create_runtime(), |cx| {
@@ -157,6 +171,69 @@ mod template;
 /// # });
 /// ```
 ///
+/// Instead of pairing `prop:value` with an event listener, a form control can be bound to an
+/// [RwSignal](leptos_reactive::RwSignal) in both directions: `bind:value` for the text of an `<input>`,
+/// `<textarea>` or `<select>`, `bind:checked` for a checkbox, and `bind:group` for a group of radio
+/// buttons (bound to the `value` of the one that is checked) or checkboxes (bound to a `Vec` of the
+/// `value`s of the ones that are checked).
+/// ```rust
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let name = create_rw_signal(cx, "Alice".to_string());
+/// let subscribed = create_rw_signal(cx, false);
+/// let plan = create_rw_signal(cx, "free".to_string());
+///
+/// view! {
+///   cx,
+///   <input type="text" bind:value=name/>
+///   <input type="checkbox" bind:checked=subscribed/>
+///   <input type="radio" value="free" bind:group=plan/>
+///   <input type="radio" value="pro" bind:group=plan/>
+/// }
+/// # ;
+/// # }
+/// # });
+/// ```
+///
+/// A directive is a function with the signature `fn(Scope, web_sys::Element, T)` that adds behavior
+/// to an element once it has been created, like trapping focus or setting up a third-party widget.
+/// `use:my_directive=param` runs `my_directive` with the element and `param`, and `use:my_directive`
+/// runs it with `()`. Directives only run in the browser, and can clean up after themselves with
+/// [on_cleanup](leptos_reactive::on_cleanup).
+/// ```rust
+/// # use leptos::{leptos_dom::web_sys, *};
+/// fn tooltip(cx: Scope, el: web_sys::Element, text: &'static str) {
+///     _ = el.set_attribute("title", text);
+///     on_cleanup(cx, move || log!("removing tooltip {text:?}"));
+/// }
+///
+/// # run_scope(create_runtime(), |cx| {
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// view! { cx, <button use:tooltip="Saves the document">"Save"</button> }
+/// # ;
+/// # }
+/// # });
+/// ```
+///
+/// A collection of attributes, properties and event listeners can be spread onto an element with
+/// `{..attrs}`, where `attrs` is a [SpreadAttributes](leptos_dom::SpreadAttributes), or anything
+/// that can be converted into one, like a list of `(name, value)` pairs. This lets a component
+/// forward any attributes it is given to the element it wraps.
+/// ```rust
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let attrs = SpreadAttributes::new()
+///     .attr("aria-label", "Close")
+///     .on(ev::click, |_| log!("closed"));
+///
+/// view! { cx, <button class="close" {..attrs}>"×"</button> }
+/// # ;
+/// # }
+/// # });
+/// ```
+///
 /// 7. Classes can be toggled with `class:` attributes, which take a `bool` (or a signal that returns a `bool`).
 /// ```rust
 /// # use leptos::*;
@@ -259,8 +336,10 @@ mod template;
 /// ```
 ///
 /// 11. You can set any HTML element’s `innerHTML` with the `inner_html` attribute on an
-///     element. Be careful: this HTML will not be escaped, so you should ensure that it
-///     only contains trusted input.
+///     element. Be careful: this HTML will not be escaped. It is cleaned by the sanitizer
+///     set with `set_html_sanitizer`, or by `ammonia` with the `sanitize` feature, but
+///     without either you should ensure that it only contains trusted input. HTML that
+///     you trust completely can skip the sanitizer with `dangerously_set_inner_html`.
 /// ```rust
 /// # use leptos::*;
 /// # run_scope(create_runtime(), |cx| {
@@ -268,12 +347,70 @@ mod template;
 /// let html = "<p>This HTML will be injected.</p>";
 /// view! { cx,
 ///   <div inner_html=html/>
+///   <div dangerously_set_inner_html="<p>So will this.</p>"/>
 /// }
 /// # ;
 /// # }
 /// # });
 /// ```
 ///
+/// 12. `if`, `match` and `for` can be used among the nodes, with blocks of nodes as their
+///     bodies. The branch an `if` or `match` takes is memoized, so its view is only
+///     rebuilt when a different branch is taken, or when a branch that binds values, like
+///     `if let Some(name)`, is taken again; the values are cloned for its view. The arms
+///     of a `match` must be blocks, and a condition that compares with `<` has to be
+///     wrapped in parentheses, since `<` starts the next tag.
+///     A `for` loop is keyed like [`<For/>`](https://docs.rs/leptos/latest/leptos/fn.For.html),
+///     by the item itself or by an optional `key=`, in which the pattern binds references
+///     to the item.
+/// ```rust
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// # if !cfg!(any(feature = "csr", feature = "hydrate")) {
+/// let (user, set_user) = create_signal(cx, None::<String>);
+/// let (todos, set_todos) = create_signal(cx, vec![(0, "Write docs")]);
+/// view! { cx,
+///   if let Some(name) = user.get() {
+///     <p>"Hello, " {name} "!"</p>
+///   } else {
+///     <button on:click=move |_| set_user.set(Some("Ferris".into()))>"Log in"</button>
+///   }
+///   match todos.with(Vec::len) {
+///     0 => { <p>"Nothing to do"</p> }
+///     n => { <p>{n} " to do"</p> }
+///   }
+///   <ul>
+///     for (id, text) in todos.get() key=*id {
+///       <li>{text}</li>
+///     }
+///   </ul>
+/// }
+/// # ;
+/// # }
+/// # });
+/// ```
+///
+/// 13. Markup that the browser would fix up or ignore is a compile error: children of void elements
+///     like `<input>`, elements like `<div>` inside a `<p>`, attributes that are set twice, attributes
+///     that an HTML element doesn’t have, unknown ARIA roles and attributes, and ARIA attributes that
+///     the element’s role doesn’t support. An attribute can be set on any element with the `attr:`
+///     prefix, like `<div attr:x-data="{}"/>`.
+/// ```rust,compile_fail
+/// # use leptos::*;
+/// # run_scope(create_runtime(), |cx| {
+/// // ❌ the browser renders this as `<p></p><div>"Hello"</div><p></p>`
+/// view! { cx, <p><div>"Hello"</div></p> }
+/// # ;
+/// # });
+/// ```
+///
+/// 14. With the `csr` feature, each HTML element inside the view, like `<li class="item">"One"</li>`,
+///     is compiled into the HTML of a `<template>`, which is parsed once and then cloned each time the
+///     view is rendered. Its dynamic attributes, listeners and bindings are then added to the elements
+///     of the copy, and its blocks, components and SVG elements are mounted where they go in it. The
+///     root of the view is always built as usual, so it keeps its type, and so is an element with a
+///     `node_ref`, which needs its own type too.
+///
 /// Here’s a simple example that shows off several of these features, put together
 /// ```rust
 /// # use leptos::*;
@@ -351,6 +488,12 @@ pub fn view(tokens: TokenStream) -> TokenStream {
                     .chain(tokens)
                     .collect()
             };
+            let tokens = control_flow::desugar_control_flow(
+                tokens,
+                &cx,
+                global_class.as_ref(),
+            );
+            let tokens = generics::extract_tag_generics(tokens);
             let config = rstml::ParserConfig::default().recover_block(true);
             let parser = rstml::Parser::new(config);
             let (nodes, errors) = parser.parse_recoverable(tokens).split_vec();
@@ -540,28 +683,45 @@ pub fn template(tokens: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
-/// 4. You can pass generic arguments, but they should be defined in a `where` clause and not inline.
+/// 4. A component can be generic, with its bounds inline or in a `where` clause. Its props struct
+///    has the same generic parameters. They are usually inferred from the props, but can also be
+///    given on the tag in the `view` macro, like `<TypedSelect<String>/>` or
+///    `<TypedSelect::<String>/>`.
 ///
-/// ```compile_error
-/// // ❌ This won't work.
+/// ```
 /// # use leptos::*;
-/// use leptos::html::Div;
+/// use std::fmt::Display;
 ///
 /// #[component]
-/// fn MyComponent<T: Fn() -> HtmlElement<Div>>(cx: Scope, render_prop: T) -> impl IntoView {
+/// fn TypedSelect<T: Display + Clone + 'static>(
+///     cx: Scope,
+///     options: Vec<T>,
+/// ) -> impl IntoView {
+///     view! { cx,
+///         <select>
+///             {options
+///                 .into_iter()
+///                 .map(|option| view! { cx, <option>{option.to_string()}</option> })
+///                 .collect_view(cx)}
+///         </select>
+///     }
 /// }
-/// ```
-///
-/// ```
-/// // ✅ Do this instead
-/// # use leptos::*;
-/// use leptos::html::Div;
 ///
 /// #[component]
 /// fn MyComponent<T>(cx: Scope, render_prop: T) -> impl IntoView
 /// where
-///     T: Fn() -> HtmlElement<Div>,
+///     T: Fn() -> HtmlElement<html::Div>,
 /// {
+///     render_prop()
+/// }
+///
+/// #[component]
+/// fn App(cx: Scope) -> impl IntoView {
+///     view! { cx,
+///         <TypedSelect options=vec![1, 2, 3]/>
+///         <TypedSelect<String> options=vec![]/>
+///         <MyComponent render_prop=move || view! { cx, <div>"Hello"</div> }/>
+///     }
 /// }
 /// ```
 ///
@@ -597,6 +757,31 @@ pub fn template(tokens: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// 6. A component can also pass data into its children, if its `children` property is a function
+///    that takes the data as well as a `Scope`. Name the data with `let:`, and it will be
+///    available inside the children under that name.
+///
+/// ```
+/// # use leptos::*;
+/// #[component]
+/// fn Greeting<F, IV>(cx: Scope, children: F) -> impl IntoView
+/// where
+///     F: Fn(Scope, &'static str) -> IV,
+///     IV: IntoView,
+/// {
+///     children(cx, "Hello")
+/// }
+///
+/// #[component]
+/// fn Greeter(cx: Scope) -> impl IntoView {
+///     view! { cx,
+///       <Greeting let:greeting>
+///         <p>{greeting} ", world!"</p>
+///       </Greeting>
+///     }
+/// }
+/// ```
+///
 /// ## Customizing Properties
 /// You can use the `#[prop]` attribute on individual component properties (function arguments) to
 /// customize the types that component property can receive. You can use the following attributes:
@@ -612,6 +797,13 @@ pub fn template(tokens: TokenStream) -> TokenStream {
 /// * `#[prop(optional_no_strip)]`: The same as `optional`, but requires values to be passed as `None` or
 ///   `Some(T)` explicitly. This means that the optional property can be omitted (and be `None`), or explicitly
 ///   specified as either `None` or `Some(T)`.
+/// * `#[prop(default = expr)]`: If the user does not specify this property, it will be set to `expr`,
+///   which can be any expression of the property’s type, and can use the properties declared before it.
+/// * `#[prop(strip_option)]`: The property is required, but a value of type `Option<T>` is passed as
+///   `name=T`, and received as `Some(T)`.
+///
+/// Any other property is required: leaving it out is a compile error on the component’s name in
+/// the `view` macro, with a message naming the missing property.
 /// ```rust
 /// # use leptos::*;
 ///
@@ -621,6 +813,7 @@ pub fn template(tokens: TokenStream) -> TokenStream {
 ///     #[prop(into)] name: String,
 ///     #[prop(optional)] optional_value: Option<i32>,
 ///     #[prop(optional_no_strip)] optional_no_strip: Option<i32>,
+///     #[prop(default = name.len())] width: usize,
 /// ) -> impl IntoView {
 ///     // whatever UI you need
 /// }
@@ -632,35 +825,307 @@ pub fn template(tokens: TokenStream) -> TokenStream {
 ///         name="Greg" // automatically converted to String with `.into()`
 ///         optional_value=42 // received as `Some(42)`
 ///         optional_no_strip=Some(42) // received as `Some(42)`
+///         width=10
 ///       />
 ///       <MyComponent
 ///         name="Bob" // automatically converted to String with `.into()`
 ///         // optional values can both be omitted, and received as `None`
+///         // `width` is omitted, and is 3
 ///       />
 ///     }
 /// }
 /// ```
+///
+/// ## Transparent Components
+/// `#[component(transparent)]` returns whatever its function returns to the parent that renders
+/// it, without wrapping it in a component of its own, so its return type can be any type that
+/// implements [`IntoView`](https://docs.rs/leptos/latest/leptos/trait.IntoView.html), not only
+/// `impl IntoView`. Such a component can return data instead of a view, by wrapping it in
+/// [`Transparent`](https://docs.rs/leptos/latest/leptos/struct.Transparent.html), and its parent can then read the data of its children with
+/// [`Fragment::transparent_children`](https://docs.rs/leptos/latest/leptos/struct.Fragment.html#method.transparent_children).
+/// This lets you declare a tree of configuration, like the routes of an app or the entries of a
+/// menu, with `view` syntax.
+/// ```rust
+/// # use leptos::*;
+///
+/// #[derive(Clone)]
+/// pub struct MenuEntry {
+///     label: &'static str,
+///     href: &'static str,
+/// }
+///
+/// #[component(transparent)]
+/// pub fn MenuItem(cx: Scope, label: &'static str, href: &'static str) -> Transparent {
+///     Transparent::new(MenuEntry { label, href })
+/// }
+///
+/// #[component]
+/// pub fn Menu(cx: Scope, children: Children) -> impl IntoView {
+///     let entries = children(cx)
+///         .transparent_children::<MenuEntry>()
+///         .into_iter()
+///         .cloned()
+///         .collect::<Vec<_>>();
+///     view! { cx,
+///         <nav>
+///             {entries
+///                 .into_iter()
+///                 .map(|entry| view! { cx, <a href=entry.href>{entry.label}</a> })
+///                 .collect_view(cx)}
+///         </nav>
+///     }
+/// }
+///
+/// #[component]
+/// pub fn App(cx: Scope) -> impl IntoView {
+///     view! { cx,
+///         <Menu>
+///             <MenuItem label="Home" href="/"/>
+///             <MenuItem label="About" href="/about"/>
+///         </Menu>
+///     }
+/// }
+/// ```
+/// A type that is always passed this way can instead implement `IntoView` by wrapping itself
+/// in `Transparent`, so that its components can return it directly, as the router’s
+/// `RouteDefinition` does for `<Route/>`.
+///
+/// ## Memoized Components
+/// `#[component(memo)]` skips rendering a component again if it is given the same props as a
+/// previous render. Every prop must implement [`Clone`], [`PartialEq`] and
+/// [`Hash`](std::hash::Hash), and the component should be *pure*: its output should depend only
+/// on its props, not on context or global state.
+/// * During server rendering, the HTML rendered for a given set of props at a given place in the
+///   page is cached for the lifetime of the process, keyed by the hash of the props, and reused
+///   by later requests.
+/// * In the browser, if the component is recreated because a reactive block around it reruns,
+///   the view rendered by the previous run is reused when its props are equal to the new ones.
+/// ```rust
+/// # use leptos::*;
+///
+/// #[component(memo)]
+/// pub fn Avatar(cx: Scope, name: String, size: u32) -> impl IntoView {
+///     let initials = name
+///         .split_whitespace()
+///         .filter_map(|word| word.chars().next())
+///         .collect::<String>();
+///     view! { cx,
+///         <span class="avatar" style:width=format!("{size}px")>{initials}</span>
+///     }
+/// }
+/// ```
+///
+/// ## Performance Budgets
+/// In debug builds, `#[component(budget_ms = 4)]` logs a warning whenever an instance of the
+/// component takes longer than the given number of milliseconds to render, or whenever an effect
+/// created while rendering it takes longer than that to run. Children that don’t declare a budget
+/// of their own count against it, so their effects are reported under this component’s name. This
+/// has no effect in release builds.
+/// ```rust
+/// # use leptos::*;
+///
+/// #[component(budget_ms = 4)]
+/// pub fn DataTable(cx: Scope, rows: Vec<String>) -> impl IntoView {
+///     rows.into_iter()
+///         .map(|row| view! { cx, <tr><td>{row}</td></tr> })
+///         .collect_view(cx)
+/// }
+/// ```
+///
+/// ## Deferred Hydration
+/// `#[component(hydrate = "...")]` renders a component on the server as usual, but waits to
+/// hydrate it in the browser until it is needed, so that widgets below the fold or rarely used
+/// ones don’t add to the cost of hydrating the page. Until then, the server-rendered HTML is
+/// shown, but does not respond to events or reactive changes.
+/// * `hydrate = "visible"` hydrates once the component scrolls into the viewport.
+/// * `hydrate = "idle"` hydrates when the browser is idle.
+/// * `hydrate = "interaction"` hydrates the first time the user presses, focuses, or types
+///   inside the component, and then passes that event on to the component’s event listeners.
+///
+/// The component is rendered inside a `<leptos-hydrate>` element with `display: contents`.
+/// When it is rendered in the browser without hydration, it is rendered immediately.
+/// ```rust
+/// # use leptos::*;
+///
+/// #[component(hydrate = "visible")]
+/// pub fn Comments(cx: Scope, comments: Vec<String>) -> impl IntoView {
+///     let (expanded, set_expanded) = create_signal(cx, false);
+///     view! { cx,
+///         <button on:click=move |_| set_expanded.update(|e| *e = !*e)>"Comments"</button>
+///         <Show when=move || expanded.get() fallback=|_| ()>
+///             <ul>{comments.clone().into_iter().map(|c| view! { cx, <li>{c}</li> }).collect_view(cx)}</ul>
+///         </Show>
+///     }
+/// }
+/// ```
+///
+/// ## Tracing Prop Changes
+/// In debug builds, `#[component(trace_props)]` logs a message whenever one of the component’s
+/// props that is a signal (a `ReadSignal`, `RwSignal`, `Memo`, `Signal`, or `MaybeSignal`)
+/// changes, with its old and new values and the signal whose write caused the change. This can
+/// help you find out why part of a view is updating. Values are printed with their `Debug`
+/// implementation. This has no effect in release builds.
+/// ```rust
+/// # use leptos::*;
+///
+/// #[component(trace_props)]
+/// pub fn Counter(cx: Scope, count: Signal<i32>, label: &'static str) -> impl IntoView {
+///     // logs e.g. "[trace_props] prop `count` of <Counter/> changed from 1 to 2, ..."
+///     view! { cx, <p>{label} ": " {count}</p> }
+/// }
+/// ```
 #[proc_macro_error::proc_macro_error]
 #[proc_macro_attribute]
 pub fn component(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
-    let is_transparent = if !args.is_empty() {
-        let transparent = parse_macro_input!(args as syn::Ident);
-
-        if transparent != "transparent" {
-            abort!(
-                transparent,
-                "only `transparent` is supported";
-                help = "try `#[component(transparent)]` or `#[component]`"
-            );
+    let mut is_transparent = false;
+    let mut is_memo = false;
+    let mut budget_ms = None;
+    let mut hydrate = None;
+    let mut trace_props = false;
+    let args = parse_macro_input!(
+        args with Punctuated::<Meta, Token![,]>::parse_terminated
+    );
+    for arg in args {
+        match &arg {
+            Meta::Path(path) if path.is_ident("transparent") => {
+                is_transparent = true;
+            }
+            Meta::Path(path) if path.is_ident("memo") => {
+                is_memo = true;
+            }
+            Meta::Path(path) if path.is_ident("trace_props") => {
+                trace_props = true;
+            }
+            Meta::NameValue(arg) if arg.path.is_ident("budget_ms") => {
+                let budget = match &arg.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Int(lit), ..
+                    }) => lit.base10_parse::<f64>(),
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Float(lit),
+                        ..
+                    }) => lit.base10_parse::<f64>(),
+                    value => abort!(
+                        value,
+                        "`budget_ms` should be a number of milliseconds";
+                        help = "try `#[component(budget_ms = 4)]`"
+                    ),
+                };
+                budget_ms = Some(budget.unwrap_or_else(|e| abort!(arg, e)));
+            }
+            Meta::NameValue(arg) if arg.path.is_ident("hydrate") => {
+                let strategy = match &arg.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit), ..
+                    }) => match lit.value().as_str() {
+                        "visible" => "Visible",
+                        "idle" => "Idle",
+                        "interaction" => "Interaction",
+                        _ => abort!(
+                            lit,
+                            "unknown hydration strategy";
+                            help = "try `\"visible\"`, `\"idle\"`, or \
+                                    `\"interaction\"`"
+                        ),
+                    },
+                    value => abort!(
+                        value,
+                        "`hydrate` should be a string";
+                        help = "try `#[component(hydrate = \"visible\")]`"
+                    ),
+                };
+                hydrate = Some(Ident::new(strategy, arg.value.span()));
+            }
+            _ => abort!(
+                arg,
+                "only `transparent`, `memo`, `budget_ms`, `hydrate`, and \
+                 `trace_props` are supported";
+                help = "try `#[component(transparent)]`, \
+                        `#[component(memo)]`, `#[component(budget_ms = 4)]`, \
+                        `#[component(hydrate = \"visible\")]`, \
+                        `#[component(trace_props)]`, or `#[component]`"
+            ),
         }
-
-        true
-    } else {
-        false
-    };
+    }
+    if is_transparent && is_memo {
+        abort_call_site!(
+            "a component cannot be both `transparent` and `memo`";
+            help = "`memo` caches the rendered view, but a transparent \
+                    component does not render one"
+        );
+    }
+    if is_transparent && hydrate.is_some() {
+        abort_call_site!(
+            "a component cannot be both `transparent` and `hydrate`";
+            help = "a transparent component does not render a view, so \
+                    there is nothing to hydrate"
+        );
+    }
 
     parse_macro_input!(s as component::Model)
         .is_transparent(is_transparent)
+        .is_memo(is_memo)
+        .budget_ms(budget_ms)
+        .hydrate(hydrate)
+        .trace_props(trace_props)
+        .into_token_stream()
+        .into()
+}
+
+/// Defines a component as an *island*: an interactive part of a page that is hydrated on its
+/// own, in islands mode.
+///
+/// An island is written, and used in a [`view`](macro@crate::view), like any other
+/// [`component`](macro@crate::component). When [`LeptosOptions::islands`](https://docs.rs/leptos_config/latest/leptos_config/struct.LeptosOptions.html#structfield.islands)
+/// is set, the server integrations load the WASM bundle without hydrating the page: everything
+/// outside an island stays as static, server-rendered HTML with no reactive scope in the
+/// browser, and only the islands are hydrated, each in its own reactive scope.
+///
+/// On the server, an island is rendered inside a `<leptos-island>` element with
+/// `display: contents`, which records its name and its props. Every prop must implement
+/// [`Serializable`](https://docs.rs/leptos/latest/leptos/trait.Serializable.html), so that it
+/// can be sent to the browser, which is why an island can’t be generic or take `children`.
+/// For each island, a `_island_{Name}` function is exported from the WASM bundle, which
+/// hydrates one of these elements; island names must be unique across the app.
+///
+/// Without islands mode, an island is hydrated along with the rest of the page, like any other
+/// component.
+/// ```rust
+/// # use leptos::*;
+///
+/// #[island]
+/// pub fn LikeButton(cx: Scope, initial: u32) -> impl IntoView {
+///     let (likes, set_likes) = create_signal(cx, initial);
+///     view! { cx,
+///         <button on:click=move |_| set_likes.update(|n| *n += 1)>
+///             {likes} " likes"
+///         </button>
+///     }
+/// }
+///
+/// // only the button is hydrated; the article is static HTML
+/// #[component]
+/// pub fn Article(cx: Scope, text: String) -> impl IntoView {
+///     view! { cx,
+///         <article>
+///             <p>{text}</p>
+///             <LikeButton initial=3/>
+///         </article>
+///     }
+/// }
+/// ```
+#[proc_macro_error::proc_macro_error]
+#[proc_macro_attribute]
+pub fn island(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
+    if !args.is_empty() {
+        abort_call_site!(
+            "`#[island]` doesn't take any arguments";
+            help = "try `#[island]`"
+        );
+    }
+
+    parse_macro_input!(s as component::Model)
+        .is_island(true)
         .into_token_stream()
         .into()
 }
@@ -771,6 +1236,63 @@ pub fn component(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// 4. A component can take any number of different slots, as well as `children`, which are given
+/// every child that isn’t a slot. A slot prop of type `Option<_>` marked `#[prop(optional)]` can be
+/// left out, and one of type `Vec<_>` can be passed any number of times.
+///
+/// ```
+/// # use leptos::*;
+/// #[slot]
+/// struct CardHeader {
+///     children: Children,
+/// }
+///
+/// #[slot]
+/// struct CardAction {
+///     label: &'static str,
+/// }
+///
+/// #[slot]
+/// struct CardFooter {
+///     children: Children,
+/// }
+///
+/// #[component]
+/// fn Card(
+///     cx: Scope,
+///     card_header: CardHeader,
+///     #[prop(optional)] card_action: Vec<CardAction>,
+///     #[prop(optional)] card_footer: Option<CardFooter>,
+///     children: Children,
+/// ) -> impl IntoView {
+///     view! { cx,
+///         <article>
+///             <header>{(card_header.children)(cx)}</header>
+///             {children(cx)}
+///             {card_action
+///                 .into_iter()
+///                 .map(|action| view! { cx, <button>{action.label}</button> })
+///                 .collect_view(cx)}
+///             {card_footer.map(|footer| {
+///                 view! { cx, <footer>{(footer.children)(cx)}</footer> }
+///             })}
+///         </article>
+///     }
+/// }
+///
+/// #[component]
+/// fn App(cx: Scope) -> impl IntoView {
+///     view! { cx,
+///         <Card>
+///             <CardHeader slot><h2>"Hello"</h2></CardHeader>
+///             <p>"These are the children."</p>
+///             <CardAction slot label="Save"/>
+///             <CardAction slot label="Share"/>
+///         </Card>
+///     }
+/// }
+/// ```
 #[proc_macro_error::proc_macro_error]
 #[proc_macro_attribute]
 pub fn slot(args: proc_macro::TokenStream, s: TokenStream) -> TokenStream {
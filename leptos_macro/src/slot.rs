@@ -144,6 +144,13 @@ struct PropOpt {
     pub into: bool,
 }
 
+impl PropOpt {
+    /// Whether the prop can be left out when the slot is used.
+    fn can_be_omitted(&self) -> bool {
+        self.optional || self.optional_no_strip || self.default.is_some()
+    }
+}
+
 struct TypedBuilderOpts {
     default: bool,
     default_with_value: Option<syn::Expr>,
@@ -226,17 +233,13 @@ fn prop_builder_fields(vis: &Visibility, props: &[Prop]) -> TokenStream {
 fn generate_prop_docs(props: &[Prop]) -> TokenStream {
     let required_prop_docs = props
         .iter()
-        .filter(|Prop { prop_opts, .. }| {
-            !(prop_opts.optional || prop_opts.optional_no_strip)
-        })
+        .filter(|Prop { prop_opts, .. }| !prop_opts.can_be_omitted())
         .map(|p| prop_to_doc(p, PropDocStyle::List))
         .collect::<TokenStream>();
 
     let optional_prop_docs = props
         .iter()
-        .filter(|Prop { prop_opts, .. }| {
-            prop_opts.optional || prop_opts.optional_no_strip
-        })
+        .filter(|Prop { prop_opts, .. }| prop_opts.can_be_omitted())
         .map(|p| prop_to_doc(p, PropDocStyle::List))
         .collect::<TokenStream>();
 
@@ -316,10 +319,21 @@ fn prop_to_doc(
             );
 
             let arg_user_docs = docs.padded();
+            let default_doc = prop_opts.default.as_ref().map(|default| {
+                let default = LitStr::new(
+                    &format!(
+                        "    - Defaults to `{}`.",
+                        default.to_token_stream()
+                    ),
+                    name.span(),
+                );
+                quote! { #[doc = #default] }
+            });
 
             quote! {
                 #[doc = #arg_ty_doc]
                 #arg_user_docs
+                #default_doc
             }
         }
         PropDocStyle::Inline => {
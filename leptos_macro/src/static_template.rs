@@ -0,0 +1,395 @@
+//! Elements of a view that are created by cloning a template in the browser.
+//!
+//! The static parts of an element, its literal attributes, text and the
+//! elements inside it, are written out as HTML at compile time. With the
+//! `csr` feature, it's parsed into a `<template>` the first time it's
+//! rendered, and then created by cloning it, which is much faster than
+//! creating each element and setting each attribute on its own.
+//!
+//! Everything else is a hole in the template, which is filled in once it has
+//! been cloned, with the same calls that would build it otherwise: the
+//! element that has a dynamic attribute, a listener, or a binding is found
+//! by its path in the clone, and is given them, and a child that is a block,
+//! a component or an element that can't be in the template is mounted in
+//! place of a comment that marks where it goes. Outside the browser, the
+//! element is built as usual.
+
+use crate::{
+    validate::{element_attributes, CLOSES_PARAGRAPH, VOID_ELEMENTS},
+    view::{child_to_tokens, element_attributes_to_tokens, TagType},
+};
+use leptos_hot_reload::parsing::{is_component_node, value_to_string};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use rstml::node::{KeyedAttribute, Node, NodeAttribute, NodeElement};
+
+/// Wraps `build`, the tokens that build `node`, so that it's created from a
+/// template in the browser, if it can be.
+pub(crate) fn static_template(
+    cx: &Ident,
+    node: &NodeElement,
+    build: TokenStream,
+) -> TokenStream {
+    if !cfg!(feature = "csr") || cfg!(feature = "hydrate") {
+        return build;
+    }
+    // a lone element gains nothing from a template
+    if node.children.is_empty() {
+        return build;
+    }
+    let Some(Template { html, holes }) = Template::new(cx, node) else {
+        return build;
+    };
+
+    let name = node.name().to_string();
+    // not visible to the tokens from the view
+    let template = Ident::new("TEMPLATE", Span::mixed_site());
+    let root = Ident::new("root", Span::mixed_site());
+    let view = Ident::new("view", Span::mixed_site());
+    let holes = holes.iter().map(|hole| hole.to_tokens(cx, &root));
+    quote! {
+        {
+            #[cfg(target_arch = "wasm32")]
+            let #view = {
+                ::std::thread_local! {
+                    static #template:
+                        ::leptos::leptos_dom::html::StaticTemplate =
+                            ::leptos::leptos_dom::html::StaticTemplate::new(
+                                #name,
+                                #html
+                            );
+                }
+                let #root = #template.with(
+                    ::leptos::leptos_dom::html::StaticTemplate::instantiate
+                );
+                #(#holes)*
+                #template.with(|template| template.view(#root))
+            };
+            #[cfg(not(target_arch = "wasm32"))]
+            let #view = #build;
+            #view
+        }
+    }
+}
+
+/// The HTML of an element, and the holes that are filled in once it has
+/// been cloned, in the order they are in the view.
+struct Template {
+    html: String,
+    holes: Vec<Hole>,
+}
+
+/// A part of a view that isn't in its template, and the path to the node
+/// that it's added to, as the index of each child node on the way there from
+/// the root of the template.
+enum Hole {
+    /// The attributes of an element that aren't static, or those that come
+    /// after its children, like bindings and directives.
+    Attributes {
+        path: Vec<u32>,
+        tag: String,
+        attrs: Vec<TokenStream>,
+    },
+    /// A child, which replaces the comment at `path`.
+    Child { path: Vec<u32>, view: TokenStream },
+}
+
+impl Template {
+    /// Writes out the template for `node`, or returns `None` if it can't be
+    /// created from one.
+    fn new(cx: &Ident, node: &NodeElement) -> Option<Self> {
+        if !in_template(node) {
+            return None;
+        }
+        let mut template = Self {
+            html: String::new(),
+            holes: Vec::new(),
+        };
+        template.element(cx, node, &mut Vec::new());
+        Some(template)
+    }
+
+    fn element(&mut self, cx: &Ident, node: &NodeElement, path: &mut Vec<u32>) {
+        let tag = node.name().to_string();
+        let is_dynamic = |attr: &NodeAttribute| match attr {
+            NodeAttribute::Attribute(attr) => !is_static(attr),
+            NodeAttribute::Block(_) => true,
+        };
+        let (attrs, after_children) =
+            element_attributes_to_tokens(cx, node, None, &is_dynamic);
+
+        self.html.push('<');
+        self.html.push_str(&tag);
+        for attr in node.attributes() {
+            let NodeAttribute::Attribute(attr) = attr else {
+                continue;
+            };
+            if !is_static(attr) {
+                continue;
+            }
+            self.html.push(' ');
+            self.html.push_str(&attr.key.to_string());
+            if let Some(value) = attr.value().and_then(value_to_string) {
+                self.html.push_str("=\"");
+                self.html.push_str(
+                    &html_escape::encode_double_quoted_attribute(&value),
+                );
+                self.html.push('"');
+            }
+        }
+        self.html.push('>');
+        if !attrs.is_empty() {
+            self.holes.push(Hole::Attributes {
+                path: path.clone(),
+                tag: tag.clone(),
+                attrs,
+            });
+        }
+
+        // the parser drops a newline right after these tags
+        if matches!(tag.as_str(), "listing" | "pre" | "textarea") {
+            self.html.push('\n');
+        }
+
+        // adjacent text is parsed into a single node, and comments aren't
+        // part of the template, so the index of each node is counted as it
+        // would be parsed
+        let mut index = 0;
+        let mut after_text = false;
+        for child in &node.children {
+            let text = match child {
+                Node::Text(text) => Some(text.value_string()),
+                Node::RawText(text) => Some(text.to_string_best()),
+                _ => None,
+            };
+            match (child, text) {
+                (Node::Comment(_) | Node::Doctype(_), _) => {}
+                // text inside a table would be moved out of it
+                (_, Some(text)) if !is_table(&tag) => {
+                    if text.is_empty() {
+                        continue;
+                    }
+                    self.html.push_str(&html_escape::encode_text(&text));
+                    if !after_text {
+                        index += 1;
+                        after_text = true;
+                    }
+                }
+                (Node::Element(child), _)
+                    if in_template(child)
+                        && stays_in_parent(&tag, &child.name().to_string()) =>
+                {
+                    path.push(index);
+                    self.element(cx, child, path);
+                    path.pop();
+                    index += 1;
+                    after_text = false;
+                }
+                (child, _) => {
+                    let (view, is_static) =
+                        child_to_tokens(cx, child, TagType::Html, None);
+                    let view = if is_static {
+                        view
+                    } else {
+                        quote! { (#cx, #[allow(unused_braces)] #view) }
+                    };
+                    self.html.push_str("<!>");
+                    let mut path = path.clone();
+                    path.push(index);
+                    self.holes.push(Hole::Child { path, view });
+                    index += 1;
+                    after_text = false;
+                }
+            }
+        }
+
+        if !VOID_ELEMENTS.contains(&tag.as_str()) {
+            self.html.push_str("</");
+            self.html.push_str(&tag);
+            self.html.push('>');
+        }
+
+        if !after_children.is_empty() {
+            self.holes.push(Hole::Attributes {
+                path: path.clone(),
+                tag,
+                attrs: after_children,
+            });
+        }
+    }
+}
+
+impl Hole {
+    fn to_tokens(&self, cx: &Ident, root: &Ident) -> TokenStream {
+        match self {
+            Hole::Attributes { path, tag, attrs } => quote! {
+                _ = ::leptos::leptos_dom::html::StaticTemplate::element(
+                    #cx,
+                    #tag,
+                    &#root,
+                    &[#(#path),*]
+                )
+                    #(#attrs)*;
+            },
+            Hole::Child { path, view } => quote! {
+                ::leptos::leptos_dom::html::StaticTemplate::mount_child(
+                    #cx,
+                    &#root,
+                    &[#(#path),*],
+                    #view
+                );
+            },
+        }
+    }
+}
+
+// Whether `node` can be written out as HTML, with holes for anything that
+// can't be: it has to be an HTML element that is parsed the same inside a
+// template, with no ref, which takes the element's own type.
+fn in_template(node: &NodeElement) -> bool {
+    if is_component_node(node) {
+        return false;
+    }
+    let tag = node.name().to_string();
+    // unknown elements might be SVG, MathML or custom elements, and these
+    // are parsed differently inside a template
+    if element_attributes(&tag).is_none()
+        || matches!(
+            tag.as_str(),
+            "body"
+                | "head"
+                | "html"
+                | "noscript"
+                | "script"
+                | "style"
+                | "template"
+        )
+    {
+        return false;
+    }
+    if VOID_ELEMENTS.contains(&tag.as_str()) && !node.children.is_empty() {
+        return false;
+    }
+    // their content is parsed as text, so it can't have holes
+    if matches!(tag.as_str(), "textarea" | "title")
+        && !node.children.iter().all(|child| {
+            matches!(child, Node::Text(_) | Node::RawText(_) | Node::Comment(_))
+        })
+    {
+        return false;
+    }
+    node.attributes().iter().all(|attr| match attr {
+        NodeAttribute::Attribute(attr) => !matches!(
+            attr.key.to_string().as_str(),
+            "ref" | "_ref" | "ref_" | "node_ref"
+        ),
+        NodeAttribute::Block(_) => true,
+    })
+}
+
+// Whether an attribute is a literal that can be written out as HTML.
+fn is_static(attr: &KeyedAttribute) -> bool {
+    let name = attr.key.to_string();
+    if name.contains(':')
+        || matches!(name.as_str(), "inner_html" | "dangerously_set_inner_html")
+    {
+        return false;
+    }
+    match attr.value() {
+        Some(value) => value_to_string(value).is_some(),
+        None => true,
+    }
+}
+
+fn is_table(tag: &str) -> bool {
+    matches!(
+        tag,
+        "table" | "tbody" | "thead" | "tfoot" | "tr" | "colgroup"
+    )
+}
+
+// Whether the parser keeps a `child` element inside a `parent`, rather than
+// closing the parent or adding another element around the child.
+fn stays_in_parent(parent: &str, child: &str) -> bool {
+    match parent {
+        "p" => !CLOSES_PARAGRAPH.contains(&child),
+        "table" => !matches!(child, "tr" | "td" | "th"),
+        "tbody" | "thead" | "tfoot" => !matches!(child, "td" | "th"),
+        "a" | "button" | "form" | "li" | "option" | "dd" | "dt" => {
+            child != parent
+                && !(matches!(parent, "dd" | "dt")
+                    && matches!(child, "dd" | "dt"))
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(tokens: TokenStream) -> Option<(String, Vec<Vec<u32>>)> {
+        let nodes = rstml::parse2(tokens).unwrap();
+        let Some(Node::Element(node)) = nodes.first() else {
+            panic!("expected an element");
+        };
+        let cx = Ident::new("cx", Span::call_site());
+        let template = Template::new(&cx, node)?;
+        let paths = template
+            .holes
+            .iter()
+            .map(|hole| match hole {
+                Hole::Attributes { path, .. } | Hole::Child { path, .. } => {
+                    path.clone()
+                }
+            })
+            .collect();
+        Some((template.html, paths))
+    }
+
+    #[test]
+    fn static_parts_are_in_the_template_and_the_rest_are_holes() {
+        let (html, paths) = template(quote! {
+            <ul class="list" aria-label=label>
+                <li data-testid="first">"One & " {count} "!"</li>
+                <li class:active=active on:click=move |_| ()>"Two"</li>
+                <Item/>
+                <svg/>
+            </ul>
+        })
+        .unwrap();
+
+        assert_eq!(
+            html,
+            "<ul class=\"list\"><li data-testid=\"first\">One &amp; \
+             <!>!</li><li>Two</li><!><!></ul>"
+        );
+        // `aria-label` on the list, `{count}`, the listener and class on
+        // the second item, and the component and SVG element
+        assert_eq!(paths, [vec![], vec![0, 1], vec![1], vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn children_are_indexed_as_they_would_be_parsed() {
+        let (html, paths) = template(quote! {
+            <div>
+                "a" <!-- "a comment" --> "b" ""
+                <br/>
+                <p>{x}</p>
+                <table>"caption" <tr><td>{y}</td></tr></table>
+            </div>
+        })
+        .unwrap();
+
+        // the text is one node, and the text and row inside the table would
+        // be moved by the parser, so they're mounted in their place
+        assert_eq!(html, "<div>ab<br><p><!></p><table><!><!></table></div>");
+        assert_eq!(paths, [vec![2, 0], vec![3, 0], vec![3, 1]]);
+    }
+
+    #[test]
+    fn elements_with_refs_are_not_templates() {
+        assert!(template(quote! { <div _ref=div_ref>"Hi"</div> }).is_none());
+        assert!(template(quote! { <svg><circle/></svg> }).is_none());
+    }
+}
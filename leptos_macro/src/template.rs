@@ -313,6 +313,14 @@ fn attr_to_tokens(
             span => leptos::leptos_dom::property(#cx, leptos::wasm_bindgen::JsCast::unchecked_ref(&#el_id), #name, #value.into_property(#cx))
         });
     }
+    // Directives
+    else if let Some(name) = name.strip_prefix("use:") {
+        let (handler, param) = crate::view::directive_parts(node, name);
+
+        expressions.push(quote_spanned! {
+            span => #handler(#cx, leptos::wasm_bindgen::JsCast::unchecked_ref::<leptos::web_sys::Element>(&#el_id).clone(), #param)
+        });
+    }
     // Classes
     else if let Some(name) = name.strip_prefix("class:") {
         let value = attribute_value(node);
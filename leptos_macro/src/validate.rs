@@ -0,0 +1,868 @@
+//! Checks the markup of a view for mistakes that the browser would silently
+//! fix up or ignore, which often shows up as a hydration mismatch.
+
+use leptos_hot_reload::parsing::{is_component_node, value_to_string};
+use rstml::node::{KeyedAttribute, Node, NodeAttribute, NodeElement};
+use std::collections::HashSet;
+use syn::spanned::Spanned;
+
+/// Emits an error for each child of a void element, each element that can't
+/// be inside a `<p>`, each repeated attribute, each attribute that isn't
+/// valid on a known HTML element, and each invalid ARIA role or attribute.
+///
+/// Attributes set with the `attr:` prefix aren't checked against the element.
+pub(crate) fn validate_markup(nodes: &[Node]) {
+    for node in nodes {
+        validate_node(node, &Context::default());
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct Context {
+    /// inside a `<p>` in this view
+    in_paragraph: bool,
+    /// inside an `<svg>` or `<math>` element
+    foreign: bool,
+}
+
+fn validate_node(node: &Node, cx: &Context) {
+    match node {
+        Node::Fragment(fragment) => {
+            for child in &fragment.children {
+                validate_node(child, cx);
+            }
+        }
+        // the children of a component are rendered wherever it puts them
+        Node::Element(el) if is_component_node(el) => {
+            for child in &el.children {
+                validate_node(child, &Context::default());
+            }
+        }
+        Node::Element(el) => validate_element(el, cx),
+        _ => {}
+    }
+}
+
+fn validate_element(el: &NodeElement, cx: &Context) {
+    let tag = el.name().to_string();
+    let foreign = cx.foreign || tag == "svg" || tag == "math";
+    let html = !foreign && !tag.contains('-');
+
+    if html && VOID_ELEMENTS.contains(&tag.as_str()) {
+        if let Some(child) = el.children.first() {
+            proc_macro_error::emit_error!(
+                child.span(),
+                "`<{}>` is a void element, so it can't have children",
+                tag
+            );
+        }
+    }
+
+    if html && cx.in_paragraph && CLOSES_PARAGRAPH.contains(&tag.as_str()) {
+        proc_macro_error::emit_error!(
+            el.name().span(),
+            "`<{}>` can't be inside a `<p>`: the browser ends the `<p>` \
+             before it, so the page won't match the view",
+            tag
+        );
+    }
+
+    let attrs = el
+        .attributes()
+        .iter()
+        .filter_map(|attr| match attr {
+            NodeAttribute::Attribute(attr) => Some(attr),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    check_duplicates(&attrs);
+    if html {
+        check_attributes(&tag, &attrs);
+        check_aria(&tag, &attrs);
+    }
+
+    let cx = Context {
+        in_paragraph: (cx.in_paragraph || tag == "p") && !foreign,
+        foreign,
+    };
+    for child in &el.children {
+        validate_node(child, &cx);
+    }
+}
+
+fn check_duplicates(attrs: &[&KeyedAttribute]) {
+    let mut seen = HashSet::new();
+    for attr in attrs {
+        let key = attr.key.to_string();
+        // several listeners and directives can be added, and
+        // `class=("name", value)` can be repeated
+        let repeatable = key.starts_with("on:")
+            || key.starts_with("use:")
+            || (matches!(key.as_str(), "class" | "style")
+                && matches!(attr.value(), Some(syn::Expr::Tuple(_))));
+        if repeatable {
+            continue;
+        }
+        let name = key.strip_prefix("attr:").unwrap_or(&key).to_string();
+        if !seen.insert(name.clone()) {
+            proc_macro_error::emit_error!(
+                attr.key.span(),
+                "the attribute `{}` is set more than once",
+                name
+            );
+        }
+    }
+}
+
+fn check_attributes(tag: &str, attrs: &[&KeyedAttribute]) {
+    let Some(allowed) = element_attributes(tag) else {
+        return;
+    };
+    for attr in attrs {
+        let name = attr.key.to_string();
+        let valid = name.contains(':')
+            || name.starts_with("data-")
+            || name.starts_with("aria-")
+            || (name.starts_with("on")
+                && name.chars().all(|c| c.is_ascii_lowercase()))
+            || LEPTOS_ATTRIBUTES.contains(&name.as_str())
+            || GLOBAL_ATTRIBUTES.contains(&name.as_str())
+            || allowed.contains(&name.as_str());
+        if !valid {
+            proc_macro_error::emit_error!(
+                attr.key.span(),
+                "`{}` isn't an attribute of `<{}>`",
+                name,
+                tag;
+                help = "to set it anyway, use `attr:{}`", name
+            );
+        }
+    }
+}
+
+fn check_aria(tag: &str, attrs: &[&KeyedAttribute]) {
+    let value_of = |name: &str| {
+        attrs
+            .iter()
+            .find(|attr| attr.key.to_string() == name)
+            .map(|attr| attr.value().and_then(value_to_string))
+    };
+
+    // the first role in the list that the browser supports is used
+    let mut role = None;
+    match value_of("role") {
+        Some(Some(roles)) => {
+            let role_attr = attrs
+                .iter()
+                .find(|attr| attr.key.to_string() == "role")
+                .expect("the role was found");
+            for name in roles.split_whitespace() {
+                if !ROLES.contains(&name) {
+                    proc_macro_error::emit_error!(
+                        role_attr.key.span(),
+                        "`{}` isn't an ARIA role",
+                        name
+                    );
+                } else if role.is_none() {
+                    role = Some(name.to_string());
+                }
+            }
+        }
+        // a dynamic role can't be checked
+        Some(None) => return,
+        None => {
+            role = match value_of("type") {
+                Some(None) => return,
+                ty => implicit_role(tag, ty.flatten().as_deref(), &value_of)
+                    .map(str::to_string),
+            }
+        }
+    }
+
+    for attr in attrs {
+        let name = attr.key.to_string();
+        if !name.starts_with("aria-") {
+            continue;
+        }
+        if GLOBAL_ARIA_ATTRIBUTES.contains(&name.as_str()) {
+            continue;
+        }
+        let Some(roles) = aria_attribute_roles(&name) else {
+            proc_macro_error::emit_error!(
+                attr.key.span(),
+                "`{}` isn't an ARIA attribute",
+                name
+            );
+            continue;
+        };
+        if let Some(role) = &role {
+            if !roles.contains(&role.as_str()) {
+                proc_macro_error::emit_error!(
+                    attr.key.span(),
+                    "`{}` isn't supported by the `{}` role of this `<{}>`",
+                    name,
+                    role,
+                    tag
+                );
+            }
+        }
+    }
+}
+
+// https://developer.mozilla.org/en-US/docs/Glossary/Void_element
+pub(crate) const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta",
+    "param", "source", "track", "wbr",
+];
+
+// the start tag of each of these closes an open `<p>`
+pub(crate) const CLOSES_PARAGRAPH: [&str; 31] = [
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "details",
+    "dialog",
+    "div",
+    "dl",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "main",
+    "menu",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "table",
+    "ul",
+];
+
+// handled by the `view` macro itself
+const LEPTOS_ATTRIBUTES: [&str; 6] = [
+    "ref",
+    "_ref",
+    "ref_",
+    "node_ref",
+    "inner_html",
+    "dangerously_set_inner_html",
+];
+
+// https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes
+const GLOBAL_ATTRIBUTES: [&str; 32] = [
+    "accesskey",
+    "anchor",
+    "autocapitalize",
+    "autocorrect",
+    "autofocus",
+    "class",
+    "contenteditable",
+    "dir",
+    "draggable",
+    "enterkeyhint",
+    "exportparts",
+    "hidden",
+    "id",
+    "inert",
+    "inputmode",
+    "is",
+    "itemid",
+    "itemprop",
+    "itemref",
+    "itemscope",
+    "itemtype",
+    "lang",
+    "nonce",
+    "part",
+    "popover",
+    "role",
+    "slot",
+    "spellcheck",
+    "style",
+    "tabindex",
+    "title",
+    "translate",
+];
+
+/// The attributes of an HTML element, besides the global ones, or `None` if
+/// the element isn't known.
+pub(crate) fn element_attributes(tag: &str) -> Option<&'static [&'static str]> {
+    Some(match tag {
+        "a" => &[
+            "download",
+            "href",
+            "hreflang",
+            "ping",
+            "referrerpolicy",
+            "rel",
+            "target",
+            "type",
+        ],
+        "area" => &[
+            "alt",
+            "coords",
+            "download",
+            "href",
+            "ping",
+            "referrerpolicy",
+            "rel",
+            "shape",
+            "target",
+        ],
+        "audio" => &[
+            "autoplay",
+            "controls",
+            "controlslist",
+            "crossorigin",
+            "disableremoteplayback",
+            "loop",
+            "muted",
+            "preload",
+            "src",
+        ],
+        "base" => &["href", "target"],
+        "blockquote" | "q" => &["cite"],
+        "button" => &[
+            "disabled",
+            "form",
+            "formaction",
+            "formenctype",
+            "formmethod",
+            "formnovalidate",
+            "formtarget",
+            "name",
+            "popovertarget",
+            "popovertargetaction",
+            "type",
+            "value",
+        ],
+        "canvas" => &["height", "width"],
+        "col" | "colgroup" => &["span"],
+        "data" => &["value"],
+        "del" | "ins" => &["cite", "datetime"],
+        "details" => &["name", "open"],
+        "dialog" => &["open"],
+        "embed" => &["height", "src", "type", "width"],
+        "fieldset" => &["disabled", "form", "name"],
+        "form" => &[
+            "accept-charset",
+            "action",
+            "autocomplete",
+            "enctype",
+            "method",
+            "name",
+            "novalidate",
+            "rel",
+            "target",
+        ],
+        "html" => &["xmlns"],
+        "iframe" => &[
+            "allow",
+            "allowfullscreen",
+            "height",
+            "loading",
+            "name",
+            "referrerpolicy",
+            "sandbox",
+            "src",
+            "srcdoc",
+            "width",
+        ],
+        "img" => &[
+            "alt",
+            "crossorigin",
+            "decoding",
+            "elementtiming",
+            "fetchpriority",
+            "height",
+            "ismap",
+            "loading",
+            "referrerpolicy",
+            "sizes",
+            "src",
+            "srcset",
+            "usemap",
+            "width",
+        ],
+        "input" => &[
+            "accept",
+            "alt",
+            "autocomplete",
+            "capture",
+            "checked",
+            "dirname",
+            "disabled",
+            "form",
+            "formaction",
+            "formenctype",
+            "formmethod",
+            "formnovalidate",
+            "formtarget",
+            "height",
+            "list",
+            "max",
+            "maxlength",
+            "min",
+            "minlength",
+            "multiple",
+            "name",
+            "pattern",
+            "placeholder",
+            "popovertarget",
+            "popovertargetaction",
+            "readonly",
+            "required",
+            "size",
+            "src",
+            "step",
+            "type",
+            "value",
+            "width",
+        ],
+        "label" | "output" => &["for", "form", "name"],
+        "li" => &["value"],
+        "link" => &[
+            "as",
+            "blocking",
+            "crossorigin",
+            "disabled",
+            "fetchpriority",
+            "href",
+            "hreflang",
+            "imagesizes",
+            "imagesrcset",
+            "integrity",
+            "media",
+            "referrerpolicy",
+            "rel",
+            "sizes",
+            "type",
+        ],
+        "map" | "slot" => &["name"],
+        // `property` is used by Open Graph
+        "meta" => &[
+            "charset",
+            "content",
+            "http-equiv",
+            "media",
+            "name",
+            "property",
+        ],
+        "meter" => &["high", "low", "max", "min", "optimum", "value"],
+        "object" => &["data", "form", "height", "name", "type", "width"],
+        "ol" => &["reversed", "start", "type"],
+        "optgroup" => &["disabled", "label"],
+        "option" => &["disabled", "label", "selected", "value"],
+        "progress" => &["max", "value"],
+        "script" => &[
+            "async",
+            "blocking",
+            "crossorigin",
+            "defer",
+            "fetchpriority",
+            "integrity",
+            "nomodule",
+            "referrerpolicy",
+            "src",
+            "type",
+        ],
+        "select" => &[
+            "autocomplete",
+            "disabled",
+            "form",
+            "multiple",
+            "name",
+            "required",
+            "size",
+        ],
+        "source" => {
+            &["height", "media", "sizes", "src", "srcset", "type", "width"]
+        }
+        "style" => &["blocking", "media"],
+        "td" => &["colspan", "headers", "rowspan"],
+        "template" => &[
+            "shadowrootclonable",
+            "shadowrootdelegatesfocus",
+            "shadowrootmode",
+        ],
+        "textarea" => &[
+            "autocomplete",
+            "cols",
+            "dirname",
+            "disabled",
+            "form",
+            "maxlength",
+            "minlength",
+            "name",
+            "placeholder",
+            "readonly",
+            "required",
+            "rows",
+            "wrap",
+        ],
+        "th" => &["abbr", "colspan", "headers", "rowspan", "scope"],
+        "time" => &["datetime"],
+        "track" => &["default", "kind", "label", "src", "srclang"],
+        "video" => &[
+            "autoplay",
+            "controls",
+            "controlslist",
+            "crossorigin",
+            "disablepictureinpicture",
+            "disableremoteplayback",
+            "height",
+            "loop",
+            "muted",
+            "playsinline",
+            "poster",
+            "preload",
+            "src",
+            "width",
+        ],
+        "abbr" | "address" | "article" | "aside" | "b" | "bdi" | "bdo"
+        | "body" | "br" | "caption" | "cite" | "code" | "datalist" | "dd"
+        | "dfn" | "div" | "dl" | "dt" | "em" | "figcaption" | "figure"
+        | "footer" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "head"
+        | "header" | "hgroup" | "hr" | "i" | "kbd" | "legend" | "main"
+        | "mark" | "menu" | "nav" | "noscript" | "p" | "picture" | "pre"
+        | "rp" | "rt" | "ruby" | "s" | "samp" | "search" | "section"
+        | "small" | "span" | "strong" | "sub" | "summary" | "sup" | "table"
+        | "tbody" | "tfoot" | "thead" | "title" | "tr" | "u" | "ul" | "var"
+        | "wbr" => &[],
+        _ => return None,
+    })
+}
+
+// https://www.w3.org/TR/wai-aria-1.2/#role_definitions
+const ROLES: [&str; 83] = [
+    "alert",
+    "alertdialog",
+    "application",
+    "article",
+    "banner",
+    "blockquote",
+    "button",
+    "caption",
+    "cell",
+    "checkbox",
+    "code",
+    "columnheader",
+    "combobox",
+    "complementary",
+    "contentinfo",
+    "definition",
+    "deletion",
+    "dialog",
+    "directory",
+    "document",
+    "emphasis",
+    "feed",
+    "figure",
+    "form",
+    "generic",
+    "grid",
+    "gridcell",
+    "group",
+    "heading",
+    "img",
+    "insertion",
+    "link",
+    "list",
+    "listbox",
+    "listitem",
+    "log",
+    "main",
+    "mark",
+    "marquee",
+    "math",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "meter",
+    "navigation",
+    "none",
+    "note",
+    "option",
+    "paragraph",
+    "presentation",
+    "progressbar",
+    "radio",
+    "radiogroup",
+    "region",
+    "row",
+    "rowgroup",
+    "rowheader",
+    "scrollbar",
+    "search",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "status",
+    "strong",
+    "subscript",
+    "superscript",
+    "switch",
+    "tab",
+    "table",
+    "tablist",
+    "tabpanel",
+    "term",
+    "textbox",
+    "time",
+    "timer",
+    "toolbar",
+    "tooltip",
+    "tree",
+    "treegrid",
+    "treeitem",
+];
+
+// https://www.w3.org/TR/wai-aria-1.2/#global_states
+const GLOBAL_ARIA_ATTRIBUTES: [&str; 24] = [
+    "aria-atomic",
+    "aria-braillelabel",
+    "aria-brailleroledescription",
+    "aria-busy",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-description",
+    "aria-details",
+    "aria-disabled",
+    "aria-dropeffect",
+    "aria-errormessage",
+    "aria-flowto",
+    "aria-grabbed",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-live",
+    "aria-owns",
+    "aria-relevant",
+    "aria-roledescription",
+];
+
+/// The roles that support an ARIA attribute that isn't global, or `None` if
+/// it isn't an ARIA attribute.
+fn aria_attribute_roles(name: &str) -> Option<&'static [&'static str]> {
+    const RANGES: &[&str] = &[
+        "meter",
+        "progressbar",
+        "scrollbar",
+        "separator",
+        "slider",
+        "spinbutton",
+    ];
+    const CELLS: &[&str] =
+        &["cell", "columnheader", "gridcell", "row", "rowheader"];
+    const SET_ITEMS: &[&str] = &[
+        "article",
+        "listitem",
+        "menuitem",
+        "menuitemcheckbox",
+        "menuitemradio",
+        "option",
+        "radio",
+        "row",
+        "tab",
+        "treeitem",
+    ];
+
+    Some(match name {
+        "aria-activedescendant" => &[
+            "application",
+            "combobox",
+            "grid",
+            "group",
+            "listbox",
+            "menu",
+            "menubar",
+            "radiogroup",
+            "searchbox",
+            "spinbutton",
+            "tablist",
+            "textbox",
+            "toolbar",
+            "tree",
+            "treegrid",
+        ],
+        "aria-autocomplete" => &["combobox", "searchbox", "textbox"],
+        "aria-checked" => &[
+            "checkbox",
+            "menuitemcheckbox",
+            "menuitemradio",
+            "option",
+            "radio",
+            "switch",
+            "treeitem",
+        ],
+        "aria-colcount" | "aria-rowcount" => &["grid", "table", "treegrid"],
+        "aria-colindex" | "aria-colindextext" | "aria-colspan"
+        | "aria-rowindex" | "aria-rowindextext" | "aria-rowspan" => CELLS,
+        "aria-expanded" => &[
+            "application",
+            "button",
+            "checkbox",
+            "columnheader",
+            "combobox",
+            "gridcell",
+            "link",
+            "listbox",
+            "menuitem",
+            "menuitemcheckbox",
+            "menuitemradio",
+            "row",
+            "rowheader",
+            "switch",
+            "tab",
+            "treeitem",
+        ],
+        "aria-level" => &["heading", "listitem", "row", "treeitem"],
+        "aria-modal" => &["alertdialog", "dialog"],
+        "aria-multiline" | "aria-placeholder" => &["searchbox", "textbox"],
+        "aria-multiselectable" => {
+            &["grid", "listbox", "tablist", "tree", "treegrid"]
+        }
+        "aria-orientation" => &[
+            "listbox",
+            "menu",
+            "menubar",
+            "radiogroup",
+            "scrollbar",
+            "separator",
+            "slider",
+            "tablist",
+            "toolbar",
+            "tree",
+            "treegrid",
+        ],
+        "aria-posinset" | "aria-setsize" => SET_ITEMS,
+        "aria-pressed" => &["button"],
+        "aria-readonly" => &[
+            "checkbox",
+            "columnheader",
+            "combobox",
+            "grid",
+            "gridcell",
+            "listbox",
+            "menuitemcheckbox",
+            "menuitemradio",
+            "radiogroup",
+            "rowheader",
+            "searchbox",
+            "slider",
+            "spinbutton",
+            "switch",
+            "textbox",
+            "treegrid",
+        ],
+        "aria-required" => &[
+            "checkbox",
+            "columnheader",
+            "combobox",
+            "gridcell",
+            "listbox",
+            "radiogroup",
+            "rowheader",
+            "searchbox",
+            "spinbutton",
+            "textbox",
+            "tree",
+            "treegrid",
+        ],
+        "aria-selected" => &[
+            "columnheader",
+            "gridcell",
+            "option",
+            "row",
+            "rowheader",
+            "tab",
+            "treeitem",
+        ],
+        "aria-sort" => &["columnheader", "rowheader"],
+        "aria-valuemax" | "aria-valuemin" | "aria-valuenow"
+        | "aria-valuetext" => RANGES,
+        _ => return None,
+    })
+}
+
+/// The role an HTML element has without a `role` attribute, if it is known.
+///
+/// `value_of` returns the value of an attribute: `Some(None)` if it is set
+/// dynamically.
+fn implicit_role(
+    tag: &str,
+    ty: Option<&str>,
+    value_of: &dyn Fn(&str) -> Option<Option<String>>,
+) -> Option<&'static str> {
+    Some(match tag {
+        "a" | "area" if value_of("href").is_some() => "link",
+        "article" => "article",
+        "aside" => "complementary",
+        "button" | "summary" => "button",
+        "datalist" => "listbox",
+        "details" | "fieldset" | "optgroup" => "group",
+        "dialog" => "dialog",
+        "figure" => "figure",
+        "footer" => "contentinfo",
+        "form" => "form",
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+        "header" => "banner",
+        "hr" => "separator",
+        "img" => "img",
+        "input" => match ty.unwrap_or("text") {
+            "button" | "image" | "reset" | "submit" => "button",
+            "checkbox" => "checkbox",
+            "number" => "spinbutton",
+            "radio" => "radio",
+            "range" => "slider",
+            "search" if value_of("list").is_none() => "searchbox",
+            "email" | "tel" | "text" | "url" if value_of("list").is_none() => {
+                "textbox"
+            }
+            "email" | "search" | "tel" | "text" | "url" => "combobox",
+            _ => return None,
+        },
+        "li" => "listitem",
+        "main" => "main",
+        "menu" | "ol" | "ul" => "list",
+        "meter" => "meter",
+        "nav" => "navigation",
+        "option" => "option",
+        "output" => "status",
+        "p" => "paragraph",
+        "progress" => "progressbar",
+        "section" => "region",
+        "select" => match (value_of("multiple"), value_of("size")) {
+            (None, None) => "combobox",
+            _ => return None,
+        },
+        "table" => "table",
+        "tbody" | "tfoot" | "thead" => "rowgroup",
+        "td" => "cell",
+        "textarea" => "textbox",
+        "th" => "columnheader",
+        "tr" => "row",
+        "b" | "bdi" | "bdo" | "div" | "i" | "pre" | "q" | "samp" | "small"
+        | "span" | "u" => "generic",
+        _ => return None,
+    })
+}
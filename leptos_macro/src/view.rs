@@ -9,11 +9,11 @@ use quote::{format_ident, quote, quote_spanned};
 use rstml::node::{
     KeyedAttribute, Node, NodeAttribute, NodeBlock, NodeElement, NodeName,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use syn::{spanned::Spanned, Expr, ExprLit, ExprPath, Lit};
 
 #[derive(Clone, Copy)]
-enum TagType {
+pub(crate) enum TagType {
     Unknown,
     Html,
     Svg,
@@ -156,6 +156,9 @@ pub(crate) fn render_view(
     global_class: Option<&TokenTree>,
     call_site: Option<String>,
 ) -> TokenStream {
+    check_ids_and_landmarks(nodes);
+    crate::validate::validate_markup(nodes);
+
     let empty = {
         let span = Span::call_site();
         quote_spanned! {
@@ -204,6 +207,60 @@ pub(crate) fn render_view(
     }
 }
 
+/// Emits an error for each element in the view that repeats a static `id`
+/// used by an earlier element, and for each `<main>` after the first, since
+/// both break ARIA relationships.
+///
+/// Only elements that are always rendered together are checked, so the children
+/// of components, which may be rendered conditionally or more than once, are skipped.
+fn check_ids_and_landmarks(nodes: &[Node]) {
+    fn check(nodes: &[Node], ids: &mut HashSet<String>, main: &mut bool) {
+        for node in nodes {
+            match node {
+                Node::Fragment(fragment) => {
+                    check(&fragment.children, ids, main)
+                }
+                Node::Element(node) if !is_component_node(node) => {
+                    if node.name().to_string() == "main" {
+                        if *main {
+                            proc_macro_error::emit_error!(
+                                node.name().span(),
+                                "a view should only contain one `<main>` landmark"
+                            );
+                        }
+                        *main = true;
+                    }
+
+                    for attr in node.attributes() {
+                        let NodeAttribute::Attribute(attr) = attr else {
+                            continue;
+                        };
+                        if attr.key.to_string() != "id" {
+                            continue;
+                        }
+                        let Some(id) = attr.value().and_then(value_to_string)
+                        else {
+                            continue;
+                        };
+                        if !ids.insert(id.clone()) {
+                            proc_macro_error::emit_error!(
+                                attr.key.span(),
+                                "`id=\"{}\"` is used by more than one element in this view",
+                                id
+                            );
+                        }
+                    }
+
+                    check(&node.children, ids, main);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    check(nodes, &mut HashSet::new(), &mut false);
+}
+
 fn root_node_to_tokens_ssr(
     cx: &Ident,
     node: &Node,
@@ -310,16 +367,29 @@ fn root_element_to_tokens_ssr(
             chunks.push(SsrElementChunks::String { template, holes })
         }
 
+        // text runs can add any number of chunks, so if there are any, every
+        // chunk is extended onto a `Vec` rather than put in an array
+        let has_text_runs = chunks
+            .iter()
+            .any(|chunk| matches!(chunk, SsrElementChunks::Views(_)));
+        let single = |chunk: TokenStream| {
+            if has_text_runs {
+                quote! { [#chunk] }
+            } else {
+                chunk
+            }
+        };
+
         let chunks = chunks.into_iter().map(|chunk| match chunk {
             SsrElementChunks::String { template, holes } => {
                 if holes.is_empty() {
                     let template = template.replace("\\{", "{").replace("\\}", "}");
-                    quote! {
+                    single(quote! {
                         leptos::leptos_dom::html::StringOrView::String(#template.into())
-                    }
+                    })
                 } else {
                 let template = template.replace("\\{", "{{").replace("\\}", "}}");
-                    quote! {
+                    single(quote! {
                         leptos::leptos_dom::html::StringOrView::String(
                             format!(
                                 #template,
@@ -327,19 +397,37 @@ fn root_element_to_tokens_ssr(
                             )
                             .into()
                         )
-                    }
+                    })
                 }
             }
             SsrElementChunks::View(view) => {
-                quote! {
+                single(quote! {
                     #[allow(unused_braces)]
                     {
                         let view = #view;
                         leptos::leptos_dom::html::StringOrView::View(std::rc::Rc::new(move || view.clone()))
                     }
+                })
+            },
+            SsrElementChunks::Views(views) => {
+                quote! {
+                    #views.into_iter().map(|view| {
+                        leptos::leptos_dom::html::StringOrView::View(std::rc::Rc::new(move || view.clone()))
+                    })
                 }
             },
         });
+        let chunks = if has_text_runs {
+            quote! {
+                {
+                    let mut chunks = Vec::new();
+                    #(chunks.extend(#chunks);)*
+                    chunks
+                }
+            }
+        } else {
+            quote! { [#(#chunks),*] }
+        };
 
         let tag_name = node.name().to_string();
         let is_custom_element = is_custom_element(&tag_name);
@@ -381,7 +469,7 @@ fn root_element_to_tokens_ssr(
         {
             #(#stmts_for_ide)*
             #(#exprs_for_compiler)*
-            ::leptos::HtmlElement::from_chunks(#cx, #full_name, [#(#chunks),*])#view_marker
+            ::leptos::HtmlElement::from_chunks(#cx, #full_name, #chunks)#view_marker
         }
         })
     }
@@ -393,6 +481,8 @@ enum SsrElementChunks {
         holes: Vec<TokenStream>,
     },
     View(TokenStream),
+    // a `Vec<View>`, each of which is its own chunk
+    Views(TokenStream),
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -443,14 +533,33 @@ fn element_to_tokens_ssr(
 
         for attr in node.attributes() {
             if let NodeAttribute::Attribute(attr) = attr {
-                inner_html = attribute_to_tokens_ssr(
+                if let Some(html) = attribute_to_tokens_ssr(
                     cx,
+                    node,
                     attr,
                     template,
                     holes,
                     exprs_for_compiler,
                     global_class,
-                );
+                ) {
+                    inner_html = Some(html);
+                }
+            } else if let Some(attrs) = spread_attribute(attr) {
+                template.push_str("{}");
+                holes.push(quote! {
+                    leptos::leptos_dom::SpreadAttributes::from(#attrs).to_html(#cx)
+                });
+            }
+        }
+
+        let mut bound_text = None;
+        for attr in node.attributes() {
+            if let NodeAttribute::Attribute(attr) = attr {
+                if let Some(text) =
+                    bind_attribute_to_tokens_ssr(node, attr, template, holes)
+                {
+                    bound_text = Some(text);
+                }
             }
         }
 
@@ -482,15 +591,37 @@ fn element_to_tokens_ssr(
         } else {
             template.push('>');
 
-            if let Some(inner_html) = inner_html {
+            if let Some((value, sanitize)) = inner_html {
                 template.push_str("{}");
-                let value = inner_html;
 
-                holes.push(quote! {
+                let html = quote! {
                   (#value).into_attribute(#cx).as_nameless_value_string().unwrap_or_default()
+                };
+                holes.push(if sanitize {
+                    quote! { leptos::leptos_dom::sanitize_html(#html) }
+                } else {
+                    html
                 })
+            } else if let Some(text) = bound_text {
+                template.push_str("{}");
+                holes.push(text);
             } else {
-                for child in &node.children {
+                for child in group_text_runs(&node.children) {
+                    let child = match child {
+                        ElementChild::Node(child) => child,
+                        ElementChild::TextRun(run) => {
+                            if !template.is_empty() {
+                                chunks.push(SsrElementChunks::String {
+                                    template: std::mem::take(template),
+                                    holes: std::mem::take(holes),
+                                })
+                            }
+                            chunks.push(SsrElementChunks::Views(
+                                text_run_to_tokens(cx, run),
+                            ));
+                            continue;
+                        }
+                    };
                     match child {
                         Node::Element(child) => {
                             element_to_tokens_ssr(
@@ -557,18 +688,25 @@ fn element_to_tokens_ssr(
     }
 }
 
-// returns `inner_html`
+// returns `inner_html`, and whether it should be sanitized
 fn attribute_to_tokens_ssr<'a>(
     cx: &Ident,
+    node: &NodeElement,
     attr: &'a KeyedAttribute,
     template: &mut String,
     holes: &mut Vec<TokenStream>,
     exprs_for_compiler: &mut Vec<TokenStream>,
     global_class: Option<&TokenTree>,
-) -> Option<&'a syn::Expr> {
+) -> Option<(&'a syn::Expr, bool)> {
     let name = attr.key.to_string();
     if name == "ref" || name == "_ref" || name == "ref_" || name == "node_ref" {
-        // ignore refs on SSR
+        // refs are never loaded on SSR, but are still type-checked
+        if let Some(element) = element_fn_path_ssr(node) {
+            let node_ref = attribute_value(attr);
+            exprs_for_compiler.push(quote! {
+                leptos::leptos_dom::helpers::ssr_node_ref(#element, #node_ref);
+            })
+        }
     } else if let Some(name) = name.strip_prefix("on:") {
         let handler = attribute_value(attr);
         let (event_type, _, _) = parse_event_name(name);
@@ -576,14 +714,28 @@ fn attribute_to_tokens_ssr<'a>(
         exprs_for_compiler.push(quote! {
             leptos::leptos_dom::helpers::ssr_event_listener(::leptos::ev::#event_type, #handler);
         })
-    } else if name.strip_prefix("prop:").is_some()
-        || name.strip_prefix("class:").is_some()
+    } else if let Some(name) = name.strip_prefix("use:") {
+        let (handler, param) = directive_parts(attr, name);
+
+        exprs_for_compiler.push(quote! {
+            leptos::leptos_dom::helpers::ssr_directive(#handler, #[allow(unused_braces)] #param);
+        })
+    } else if name.starts_with("prop:") {
+        // props aren't rendered to HTML, but are still type-checked
+        let value = attribute_value(attr);
+        exprs_for_compiler.push(quote! {
+            leptos::leptos_dom::helpers::ssr_property(#[allow(unused_braces)] #value);
+        })
+    } else if name.strip_prefix("class:").is_some()
         || name.strip_prefix("style:").is_some()
+        || name.strip_prefix("bind:").is_some()
     {
-        // ignore props for SSR
         // ignore classes and sdtyles: we'll handle these separately
+        // bindings are rendered once all the attributes are known
     } else if name == "inner_html" {
-        return attr.value();
+        return attr.value().map(|value| (value, true));
+    } else if name == "dangerously_set_inner_html" {
+        return attr.value().map(|value| (value, false));
     } else {
         let name = name.replacen("attr:", "", 1);
 
@@ -625,6 +777,96 @@ fn attribute_to_tokens_ssr<'a>(
     None
 }
 
+// the path to the function that creates `node` in the browser, if its
+// namespace is known without the parent element
+fn element_fn_path_ssr(node: &NodeElement) -> Option<TokenStream> {
+    let NodeName::Path(path) = node.name() else {
+        return None;
+    };
+    let name = path.path.get_ident()?;
+    let tag = name.to_string();
+    if is_custom_element(&tag) || is_ambiguous_element(&tag) {
+        None
+    } else if is_svg_element(&tag) {
+        Some(quote! { leptos::leptos_dom::svg::#name })
+    } else if is_math_ml_element(&tag) {
+        Some(quote! { leptos::leptos_dom::math::#name })
+    } else {
+        Some(quote! { leptos::leptos_dom::html::#name })
+    }
+}
+
+// renders a `bind:` attribute, returning the text content it gives a `<textarea>`
+fn bind_attribute_to_tokens_ssr(
+    node: &NodeElement,
+    attr: &KeyedAttribute,
+    template: &mut String,
+    holes: &mut Vec<TokenStream>,
+) -> Option<TokenStream> {
+    let name = attr.key.to_string();
+    let name = name.strip_prefix("bind:")?;
+    bind_method(attr, name);
+    let signal = attribute_value(attr);
+    let tag_name = node.name().to_string();
+    match name {
+        "value" if tag_name == "textarea" => {
+            return Some(quote! {
+                leptos::leptos_dom::ssr::escape_attr(
+                    &leptos::SignalGetUntracked::get_untracked(&#signal)
+                )
+            });
+        }
+        // the selected option is set when the page is hydrated
+        "value" if tag_name == "select" => {}
+        "value" => {
+            template.push_str(" value=\"{}\"");
+            holes.push(quote! {
+                leptos::leptos_dom::ssr::escape_attr(
+                    &leptos::SignalGetUntracked::get_untracked(&#signal)
+                )
+            });
+        }
+        "checked" => {
+            template.push_str("{}");
+            holes.push(quote! {
+                if leptos::SignalGetUntracked::get_untracked(&#signal) {
+                    " checked"
+                } else {
+                    ""
+                }
+            });
+        }
+        _ => {
+            let value = node.attributes().iter().find_map(|attr| match attr {
+                NodeAttribute::Attribute(attr)
+                    if attr.key.to_string() == "value" =>
+                {
+                    attr.value()
+                }
+                _ => None,
+            });
+            let Some(value) = value else {
+                abort!(
+                    attr.key.span(),
+                    "`bind:group` needs the input to have a `value`"
+                );
+            };
+            template.push_str("{}");
+            holes.push(quote! {
+                if leptos::leptos_dom::BindGroup::is_checked(
+                    &#signal,
+                    &::std::string::ToString::to_string(&#value),
+                ) {
+                    " checked"
+                } else {
+                    ""
+                }
+            });
+        }
+    }
+    None
+}
+
 fn set_class_attribute_ssr(
     cx: &Ident,
     node: &NodeElement,
@@ -735,7 +977,7 @@ fn set_class_attribute_ssr(
             if let Some(value) = value {
                 template.push_str(" {}");
                 holes.push(quote! {
-                  &(#cx, #value).into_attribute(#cx).as_nameless_value_string()
+                  &(#cx, #value).into_class_attribute(#cx).as_nameless_value_string(#cx)
                     .map(|a| leptos::leptos_dom::ssr::escape_attr(&a).to_string())
                     .unwrap_or_default()
                 });
@@ -848,7 +1090,7 @@ fn set_style_attribute_ssr(
             if let Some(value) = value {
                 template.push_str(" {};");
                 holes.push(quote! {
-                  &(#cx, #value).into_attribute(#cx).as_nameless_value_string()
+                  &(#cx, #value).into_style_attribute(#cx).as_nameless_value_string(#cx)
                     .map(|a| leptos::leptos_dom::ssr::escape_attr(&a).to_string())
                     .unwrap_or_default()
                 });
@@ -883,7 +1125,7 @@ fn fragment_to_tokens(
     let mut nodes = nodes
         .iter()
         .filter_map(|node| {
-            let node = node_to_tokens(
+            let tokens = node_to_tokens(
                 cx,
                 node,
                 parent_type,
@@ -891,6 +1133,12 @@ fn fragment_to_tokens(
                 global_class,
                 None,
             )?;
+            let node = match node {
+                Node::Element(node) => {
+                    child_template(cx, node, parent_type, global_class, tokens)
+                }
+                _ => tokens,
+            };
 
             Some(quote! {
                 #node.into_view(#cx)
@@ -1048,51 +1296,8 @@ fn element_to_tokens(
             ide_helper_close_tag.save_tag_completion(close_tag)
         }
 
-        let attrs = node.attributes().iter().filter_map(|node| {
-            if let NodeAttribute::Attribute(node) = node {
-                let name = node.key.to_string();
-                let name = name.trim();
-                if name.starts_with("class:")
-                    || fancy_class_name(name, cx, node).is_some()
-                    || name.starts_with("style:")
-                    || fancy_style_name(name, cx, node).is_some()
-                {
-                    None
-                } else {
-                    Some(attribute_to_tokens(cx, node, global_class))
-                }
-            } else {
-                None
-            }
-        });
-        let class_attrs = node.attributes().iter().filter_map(|node| {
-            if let NodeAttribute::Attribute(node) = node {
-                let name = node.key.to_string();
-                if let Some((fancy, _, _)) = fancy_class_name(&name, cx, node) {
-                    Some(fancy)
-                } else if name.trim().starts_with("class:") {
-                    Some(attribute_to_tokens(cx, node, global_class))
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        });
-        let style_attrs = node.attributes().iter().filter_map(|node| {
-            if let NodeAttribute::Attribute(node) = node {
-                let name = node.key.to_string();
-                if let Some((fancy, _, _)) = fancy_style_name(&name, cx, node) {
-                    Some(fancy)
-                } else if name.trim().starts_with("style:") {
-                    Some(attribute_to_tokens(cx, node, global_class))
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        });
+        let (attrs, after_children) =
+            element_attributes_to_tokens(cx, node, global_class, &|_| true);
         let global_class_expr = match global_class {
             None => quote! {},
             Some(class) => {
@@ -1104,53 +1309,19 @@ fn element_to_tokens(
                 }
             }
         };
-        let children = node.children.iter().map(|node| {
-            let (child, is_static) = match node {
-                Node::Fragment(fragment) => (
-                    fragment_to_tokens(
-                        cx,
-                        Span::call_site(),
-                        &fragment.children,
-                        true,
-                        parent_type,
-                        None,
-                        global_class,
-                        None,
-                    )
-                    .unwrap_or({
-                        let span = Span::call_site();
-                        quote_spanned! {
-                            span => leptos::leptos_dom::Unit
-                        }
-                    }),
-                    false,
-                ),
-                Node::Text(node) => (quote! { #node }, true),
-                Node::RawText(node) => {
-                    let text = node.to_string_best();
-                    let text = syn::LitStr::new(&text, node.span());
-                    (quote! { #text }, true)
+        let children = group_text_runs(&node.children);
+        let children = children.into_iter().map(|child| {
+            let node = match child {
+                ElementChild::Node(node) => node,
+                ElementChild::TextRun(run) => {
+                    let views = text_run_to_tokens(cx, run);
+                    return quote! {
+                        .child_views(#views)
+                    };
                 }
-                Node::Block(node) => (
-                    quote! {
-                       #node
-                    },
-                    false,
-                ),
-                Node::Element(node) => (
-                    element_to_tokens(
-                        cx,
-                        node,
-                        parent_type,
-                        None,
-                        global_class,
-                        None,
-                    )
-                    .unwrap_or_default(),
-                    false,
-                ),
-                Node::Comment(_) | Node::Doctype(_) => (quote! {}, false),
             };
+            let (child, is_static) =
+                child_to_tokens(cx, node, parent_type, global_class);
             if is_static {
                 quote! {
                     .child(#child)
@@ -1172,16 +1343,243 @@ fn element_to_tokens(
             #(#ide_helper_close_tag)*
             #name
                 #(#attrs)*
-                #(#class_attrs)*
-                #(#style_attrs)*
                 #global_class_expr
                 #(#children)*
+                #(#after_children)*
                 #view_marker
             }
         })
     }
 }
 
+// The calls that add the attributes of an element that are `include`d, other
+// than a global class: those that come before its children, and those that
+// come after them.
+pub(crate) fn element_attributes_to_tokens(
+    cx: &Ident,
+    node: &NodeElement,
+    global_class: Option<&TokenTree>,
+    include: &dyn Fn(&NodeAttribute) -> bool,
+) -> (Vec<TokenStream>, Vec<TokenStream>) {
+    let attrs = node.attributes().iter().filter(|attr| include(attr)).filter_map(|node| {
+        if let NodeAttribute::Attribute(node) = node {
+            let name = node.key.to_string();
+            let name = name.trim();
+            if name.starts_with("class:")
+                || fancy_class_name(name, cx, node).is_some()
+                || name.starts_with("style:")
+                || fancy_style_name(name, cx, node).is_some()
+                || name.starts_with("bind:")
+                || name.starts_with("use:")
+            {
+                None
+            } else {
+                Some(attribute_to_tokens(cx, node, global_class))
+            }
+        } else {
+            spread_attribute(node).map(|attrs| {
+                quote! {
+                    .spread(#attrs)
+                }
+            })
+        }
+    });
+    let class_attrs = node.attributes().iter().filter(|attr| include(attr)).filter_map(|node| {
+        if let NodeAttribute::Attribute(node) = node {
+            let name = node.key.to_string();
+            if let Some((fancy, _, _)) = fancy_class_name(&name, cx, node) {
+                Some(fancy)
+            } else if name.trim().starts_with("class:") {
+                Some(attribute_to_tokens(cx, node, global_class))
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    });
+    let style_attrs = node.attributes().iter().filter(|attr| include(attr)).filter_map(|node| {
+        if let NodeAttribute::Attribute(node) = node {
+            let name = node.key.to_string();
+            if let Some((fancy, _, _)) = fancy_style_name(&name, cx, node) {
+                Some(fancy)
+            } else if name.trim().starts_with("style:") {
+                Some(attribute_to_tokens(cx, node, global_class))
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    });
+    // a `<select>` can only take a value once its options have been
+    // added, so bindings come after the children
+    let bind_attrs = node.attributes().iter().filter(|attr| include(attr)).filter_map(|node| {
+        if let NodeAttribute::Attribute(node) = node {
+            let name = node.key.to_string();
+            name.trim()
+                .starts_with("bind:")
+                .then(|| attribute_to_tokens(cx, node, global_class))
+        } else {
+            None
+        }
+    });
+    // directives are given the element once its children are there
+    let directives = node.attributes().iter().filter(|attr| include(attr)).filter_map(|node| {
+        if let NodeAttribute::Attribute(node) = node {
+            let name = node.key.to_string();
+            name.trim()
+                .starts_with("use:")
+                .then(|| attribute_to_tokens(cx, node, global_class))
+        } else {
+            None
+        }
+    });
+
+    let before_children = attrs.chain(class_attrs).chain(style_attrs).collect();
+    let after_children = bind_attrs.chain(directives).collect();
+    (before_children, after_children)
+}
+
+// The view for a child of an element, and whether it's static text.
+pub(crate) fn child_to_tokens(
+    cx: &Ident,
+    node: &Node,
+    parent_type: TagType,
+    global_class: Option<&TokenTree>,
+) -> (TokenStream, bool) {
+    match node {
+        Node::Fragment(fragment) => (
+            fragment_to_tokens(
+                cx,
+                Span::call_site(),
+                &fragment.children,
+                true,
+                parent_type,
+                None,
+                global_class,
+                None,
+            )
+            .unwrap_or({
+                let span = Span::call_site();
+                quote_spanned! {
+                    span => leptos::leptos_dom::Unit
+                }
+            }),
+            false,
+        ),
+        Node::Text(node) => (quote! { #node }, true),
+        Node::RawText(node) => {
+            let text = node.to_string_best();
+            let text = syn::LitStr::new(&text, node.span());
+            (quote! { #text }, true)
+        }
+        Node::Block(node) => (
+            quote! {
+               #node
+            },
+            false,
+        ),
+        Node::Element(node) => (
+            child_template(
+                cx,
+                node,
+                parent_type,
+                global_class,
+                element_to_tokens(
+                    cx,
+                    node,
+                    parent_type,
+                    None,
+                    global_class,
+                    None,
+                )
+                .unwrap_or_default(),
+            ),
+            false,
+        ),
+        Node::Comment(_) | Node::Doctype(_) => (quote! {}, false),
+    }
+}
+
+// Creates a static element that is inside another element or a fragment
+// from a template. The root of a view isn't, so that it keeps its type.
+fn child_template(
+    cx: &Ident,
+    node: &NodeElement,
+    parent_type: TagType,
+    global_class: Option<&TokenTree>,
+    tokens: TokenStream,
+) -> TokenStream {
+    if global_class.is_some()
+        || matches!(parent_type, TagType::Svg | TagType::Math)
+    {
+        tokens
+    } else {
+        crate::static_template::static_template(cx, node, tokens)
+    }
+}
+
+/// The most items a run of adjacent text and blocks can have to be merged
+/// into a single text node.
+const MAX_TEXT_RUN_LEN: usize = 12;
+
+enum ElementChild<'a> {
+    Node(&'a Node),
+    // adjacent text and blocks, including at least two blocks
+    TextRun(&'a [Node]),
+}
+
+// Groups the children of an element into runs of text and blocks that may
+// be merged into one text node, if every block turns out to be text.
+fn group_text_runs(children: &[Node]) -> Vec<ElementChild<'_>> {
+    let is_text_or_block = |node: &Node| {
+        matches!(node, Node::Text(_) | Node::Block(NodeBlock::ValidBlock(_)))
+    };
+
+    let mut grouped = Vec::with_capacity(children.len());
+    let mut rest = children;
+    while let Some(first) = rest.first() {
+        let len = rest
+            .iter()
+            .take_while(|node| is_text_or_block(node))
+            .count();
+        if len == 0 {
+            grouped.push(ElementChild::Node(first));
+            rest = &rest[1..];
+            continue;
+        }
+
+        let (run, remaining) = rest.split_at(len);
+        let blocks = run
+            .iter()
+            .filter(|node| matches!(node, Node::Block(_)))
+            .count();
+        if blocks >= 2 && len <= MAX_TEXT_RUN_LEN {
+            grouped.push(ElementChild::TextRun(run));
+        } else {
+            grouped.extend(run.iter().map(ElementChild::Node));
+        }
+        rest = remaining;
+    }
+    grouped
+}
+
+// Expands to a `Vec<View>` holding either a single text node for the whole run,
+// or one view per item if some block is not text.
+fn text_run_to_tokens(cx: &Ident, run: &[Node]) -> TokenStream {
+    let items = run.iter().map(|node| quote! { #node });
+    quote! {
+        {
+            #[allow(unused_imports)]
+            use leptos::leptos_dom::{MergedTextKind as _, SeparateViewsKind as _};
+            #[allow(unused_braces)]
+            let run = leptos::leptos_dom::TextRun((#(#items,)*));
+            (&run).kind().into_views(#cx, run)
+        }
+    }
+}
+
 fn attribute_to_tokens(
     cx: &Ident,
     node: &KeyedAttribute,
@@ -1199,8 +1597,7 @@ fn attribute_to_tokens(
     } else if let Some(name) = name.strip_prefix("on:") {
         let handler = attribute_value(node);
 
-        let (event_type, is_custom, is_force_undelegated) =
-            parse_event_name(name);
+        let (event_type, is_custom, modifiers) = parse_event_name(name);
 
         let event_name_ident = match &node.key {
             NodeName::Punctuated(parts) => {
@@ -1213,13 +1610,9 @@ fn attribute_to_tokens(
             _ => unreachable!(),
         };
         let undelegated_ident = match &node.key {
-            NodeName::Punctuated(parts) => parts.last().and_then(|last| {
-                if last == "undelegated" {
-                    Some(last)
-                } else {
-                    None
-                }
-            }),
+            NodeName::Punctuated(parts) => {
+                parts.iter().skip(2).find(|part| *part == "undelegated")
+            }
             _ => unreachable!(),
         };
         let on = match &node.key {
@@ -1243,7 +1636,7 @@ fn attribute_to_tokens(
             event_type
         };
 
-        let event_type = if is_force_undelegated {
+        let event_type = if modifiers.undelegated {
             let undelegated = if let Some(undelegated) = undelegated_ident {
                 let span = undelegated.span();
                 quote_spanned! {
@@ -1256,6 +1649,8 @@ fn attribute_to_tokens(
         } else {
             quote! { ::leptos::ev::#event_type }
         };
+        let event_type =
+            modifiers.with_options(event_type, quote! { ::leptos::ev });
 
         quote! {
             #on(#event_type, #handler)
@@ -1275,6 +1670,17 @@ fn attribute_to_tokens(
         quote! {
             #prop(#name, (#cx, #[allow(unused_braces)] #value))
         }
+    } else if let Some(name) = name.strip_prefix("bind:") {
+        let value = attribute_value(node);
+        let method = bind_method(node, name);
+        quote! {
+            .#method(#value)
+        }
+    } else if let Some(name) = name.strip_prefix("use:") {
+        let (handler, param) = directive_parts(node, name);
+        quote! {
+            .directive(#handler, #[allow(unused_braces)] #param)
+        }
     } else if let Some(name) = name.strip_prefix("class:") {
         let value = attribute_value(node);
         let class = match &node.key {
@@ -1336,6 +1742,22 @@ fn attribute_to_tokens(
             NodeName::Punctuated(parts) => Some(&parts[0]),
             _ => None,
         };
+
+        // a dynamic `class` can be a `ClassList`, which toggles each class
+        // separately, and a dynamic `style` a `StyleList`
+        if (name == "class" || name == "style")
+            && node.value().and_then(value_to_string).is_none()
+        {
+            let method = if name == "class" {
+                quote_spanned! { span => .class_attribute }
+            } else {
+                quote_spanned! { span => .style_attribute }
+            };
+            return quote! {
+                #method((#cx, #value))
+            };
+        }
+
         let attr = if let Some(attr) = attr {
             let span = attr.span();
             quote_spanned! {
@@ -1352,8 +1774,72 @@ fn attribute_to_tokens(
     }
 }
 
-pub(crate) fn parse_event_name(name: &str) -> (TokenStream, bool, bool) {
-    let (name, is_force_undelegated) = parse_event(name);
+// the expression in a `{..attrs}` attribute, which spreads a collection of
+// attributes onto an element
+fn spread_attribute(attr: &NodeAttribute) -> Option<&Expr> {
+    let NodeAttribute::Block(block) = attr else {
+        return None;
+    };
+    if let NodeBlock::ValidBlock(block) = block {
+        if let [syn::Stmt::Expr(Expr::Range(range), None)] =
+            block.stmts.as_slice()
+        {
+            if let (None, syn::RangeLimits::HalfOpen(_), Some(attrs)) =
+                (&range.start, &range.limits, &range.end)
+            {
+                return Some(attrs);
+            }
+        }
+    }
+    abort!(
+        block,
+        "blocks can only be used as attributes to spread attributes onto \
+         an element";
+        help = "use `{..attrs}`, where `attrs` can be converted into \
+                `SpreadAttributes`"
+    )
+}
+
+// the directive function and its parameter for a `use:` attribute, which is
+// given `()` if it has no value
+pub(crate) fn directive_parts(
+    attr: &KeyedAttribute,
+    name: &str,
+) -> (Ident, TokenStream) {
+    let handler = match syn::parse_str::<Ident>(name) {
+        Ok(handler) => Ident::new(&handler.to_string(), attr.key.span()),
+        Err(_) => abort!(
+            attr.key,
+            "`use:{}` should name a directive function", name;
+            help = "directives are written like `use:my_directive=param`"
+        ),
+    };
+    let param = match attr.value() {
+        Some(value) => quote! { #value },
+        None => quote! { () },
+    };
+    (handler, param)
+}
+
+// the `HtmlElement` method that sets up a `bind:` attribute
+fn bind_method(attr: &KeyedAttribute, name: &str) -> Ident {
+    let method = match name {
+        "value" => "bind_value",
+        "checked" => "bind_checked",
+        "group" => "bind_group",
+        _ => abort!(
+            attr.key.span(),
+            "unknown binding `bind:{}`", name;
+            help = "elements support `bind:value`, `bind:checked` and `bind:group`"
+        ),
+    };
+    Ident::new(method, attr.key.span())
+}
+
+pub(crate) fn parse_event_name(
+    name: &str,
+) -> (TokenStream, bool, EventModifiers) {
+    let (name, modifiers) = parse_event(name);
 
     let event_type = TYPED_EVENTS
         .iter()
@@ -1371,7 +1857,7 @@ pub(crate) fn parse_event_name(name: &str) -> (TokenStream, bool, bool) {
     } else {
         event_type
     };
-    (event_type, is_custom, is_force_undelegated)
+    (event_type, is_custom, modifiers)
 }
 
 pub(crate) fn slot_to_tokens(
@@ -1399,7 +1885,7 @@ pub(crate) fn slot_to_tokens(
 
     let attrs = node.attributes().iter().filter_map(|node| {
         if let NodeAttribute::Attribute(node) = node {
-            if is_slot(node) {
+            if is_slot(node) || is_generics(node) {
                 None
             } else {
                 Some(node)
@@ -1412,7 +1898,7 @@ pub(crate) fn slot_to_tokens(
     let props = attrs
         .clone()
         .filter(|attr| {
-            !attr.key.to_string().starts_with("bind:")
+            binding_from_attribute(attr).is_none()
                 && !attr.key.to_string().starts_with("clone:")
         })
         .map(|attr| {
@@ -1432,12 +1918,7 @@ pub(crate) fn slot_to_tokens(
 
     let items_to_bind = attrs
         .clone()
-        .filter_map(|attr| {
-            attr.key
-                .to_string()
-                .strip_prefix("bind:")
-                .map(|ident| format_ident!("{ident}", span = attr.key.span()))
-        })
+        .filter_map(binding_from_attribute)
         .collect::<Vec<_>>();
 
     let items_to_clone = attrs
@@ -1505,7 +1986,7 @@ pub(crate) fn slot_to_tokens(
     };
 
     let slots = slots.drain().map(|(slot, values)| {
-        let slot = Ident::new(&slot, span);
+        let slot = slot_ident(&slot, span);
         if values.len() > 1 {
             quote! {
                 .#slot([
@@ -1518,12 +1999,18 @@ pub(crate) fn slot_to_tokens(
         }
     });
 
+    // a missing required prop is reported on the name of the slot
+    let build = quote_spanned! { span => .build() };
+    let path = match generic_path(node) {
+        Some(path) => quote! { #path },
+        None => quote! { #component_name },
+    };
     let slot = quote! {
-        #component_name::builder()
+        #path::builder()
             #(#props)*
             #(#slots)*
             #children
-            .build()
+            #build
             .into(),
     };
 
@@ -1553,9 +2040,10 @@ pub(crate) fn component_to_tokens(
     let props = attrs
         .clone()
         .filter(|attr| {
-            !attr.key.to_string().starts_with("bind:")
+            binding_from_attribute(attr).is_none()
                 && !attr.key.to_string().starts_with("clone:")
                 && !attr.key.to_string().starts_with("on:")
+                && !is_generics(attr)
         })
         .map(|attr| {
             let name = &attr.key;
@@ -1574,12 +2062,7 @@ pub(crate) fn component_to_tokens(
 
     let items_to_bind = attrs
         .clone()
-        .filter_map(|attr| {
-            attr.key
-                .to_string()
-                .strip_prefix("bind:")
-                .map(|ident| format_ident!("{ident}", span = attr.key.span()))
-        })
+        .filter_map(binding_from_attribute)
         .collect::<Vec<_>>();
 
     let items_to_clone = attrs
@@ -1658,7 +2141,7 @@ pub(crate) fn component_to_tokens(
     };
 
     let slots = slots.drain().map(|(slot, values)| {
-        let slot = Ident::new(&slot, span);
+        let slot = slot_ident(&slot, span);
         if values.len() > 1 {
             quote! {
                 .#slot(vec![
@@ -1671,6 +2154,12 @@ pub(crate) fn component_to_tokens(
         }
     });
 
+    // a missing required prop is reported on the name of the component
+    let build = quote_spanned! { span => .build() };
+    let name = match generic_path(node) {
+        Some(path) => quote! { #path },
+        None => quote! { #name },
+    };
     let mut component = quote! {
         ::leptos::component_view(
             &#name,
@@ -1679,7 +2168,7 @@ pub(crate) fn component_to_tokens(
                 #(#props)*
                 #(#slots)*
                 #children
-                .build()
+                #build
         )
     };
 
@@ -1709,8 +2198,7 @@ pub(crate) fn event_from_attribute_node(
 
     let handler = attribute_value(attr);
 
-    #[allow(unused_variables)]
-    let (name, name_undelegated) = parse_event(&event_name);
+    let (name, modifiers) = parse_event(&event_name);
 
     let event_type = TYPED_EVENTS
         .iter()
@@ -1722,11 +2210,13 @@ pub(crate) fn event_from_attribute_node(
         abort!(attr.key, "couldn't parse event name");
     };
 
-    let event_type = if force_undelegated || name_undelegated {
+    let event_type = if force_undelegated || modifiers.undelegated {
         quote! { ::leptos::leptos_dom::ev::undelegated(::leptos::leptos_dom::ev::#event_type) }
     } else {
         quote! { ::leptos::leptos_dom::ev::#event_type }
     };
+    let event_type =
+        modifiers.with_options(event_type, quote! { ::leptos::leptos_dom::ev });
     (event_type, handler)
 }
 
@@ -1768,12 +2258,43 @@ fn expr_to_ident(expr: &syn::Expr) -> Option<&ExprPath> {
     }
 }
 
+/// The name given to the data that a component passes into its children
+/// with `let:name` (or the older `bind:name`).
+fn binding_from_attribute(attr: &KeyedAttribute) -> Option<Ident> {
+    let key = attr.key.to_string();
+    key.strip_prefix("let:")
+        .or_else(|| key.strip_prefix("bind:"))
+        .map(|ident| format_ident!("{ident}", span = attr.key.span()))
+}
+
 fn is_slot(node: &KeyedAttribute) -> bool {
     let key = node.key.to_string();
     let key = key.trim();
     key == "slot" || key.starts_with("slot:")
 }
 
+// the attribute that the generic arguments of a component's tag are moved to
+fn is_generics(attr: &KeyedAttribute) -> bool {
+    attr.key.to_string() == crate::generics::GENERICS_ATTRIBUTE
+}
+
+// the path to a component or slot with the generic arguments of its tag,
+// like `TypedSelect::<String>`
+fn generic_path(node: &NodeElement) -> Option<&Expr> {
+    node.attributes().iter().find_map(|attr| match attr {
+        NodeAttribute::Attribute(attr) if is_generics(attr) => {
+            match attr.value()? {
+                Expr::Block(block) => match block.block.stmts.as_slice() {
+                    [syn::Stmt::Expr(path, None)] => Some(path),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
+
 fn get_slot(node: &NodeElement) -> Option<&KeyedAttribute> {
     node.attributes().iter().find_map(|node| {
         if let NodeAttribute::Attribute(node) = node {
@@ -1788,6 +2309,16 @@ fn get_slot(node: &NodeElement) -> Option<&KeyedAttribute> {
     })
 }
 
+// A slot named after a keyword, like `<Match slot>`, is passed to the prop
+// `r#match`.
+fn slot_ident(slot: &str, span: Span) -> Ident {
+    if syn::parse_str::<Ident>(slot).is_ok() {
+        Ident::new(slot, span)
+    } else {
+        Ident::new_raw(slot, span)
+    }
+}
+
 fn convert_to_snake_case(name: String) -> String {
     if !name.is_case(Snake) {
         name.to_case(Snake)
@@ -1948,11 +2479,49 @@ fn is_ambiguous_element(tag: &str) -> bool {
     tag == "a" || tag == "script" || tag == "title"
 }
 
-fn parse_event(event_name: &str) -> (&str, bool) {
-    if let Some(event_name) = event_name.strip_suffix(":undelegated") {
-        (event_name, true)
-    } else {
-        (event_name, false)
+/// The modifiers that can follow an event name, like `on:click:capture`.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct EventModifiers {
+    undelegated: bool,
+    capture: bool,
+    passive: bool,
+}
+
+impl EventModifiers {
+    // wraps the event type in the listener options it needs, if any
+    fn with_options(
+        self,
+        event_type: TokenStream,
+        ev: TokenStream,
+    ) -> TokenStream {
+        let event_type = if self.capture {
+            quote! { #ev::capture(#event_type) }
+        } else {
+            event_type
+        };
+        if self.passive {
+            quote! { #ev::passive(#event_type) }
+        } else {
+            event_type
+        }
+    }
+}
+
+fn parse_event(mut event_name: &str) -> (&str, EventModifiers) {
+    let mut modifiers = EventModifiers::default();
+    loop {
+        if let Some(name) = event_name.strip_suffix(":undelegated") {
+            modifiers.undelegated = true;
+            event_name = name;
+        } else if let Some(name) = event_name.strip_suffix(":capture") {
+            modifiers.capture = true;
+            event_name = name;
+        } else if let Some(name) = event_name.strip_suffix(":passive") {
+            modifiers.passive = true;
+            event_name = name;
+        } else {
+            return (event_name, modifiers);
+        }
     }
 }
 
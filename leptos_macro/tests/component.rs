@@ -26,3 +26,178 @@ fn component() {
     assert_eq!(cp.default, NonZeroUsize::new(10).unwrap());
     assert_eq!(cp.into, "");
 }
+
+// Collects the messages emitted on this thread at the given level while `f` runs.
+fn capture_diagnostics(
+    level: DiagnosticLevel,
+    f: impl FnOnce(),
+) -> Vec<String> {
+    use std::{cell::RefCell, rc::Rc};
+
+    let messages = Rc::new(RefCell::new(Vec::new()));
+    set_diagnostics_hook(Some(Rc::new({
+        let messages = Rc::clone(&messages);
+        move |emitted, message: &str| {
+            if emitted == level {
+                messages.borrow_mut().push(message.to_string());
+            }
+        }
+    })));
+    f();
+    set_diagnostics_hook(None);
+    Rc::try_unwrap(messages).unwrap().into_inner()
+}
+
+#[component(budget_ms = 1000)]
+fn Budgeted(cx: Scope, label: &'static str) -> impl IntoView {
+    view! { cx, <p>{label}</p> }
+}
+
+#[component(transparent, budget_ms = 0.5)]
+fn BudgetedTransparent(_cx: Scope) -> impl IntoView {
+    std::thread::sleep(std::time::Duration::from_millis(5));
+}
+
+#[test]
+fn component_with_budget() {
+    let warnings = capture_diagnostics(DiagnosticLevel::Warn, || {
+        run_scope(create_runtime(), |cx| {
+            _ = view! { cx, <Budgeted label="hi"/><BudgetedTransparent/> };
+        });
+    });
+
+    assert_eq!(warnings.len(), 1, "{warnings:?}");
+    assert!(warnings[0].starts_with(
+        "[PerformanceBudget] render of <BudgetedTransparent/> took"
+    ));
+    assert!(warnings[0].ends_with("over its budget of 0.5ms"));
+}
+
+#[component(budget_ms = 4)]
+fn BudgetedParent(cx: Scope, count: ReadSignal<u32>) -> impl IntoView {
+    view! { cx, <SlowEffect count=count/> }
+}
+
+#[component]
+fn SlowEffect(cx: Scope, count: ReadSignal<u32>) -> impl IntoView {
+    create_isomorphic_effect(cx, move |_| {
+        if count.get() > 0 {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    });
+}
+
+#[test]
+fn component_with_budget_times_effects() {
+    let warnings = capture_diagnostics(DiagnosticLevel::Warn, || {
+        run_scope(create_runtime(), |cx| {
+            let (count, set_count) = create_signal(cx, 0);
+            _ = view! { cx, <BudgetedParent count=count/> };
+            set_count.set(1);
+        });
+    });
+
+    // the child has no budget of its own, so its effect counts against the
+    // parent's
+    assert_eq!(warnings.len(), 1, "{warnings:?}");
+    assert!(warnings[0]
+        .starts_with("[PerformanceBudget] effect of <BudgetedParent/> took"));
+    assert!(warnings[0].ends_with("over its budget of 4ms"));
+}
+
+#[component(trace_props)]
+fn Traced(
+    cx: Scope,
+    count: Signal<i32>,
+    #[prop(into)] label: MaybeSignal<String>,
+    items: Vec<i32>,
+) -> impl IntoView {
+    view! { cx, <p>{label} ": " {count} {items.len()}</p> }
+}
+
+#[test]
+fn component_with_traced_props() {
+    run_scope(create_runtime(), |cx| {
+        let (count, set_count) = create_signal(cx, 0);
+        let (label, _) = create_signal(cx, "count".to_string());
+        _ = view! { cx,
+            <Traced count=count.into() label=label items=vec![1, 2]/>
+            <Traced count=Signal::derive(cx, move || count.get() * 2) label="static" items=vec![]/>
+        };
+        set_count.set(1);
+    });
+}
+
+#[test]
+fn memo_component_reuses_html() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+    #[component(memo)]
+    fn Memoized(cx: Scope, label: &'static str) -> impl IntoView {
+        RENDERS.fetch_add(1, Ordering::Relaxed);
+        view! { cx, <p>{label}</p> }
+    }
+
+    let page = |label| {
+        leptos::ssr::render_to_string(move |cx| {
+            view! { cx, <main><Memoized label=label/></main> }
+        })
+    };
+
+    let first = page("hi");
+    assert_eq!(page("hi"), first);
+    assert_eq!(RENDERS.load(Ordering::Relaxed), 1);
+    assert!(first.contains("hi</p>"));
+    assert!(!first.contains("<leptos-memo"));
+
+    assert!(page("bye").contains("bye</p>"));
+    assert_eq!(RENDERS.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn memo_component_takes_owned_props() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+    #[component(memo)]
+    fn List(cx: Scope, title: String, items: Vec<u32>) -> impl IntoView {
+        RENDERS.fetch_add(1, Ordering::Relaxed);
+        view! { cx,
+            <h2>{title}</h2>
+            <ul>{items.into_iter().map(|n| view! { cx, <li>{n}</li> }).collect_view(cx)}</ul>
+        }
+    }
+
+    let page = |items: Vec<u32>| {
+        leptos::ssr::render_to_string(move |cx| {
+            view! { cx, <main><List title="Items".to_string() items=items/></main> }
+        })
+    };
+
+    let first = page(vec![1, 2]);
+    assert_eq!(page(vec![1, 2]), first);
+    assert_eq!(RENDERS.load(Ordering::Relaxed), 1);
+
+    assert!(page(vec![3]).contains("3</li>"));
+    assert_eq!(RENDERS.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn deferred_hydration_records_hydration_keys() {
+    #[component(hydrate = "visible")]
+    fn Deferred(cx: Scope, label: &'static str) -> impl IntoView {
+        view! { cx, <p>{label}</p> }
+    }
+
+    let html = leptos::ssr::render_to_string(|cx| {
+        view! { cx, <main><Deferred label="hi"/></main> }
+    });
+
+    assert!(html.contains("<leptos-hydrate"));
+    assert!(html.contains("data-hk-start="));
+    assert!(html.contains("data-hk-end="));
+    assert!(html.contains("hi</p>"));
+}
@@ -3,4 +3,5 @@ fn ui() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/ui/component.rs");
     t.compile_fail("tests/ui/component_absolute.rs");
+    t.compile_fail("tests/ui/view.rs");
 }
@@ -0,0 +1,31 @@
+use leptos::*;
+
+fn duplicate_ids(cx: Scope) -> impl IntoView {
+    view! { cx,
+        <div>
+            <label for="name">"Name"</label>
+            <input id="name"/>
+            <p id="name">"Enter your name"</p>
+        </div>
+    }
+}
+
+fn multiple_main_landmarks(cx: Scope) -> impl IntoView {
+    view! { cx,
+        <main>"One"</main>
+        <main>"Two"</main>
+    }
+}
+
+fn invalid_markup(cx: Scope) -> impl IntoView {
+    view! { cx,
+        <p>"Intro" <div>"Block"</div></p>
+        <br>"Text"</br>
+        <input type="text" value="a" value="b"/>
+        <div colspan="2" attr:x-data="{}"/>
+        <div role="buton"/>
+        <button aria-checked="true">"Save"</button>
+    }
+}
+
+fn main() {}
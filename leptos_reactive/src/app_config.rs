@@ -0,0 +1,146 @@
+#![forbid(unsafe_code)]
+use crate::{
+    provide_context, runtime::with_runtime, use_context, Scope, Serializable,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Wraps the config in context, so it doesn't clash with other contexts of the same type.
+#[derive(Clone)]
+struct ProvidedConfig<T>(T);
+
+/// Makes a typed app config available to this [`Scope`] and its descendants through
+/// [`use_config`].
+///
+/// On the server, the config is also serialized into the page, so that it can be read
+/// with [`use_config`] while hydrating in the browser. Any field wrapped in [`ServerOnly`]
+/// is left out, so secrets like database credentials never reach the client.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// #[derive(Clone, serde::Serialize, serde::Deserialize)]
+/// struct AppConfig {
+///     api_url: String,
+///     database_url: ServerOnly<String>,
+/// }
+///
+/// # run_scope(create_runtime(), |cx| {
+/// provide_config(
+///     cx,
+///     AppConfig {
+///         api_url: "https://api.example.com".into(),
+///         database_url: ServerOnly::new("postgres://localhost/app".into()),
+///     },
+/// );
+///
+/// let config = use_config::<AppConfig>(cx).unwrap();
+/// assert_eq!(config.api_url, "https://api.example.com");
+/// # });
+/// ```
+pub fn provide_config<T>(cx: Scope, config: T)
+where
+    T: Serializable + Clone + 'static,
+{
+    #[cfg(feature = "ssr")]
+    match config.ser() {
+        Ok(serialized) => {
+            _ = with_runtime(cx.runtime, |runtime| {
+                runtime.shared_context.borrow_mut().config = Some(serialized);
+            });
+        }
+        Err(e) => crate::console_warn(&format!(
+            "could not serialize the config passed to provide_config: {e}"
+        )),
+    }
+    provide_context(cx, ProvidedConfig(config));
+}
+
+/// Returns the app config of type `T` that was provided with [`provide_config`].
+///
+/// While hydrating in the browser, this returns the client-safe part of the config that
+/// was serialized into the page by the server, so the same code can read the config on
+/// either side. Fields wrapped in [`ServerOnly`] are empty in the browser.
+///
+/// Returns `None` if no config of this type has been provided.
+pub fn use_config<T>(cx: Scope) -> Option<T>
+where
+    T: Serializable + Clone + 'static,
+{
+    if let Some(ProvidedConfig(config)) = use_context::<ProvidedConfig<T>>(cx) {
+        return Some(config);
+    }
+
+    let serialized = with_runtime(cx.runtime, |runtime| {
+        runtime.shared_context.borrow().config.clone()
+    })
+    .ok()
+    .flatten()?;
+    let config = T::de(&serialized).ok()?;
+    // provide it at the root, so it's only deserialized once
+    let root = cx.ancestry().last().copied().unwrap_or(cx.id);
+    provide_context(
+        Scope {
+            runtime: cx.runtime,
+            id: root,
+        },
+        ProvidedConfig(config.clone()),
+    );
+    Some(config)
+}
+
+/// A part of an app config that is only available on the server.
+///
+/// When the config is sent to the browser by [`provide_config`], this is serialized as
+/// `null`, so it deserializes to an empty value in the browser. It is deserialized as
+/// usual from any other source, like a config file or environment variables.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ServerOnly<T>(Option<T>);
+
+impl<T> ServerOnly<T> {
+    /// Wraps a server-only value.
+    pub fn new(value: T) -> Self {
+        Self(Some(value))
+    }
+
+    /// Returns the value on the server, or `None` in the browser.
+    pub fn get(&self) -> Option<&T> {
+        self.0.as_ref()
+    }
+
+    /// Returns the value on the server, or `None` in the browser.
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> Serialize for ServerOnly<T> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_none()
+    }
+}
+
+impl<'de, T> Deserialize<'de> for ServerOnly<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        Option::<T>::deserialize(deserializer).map(Self)
+    }
+}
+
+impl Scope {
+    /// The serialized config that was provided with [`provide_config`], which should be
+    /// sent to the browser along with the server-rendered HTML.
+    #[doc(hidden)]
+    pub fn serialized_config(&self) -> Option<String> {
+        with_runtime(self.runtime, |runtime| {
+            runtime.shared_context.borrow().config.clone()
+        })
+        .ok()
+        .flatten()
+    }
+}
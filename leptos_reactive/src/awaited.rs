@@ -0,0 +1,152 @@
+#![forbid(unsafe_code)]
+use crate::{
+    create_local_resource, create_resource, serialization::Serializable,
+    ReadSignal, Resource, Scope, SignalGet,
+};
+use futures::future::{pending, Either, Pending};
+use std::{cell::RefCell, future::Future};
+
+/// Runs a [`Future`] once and exposes its output as a signal, which is `None`
+/// until the `Future` has resolved.
+///
+/// This is for one-shot `async` reads that will never need to be refetched. It
+/// integrates with `<Suspense/>` and server-side rendering just like a
+/// [`Resource`] created with [`create_resource`]: reading it under a
+/// `<Suspense/>` holds the suspense open until it has resolved, and its output
+/// is serialized into the HTML so that the `Future` does not have to run
+/// again when the page is hydrated.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// async fn load_config() -> String {
+///   // pretend we're reading a config file
+///   "dark".to_string()
+/// }
+///
+/// # if false {
+/// let theme = create_await(cx, load_config());
+///
+/// // this will be `None` until the config has loaded
+/// theme.get();
+/// # }
+/// # }).dispose();
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
+    instrument(
+        level = "info",
+        skip_all,
+        fields(
+            scope = ?cx.id,
+            ty = %std::any::type_name::<T>(),
+        )
+    )
+)]
+pub fn create_await<T, Fu>(cx: Scope, future: Fu) -> Awaited<T>
+where
+    T: Serializable + 'static,
+    Fu: Future<Output = T> + 'static,
+{
+    Awaited {
+        cx,
+        resource: create_resource(cx, || (), run_once(future)),
+    }
+}
+
+/// Runs a [`Future`] once and exposes its output as a signal, which is `None`
+/// until the `Future` has resolved. See [`create_await`] for details.
+///
+/// Unlike [`create_await()`], this [`Future`] will always run on the local
+/// system and therefore its output type does not need to be [`Serializable`].
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
+    instrument(
+        level = "info",
+        skip_all,
+        fields(
+            scope = ?cx.id,
+            ty = %std::any::type_name::<T>(),
+        )
+    )
+)]
+pub fn create_local_await<T, Fu>(cx: Scope, future: Fu) -> Awaited<T>
+where
+    T: 'static,
+    Fu: Future<Output = T> + 'static,
+{
+    Awaited {
+        cx,
+        resource: create_local_resource(cx, || (), run_once(future)),
+    }
+}
+
+// The source of the resource never changes, so the fetcher only runs once (or
+// not at all, if its value was serialized from the server).
+fn run_once<T, Fu>(future: Fu) -> impl Fn(()) -> Either<Fu, Pending<T>>
+where
+    Fu: Future<Output = T>,
+{
+    let future = RefCell::new(Some(future));
+    move |_| match future.take() {
+        Some(future) => Either::Left(future),
+        None => Either::Right(pending()),
+    }
+}
+
+/// The output of a [`Future`] that is run once, created with [`create_await`]
+/// or [`create_local_await`].
+///
+/// Reading it with [`get`](SignalGet::get) or [`Awaited::with`] subscribes the
+/// running effect to it, and registers it with the nearest `<Suspense/>` above
+/// the scope it was created in.
+pub struct Awaited<T>
+where
+    T: 'static,
+{
+    cx: Scope,
+    resource: Resource<(), T>,
+}
+
+impl<T> Awaited<T> {
+    /// Applies a function to the output of the [`Future`], and subscribes the
+    /// running effect to it. If it hasn't resolved yet, the function won't be
+    /// called and this will return [`Option::None`].
+    #[track_caller]
+    pub fn with<U>(&self, f: impl FnOnce(&T) -> U) -> Option<U> {
+        self.resource.with(self.cx, f)
+    }
+
+    /// Returns a signal that indicates whether the [`Future`] is still running.
+    pub fn loading(&self) -> ReadSignal<bool> {
+        self.resource.loading()
+    }
+}
+
+impl<T: Clone> SignalGet<Option<T>> for Awaited<T> {
+    #[track_caller]
+    fn get(&self) -> Option<T> {
+        self.resource.read(self.cx)
+    }
+
+    #[track_caller]
+    fn try_get(&self) -> Option<Option<T>> {
+        Some(self.get())
+    }
+}
+
+impl<T> Clone for Awaited<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Awaited<T> {}
+
+impl<T> std::fmt::Debug for Awaited<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Awaited")
+            .field("resource", &self.resource.id)
+            .finish()
+    }
+}
@@ -1,7 +1,14 @@
 #![forbid(unsafe_code)]
 
-use crate::{runtime::with_runtime, Scope};
-use std::any::{Any, TypeId};
+use crate::{
+    create_rw_signal, runtime::with_runtime, RwSignal, Scope, Signal, SignalSet,
+};
+use rustc_hash::FxHashMap;
+use std::{
+    any::{Any, TypeId},
+    fmt,
+    marker::PhantomData,
+};
 
 /// Provides a context value of type `T` to the current reactive [`Scope`](crate::Scope)
 /// and all of its descendants. This can be consumed using [`use_context`](crate::use_context).
@@ -46,7 +53,7 @@ use std::any::{Any, TypeId};
 /// }
 /// ```
 #[cfg_attr(
-    any(debug_assertions, feature = "ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(level = "info", skip_all,)
 )]
 pub fn provide_context<T>(cx: Scope, value: T)
@@ -55,6 +62,20 @@ where
 {
     let id = value.type_id();
 
+    #[cfg(debug_assertions)]
+    warn_if_shadowed(
+        cx,
+        id,
+        |_| true,
+        || {
+            format!(
+            "context of type `{}`. If they are unrelated, consider using a \
+             `ContextKey`.",
+            std::any::type_name::<T>()
+        )
+        },
+    );
+
     _ = with_runtime(cx.runtime, |runtime| {
         let mut contexts = runtime.scope_contexts.borrow_mut();
         let context = contexts.entry(cx.id).unwrap().or_default();
@@ -108,7 +129,7 @@ where
 /// }
 /// ```
 #[cfg_attr(
-    any(debug_assertions, feature = "ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(level = "info", skip_all,)
 )]
 pub fn use_context<T>(cx: Scope) -> Option<T>
@@ -203,3 +224,266 @@ where
         )
     })
 }
+
+/// A key for a context value of type `T`.
+///
+/// Contexts provided with [`provide_context`] are identified only by their type, so two
+/// libraries that each provide a `String` would overwrite one another. A context provided
+/// with [`provide_keyed_context`] is identified by its key as well, so any number of them can
+/// be provided with the same type. Keys are compared by name, so a library should include its
+/// own name in the names of its keys.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// const API_TOKEN: ContextKey<String> = ContextKey::new("my_api_client::token");
+/// const USER_NAME: ContextKey<String> = ContextKey::new("my_auth::user_name");
+///
+/// # run_scope(create_runtime(), |cx| {
+/// provide_keyed_context(cx, API_TOKEN, "secret".to_string());
+/// provide_keyed_context(cx, USER_NAME, "Ferris".to_string());
+///
+/// assert_eq!(use_keyed_context(cx, API_TOKEN).as_deref(), Some("secret"));
+/// assert_eq!(use_keyed_context(cx, USER_NAME).as_deref(), Some("Ferris"));
+/// // keyed contexts don't clash with a context of the same type
+/// assert_eq!(use_context::<String>(cx), None);
+/// # });
+/// ```
+pub struct ContextKey<T: 'static> {
+    name: &'static str,
+    ty: PhantomData<fn() -> T>,
+}
+
+impl<T> ContextKey<T> {
+    /// Creates a key with the given name.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            ty: PhantomData,
+        }
+    }
+
+    /// The name of the key.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<T> Clone for ContextKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ContextKey<T> {}
+
+impl<T> fmt::Debug for ContextKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ContextKey").field(&self.name).finish()
+    }
+}
+
+// The keyed contexts of type `T` provided in a single scope.
+struct KeyedContexts<T>(FxHashMap<&'static str, T>);
+
+/// Provides a context value of type `T`, identified by a [`ContextKey`], to the current
+/// reactive [`Scope`](crate::Scope) and all of its descendants. This can be consumed using
+/// [`use_keyed_context`].
+pub fn provide_keyed_context<T>(cx: Scope, key: ContextKey<T>, value: T)
+where
+    T: Clone + 'static,
+{
+    let id = TypeId::of::<KeyedContexts<T>>();
+
+    #[cfg(debug_assertions)]
+    warn_if_shadowed(
+        cx,
+        id,
+        |keyed| {
+            keyed
+                .downcast_ref::<KeyedContexts<T>>()
+                .map(|keyed| keyed.0.contains_key(key.name))
+                .unwrap_or(false)
+        },
+        || format!("context with the key {:?}.", key.name),
+    );
+
+    _ = with_runtime(cx.runtime, |runtime| {
+        let mut contexts = runtime.scope_contexts.borrow_mut();
+        let context = contexts.entry(cx.id).unwrap().or_default();
+        if let Some(keyed) = context
+            .entry(id)
+            .or_insert_with(|| Box::new(KeyedContexts::<T>(Default::default())))
+            .downcast_mut::<KeyedContexts<T>>()
+        {
+            keyed.0.insert(key.name, value);
+        }
+    });
+}
+
+/// Extracts a context value of type `T`, identified by a [`ContextKey`], from the reactive
+/// system by traversing it upwards, beginning from the current [`Scope`](crate::Scope). The
+/// context value should have been provided elsewhere using [`provide_keyed_context`].
+pub fn use_keyed_context<T>(cx: Scope, key: ContextKey<T>) -> Option<T>
+where
+    T: Clone + 'static,
+{
+    let id = TypeId::of::<KeyedContexts<T>>();
+    let ancestry = cx.ancestry();
+    with_runtime(cx.runtime, |runtime| {
+        let contexts = runtime.scope_contexts.borrow();
+        ancestry.into_iter().find_map(|scope| {
+            contexts
+                .get(scope)?
+                .get(&id)?
+                .downcast_ref::<KeyedContexts<T>>()?
+                .0
+                .get(key.name)
+                .cloned()
+        })
+    })
+    .ok()
+    .flatten()
+}
+
+#[cfg(debug_assertions)]
+thread_local! {
+    static WARN_ON_SHADOWING: std::cell::Cell<bool> =
+        const { std::cell::Cell::new(false) };
+}
+
+/// Turns on warnings whenever [`provide_context`] or [`provide_keyed_context`] overwrites a
+/// context that was already provided in the same scope, or shadows one provided by a parent
+/// scope. This can help to track down a context that has the wrong value, because a library
+/// or another part of the app provided a value of the same type.
+///
+/// Warnings are off by default. This is a no-op in release mode.
+pub fn set_context_shadowing_warnings(enabled: bool) {
+    #[cfg(debug_assertions)]
+    WARN_ON_SHADOWING.with(|warn| warn.set(enabled));
+    #[cfg(not(debug_assertions))]
+    {
+        _ = enabled;
+    }
+}
+
+#[cfg(debug_assertions)]
+fn warn_if_shadowed(
+    cx: Scope,
+    id: TypeId,
+    is_provided: impl Fn(&dyn Any) -> bool,
+    describe: impl FnOnce() -> String,
+) {
+    if !WARN_ON_SHADOWING.with(|warn| warn.get()) {
+        return;
+    }
+    let ancestry = cx.ancestry();
+    let depth = with_runtime(cx.runtime, |runtime| {
+        let contexts = runtime.scope_contexts.borrow();
+        ancestry.into_iter().position(|scope| {
+            contexts
+                .get(scope)
+                .and_then(|context| context.get(&id))
+                .map(|value| is_provided(&**value))
+                .unwrap_or(false)
+        })
+    })
+    .ok()
+    .flatten();
+
+    match depth {
+        Some(0) => crate::console_warn(&format!(
+            "[provide_context] overwriting a value in the same scope: {}",
+            describe()
+        )),
+        Some(_) => crate::console_warn(&format!(
+            "[provide_context] shadowing a value provided by a parent \
+             scope: {}",
+            describe()
+        )),
+        None => {}
+    }
+}
+
+// Wraps the signal in context, so it doesn't clash with a context of type `RwSignal<T>`.
+struct ReactiveContext<T: 'static>(RwSignal<T>);
+
+impl<T> Clone for ReactiveContext<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ReactiveContext<T> {}
+
+/// Provides a reactive context value of type `T` to the current reactive [`Scope`](crate::Scope)
+/// and all of its descendants. This can be consumed using
+/// [`use_reactive_context`](crate::use_reactive_context).
+///
+/// Unlike [`provide_context`], the value is held in a signal. If this scope has already
+/// provided a reactive context of type `T`, calling this again replaces its value, and every
+/// descendant that is reading it will update. The signal is also returned, so that the provider
+/// can update the value later.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # run_scope(create_runtime(), |cx| {
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Theme {
+///     Light,
+///     Dark,
+/// }
+///
+/// provide_reactive_context(cx, Theme::Light);
+///
+/// cx.child_scope(|cx| {
+///     // deep in the tree, without passing the signal down as a prop
+///     let theme = use_reactive_context::<Theme>(cx).unwrap();
+///     let is_dark = create_memo(cx, move |_| theme.get() == Theme::Dark);
+///
+///     provide_reactive_context(cx.parent().unwrap(), Theme::Dark);
+///     assert!(is_dark.get());
+/// });
+/// # });
+/// ```
+pub fn provide_reactive_context<T>(cx: Scope, value: T) -> RwSignal<T>
+where
+    T: 'static,
+{
+    let provided = with_runtime(cx.runtime, |runtime| {
+        runtime
+            .scope_contexts
+            .borrow()
+            .get(cx.id)?
+            .get(&TypeId::of::<ReactiveContext<T>>())?
+            .downcast_ref::<ReactiveContext<T>>()
+            .copied()
+    })
+    .ok()
+    .flatten();
+
+    match provided {
+        Some(ReactiveContext(signal)) => {
+            signal.set(value);
+            signal
+        }
+        None => {
+            let signal = create_rw_signal(cx, value);
+            provide_context(cx, ReactiveContext(signal));
+            signal
+        }
+    }
+}
+
+/// Extracts a reactive context value of type `T`, which was provided with
+/// [`provide_reactive_context`], by traversing the reactive system upwards from the
+/// current [`Scope`](crate::Scope).
+///
+/// This returns a signal, rather than a snapshot of the value, so anything that reads it
+/// will update when the provider replaces the value.
+pub fn use_reactive_context<T>(cx: Scope) -> Option<Signal<T>>
+where
+    T: 'static,
+{
+    use_context::<ReactiveContext<T>>(cx)
+        .map(|ReactiveContext(signal)| signal.into())
+}
@@ -2,6 +2,8 @@
 // tries to access a reactive variable outside the reactive scope. They track when
 // you create a signal/memo, and where you access it non-reactively.
 
+use std::{cell::RefCell, rc::Rc};
+
 #[cfg(debug_assertions)]
 #[allow(dead_code)] // allowed for SSR
 #[derive(Copy, Clone)]
@@ -60,6 +62,152 @@ impl SpecialNonReactiveZone {
     }
 }
 
+/// The kind of message passed to a hook set with [`set_diagnostics_hook`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DiagnosticLevel {
+    /// Informational output, like the changes logged by
+    /// `#[component(trace_props)]`.
+    Log,
+    /// A warning, like a performance budget that was exceeded or a context
+    /// that was shadowed.
+    Warn,
+}
+
+/// A hook that receives the warnings and logs of the reactive system, set with
+/// [`set_diagnostics_hook`].
+pub type DiagnosticsHook = Rc<dyn Fn(DiagnosticLevel, &str)>;
+
+thread_local! {
+    static DIAGNOSTICS_HOOK: RefCell<Option<DiagnosticsHook>> =
+        RefCell::new(None);
+}
+
+/// Sends the warnings and logs that the reactive system prints on the current
+/// thread to `hook`, instead of the browser console or standard output.
+/// Passing `None` prints them again.
+///
+/// This can be used to forward them to a logger, or to check in a test that a
+/// warning was emitted.
+/// ```
+/// # use leptos_reactive::*;
+/// # use std::{cell::RefCell, rc::Rc};
+/// let warnings = Rc::new(RefCell::new(Vec::new()));
+/// set_diagnostics_hook(Some(Rc::new({
+///     let warnings = Rc::clone(&warnings);
+///     move |level, message| {
+///         if level == DiagnosticLevel::Warn {
+///             warnings.borrow_mut().push(message.to_string());
+///         }
+///     }
+/// })));
+/// // ...
+/// set_diagnostics_hook(None);
+/// ```
+pub fn set_diagnostics_hook(hook: Option<DiagnosticsHook>) {
+    DIAGNOSTICS_HOOK.with(|current| *current.borrow_mut() = hook);
+}
+
+/// Passes the message to the hook set with [`set_diagnostics_hook`], returning
+/// `false` if there isn't one.
+pub(crate) fn call_diagnostics_hook(level: DiagnosticLevel, s: &str) -> bool {
+    // the hook is cloned out, in case it emits diagnostics itself
+    let hook = DIAGNOSTICS_HOOK.with(|hook| hook.borrow().clone());
+    match hook {
+        Some(hook) => {
+            hook(level, s);
+            true
+        }
+        None => false,
+    }
+}
+
+/// A dev-only performance budget for a component, set with
+/// `#[component(budget_ms = ...)]`. A warning is logged whenever the
+/// component’s initial render, or an effect created while rendering it, takes
+/// longer than the budget. All of this is a no-op in release mode.
+///
+/// Like the render time, the budget covers the component’s children: effects
+/// created while rendering a child that doesn’t declare a budget of its own
+/// are measured against it, and their warnings name the component that
+/// declared it.
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PerformanceBudget {
+    component: &'static str,
+    budget_ms: f64,
+}
+
+#[cfg(debug_assertions)]
+thread_local! {
+    static CURRENT_BUDGET: Cell<Option<PerformanceBudget>> =
+        const { Cell::new(None) };
+}
+
+impl PerformanceBudget {
+    #[inline(always)]
+    pub fn new(component: &'static str, budget_ms: f64) -> Self {
+        Self {
+            component,
+            budget_ms,
+        }
+    }
+
+    /// Runs the initial render of the component.
+    #[inline(always)]
+    pub fn measure<T>(self, f: impl FnOnce() -> T) -> T {
+        #[cfg(debug_assertions)]
+        {
+            let prev = CURRENT_BUDGET.with(|budget| budget.replace(Some(self)));
+            let value = self.time("render", f);
+            CURRENT_BUDGET.with(|budget| budget.set(prev));
+            value
+        }
+        #[cfg(not(debug_assertions))]
+        f()
+    }
+
+    /// The budget of the component that is currently rendering, if any.
+    #[cfg(debug_assertions)]
+    pub(crate) fn current() -> Option<Self> {
+        CURRENT_BUDGET.with(|budget| budget.get())
+    }
+
+    #[cfg(debug_assertions)]
+    pub(crate) fn time<T>(self, what: &str, f: impl FnOnce() -> T) -> T {
+        let start = now_ms();
+        let value = f();
+        let elapsed = now_ms() - start;
+        if elapsed > self.budget_ms {
+            crate::console_warn(&format!(
+                "[PerformanceBudget] {what} of <{}/> took {elapsed:.2}ms, \
+                 over its budget of {}ms",
+                self.component, self.budget_ms
+            ));
+        }
+        value
+    }
+}
+
+#[cfg(debug_assertions)]
+fn now_ms() -> f64 {
+    cfg_if::cfg_if! {
+        if #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        ))] {
+            web_sys::window()
+                .and_then(|window| window.performance())
+                .map(|performance| performance.now())
+                .unwrap_or_else(js_sys::Date::now)
+        } else {
+            thread_local! {
+                static START: std::time::Instant = std::time::Instant::now();
+            }
+            START.with(|start| start.elapsed().as_secs_f64() * 1000.0)
+        }
+    }
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! diagnostics {
@@ -76,3 +224,247 @@ macro_rules! diagnostics {
         }
     }};
 }
+
+/// A dev-only trace of one of a component’s props, set with
+/// `#[component(trace_props)]`. Whenever a prop that is a signal changes, its
+/// old and new values are logged, along with the signal whose write caused the
+/// change, so that you can see why part of the view updated. Props that are not
+/// signals never change, so they are not traced. All of this is a no-op in
+/// release mode.
+#[doc(hidden)]
+#[allow(dead_code)] // allowed for release mode
+pub struct PropTrace<'a, T> {
+    cx: crate::Scope,
+    component: &'static str,
+    prop: &'static str,
+    value: &'a T,
+}
+
+impl<'a, T> PropTrace<'a, T> {
+    #[inline(always)]
+    pub fn new(
+        cx: crate::Scope,
+        component: &'static str,
+        prop: &'static str,
+        value: &'a T,
+    ) -> Self {
+        Self {
+            cx,
+            component,
+            prop,
+            value,
+        }
+    }
+}
+
+// `(&&PropTrace::new(..)).trace()` picks `TraceReactiveProp` for the signal
+// types below, and falls back to `TraceStaticProp` for everything else.
+#[doc(hidden)]
+pub trait TraceStaticProp {
+    fn trace(&self);
+}
+
+impl<T> TraceStaticProp for PropTrace<'_, T> {
+    #[inline(always)]
+    fn trace(&self) {}
+}
+
+#[doc(hidden)]
+pub trait TraceReactiveProp {
+    fn trace(&self);
+}
+
+macro_rules! impl_trace_reactive_prop {
+    ($($ty:ident),*) => {
+        $(
+            impl<T> TraceReactiveProp for &PropTrace<'_, crate::$ty<T>>
+            where
+                T: std::fmt::Debug + 'static,
+            {
+                #[inline(always)]
+                fn trace(&self) {
+                    #[cfg(debug_assertions)]
+                    trace_prop(self.cx, self.component, self.prop, *self.value);
+                }
+            }
+        )*
+    };
+}
+
+impl_trace_reactive_prop!(ReadSignal, RwSignal, Memo, Signal);
+
+impl<T> TraceReactiveProp for &PropTrace<'_, crate::MaybeSignal<T>>
+where
+    T: std::fmt::Debug + 'static,
+{
+    #[inline(always)]
+    fn trace(&self) {
+        #[cfg(debug_assertions)]
+        if let crate::MaybeSignal::Dynamic(signal) = self.value {
+            trace_prop(self.cx, self.component, self.prop, *signal);
+        }
+    }
+}
+
+#[cfg(debug_assertions)]
+fn trace_prop<T: std::fmt::Debug>(
+    cx: crate::Scope,
+    component: &'static str,
+    prop: &'static str,
+    signal: impl crate::SignalWith<T> + 'static,
+) {
+    crate::create_effect(cx, move |prev: Option<String>| {
+        let current = signal.with(|value| format!("{value:?}"));
+        if let Some(prev) = prev.filter(|prev| *prev != current) {
+            let cause = crate::time_travel::last_write(cx.runtime)
+                .map(|signal| format!(", after a write to {signal:?}"))
+                .unwrap_or_default();
+            crate::console_log(&format!(
+                "[trace_props] prop `{prop}` of <{component}/> changed from \
+                 {prev} to {current}{cause}"
+            ));
+        }
+        current
+    });
+}
+
+// Borrows of the values of signals and memos. A `RefCell` only says that it
+// is “already borrowed”, so in debug mode every borrow is recorded along with
+// where it was made, and a conflicting borrow panics with a message that says
+// which value it was, where both borrows came from, and how to fix it.
+#[cfg(debug_assertions)]
+thread_local! {
+    static VALUE_BORROWS: std::cell::RefCell<Vec<ValueBorrow>> =
+        const { std::cell::RefCell::new(Vec::new()) };
+}
+
+#[cfg(debug_assertions)]
+#[derive(Clone, Copy)]
+struct ValueBorrow {
+    node: crate::NodeId,
+    is_mut: bool,
+    at: &'static std::panic::Location<'static>,
+}
+
+#[cfg(debug_assertions)]
+struct ValueBorrowGuard;
+
+#[cfg(debug_assertions)]
+impl ValueBorrowGuard {
+    fn new(borrow: ValueBorrow) -> Self {
+        VALUE_BORROWS.with(|borrows| borrows.borrow_mut().push(borrow));
+        Self
+    }
+}
+
+#[cfg(debug_assertions)]
+impl Drop for ValueBorrowGuard {
+    fn drop(&mut self) {
+        // borrows are scoped to a closure, so they end in reverse order
+        VALUE_BORROWS.with(|borrows| borrows.borrow_mut().pop());
+    }
+}
+
+/// Runs `f` with a shared borrow of the value of a signal or memo, which was
+/// asked for `at` that location.
+#[inline(always)]
+pub(crate) fn borrow_value<T: ?Sized, U>(
+    node: crate::NodeId,
+    value: &std::cell::RefCell<T>,
+    type_name: &str,
+    at: &'static std::panic::Location<'static>,
+    f: impl FnOnce(&T) -> U,
+) -> U {
+    #[cfg(debug_assertions)]
+    {
+        let borrow = ValueBorrow {
+            node,
+            is_mut: false,
+            at,
+        };
+        let Ok(value) = value.try_borrow() else {
+            borrow_conflict(borrow, type_name);
+        };
+        let _guard = ValueBorrowGuard::new(borrow);
+        f(&value)
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        _ = (node, type_name, at);
+        f(&value.borrow())
+    }
+}
+
+/// Runs `f` with a mutable borrow of the value of a signal or memo, which was
+/// asked for `at` that location.
+#[inline(always)]
+pub(crate) fn borrow_value_mut<T: ?Sized, U>(
+    node: crate::NodeId,
+    value: &std::cell::RefCell<T>,
+    type_name: &str,
+    at: &'static std::panic::Location<'static>,
+    f: impl FnOnce(&mut T) -> U,
+) -> U {
+    #[cfg(debug_assertions)]
+    {
+        let borrow = ValueBorrow {
+            node,
+            is_mut: true,
+            at,
+        };
+        let Ok(mut value) = value.try_borrow_mut() else {
+            borrow_conflict(borrow, type_name);
+        };
+        let _guard = ValueBorrowGuard::new(borrow);
+        f(&mut value)
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        _ = (node, type_name, at);
+        f(&mut value.borrow_mut())
+    }
+}
+
+#[cfg(debug_assertions)]
+#[cold]
+#[inline(never)]
+fn borrow_conflict(attempted: ValueBorrow, type_name: &str) -> ! {
+    let held = VALUE_BORROWS.with(|borrows| {
+        borrows
+            .borrow()
+            .iter()
+            .rev()
+            .find(|borrow| {
+                borrow.node == attempted.node
+                    && (borrow.is_mut || attempted.is_mut)
+            })
+            .copied()
+    });
+    let verb = |is_mut| if is_mut { "updated" } else { "read" };
+    let held_at = match held {
+        Some(held) => format!("being {} at {}", verb(held.is_mut), held.at),
+        None => "borrowed elsewhere".to_string(),
+    };
+    let hint = match held {
+        Some(held) if held.is_mut && attempted.is_mut => {
+            "Signals can’t be updated from inside their own `.update()`: \
+             change the `&mut` value that the outer update is given instead."
+        }
+        Some(held) if held.is_mut => {
+            "Signals can’t be read from inside their own `.update()`: use the \
+             `&mut` value that the update is given instead."
+        }
+        _ => {
+            "Signals can’t be updated while they are being read, for example \
+             from inside their own `.with()`, or from an effect that the read \
+             runs synchronously. Clone the value out with `.get()` first, or \
+             move the update after the `.with()`."
+        }
+    };
+    panic!(
+        "Tried to {} a signal or memo of type `{type_name}` at {}, but it is \
+         already {held_at}.\n{hint}",
+        if attempted.is_mut { "update" } else { "read" },
+        attempted.at,
+    )
+}
@@ -47,7 +47,7 @@ use std::{any::Any, cell::RefCell, marker::PhantomData, rc::Rc};
 /// # }).dispose();
 /// ```
 #[cfg_attr(
-    any(debug_assertions, feature="ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "trace",
         skip_all,
@@ -103,7 +103,7 @@ where
 /// # assert_eq!(b(), 2);
 /// # }).dispose();
 #[cfg_attr(
-    any(debug_assertions, feature="ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "trace",
         skip_all,
@@ -128,7 +128,7 @@ pub fn create_isomorphic_effect<T>(
 
 #[doc(hidden)]
 #[cfg_attr(
-    any(debug_assertions, feature="ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "trace",
         skip_all,
@@ -153,8 +153,10 @@ where
 {
     pub(crate) f: F,
     pub(crate) ty: PhantomData<T>,
-    #[cfg(any(debug_assertions, feature = "ssr"))]
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     pub(crate) defined_at: &'static std::panic::Location<'static>,
+    #[cfg(debug_assertions)]
+    pub(crate) budget: Option<crate::PerformanceBudget>,
 }
 
 pub(crate) trait AnyComputation {
@@ -167,7 +169,7 @@ where
     F: Fn(Option<T>) -> T,
 {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             name = "Effect::run()",
             level = "debug",
@@ -192,6 +194,12 @@ where
         };
 
         // run the effect
+        #[cfg(debug_assertions)]
+        let new_value = match self.budget {
+            Some(budget) => budget.time("effect", || (self.f)(curr_value)),
+            None => (self.f)(curr_value),
+        };
+        #[cfg(not(debug_assertions))]
         let new_value = (self.f)(curr_value);
 
         // set new value
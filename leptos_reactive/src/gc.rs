@@ -0,0 +1,105 @@
+#![forbid(unsafe_code)]
+use crate::{
+    node::{NodeId, ReactiveNodeType},
+    runtime::{with_runtime, Runtime},
+    RuntimeId, ScopeProperty,
+};
+use rustc_hash::FxHashSet;
+
+impl RuntimeId {
+    /// Removes signals, triggers and memos that are not owned by any
+    /// [`Scope`](crate::Scope) and have no subscribers, returning the number of
+    /// nodes removed. In debug builds, each node that is removed is logged.
+    ///
+    /// Every node normally belongs to the scope it was created in, and is removed
+    /// when that scope is disposed. A node created in a scope that has *already*
+    /// been disposed (for example, by an async callback that finishes after its
+    /// component was unmounted) has no owner, so nothing would ever remove it.
+    /// In the browser, a collection pass is queued automatically, to run when the
+    /// browser is idle, whenever such a node is created.
+    ///
+    /// Reading or writing a signal after it has been collected behaves just like
+    /// reading or writing a signal whose scope has been disposed.
+    pub fn gc(self) -> usize {
+        with_runtime(self, Runtime::collect_orphans).unwrap_or_default()
+    }
+}
+
+impl Runtime {
+    pub(crate) fn collect_orphans(&self) -> usize {
+        self.gc_queued.set(false);
+
+        let mut owned = FxHashSet::default();
+        for (_, properties) in self.scopes.borrow().iter() {
+            for property in properties.borrow().iter() {
+                if let ScopeProperty::Trigger(id)
+                | ScopeProperty::Signal(id)
+                | ScopeProperty::Effect(id) = property
+                {
+                    owned.insert(*id);
+                }
+            }
+        }
+
+        let mut collected = 0;
+        // removing a memo can leave its sources without any subscribers
+        loop {
+            let orphans = {
+                let nodes = self.nodes.borrow();
+                let subscribers = self.node_subscribers.borrow();
+                nodes
+                    .iter()
+                    .filter(|(id, node)| {
+                        !owned.contains(id)
+                            && !matches!(
+                                node.node_type,
+                                ReactiveNodeType::Effect { .. }
+                            )
+                            && !subscribers.get(*id).is_some_and(|subs| {
+                                // subscribers that have been disposed don't count
+                                subs.borrow()
+                                    .iter()
+                                    .any(|sub| nodes.contains_key(*sub))
+                            })
+                    })
+                    .map(|(id, _)| id)
+                    .collect::<Vec<_>>()
+            };
+            if orphans.is_empty() {
+                break;
+            }
+
+            for id in orphans {
+                crate::macros::debug_warn!(
+                    "[gc] collected {} {id:?}, which had no owner and no \
+                     subscribers",
+                    self.nodes
+                        .borrow()
+                        .get(id)
+                        .map(|node| match node.node_type {
+                            ReactiveNodeType::Trigger => "trigger",
+                            ReactiveNodeType::Signal => "signal",
+                            _ => "memo",
+                        })
+                        .unwrap_or_default()
+                );
+                self.unsubscribe_from_sources(id);
+                self.dispose_node(id);
+                collected += 1;
+            }
+        }
+        collected
+    }
+
+    fn unsubscribe_from_sources(&self, id: NodeId) {
+        let sources = self.node_sources.borrow();
+        let subscribers = self.node_subscribers.borrow();
+        for source in
+            sources.get(id).into_iter().flat_map(|s| s.borrow().clone())
+        {
+            if let Some(subs) = subscribers.get(source) {
+                subs.borrow_mut().remove(&id);
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+#![forbid(unsafe_code)]
+use crate::{
+    node::{NodeId, ReactiveNodeState, ReactiveNodeType},
+    runtime::with_runtime,
+    RuntimeId, ScopeId, ScopeProperty,
+};
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// A point-in-time copy of the reactive graph owned by a runtime, created by
+/// [`RuntimeId::snapshot_graph`].
+///
+/// Snapshots can be serialized and compared with one another, which makes it
+/// possible to dump the dependency graph between renders and look for nodes that
+/// were never disposed or subscriptions that keep growing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphSnapshot {
+    /// Every reactive node that currently exists in the runtime.
+    pub nodes: Vec<GraphNode>,
+    /// Every dependency between two nodes.
+    pub edges: Vec<GraphEdge>,
+}
+
+/// A single node in a [`GraphSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphNode {
+    /// The unique ID of the node.
+    pub id: NodeId,
+    /// What kind of reactive primitive this node is.
+    pub kind: GraphNodeKind,
+    /// Whether the node is up to date.
+    pub state: GraphNodeState,
+    /// The [`Scope`](crate::Scope) that owns this node, if any.
+    pub scope: Option<ScopeId>,
+}
+
+/// The kind of reactive primitive a [`GraphNode`] represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GraphNodeKind {
+    /// A [`Trigger`](crate::Trigger).
+    Trigger,
+    /// A signal, created by [`create_signal`](crate::create_signal) or similar.
+    Signal,
+    /// A [`Memo`](crate::Memo).
+    Memo,
+    /// An effect, created by [`create_effect`](crate::create_effect) or similar.
+    Effect,
+}
+
+/// Whether a [`GraphNode`] was up to date when the snapshot was taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GraphNodeState {
+    /// The node is up to date.
+    Clean,
+    /// One of the node’s sources may have changed.
+    Check,
+    /// One of the node’s sources has changed, and it needs to be rerun.
+    Dirty,
+}
+
+/// A dependency between two nodes in a [`GraphSnapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphEdge {
+    /// The node that is read.
+    pub source: NodeId,
+    /// The node that reads `source`, and will be notified when it changes.
+    pub subscriber: NodeId,
+}
+
+impl GraphSnapshot {
+    /// Renders the snapshot as a [DOT](https://graphviz.org/doc/info/lang.html)
+    /// graph, with edges pointing from each source to its subscribers.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph reactive_graph {\n");
+        for node in &self.nodes {
+            let shape = match node.kind {
+                GraphNodeKind::Trigger | GraphNodeKind::Signal => "ellipse",
+                GraphNodeKind::Memo => "box",
+                GraphNodeKind::Effect => "doubleoctagon",
+            };
+            _ = writeln!(
+                dot,
+                "  \"{:?}\" [label=\"{:?} {:?}\\n{:?}\", shape={shape}];",
+                node.id, node.kind, node.id, node.state
+            );
+        }
+        for edge in &self.edges {
+            _ = writeln!(
+                dot,
+                "  \"{:?}\" -> \"{:?}\";",
+                edge.source, edge.subscriber
+            );
+        }
+        dot.push('}');
+        dot
+    }
+
+    /// Returns the node with the given ID, if it is in the snapshot.
+    pub fn node(&self, id: NodeId) -> Option<&GraphNode> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+}
+
+impl RuntimeId {
+    /// Takes a [`GraphSnapshot`] of all the reactive nodes and dependencies
+    /// that currently exist in this runtime.
+    ///
+    /// Returns an empty snapshot if the runtime has already been disposed.
+    pub fn snapshot_graph(self) -> GraphSnapshot {
+        with_runtime(self, |runtime| {
+            let mut owners = FxHashMap::default();
+            for (scope_id, properties) in runtime.scopes.borrow().iter() {
+                for property in properties.borrow().iter() {
+                    if let ScopeProperty::Trigger(id)
+                    | ScopeProperty::Signal(id)
+                    | ScopeProperty::Effect(id) = property
+                    {
+                        owners.insert(*id, scope_id);
+                    }
+                }
+            }
+
+            let nodes = runtime
+                .nodes
+                .borrow()
+                .iter()
+                .map(|(id, node)| GraphNode {
+                    id,
+                    kind: match node.node_type {
+                        ReactiveNodeType::Trigger => GraphNodeKind::Trigger,
+                        ReactiveNodeType::Signal => GraphNodeKind::Signal,
+                        ReactiveNodeType::Memo { .. } => GraphNodeKind::Memo,
+                        ReactiveNodeType::Effect { .. } => {
+                            GraphNodeKind::Effect
+                        }
+                    },
+                    state: match node.state {
+                        ReactiveNodeState::Clean => GraphNodeState::Clean,
+                        ReactiveNodeState::Check => GraphNodeState::Check,
+                        ReactiveNodeState::Dirty
+                        | ReactiveNodeState::DirtyMarked => {
+                            GraphNodeState::Dirty
+                        }
+                    },
+                    scope: owners.get(&id).copied(),
+                })
+                .collect();
+
+            let edges = runtime
+                .node_subscribers
+                .borrow()
+                .iter()
+                .flat_map(|(source, subscribers)| {
+                    subscribers
+                        .borrow()
+                        .iter()
+                        .map(|subscriber| GraphEdge {
+                            source,
+                            subscriber: *subscriber,
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+
+            GraphSnapshot { nodes, edges }
+        })
+        .unwrap_or_default()
+    }
+}
@@ -9,6 +9,8 @@ pub struct SharedContext {
     pub resolved_resources: HashMap<ResourceId, String>,
     #[allow(clippy::type_complexity)]
     pub pending_fragments: HashMap<String, FragmentData>,
+    /// The serialized config from [`provide_config`](crate::provide_config).
+    pub config: Option<String>,
 }
 
 /// Represents its pending `<Suspense/>` fragment.
@@ -62,11 +64,19 @@ impl Default for SharedContext {
                 let resolved_resources =
                     serde_wasm_bindgen::from_value(resolved_resources).unwrap_or_default();
 
+                let config = js_sys::Reflect::get(
+                    &web_sys::window().unwrap(),
+                    &wasm_bindgen::JsValue::from_str("__LEPTOS_CONFIG"),
+                )
+                .ok()
+                .and_then(|config| config.as_string());
+
                 Self {
                     events: Default::default(),
                     pending_resources,
                     resolved_resources,
                     pending_fragments: Default::default(),
+                    config,
                 }
             } else {
                 Self {
@@ -74,6 +84,7 @@ impl Default for SharedContext {
                     pending_resources: Default::default(),
                     resolved_resources: Default::default(),
                     pending_fragments: Default::default(),
+                    config: Default::default(),
                 }
             }
         }
@@ -67,50 +67,78 @@
 //! });
 //! ```
 
-#[cfg_attr(any(debug_assertions, feature = "ssr"), macro_use)]
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
+    macro_use
+)]
 extern crate tracing;
 
 #[macro_use]
 mod signal;
+mod app_config;
+mod awaited;
 mod context;
 #[macro_use]
 mod diagnostics;
 mod effect;
+mod gc;
+mod graph;
 mod hydration;
 mod memo;
 mod node;
 mod resource;
 mod runtime;
+mod scheduler;
 mod scope;
 mod selector;
 mod serialization;
+mod signal_changes;
 mod signal_wrappers_read;
 mod signal_wrappers_write;
 mod slice;
 mod spawn;
 mod spawn_microtask;
+mod stale_while_refresh;
+mod stats;
 mod stored_value;
 pub mod suspense;
+#[cfg(debug_assertions)]
+mod time_travel;
+mod transition;
 mod trigger;
 
+pub use app_config::{provide_config, use_config, ServerOnly};
+pub use awaited::*;
 pub use context::*;
-pub use diagnostics::SpecialNonReactiveZone;
+pub use diagnostics::{
+    set_diagnostics_hook, DiagnosticLevel, DiagnosticsHook, PerformanceBudget,
+    PropTrace, SpecialNonReactiveZone, TraceReactiveProp, TraceStaticProp,
+};
 pub use effect::*;
+pub use graph::*;
 pub use memo::*;
+pub use node::NodeId;
 pub use resource::*;
 use runtime::*;
-pub use runtime::{create_runtime, RuntimeId};
+pub use runtime::{create_runtime, set_runtime_pool_capacity, RuntimeId};
+pub use scheduler::*;
 pub use scope::*;
 pub use selector::*;
 pub use serialization::*;
 pub use signal::{prelude as signal_prelude, *};
+pub use signal_changes::*;
 pub use signal_wrappers_read::*;
 pub use signal_wrappers_write::*;
 pub use slice::*;
 pub use spawn::*;
 pub use spawn_microtask::*;
+pub use stale_while_refresh::*;
+pub use stats::*;
 pub use stored_value::*;
 pub use suspense::{GlobalSuspenseContext, SuspenseContext};
+#[cfg(debug_assertions)]
+pub use time_travel::SignalWrite;
+pub use transition::*;
 pub use trigger::*;
 
 mod macros {
@@ -133,6 +161,9 @@ mod macros {
 }
 
 pub(crate) fn console_warn(s: &str) {
+    if diagnostics::call_diagnostics_hook(DiagnosticLevel::Warn, s) {
+        return;
+    }
     cfg_if::cfg_if! {
         if #[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))] {
             web_sys::console::warn_1(&wasm_bindgen::JsValue::from_str(s));
@@ -141,3 +172,17 @@ pub(crate) fn console_warn(s: &str) {
         }
     }
 }
+
+#[cfg(debug_assertions)]
+pub(crate) fn console_log(s: &str) {
+    if diagnostics::call_diagnostics_hook(DiagnosticLevel::Log, s) {
+        return;
+    }
+    cfg_if::cfg_if! {
+        if #[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))] {
+            web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(s));
+        } else {
+            println!("{s}");
+        }
+    }
+}
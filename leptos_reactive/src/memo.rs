@@ -16,6 +16,16 @@ use std::{any::Any, cell::RefCell, fmt, marker::PhantomData, rc::Rc};
 ///
 /// This makes a memo the perfect tool for expensive computations.
 ///
+/// Memos are lazy: the computation does not run until the memo is first read.
+/// When one of its dependencies changes, the memo is only marked as stale, and
+/// the computation runs again the next time the memo is read, whether directly
+/// or by an effect or another memo that depends on it. A memo that is not read,
+/// such as one used only by a collapsed section of the UI, does no work at all.
+///
+/// Updates are glitch-free: before a memo runs, every memo it read last time is
+/// brought up to date first, so even when two of its dependencies share a
+/// source (a “diamond”), it never sees one updated and the other stale.
+///
 /// Memos have a certain overhead compared to derived signals. In most cases, you should
 /// create a derived signal. But if the derivation calculation is expensive, you should
 /// create a memo.
@@ -47,7 +57,7 @@ use std::{any::Any, cell::RefCell, fmt, marker::PhantomData, rc::Rc};
 /// });
 ///
 /// // instead, we create a memo
-/// // 🆗 run #1: the calculation runs once immediately
+/// // 🆗 run #1: the calculation runs once, when the memo is first read
 /// let memoized = create_memo(cx, move |_| really_expensive_computation(value()));
 /// create_effect(cx, move |_| {
 ///  // 🆗 reads the current value of the memo
@@ -61,7 +71,7 @@ use std::{any::Any, cell::RefCell, fmt, marker::PhantomData, rc::Rc};
 /// # }).dispose();
 /// ```
 #[cfg_attr(
-    any(debug_assertions, feature="ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "trace",
         skip_all,
@@ -80,7 +90,49 @@ pub fn create_memo<T>(
 where
     T: PartialEq + 'static,
 {
-    let memo = cx.runtime.create_memo(f);
+    let memo = cx.runtime.create_memo(f, false);
+    cx.push_scope_property(ScopeProperty::Effect(memo.id));
+    memo
+}
+
+/// Creates a memo, like [`create_memo`], that also keeps the value it had before
+/// it last changed, which can be read with [`Memo::get_previous`].
+///
+/// This holds a second copy of the value, so it should only be used when the
+/// previous value is actually needed.
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// let (count, set_count) = create_signal(cx, 1);
+/// let double_count = create_memo_with_previous(cx, move |_| count.get() * 2);
+///
+/// assert_eq!(double_count.get_previous(), None);
+/// set_count.set(2);
+/// assert_eq!(double_count.get(), 4);
+/// assert_eq!(double_count.get_previous(), Some(2));
+/// # }).dispose();
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
+    instrument(
+        level = "trace",
+        skip_all,
+        fields(
+            scope = ?cx.id,
+            ty = %std::any::type_name::<T>()
+        )
+    )
+)]
+#[track_caller]
+#[inline(always)]
+pub fn create_memo_with_previous<T>(
+    cx: Scope,
+    f: impl Fn(Option<&T>) -> T + 'static,
+) -> Memo<T>
+where
+    T: PartialEq + 'static,
+{
+    let memo = cx.runtime.create_memo(f, true);
     cx.push_scope_property(ScopeProperty::Effect(memo.id));
     memo
 }
@@ -94,12 +146,24 @@ where
 ///
 /// This makes a memo the perfect tool for expensive computations.
 ///
+/// Memos are lazy: the computation does not run until the memo is first read.
+/// When one of its dependencies changes, the memo is only marked as stale, and
+/// the computation runs again the next time the memo is read, whether directly
+/// or by an effect or another memo that depends on it. A memo that is not read,
+/// such as one used only by a collapsed section of the UI, does no work at all.
+///
+/// Updates are glitch-free: before a memo runs, every memo it read last time is
+/// brought up to date first, so even when two of its dependencies share a
+/// source (a “diamond”), it never sees one updated and the other stale.
+///
 /// Memos have a certain overhead compared to derived signals. In most cases, you should
 /// create a derived signal. But if the derivation calculation is expensive, you should
 /// create a memo.
 ///
 /// As with [`create_effect`](crate::create_effect), the argument to the memo function is the previous value,
 /// i.e., the current value of the memo, which will be `None` for the initial calculation.
+/// To also read the value the memo had before it last changed, create it with
+/// [`create_memo_with_previous`] and use [`Memo::get_previous`].
 ///
 /// ## Core Trait Implementations
 /// - [`.get()`](#impl-SignalGet<T>-for-Memo<T>) (or calling the signal as a function) clones the current
@@ -138,7 +202,7 @@ where
 /// });
 ///
 /// // instead, we create a memo
-/// // 🆗 run #1: the calculation runs once immediately
+/// // 🆗 run #1: the calculation runs once, when the memo is first read
 /// let memoized = create_memo(cx, move |_| really_expensive_computation(value()));
 /// create_effect(cx, move |_| {
 ///  // 🆗 reads the current value of the memo
@@ -158,7 +222,7 @@ where
     pub(crate) runtime: RuntimeId,
     pub(crate) id: NodeId,
     pub(crate) ty: PhantomData<T>,
-    #[cfg(any(debug_assertions, feature = "ssr"))]
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     pub(crate) defined_at: &'static std::panic::Location<'static>,
 }
 
@@ -171,7 +235,11 @@ where
             runtime: self.runtime,
             id: self.id,
             ty: PhantomData,
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: self.defined_at,
         }
     }
@@ -185,7 +253,7 @@ impl<T> fmt::Debug for Memo<T> {
         s.field("runtime", &self.runtime);
         s.field("id", &self.id);
         s.field("ty", &self.ty);
-        #[cfg(any(debug_assertions, feature = "ssr"))]
+        #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
         s.field("defined_at", &self.defined_at);
         s.finish()
     }
@@ -201,7 +269,7 @@ impl<T> PartialEq for Memo<T> {
 
 impl<T: Clone> SignalGetUntracked<T> for Memo<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Memo::get_untracked()",
@@ -219,7 +287,11 @@ impl<T: Clone> SignalGetUntracked<T> for Memo<T> {
             match self.id.try_with_no_subscription(runtime, f) {
                 Ok(t) => t,
                 Err(_) => panic_getting_dead_memo(
-                    #[cfg(any(debug_assertions, feature = "ssr"))]
+                    #[cfg(any(
+                        debug_assertions,
+                        feature = "ssr",
+                        feature = "tracing"
+                    ))]
                     self.defined_at,
                 ),
             }
@@ -228,7 +300,7 @@ impl<T: Clone> SignalGetUntracked<T> for Memo<T> {
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Memo::try_get_untracked()",
@@ -248,7 +320,7 @@ impl<T: Clone> SignalGetUntracked<T> for Memo<T> {
 
 impl<T> SignalWithUntracked<T> for Memo<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Memo::with_untracked()",
@@ -267,7 +339,11 @@ impl<T> SignalWithUntracked<T> for Memo<T> {
             match self.id.try_with_no_subscription(runtime, |v: &T| f(v)) {
                 Ok(t) => t,
                 Err(_) => panic_getting_dead_memo(
-                    #[cfg(any(debug_assertions, feature = "ssr"))]
+                    #[cfg(any(
+                        debug_assertions,
+                        feature = "ssr",
+                        feature = "tracing"
+                    ))]
                     self.defined_at,
                 ),
             }
@@ -276,7 +352,7 @@ impl<T> SignalWithUntracked<T> for Memo<T> {
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Memo::try_with_untracked()",
@@ -316,7 +392,7 @@ impl<T> SignalWithUntracked<T> for Memo<T> {
 /// ```
 impl<T: Clone> SignalGet<T> for Memo<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             name = "Memo::get()",
             level = "trace",
@@ -335,7 +411,7 @@ impl<T: Clone> SignalGet<T> for Memo<T> {
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Memo::try_get()",
@@ -356,7 +432,7 @@ impl<T: Clone> SignalGet<T> for Memo<T> {
 
 impl<T> SignalWith<T> for Memo<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Memo::with()",
@@ -373,14 +449,18 @@ impl<T> SignalWith<T> for Memo<T> {
         match self.try_with(f) {
             Some(t) => t,
             None => panic_getting_dead_memo(
-                #[cfg(any(debug_assertions, feature = "ssr"))]
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
                 self.defined_at,
             ),
         }
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Memo::try_with()",
@@ -411,7 +491,7 @@ impl<T> SignalWith<T> for Memo<T> {
 
 impl<T: Clone> SignalStream<T> for Memo<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Memo::to_stream()",
@@ -443,6 +523,30 @@ impl<T: Clone> SignalStream<T> for Memo<T> {
     }
 }
 
+impl<T: Clone> Memo<T> {
+    /// Clones the value the memo had before it last changed, or returns `None` if
+    /// its value has not changed since it was first computed.
+    ///
+    /// The previous value is only kept for memos created with
+    /// [`create_memo_with_previous`]; for any other memo, this returns `None`.
+    ///
+    /// Like [`.get()`](SignalGet::get), this subscribes the running effect to the
+    /// memo, as the previous value only changes when the current one does.
+    #[track_caller]
+    pub fn get_previous(&self) -> Option<T> {
+        // brings the memo up to date, so that its previous value is too
+        self.with(|_| ());
+        with_runtime(self.runtime, |runtime| {
+            let previous =
+                runtime.memo_previous.borrow().get(self.id).cloned()?;
+            let previous = previous.borrow();
+            previous.downcast_ref::<Option<T>>()?.clone()
+        })
+        .ok()
+        .flatten()
+    }
+}
+
 impl<T> SignalDispose for Memo<T> {
     fn dispose(self) {
         _ = with_runtime(self.runtime, |runtime| runtime.dispose_node(self.id));
@@ -458,7 +562,10 @@ where
 {
     pub f: F,
     pub t: PhantomData<T>,
-    #[cfg(any(debug_assertions, feature = "ssr"))]
+    // the value before the most recent change, shared with `Runtime::memo_previous`,
+    // if the memo was created with `create_memo_with_previous`
+    pub previous: Option<Rc<RefCell<Option<T>>>>,
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     pub(crate) defined_at: &'static std::panic::Location<'static>,
 }
 
@@ -468,7 +575,7 @@ where
     F: Fn(Option<&T>) -> T,
 {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             name = "Memo::run()",
             level = "debug",
@@ -496,7 +603,10 @@ where
             let curr_value = value
                 .downcast_mut::<Option<T>>()
                 .expect("to downcast memo value");
-            *curr_value = Some(new_value);
+            let previous = curr_value.replace(new_value);
+            if let Some(slot) = &self.previous {
+                *slot.borrow_mut() = previous;
+            }
         }
 
         is_different
@@ -508,18 +618,22 @@ where
 #[track_caller]
 fn format_memo_warning(
     msg: &str,
-    #[cfg(any(debug_assertions, feature = "ssr"))]
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     defined_at: &'static std::panic::Location<'static>,
 ) -> String {
     let location = std::panic::Location::caller();
 
     let defined_at_msg = {
-        #[cfg(any(debug_assertions, feature = "ssr"))]
+        #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
         {
             format!("signal created here: {defined_at}\n")
         }
 
-        #[cfg(not(any(debug_assertions, feature = "ssr")))]
+        #[cfg(not(any(
+            debug_assertions,
+            feature = "ssr",
+            feature = "tracing"
+        )))]
         {
             String::default()
         }
@@ -532,14 +646,18 @@ fn format_memo_warning(
 #[inline(never)]
 #[track_caller]
 pub(crate) fn panic_getting_dead_memo(
-    #[cfg(any(debug_assertions, feature = "ssr"))]
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     defined_at: &'static std::panic::Location<'static>,
 ) -> ! {
     panic!(
         "{}",
         format_memo_warning(
             "Attempted to get a memo after it was disposed.",
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at,
         )
     )
@@ -5,20 +5,24 @@ use crate::{
     runtime::{with_runtime, RuntimeId},
     serialization::Serializable,
     spawn::spawn_local,
+    transition::running_transition,
     use_context, GlobalSuspenseContext, Memo, ReadSignal, Scope, ScopeProperty,
-    SignalGetUntracked, SignalSet, SignalUpdate, SignalWith, SuspenseContext,
-    WriteSignal,
+    Signal, SignalGet, SignalGetUntracked, SignalSet, SignalUpdate, SignalWith,
+    SuspenseContext, WriteSignal,
 };
+use futures::future::{AbortHandle, AbortRegistration, Abortable};
 use std::{
     any::Any,
     cell::{Cell, RefCell},
     collections::HashSet,
+    error::Error,
     fmt::Debug,
     future::Future,
     marker::PhantomData,
     panic::Location,
     pin::Pin,
     rc::Rc,
+    sync::Arc,
 };
 
 /// Creates a [`Resource`](crate::Resource), which is a signal that reflects the
@@ -65,7 +69,7 @@ use std::{
 /// # }).dispose();
 /// ```
 #[cfg_attr(
-    any(debug_assertions, feature="ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "info",
         skip_all,
@@ -101,7 +105,7 @@ where
 /// serialized, or you just want to make sure the [`Future`] runs locally, use
 /// [`create_local_resource_with_initial_value()`].
 #[cfg_attr(
-    any(debug_assertions, feature="ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "info",
         skip_all,
@@ -148,7 +152,7 @@ where
 /// **Note**: This is not “blocking” in the sense that it blocks the current thread. Rather,
 /// it is blocking in the sense that it blocks the server from sending a response.
 #[cfg_attr(
-    any(debug_assertions, feature="ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "info",
         skip_all,
@@ -179,6 +183,126 @@ where
     )
 }
 
+/// Creates a [`Resource`](crate::Resource) that loads a [`Result`], like
+/// [`create_resource`], and reports its errors to the nearest
+/// [`ResourceErrorCollector`] above it.
+///
+/// `<ErrorBoundary/>` provides a collector, so an error returned by the `fetcher`
+/// shows the boundary’s fallback, whether or not the resource is rendered, and
+/// during server-side rendering and hydration as well as in the browser. The
+/// error is cleared when the resource loads successfully again. It is also
+/// available as a signal from [`Resource::error`].
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// # #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+/// # struct User;
+/// #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+/// struct NotFound;
+///
+/// impl std::fmt::Display for NotFound {
+///   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///     write!(f, "user not found")
+///   }
+/// }
+///
+/// impl std::error::Error for NotFound {}
+///
+/// async fn fetch_user(id: usize) -> Result<User, NotFound> {
+///   // pretend we're fetching a user
+///   Err(NotFound)
+/// }
+///
+/// let (id, set_id) = create_signal(cx, 0);
+///
+/// # if false {
+/// let user = create_fallible_resource(cx, move || id.get(), fetch_user);
+/// // the error is caught by the nearest `<ErrorBoundary/>`, but can also be
+/// // read directly
+/// let not_found = move || user.error(cx).get().is_some();
+/// # }
+/// # }).dispose();
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
+    instrument(
+        level = "info",
+        skip_all,
+        fields(
+            scope = ?cx.id,
+            ty = %std::any::type_name::<T>(),
+            signal_ty = %std::any::type_name::<S>(),
+        )
+    )
+)]
+pub fn create_fallible_resource<S, T, E, Fu>(
+    cx: Scope,
+    source: impl Fn() -> S + 'static,
+    fetcher: impl Fn(S) -> Fu + 'static,
+) -> Resource<S, Result<T, E>>
+where
+    S: PartialEq + Clone + 'static,
+    T: 'static,
+    E: Error + Clone + Send + Sync + 'static,
+    Result<T, E>: Serializable,
+    Fu: Future<Output = Result<T, E>> + 'static,
+{
+    let resource = create_resource(cx, source, fetcher);
+
+    if let Some(collector) = use_context::<ResourceErrorCollector>(cx) {
+        let error = resource.error(cx);
+        let id = resource.id;
+        create_isomorphic_effect(cx, {
+            let collector = collector.clone();
+            move |_| {
+                let error = error.get().map(|error| {
+                    Arc::new(error) as Arc<dyn Error + Send + Sync>
+                });
+                (collector.0)(id, error);
+            }
+        });
+
+        // the error no longer applies once the resource is gone; this can
+        // only be cleaned up in the browser, as the server disposes of
+        // everything at once
+        #[cfg(any(feature = "csr", feature = "hydrate"))]
+        crate::on_cleanup(cx, move || {
+            queue_microtask(move || (collector.0)(id, None));
+        });
+    }
+
+    resource
+}
+
+/// Collects the errors reported by resources created with
+/// [`create_fallible_resource`] beneath it, when provided as context.
+///
+/// `<ErrorBoundary/>` provides one of these, so this is only needed to collect
+/// errors somewhere else.
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct ResourceErrorCollector(
+    Rc<dyn Fn(ResourceId, Option<Arc<dyn Error + Send + Sync>>)>,
+);
+
+impl ResourceErrorCollector {
+    /// Creates a collector that calls `f` with the ID of a resource and its
+    /// error whenever the resource loads: `Some(error)` if it failed, or
+    /// `None` if it succeeded or has been disposed of.
+    pub fn new(
+        f: impl Fn(ResourceId, Option<Arc<dyn Error + Send + Sync>>) + 'static,
+    ) -> Self {
+        Self(Rc::new(f))
+    }
+}
+
+impl Debug for ResourceErrorCollector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ResourceErrorCollector").finish()
+    }
+}
+
 fn create_resource_helper<S, T, Fu>(
     cx: Scope,
     source: impl Fn() -> S + 'static,
@@ -197,7 +321,7 @@ where
     let (loading, set_loading) = create_signal(cx, false);
 
     //crate::macros::debug_warn!("creating fetcher");
-    let fetcher = Rc::new(move |s| {
+    let fetcher = Rc::new(move |s, _| {
         Box::pin(fetcher(s)) as Pin<Box<dyn Future<Output = T>>>
     });
     let source = create_memo(cx, move |_| source());
@@ -212,6 +336,7 @@ where
         resolved: Rc::new(Cell::new(resolved)),
         scheduled: Rc::new(Cell::new(false)),
         version: Rc::new(Cell::new(0)),
+        abort_handle: Default::default(),
         suspense_contexts: Default::default(),
         serializable,
     });
@@ -237,7 +362,7 @@ where
         id,
         source_ty: PhantomData,
         out_ty: PhantomData,
-        #[cfg(any(debug_assertions, features = "ssr"))]
+        #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
         defined_at: std::panic::Location::caller(),
     }
 }
@@ -274,7 +399,7 @@ where
 /// # }).dispose();
 /// ```
 #[cfg_attr(
-    any(debug_assertions, feature="ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "info",
         skip_all,
@@ -307,7 +432,7 @@ where
 /// on the local system and therefore its output type does not need to be
 /// [`Serializable`].
 #[cfg_attr(
-    any(debug_assertions, feature="ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "info",
         skip_all,
@@ -324,6 +449,76 @@ pub fn create_local_resource_with_initial_value<S, T, Fu>(
     fetcher: impl Fn(S) -> Fu + 'static,
     initial_value: Option<T>,
 ) -> Resource<S, T>
+where
+    S: PartialEq + Clone + 'static,
+    T: 'static,
+    Fu: Future<Output = T> + 'static,
+{
+    create_local_resource_helper(
+        cx,
+        source,
+        move |s, _| fetcher(s),
+        initial_value,
+    )
+}
+
+/// Creates a _local_ [`Resource`](crate::Resource) whose `fetcher` is also
+/// given a [`ResourceAbortHandle`], which is aborted when the request it made
+/// is no longer needed: because the `source` has changed and a new request has
+/// started, or because the resource was aborted with [`Resource::abort`].
+///
+/// Every resource drops the [`Future`] for a request that has been superseded,
+/// which cancels it as far as Rust is concerned. In the browser, a `fetch` that
+/// has already been sent keeps running unless it is given an `AbortSignal`;
+/// pass it the one returned by `ResourceAbortHandle::signal` to cancel the
+/// request itself.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// // pretend we're making a `fetch` request that can be aborted
+/// async fn search(query: String, abort: ResourceAbortHandle) -> Vec<String> {
+///   # #[cfg(any(feature = "csr", feature = "hydrate"))]
+///   # let _ = abort.signal();
+///   vec![query]
+/// }
+///
+/// let (query, set_query) = create_signal(cx, "cats".to_string());
+///
+/// # if false {
+/// let results = create_abortable_local_resource(
+///   cx,
+///   move || query.get(),
+///   |query, abort| search(query, abort),
+/// );
+///
+/// // the search for "cats" is aborted if it hasn't finished yet
+/// set_query.set("dogs".to_string());
+///
+/// // as is the search for "dogs"
+/// results.abort();
+/// # }
+/// # }).dispose();
+/// ```
+pub fn create_abortable_local_resource<S, T, Fu>(
+    cx: Scope,
+    source: impl Fn() -> S + 'static,
+    fetcher: impl Fn(S, ResourceAbortHandle) -> Fu + 'static,
+) -> Resource<S, T>
+where
+    S: PartialEq + Clone + 'static,
+    T: 'static,
+    Fu: Future<Output = T> + 'static,
+{
+    create_local_resource_helper(cx, source, fetcher, None)
+}
+
+fn create_local_resource_helper<S, T, Fu>(
+    cx: Scope,
+    source: impl Fn() -> S + 'static,
+    fetcher: impl Fn(S, ResourceAbortHandle) -> Fu + 'static,
+    initial_value: Option<T>,
+) -> Resource<S, T>
 where
     S: PartialEq + Clone + 'static,
     T: 'static,
@@ -334,8 +529,8 @@ where
 
     let (loading, set_loading) = create_signal(cx, false);
 
-    let fetcher = Rc::new(move |s| {
-        Box::pin(fetcher(s)) as Pin<Box<dyn Future<Output = T>>>
+    let fetcher = Rc::new(move |s, abort| {
+        Box::pin(fetcher(s, abort)) as Pin<Box<dyn Future<Output = T>>>
     });
     let source = create_memo(cx, move |_| source());
 
@@ -349,6 +544,7 @@ where
         resolved: Rc::new(Cell::new(resolved)),
         scheduled: Rc::new(Cell::new(false)),
         version: Rc::new(Cell::new(0)),
+        abort_handle: Default::default(),
         suspense_contexts: Default::default(),
         serializable: ResourceSerialization::Local,
     });
@@ -373,11 +569,158 @@ where
         id,
         source_ty: PhantomData,
         out_ty: PhantomData,
-        #[cfg(any(debug_assertions, features = "ssr"))]
+        #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
         defined_at: std::panic::Location::caller(),
     }
 }
 
+/// Creates a [`Resource`](crate::Resource) that depends on several sources, like
+/// [`create_resource`], whose `fetcher` is also told which of the sources have
+/// changed since it last ran.
+///
+/// The sources are given as a function that returns a tuple, with one item for
+/// each source. Knowing which items changed lets the fetcher do less work: for
+/// example, reusing data it already has for a query when only the page number
+/// has changed. The first time the fetcher runs, every source is counted as changed;
+/// when the resource is refetched with [`Resource::refetch`], none are.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// async fn search(query: String, page: usize, refine: bool) -> Vec<String> {
+///   // pretend we're calling a search API, which can refine the previous
+///   // results for the same query more cheaply than starting again
+///   vec![format!("{query} {page} {refine}")]
+/// }
+///
+/// let (query, set_query) = create_signal(cx, "cats".to_string());
+/// let (page, set_page) = create_signal(cx, 1);
+///
+/// # if false {
+/// let results = create_multi_source_resource(
+///   cx,
+///   move || (query.get(), page.get()),
+///   |(query, page), changes| search(query, page, !changes.changed(0)),
+/// );
+/// # }
+/// # }).dispose();
+/// ```
+#[track_caller]
+pub fn create_multi_source_resource<S, T, Fu>(
+    cx: Scope,
+    sources: impl Fn() -> S + 'static,
+    fetcher: impl Fn(S, SourceChanges) -> Fu + 'static,
+) -> Resource<S, T>
+where
+    S: ResourceSources,
+    T: Serializable + 'static,
+    Fu: Future<Output = T> + 'static,
+{
+    create_resource(cx, sources, track_source_changes(fetcher))
+}
+
+/// Creates a _local_ [`Resource`](crate::Resource) that depends on several
+/// sources, whose `fetcher` is also told which of them have changed since it
+/// last ran. See [`create_multi_source_resource`] for details.
+///
+/// Unlike [`create_multi_source_resource()`], this [`Future`] will always run
+/// on the local system and therefore its output type does not need to be
+/// [`Serializable`].
+#[track_caller]
+pub fn create_local_multi_source_resource<S, T, Fu>(
+    cx: Scope,
+    sources: impl Fn() -> S + 'static,
+    fetcher: impl Fn(S, SourceChanges) -> Fu + 'static,
+) -> Resource<S, T>
+where
+    S: ResourceSources,
+    T: 'static,
+    Fu: Future<Output = T> + 'static,
+{
+    create_local_resource(cx, sources, track_source_changes(fetcher))
+}
+
+fn track_source_changes<S, Fu>(
+    fetcher: impl Fn(S, SourceChanges) -> Fu + 'static,
+) -> impl Fn(S) -> Fu + 'static
+where
+    S: ResourceSources,
+{
+    let previous = RefCell::new(None::<S>);
+    move |sources: S| {
+        let changes = match previous.replace(Some(sources.clone())) {
+            Some(previous) => sources.changes(&previous),
+            None => SourceChanges::ALL,
+        };
+        fetcher(sources, changes)
+    }
+}
+
+/// Which of the sources of a resource created with
+/// [`create_multi_source_resource`] have changed since its fetcher last ran.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SourceChanges(u32);
+
+impl SourceChanges {
+    const ALL: Self = Self(u32::MAX);
+
+    /// Whether the source at `index` in the tuple of sources has changed.
+    pub fn changed(&self, index: usize) -> bool {
+        index < 32 && self.0 & (1 << index) != 0
+    }
+
+    /// Whether any of the sources have changed. This is `false` when the
+    /// resource is refetched without any of its sources changing.
+    pub fn any(&self) -> bool {
+        self.0 != 0
+    }
+}
+
+/// A tuple of values read from the sources of a resource created with
+/// [`create_multi_source_resource`], which can tell which of its items differ
+/// from those in another tuple.
+///
+/// This is implemented for tuples of up to 12 items that each implement
+/// [`PartialEq`] and [`Clone`].
+pub trait ResourceSources: PartialEq + Clone + 'static {
+    /// Returns which items in `self` are not equal to the items in `previous`.
+    fn changes(&self, previous: &Self) -> SourceChanges;
+}
+
+macro_rules! impl_resource_sources {
+    ($($ty:ident $index:tt),*) => {
+        impl<$($ty),*> ResourceSources for ($($ty,)*)
+        where
+            $($ty: PartialEq + Clone + 'static),*
+        {
+            fn changes(&self, previous: &Self) -> SourceChanges {
+                let mut changed = 0;
+                $(
+                    if self.$index != previous.$index {
+                        changed |= 1 << $index;
+                    }
+                )*
+                SourceChanges(changed)
+            }
+        }
+    };
+}
+
+impl_resource_sources!(A 0);
+impl_resource_sources!(A 0, B 1);
+impl_resource_sources!(A 0, B 1, C 2);
+impl_resource_sources!(A 0, B 1, C 2, D 3);
+impl_resource_sources!(A 0, B 1, C 2, D 3, E 4);
+impl_resource_sources!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_resource_sources!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_resource_sources!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_resource_sources!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_resource_sources!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_resource_sources!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_resource_sources!(
+    A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11
+);
+
 #[cfg(not(feature = "hydrate"))]
 fn load_resource<S, T>(_cx: Scope, _id: ResourceId, r: Rc<ResourceState<S, T>>)
 where
@@ -473,7 +816,7 @@ where
     /// If you want to get the value without cloning it, use [`Resource::with`].
     /// (`value.read(cx)` is equivalent to `value.with(cx, T::clone)`.)
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "info", skip_all,)
     )]
     #[track_caller]
@@ -499,7 +842,7 @@ where
     /// If you want to get the value by cloning it, you can use
     /// [`Resource::read`].
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "info", skip_all,)
     )]
     #[track_caller]
@@ -516,7 +859,7 @@ where
 
     /// Returns a signal that indicates whether the resource is currently loading.
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub fn loading(&self) -> ReadSignal<bool> {
@@ -533,7 +876,7 @@ where
 
     /// Re-runs the async function with the current source data.
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub fn refetch(&self) {
@@ -544,11 +887,30 @@ where
         });
     }
 
+    /// Cancels the request the resource is currently making, if any, leaving
+    /// its value as it was and marking it as no longer loading.
+    ///
+    /// The [`Future`] for the request is dropped, and the [`ResourceAbortHandle`]
+    /// given to the fetcher of a resource created with
+    /// [`create_abortable_local_resource`] is aborted. The next change to the
+    /// `source`, or a call to [`Resource::refetch`], starts a new request.
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
+        instrument(level = "trace", skip_all,)
+    )]
+    pub fn abort(&self) {
+        _ = with_runtime(self.runtime, |runtime| {
+            runtime.resource(self.id, |resource: &ResourceState<S, T>| {
+                resource.abort()
+            })
+        });
+    }
+
     /// Returns a [`Future`] that will resolve when the resource has loaded,
     /// yield its [`ResourceId`] and a JSON string.
     #[cfg(any(feature = "ssr", doc))]
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub async fn to_serialization_resolver(
@@ -571,9 +933,43 @@ where
     }
 }
 
+impl<S, T, E> Resource<S, Result<T, E>>
+where
+    S: 'static,
+    T: 'static,
+    E: Clone + 'static,
+{
+    /// Returns a signal that holds the error the resource last loaded, or
+    /// `None` if it loaded successfully or hasn't loaded yet.
+    ///
+    /// Unlike [`Resource::read`], reading this signal does not register the
+    /// resource with the `<Suspense/>` it is read under.
+    pub fn error(&self, cx: Scope) -> Signal<Option<E>> {
+        let value = with_runtime(self.runtime, |runtime| {
+            runtime.resource(
+                self.id,
+                |resource: &ResourceState<S, Result<T, E>>| resource.value,
+            )
+        })
+        .expect(
+            "tried to call Resource::error() in a runtime that has already \
+             been disposed.",
+        );
+        Signal::derive(cx, move || {
+            value
+                .try_with(|value| match value {
+                    Some(Err(error)) => Some(error.clone()),
+                    _ => None,
+                })
+                .ok()
+                .flatten()
+        })
+    }
+}
+
 impl<S, T> SignalUpdate<Option<T>> for Resource<S, T> {
     #[cfg_attr(
-        debug_assertions,
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Resource::update()",
@@ -591,7 +987,7 @@ impl<S, T> SignalUpdate<Option<T>> for Resource<S, T> {
     }
 
     #[cfg_attr(
-        debug_assertions,
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Resource::try_update()",
@@ -629,7 +1025,7 @@ impl<S, T> SignalUpdate<Option<T>> for Resource<S, T> {
 
 impl<S, T> SignalSet<T> for Resource<S, T> {
     #[cfg_attr(
-        debug_assertions,
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Resource::set()",
@@ -647,7 +1043,7 @@ impl<S, T> SignalSet<T> for Resource<S, T> {
     }
 
     #[cfg_attr(
-        debug_assertions,
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Resource::try_set()",
@@ -719,7 +1115,7 @@ where
     pub(crate) id: ResourceId,
     pub(crate) source_ty: PhantomData<S>,
     pub(crate) out_ty: PhantomData<T>,
-    #[cfg(any(debug_assertions, features = "ssr"))]
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     pub(crate) defined_at: &'static std::panic::Location<'static>,
 }
 
@@ -735,7 +1131,7 @@ where
     T: 'static,
 {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     fn clone(&self) -> Self {
@@ -744,7 +1140,11 @@ where
             id: self.id,
             source_ty: PhantomData,
             out_ty: PhantomData,
-            #[cfg(any(debug_assertions, features = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: self.defined_at,
         }
     }
@@ -769,10 +1169,12 @@ where
     set_loading: WriteSignal<bool>,
     source: Memo<S>,
     #[allow(clippy::type_complexity)]
-    fetcher: Rc<dyn Fn(S) -> Pin<Box<dyn Future<Output = T>>>>,
+    fetcher:
+        Rc<dyn Fn(S, ResourceAbortHandle) -> Pin<Box<dyn Future<Output = T>>>>,
     resolved: Rc<Cell<bool>>,
     scheduled: Rc<Cell<bool>>,
     version: Rc<Cell<usize>>,
+    abort_handle: Rc<RefCell<Option<ResourceAbortHandle>>>,
     suspense_contexts: Rc<RefCell<HashSet<SuspenseContext>>>,
     serializable: ResourceSerialization,
 }
@@ -795,7 +1197,7 @@ where
     T: 'static,
 {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "info", skip_all,)
     )]
     #[track_caller]
@@ -811,7 +1213,7 @@ where
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "info", skip_all,)
     )]
     #[track_caller]
@@ -908,15 +1310,39 @@ where
         v
     }
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub fn refetch(&self) {
         self.load(true);
     }
+
+    pub fn abort(&self) {
+        if let Some(abort_handle) = self.abort_handle.take() {
+            abort_handle.abort();
+        }
+        if self.loading.get_untracked() {
+            self.version.set(self.version.get() + 1);
+            for suspense_context in self.suspense_contexts.borrow().iter() {
+                suspense_context.decrement(
+                    self.serializable != ResourceSerialization::Local,
+                );
+            }
+            self.set_loading.set(false);
+        }
+    }
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
-        instrument(level = "trace", skip_all,)
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
+        instrument(
+            name = "Resource::load()",
+            level = "debug",
+            skip_all,
+            fields(
+                refetching = refetching,
+                source = %std::any::type_name::<S>(),
+                ty = %std::any::type_name::<T>()
+            )
+        )
     )]
     fn load(&self, refetching: bool) {
         // doesn't refetch if already refetching
@@ -929,7 +1355,17 @@ where
         self.scheduled.set(false);
 
         _ = self.source.try_with(|source| {
-            let fut = (self.fetcher)(source.clone());
+            // a request that is still running is no longer needed
+            let (abort_handle, registration) = ResourceAbortHandle::new();
+            if let Some(previous) =
+                self.abort_handle.replace(Some(abort_handle.clone()))
+            {
+                previous.abort();
+            }
+            let fut = Abortable::new(
+                (self.fetcher)(source.clone(), abort_handle),
+                registration,
+            );
 
             // `scheduled` is true for the rest of this code only
             self.scheduled.set(true);
@@ -942,6 +1378,12 @@ where
 
             self.set_loading.update(|n| *n = true);
 
+            // resources that start loading during a transition hold it open
+            let transition = running_transition(self.set_value.runtime);
+            if let Some(transition) = transition {
+                transition.add_pending();
+            }
+
             // increment counter everywhere it's read
             let suspense_contexts = self.suspense_contexts.clone();
 
@@ -961,10 +1403,20 @@ where
                 let set_value = self.set_value;
                 let set_loading = self.set_loading;
                 let last_version = self.version.clone();
+                let abort_handle = self.abort_handle.clone();
                 async move {
                     let res = fut.await;
 
-                    if version == last_version.get() {
+                    if let Some(transition) = transition {
+                        transition.remove_pending();
+                    }
+
+                    // an aborted request has always been superseded, so its
+                    // version never matches
+                    if let (Ok(res), true) =
+                        (res, version == last_version.get())
+                    {
+                        abort_handle.take();
                         resolved.set(true);
 
                         set_value.update(|n| *n = Some(res));
@@ -984,7 +1436,7 @@ where
         });
     }
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub fn resource_to_serialization_resolver(
@@ -1024,6 +1476,57 @@ where
     }
 }
 
+/// Aborted when the request made by the fetcher of a resource created with
+/// [`create_abortable_local_resource`] is no longer needed.
+#[derive(Debug, Clone)]
+pub struct ResourceAbortHandle {
+    handle: AbortHandle,
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    controller: Rc<RefCell<Option<web_sys::AbortController>>>,
+}
+
+impl ResourceAbortHandle {
+    fn new() -> (Self, AbortRegistration) {
+        let (handle, registration) = AbortHandle::new_pair();
+        let abort_handle = Self {
+            handle,
+            #[cfg(any(feature = "csr", feature = "hydrate"))]
+            controller: Default::default(),
+        };
+        (abort_handle, registration)
+    }
+
+    /// Whether the request has been aborted.
+    pub fn is_aborted(&self) -> bool {
+        self.handle.is_aborted()
+    }
+
+    /// Returns an [`AbortSignal`](web_sys::AbortSignal) that is aborted along
+    /// with this handle, which can be passed to `fetch` (or any other browser
+    /// API that accepts one) to cancel the request itself.
+    #[cfg(any(feature = "csr", feature = "hydrate"))]
+    pub fn signal(&self) -> web_sys::AbortSignal {
+        let mut controller = self.controller.borrow_mut();
+        let controller = controller.get_or_insert_with(|| {
+            let controller = web_sys::AbortController::new()
+                .expect("could not create an AbortController");
+            if self.is_aborted() {
+                controller.abort();
+            }
+            controller
+        });
+        controller.signal()
+    }
+
+    fn abort(&self) {
+        self.handle.abort();
+        #[cfg(any(feature = "csr", feature = "hydrate"))]
+        if let Some(controller) = self.controller.borrow().as_ref() {
+            controller.abort();
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(crate) enum AnyResource {
     Unserializable(Rc<dyn UnserializableResource>),
@@ -1033,6 +1536,8 @@ pub(crate) enum AnyResource {
 pub(crate) trait SerializableResource {
     fn as_any(&self) -> &dyn Any;
 
+    fn is_loading(&self) -> bool;
+
     fn to_serialization_resolver(
         &self,
         cx: Scope,
@@ -1048,8 +1553,13 @@ where
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn is_loading(&self) -> bool {
+        self.loading.try_get_untracked().unwrap_or(false)
+    }
+
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     fn to_serialization_resolver(
@@ -1064,12 +1574,18 @@ where
 
 pub(crate) trait UnserializableResource {
     fn as_any(&self) -> &dyn Any;
+
+    fn is_loading(&self) -> bool;
 }
 
 impl<S, T> UnserializableResource for ResourceState<S, T> {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn is_loading(&self) -> bool {
+        self.loading.try_get_untracked().unwrap_or(false)
+    }
 }
 
 thread_local! {
@@ -2,9 +2,12 @@
 use crate::{
     hydration::SharedContext,
     node::{NodeId, ReactiveNode, ReactiveNodeState, ReactiveNodeType},
-    AnyComputation, AnyResource, Effect, Memo, MemoState, ReadSignal,
-    ResourceId, ResourceState, RwSignal, Scope, ScopeDisposer, ScopeId,
-    ScopeProperty, SerializableResource, StoredValueId, Trigger,
+    scheduler::Scheduler,
+    selector::SelectorCounters,
+    transition::Transitions,
+    AnyComputation, AnyResource, Effect, EffectPriority, Memo, MemoState,
+    ReadSignal, ResourceId, ResourceState, RwSignal, Scope, ScopeDisposer,
+    ScopeId, ScopeProperty, SerializableResource, StoredValueId, Trigger,
     UnserializableResource, WriteSignal,
 };
 use cfg_if::cfg_if;
@@ -20,7 +23,7 @@ use std::{
     future::Future,
     marker::PhantomData,
     pin::Pin,
-    rc::Rc,
+    rc::{Rc, Weak},
 };
 
 pub(crate) type PinnedFuture<T> = Pin<Box<dyn Future<Output = T>>>;
@@ -31,23 +34,35 @@ cfg_if! {
             pub(crate) static RUNTIME: Runtime = Runtime::new();
         }
     } else {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
         thread_local! {
             pub(crate) static RUNTIMES: RefCell<SlotMap<RuntimeId, Runtime>> = Default::default();
+
+            // disposed runtimes that have been cleared, ready to be reused
+            static RUNTIME_POOL: RefCell<Vec<Runtime>> = Default::default();
         }
+
+        static RUNTIME_POOL_CAPACITY: AtomicUsize = AtomicUsize::new(0);
     }
 }
 
-type FxIndexSet<T> = IndexSet<T, BuildHasherDefault<FxHasher>>;
+pub(crate) type FxIndexSet<T> = IndexSet<T, BuildHasherDefault<FxHasher>>;
 
 // The data structure that owns all the signals, memos, effects,
 // and other data included in the reactive system.
 #[derive(Default)]
 pub(crate) struct Runtime {
+    pub id: RuntimeId,
     pub shared_context: RefCell<SharedContext>,
     pub observer: Cell<Option<NodeId>>,
     pub scopes: RefCell<SlotMap<ScopeId, RefCell<Vec<ScopeProperty>>>>,
     pub scope_parents: RefCell<SparseSecondaryMap<ScopeId, ScopeId>>,
     pub scope_children: RefCell<SparseSecondaryMap<ScopeId, Vec<ScopeId>>>,
+    // for each type of key, a `FxHashMap<K, ScopeId>` of the scope's keyed children
+    #[allow(clippy::type_complexity)]
+    pub scope_keyed_children:
+        RefCell<SparseSecondaryMap<ScopeId, FxHashMap<TypeId, Box<dyn Any>>>>,
     #[allow(clippy::type_complexity)]
     pub scope_contexts:
         RefCell<SparseSecondaryMap<ScopeId, FxHashMap<TypeId, Box<dyn Any>>>>,
@@ -60,9 +75,24 @@ pub(crate) struct Runtime {
         RefCell<SecondaryMap<NodeId, RefCell<FxIndexSet<NodeId>>>>,
     pub node_sources:
         RefCell<SecondaryMap<NodeId, RefCell<FxIndexSet<NodeId>>>>,
-    pub pending_effects: RefCell<Vec<NodeId>>,
+    // for each signal created with a custom comparator, whether two values are equal
+    #[allow(clippy::type_complexity)]
+    pub signal_eq:
+        RefCell<SecondaryMap<NodeId, Rc<dyn Fn(&dyn Any, &dyn Any) -> bool>>>,
+    // for each memo created with `create_memo_with_previous`, an `Option<T>`
+    // holding its value before it last changed
+    pub memo_previous: RefCell<SecondaryMap<NodeId, Rc<RefCell<dyn Any>>>>,
+    // each effect is queued at most once before the queue is flushed
+    pub pending_effects: RefCell<FxIndexSet<NodeId>>,
     pub resources: RefCell<SlotMap<ResourceId, AnyResource>>,
+    pub selectors: RefCell<Vec<Weak<RefCell<SelectorCounters>>>>,
+    // whether a garbage collection pass is waiting for the browser to be idle
+    pub gc_queued: Cell<bool>,
     pub batching: Cell<bool>,
+    pub scheduler: Scheduler,
+    pub transitions: Transitions,
+    #[cfg(debug_assertions)]
+    pub time_travel: RefCell<crate::time_travel::TimeTravel>,
 }
 
 // This core Runtime impl block handles all the work of marking and updating
@@ -70,6 +100,14 @@ pub(crate) struct Runtime {
 //
 // In terms of concept and algorithm, this reactive-system implementation
 // is significantly inspired by Reactively (https://github.com/modderme123/reactively)
+//
+// Writing to a signal only marks its subscribers as `Dirty` and everything
+// further downstream as `Check`. Nodes are then updated by pulling: a node in
+// the `Check` state first updates each of its sources, in the order it read
+// them, and only reruns if one of them actually changed. Every node therefore
+// runs after everything upstream of it is settled, which gives the same
+// glitch-free ordering as sorting updates by height in the graph, without
+// having to track heights as dependencies change.
 impl Runtime {
     pub(crate) fn update_if_necessary(&self, node_id: NodeId) {
         //crate::macros::debug_warn!("update_if_necessary {node_id:?}");
@@ -311,7 +349,7 @@ impl Runtime {
         node_id: NodeId,
         node: &mut ReactiveNode,
         level: ReactiveNodeState,
-        pending_effects: &mut Vec<NodeId>,
+        pending_effects: &mut FxIndexSet<NodeId>,
         current_observer: Option<NodeId>,
     ) {
         //crate::macros::debug_warn!("marking {node_id:?} {level:?}");
@@ -322,8 +360,7 @@ impl Runtime {
         if matches!(node.node_type, ReactiveNodeType::Effect { .. } if current_observer != Some(node_id))
         {
             //crate::macros::debug_warn!("pushing effect {node_id:?}");
-            //debug_assert!(!pending_effects.contains(&node_id));
-            pending_effects.push(node_id)
+            pending_effects.insert(node_id);
         }
 
         if node.state == ReactiveNodeState::Dirty {
@@ -335,7 +372,12 @@ impl Runtime {
         if !self.batching.get() {
             let effects = self.pending_effects.take();
             for effect_id in effects {
-                self.update_if_necessary(effect_id);
+                match self.effect_priority(effect_id) {
+                    EffectPriority::UserBlocking => {
+                        self.update_if_necessary(effect_id)
+                    }
+                    priority => self.defer_effect(effect_id, priority),
+                }
             }
         }
     }
@@ -343,6 +385,8 @@ impl Runtime {
     pub(crate) fn dispose_node(&self, node: NodeId) {
         self.node_sources.borrow_mut().remove(node);
         self.node_subscribers.borrow_mut().remove(node);
+        self.signal_eq.borrow_mut().remove(node);
+        self.memo_previous.borrow_mut().remove(node);
         self.nodes.borrow_mut().remove(node);
     }
 }
@@ -361,7 +405,7 @@ impl Debug for Runtime {
 /// Get the selected runtime from the thread-local set of runtimes. On the server,
 /// this will return the correct runtime. In the browser, there should only be one runtime.
 #[cfg_attr(
-    any(debug_assertions, feature = "ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(level = "trace", skip_all,)
 )]
 #[inline(always)] // it monomorphizes anyway
@@ -394,7 +438,35 @@ pub fn create_runtime() -> RuntimeId {
         if #[cfg(any(feature = "csr", feature = "hydrate"))] {
             Default::default()
         } else {
-            RUNTIMES.with(|runtimes| runtimes.borrow_mut().insert(Runtime::new()))
+            let pooled = RUNTIME_POOL.with(|pool| pool.borrow_mut().pop());
+            RUNTIMES.with(|runtimes| {
+                runtimes.borrow_mut().insert_with_key(|id| match pooled {
+                    Some(mut runtime) => {
+                        runtime.id = id;
+                        runtime
+                    }
+                    None => Runtime { id, ..Runtime::new() },
+                })
+            })
+        }
+    }
+}
+
+/// Sets the number of disposed runtimes that each thread keeps, so that
+/// [`create_runtime`] can reuse their allocations instead of making new ones.
+/// Defaults to `0`, which means runtimes are never reused.
+///
+/// This is useful on the server, where a runtime is created and disposed for every
+/// request. A runtime is cleared completely before it is reused, and is given a new
+/// [`RuntimeId`], so that signals, scopes and resources from a previous request
+/// can never be reached through it. In the browser, this does nothing.
+pub fn set_runtime_pool_capacity(capacity: usize) {
+    cfg_if! {
+        if #[cfg(not(any(feature = "csr", feature = "hydrate")))] {
+            RUNTIME_POOL_CAPACITY.store(capacity, Ordering::Relaxed);
+            RUNTIME_POOL.with(|pool| pool.borrow_mut().truncate(capacity));
+        } else {
+            _ = capacity;
         }
     }
 }
@@ -416,7 +488,18 @@ impl RuntimeId {
         cfg_if! {
             if #[cfg(not(any(feature = "csr", feature = "hydrate")))] {
                 let runtime = RUNTIMES.with(move |runtimes| runtimes.borrow_mut().remove(self));
-                drop(runtime);
+                let capacity = RUNTIME_POOL_CAPACITY.load(Ordering::Relaxed);
+                if let Some(mut runtime) = runtime.filter(|_| capacity > 0) {
+                    // values dropped while clearing the runtime can't reach it,
+                    // because it has already been removed
+                    runtime.clear();
+                    RUNTIME_POOL.with(|pool| {
+                        let mut pool = pool.borrow_mut();
+                        if pool.len() < capacity {
+                            pool.push(runtime);
+                        }
+                    });
+                }
             }
         }
     }
@@ -489,7 +572,11 @@ impl RuntimeId {
         Trigger {
             id,
             runtime: self,
-            #[cfg(debug_assertions)]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: std::panic::Location::caller(),
         }
     }
@@ -526,14 +613,22 @@ impl RuntimeId {
                 runtime: self,
                 id,
                 ty: PhantomData,
-                #[cfg(any(debug_assertions, feature = "ssr"))]
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
                 defined_at: std::panic::Location::caller(),
             },
             WriteSignal {
                 runtime: self,
                 id,
                 ty: PhantomData,
-                #[cfg(any(debug_assertions, feature = "ssr"))]
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
                 defined_at: std::panic::Location::caller(),
             },
         )
@@ -577,14 +672,22 @@ impl RuntimeId {
                             runtime: self,
                             id,
                             ty: PhantomData,
-                            #[cfg(any(debug_assertions, feature = "ssr"))]
+                            #[cfg(any(
+                                debug_assertions,
+                                feature = "ssr",
+                                feature = "tracing"
+                            ))]
                             defined_at: std::panic::Location::caller(),
                         },
                         WriteSignal {
                             runtime: self,
                             id,
                             ty: PhantomData,
-                            #[cfg(any(debug_assertions, feature = "ssr"))]
+                            #[cfg(any(
+                                debug_assertions,
+                                feature = "ssr",
+                                feature = "tracing"
+                            ))]
                             defined_at: std::panic::Location::caller(),
                         },
                     )
@@ -612,7 +715,11 @@ impl RuntimeId {
             runtime: self,
             id,
             ty: PhantomData,
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: std::panic::Location::caller(),
         }
     }
@@ -675,8 +782,14 @@ impl RuntimeId {
             Rc::new(Effect {
                 f,
                 ty: PhantomData,
-                #[cfg(any(debug_assertions, feature = "ssr"))]
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
                 defined_at: std::panic::Location::caller(),
+                #[cfg(debug_assertions)]
+                budget: crate::PerformanceBudget::current(),
             }),
         )
     }
@@ -686,23 +799,41 @@ impl RuntimeId {
     pub(crate) fn create_memo<T>(
         self,
         f: impl Fn(Option<&T>) -> T + 'static,
+        keep_previous: bool,
     ) -> Memo<T>
     where
         T: PartialEq + Any + 'static,
     {
+        let previous = keep_previous.then(|| Rc::new(RefCell::new(None::<T>)));
+        let id = self.create_concrete_memo(
+            Rc::new(RefCell::new(None::<T>)),
+            Rc::new(MemoState {
+                f,
+                t: PhantomData,
+                previous: previous.clone(),
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
+                defined_at: std::panic::Location::caller(),
+            }),
+        );
+        if let Some(previous) = previous {
+            _ = with_runtime(self, |runtime| {
+                runtime.memo_previous.borrow_mut().insert(id, previous);
+            });
+        }
+
         Memo {
             runtime: self,
-            id: self.create_concrete_memo(
-                Rc::new(RefCell::new(None::<T>)),
-                Rc::new(MemoState {
-                    f,
-                    t: PhantomData,
-                    #[cfg(any(debug_assertions, feature = "ssr"))]
-                    defined_at: std::panic::Location::caller(),
-                }),
-            ),
+            id,
             ty: PhantomData,
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: std::panic::Location::caller(),
         }
     }
@@ -713,6 +844,65 @@ impl Runtime {
         Self::default()
     }
 
+    // Drops everything in the runtime, keeping the memory that has been allocated
+    // for it, so that it can be reused for a different runtime.
+    #[cfg(not(any(feature = "csr", feature = "hydrate")))]
+    fn clear(&mut self) {
+        // destructured, so that any new field has to be cleared here too
+        let Runtime {
+            id: _,
+            shared_context,
+            observer,
+            scopes,
+            scope_parents,
+            scope_children,
+            scope_keyed_children,
+            scope_contexts,
+            scope_cleanups,
+            stored_values,
+            nodes,
+            node_subscribers,
+            node_sources,
+            signal_eq,
+            memo_previous,
+            pending_effects,
+            resources,
+            selectors,
+            gc_queued,
+            batching,
+            scheduler,
+            transitions,
+            #[cfg(debug_assertions)]
+            time_travel,
+        } = self;
+
+        *shared_context.get_mut() = Default::default();
+        observer.set(None);
+        scopes.get_mut().clear();
+        scope_parents.get_mut().clear();
+        scope_children.get_mut().clear();
+        scope_keyed_children.get_mut().clear();
+        scope_contexts.get_mut().clear();
+        scope_cleanups.get_mut().clear();
+        stored_values.get_mut().clear();
+        nodes.get_mut().clear();
+        node_subscribers.get_mut().clear();
+        node_sources.get_mut().clear();
+        signal_eq.get_mut().clear();
+        memo_previous.get_mut().clear();
+        pending_effects.get_mut().clear();
+        resources.get_mut().clear();
+        selectors.get_mut().clear();
+        gc_queued.set(false);
+        batching.set(false);
+        *scheduler = Default::default();
+        *transitions = Default::default();
+        #[cfg(debug_assertions)]
+        {
+            *time_travel.get_mut() = Default::default();
+        }
+    }
+
     pub(crate) fn create_unserializable_resource(
         &self,
         state: Rc<dyn UnserializableResource>,
@@ -731,7 +921,7 @@ impl Runtime {
             .insert(AnyResource::Serializable(state))
     }
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub(crate) fn resource<S, T, U>(
@@ -775,6 +965,22 @@ impl Runtime {
             .collect()
     }
 
+    /// Returns IDs for all [resources](crate::Resource) found on any scope
+    /// that are loading.
+    pub(crate) fn loading_resources(&self) -> Vec<ResourceId> {
+        self.resources
+            .borrow()
+            .iter()
+            .filter_map(|(resource_id, res)| {
+                let loading = match res {
+                    AnyResource::Serializable(res) => res.is_loading(),
+                    AnyResource::Unserializable(res) => res.is_loading(),
+                };
+                loading.then_some(resource_id)
+            })
+            .collect()
+    }
+
     /// Returns IDs for all [resources](crate::Resource) found on any
     /// scope, pending from the server.
     pub(crate) fn pending_resources(&self) -> Vec<ResourceId> {
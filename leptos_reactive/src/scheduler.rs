@@ -0,0 +1,217 @@
+#![forbid(unsafe_code)]
+use crate::{
+    node::NodeId,
+    queue_microtask,
+    runtime::{with_runtime, FxIndexSet, Runtime},
+    Scope,
+};
+use cfg_if::cfg_if;
+use slotmap::SecondaryMap;
+use std::cell::{Cell, RefCell};
+
+/// The priority with which an effect runs after one of its sources changes.
+///
+/// In the browser, effects with a lower priority are batched: no matter how many
+/// times their sources change in the meantime, they run only once.
+///
+/// Outside the browser, microtasks and idle callbacks run immediately, so every
+/// priority runs as soon as one of its sources changes, like
+/// [`UserBlocking`](EffectPriority::UserBlocking). Only updates made inside
+/// [`Scope::batch`] are coalesced there.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EffectPriority {
+    /// Runs synchronously, as soon as one of its sources changes. This is the
+    /// priority of effects created with [`create_effect`](crate::create_effect).
+    #[default]
+    UserBlocking,
+    /// Runs in a microtask, once the current task has finished making changes.
+    Normal,
+    /// Runs when the browser is idle, using
+    /// [`requestIdleCallback`](https://developer.mozilla.org/en-US/docs/Web/API/Window/requestIdleCallback)
+    /// where it is available.
+    Idle,
+}
+
+// Lower-priority effects that are waiting to run.
+#[derive(Default)]
+pub(crate) struct Scheduler {
+    priorities: RefCell<SecondaryMap<NodeId, EffectPriority>>,
+    normal: RefCell<FxIndexSet<NodeId>>,
+    idle: RefCell<FxIndexSet<NodeId>>,
+    normal_queued: Cell<bool>,
+    idle_queued: Cell<bool>,
+}
+
+/// Creates an effect that runs with the given [`EffectPriority`]. Apart from when it
+/// runs, this works just like [`create_effect`](crate::create_effect): it runs once
+/// immediately, and does not run on the server. Outside the browser, it runs as
+/// soon as one of its sources changes, whatever its priority.
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// let (count, set_count) = create_signal(cx, 0);
+///
+/// create_effect_with_priority(cx, EffectPriority::Normal, move |_| {
+///     // in the browser, this runs once after all three updates below,
+///     // instead of once per update
+///     log::debug!("count is {}", count.get());
+/// });
+///
+/// set_count.set(1);
+/// set_count.set(2);
+/// set_count.set(3);
+/// # }).dispose();
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
+    instrument(
+        level = "trace",
+        skip_all,
+        fields(
+            scope = ?cx.id,
+            ty = %std::any::type_name::<T>()
+        )
+    )
+)]
+#[track_caller]
+#[inline(always)]
+pub fn create_effect_with_priority<T>(
+    cx: Scope,
+    priority: EffectPriority,
+    f: impl Fn(Option<T>) -> T + 'static,
+) where
+    T: 'static,
+{
+    cfg_if! {
+        if #[cfg(not(feature = "ssr"))] {
+            let e = cx.runtime.create_effect(f);
+            _ = with_runtime(cx.runtime, |runtime| {
+                runtime.scheduler.priorities.borrow_mut().insert(e, priority);
+            });
+            cx.push_scope_property(crate::ScopeProperty::Effect(e))
+        } else {
+            // clear warnings
+            _ = cx;
+            _ = priority;
+            _ = f;
+        }
+    }
+}
+
+/// Runs the given function, then immediately runs every effect that is waiting to
+/// run, whatever its [`EffectPriority`].
+///
+/// This is useful when you need the effects of a change to have been applied
+/// (for example, to measure the DOM) before continuing.
+///
+/// This also works inside [`Scope::batch`], or an event handler whose effects
+/// are held back until it returns: the effects waiting at that point run, and
+/// any later updates are batched as before.
+pub fn flush_sync<T>(cx: Scope, f: impl FnOnce() -> T) -> T {
+    let value = f();
+    _ = with_runtime(cx.runtime, |runtime| {
+        let batching = runtime.batching.replace(false);
+        runtime.run_effects();
+        while runtime.flush_effects(EffectPriority::Normal)
+            || runtime.flush_effects(EffectPriority::Idle)
+        {}
+        runtime.batching.set(batching);
+    });
+    value
+}
+
+impl Runtime {
+    pub(crate) fn effect_priority(&self, effect: NodeId) -> EffectPriority {
+        self.scheduler
+            .priorities
+            .borrow()
+            .get(effect)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn remove_effect_priority(&self, effect: NodeId) {
+        self.scheduler.priorities.borrow_mut().remove(effect);
+    }
+
+    pub(crate) fn defer_effect(
+        &self,
+        effect: NodeId,
+        priority: EffectPriority,
+    ) {
+        let (queue, queued) = match priority {
+            EffectPriority::UserBlocking => {
+                self.update_if_necessary(effect);
+                return;
+            }
+            EffectPriority::Normal => {
+                (&self.scheduler.normal, &self.scheduler.normal_queued)
+            }
+            EffectPriority::Idle => {
+                (&self.scheduler.idle, &self.scheduler.idle_queued)
+            }
+        };
+        queue.borrow_mut().insert(effect);
+
+        if !queued.replace(true) {
+            let runtime = self.id;
+            let flush = move || {
+                _ = with_runtime(runtime, |runtime| {
+                    runtime.flush_effects(priority)
+                });
+            };
+            if priority == EffectPriority::Idle {
+                queue_idle_callback(flush);
+            } else {
+                queue_microtask(flush);
+            }
+        }
+    }
+
+    // Runs the effects waiting in the given lane. Returns `false` if there were none.
+    fn flush_effects(&self, priority: EffectPriority) -> bool {
+        let (queue, queued) = match priority {
+            EffectPriority::UserBlocking => return false,
+            EffectPriority::Normal => {
+                (&self.scheduler.normal, &self.scheduler.normal_queued)
+            }
+            EffectPriority::Idle => {
+                (&self.scheduler.idle, &self.scheduler.idle_queued)
+            }
+        };
+        queued.set(false);
+        let effects = queue.take();
+        for effect in &effects {
+            self.update_if_necessary(*effect);
+        }
+        !effects.is_empty()
+    }
+}
+
+pub(crate) fn queue_idle_callback(task: impl FnOnce() + 'static) {
+    cfg_if! {
+        if #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "hydrate", feature = "csr")
+        ))] {
+            use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+
+            let task = Closure::once_into_js(task);
+            let queued = web_sys::window()
+                .map(|window| {
+                    window.request_idle_callback(task.unchecked_ref()).is_ok()
+                })
+                .unwrap_or(false);
+            // `requestIdleCallback` is not supported everywhere
+            if !queued {
+                queue_microtask(move || {
+                    _ = task
+                        .unchecked_ref::<js_sys::Function>()
+                        .call0(&JsValue::NULL);
+                });
+            }
+        } else {
+            queue_microtask(task);
+        }
+    }
+}
@@ -9,9 +9,12 @@ use crate::{
     SuspenseContext,
 };
 use futures::stream::FuturesUnordered;
+use rustc_hash::FxHashMap;
 use std::{
+    any::TypeId,
     collections::{HashMap, VecDeque},
     fmt,
+    hash::Hash,
 };
 
 #[doc(hidden)]
@@ -38,7 +41,7 @@ pub fn create_scope(
 ///
 /// You usually don't need to call this manually.
 #[cfg_attr(
-    any(debug_assertions, features = "ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(level = "trace", skip_all,)
 )]
 pub fn raw_scope_and_disposer(runtime: RuntimeId) -> (Scope, ScopeDisposer) {
@@ -53,7 +56,7 @@ pub fn raw_scope_and_disposer(runtime: RuntimeId) -> (Scope, ScopeDisposer) {
 ///
 /// You usually don't need to call this manually.
 #[cfg_attr(
-    any(debug_assertions, features = "ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(level = "trace", skip_all,)
 )]
 pub fn run_scope<T>(
@@ -70,7 +73,7 @@ pub fn run_scope<T>(
 ///
 /// You usually don't need to call this manually.
 #[cfg_attr(
-    any(debug_assertions, features = "ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(level = "trace", skip_all,)
 )]
 pub fn run_scope_undisposed<T>(
@@ -129,7 +132,7 @@ impl Scope {
     /// dispose of them when they are no longer needed (e.g., a list item has been destroyed or the user
     /// has navigated away from the route.)
     #[cfg_attr(
-        any(debug_assertions, features = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     #[inline(always)]
@@ -148,7 +151,7 @@ impl Scope {
     /// dispose of them when they are no longer needed (e.g., a list item has been destroyed or the user
     /// has navigated away from the route.)
     #[cfg_attr(
-        any(debug_assertions, features = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     #[inline(always)]
@@ -178,6 +181,115 @@ impl Scope {
         });
     }
 
+    /// Creates a child scope identified by `key` and runs the given function within it,
+    /// returning the function's return value.
+    ///
+    /// The child scope can later be looked up with [`Scope::keyed_child`] and disposed of with
+    /// [`Scope::dispose_child`]. Like any other child scope, it will be disposed when the parent is
+    /// disposed, if it has not been already. If this scope already has a child with the same key,
+    /// that child is disposed first. Keys are compared by type as well as value, so `1_u32` and
+    /// `1_usize` identify different children.
+    ///
+    /// This is useful for keyed control flow (like a list that creates a scope for each item) built
+    /// outside of Leptos, which needs to dispose of the scope for one key without keeping track of
+    /// a [`ScopeDisposer`] for each.
+    /// ```
+    /// # use leptos_reactive::*;
+    /// # run_scope(create_runtime(), |cx| {
+    /// let row = cx.keyed_child_scope(7, |cx| create_rw_signal(cx, "row 7"));
+    /// assert_eq!(row.get(), "row 7");
+    ///
+    /// // the row has been removed, so we're done with its scope
+    /// assert!(cx.dispose_child(&7));
+    /// assert_eq!(row.try_get(), None);
+    /// # });
+    /// ```
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
+        instrument(level = "trace", skip_all,)
+    )]
+    pub fn keyed_child_scope<K, T>(
+        self,
+        key: K,
+        f: impl FnOnce(Scope) -> T,
+    ) -> T
+    where
+        K: Hash + Eq + 'static,
+    {
+        self.dispose_child(&key);
+        let (res, child_id, _) =
+            self.runtime.run_scope_undisposed(f, Some(self));
+        self.push_child(child_id);
+
+        _ = with_runtime(self.runtime, |runtime| {
+            let mut keyed_children = runtime.scope_keyed_children.borrow_mut();
+            if let Some(entry) = keyed_children.entry(self.id) {
+                entry
+                    .or_default()
+                    .entry(TypeId::of::<K>())
+                    .or_insert_with(|| Box::<FxHashMap<K, ScopeId>>::default())
+                    .downcast_mut::<FxHashMap<K, ScopeId>>()
+                    .expect("keyed children should be stored by key type")
+                    .insert(key, child_id);
+            }
+        });
+
+        res
+    }
+
+    /// Returns the child scope created by [`Scope::keyed_child_scope`] with the given key, if it
+    /// exists and has not been disposed.
+    pub fn keyed_child<K>(self, key: &K) -> Option<Scope>
+    where
+        K: Hash + Eq + 'static,
+    {
+        with_runtime(self.runtime, |runtime| {
+            let id = *runtime
+                .scope_keyed_children
+                .borrow()
+                .get(self.id)?
+                .get(&TypeId::of::<K>())?
+                .downcast_ref::<FxHashMap<K, ScopeId>>()?
+                .get(key)?;
+            runtime.scopes.borrow().contains_key(id).then_some(Scope {
+                runtime: self.runtime,
+                id,
+            })
+        })
+        .ok()
+        .flatten()
+    }
+
+    /// Disposes of the child scope created by [`Scope::keyed_child_scope`] with the given key.
+    /// Returns `false` if there was no such child, or if it had already been disposed.
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
+        instrument(level = "trace", skip_all,)
+    )]
+    pub fn dispose_child<K>(self, key: &K) -> bool
+    where
+        K: Hash + Eq + 'static,
+    {
+        let Some(child) = self.keyed_child(key) else {
+            return false;
+        };
+        _ = with_runtime(self.runtime, |runtime| {
+            if let Some(children) = runtime
+                .scope_keyed_children
+                .borrow_mut()
+                .get_mut(self.id)
+                .and_then(|children| children.get_mut(&TypeId::of::<K>()))
+                .and_then(|children| {
+                    children.downcast_mut::<FxHashMap<K, ScopeId>>()
+                })
+            {
+                children.remove(key);
+            }
+        });
+        child.dispose();
+        true
+    }
+
     /// Suspends reactive tracking while running the given function.
     ///
     /// This can be used to isolate parts of the reactive graph from one another.
@@ -204,7 +316,7 @@ impl Scope {
     /// # });
     /// ```
     #[cfg_attr(
-        any(debug_assertions, features = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     #[inline(always)]
@@ -253,7 +365,7 @@ impl Scope {
     /// 2. run all cleanup functions defined for this scope by [`on_cleanup`](crate::on_cleanup).
     /// 3. dispose of all signals, effects, and resources owned by this `Scope`.
     #[cfg_attr(
-        any(debug_assertions, features = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub fn dispose(self) {
@@ -273,6 +385,7 @@ impl Scope {
                     .dispose();
                 }
             }
+            runtime.scope_keyed_children.borrow_mut().remove(self.id);
             // run cleanups
             if let Some(cleanups) =
                 runtime.scope_cleanups.borrow_mut().remove(self.id)
@@ -296,6 +409,7 @@ impl Scope {
                         | ScopeProperty::Trigger(id) => {
                             // remove the signal
                             runtime.nodes.borrow_mut().remove(id);
+                            runtime.signal_eq.borrow_mut().remove(id);
                             let subs = runtime
                                 .node_subscribers
                                 .borrow_mut()
@@ -317,6 +431,8 @@ impl Scope {
                         ScopeProperty::Effect(id) => {
                             runtime.nodes.borrow_mut().remove(id);
                             runtime.node_sources.borrow_mut().remove(id);
+                            runtime.memo_previous.borrow_mut().remove(id);
+                            runtime.remove_effect_priority(id);
                         }
                         ScopeProperty::Resource(id) => {
                             runtime.resources.borrow_mut().remove(id);
@@ -330,7 +446,7 @@ impl Scope {
         })
     }
     #[cfg_attr(
-        any(debug_assertions, features = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub(crate) fn push_scope_property(&self, prop: ScopeProperty) {
@@ -341,12 +457,23 @@ impl Scope {
             } else {
                 console_warn(
                     "tried to add property to a scope that has been disposed",
-                )
+                );
+                // nothing will dispose of the property, so collect it later
+                #[cfg(all(
+                    target_arch = "wasm32",
+                    any(feature = "csr", feature = "hydrate")
+                ))]
+                if !runtime.gc_queued.replace(true) {
+                    let id = self.runtime;
+                    crate::scheduler::queue_idle_callback(move || {
+                        id.gc();
+                    });
+                }
             }
         })
     }
     #[cfg_attr(
-        any(debug_assertions, features = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     /// Returns the the parent Scope, if any.
@@ -364,7 +491,7 @@ impl Scope {
 }
 
 #[cfg_attr(
-    any(debug_assertions, features = "ssr"),
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(level = "trace", skip_all,)
 )]
 fn push_cleanup(cx: Scope, cleanup_fn: Box<dyn FnOnce()>) {
@@ -427,7 +554,7 @@ impl ScopeDisposer {
 impl Scope {
     /// Returns IDs for all [`Resource`](crate::Resource)s found on any scope.
     #[cfg_attr(
-        any(debug_assertions, features = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub fn all_resources(&self) -> Vec<ResourceId> {
@@ -435,10 +562,21 @@ impl Scope {
             .unwrap_or_default()
     }
 
+    /// Returns IDs for all [`Resource`](crate::Resource)s found on any scope that are
+    /// loading.
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
+        instrument(level = "trace", skip_all,)
+    )]
+    pub fn loading_resources(&self) -> Vec<ResourceId> {
+        with_runtime(self.runtime, |runtime| runtime.loading_resources())
+            .unwrap_or_default()
+    }
+
     /// Returns IDs for all [`Resource`](crate::Resource)s found on any scope that are
     /// pending from the server.
     #[cfg_attr(
-        any(debug_assertions, features = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub fn pending_resources(&self) -> Vec<ResourceId> {
@@ -448,7 +586,7 @@ impl Scope {
 
     /// Returns IDs for all [`Resource`](crate::Resource)s found on any scope.
     #[cfg_attr(
-        any(debug_assertions, features = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub fn serialization_resolvers(
@@ -463,7 +601,7 @@ impl Scope {
     /// Registers the given [`SuspenseContext`](crate::SuspenseContext) with the current scope,
     /// calling the `resolver` when its resources are all resolved.
     #[cfg_attr(
-        any(debug_assertions, features = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub fn register_suspense(
@@ -520,7 +658,7 @@ impl Scope {
     /// The keys are hydration IDs. Values are tuples of two pinned
     /// `Future`s that return content for out-of-order and in-order streaming, respectively.
     #[cfg_attr(
-        any(debug_assertions, features = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub fn pending_fragments(&self) -> HashMap<String, FragmentData> {
@@ -533,7 +671,7 @@ impl Scope {
 
     /// A future that will resolve when all blocking fragments are ready.
     #[cfg_attr(
-        any(debug_assertions, features = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub fn blocking_fragments_ready(self) -> PinnedFuture<()> {
@@ -560,7 +698,7 @@ impl Scope {
     /// Returns a tuple of two pinned `Future`s that return content for out-of-order
     /// and in-order streaming, respectively.
     #[cfg_attr(
-        any(debug_assertions, features = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     pub fn take_pending_fragment(&self, id: &str) -> Option<FragmentData> {
@@ -579,7 +717,7 @@ impl Scope {
     /// # Panics
     /// Panics if the runtime this scope belongs to has already been disposed.
     #[cfg_attr(
-        any(debug_assertions, features = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(level = "trace", skip_all,)
     )]
     #[inline(always)]
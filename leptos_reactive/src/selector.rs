@@ -1,9 +1,92 @@
 #![forbid(unsafe_code)]
 use crate::{
-    create_isomorphic_effect, create_signal, ReadSignal, Scope, SignalUpdate,
+    create_isomorphic_effect, create_signal, node::NodeId,
+    runtime::with_runtime, ReadSignal, RuntimeId, Scope, SignalUpdate,
     WriteSignal,
 };
-use std::{cell::RefCell, collections::HashMap, hash::Hash, rc::Rc};
+use rustc_hash::FxHashMap;
+use std::{
+    cell::RefCell, collections::HashMap, hash::Hash, panic::Location, rc::Rc,
+};
+
+// The number of keys included in [`SelectorStats::hot_keys`].
+const HOT_KEYS: usize = 10;
+
+/// Statistics about a selector created with [`create_selector`] or
+/// [`create_selector_with_fn`], returned by [`RuntimeId::selector_stats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorStats {
+    /// Where the selector was created.
+    pub defined_at: &'static Location<'static>,
+    /// The number of keys the selector has been called with. Each key keeps
+    /// its own signal for as long as the selector exists, so this only grows.
+    pub entries: usize,
+    /// The total number of times any key has been notified of a change.
+    pub notifications: usize,
+    /// The keys that have been notified most often, most notified first.
+    /// At most 10 keys are included.
+    pub hot_keys: Vec<SelectorKeyStats>,
+}
+
+/// How often a single key of a selector has been notified, as part of
+/// [`SelectorStats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectorKeyStats {
+    /// The signal the selector created for this key, which can be found in
+    /// a [`GraphSnapshot`](crate::GraphSnapshot).
+    pub signal: NodeId,
+    /// The number of times the key has been notified of a change.
+    pub notifications: usize,
+}
+
+pub(crate) struct SelectorCounters {
+    defined_at: &'static Location<'static>,
+    notifications: usize,
+    // the number of notifications for the signal created for each key
+    keys: FxHashMap<NodeId, usize>,
+}
+
+impl SelectorCounters {
+    fn stats(&self) -> SelectorStats {
+        let mut hot_keys = self
+            .keys
+            .iter()
+            .map(|(signal, notifications)| SelectorKeyStats {
+                signal: *signal,
+                notifications: *notifications,
+            })
+            .collect::<Vec<_>>();
+        hot_keys.sort_by_key(|key| std::cmp::Reverse(key.notifications));
+        hot_keys.truncate(HOT_KEYS);
+
+        SelectorStats {
+            defined_at: self.defined_at,
+            entries: self.keys.len(),
+            notifications: self.notifications,
+            hot_keys,
+        }
+    }
+}
+
+impl RuntimeId {
+    /// Returns [`SelectorStats`] for every selector that currently exists in
+    /// this runtime, in the order they were created.
+    ///
+    /// This is useful for finding selectors whose key space grows without bound,
+    /// or keys that are notified far more often than expected.
+    pub fn selector_stats(self) -> Vec<SelectorStats> {
+        with_runtime(self, |runtime| {
+            let mut selectors = runtime.selectors.borrow_mut();
+            selectors.retain(|counters| counters.strong_count() > 0);
+            selectors
+                .iter()
+                .filter_map(|counters| counters.upgrade())
+                .map(|counters| counters.borrow().stats())
+                .collect()
+        })
+        .unwrap_or_default()
+    }
+}
 
 /// Creates a conditional signal that only notifies subscribers when a change
 /// in the source signal’s value changes whether it is equal to the key value
@@ -45,6 +128,7 @@ use std::{cell::RefCell, collections::HashMap, hash::Hash, rc::Rc};
 ///  # .dispose()
 /// ```
 #[inline(always)]
+#[track_caller]
 pub fn create_selector<T>(
     cx: Scope,
     source: impl Fn() -> T + Clone + 'static,
@@ -61,6 +145,7 @@ where
 /// **You probably don’t need this,** but it can be a very useful optimization
 /// in certain situations (e.g., “set the class `selected` if `selected() == this_row_index`)
 /// because it reduces them from `O(n)` to `O(1)`.
+#[track_caller]
 pub fn create_selector_with_fn<T>(
     cx: Scope,
     source: impl Fn() -> T + Clone + 'static,
@@ -74,11 +159,23 @@ where
         RefCell<HashMap<T, (ReadSignal<bool>, WriteSignal<bool>)>>,
     > = Rc::new(RefCell::new(HashMap::new()));
     let v = Rc::new(RefCell::new(None));
+    let counters = Rc::new(RefCell::new(SelectorCounters {
+        defined_at: Location::caller(),
+        notifications: 0,
+        keys: FxHashMap::default(),
+    }));
+    _ = with_runtime(cx.runtime, |runtime| {
+        runtime
+            .selectors
+            .borrow_mut()
+            .push(Rc::downgrade(&counters))
+    });
 
     create_isomorphic_effect(cx, {
         let subs = Rc::clone(&subs);
         let f = f.clone();
         let v = Rc::clone(&v);
+        let counters = Rc::clone(&counters);
         move |prev: Option<T>| {
             let next_value = source();
             *v.borrow_mut() = Some(next_value.clone());
@@ -88,6 +185,11 @@ where
                     if f(&key, &next_value)
                         || (prev.is_some() && f(&key, prev.as_ref().unwrap()))
                     {
+                        {
+                            let mut counters = counters.borrow_mut();
+                            counters.notifications += 1;
+                            *counters.keys.entry(signal.0.id).or_default() += 1;
+                        }
                         signal.1.update(|n| *n = true);
                     }
                 }
@@ -98,9 +200,11 @@ where
 
     move |key| {
         let mut subs = subs.borrow_mut();
-        let (read, _) = subs
-            .entry(key.clone())
-            .or_insert_with(|| create_signal(cx, false));
+        let (read, _) = subs.entry(key.clone()).or_insert_with(|| {
+            let signal = create_signal(cx, false);
+            counters.borrow_mut().keys.insert(signal.0.id, 0);
+            signal
+        });
         _ = read.try_with(|n| *n);
         f(&key, v.borrow().as_ref().unwrap())
     }
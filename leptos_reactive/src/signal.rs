@@ -313,7 +313,7 @@ pub trait SignalDispose {
 /// #
 /// ```
 #[cfg_attr(
- any(debug_assertions, features="ssr"),
+ any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "trace",
         skip_all,
@@ -333,9 +333,64 @@ pub fn create_signal<T>(
     s
 }
 
+/// Creates a signal like [`create_signal`], which only notifies its subscribers
+/// when it is set to a value that `eq` does not consider equal to its current
+/// value.
+///
+/// This applies to [`set`](SignalSet::set) and [`try_set`](SignalSet::try_set),
+/// which replace the value. Updating the value in place with
+/// [`update`](SignalUpdate::update) always notifies subscribers, because the old
+/// value is no longer there to compare against.
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// // only notify subscribers when the position moves by at least one pixel
+/// let (position, set_position) =
+///     create_signal_with_eq(cx, 0.0_f64, |a, b| (a - b).abs() < 1.0);
+/// let runs = store_value(cx, 0);
+/// create_isomorphic_effect(cx, move |_| {
+///     position.get();
+///     runs.update_value(|n| *n += 1);
+/// });
+///
+/// set_position.set(0.5);
+/// assert_eq!(runs.get_value(), 1);
+/// set_position.set(2.0);
+/// assert_eq!(runs.get_value(), 2);
+/// # }).dispose();
+/// ```
+#[track_caller]
+pub fn create_signal_with_eq<T>(
+    cx: Scope,
+    value: T,
+    eq: impl Fn(&T, &T) -> bool + 'static,
+) -> (ReadSignal<T>, WriteSignal<T>) {
+    let s = create_signal(cx, value);
+    set_signal_eq(cx.runtime, s.0.id, eq);
+    s
+}
+
+fn set_signal_eq<T: 'static>(
+    runtime: RuntimeId,
+    id: NodeId,
+    eq: impl Fn(&T, &T) -> bool + 'static,
+) {
+    _ = with_runtime(runtime, |runtime| {
+        runtime.signal_eq.borrow_mut().insert(
+            id,
+            Rc::new(move |a: &dyn Any, b: &dyn Any| {
+                match (a.downcast_ref::<T>(), b.downcast_ref::<T>()) {
+                    (Some(a), Some(b)) => eq(a, b),
+                    _ => false,
+                }
+            }),
+        );
+    });
+}
+
 /// Works exactly as [`create_signal`], but creates multiple signals at once.
 #[cfg_attr(
- any(debug_assertions, features="ssr"),
+ any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "trace",
         skip_all,
@@ -355,7 +410,7 @@ pub fn create_many_signals<T>(
 
 /// Works exactly as [`create_many_signals`], but applies the map function to each signal pair.
 #[cfg_attr(
- any(debug_assertions, features="ssr"),
+ any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "trace",
         skip_all,
@@ -385,7 +440,7 @@ where
 /// **Note**: If used on the server side during server rendering, this will return `None`
 /// immediately and not begin driving the stream.
 #[cfg_attr(
- any(debug_assertions, features="ssr"),
+ any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "trace",
         skip_all,
@@ -476,13 +531,13 @@ where
     pub(crate) runtime: RuntimeId,
     pub(crate) id: NodeId,
     pub(crate) ty: PhantomData<T>,
-    #[cfg(any(debug_assertions, feature = "ssr"))]
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     pub(crate) defined_at: &'static std::panic::Location<'static>,
 }
 
 impl<T: Clone> SignalGetUntracked<T> for ReadSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "ReadSignal::get_untracked()",
@@ -502,14 +557,18 @@ impl<T: Clone> SignalGetUntracked<T> for ReadSignal<T> {
         {
             Ok(t) => t,
             Err(_) => panic_getting_dead_signal(
-                #[cfg(any(debug_assertions, feature = "ssr"))]
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
                 self.defined_at,
             ),
         }
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "ReadSignal::try_get_untracked()",
@@ -533,7 +592,7 @@ impl<T: Clone> SignalGetUntracked<T> for ReadSignal<T> {
 
 impl<T> SignalWithUntracked<T> for ReadSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "ReadSignal::with_untracked()",
@@ -551,7 +610,7 @@ impl<T> SignalWithUntracked<T> for ReadSignal<T> {
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "ReadSignal::try_with_untracked()",
@@ -597,7 +656,7 @@ impl<T> SignalWithUntracked<T> for ReadSignal<T> {
 /// ```
 impl<T> SignalWith<T> for ReadSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "ReadSignal::with()",
@@ -621,14 +680,18 @@ impl<T> SignalWith<T> for ReadSignal<T> {
         {
             Ok(o) => o,
             Err(_) => panic_getting_dead_signal(
-                #[cfg(any(debug_assertions, feature = "ssr"))]
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
                 self.defined_at,
             ),
         }
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "ReadSignal::try_with()",
@@ -668,7 +731,7 @@ impl<T> SignalWith<T> for ReadSignal<T> {
 /// ```
 impl<T: Clone> SignalGet<T> for ReadSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "ReadSignal::get()",
@@ -691,14 +754,18 @@ impl<T: Clone> SignalGet<T> for ReadSignal<T> {
         {
             Ok(t) => t,
             Err(_) => panic_getting_dead_signal(
-                #[cfg(any(debug_assertions, feature = "ssr"))]
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
                 self.defined_at,
             ),
         }
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "ReadSignal::try_get()",
@@ -717,7 +784,7 @@ impl<T: Clone> SignalGet<T> for ReadSignal<T> {
 
 impl<T: Clone> SignalStream<T> for ReadSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "ReadSignal::to_stream()",
@@ -795,7 +862,7 @@ impl<T> fmt::Debug for ReadSignal<T> {
         s.field("runtime", &self.runtime);
         s.field("id", &self.id);
         s.field("ty", &self.ty);
-        #[cfg(any(debug_assertions, feature = "ssr"))]
+        #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
         s.field("defined_at", &self.defined_at);
         s.finish()
     }
@@ -868,7 +935,7 @@ where
     pub(crate) runtime: RuntimeId,
     pub(crate) id: NodeId,
     pub(crate) ty: PhantomData<T>,
-    #[cfg(any(debug_assertions, feature = "ssr"))]
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     pub(crate) defined_at: &'static std::panic::Location<'static>,
 }
 
@@ -877,7 +944,7 @@ where
     T: 'static,
 {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "WriteSignal::set_untracked()",
@@ -895,7 +962,7 @@ where
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "WriteSignal::try_set_untracked()",
@@ -919,7 +986,7 @@ where
 
 impl<T> SignalUpdateUntracked<T> for WriteSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "WriteSignal::updated_untracked()",
@@ -963,7 +1030,7 @@ impl<T> SignalUpdateUntracked<T> for WriteSignal<T> {
 /// ```
 impl<T> SignalUpdate<T> for WriteSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             name = "WriteSignal::update()",
             level = "trace",
@@ -979,14 +1046,18 @@ impl<T> SignalUpdate<T> for WriteSignal<T> {
     fn update(&self, f: impl FnOnce(&mut T)) {
         if self.id.update(self.runtime, f).is_none() {
             warn_updating_dead_signal(
-                #[cfg(any(debug_assertions, feature = "ssr"))]
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
                 self.defined_at,
             );
         }
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             name = "WriteSignal::try_update()",
             level = "trace",
@@ -1023,7 +1094,7 @@ impl<T> SignalUpdate<T> for WriteSignal<T> {
 /// ```
 impl<T> SignalSet<T> for WriteSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "WriteSignal::set()",
@@ -1036,11 +1107,11 @@ impl<T> SignalSet<T> for WriteSignal<T> {
         )
     )]
     fn set(&self, new_value: T) {
-        self.id.update(self.runtime, |n| *n = new_value);
+        self.id.set(self.runtime, new_value);
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "WriteSignal::try_set()",
@@ -1053,12 +1124,37 @@ impl<T> SignalSet<T> for WriteSignal<T> {
         )
     )]
     fn try_set(&self, new_value: T) -> Option<T> {
-        let mut new_value = Some(new_value);
-
-        self.id
-            .update(self.runtime, |t| *t = new_value.take().unwrap());
+        self.id.set(self.runtime, new_value)
+    }
+}
 
-        new_value
+impl<T: PartialEq> WriteSignal<T> {
+    /// Sets the value of the signal, but only notifies subscribers if the new
+    /// value is not equal to the current value.
+    /// ```
+    /// # use leptos_reactive::*;
+    /// # create_scope(create_runtime(), |cx| {
+    /// let (count, set_count) = create_signal(cx, 0);
+    /// let runs = store_value(cx, 0);
+    /// create_isomorphic_effect(cx, move |_| {
+    ///     count.get();
+    ///     runs.update_value(|n| *n += 1);
+    /// });
+    ///
+    /// set_count.set_if_changed(0);
+    /// assert_eq!(runs.get_value(), 1);
+    /// set_count.set_if_changed(1);
+    /// assert_eq!(runs.get_value(), 2);
+    /// # }).dispose();
+    /// ```
+    pub fn set_if_changed(&self, new_value: T) {
+        self.id.update_if_changed(self.runtime, |value: &mut T| {
+            let changed = *value != new_value;
+            if changed {
+                *value = new_value;
+            }
+            changed
+        });
     }
 }
 
@@ -1082,7 +1178,7 @@ impl<T> fmt::Debug for WriteSignal<T> {
         s.field("runtime", &self.runtime);
         s.field("id", &self.id);
         s.field("ty", &self.ty);
-        #[cfg(any(debug_assertions, feature = "ssr"))]
+        #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
         s.field("defined_at", &self.defined_at);
         s.finish()
     }
@@ -1125,7 +1221,7 @@ impl<T> Hash for WriteSignal<T> {
 /// #
 /// ```
 #[cfg_attr(
- any(debug_assertions, features="ssr"),
+ any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "trace",
         skip_all,
@@ -1141,6 +1237,20 @@ pub fn create_rw_signal<T>(cx: Scope, value: T) -> RwSignal<T> {
     s
 }
 
+/// Creates a signal like [`create_rw_signal`], which only notifies its subscribers
+/// when it is set to a value that `eq` does not consider equal to its current
+/// value. See [`create_signal_with_eq`] for details.
+#[track_caller]
+pub fn create_rw_signal_with_eq<T>(
+    cx: Scope,
+    value: T,
+    eq: impl Fn(&T, &T) -> bool + 'static,
+) -> RwSignal<T> {
+    let s = create_rw_signal(cx, value);
+    set_signal_eq(cx.runtime, s.id, eq);
+    s
+}
+
 /// A signal that combines the getter and setter into one value, rather than
 /// separating them into a [`ReadSignal`] and a [`WriteSignal`]. You may prefer this
 /// its style, or it may be easier to pass around in a context or as a function argument.
@@ -1191,7 +1301,7 @@ where
     pub(crate) runtime: RuntimeId,
     pub(crate) id: NodeId,
     pub(crate) ty: PhantomData<T>,
-    #[cfg(any(debug_assertions, feature = "ssr"))]
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     pub(crate) defined_at: &'static std::panic::Location<'static>,
 }
 
@@ -1209,7 +1319,7 @@ impl<T> fmt::Debug for RwSignal<T> {
         s.field("runtime", &self.runtime);
         s.field("id", &self.id);
         s.field("ty", &self.ty);
-        #[cfg(any(debug_assertions, feature = "ssr"))]
+        #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
         s.field("defined_at", &self.defined_at);
         s.finish()
     }
@@ -1232,7 +1342,7 @@ impl<T> Hash for RwSignal<T> {
 
 impl<T: Clone> SignalGetUntracked<T> for RwSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::get_untracked()",
@@ -1249,7 +1359,7 @@ impl<T: Clone> SignalGetUntracked<T> for RwSignal<T> {
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::try_get_untracked()",
@@ -1269,7 +1379,11 @@ impl<T: Clone> SignalGetUntracked<T> for RwSignal<T> {
         {
             Ok(t) => t,
             Err(_) => panic_getting_dead_signal(
-                #[cfg(any(debug_assertions, feature = "ssr"))]
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
                 self.defined_at,
             ),
         }
@@ -1278,7 +1392,7 @@ impl<T: Clone> SignalGetUntracked<T> for RwSignal<T> {
 
 impl<T> SignalWithUntracked<T> for RwSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::with_untracked()",
@@ -1296,7 +1410,7 @@ impl<T> SignalWithUntracked<T> for RwSignal<T> {
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::try_with_untracked()",
@@ -1324,7 +1438,7 @@ impl<T> SignalWithUntracked<T> for RwSignal<T> {
 
 impl<T> SignalSetUntracked<T> for RwSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::set_untracked()",
@@ -1342,7 +1456,7 @@ impl<T> SignalSetUntracked<T> for RwSignal<T> {
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::try_set_untracked()",
@@ -1366,7 +1480,7 @@ impl<T> SignalSetUntracked<T> for RwSignal<T> {
 
 impl<T> SignalUpdateUntracked<T> for RwSignal<T> {
     #[cfg_attr(
- any(debug_assertions, features="ssr"),
+ any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "trace",
         name = "RwSignal::update_untracked()",
@@ -1384,7 +1498,7 @@ impl<T> SignalUpdateUntracked<T> for RwSignal<T> {
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::try_update_untracked()",
@@ -1426,7 +1540,7 @@ impl<T> SignalUpdateUntracked<T> for RwSignal<T> {
 /// ```
 impl<T> SignalWith<T> for RwSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::with()",
@@ -1450,14 +1564,18 @@ impl<T> SignalWith<T> for RwSignal<T> {
         {
             Ok(o) => o,
             Err(_) => panic_getting_dead_signal(
-                #[cfg(any(debug_assertions, feature = "ssr"))]
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
                 self.defined_at,
             ),
         }
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::try_with()",
@@ -1498,7 +1616,7 @@ impl<T> SignalWith<T> for RwSignal<T> {
 /// ```
 impl<T: Clone> SignalGet<T> for RwSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::get()",
@@ -1524,14 +1642,18 @@ impl<T: Clone> SignalGet<T> for RwSignal<T> {
         {
             Ok(t) => t,
             Err(_) => panic_getting_dead_signal(
-                #[cfg(any(debug_assertions, feature = "ssr"))]
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
                 self.defined_at,
             ),
         }
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::try_get()",
@@ -1578,7 +1700,7 @@ impl<T: Clone> SignalGet<T> for RwSignal<T> {
 /// ```
 impl<T> SignalUpdate<T> for RwSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::update()",
@@ -1594,14 +1716,18 @@ impl<T> SignalUpdate<T> for RwSignal<T> {
     fn update(&self, f: impl FnOnce(&mut T)) {
         if self.id.update(self.runtime, f).is_none() {
             warn_updating_dead_signal(
-                #[cfg(any(debug_assertions, feature = "ssr"))]
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
                 self.defined_at,
             );
         }
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::try_update()",
@@ -1633,7 +1759,7 @@ impl<T> SignalUpdate<T> for RwSignal<T> {
 /// ```
 impl<T> SignalSet<T> for RwSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::set()",
@@ -1646,11 +1772,11 @@ impl<T> SignalSet<T> for RwSignal<T> {
         )
     )]
     fn set(&self, value: T) {
-        self.id.update(self.runtime, |n| *n = value);
+        self.id.set(self.runtime, value);
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::try_set()",
@@ -1663,12 +1789,7 @@ impl<T> SignalSet<T> for RwSignal<T> {
         )
     )]
     fn try_set(&self, new_value: T) -> Option<T> {
-        let mut new_value = Some(new_value);
-
-        self.id
-            .update(self.runtime, |t| *t = new_value.take().unwrap());
-
-        new_value
+        self.id.set(self.runtime, new_value)
     }
 }
 
@@ -1690,6 +1811,20 @@ impl<T: Clone> SignalStream<T> for RwSignal<T> {
     }
 }
 
+impl<T: PartialEq> RwSignal<T> {
+    /// Sets the value of the signal, but only notifies subscribers if the new
+    /// value is not equal to the current value.
+    pub fn set_if_changed(&self, new_value: T) {
+        self.id.update_if_changed(self.runtime, |value: &mut T| {
+            let changed = *value != new_value;
+            if changed {
+                *value = new_value;
+            }
+            changed
+        });
+    }
+}
+
 impl<T> SignalDispose for RwSignal<T> {
     fn dispose(self) {
         _ = with_runtime(self.runtime, |runtime| runtime.dispose_node(self.id));
@@ -1714,7 +1849,7 @@ impl<T> RwSignal<T> {
     /// # }).dispose();
     /// ```
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::read_only()",
@@ -1732,7 +1867,11 @@ impl<T> RwSignal<T> {
             runtime: self.runtime,
             id: self.id,
             ty: PhantomData,
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: std::panic::Location::caller(),
         }
     }
@@ -1752,7 +1891,7 @@ impl<T> RwSignal<T> {
     /// # }).dispose();
     /// ```
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::write_only()",
@@ -1770,7 +1909,11 @@ impl<T> RwSignal<T> {
             runtime: self.runtime,
             id: self.id,
             ty: PhantomData,
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: std::panic::Location::caller(),
         }
     }
@@ -1789,7 +1932,7 @@ impl<T> RwSignal<T> {
     /// # }).dispose();
     /// ```
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "RwSignal::split()",
@@ -1808,14 +1951,22 @@ impl<T> RwSignal<T> {
                 runtime: self.runtime,
                 id: self.id,
                 ty: PhantomData,
-                #[cfg(any(debug_assertions, feature = "ssr"))]
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
                 defined_at: std::panic::Location::caller(),
             },
             WriteSignal {
                 runtime: self.runtime,
                 id: self.id,
                 ty: PhantomData,
-                #[cfg(any(debug_assertions, feature = "ssr"))]
+                #[cfg(any(
+                    debug_assertions,
+                    feature = "ssr",
+                    feature = "tracing"
+                ))]
                 defined_at: std::panic::Location::caller(),
             },
         )
@@ -1899,16 +2050,35 @@ impl NodeId {
         runtime: &Runtime,
         f: impl FnOnce(&T) -> U,
     ) -> Result<U, SignalError>
+    where
+        T: 'static,
+    {
+        self.try_with_no_subscription_at(
+            runtime,
+            f,
+            std::panic::Location::caller(),
+        )
+    }
+
+    #[inline(always)]
+    fn try_with_no_subscription_at<T, U>(
+        &self,
+        runtime: &Runtime,
+        f: impl FnOnce(&T) -> U,
+        at: &'static std::panic::Location<'static>,
+    ) -> Result<U, SignalError>
     where
         T: 'static,
     {
         let value = self.try_with_no_subscription_inner(runtime)?;
-        let value = value.borrow();
-        let value = value
-            .downcast_ref::<T>()
-            .ok_or_else(|| SignalError::Type(std::any::type_name::<T>()))
-            .expect("to downcast signal type");
-        Ok(f(value))
+        let type_name = std::any::type_name::<T>();
+        Ok(borrow_value(*self, &value, type_name, at, |value| {
+            let value = value
+                .downcast_ref::<T>()
+                .ok_or(SignalError::Type(type_name))
+                .expect("to downcast signal type");
+            f(value)
+        }))
     }
 
     #[track_caller]
@@ -1924,7 +2094,12 @@ impl NodeId {
     {
         self.subscribe(runtime, diagnostics);
 
-        self.try_with_no_subscription(runtime, f)
+        #[cfg(debug_assertions)]
+        let at = diagnostics.called_at;
+        #[cfg(not(debug_assertions))]
+        let at = std::panic::Location::caller();
+
+        self.try_with_no_subscription_at(runtime, f, at)
     }
 
     #[inline(always)]
@@ -1942,28 +2117,44 @@ impl NodeId {
         .expect("runtime to be alive")
     }
 
+    // Updates the value without notifying subscribers. The write is only
+    // recorded for time travel if `is_write` returns `true` for the result.
+    #[track_caller]
     #[inline(always)]
     fn update_value<T, U>(
         &self,
         runtime: RuntimeId,
         f: impl FnOnce(&mut T) -> U,
+        is_write: impl FnOnce(&U) -> bool,
     ) -> Option<U>
     where
         T: 'static,
     {
+        let at = std::panic::Location::caller();
         with_runtime(runtime, |runtime| {
             if let Some(value) = runtime.get_value(*self) {
-                let mut value = value.borrow_mut();
-                if let Some(value) = value.downcast_mut::<T>() {
-                    Some(f(value))
-                } else {
-                    debug_warn!(
-                        "[Signal::update] failed when downcasting to \
-                         Signal<{}>",
-                        std::any::type_name::<T>()
-                    );
-                    None
-                }
+                let type_name = std::any::type_name::<T>();
+                borrow_value_mut(*self, &value, type_name, at, |value| {
+                    if let Some(value) = value.downcast_mut::<T>() {
+                        let updated = f(value);
+                        #[cfg(debug_assertions)]
+                        if is_write(&updated) {
+                            crate::time_travel::record_write(
+                                runtime, *self, value,
+                            );
+                        }
+                        #[cfg(not(debug_assertions))]
+                        let _ = is_write;
+                        Some(updated)
+                    } else {
+                        debug_warn!(
+                            "[Signal::update] failed when downcasting to \
+                             Signal<{}>",
+                            type_name
+                        );
+                        None
+                    }
+                })
             } else {
                 debug_warn!(
                     "[Signal::update] You’re trying to update a Signal<{}> \
@@ -1979,6 +2170,7 @@ impl NodeId {
         .unwrap_or_default()
     }
 
+    #[track_caller]
     #[inline(always)]
     pub(crate) fn update<T, U>(
         &self,
@@ -1988,19 +2180,25 @@ impl NodeId {
     where
         T: 'static,
     {
+        let at = std::panic::Location::caller();
         with_runtime(runtime_id, |runtime| {
             let updated = if let Some(value) = runtime.get_value(*self) {
-                let mut value = value.borrow_mut();
-                if let Some(value) = value.downcast_mut::<T>() {
-                    Some(f(value))
-                } else {
-                    debug_warn!(
-                        "[Signal::update] failed when downcasting to \
-                         Signal<{}>",
-                        std::any::type_name::<T>()
-                    );
-                    None
-                }
+                let type_name = std::any::type_name::<T>();
+                borrow_value_mut(*self, &value, type_name, at, |value| {
+                    if let Some(value) = value.downcast_mut::<T>() {
+                        let updated = f(value);
+                        #[cfg(debug_assertions)]
+                        crate::time_travel::record_write(runtime, *self, value);
+                        Some(updated)
+                    } else {
+                        debug_warn!(
+                            "[Signal::update] failed when downcasting to \
+                             Signal<{}>",
+                            type_name
+                        );
+                        None
+                    }
+                })
             } else {
                 debug_warn!(
                     "[Signal::update] You’re trying to update a Signal<{}> \
@@ -2026,6 +2224,70 @@ impl NodeId {
         .unwrap_or_default()
     }
 
+    // Like `update`, but only notifies subscribers if `f` returns `true`.
+    #[track_caller]
+    #[inline(always)]
+    pub(crate) fn update_if_changed<T>(
+        &self,
+        runtime_id: RuntimeId,
+        f: impl FnOnce(&mut T) -> bool,
+    ) -> Option<bool>
+    where
+        T: 'static,
+    {
+        // an unchanged value isn't a write, so it isn't recorded either
+        let changed = self.update_value(runtime_id, f, |changed| *changed);
+        if changed == Some(true) {
+            _ = with_runtime(runtime_id, |runtime| {
+                runtime.mark_dirty(*self);
+                runtime.run_effects();
+            });
+        }
+        changed
+    }
+
+    // Replaces the value, notifying subscribers unless the signal was created
+    // with a comparator that finds the new value equal to the current one.
+    // Returns the new value if the signal has been disposed.
+    #[track_caller]
+    #[inline(always)]
+    pub(crate) fn set<T>(
+        &self,
+        runtime_id: RuntimeId,
+        new_value: T,
+    ) -> Option<T>
+    where
+        T: 'static,
+    {
+        let eq = with_runtime(runtime_id, |runtime| {
+            runtime.signal_eq.borrow().get(*self).cloned()
+        })
+        .ok()
+        .flatten();
+        let mut new_value = Some(new_value);
+
+        match eq {
+            None => {
+                self.update(runtime_id, |value: &mut T| {
+                    *value = new_value.take().unwrap()
+                });
+            }
+            Some(eq) => {
+                self.update_if_changed(runtime_id, |value: &mut T| {
+                    let new_value = new_value.take().unwrap();
+                    let changed = !eq(value, &new_value);
+                    if changed {
+                        *value = new_value;
+                    }
+                    changed
+                });
+            }
+        }
+
+        new_value
+    }
+
+    #[track_caller]
     #[inline(always)]
     pub(crate) fn update_with_no_effect<T, U>(
         &self,
@@ -2036,7 +2298,7 @@ impl NodeId {
         T: 'static,
     {
         // update the value
-        self.update_value(runtime, f)
+        self.update_value(runtime, f, |_| true)
     }
 }
 
@@ -2045,18 +2307,22 @@ impl NodeId {
 #[track_caller]
 fn format_signal_warning(
     msg: &str,
-    #[cfg(any(debug_assertions, feature = "ssr"))]
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     defined_at: &'static std::panic::Location<'static>,
 ) -> String {
     let location = std::panic::Location::caller();
 
     let defined_at_msg = {
-        #[cfg(any(debug_assertions, feature = "ssr"))]
+        #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
         {
             format!("signal created here: {defined_at}\n")
         }
 
-        #[cfg(not(any(debug_assertions, feature = "ssr")))]
+        #[cfg(not(any(
+            debug_assertions,
+            feature = "ssr",
+            feature = "tracing"
+        )))]
         {
             String::default()
         }
@@ -2069,14 +2335,18 @@ fn format_signal_warning(
 #[inline(never)]
 #[track_caller]
 pub(crate) fn panic_getting_dead_signal(
-    #[cfg(any(debug_assertions, feature = "ssr"))]
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     defined_at: &'static std::panic::Location<'static>,
 ) -> ! {
     panic!(
         "{}",
         format_signal_warning(
             "Attempted to get a signal after it was disposed.",
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at,
         )
     )
@@ -2086,12 +2356,12 @@ pub(crate) fn panic_getting_dead_signal(
 #[inline(never)]
 #[track_caller]
 pub(crate) fn warn_updating_dead_signal(
-    #[cfg(any(debug_assertions, feature = "ssr"))]
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     defined_at: &'static std::panic::Location<'static>,
 ) {
     console_warn(&format_signal_warning(
         "Attempted to update a signal after it was disposed.",
-        #[cfg(any(debug_assertions, feature = "ssr"))]
+        #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
         defined_at,
     ));
 }
@@ -0,0 +1,179 @@
+#![forbid(unsafe_code)]
+use crate::{create_effect, on_cleanup, ReadSignal, Scope, SignalGet};
+use futures::Stream;
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll, Waker},
+};
+
+/// How a [`SignalChanges`] stream buffers the values that have not been
+/// read yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeBuffer {
+    /// Keeps only the most recent value. A slow reader skips intermediate
+    /// values, and always reads the latest one.
+    Latest,
+    /// Keeps up to this many values. When the buffer is full, the oldest value
+    /// is dropped to make room, and counted in [`SignalChanges::missed`].
+    Bounded(usize),
+    /// Keeps every value until it is read, so no value is ever missed.
+    Unbounded,
+}
+
+impl<T: Clone> ReadSignal<T> {
+    /// Returns a [`Stream`] that yields every new value of the signal from
+    /// now on, buffered according to `buffer`. The stream ends when `cx` is
+    /// disposed, once the buffered values have been read.
+    ///
+    /// Unlike [`to_stream`](crate::SignalStream::to_stream), this does not
+    /// yield the current value, and lets the reader choose what happens when
+    /// it falls behind.
+    ///
+    /// ```
+    /// # use leptos_reactive::*;
+    /// # use futures::StreamExt;
+    /// # create_scope(create_runtime(), |cx| {
+    /// let (count, set_count) = create_signal(cx, 0);
+    /// let mut changes = count.changes(cx, ChangeBuffer::Unbounded);
+    ///
+    /// set_count.set(1);
+    /// set_count.set(2);
+    /// # if !cfg!(feature = "ssr") {
+    /// # futures::executor::block_on(async move {
+    /// assert_eq!(changes.next().await, Some(1));
+    /// assert_eq!(changes.next().await, Some(2));
+    /// # });
+    /// # }
+    /// # }).dispose();
+    /// ```
+    #[cfg_attr(
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
+        instrument(
+            level = "trace",
+            name = "ReadSignal::changes()",
+            skip_all,
+            fields(
+                id = ?self.id,
+                defined_at = %self.defined_at,
+                ty = %std::any::type_name::<T>()
+            )
+        )
+    )]
+    pub fn changes(&self, cx: Scope, buffer: ChangeBuffer) -> SignalChanges<T> {
+        let capacity = match buffer {
+            ChangeBuffer::Latest => Some(1),
+            ChangeBuffer::Bounded(capacity) => Some(capacity.max(1)),
+            ChangeBuffer::Unbounded => None,
+        };
+        let state = Rc::new(RefCell::new(ChangesState {
+            queue: VecDeque::new(),
+            capacity,
+            missed: 0,
+            closed: false,
+            waker: None,
+        }));
+
+        on_cleanup(cx, {
+            let state = Rc::clone(&state);
+            move || {
+                let mut state = state.borrow_mut();
+                state.closed = true;
+                state.wake();
+            }
+        });
+
+        let this = *self;
+        create_effect(cx, {
+            let state = Rc::clone(&state);
+            move |prev: Option<()>| {
+                let value = this.get();
+                // the first run only subscribes to the signal
+                if prev.is_some() {
+                    state.borrow_mut().push(value);
+                }
+            }
+        });
+
+        SignalChanges { state }
+    }
+}
+
+/// A [`Stream`] of the new values of a signal, created with
+/// [`ReadSignal::changes`].
+pub struct SignalChanges<T> {
+    state: Rc<RefCell<ChangesState<T>>>,
+}
+
+impl<T> SignalChanges<T> {
+    /// The number of values that were dropped from a full
+    /// [`ChangeBuffer::Bounded`] or [`ChangeBuffer::Latest`] buffer before
+    /// they could be read.
+    pub fn missed(&self) -> usize {
+        self.state.borrow().missed
+    }
+
+    /// The number of values that are buffered, waiting to be read.
+    pub fn pending(&self) -> usize {
+        self.state.borrow().queue.len()
+    }
+}
+
+impl<T> Stream for SignalChanges<T> {
+    type Item = T;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let mut state = self.state.borrow_mut();
+        if let Some(value) = state.queue.pop_front() {
+            Poll::Ready(Some(value))
+        } else if state.closed {
+            Poll::Ready(None)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for SignalChanges<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.state.borrow();
+        f.debug_struct("SignalChanges")
+            .field("pending", &state.queue.len())
+            .field("capacity", &state.capacity)
+            .field("missed", &state.missed)
+            .field("closed", &state.closed)
+            .finish()
+    }
+}
+
+struct ChangesState<T> {
+    queue: VecDeque<T>,
+    // `None` if unbounded
+    capacity: Option<usize>,
+    missed: usize,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+impl<T> ChangesState<T> {
+    fn push(&mut self, value: T) {
+        if self.capacity == Some(self.queue.len()) {
+            self.queue.pop_front();
+            self.missed += 1;
+        }
+        self.queue.push_back(value);
+        self.wake();
+    }
+
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
@@ -65,7 +65,7 @@ where
     T: 'static,
 {
     inner: SignalTypes<T>,
-    #[cfg(any(debug_assertions, feature = "ssr"))]
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     defined_at: &'static std::panic::Location<'static>,
 }
 
@@ -73,7 +73,11 @@ impl<T> Clone for Signal<T> {
     fn clone(&self) -> Self {
         Self {
             inner: self.inner,
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: self.defined_at,
         }
     }
@@ -85,7 +89,7 @@ impl<T> std::fmt::Debug for Signal<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut s = f.debug_struct("Signal");
         s.field("inner", &self.inner);
-        #[cfg(any(debug_assertions, feature = "ssr"))]
+        #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
         s.field("defined_at", &self.defined_at);
         s.finish()
     }
@@ -104,7 +108,7 @@ impl<T> PartialEq for Signal<T> {
 /// `Signal::get_untracked`.
 impl<T: Clone> SignalGetUntracked<T> for Signal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Signal::get_untracked()",
@@ -126,7 +130,7 @@ impl<T: Clone> SignalGetUntracked<T> for Signal<T> {
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Signal::try_get_untracked()",
@@ -150,7 +154,7 @@ impl<T: Clone> SignalGetUntracked<T> for Signal<T> {
 
 impl<T> SignalWithUntracked<T> for Signal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Signal::with_untracked()",
@@ -176,7 +180,7 @@ impl<T> SignalWithUntracked<T> for Signal<T> {
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Signal::try_with_untracked()",
@@ -229,7 +233,7 @@ impl<T> SignalWithUntracked<T> for Signal<T> {
 /// ```
 impl<T> SignalWith<T> for Signal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Signal::with()",
@@ -249,7 +253,7 @@ impl<T> SignalWith<T> for Signal<T> {
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Signal::try_with()",
@@ -355,7 +359,7 @@ where
     /// ```
     #[track_caller]
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             skip_all,
@@ -365,11 +369,13 @@ where
         )
     )]
     pub fn derive(cx: Scope, derived_signal: impl Fn() -> T + 'static) -> Self {
-        let span = ::tracing::Span::current();
-
-        let derived_signal = move || {
-            let _guard = span.enter();
-            derived_signal()
+        #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
+        let derived_signal = {
+            let span = ::tracing::Span::current();
+            move || {
+                let _guard = span.enter();
+                derived_signal()
+            }
         };
 
         Self {
@@ -377,7 +383,11 @@ where
                 cx,
                 store_value(cx, Box::new(derived_signal)),
             ),
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: std::panic::Location::caller(),
         }
     }
@@ -397,7 +407,11 @@ impl<T> From<ReadSignal<T>> for Signal<T> {
     fn from(value: ReadSignal<T>) -> Self {
         Self {
             inner: SignalTypes::ReadSignal(value),
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: std::panic::Location::caller(),
         }
     }
@@ -408,7 +422,11 @@ impl<T> From<RwSignal<T>> for Signal<T> {
     fn from(value: RwSignal<T>) -> Self {
         Self {
             inner: SignalTypes::ReadSignal(value.read_only()),
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: std::panic::Location::caller(),
         }
     }
@@ -419,7 +437,11 @@ impl<T> From<Memo<T>> for Signal<T> {
     fn from(value: Memo<T>) -> Self {
         Self {
             inner: SignalTypes::Memo(value),
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: std::panic::Location::caller(),
         }
     }
@@ -617,7 +639,7 @@ impl<T: Clone> SignalGet<T> for MaybeSignal<T> {
 /// ```
 impl<T> SignalWith<T> for MaybeSignal<T> {
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "MaybeSignal::with()",
@@ -633,7 +655,7 @@ impl<T> SignalWith<T> for MaybeSignal<T> {
     }
 
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "MaybeSignal::try_with()",
@@ -722,7 +744,7 @@ where
     /// # });
     /// ```
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "MaybeSignal::derive()",
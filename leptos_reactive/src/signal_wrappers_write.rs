@@ -57,7 +57,7 @@ where
     T: 'static,
 {
     inner: SignalSetterTypes<T>,
-    #[cfg(any(debug_assertions, feature = "ssr"))]
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     defined_at: &'static std::panic::Location<'static>,
 }
 
@@ -65,7 +65,11 @@ impl<T> Clone for SignalSetter<T> {
     fn clone(&self) -> Self {
         Self {
             inner: self.inner,
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: self.defined_at,
         }
     }
@@ -76,7 +80,11 @@ impl<T: Default + 'static> Default for SignalSetter<T> {
     fn default() -> Self {
         Self {
             inner: SignalSetterTypes::Default,
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: std::panic::Location::caller(),
         }
     }
@@ -138,7 +146,7 @@ where
     /// ```
     #[track_caller]
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             skip_all,
@@ -153,7 +161,11 @@ where
                 cx,
                 store_value(cx, Box::new(mapped_setter)),
             ),
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: std::panic::Location::caller(),
         }
     }
@@ -179,7 +191,7 @@ where
     /// assert_eq!(count(), 8);
     /// # });
     #[cfg_attr(
-        any(debug_assertions, feature = "ssr"),
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             skip_all,
@@ -203,7 +215,11 @@ impl<T> From<WriteSignal<T>> for SignalSetter<T> {
     fn from(value: WriteSignal<T>) -> Self {
         Self {
             inner: SignalSetterTypes::Write(value),
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: std::panic::Location::caller(),
         }
     }
@@ -214,7 +230,11 @@ impl<T> From<RwSignal<T>> for SignalSetter<T> {
     fn from(value: RwSignal<T>) -> Self {
         Self {
             inner: SignalSetterTypes::Write(value.write_only()),
-            #[cfg(any(debug_assertions, feature = "ssr"))]
+            #[cfg(any(
+                debug_assertions,
+                feature = "ssr",
+                feature = "tracing"
+            ))]
             defined_at: std::panic::Location::caller(),
         }
     }
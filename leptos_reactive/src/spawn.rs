@@ -1,4 +1,5 @@
 #![forbid(unsafe_code)]
+use crate::{on_cleanup, with_runtime, Scope};
 use cfg_if::cfg_if;
 use std::future::Future;
 
@@ -22,3 +23,94 @@ where
         }
     }
 }
+
+/// Spawns and runs a [`Future`] that can be sent between threads, in a
+/// platform-independent way.
+///
+/// On the server, this uses [`tokio::spawn`](https://docs.rs/tokio/latest/tokio/fn.spawn.html),
+/// so the future can run on any of the runtime’s worker threads. In the
+/// browser, it falls back to [`spawn_local`]. Because the future may not run on
+/// the thread that created the reactive system, it should not read or write
+/// signals; send its results back to the reactive system with a channel or
+/// with a [`Resource`](crate::Resource) instead.
+pub fn spawn<F>(fut: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    cfg_if! {
+        if #[cfg(all(target_arch = "wasm32", any(feature = "csr", feature = "hydrate")))] {
+            wasm_bindgen_futures::spawn_local(fut)
+        }
+        else if #[cfg(any(test, doctest))] {
+            tokio_test::block_on(fut);
+        } else if #[cfg(feature = "ssr")] {
+            tokio::spawn(fut);
+        }  else {
+            futures::executor::block_on(fut)
+        }
+    }
+}
+
+/// Spawns and runs a thread-local [`Future`], like [`spawn_local`], that is
+/// cancelled when `cx` is disposed.
+///
+/// Once the scope is disposed, the future is dropped instead of being polled
+/// again, so async work started by a component never outlives it or touches
+/// its disposed signals. If the scope has already been disposed, the future
+/// is not run at all.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # run_scope(create_runtime(), |cx| {
+/// let (data, set_data) = create_signal(cx, None);
+/// spawn_local_scoped(cx, async move {
+///     let value = async { 42 }.await;
+///     // the scope is still alive, so this is safe
+///     set_data.set(Some(value));
+/// });
+/// # });
+/// ```
+pub fn spawn_local_scoped<F>(cx: Scope, fut: F)
+where
+    F: Future<Output = ()> + 'static,
+{
+    let is_alive = with_runtime(cx.runtime, |runtime| {
+        runtime.scopes.borrow().contains_key(cx.id)
+    })
+    .unwrap_or(false);
+    if !is_alive {
+        return;
+    }
+
+    let (fut, abort_handle) = futures::future::abortable(fut);
+    on_cleanup(cx, move || abort_handle.abort());
+    spawn_local(async move {
+        _ = fut.await;
+    });
+}
+
+/// Runs a blocking function, like reading a file or hashing a password,
+/// without blocking the async runtime, and returns its result.
+///
+/// On the server, this uses [`tokio::task::spawn_blocking`](https://docs.rs/tokio/latest/tokio/task/fn.spawn_blocking.html),
+/// so the function runs on a thread set aside for blocking work. Elsewhere,
+/// there are no such threads, so it runs on the current thread.
+pub async fn spawn_blocking<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    cfg_if! {
+        if #[cfg(all(feature = "ssr", not(any(test, doctest))))] {
+            match tokio::task::spawn_blocking(f).await {
+                Ok(value) => value,
+                Err(e) if e.is_panic() => {
+                    std::panic::resume_unwind(e.into_panic())
+                }
+                Err(e) => panic!("blocking task could not finish: {e}"),
+            }
+        } else {
+            f()
+        }
+    }
+}
@@ -0,0 +1,184 @@
+#![forbid(unsafe_code)]
+use crate::{
+    create_isomorphic_effect, create_signal, on_cleanup, spawn_local,
+    ReadSignal, Scope, SignalGet, SignalGetUntracked, SignalSet, SignalWith,
+    SignalWithUntracked,
+};
+use std::{cell::Cell, future::Future, rc::Rc};
+
+/// Creates a memo whose value is computed asynchronously, and which keeps
+/// showing its last computed value while a new one is being computed.
+///
+/// `f` is run right away, and again whenever any signal it reads changes. It
+/// should read its dependencies synchronously and return a [`Future`] that does
+/// the expensive part of the work. The memo is `None` until the first `Future`
+/// resolves; after that, it always holds the output of the most recent
+/// `Future` to have resolved, so UI built on it never flashes empty.
+/// [`is_refreshing`](StaleWhileRefreshMemo::is_refreshing) is `true` while a
+/// newer value is being computed.
+///
+/// If its dependencies change again before a `Future` has resolved, that
+/// `Future`'s output is discarded, so the memo never goes back to an older
+/// value. Unlike a [`Resource`](crate::Resource), reading the memo does not
+/// register it with `<Suspense/>`, and its value is not serialized from the
+/// server.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// async fn search(query: String) -> Vec<String> {
+///   // pretend we're searching a large index
+///   vec![query]
+/// }
+///
+/// # if false {
+/// let (query, set_query) = create_signal(cx, "leptos".to_string());
+/// let results = create_stale_while_refresh_memo(cx, move || {
+///   let query = query.get();
+///   search(query)
+/// });
+///
+/// // the results for "leptos" stay visible while this search is running
+/// set_query.set("leptos_reactive".to_string());
+/// assert!(results.is_refreshing().get());
+/// # }
+/// # }).dispose();
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
+    instrument(
+        level = "info",
+        skip_all,
+        fields(
+            scope = ?cx.id,
+            ty = %std::any::type_name::<T>(),
+        )
+    )
+)]
+pub fn create_stale_while_refresh_memo<T, Fu>(
+    cx: Scope,
+    f: impl Fn() -> Fu + 'static,
+) -> StaleWhileRefreshMemo<T>
+where
+    T: 'static,
+    Fu: Future<Output = T> + 'static,
+{
+    let (value, set_value) = create_signal(cx, None);
+    let (refreshing, set_refreshing) = create_signal(cx, false);
+
+    // the latest computation to have started; any other is stale
+    let version = Rc::new(Cell::new(0_usize));
+    on_cleanup(cx, {
+        let version = Rc::clone(&version);
+        move || version.set(version.get().wrapping_add(1))
+    });
+
+    create_isomorphic_effect(cx, move |_| {
+        let future = f();
+        let id = version.get().wrapping_add(1);
+        version.set(id);
+        if !refreshing.get_untracked() {
+            set_refreshing.set(true);
+        }
+
+        let version = Rc::clone(&version);
+        spawn_local(async move {
+            let new_value = future.await;
+            if version.get() == id {
+                set_value.set(Some(new_value));
+                set_refreshing.set(false);
+            }
+        });
+    });
+
+    StaleWhileRefreshMemo { value, refreshing }
+}
+
+/// A memo that is computed asynchronously, created with
+/// [`create_stale_while_refresh_memo`].
+///
+/// Its value is `None` until it has been computed once, and then holds the
+/// most recently computed value, even while a newer one is being computed.
+pub struct StaleWhileRefreshMemo<T>
+where
+    T: 'static,
+{
+    value: ReadSignal<Option<T>>,
+    refreshing: ReadSignal<bool>,
+}
+
+impl<T> StaleWhileRefreshMemo<T> {
+    /// Returns a signal that is `true` while a new value is being computed,
+    /// including while the first value is.
+    pub fn is_refreshing(&self) -> ReadSignal<bool> {
+        self.refreshing
+    }
+}
+
+impl<T: Clone> SignalGet<Option<T>> for StaleWhileRefreshMemo<T> {
+    #[track_caller]
+    fn get(&self) -> Option<T> {
+        self.value.get()
+    }
+
+    #[track_caller]
+    fn try_get(&self) -> Option<Option<T>> {
+        self.value.try_get()
+    }
+}
+
+impl<T> SignalWith<Option<T>> for StaleWhileRefreshMemo<T> {
+    #[track_caller]
+    fn with<O>(&self, f: impl FnOnce(&Option<T>) -> O) -> O {
+        self.value.with(f)
+    }
+
+    #[track_caller]
+    fn try_with<O>(&self, f: impl FnOnce(&Option<T>) -> O) -> Option<O> {
+        SignalWith::try_with(&self.value, f)
+    }
+}
+
+impl<T: Clone> SignalGetUntracked<Option<T>> for StaleWhileRefreshMemo<T> {
+    #[track_caller]
+    fn get_untracked(&self) -> Option<T> {
+        self.value.get_untracked()
+    }
+
+    #[track_caller]
+    fn try_get_untracked(&self) -> Option<Option<T>> {
+        self.value.try_get_untracked()
+    }
+}
+
+impl<T> SignalWithUntracked<Option<T>> for StaleWhileRefreshMemo<T> {
+    #[track_caller]
+    fn with_untracked<O>(&self, f: impl FnOnce(&Option<T>) -> O) -> O {
+        self.value.with_untracked(f)
+    }
+
+    #[track_caller]
+    fn try_with_untracked<O>(
+        &self,
+        f: impl FnOnce(&Option<T>) -> O,
+    ) -> Option<O> {
+        self.value.try_with_untracked(f)
+    }
+}
+
+impl<T> Clone for StaleWhileRefreshMemo<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for StaleWhileRefreshMemo<T> {}
+
+impl<T> std::fmt::Debug for StaleWhileRefreshMemo<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StaleWhileRefreshMemo")
+            .field("value", &self.value)
+            .field("refreshing", &self.refreshing)
+            .finish()
+    }
+}
@@ -0,0 +1,123 @@
+#![forbid(unsafe_code)]
+use crate::{
+    node::ReactiveNodeType,
+    runtime::{with_runtime, Runtime},
+    RuntimeId, Scope, ScopeId, ScopeProperty,
+};
+use std::ops::AddAssign;
+
+/// Counts of the reactive primitives that are currently alive, returned by
+/// [`Scope::stats`] and [`RuntimeId::stats`].
+///
+/// Comparing the stats for a scope before and after some action that should
+/// leave it as it was (like opening and closing a dialog) is a quick way to
+/// find leaks, without having to profile the heap.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ScopeStats {
+    /// The number of scopes.
+    pub scopes: usize,
+    /// The number of signals, including the signals created for resources.
+    pub signals: usize,
+    /// The number of [`Trigger`](crate::Trigger)s.
+    pub triggers: usize,
+    /// The number of [`Memo`](crate::Memo)s.
+    pub memos: usize,
+    /// The number of effects.
+    pub effects: usize,
+    /// The number of [`Resource`](crate::Resource)s.
+    pub resources: usize,
+    /// The number of [`StoredValue`](crate::StoredValue)s.
+    pub stored_values: usize,
+    /// The number of callbacks registered with [`on_cleanup`](crate::on_cleanup)
+    /// that have not run yet.
+    pub cleanups: usize,
+}
+
+impl AddAssign for ScopeStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.scopes += rhs.scopes;
+        self.signals += rhs.signals;
+        self.triggers += rhs.triggers;
+        self.memos += rhs.memos;
+        self.effects += rhs.effects;
+        self.resources += rhs.resources;
+        self.stored_values += rhs.stored_values;
+        self.cleanups += rhs.cleanups;
+    }
+}
+
+impl Scope {
+    /// Counts everything owned by this scope and all of its descendants.
+    ///
+    /// Returns the default (all zero) stats if the scope has been disposed.
+    pub fn stats(&self) -> ScopeStats {
+        with_runtime(self.runtime, |runtime| {
+            let mut stats = ScopeStats::default();
+            let mut scopes = vec![self.id];
+            while let Some(id) = scopes.pop() {
+                stats += runtime.scope_stats(id);
+                if let Some(children) = runtime.scope_children.borrow().get(id)
+                {
+                    scopes.extend(children.iter().copied());
+                }
+            }
+            stats
+        })
+        .unwrap_or_default()
+    }
+}
+
+impl RuntimeId {
+    /// Counts everything owned by every scope in this runtime.
+    ///
+    /// Returns the default (all zero) stats if the runtime has been disposed.
+    pub fn stats(self) -> ScopeStats {
+        with_runtime(self, |runtime| {
+            let ids = runtime.scopes.borrow().keys().collect::<Vec<_>>();
+            let mut stats = ScopeStats::default();
+            for id in ids {
+                stats += runtime.scope_stats(id);
+            }
+            stats
+        })
+        .unwrap_or_default()
+    }
+}
+
+impl Runtime {
+    // Counts what a single scope owns, not including its children.
+    fn scope_stats(&self, id: ScopeId) -> ScopeStats {
+        let mut stats = ScopeStats::default();
+        let scopes = self.scopes.borrow();
+        // children that have been disposed may not have been removed yet
+        let Some(properties) = scopes.get(id) else {
+            return stats;
+        };
+        stats.scopes = 1;
+
+        let nodes = self.nodes.borrow();
+        for property in properties.borrow().iter() {
+            match property {
+                ScopeProperty::Signal(_) => stats.signals += 1,
+                ScopeProperty::Trigger(_) => stats.triggers += 1,
+                ScopeProperty::Effect(id) => {
+                    if let Some(node) = nodes.get(*id) {
+                        match node.node_type {
+                            ReactiveNodeType::Memo { .. } => stats.memos += 1,
+                            _ => stats.effects += 1,
+                        }
+                    }
+                }
+                ScopeProperty::Resource(_) => stats.resources += 1,
+                ScopeProperty::StoredValue(_) => stats.stored_values += 1,
+            }
+        }
+        stats.cleanups = self
+            .scope_cleanups
+            .borrow()
+            .get(id)
+            .map(Vec::len)
+            .unwrap_or_default();
+        stats
+    }
+}
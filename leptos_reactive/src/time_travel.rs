@@ -0,0 +1,322 @@
+#![forbid(unsafe_code)]
+use crate::{
+    node::NodeId,
+    runtime::{with_runtime, Runtime},
+    RuntimeId, RwSignal, Serializable, WriteSignal,
+};
+use slotmap::SecondaryMap;
+use std::{any::Any, collections::VecDeque, time::Duration};
+
+/// A single write to a signal, captured while a runtime is recording
+/// with [`RuntimeId::start_recording`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalWrite {
+    /// The signal that was written to.
+    pub node: NodeId,
+    /// The serialized value after the write, if the signal is being recorded
+    /// with [`WriteSignal::record`] or [`RwSignal::record`].
+    pub value: Option<String>,
+    /// The serialized value before the write, if it is known.
+    pub previous: Option<String>,
+    /// The time at which the write happened, relative to the Unix epoch.
+    pub timestamp: Duration,
+    /// The effect or memo that was running when the write happened, if any.
+    pub effect: Option<NodeId>,
+}
+
+#[derive(Default)]
+pub(crate) struct TimeTravel {
+    recording: Option<Recording>,
+    nodes: SecondaryMap<NodeId, RecordedNode>,
+    replaying: bool,
+    // the most recent signal written to, whether or not the runtime is recording
+    last_write: Option<NodeId>,
+}
+
+struct Recording {
+    capacity: usize,
+    writes: VecDeque<SignalWrite>,
+    // the number of writes in `writes` that are currently applied
+    cursor: usize,
+}
+
+struct RecordedNode {
+    ser: fn(&dyn Any) -> Option<String>,
+    de: fn(&mut dyn Any, &str) -> bool,
+    current: Option<String>,
+}
+
+fn ser<T: Serializable + 'static>(value: &dyn Any) -> Option<String> {
+    value.downcast_ref::<T>()?.ser().ok()
+}
+
+fn de<T: Serializable + 'static>(
+    value: &mut dyn Any,
+    serialized: &str,
+) -> bool {
+    match (value.downcast_mut::<T>(), T::de(serialized)) {
+        (Some(value), Ok(restored)) => {
+            *value = restored;
+            true
+        }
+        _ => false,
+    }
+}
+
+fn now() -> Duration {
+    cfg_if::cfg_if! {
+        if #[cfg(all(
+            target_arch = "wasm32",
+            any(feature = "csr", feature = "hydrate")
+        ))] {
+            Duration::from_secs_f64(js_sys::Date::now() / 1000.0)
+        } else {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+        }
+    }
+}
+
+fn snapshot(
+    runtime: &Runtime,
+    node: NodeId,
+    ser: fn(&dyn Any) -> Option<String>,
+) -> Option<String> {
+    let value = runtime.get_value(node)?;
+    let value = value.borrow();
+    ser(&*value)
+}
+
+// Called by `NodeId::update` and `NodeId::update_value` after every write.
+pub(crate) fn record_write(runtime: &Runtime, node: NodeId, value: &dyn Any) {
+    let Ok(mut time_travel) = runtime.time_travel.try_borrow_mut() else {
+        return;
+    };
+    let TimeTravel {
+        recording,
+        nodes,
+        replaying,
+        last_write,
+    } = &mut *time_travel;
+    *last_write = Some(node);
+    let Some(recording) = recording else {
+        return;
+    };
+    if *replaying {
+        return;
+    }
+
+    let (value, previous) = match nodes.get_mut(node) {
+        Some(recorded) => {
+            let value = (recorded.ser)(value);
+            let previous =
+                std::mem::replace(&mut recorded.current, value.clone());
+            (value, previous)
+        }
+        None => (None, None),
+    };
+
+    // writing after rewinding discards the writes that were undone
+    recording.writes.truncate(recording.cursor);
+    recording.writes.push_back(SignalWrite {
+        node,
+        value,
+        previous,
+        timestamp: now(),
+        effect: runtime.observer.get(),
+    });
+    if recording.writes.len() > recording.capacity {
+        recording.writes.pop_front();
+    }
+    recording.cursor = recording.writes.len();
+}
+
+// The most recent signal written to in this runtime, if any.
+pub(crate) fn last_write(runtime: RuntimeId) -> Option<NodeId> {
+    with_runtime(runtime, |runtime| {
+        runtime.time_travel.try_borrow().ok()?.last_write
+    })
+    .ok()
+    .flatten()
+}
+
+fn register<T: Serializable + 'static>(runtime: RuntimeId, node: NodeId) {
+    _ = with_runtime(runtime, |runtime| {
+        let current = runtime
+            .time_travel
+            .borrow()
+            .recording
+            .is_some()
+            .then(|| snapshot(runtime, node, ser::<T>))
+            .flatten();
+        if let Some(entry) = runtime.time_travel.borrow_mut().nodes.entry(node)
+        {
+            entry.or_insert(RecordedNode {
+                ser: ser::<T>,
+                de: de::<T>,
+                current,
+            });
+        }
+    });
+}
+
+impl<T: Serializable + 'static> WriteSignal<T> {
+    /// Includes the serialized value of this signal in the writes captured by
+    /// [`RuntimeId::start_recording`], so that it can be restored by
+    /// [`RuntimeId::rewind`] and [`RuntimeId::replay`]. Only available in debug builds.
+    pub fn record(&self) {
+        register::<T>(self.runtime, self.id);
+    }
+}
+
+impl<T: Serializable + 'static> RwSignal<T> {
+    /// Includes the serialized value of this signal in the writes captured by
+    /// [`RuntimeId::start_recording`], so that it can be restored by
+    /// [`RuntimeId::rewind`] and [`RuntimeId::replay`]. Only available in debug builds.
+    pub fn record(&self) {
+        register::<T>(self.runtime, self.id);
+    }
+}
+
+impl RuntimeId {
+    /// Starts recording every signal write in this runtime into a ring buffer that
+    /// holds up to `capacity` writes, discarding any previous recording.
+    /// Only available in debug builds.
+    pub fn start_recording(self, capacity: usize) {
+        _ = with_runtime(self, |runtime| {
+            let snapshots = runtime
+                .time_travel
+                .borrow()
+                .nodes
+                .iter()
+                .map(|(node, recorded)| {
+                    (node, snapshot(runtime, node, recorded.ser))
+                })
+                .collect::<Vec<_>>();
+
+            let mut time_travel = runtime.time_travel.borrow_mut();
+            for (node, current) in snapshots {
+                if let Some(recorded) = time_travel.nodes.get_mut(node) {
+                    recorded.current = current;
+                }
+            }
+            time_travel.recording = Some(Recording {
+                capacity,
+                writes: VecDeque::with_capacity(capacity),
+                cursor: 0,
+            });
+        });
+    }
+
+    /// Stops recording signal writes and discards the recording.
+    /// Only available in debug builds.
+    pub fn stop_recording(self) {
+        _ = with_runtime(self, |runtime| {
+            runtime.time_travel.borrow_mut().recording = None;
+        });
+    }
+
+    /// Returns the signal writes that are currently applied, oldest first.
+    /// Writes that have been undone by [`RuntimeId::rewind`] are not included.
+    /// Only available in debug builds.
+    pub fn recorded_writes(self) -> Vec<SignalWrite> {
+        with_runtime(self, |runtime| {
+            runtime
+                .time_travel
+                .borrow()
+                .recording
+                .as_ref()
+                .map(|recording| {
+                    recording
+                        .writes
+                        .iter()
+                        .take(recording.cursor)
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_default()
+        })
+        .unwrap_or_default()
+    }
+
+    /// Undoes up to `steps` of the most recent recorded writes, restoring each
+    /// recorded signal to its previous value and notifying its subscribers.
+    /// Returns the number of writes that were undone. Only available in debug builds.
+    pub fn rewind(self, steps: usize) -> usize {
+        self.travel(steps, true)
+    }
+
+    /// Reapplies up to `steps` writes that were undone by [`RuntimeId::rewind`].
+    /// Returns the number of writes that were reapplied. Only available in debug builds.
+    pub fn replay(self, steps: usize) -> usize {
+        self.travel(steps, false)
+    }
+
+    fn travel(self, steps: usize, backward: bool) -> usize {
+        with_runtime(self, |runtime| {
+            let mut applied = 0;
+            while applied < steps {
+                let restore = {
+                    let mut time_travel = runtime.time_travel.borrow_mut();
+                    let TimeTravel {
+                        recording, nodes, ..
+                    } = &mut *time_travel;
+                    let Some(recording) = recording else {
+                        break;
+                    };
+                    let write = if backward {
+                        if recording.cursor == 0 {
+                            break;
+                        }
+                        recording.cursor -= 1;
+                        &recording.writes[recording.cursor]
+                    } else {
+                        if recording.cursor == recording.writes.len() {
+                            break;
+                        }
+                        recording.cursor += 1;
+                        &recording.writes[recording.cursor - 1]
+                    };
+                    let value = if backward {
+                        &write.previous
+                    } else {
+                        &write.value
+                    };
+                    match (nodes.get_mut(write.node), value) {
+                        (Some(recorded), Some(value)) => {
+                            recorded.current = Some(value.clone());
+                            Some((write.node, value.clone(), recorded.de))
+                        }
+                        _ => None,
+                    }
+                };
+                applied += 1;
+
+                if let Some((node, value, de)) = restore {
+                    restore_value(runtime, node, &value, de);
+                }
+            }
+            applied
+        })
+        .unwrap_or_default()
+    }
+}
+
+fn restore_value(
+    runtime: &Runtime,
+    node: NodeId,
+    value: &str,
+    de: fn(&mut dyn Any, &str) -> bool,
+) {
+    let restored = runtime
+        .get_value(node)
+        .map(|current| de(&mut *current.borrow_mut(), value))
+        .unwrap_or(false);
+    if restored {
+        runtime.time_travel.borrow_mut().replaying = true;
+        runtime.mark_dirty(node);
+        runtime.run_effects();
+        runtime.time_travel.borrow_mut().replaying = false;
+    }
+}
@@ -0,0 +1,174 @@
+#![forbid(unsafe_code)]
+use crate::{
+    create_rw_signal, runtime::with_runtime, RuntimeId, RwSignal, Scope,
+    SignalGet, SignalSet, SignalUpdate, SignalWithUntracked,
+};
+use std::cell::Cell;
+
+/// A handle to a transition started with [`start_transition`].
+///
+/// A transition is pending while any [`Resource`](crate::Resource) that began
+/// loading during the transition is still loading. Starting a new transition
+/// interrupts the previous one, so that at most one transition is pending at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransitionHandle {
+    // owns the signals below, and is disposed once the transition settles
+    scope: Scope,
+    pending: RwSignal<usize>,
+    interrupted: RwSignal<bool>,
+}
+
+// The transitions that have been started in a runtime.
+#[derive(Default)]
+pub(crate) struct Transitions {
+    // the most recently started transition
+    current: Cell<Option<TransitionHandle>>,
+    // the transition whose function is running right now, if any
+    running: Cell<Option<TransitionHandle>>,
+}
+
+/// Runs the given function as a transition, and returns a [`TransitionHandle`] that
+/// reports whether the transition is still pending.
+///
+/// Any [`Resource`](crate::Resource)s that begin loading while the function runs
+/// belong to the transition, which stays pending until all of them have resolved.
+/// While a transition is pending, `<Suspense/>` boundaries that have already shown
+/// their children will keep showing the stale content, instead of falling back.
+///
+/// If another transition is started before this one has finished, this one is
+/// interrupted: it is no longer pending, and only the newer transition is tracked.
+///
+/// Once a transition has settled, because its resources have resolved or it
+/// was interrupted, it stops being reactive: [`TransitionHandle::is_pending`]
+/// and [`TransitionHandle::is_interrupted`] still return the right values,
+/// but no longer notify effects or memos that read them.
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// let (tab, set_tab) = create_signal(cx, 0);
+///
+/// let first = start_transition(cx, move || set_tab.set(1));
+/// let second = start_transition(cx, move || set_tab.set(2));
+///
+/// assert!(first.is_interrupted());
+/// assert!(!second.is_interrupted());
+/// // no resources were loaded, so there is nothing to wait for
+/// assert!(!second.is_pending());
+/// # }).dispose();
+/// ```
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
+    instrument(level = "trace", skip_all, fields(scope = ?cx.id))
+)]
+pub fn start_transition(cx: Scope, f: impl FnOnce()) -> TransitionHandle {
+    // transitions are often started from long-lived scopes, like an event
+    // handler under the root, so their signals get a scope of their own
+    let ((pending, interrupted), disposer) = cx.run_child_scope(|cx| {
+        (create_rw_signal(cx, 0), create_rw_signal(cx, false))
+    });
+
+    let handle = TransitionHandle {
+        scope: disposer.0,
+        pending,
+        interrupted,
+    };
+
+    let (previous, outer) = with_runtime(cx.runtime, |runtime| {
+        let transitions = &runtime.transitions;
+        (
+            transitions.current.replace(Some(handle)),
+            transitions.running.replace(Some(handle)),
+        )
+    })
+    .expect("tried to start a transition in a runtime that has been disposed");
+
+    if let Some(previous) = previous {
+        previous.interrupted.try_set(true);
+        previous.settle();
+    }
+
+    f();
+
+    _ = with_runtime(cx.runtime, |runtime| {
+        runtime.transitions.running.set(outer);
+    });
+    // no resources began loading, so there is nothing to wait for
+    if handle.pending.try_with_untracked(|n| *n == 0) == Some(true) {
+        handle.settle();
+    }
+    handle
+}
+
+/// Returns the most recently started transition, if it is still pending.
+///
+/// This is reactive: reading it in an effect or memo will cause it to rerun when
+/// that transition stops being pending.
+pub fn pending_transition(cx: Scope) -> Option<TransitionHandle> {
+    with_runtime(cx.runtime, |runtime| runtime.transitions.current.get())
+        .ok()
+        .flatten()
+        .filter(TransitionHandle::is_pending)
+}
+
+// Returns the transition whose function is currently running, so that
+// resources that begin loading can be added to it.
+pub(crate) fn running_transition(
+    runtime: RuntimeId,
+) -> Option<TransitionHandle> {
+    with_runtime(runtime, |runtime| runtime.transitions.running.get())
+        .ok()
+        .flatten()
+}
+
+impl TransitionHandle {
+    /// Whether any resources that began loading during this transition are still
+    /// loading. An interrupted transition is never pending.
+    ///
+    /// This is reactive.
+    pub fn is_pending(&self) -> bool {
+        !self.is_interrupted() && self.pending.try_get().unwrap_or(0) > 0
+    }
+
+    /// Whether this transition was interrupted, either by a newer transition or
+    /// by [`TransitionHandle::interrupt`].
+    ///
+    /// This is reactive.
+    pub fn is_interrupted(&self) -> bool {
+        self.interrupted.try_get().unwrap_or_else(|| {
+            // a settled transition has been interrupted if it is no longer
+            // the most recent one
+            with_runtime(self.scope.runtime, |runtime| {
+                runtime.transitions.current.get() != Some(*self)
+            })
+            .unwrap_or(false)
+        })
+    }
+
+    /// Interrupts this transition, so that it is no longer pending.
+    pub fn interrupt(&self) {
+        _ = with_runtime(self.scope.runtime, |runtime| {
+            let transitions = &runtime.transitions;
+            if transitions.current.get() == Some(*self) {
+                transitions.current.set(None);
+            }
+        });
+        self.interrupted.try_set(true);
+        self.settle();
+    }
+
+    pub(crate) fn add_pending(&self) {
+        self.pending.try_update(|n| *n += 1);
+    }
+
+    pub(crate) fn remove_pending(&self) {
+        self.pending.try_update(|n| *n = n.saturating_sub(1));
+        if self.pending.try_with_untracked(|n| *n == 0) == Some(true) {
+            self.settle();
+        }
+    }
+
+    // Disposes of the transition's signals, once nothing can change them.
+    fn settle(&self) {
+        self.scope.dispose();
+    }
+}
@@ -16,7 +16,7 @@ pub struct Trigger {
     pub(crate) runtime: RuntimeId,
     pub(crate) id: NodeId,
 
-    #[cfg(debug_assertions)]
+    #[cfg(any(debug_assertions, feature = "ssr", feature = "tracing"))]
     pub(crate) defined_at: &'static std::panic::Location<'static>,
 }
 
@@ -91,7 +91,7 @@ impl Trigger {
 /// # }).dispose();
 /// ```
 #[cfg_attr(
-    debug_assertions,
+    any(debug_assertions, feature = "ssr", feature = "tracing"),
     instrument(
         level = "trace",
         skip_all,
@@ -107,7 +107,7 @@ pub fn create_trigger(cx: Scope) -> Trigger {
 
 impl SignalGet<()> for Trigger {
     #[cfg_attr(
-        debug_assertions,
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Trigger::get()",
@@ -125,7 +125,7 @@ impl SignalGet<()> for Trigger {
     }
 
     #[cfg_attr(
-        debug_assertions,
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Trigger::try_get()",
@@ -144,7 +144,7 @@ impl SignalGet<()> for Trigger {
 
 impl SignalUpdate<()> for Trigger {
     #[cfg_attr(
-        debug_assertions,
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             name = "Trigger::update()",
             level = "trace",
@@ -161,7 +161,7 @@ impl SignalUpdate<()> for Trigger {
     }
 
     #[cfg_attr(
-        debug_assertions,
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             name = "Trigger::try_update()",
             level = "trace",
@@ -191,7 +191,7 @@ impl SignalUpdate<()> for Trigger {
 
 impl SignalSet<()> for Trigger {
     #[cfg_attr(
-        debug_assertions,
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Trigger::set()",
@@ -208,7 +208,7 @@ impl SignalSet<()> for Trigger {
     }
 
     #[cfg_attr(
-        debug_assertions,
+        any(debug_assertions, feature = "ssr", feature = "tracing"),
         instrument(
             level = "trace",
             name = "Trigger::try_set()",
@@ -0,0 +1,53 @@
+use leptos_reactive::{
+    create_runtime, provide_config, run_scope, use_config, Serializable,
+    ServerOnly,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct AppConfig {
+    api_url: String,
+    database_url: ServerOnly<String>,
+}
+
+fn config() -> AppConfig {
+    AppConfig {
+        api_url: "https://api.example.com".into(),
+        database_url: ServerOnly::new("postgres://localhost/app".into()),
+    }
+}
+
+#[test]
+fn use_config_returns_provided_config() {
+    run_scope(create_runtime(), |cx| {
+        assert_eq!(use_config::<AppConfig>(cx), None);
+        provide_config(cx, config());
+        let child = cx.child_scope(|cx| {
+            assert_eq!(use_config::<AppConfig>(cx), Some(config()));
+        });
+        child.dispose();
+    });
+}
+
+#[test]
+fn server_only_fields_are_not_serialized() {
+    let serialized = config().ser().unwrap();
+    assert!(!serialized.contains("postgres"));
+
+    let client = AppConfig::de(&serialized).unwrap();
+    assert_eq!(client.api_url, "https://api.example.com");
+    assert_eq!(client.database_url.get(), None);
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn provided_config_is_serialized_for_the_client() {
+    run_scope(create_runtime(), |cx| {
+        provide_config(cx, config());
+        let serialized = cx.serialized_config().unwrap();
+        assert_eq!(
+            AppConfig::de(&serialized).unwrap().database_url,
+            ServerOnly::default()
+        );
+    });
+}
@@ -0,0 +1,33 @@
+#[test]
+fn awaited_resolves_once() {
+    #[cfg(feature = "ssr")]
+    {
+        use futures::channel::oneshot::channel;
+        use leptos_reactive::{
+            create_await, create_runtime, raw_scope_and_disposer, SignalGet,
+            SignalGetUntracked,
+        };
+        use tokio::task;
+        use tokio_test::block_on;
+
+        block_on(task::LocalSet::new().run_until(async move {
+            let (cx, disposer) = raw_scope_and_disposer(create_runtime());
+            task::spawn_local(async move {
+                let (tx, rx) = channel::<i32>();
+                let value = create_await(cx, async move { rx.await.unwrap() });
+                task::yield_now().await;
+                assert!(value.loading().get_untracked());
+                assert_eq!(value.get(), None);
+
+                tx.send(42).unwrap();
+                task::yield_now().await;
+                assert!(!value.loading().get_untracked());
+                assert_eq!(value.get(), Some(42));
+                assert_eq!(value.with(|n| n * 2), Some(84));
+            })
+            .await
+            .unwrap();
+            disposer.dispose();
+        }));
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(debug_assertions)]
+use leptos_reactive::{
+    create_runtime, create_rw_signal, create_scope, signal_prelude::*,
+};
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Tried to update a signal or memo of type `i32`")]
+fn updating_a_signal_while_reading_it_panics_with_diagnostics() {
+    _ = create_scope(create_runtime(), |cx| {
+        let count = create_rw_signal(cx, 0);
+        count.with(|n| count.set(*n + 1));
+    });
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Signals can’t be read from inside their own")]
+fn reading_a_signal_while_updating_it_panics_with_diagnostics() {
+    _ = create_scope(create_runtime(), |cx| {
+        let count = create_rw_signal(cx, 0);
+        count.update(|n| *n = count.get_untracked() + 1);
+    });
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn borrow_diagnostics_name_both_locations() {
+    let message = std::panic::catch_unwind(|| {
+        _ = create_scope(create_runtime(), |cx| {
+            let count = create_rw_signal(cx, 0);
+            count.with(|_| count.update(|n| *n += 1));
+        });
+    })
+    .unwrap_err();
+    let message = message.downcast_ref::<String>().unwrap();
+    let this_file = file!();
+    assert_eq!(message.matches(this_file).count(), 2, "{message}");
+    assert!(message.contains("already being read at"), "{message}");
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn reads_after_a_conflict_still_work() {
+    _ = create_scope(create_runtime(), |cx| {
+        let count = create_rw_signal(cx, 0);
+        count.with(|n| {
+            // nested reads are fine
+            assert_eq!(count.get(), *n);
+        });
+        count.update(|n| *n += 1);
+        assert_eq!(count.get(), 1);
+    });
+}
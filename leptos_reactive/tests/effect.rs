@@ -133,3 +133,70 @@ fn batching_actually_batches() {
     })
     .dispose()
 }
+
+#[test]
+fn effect_runs_once_per_change_in_diamond() {
+    use leptos_reactive::{
+        create_isomorphic_effect, create_memo, create_runtime, create_scope,
+        create_signal, SignalGet, SignalSet,
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    create_scope(create_runtime(), |cx| {
+        let (count, set_count) = create_signal(cx, 1);
+        let double = create_memo(cx, move |_| count.get() * 2);
+        let triple = create_memo(cx, move |_| count.get() * 3);
+        let runs = Rc::new(Cell::new(0));
+
+        // depends on `count` directly and through both memos
+        create_isomorphic_effect(cx, {
+            let runs = Rc::clone(&runs);
+            move |_| {
+                _ = (count.get(), double.get(), triple.get());
+                runs.set(runs.get() + 1);
+            }
+        });
+        assert_eq!(runs.get(), 1);
+
+        set_count.set(2);
+        assert_eq!(runs.get(), 2);
+    })
+    .dispose()
+}
+
+#[test]
+fn effect_runs_once_per_batch() {
+    use leptos_reactive::{
+        create_isomorphic_effect, create_memo, create_runtime, create_scope,
+        create_signal, SignalGet, SignalGetUntracked, SignalSet,
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    create_scope(create_runtime(), |cx| {
+        let (a, set_a) = create_signal(cx, 1);
+        let (b, set_b) = create_signal(cx, 1);
+        let sum = create_memo(cx, move |_| a.get() + b.get());
+        let runs = Rc::new(Cell::new(0));
+
+        create_isomorphic_effect(cx, {
+            let runs = Rc::clone(&runs);
+            move |_| {
+                _ = (a.get(), b.get(), sum.get());
+                runs.set(runs.get() + 1);
+            }
+        });
+        assert_eq!(runs.get(), 1);
+
+        cx.batch(move || {
+            set_a.set(2);
+            // reading the memo in the middle of the batch marks the effect
+            // dirty again before the second write queues it
+            assert_eq!(sum.get_untracked(), 3);
+            set_b.set(2);
+            set_a.set(3);
+        });
+        assert_eq!(runs.get(), 2);
+        assert_eq!(sum.get_untracked(), 5);
+    })
+    .dispose()
+}
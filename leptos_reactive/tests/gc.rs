@@ -0,0 +1,29 @@
+#[test]
+fn gc_collects_nodes_created_in_disposed_scopes() {
+    use leptos_reactive::*;
+    let runtime = create_runtime();
+
+    create_scope(runtime, move |cx| {
+        let (owned, _) = create_signal(cx, 0);
+        let (child, disposer) = cx.run_child_scope(|cx| cx);
+        disposer.dispose();
+
+        // neither of these has an owner that will ever dispose of them
+        let (orphan, _) = create_signal(child, 1);
+        let doubled = create_memo(child, move |_| orphan.get() * 2);
+        assert_eq!(doubled.get_untracked(), 2);
+
+        // an effect that is still running keeps its sources alive
+        let (read, _) = create_signal(child, 0);
+        create_isomorphic_effect(cx, move |_| read.get());
+
+        assert_eq!(runtime.gc(), 2);
+        assert_eq!(runtime.gc(), 0);
+        assert_eq!(owned.get_untracked(), 0);
+        assert_eq!(read.get_untracked(), 0);
+        assert_eq!(orphan.try_get_untracked(), None);
+    })
+    .dispose();
+
+    runtime.dispose();
+}
@@ -0,0 +1,41 @@
+#[test]
+fn snapshot_graph_tracks_nodes_and_edges() {
+    use leptos_reactive::*;
+    let runtime = create_runtime();
+
+    create_scope(runtime, move |cx| {
+        let (a, set_a) = create_signal(cx, 0);
+        let double = create_memo(cx, move |_| a.get() * 2);
+        create_isomorphic_effect(cx, move |_| {
+            double.get();
+        });
+
+        let snapshot = runtime.snapshot_graph();
+        let count = |kind| {
+            snapshot
+                .nodes
+                .iter()
+                .filter(|node| node.kind == kind)
+                .count()
+        };
+        assert_eq!(count(GraphNodeKind::Signal), 1);
+        assert_eq!(count(GraphNodeKind::Memo), 1);
+        assert_eq!(count(GraphNodeKind::Effect), 1);
+        assert_eq!(snapshot.edges.len(), 2);
+        assert!(snapshot
+            .nodes
+            .iter()
+            .all(|node| node.scope == Some(cx.id())));
+
+        let dot = snapshot.to_dot();
+        assert!(dot.starts_with("digraph"));
+        assert_eq!(dot.matches("->").count(), 2);
+
+        set_a.set(1);
+        assert_eq!(runtime.snapshot_graph().edges, snapshot.edges);
+    })
+    .dispose();
+
+    assert!(runtime.snapshot_graph().nodes.is_empty());
+    runtime.dispose();
+}
@@ -0,0 +1,48 @@
+use leptos_reactive::{
+    create_runtime, provide_context, provide_keyed_context, run_scope,
+    set_context_shadowing_warnings, use_context, use_keyed_context, ContextKey,
+};
+
+const FIRST: ContextKey<String> = ContextKey::new("tests::first");
+const SECOND: ContextKey<String> = ContextKey::new("tests::second");
+
+#[test]
+fn keyed_contexts_of_the_same_type_do_not_collide() {
+    run_scope(create_runtime(), |cx| {
+        provide_context(cx, "plain".to_string());
+        provide_keyed_context(cx, FIRST, "first".to_string());
+
+        let child = cx.child_scope(|cx| {
+            provide_keyed_context(cx, SECOND, "second".to_string());
+
+            // a nearer scope providing another key doesn't hide the outer one
+            assert_eq!(use_keyed_context(cx, FIRST).as_deref(), Some("first"));
+            assert_eq!(
+                use_keyed_context(cx, SECOND).as_deref(),
+                Some("second")
+            );
+            assert_eq!(use_context::<String>(cx).as_deref(), Some("plain"));
+        });
+
+        assert_eq!(use_keyed_context(cx, SECOND), None);
+        child.dispose();
+    });
+}
+
+#[test]
+fn keyed_contexts_can_be_shadowed() {
+    set_context_shadowing_warnings(true);
+    run_scope(create_runtime(), |cx| {
+        provide_keyed_context(cx, FIRST, "outer".to_string());
+
+        let child = cx.child_scope(|cx| {
+            provide_keyed_context(cx, FIRST, "inner".to_string());
+            assert_eq!(use_keyed_context(cx, FIRST).as_deref(), Some("inner"));
+        });
+
+        provide_keyed_context(cx, FIRST, "replaced".to_string());
+        assert_eq!(use_keyed_context(cx, FIRST).as_deref(), Some("replaced"));
+        child.dispose();
+    });
+    set_context_shadowing_warnings(false);
+}
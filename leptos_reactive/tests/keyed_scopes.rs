@@ -0,0 +1,57 @@
+use leptos_reactive::{
+    create_runtime, create_rw_signal, on_cleanup, run_scope, SignalGet,
+};
+use std::{cell::Cell, rc::Rc};
+
+#[test]
+fn keyed_child_scopes_are_disposed_individually() {
+    run_scope(create_runtime(), |cx| {
+        let cleanups = Rc::new(Cell::new(0));
+        let create_row = |key: usize| {
+            let cleanups = Rc::clone(&cleanups);
+            cx.keyed_child_scope(key, move |cx| {
+                on_cleanup(cx, move || cleanups.set(cleanups.get() + 1));
+                create_rw_signal(cx, key)
+            })
+        };
+
+        let first = create_row(1);
+        let second = create_row(2);
+        assert!(cx.keyed_child(&1usize).is_some());
+        assert_eq!(cx.keyed_child(&3usize), None);
+        // keys of different types don't collide
+        assert_eq!(cx.keyed_child(&"1"), None);
+
+        assert!(cx.dispose_child(&1usize));
+        assert_eq!(cleanups.get(), 1);
+        assert_eq!(first.try_get(), None);
+        assert_eq!(second.try_get(), Some(2));
+        assert_eq!(cx.keyed_child(&1usize), None);
+        assert!(!cx.dispose_child(&1usize));
+
+        // reusing a key disposes of the previous child
+        let replaced = create_row(2);
+        assert_eq!(cleanups.get(), 2);
+        assert_eq!(second.try_get(), None);
+        assert_eq!(replaced.try_get(), Some(2));
+    });
+}
+
+#[test]
+fn keyed_child_scopes_are_disposed_with_parent() {
+    run_scope(create_runtime(), |cx| {
+        let disposed = Rc::new(Cell::new(false));
+        let (parent, disposer) = cx.run_child_scope(|parent| {
+            let disposed = Rc::clone(&disposed);
+            parent.keyed_child_scope("row", move |cx| {
+                on_cleanup(cx, move || disposed.set(true));
+            });
+            parent
+        });
+
+        disposer.dispose();
+        assert!(disposed.get());
+        assert_eq!(parent.keyed_child(&"row"), None);
+        assert!(!parent.dispose_child(&"row"));
+    });
+}
@@ -189,3 +189,142 @@ fn dynamic_dependencies() {
     })
     .dispose()
 }
+
+#[test]
+fn memo_previous_value() {
+    use leptos_reactive::{
+        create_memo, create_memo_with_previous, create_runtime, create_scope,
+        create_signal, SignalGet, SignalSet,
+    };
+
+    create_scope(create_runtime(), |cx| {
+        let (count, set_count) = create_signal(cx, 1);
+        let parity = create_memo_with_previous(cx, move |_| count.get() % 2);
+        let delta =
+            create_memo_with_previous(cx, move |prev: Option<&(i32, i32)>| {
+                let last = prev.map(|(_, last)| *last).unwrap_or_default();
+                (count.get() - last, count.get())
+            });
+        let plain = create_memo(cx, move |_| count.get());
+
+        assert_eq!(parity.get_previous(), None);
+        assert_eq!(delta.get(), (1, 1));
+
+        set_count.set(4);
+        assert_eq!(parity.get_previous(), Some(1));
+        assert_eq!(parity.get(), 0);
+        assert_eq!(delta.get(), (3, 4));
+        assert_eq!(delta.get_previous(), Some((1, 1)));
+
+        // an unchanged value keeps the previous one
+        set_count.set(6);
+        assert_eq!(parity.get_previous(), Some(1));
+        assert_eq!(delta.get_previous(), Some((3, 4)));
+
+        // a memo created without `create_memo_with_previous` keeps no copy
+        assert_eq!(plain.get(), 6);
+        assert_eq!(plain.get_previous(), None);
+    })
+    .dispose()
+}
+
+#[test]
+fn memo_previous_value_dropped_with_scope() {
+    use leptos_reactive::{
+        create_memo_with_previous, create_runtime, create_signal,
+        run_scope_undisposed, SignalGet, SignalSet,
+    };
+    use std::rc::Rc;
+
+    let runtime = create_runtime();
+    let (weak, _, disposer) = run_scope_undisposed(runtime, |cx| {
+        let (count, set_count) = create_signal(cx, 1);
+        let memo = create_memo_with_previous(cx, move |_| Rc::new(count.get()));
+        assert_eq!(*memo.get(), 1);
+        set_count.set(2);
+        assert_eq!(*memo.get(), 2);
+        Rc::downgrade(&memo.get_previous().unwrap())
+    });
+    assert!(weak.upgrade().is_some());
+
+    // disposing the scope frees the previous value, not only the runtime
+    disposer.dispose();
+    assert!(weak.upgrade().is_none());
+    runtime.dispose();
+}
+
+#[test]
+fn memo_is_lazy() {
+    use leptos_reactive::{
+        create_memo, create_runtime, create_scope, create_signal, SignalGet,
+        SignalSet,
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    create_scope(create_runtime(), |cx| {
+        let runs = Rc::new(Cell::new(0));
+        let (count, set_count) = create_signal(cx, 1);
+        let double = create_memo(cx, {
+            let runs = Rc::clone(&runs);
+            move |_| {
+                runs.set(runs.get() + 1);
+                count.get() * 2
+            }
+        });
+
+        // nothing is computed until the memo is read
+        assert_eq!(runs.get(), 0);
+        assert_eq!(double.get(), 2);
+        assert_eq!(runs.get(), 1);
+
+        // changes only mark the memo as stale
+        set_count.set(2);
+        set_count.set(3);
+        assert_eq!(runs.get(), 1);
+
+        // and it is recomputed once on the next read
+        assert_eq!(double.get(), 6);
+        assert_eq!(double.get(), 6);
+        assert_eq!(runs.get(), 2);
+    })
+    .dispose()
+}
+
+#[test]
+fn memo_diamond_is_glitch_free() {
+    use leptos_reactive::{
+        create_isomorphic_effect, create_memo, create_runtime, create_scope,
+        create_signal, SignalGet, SignalSet,
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    create_scope(create_runtime(), |cx| {
+        let (a, set_a) = create_signal(cx, 1);
+        // the two sides of the diamond have different depths
+        let b = create_memo(cx, move |_| a.get() * 2);
+        let b2 = create_memo(cx, move |_| b.get() + 1);
+        let c = create_memo(cx, move |_| a.get() * 3);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let d = create_memo(cx, {
+            let seen = Rc::clone(&seen);
+            move |_| {
+                let (b2, c) = (b2.get(), c.get());
+                seen.borrow_mut().push((b2, c));
+                b2 + c
+            }
+        });
+        let effect_seen = Rc::new(RefCell::new(Vec::new()));
+        create_isomorphic_effect(cx, {
+            let effect_seen = Rc::clone(&effect_seen);
+            move |_| effect_seen.borrow_mut().push((a.get(), d.get()))
+        });
+
+        set_a.set(2);
+        set_a.set(3);
+
+        // `d` only ever sees both sides computed from the same `a`
+        assert_eq!(*seen.borrow(), vec![(3, 3), (5, 6), (7, 9)]);
+        assert_eq!(*effect_seen.borrow(), vec![(1, 6), (2, 11), (3, 16)]);
+    })
+    .dispose()
+}
@@ -0,0 +1,45 @@
+use leptos_reactive::{
+    create_memo, create_runtime, provide_context, provide_reactive_context,
+    run_scope, use_context, use_reactive_context, SignalGet, SignalSet,
+};
+
+#[test]
+fn reactive_context_updates_descendants() {
+    run_scope(create_runtime(), |cx| {
+        assert!(use_reactive_context::<&str>(cx).is_none());
+        provide_reactive_context(cx, "light");
+
+        let child = cx.child_scope(|child| {
+            let theme = use_reactive_context::<&str>(child).unwrap();
+            let class =
+                create_memo(child, move |_| format!("theme-{}", theme.get()));
+            assert_eq!(class.get(), "theme-light");
+
+            // replacing the value updates readers, rather than shadowing it
+            let signal = provide_reactive_context(cx, "dark");
+            assert_eq!(class.get(), "theme-dark");
+
+            signal.set("system");
+            assert_eq!(class.get(), "theme-system");
+        });
+        child.dispose();
+    });
+}
+
+#[test]
+fn reactive_context_is_separate_from_context() {
+    run_scope(create_runtime(), |cx| {
+        provide_context(cx, 1);
+        provide_reactive_context(cx, 2);
+        assert_eq!(use_context::<i32>(cx), Some(1));
+        assert_eq!(use_reactive_context::<i32>(cx).unwrap().get(), 2);
+
+        // a nearer provider shadows the outer one
+        let child = cx.child_scope(|child| {
+            provide_reactive_context(child, 3);
+            assert_eq!(use_reactive_context::<i32>(child).unwrap().get(), 3);
+        });
+        assert_eq!(use_reactive_context::<i32>(cx).unwrap().get(), 2);
+        child.dispose();
+    });
+}
@@ -63,3 +63,208 @@ fn resource_returns_last_future() {
         }));
     }
 }
+
+#[test]
+fn multi_source_resource_tells_fetcher_which_sources_changed() {
+    #[cfg(feature = "ssr")]
+    {
+        use leptos_reactive::{
+            create_multi_source_resource, create_runtime, create_signal,
+            raw_scope_and_disposer, SignalGet, SignalSet,
+        };
+        use std::{cell::RefCell, rc::Rc};
+        use tokio::task;
+        use tokio_test::block_on;
+
+        block_on(task::LocalSet::new().run_until(async move {
+            let (cx, disposer) = raw_scope_and_disposer(create_runtime());
+            task::spawn_local(async move {
+                let (query, set_query) = create_signal(cx, "a");
+                let (page, set_page) = create_signal(cx, 1);
+                let calls = Rc::new(RefCell::new(Vec::new()));
+
+                let resource = create_multi_source_resource(
+                    cx,
+                    move || (query.get(), page.get()),
+                    {
+                        let calls = Rc::clone(&calls);
+                        move |(query, page), changes| {
+                            calls.borrow_mut().push((
+                                changes.changed(0),
+                                changes.changed(1),
+                                changes.any(),
+                            ));
+                            async move { format!("{query}{page}") }
+                        }
+                    },
+                );
+                task::yield_now().await;
+                assert_eq!(resource.read(cx), Some("a1".to_string()));
+
+                set_page.set(2);
+                task::yield_now().await;
+                assert_eq!(resource.read(cx), Some("a2".to_string()));
+
+                set_query.set("b");
+                task::yield_now().await;
+                resource.refetch();
+                task::yield_now().await;
+                assert_eq!(resource.read(cx), Some("b2".to_string()));
+
+                assert_eq!(
+                    *calls.borrow(),
+                    vec![
+                        (true, true, true),
+                        (false, true, true),
+                        (true, false, true),
+                        (false, false, false),
+                    ]
+                );
+            })
+            .await
+            .unwrap();
+            disposer.dispose();
+        }));
+    }
+}
+
+#[test]
+fn resource_drops_superseded_and_aborted_requests() {
+    #[cfg(feature = "ssr")]
+    {
+        use futures::{channel::oneshot::channel, FutureExt};
+        use leptos_reactive::{
+            create_resource, create_runtime, create_signal,
+            raw_scope_and_disposer, SignalGet, SignalGetUntracked, SignalSet,
+        };
+        use std::{cell::RefCell, rc::Rc};
+        use tokio::task;
+        use tokio_test::block_on;
+
+        // records the request it belongs to when the request is dropped
+        struct Request(i32, Rc<RefCell<Vec<i32>>>);
+
+        impl Drop for Request {
+            fn drop(&mut self) {
+                self.1.borrow_mut().push(self.0);
+            }
+        }
+
+        block_on(task::LocalSet::new().run_until(async move {
+            let (cx, disposer) = raw_scope_and_disposer(create_runtime());
+            task::spawn_local(async move {
+                let (tx, rx) = channel::<()>();
+                let rx = rx.shared();
+                let dropped = Rc::new(RefCell::new(Vec::new()));
+
+                let (id, set_id) = create_signal(cx, 1);
+                let resource = create_resource(cx, move || id.get(), {
+                    let dropped = Rc::clone(&dropped);
+                    move |id| {
+                        let request = Request(id, Rc::clone(&dropped));
+                        let rx = rx.clone();
+                        async move {
+                            let request = request;
+                            rx.await.unwrap();
+                            request.0
+                        }
+                    }
+                });
+                task::yield_now().await;
+                assert!(resource.loading().get_untracked());
+
+                // changing the source drops the first request
+                set_id.set(2);
+                task::yield_now().await;
+                assert_eq!(*dropped.borrow(), vec![1]);
+
+                // aborting the resource drops the second
+                resource.abort();
+                assert!(!resource.loading().get_untracked());
+                task::yield_now().await;
+                assert_eq!(*dropped.borrow(), vec![1, 2]);
+
+                // refetching starts a new request
+                resource.refetch();
+                tx.send(()).unwrap();
+                task::yield_now().await;
+                assert_eq!(resource.read(cx), Some(2));
+            })
+            .await
+            .unwrap();
+            disposer.dispose();
+        }));
+    }
+}
+
+#[test]
+fn fallible_resource_reports_errors_to_collector() {
+    #[cfg(feature = "ssr")]
+    {
+        use leptos_reactive::{
+            create_fallible_resource, create_runtime, create_signal,
+            provide_context, raw_scope_and_disposer, ResourceErrorCollector,
+            SignalGet, SignalSet,
+        };
+        use std::{cell::RefCell, rc::Rc};
+        use tokio::task;
+        use tokio_test::block_on;
+
+        #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+        struct Odd(u32);
+
+        impl std::fmt::Display for Odd {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{} is odd", self.0)
+            }
+        }
+
+        impl std::error::Error for Odd {}
+
+        block_on(task::LocalSet::new().run_until(async move {
+            let (cx, disposer) = raw_scope_and_disposer(create_runtime());
+            task::spawn_local(async move {
+                let reported = Rc::new(RefCell::new(Vec::new()));
+                provide_context(
+                    cx,
+                    ResourceErrorCollector::new({
+                        let reported = Rc::clone(&reported);
+                        move |_, error| {
+                            reported
+                                .borrow_mut()
+                                .push(error.map(|error| error.to_string()))
+                        }
+                    }),
+                );
+
+                let (n, set_n) = create_signal(cx, 1);
+                let resource = create_fallible_resource(
+                    cx,
+                    move || n.get(),
+                    |n| async move {
+                        if n % 2 == 0 {
+                            Ok(n)
+                        } else {
+                            Err(Odd(n))
+                        }
+                    },
+                );
+                let error = resource.error(cx);
+                task::yield_now().await;
+                assert_eq!(error.get().map(|error| error.0), Some(1));
+
+                set_n.set(2);
+                task::yield_now().await;
+                assert!(error.get().is_none());
+
+                assert_eq!(
+                    *reported.borrow(),
+                    vec![None, Some("1 is odd".to_string()), None]
+                );
+            })
+            .await
+            .unwrap();
+            disposer.dispose();
+        }));
+    }
+}
@@ -0,0 +1,32 @@
+#![cfg(not(any(feature = "csr", feature = "hydrate")))]
+
+use leptos_reactive::{
+    create_runtime, create_rw_signal, provide_context, run_scope,
+    run_scope_undisposed, set_runtime_pool_capacity, use_context, SignalGet,
+};
+
+#[test]
+fn pooled_runtimes_are_isolated() {
+    set_runtime_pool_capacity(1);
+
+    let first = create_runtime();
+    let (signal, _, _) = run_scope_undisposed(first, |cx| {
+        provide_context(cx, "first");
+        create_rw_signal(cx, 1)
+    });
+    assert_eq!(signal.try_get(), Some(1));
+    first.dispose();
+
+    let second = create_runtime();
+    assert_ne!(first, second);
+    // handles from the disposed runtime can't reach the reused one
+    assert_eq!(signal.try_get(), None);
+    run_scope(second, |cx| {
+        assert_eq!(use_context::<&str>(cx), None);
+        let signal = create_rw_signal(cx, 2);
+        assert_eq!(signal.get(), 2);
+    });
+    second.dispose();
+
+    set_runtime_pool_capacity(0);
+}
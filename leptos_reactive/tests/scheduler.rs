@@ -0,0 +1,118 @@
+#![cfg(not(feature = "ssr"))]
+
+use leptos_reactive::*;
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn effects_with_priority_run_when_sources_change() {
+    create_scope(create_runtime(), |cx| {
+        let (count, set_count) = create_signal(cx, 0);
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        for priority in [
+            EffectPriority::UserBlocking,
+            EffectPriority::Normal,
+            EffectPriority::Idle,
+        ] {
+            create_effect_with_priority(cx, priority, {
+                let log = Rc::clone(&log);
+                move |_| log.borrow_mut().push((priority, count.get()))
+            });
+        }
+        assert_eq!(log.borrow().len(), 3);
+
+        // outside the browser, microtasks and idle callbacks run immediately
+        set_count.set(1);
+        assert_eq!(
+            log.borrow()[3..],
+            [
+                (EffectPriority::UserBlocking, 1),
+                (EffectPriority::Normal, 1),
+                (EffectPriority::Idle, 1)
+            ]
+        );
+    })
+    .dispose()
+}
+
+#[test]
+fn effects_with_priority_run_on_every_write_outside_the_browser() {
+    create_scope(create_runtime(), |cx| {
+        let (count, set_count) = create_signal(cx, 0);
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        create_effect_with_priority(cx, EffectPriority::Normal, {
+            let log = Rc::clone(&log);
+            move |_| log.borrow_mut().push(count.get())
+        });
+
+        // nothing is deferred: each write runs the effect once...
+        set_count.set(1);
+        set_count.set(2);
+        assert_eq!(*log.borrow(), [0, 1, 2]);
+
+        // ...so the only coalescing comes from batching the writes
+        cx.batch(|| {
+            set_count.set(3);
+            set_count.set(4);
+        });
+        assert_eq!(*log.borrow(), [0, 1, 2, 4]);
+    })
+    .dispose()
+}
+
+#[test]
+fn flush_sync_runs_batched_effects() {
+    create_scope(create_runtime(), |cx| {
+        let (count, set_count) = create_signal(cx, 0);
+        let runs = Rc::new(RefCell::new(0));
+
+        create_effect_with_priority(cx, EffectPriority::Normal, {
+            let runs = Rc::clone(&runs);
+            move |_| {
+                count.get();
+                *runs.borrow_mut() += 1;
+            }
+        });
+
+        // the two writes are coalesced by `cx.batch`, not by the scheduler
+        let value = flush_sync(cx, || {
+            cx.batch(|| {
+                set_count.set(1);
+                set_count.set(2);
+            });
+            count.get_untracked()
+        });
+        assert_eq!(value, 2);
+        assert_eq!(*runs.borrow(), 2);
+    })
+    .dispose()
+}
+
+#[test]
+fn flush_sync_runs_effects_inside_a_batch() {
+    create_scope(create_runtime(), |cx| {
+        let (count, set_count) = create_signal(cx, 0);
+        let runs = Rc::new(RefCell::new(0));
+
+        create_effect(cx, {
+            let runs = Rc::clone(&runs);
+            move |_| {
+                count.get();
+                *runs.borrow_mut() += 1;
+            }
+        });
+
+        cx.batch(|| {
+            set_count.set(1);
+            assert_eq!(*runs.borrow(), 1);
+            flush_sync(cx, || ());
+            assert_eq!(*runs.borrow(), 2);
+            // still batching after the flush
+            set_count.set(2);
+            assert_eq!(*runs.borrow(), 2);
+        });
+        assert_eq!(*runs.borrow(), 3);
+    })
+    .dispose()
+}
@@ -0,0 +1,40 @@
+#[test]
+fn selector_stats_count_entries_and_notifications() {
+    use leptos_reactive::*;
+    let runtime = create_runtime();
+
+    create_scope(runtime, move |cx| {
+        let (selected, set_selected) = create_signal(cx, 0);
+        let is_selected = create_selector(cx, move || selected.get());
+        for row in 0..5 {
+            create_isomorphic_effect(cx, {
+                let is_selected = is_selected.clone();
+                move |_| is_selected(row)
+            });
+        }
+
+        let stats = runtime.selector_stats();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].defined_at.file(), file!());
+        assert_eq!(stats[0].entries, 5);
+        assert_eq!(stats[0].notifications, 0);
+
+        // each change notifies the key that was selected and the new one
+        set_selected.set(3);
+        set_selected.set(0);
+        set_selected.set(3);
+
+        let stats = runtime.selector_stats();
+        assert_eq!(stats[0].entries, 5);
+        assert_eq!(stats[0].notifications, 6);
+        let hot_keys = stats[0]
+            .hot_keys
+            .iter()
+            .map(|key| key.notifications)
+            .collect::<Vec<_>>();
+        assert_eq!(hot_keys, vec![3, 3, 0, 0, 0]);
+    })
+    .dispose();
+
+    runtime.dispose();
+}
@@ -28,3 +28,93 @@ fn derived_signals() {
     })
     .dispose()
 }
+
+#[test]
+fn signal_with_eq_skips_equal_writes() {
+    use leptos_reactive::{
+        create_isomorphic_effect, create_runtime, create_rw_signal_with_eq,
+        create_scope, create_signal_with_eq, SignalGet, SignalSet,
+        SignalUpdate,
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    create_scope(create_runtime(), |cx| {
+        // compares names case-insensitively
+        let (name, set_name) = create_signal_with_eq(
+            cx,
+            "Alice".to_string(),
+            |a: &String, b: &String| a.eq_ignore_ascii_case(b),
+        );
+        let runs = Rc::new(Cell::new(0));
+        create_isomorphic_effect(cx, {
+            let runs = Rc::clone(&runs);
+            move |_| {
+                name.get();
+                runs.set(runs.get() + 1);
+            }
+        });
+        assert_eq!(runs.get(), 1);
+
+        set_name.set("ALICE".to_string());
+        assert_eq!(runs.get(), 1);
+        assert_eq!(name.get(), "Alice");
+
+        set_name.set("Bob".to_string());
+        assert_eq!(runs.get(), 2);
+        assert_eq!(name.get(), "Bob");
+
+        // updating in place always notifies
+        set_name.update(|name| name.make_ascii_uppercase());
+        assert_eq!(runs.get(), 3);
+
+        let count = create_rw_signal_with_eq(cx, 0, |a, b| a == b);
+        create_isomorphic_effect(cx, {
+            let runs = Rc::clone(&runs);
+            move |_| {
+                count.get();
+                runs.set(runs.get() + 1);
+            }
+        });
+        assert_eq!(runs.get(), 4);
+        count.set(0);
+        assert_eq!(runs.get(), 4);
+        assert_eq!(count.try_set(1), None);
+        assert_eq!(runs.get(), 5);
+    })
+    .dispose()
+}
+
+#[test]
+fn set_if_changed_skips_equal_writes() {
+    use leptos_reactive::{
+        create_isomorphic_effect, create_runtime, create_rw_signal,
+        create_scope, create_signal, SignalGet,
+    };
+    use std::{cell::Cell, rc::Rc};
+
+    create_scope(create_runtime(), |cx| {
+        let (a, set_a) = create_signal(cx, 0);
+        let b = create_rw_signal(cx, 0);
+        let runs = Rc::new(Cell::new(0));
+        create_isomorphic_effect(cx, {
+            let runs = Rc::clone(&runs);
+            move |_| {
+                a.get();
+                b.get();
+                runs.set(runs.get() + 1);
+            }
+        });
+        assert_eq!(runs.get(), 1);
+
+        set_a.set_if_changed(0);
+        b.set_if_changed(0);
+        assert_eq!(runs.get(), 1);
+
+        set_a.set_if_changed(1);
+        assert_eq!(runs.get(), 2);
+        b.set_if_changed(2);
+        assert_eq!(runs.get(), 3);
+        assert_eq!((a.get(), b.get()), (1, 2));
+    })
+    .dispose()
+}
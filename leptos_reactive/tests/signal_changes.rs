@@ -0,0 +1,64 @@
+#![cfg(not(feature = "ssr"))]
+
+use futures::{FutureExt, StreamExt};
+use leptos_reactive::{
+    create_runtime, create_scope, create_signal, ChangeBuffer, SignalSet,
+};
+
+#[test]
+fn changes_yields_only_new_values() {
+    create_scope(create_runtime(), |cx| {
+        let (count, set_count) = create_signal(cx, 0);
+        let mut changes = count.changes(cx, ChangeBuffer::Unbounded);
+        assert_eq!(changes.next().now_or_never(), None);
+
+        set_count.set(1);
+        set_count.set(2);
+        set_count.set(3);
+        assert_eq!(changes.pending(), 3);
+        assert_eq!(changes.next().now_or_never(), Some(Some(1)));
+        assert_eq!(changes.next().now_or_never(), Some(Some(2)));
+        assert_eq!(changes.next().now_or_never(), Some(Some(3)));
+        assert_eq!(changes.missed(), 0);
+    })
+    .dispose()
+}
+
+#[test]
+fn changes_drops_oldest_values_when_full() {
+    create_scope(create_runtime(), |cx| {
+        let (count, set_count) = create_signal(cx, 0);
+        let mut latest = count.changes(cx, ChangeBuffer::Latest);
+        let mut bounded = count.changes(cx, ChangeBuffer::Bounded(2));
+
+        for n in 1..=4 {
+            set_count.set(n);
+        }
+
+        assert_eq!(latest.next().now_or_never(), Some(Some(4)));
+        assert_eq!(latest.missed(), 3);
+
+        assert_eq!(bounded.next().now_or_never(), Some(Some(3)));
+        assert_eq!(bounded.next().now_or_never(), Some(Some(4)));
+        assert_eq!(bounded.missed(), 2);
+    })
+    .dispose()
+}
+
+#[test]
+fn changes_ends_when_scope_is_disposed() {
+    create_scope(create_runtime(), |cx| {
+        let (count, set_count) = create_signal(cx, 0);
+        let (mut changes, disposer) = cx.run_child_scope(|child| {
+            count.changes(child, ChangeBuffer::Unbounded)
+        });
+
+        set_count.set(1);
+        disposer.dispose();
+        set_count.set(2);
+
+        assert_eq!(changes.next().now_or_never(), Some(Some(1)));
+        assert_eq!(changes.next().now_or_never(), Some(None));
+    })
+    .dispose()
+}
@@ -0,0 +1,81 @@
+#![cfg(not(feature = "ssr"))]
+
+use leptos_reactive::*;
+use std::{cell::RefCell, rc::Rc, task::Poll};
+
+// yields to the executor once, so that it has to poll the task again
+async fn yield_now() {
+    let mut yielded = false;
+    futures::future::poll_fn(|cx| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+#[test]
+fn spawn_local_scoped_runs_while_scope_is_alive() {
+    create_scope(create_runtime(), |cx| {
+        let (value, set_value) = create_signal(cx, 0);
+        spawn_local_scoped(cx, async move {
+            yield_now().await;
+            set_value.set(1);
+        });
+        assert_eq!(value.get_untracked(), 1);
+    })
+    .dispose()
+}
+
+#[test]
+fn spawn_local_scoped_stops_when_scope_is_disposed() {
+    create_scope(create_runtime(), |cx| {
+        let steps = Rc::new(RefCell::new(Vec::new()));
+        let mut child = None;
+        _ = cx.child_scope(|cx| child = Some(cx));
+        let child = child.unwrap();
+
+        spawn_local_scoped(child, {
+            let steps = Rc::clone(&steps);
+            async move {
+                steps.borrow_mut().push("started");
+                child.dispose();
+                yield_now().await;
+                steps.borrow_mut().push("finished");
+            }
+        });
+        assert_eq!(*steps.borrow(), ["started"]);
+
+        // the scope has already been disposed, so this never starts
+        spawn_local_scoped(child, {
+            let steps = Rc::clone(&steps);
+            async move {
+                steps.borrow_mut().push("started again");
+            }
+        });
+        assert_eq!(*steps.borrow(), ["started"]);
+    })
+    .dispose()
+}
+
+#[test]
+fn spawn_blocking_returns_result() {
+    let value = futures::executor::block_on(spawn_blocking(|| 6 * 7));
+    assert_eq!(value, 42);
+}
+
+#[test]
+fn spawn_runs_send_future() {
+    let (tx, rx) = std::sync::mpsc::channel();
+    spawn(async move {
+        yield_now().await;
+        tx.send(42).unwrap();
+    });
+    // outside the browser and without `ssr`, the future runs to completion
+    // before `spawn` returns
+    assert_eq!(rx.try_recv(), Ok(42));
+}
@@ -0,0 +1,64 @@
+#[test]
+fn stale_while_refresh_memo_keeps_last_value_while_refreshing() {
+    #[cfg(feature = "ssr")]
+    {
+        use futures::channel::oneshot::channel;
+        use leptos_reactive::{
+            create_runtime, create_signal, create_stale_while_refresh_memo,
+            raw_scope_and_disposer, SignalGet, SignalGetUntracked, SignalSet,
+        };
+        use std::{cell::RefCell, rc::Rc};
+        use tokio::task;
+        use tokio_test::block_on;
+
+        block_on(task::LocalSet::new().run_until(async move {
+            let (cx, disposer) = raw_scope_and_disposer(create_runtime());
+            task::spawn_local(async move {
+                let (input, set_input) = create_signal(cx, 1);
+                let senders = Rc::new(RefCell::new(Vec::new()));
+                let memo = create_stale_while_refresh_memo(cx, {
+                    let senders = Rc::clone(&senders);
+                    move || {
+                        let input = input.get();
+                        let (tx, rx) = channel::<i32>();
+                        senders.borrow_mut().push((input, tx));
+                        async move { rx.await.unwrap_or_default() * 10 }
+                    }
+                });
+
+                task::yield_now().await;
+                assert!(memo.is_refreshing().get_untracked());
+                assert_eq!(memo.get(), None);
+
+                let (n, tx) = senders.borrow_mut().remove(0);
+                tx.send(n).unwrap();
+                task::yield_now().await;
+                assert!(!memo.is_refreshing().get_untracked());
+                assert_eq!(memo.get(), Some(10));
+
+                // the old value stays while the new one is computed
+                set_input.set(2);
+                task::yield_now().await;
+                assert!(memo.is_refreshing().get_untracked());
+                assert_eq!(memo.get(), Some(10));
+
+                // a superseded computation never overwrites a newer one
+                set_input.set(3);
+                task::yield_now().await;
+                let (three, tx3) = senders.borrow_mut().pop().unwrap();
+                let (two, tx2) = senders.borrow_mut().pop().unwrap();
+                tx3.send(three).unwrap();
+                task::yield_now().await;
+                assert_eq!(memo.get(), Some(30));
+                assert!(!memo.is_refreshing().get_untracked());
+
+                tx2.send(two).unwrap();
+                task::yield_now().await;
+                assert_eq!(memo.get(), Some(30));
+            })
+            .await
+            .unwrap();
+            disposer.dispose();
+        }));
+    }
+}
@@ -0,0 +1,59 @@
+#[test]
+fn stats_count_what_scopes_own() {
+    use leptos_reactive::*;
+    let runtime = create_runtime();
+
+    create_scope(runtime, move |cx| {
+        let (a, _) = create_signal(cx, 0);
+        let (child, disposer) = cx.run_child_scope(|cx| {
+            let double = create_memo(cx, move |_| a.get() * 2);
+            create_isomorphic_effect(cx, move |_| double.get());
+            create_trigger(cx);
+            store_value(cx, ());
+            on_cleanup(cx, || {});
+            cx
+        });
+
+        assert_eq!(
+            child.stats(),
+            ScopeStats {
+                scopes: 1,
+                triggers: 1,
+                memos: 1,
+                effects: 1,
+                stored_values: 1,
+                cleanups: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            cx.stats(),
+            ScopeStats {
+                scopes: 2,
+                signals: 1,
+                triggers: 1,
+                memos: 1,
+                effects: 1,
+                stored_values: 1,
+                cleanups: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(runtime.stats(), cx.stats());
+
+        disposer.dispose();
+        assert_eq!(child.stats(), ScopeStats::default());
+        assert_eq!(
+            cx.stats(),
+            ScopeStats {
+                scopes: 1,
+                signals: 1,
+                ..Default::default()
+            }
+        );
+    })
+    .dispose();
+
+    assert_eq!(runtime.stats(), ScopeStats::default());
+    runtime.dispose();
+}
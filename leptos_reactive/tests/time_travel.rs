@@ -0,0 +1,118 @@
+#![cfg(debug_assertions)]
+
+use leptos_reactive::*;
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn records_signal_writes() {
+    let runtime = create_runtime();
+
+    create_scope(runtime, move |cx| {
+        let count = create_rw_signal(cx, 0);
+        count.record();
+        let (name, set_name) = create_signal(cx, String::new());
+
+        count.set(1);
+        runtime.start_recording(10);
+        count.set(2);
+        set_name.set("Alice".to_string());
+        name.get_untracked();
+
+        let writes = runtime.recorded_writes();
+        assert_eq!(writes.len(), 2);
+        assert_eq!(writes[0].previous.as_deref(), Some("1"));
+        assert_eq!(writes[0].value.as_deref(), Some("2"));
+        assert_eq!(writes[0].effect, None);
+        // signals that are not recorded still show up, without values
+        assert_eq!(writes[1].value, None);
+
+        runtime.stop_recording();
+        count.set(3);
+        assert!(runtime.recorded_writes().is_empty());
+    })
+    .dispose();
+    runtime.dispose();
+}
+
+#[test]
+fn records_originating_effect() {
+    let runtime = create_runtime();
+
+    create_scope(runtime, move |cx| {
+        runtime.start_recording(10);
+        let (a, set_a) = create_signal(cx, 0);
+        let b = create_rw_signal(cx, 0);
+        create_isomorphic_effect(cx, move |_| b.set(a.get() * 2));
+        set_a.set(1);
+
+        let writes = runtime.recorded_writes();
+        assert_eq!(writes.len(), 3);
+        assert_eq!(writes[0].effect, writes[2].effect);
+        assert!(writes[2].effect.is_some());
+        assert_eq!(writes[1].effect, None);
+    })
+    .dispose();
+    runtime.dispose();
+}
+
+#[test]
+fn rewind_and_replay() {
+    let runtime = create_runtime();
+
+    create_scope(runtime, move |cx| {
+        let count = create_rw_signal(cx, 0);
+        count.record();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        create_isomorphic_effect(cx, {
+            let log = Rc::clone(&log);
+            move |_| log.borrow_mut().push(count.get())
+        });
+
+        runtime.start_recording(2);
+        count.set(1);
+        count.set(2);
+        count.set(3);
+        // the oldest write was dropped from the ring buffer
+        assert_eq!(runtime.recorded_writes().len(), 2);
+
+        assert_eq!(runtime.rewind(5), 2);
+        assert_eq!(count.get_untracked(), 1);
+        assert!(runtime.recorded_writes().is_empty());
+
+        assert_eq!(runtime.replay(1), 1);
+        assert_eq!(count.get_untracked(), 2);
+        assert_eq!(*log.borrow(), vec![0, 1, 2, 3, 2, 1, 2]);
+
+        // writing discards the writes that were undone
+        count.set(10);
+        assert_eq!(runtime.replay(1), 0);
+        assert_eq!(runtime.recorded_writes().len(), 2);
+    })
+    .dispose();
+    runtime.dispose();
+}
+
+#[test]
+fn unchanged_set_if_changed_keeps_redo_history() {
+    let runtime = create_runtime();
+
+    create_scope(runtime, move |cx| {
+        let count = create_rw_signal(cx, 0);
+        count.record();
+
+        runtime.start_recording(10);
+        count.set(1);
+        count.set(2);
+        assert_eq!(runtime.rewind(1), 1);
+        assert_eq!(count.get_untracked(), 1);
+
+        // the value doesn't change, so nothing is written
+        count.set_if_changed(1);
+        assert_eq!(runtime.recorded_writes().len(), 1);
+
+        assert_eq!(runtime.replay(1), 1);
+        assert_eq!(count.get_untracked(), 2);
+    })
+    .dispose();
+    runtime.dispose();
+}
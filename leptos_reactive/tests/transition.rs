@@ -0,0 +1,103 @@
+use leptos_reactive::*;
+
+#[test]
+fn newer_transition_interrupts_pending_one() {
+    create_scope(create_runtime(), |cx| {
+        let (tab, set_tab) = create_signal(cx, 0);
+
+        let first = start_transition(cx, move || set_tab.set(1));
+        assert!(!first.is_interrupted());
+        assert!(!first.is_pending());
+
+        let second = start_transition(cx, move || set_tab.set(2));
+        assert!(first.is_interrupted());
+        assert!(!second.is_interrupted());
+        assert_eq!(tab.get(), 2);
+
+        second.interrupt();
+        assert!(second.is_interrupted());
+        assert_eq!(pending_transition(cx), None);
+    })
+    .dispose()
+}
+
+#[test]
+fn settled_transitions_do_not_leak_signals() {
+    create_scope(create_runtime(), |cx| {
+        let (_, set_tab) = create_signal(cx, 0);
+        let before = cx.stats();
+
+        for tab in 1..=10 {
+            start_transition(cx, move || set_tab.set(tab));
+        }
+        assert_eq!(cx.stats(), before);
+
+        let last = start_transition(cx, move || set_tab.set(11));
+        assert!(!last.is_interrupted());
+        last.interrupt();
+        assert!(last.is_interrupted());
+        assert_eq!(cx.stats(), before);
+    })
+    .dispose()
+}
+
+#[test]
+fn transition_is_pending_while_resources_load() {
+    #[cfg(feature = "ssr")]
+    {
+        use futures::{channel::oneshot::channel, FutureExt};
+        use tokio::task;
+        use tokio_test::block_on;
+
+        block_on(task::LocalSet::new().run_until(async move {
+            let (cx, disposer) = raw_scope_and_disposer(create_runtime());
+            task::spawn_local(async move {
+                let (tx_1, rx_1) = channel::<()>();
+                let (tx_2, rx_2) = channel::<()>();
+                let rx_1 = rx_1.shared();
+                let rx_2 = rx_2.shared();
+
+                let (page, set_page) = create_signal(cx, 0);
+                let _resource = create_resource(
+                    cx,
+                    move || page.get(),
+                    move |page| {
+                        let rx_1 = rx_1.clone();
+                        let rx_2 = rx_2.clone();
+                        async move {
+                            match page {
+                                0 => {}
+                                1 => rx_1.await.unwrap(),
+                                _ => rx_2.await.unwrap(),
+                            }
+                            page
+                        }
+                    },
+                );
+                task::yield_now().await;
+
+                let first = start_transition(cx, move || set_page.set(1));
+                assert!(first.is_pending());
+                assert_eq!(pending_transition(cx), Some(first));
+
+                let second = start_transition(cx, move || set_page.set(2));
+                assert!(!first.is_pending());
+                assert!(second.is_pending());
+
+                // resolving the interrupted transition's future changes nothing
+                tx_1.send(()).unwrap();
+                task::yield_now().await;
+                assert!(second.is_pending());
+
+                tx_2.send(()).unwrap();
+                task::yield_now().await;
+                assert!(!second.is_pending());
+                assert_eq!(pending_transition(cx), None);
+            })
+            .await
+            .unwrap();
+
+            disposer.dispose();
+        }));
+    }
+}
@@ -63,6 +63,7 @@ mod body;
 mod html;
 mod link;
 mod meta_tags;
+mod resource_hints;
 mod script;
 mod style;
 mod stylesheet;
@@ -71,6 +72,7 @@ pub use body::*;
 pub use html::*;
 pub use link::*;
 pub use meta_tags::*;
+pub use resource_hints::*;
 pub use script::*;
 pub use style::*;
 pub use stylesheet::*;
@@ -90,6 +92,8 @@ pub struct MetaContext {
     pub body: BodyContext,
     /// Other metadata tags.
     pub tags: MetaTagsContext,
+    /// Resource hints, like `preconnect` and `preload` links.
+    pub hints: ResourceHintsContext,
 }
 
 /// Manages all of the element created by components.
@@ -274,6 +278,7 @@ impl MetaContext {
             tags.push_str(&title);
             tags.push_str("</title>");
         }
+        tags.push_str(&self.hints.as_string());
         tags.push_str(&self.tags.as_string());
 
         HydrationCtx::continue_from(prev_key);
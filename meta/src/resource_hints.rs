@@ -0,0 +1,357 @@
+use crate::use_head;
+use cfg_if::cfg_if;
+use indexmap::IndexMap;
+use leptos::{leptos_dom::html::AnyElement, *};
+use std::{borrow::Cow, cell::RefCell, rc::Rc};
+#[cfg(any(feature = "csr", feature = "hydrate"))]
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+
+/// A hint that tells the browser about a resource the page will need, so that
+/// it can start connecting to its server or loading it before it finds it in
+/// the page.
+///
+/// Hints are added to the `<head>` with [`use_resource_hint`] or the
+/// [`Preconnect`], [`DnsPrefetch`] and [`Preload`] components. The same hint
+/// is only added once, however many components ask for it, and stays until the
+/// last of them is unmounted.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceHint {
+    /// Opens a connection to an origin that resources will be loaded from.
+    Preconnect {
+        /// The origin, like `https://fonts.gstatic.com`.
+        href: Cow<'static, str>,
+        /// Whether the resources will be loaded with CORS, like fonts are.
+        crossorigin: bool,
+    },
+    /// Looks up the DNS records of an origin that resources will be loaded
+    /// from.
+    DnsPrefetch {
+        /// The origin, like `https://cdn.example.com`.
+        href: Cow<'static, str>,
+    },
+    /// Starts loading a resource that the page will need.
+    Preload {
+        /// The URL of the resource.
+        href: Cow<'static, str>,
+        /// What kind of resource it is, like `font`, `image` or `script`.
+        as_: Cow<'static, str>,
+        /// The MIME type of the resource, like `font/woff2`.
+        type_: Option<Cow<'static, str>>,
+        /// Whether the resource is loaded with CORS. Fonts always are.
+        crossorigin: bool,
+    },
+}
+
+impl ResourceHint {
+    /// Opens a connection to an origin that resources will be loaded from.
+    pub fn preconnect(href: impl Into<Cow<'static, str>>) -> Self {
+        Self::Preconnect {
+            href: href.into(),
+            crossorigin: false,
+        }
+    }
+
+    /// Looks up the DNS records of an origin that resources will be loaded
+    /// from.
+    pub fn dns_prefetch(href: impl Into<Cow<'static, str>>) -> Self {
+        Self::DnsPrefetch { href: href.into() }
+    }
+
+    /// Starts loading a font, like a `font/woff2` file.
+    pub fn preload_font(
+        href: impl Into<Cow<'static, str>>,
+        type_: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self::Preload {
+            href: href.into(),
+            as_: "font".into(),
+            type_: Some(type_.into()),
+            crossorigin: true,
+        }
+    }
+
+    /// Starts loading an image.
+    pub fn preload_image(href: impl Into<Cow<'static, str>>) -> Self {
+        Self::Preload {
+            href: href.into(),
+            as_: "image".into(),
+            type_: None,
+            crossorigin: false,
+        }
+    }
+
+    fn rel(&self) -> &'static str {
+        match self {
+            Self::Preconnect { .. } => "preconnect",
+            Self::DnsPrefetch { .. } => "dns-prefetch",
+            Self::Preload { .. } => "preload",
+        }
+    }
+
+    fn href(&self) -> &Cow<'static, str> {
+        match self {
+            Self::Preconnect { href, .. }
+            | Self::DnsPrefetch { href }
+            | Self::Preload { href, .. } => href,
+        }
+    }
+
+    // connection hints come before preloads, so that the connections are
+    // already open when the preloads start
+    fn is_connection(&self) -> bool {
+        !matches!(self, Self::Preload { .. })
+    }
+
+    // hints with the same key are the same hint, even if they differ in
+    // details like `type`
+    fn key(&self) -> String {
+        match self {
+            Self::Preload { as_, .. } => {
+                format!("leptos-hint-{}-{as_}-{}", self.rel(), self.href())
+            }
+            _ => format!("leptos-hint-{}-{}", self.rel(), self.href()),
+        }
+    }
+
+    fn to_element(&self, cx: Scope, id: String) -> HtmlElement<AnyElement> {
+        let (as_, type_, crossorigin) = match self {
+            Self::Preconnect { crossorigin, .. } => (None, None, *crossorigin),
+            Self::DnsPrefetch { .. } => (None, None, false),
+            Self::Preload {
+                as_,
+                type_,
+                crossorigin,
+                ..
+            } => (
+                Some(as_.clone()),
+                type_.clone(),
+                *crossorigin || as_ == "font",
+            ),
+        };
+        let href = self.href().clone();
+        let rel = self.rel();
+        leptos::leptos_dom::html::as_meta_tag(move || {
+            leptos::leptos_dom::html::link(cx)
+                .attr("id", id)
+                .attr("rel", rel)
+                .attr("href", href)
+                .attr("as", as_)
+                .attr("type", type_)
+                .attr("crossorigin", crossorigin.then_some("anonymous"))
+                .into_any()
+        })
+    }
+}
+
+/// Manages the resource hints added by components.
+#[derive(Clone, Default)]
+pub struct ResourceHintsContext {
+    hints: Rc<RefCell<IndexMap<String, RegisteredHint>>>,
+}
+
+struct RegisteredHint {
+    hint: ResourceHint,
+    el: HtmlElement<AnyElement>,
+    cx: Scope,
+    // how many scopes are using the hint
+    count: usize,
+}
+
+impl std::fmt::Debug for ResourceHintsContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResourceHintsContext").finish()
+    }
+}
+
+impl ResourceHintsContext {
+    /// Converts the resource hints into an HTML string, with the connection
+    /// hints first.
+    #[cfg(any(feature = "ssr", docs))]
+    pub fn as_string(&self) -> String {
+        let hints = self.hints.borrow();
+        let (connections, preloads): (Vec<_>, Vec<_>) =
+            hints.values().partition(|hint| hint.hint.is_connection());
+        connections
+            .into_iter()
+            .chain(preloads)
+            .map(|RegisteredHint { el, cx, .. }| {
+                el.clone().into_view(*cx).render_to_string(*cx)
+            })
+            .collect()
+    }
+
+    #[doc(hidden)]
+    pub fn register(&self, cx: Scope, hint: ResourceHint) {
+        let key = hint.key();
+        let is_registered = match self.hints.borrow_mut().get_mut(&key) {
+            Some(registered) => {
+                registered.count += 1;
+                true
+            }
+            None => false,
+        };
+        if !is_registered {
+            let el = hint.to_element(cx, key.clone());
+
+            cfg_if! {
+                if #[cfg(any(feature = "csr", feature = "hydrate"))] {
+                    // a hint rendered on the server is reused, rather than
+                    // added again
+                    if document().get_element_by_id(&key).is_none() {
+                        document()
+                            .head()
+                            .unwrap_throw()
+                            .append_child(el.unchecked_ref())
+                            .unwrap_throw();
+                    }
+                }
+            }
+
+            self.hints.borrow_mut().insert(
+                key.clone(),
+                RegisteredHint {
+                    hint,
+                    el,
+                    cx,
+                    count: 1,
+                },
+            );
+        }
+
+        on_cleanup(cx, {
+            let hints = Rc::clone(&self.hints);
+            move || {
+                let mut hints = hints.borrow_mut();
+                let Some(registered) = hints.get_mut(&key) else {
+                    return;
+                };
+                registered.count -= 1;
+                if registered.count == 0 {
+                    hints.remove(&key);
+
+                    #[cfg(any(feature = "csr", feature = "hydrate"))]
+                    if let Some(el) = document().get_element_by_id(&key) {
+                        el.remove();
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// Adds a [`ResourceHint`] to the document head for as long as this scope is
+/// alive.
+///
+/// When rendering on the server, the hints are included in the HTML that
+/// `MetaContext::dehydrate` generates for the `<head>`, so the browser can act
+/// on them before it has loaded the rest of the page.
+// the example calls `MetaContext::dehydrate`, which only exists with `ssr`
+#[cfg_attr(
+    feature = "ssr",
+    doc = r##"
+```
+use leptos::*;
+use leptos_meta::*;
+
+run_scope(create_runtime(), |cx| {
+    provide_meta_context(cx);
+
+    use_resource_hint(
+        cx,
+        ResourceHint::preload_font("/fonts/inter.woff2", "font/woff2"),
+    );
+    // the same hint, asked for by another component
+    use_resource_hint(
+        cx,
+        ResourceHint::preload_font("/fonts/inter.woff2", "font/woff2"),
+    );
+    use_resource_hint(cx, ResourceHint::preconnect("https://cdn.example.com"));
+
+    let head = use_head(cx).dehydrate();
+    assert_eq!(head.matches(r#"rel="preload""#).count(), 1);
+    assert!(
+        head.find("preconnect").unwrap() < head.find("preload").unwrap()
+    );
+});
+```
+"##
+)]
+pub fn use_resource_hint(cx: Scope, hint: ResourceHint) {
+    use_head(cx).hints.register(cx, hint);
+}
+
+/// Opens a connection to an origin that resources will be loaded from, with a
+/// [`preconnect`](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/rel/preconnect)
+/// link in the document head.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_meta::*;
+///
+/// #[component]
+/// fn MyApp(cx: Scope) -> impl IntoView {
+///     provide_meta_context(cx);
+///
+///     view! { cx,
+///       <Preconnect href="https://fonts.gstatic.com" crossorigin=true/>
+///       <DnsPrefetch href="https://analytics.example.com"/>
+///       <Preload href="/hero.avif" as_="image"/>
+///       <main>"…"</main>
+///     }
+/// }
+/// ```
+#[component(transparent)]
+pub fn Preconnect(
+    cx: Scope,
+    /// The origin, like `https://fonts.gstatic.com`.
+    #[prop(into)]
+    href: Cow<'static, str>,
+    /// Whether the resources will be loaded with CORS, like fonts are.
+    #[prop(optional)]
+    crossorigin: bool,
+) -> impl IntoView {
+    use_resource_hint(cx, ResourceHint::Preconnect { href, crossorigin })
+}
+
+/// Looks up the DNS records of an origin that resources will be loaded from,
+/// with a [`dns-prefetch`](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/rel/dns-prefetch)
+/// link in the document head.
+#[component(transparent)]
+pub fn DnsPrefetch(
+    cx: Scope,
+    /// The origin, like `https://cdn.example.com`.
+    #[prop(into)]
+    href: Cow<'static, str>,
+) -> impl IntoView {
+    use_resource_hint(cx, ResourceHint::DnsPrefetch { href })
+}
+
+/// Starts loading a resource the page will need, like a font or an image,
+/// with a [`preload`](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/rel/preload)
+/// link in the document head.
+#[component(transparent)]
+pub fn Preload(
+    cx: Scope,
+    /// The URL of the resource.
+    #[prop(into)]
+    href: Cow<'static, str>,
+    /// What kind of resource it is, like `font`, `image` or `script`.
+    #[prop(into)]
+    as_: Cow<'static, str>,
+    /// The MIME type of the resource, like `font/woff2`.
+    #[prop(optional, into)]
+    type_: Option<Cow<'static, str>>,
+    /// Whether the resource is loaded with CORS. Fonts always are.
+    #[prop(optional)]
+    crossorigin: bool,
+) -> impl IntoView {
+    use_resource_hint(
+        cx,
+        ResourceHint::Preload {
+            href,
+            as_,
+            type_,
+            crossorigin,
+        },
+    )
+}
@@ -0,0 +1,129 @@
+use crate::{matching::get_route_matches, use_router, ParamsMap};
+use leptos::*;
+use std::{fmt::Debug, rc::Rc};
+
+/// Describes how a route should be labeled in a list of breadcrumbs. This can be
+/// a static [String] or [&str], or a function that builds the label from the
+/// route’s current params.
+#[derive(Clone)]
+pub struct BreadcrumbLabel(Rc<dyn Fn(&ParamsMap) -> String>);
+
+impl BreadcrumbLabel {
+    /// Resolves the label for the given set of route params.
+    #[inline(always)]
+    pub fn resolve(&self, params: &ParamsMap) -> String {
+        (self.0)(params)
+    }
+}
+
+impl Debug for BreadcrumbLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("BreadcrumbLabel").finish()
+    }
+}
+
+impl From<String> for BreadcrumbLabel {
+    fn from(s: String) -> Self {
+        BreadcrumbLabel(Rc::new(move |_| s.clone()))
+    }
+}
+
+impl From<&str> for BreadcrumbLabel {
+    fn from(s: &str) -> Self {
+        let s = s.to_string();
+        BreadcrumbLabel(Rc::new(move |_| s.clone()))
+    }
+}
+
+impl<F> From<F> for BreadcrumbLabel
+where
+    F: Fn(&ParamsMap) -> String + 'static,
+{
+    #[inline(always)]
+    fn from(f: F) -> Self {
+        BreadcrumbLabel(Rc::new(f))
+    }
+}
+
+/// A single segment in the chain of currently-matched routes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breadcrumb {
+    /// The URL path matched by this segment, with params in their places
+    /// (e.g., `/users/42`). This can be used as the `href` of a link.
+    pub path: String,
+    /// The route pattern for this segment (e.g., `/users/:id`).
+    pub pattern: String,
+    /// The label declared with the `breadcrumb` prop of the matched
+    /// [`<Route/>`](crate::Route), if any.
+    pub label: Option<String>,
+    /// The params matched so far.
+    pub params: ParamsMap,
+}
+
+/// Returns a reactive list of the routes that are currently matched, from the
+/// outermost route to the innermost one, with the labels they declared using the
+/// `breadcrumb` prop of [`<Route/>`](crate::Route).
+///
+/// Nested routes that match the same path (like an index route beneath its parent)
+/// are merged into a single [`Breadcrumb`], preferring the innermost label.
+///
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// #[component]
+/// pub fn Breadcrumbs(cx: Scope) -> impl IntoView {
+///     let crumbs = use_breadcrumbs(cx);
+///     view! { cx,
+///         <nav>
+///             <For
+///                 each=move || crumbs.get()
+///                 key=|crumb| crumb.path.clone()
+///                 view=move |cx, crumb: Breadcrumb| view! { cx,
+///                     <A href=crumb.path>{crumb.label.unwrap_or_default()}</A>
+///                 }
+///             />
+///         </nav>
+///     }
+/// }
+/// ```
+pub fn use_breadcrumbs(cx: Scope) -> Memo<Vec<Breadcrumb>> {
+    let router = use_router(cx);
+    let routes_base = router.inner.routes_base;
+    let pathname = router.pathname();
+
+    create_memo(cx, move |_| {
+        // `<Routes/>` may not have been rendered yet, if the breadcrumbs
+        // are placed above it
+        let Some(base) = routes_base.get() else {
+            return Vec::new();
+        };
+        let matches = get_route_matches(&base, pathname.get());
+
+        let mut crumbs: Vec<Breadcrumb> = Vec::with_capacity(matches.len());
+        for route_match in matches.iter() {
+            let label = route_match
+                .route
+                .key
+                .breadcrumb
+                .as_ref()
+                .map(|label| label.resolve(&route_match.path_match.params));
+
+            match crumbs.last_mut() {
+                Some(prev) if prev.path == route_match.path_match.path => {
+                    if label.is_some() {
+                        prev.label = label;
+                    }
+                    prev.pattern = route_match.route.pattern.clone();
+                    prev.params = route_match.path_match.params.clone();
+                }
+                _ => crumbs.push(Breadcrumb {
+                    path: route_match.path_match.path.clone(),
+                    pattern: route_match.route.pattern.clone(),
+                    label,
+                    params: route_match.path_match.params.clone(),
+                }),
+            }
+        }
+        crumbs
+    })
+}
@@ -0,0 +1,171 @@
+use super::route::define_route;
+use crate::{
+    use_route, use_router, BreadcrumbLabel, Method, Redirect, SsrMode,
+};
+use leptos::*;
+use std::{future::Future, rc::Rc};
+
+/// Describes a route that is only shown if a check on the server allows it. This works the
+/// same way as [`<ProtectedRoute/>`](crate::ProtectedRoute), except that `check` is
+/// asynchronous, and should be a [server function](leptos::server) that returns whether the
+/// current user may see the route.
+///
+/// Because the same server function decides on the server and in the browser, client-side
+/// protection always agrees with the server. Its result is cached for each route path until
+/// the page is reloaded, or until [`invalidate_route_guards`] is called (for example, after
+/// the user logs in or out).
+///
+/// If the check fails or returns `false`, this redirects to `redirect_path` if it is set.
+/// Otherwise, it shows the `unauthorized` view and, when rendered on the server, responds
+/// with a `403 Forbidden` status.
+///
+/// **Note**: Support for setting the status on the server is provided by the server
+/// framework integrations (`leptos_actix`, `leptos_axum`, and `leptos_viz`). If you’re
+/// not using one of those integrations, you should provide it with [`provide_server_status`].
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "info", skip_all,)
+)]
+#[component(transparent)]
+pub fn ServerProtectedRoute<P, E, F, C, Fu>(
+    cx: Scope,
+    /// The path fragment that this route should match. This can be static (`users`),
+    /// include a parameter (`:id`) or an optional parameter (`:id?`), or match a
+    /// wildcard (`user/*any`).
+    path: P,
+    /// The server function that checks whether the route may be shown.
+    check: C,
+    /// View that will be shown if the check returns `true`.
+    view: F,
+    /// The path that will be redirected to if the check does not return `true`.
+    #[prop(optional, into)]
+    redirect_path: Option<String>,
+    /// The view that will be shown if the check does not return `true` and there is no
+    /// `redirect_path`. Defaults to showing nothing.
+    #[prop(optional)]
+    unauthorized: Option<fn(Scope) -> View>,
+    /// The mode that this route prefers during server-side rendering. Defaults to out-of-order streaming.
+    #[prop(optional)]
+    ssr: SsrMode,
+    /// The HTTP methods that this route can handle (defaults to only `GET`).
+    #[prop(default = &[Method::Get])]
+    methods: &'static [Method],
+    /// The label for this route in the list returned by
+    /// [`use_breadcrumbs`](crate::use_breadcrumbs). This can be a string, or a function
+    /// that builds the label from the route’s params.
+    #[prop(optional, into)]
+    breadcrumb: Option<BreadcrumbLabel>,
+    /// The `Cache-Control` header that the server integrations send with pages
+    /// rendered for this route, like `"s-maxage=300, stale-while-revalidate=60"`.
+    /// A nested route’s policy takes precedence over its parent’s.
+    #[prop(optional)]
+    cache_control: Option<&'static str>,
+    /// `children` may be empty or include nested routes.
+    #[prop(optional)]
+    children: Option<Children>,
+) -> impl IntoView
+where
+    E: IntoView,
+    F: Fn(Scope) -> E + 'static,
+    P: std::fmt::Display,
+    C: Fn(Scope) -> Fu + 'static,
+    Fu: Future<Output = Result<bool, ServerFnError>> + 'static,
+{
+    let check = Rc::new(check);
+    let view = Rc::new(move |cx, allowed: bool| {
+        if allowed {
+            view(cx).into_view(cx)
+        } else if let Some(redirect_path) = &redirect_path {
+            view! { cx, <Redirect path=redirect_path.clone()/> }.into_view(cx)
+        } else {
+            if let Some(status_fn) = use_context::<ServerStatusFunction>(cx) {
+                (status_fn.f)(403);
+            }
+            unauthorized.map(|view| view(cx)).unwrap_or_default()
+        }
+    });
+
+    define_route(
+        cx,
+        children,
+        path.to_string(),
+        Rc::new(move |cx| {
+            let results = use_router(cx).inner.guard_results;
+            let key = use_route(cx).path();
+            if let Some(allowed) =
+                results.with_value(|results| results.get(&key).copied())
+            {
+                return view(cx, allowed);
+            }
+
+            let check = Rc::clone(&check);
+            let allowed = create_blocking_resource(
+                cx,
+                || (),
+                move |_| {
+                    let allowed = check(cx);
+                    async move { matches!(allowed.await, Ok(true)) }
+                },
+            );
+            let view = Rc::clone(&view);
+            view! { cx,
+                <Suspense fallback=|| ()>
+                    {
+                        let view = Rc::clone(&view);
+                        let key = key.clone();
+                        move || allowed.read(cx).map(|allowed| {
+                            results.update_value(|results| {
+                                results.insert(key.clone(), allowed);
+                            });
+                            view(cx, allowed)
+                        })
+                    }
+                </Suspense>
+            }
+            .into_view(cx)
+        }),
+        ssr,
+        methods,
+        breadcrumb,
+        cache_control,
+    )
+}
+
+/// Forgets the results of every [`<ServerProtectedRoute/>`](ServerProtectedRoute) check, so
+/// that each route is checked with the server again the next time it is shown. This should
+/// be called whenever the user’s permissions may have changed, like after logging in or out.
+pub fn invalidate_route_guards(cx: Scope) {
+    use_router(cx)
+        .inner
+        .guard_results
+        .update_value(|results| results.clear());
+}
+
+/// Wrapping type for a function provided as context to allow for
+/// setting the response status on the server. See [provide_server_status]
+/// and [ServerProtectedRoute].
+#[derive(Clone)]
+pub struct ServerStatusFunction {
+    f: Rc<dyn Fn(u16)>,
+}
+
+impl std::fmt::Debug for ServerStatusFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServerStatusFunction").finish()
+    }
+}
+
+/// Provides a function that can be used to set the HTTP status code of the
+/// response, on the server.
+#[cfg_attr(
+    any(debug_assertions, feature = "ssr"),
+    tracing::instrument(level = "trace", skip_all,)
+)]
+pub fn provide_server_status(cx: Scope, handler: impl Fn(u16) + 'static) {
+    provide_context(
+        cx,
+        ServerStatusFunction {
+            f: Rc::new(handler),
+        },
+    )
+}
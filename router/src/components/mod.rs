@@ -1,4 +1,5 @@
 mod form;
+mod guard;
 mod link;
 mod outlet;
 mod progress;
@@ -8,6 +9,7 @@ mod router;
 mod routes;
 
 pub use form::*;
+pub use guard::*;
 pub use link::*;
 pub use outlet::*;
 pub use progress::*;
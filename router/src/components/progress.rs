@@ -63,7 +63,7 @@ pub fn RoutingProgress(
 
     view! { cx,
         <Show when=move || is_showing.get() fallback=|_| ()>
-            <progress class=class.clone() min="0" max="100" value=move || progress.get()/>
+            <progress class=class.clone() max="100" value=move || progress.get()/>
         </Show>
     }
 }
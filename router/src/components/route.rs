@@ -1,6 +1,6 @@
 use crate::{
     matching::{resolve_path, PathMatch, RouteDefinition, RouteMatch},
-    ParamsMap, RouterContext, SsrMode,
+    BreadcrumbLabel, ParamsMap, RouterContext, SsrMode,
 };
 use leptos::{leptos_dom::Transparent, *};
 use std::{cell::Cell, rc::Rc};
@@ -54,6 +54,16 @@ pub fn Route<E, F, P>(
     /// The HTTP methods that this route can handle (defaults to only `GET`).
     #[prop(default = &[Method::Get])]
     methods: &'static [Method],
+    /// The label for this route in the list returned by
+    /// [`use_breadcrumbs`](crate::use_breadcrumbs). This can be a string, or a function
+    /// that builds the label from the route’s params.
+    #[prop(optional, into)]
+    breadcrumb: Option<BreadcrumbLabel>,
+    /// The `Cache-Control` header that the server integrations send with pages
+    /// rendered for this route, like `"s-maxage=300, stale-while-revalidate=60"`.
+    /// A nested route’s policy takes precedence over its parent’s.
+    #[prop(optional)]
+    cache_control: Option<&'static str>,
     /// `children` may be empty or include nested routes.
     #[prop(optional)]
     children: Option<Children>,
@@ -70,6 +80,8 @@ where
         Rc::new(move |cx| view(cx).into_view(cx)),
         ssr,
         methods,
+        breadcrumb,
+        cache_control,
     )
 }
 
@@ -99,6 +111,16 @@ pub fn ProtectedRoute<P, E, F, C>(
     /// The HTTP methods that this route can handle (defaults to only `GET`).
     #[prop(default = &[Method::Get])]
     methods: &'static [Method],
+    /// The label for this route in the list returned by
+    /// [`use_breadcrumbs`](crate::use_breadcrumbs). This can be a string, or a function
+    /// that builds the label from the route’s params.
+    #[prop(optional, into)]
+    breadcrumb: Option<BreadcrumbLabel>,
+    /// The `Cache-Control` header that the server integrations send with pages
+    /// rendered for this route, like `"s-maxage=300, stale-while-revalidate=60"`.
+    /// A nested route’s policy takes precedence over its parent’s.
+    #[prop(optional)]
+    cache_control: Option<&'static str>,
     /// `children` may be empty or include nested routes.
     #[prop(optional)]
     children: Option<Children>,
@@ -126,12 +148,15 @@ where
         }),
         ssr,
         methods,
+        breadcrumb,
+        cache_control,
     )
 }
 #[cfg_attr(
     any(debug_assertions, feature = "ssr"),
     tracing::instrument(level = "info", skip_all,)
 )]
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn define_route(
     cx: Scope,
     children: Option<Children>,
@@ -139,6 +164,8 @@ pub(crate) fn define_route(
     view: Rc<dyn Fn(Scope) -> View>,
     ssr_mode: SsrMode,
     methods: &'static [Method],
+    breadcrumb: Option<BreadcrumbLabel>,
+    cache_control: Option<&'static str>,
 ) -> RouteDefinition {
     let children = children
         .map(|children| {
@@ -168,6 +195,8 @@ pub(crate) fn define_route(
         view,
         ssr_mode,
         methods,
+        breadcrumb,
+        cache_control,
     }
 }
 
@@ -1,6 +1,7 @@
 use crate::{
-    create_location, matching::resolve_path, Branch, History, Location,
-    LocationChange, RouteContext, RouterIntegrationContext, State,
+    create_location, events::NavigationTracker, matching::resolve_path, Branch,
+    History, Location, LocationChange, RouteContext, RouterIntegrationContext,
+    State,
 };
 #[cfg(not(feature = "ssr"))]
 use crate::{unescape, Url};
@@ -8,7 +9,7 @@ use cfg_if::cfg_if;
 use leptos::*;
 #[cfg(feature = "transition")]
 use leptos_reactive::use_transition;
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 use thiserror::Error;
 #[cfg(not(feature = "ssr"))]
 use wasm_bindgen::JsCast;
@@ -34,13 +35,24 @@ pub fn Router(
 ) -> impl IntoView {
     // create a new RouterContext and provide it to every component beneath the router
     let router = RouterContext::new(cx, base, fallback);
-    provide_context(cx, router);
+    provide_context(cx, router.clone());
     provide_context(cx, GlobalSuspenseContext::new(cx));
     if let Some(set_is_routing) = set_is_routing {
         provide_context(cx, SetIsRouting(set_is_routing));
     }
 
-    children(cx)
+    let children = children(cx);
+
+    // the initial page load
+    cfg_if! {
+        if #[cfg(feature = "ssr")] {
+            router.inner.end_navigation();
+        } else {
+            router.inner.end_navigation_when_loaded();
+        }
+    }
+
+    children
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -58,7 +70,7 @@ pub(crate) struct RouterContextInner {
     #[allow(unused)] // used in CSR/hydrate
     base_path: String,
     history: Box<dyn History>,
-    cx: Scope,
+    pub(crate) cx: Scope,
     reference: ReadSignal<String>,
     set_reference: WriteSignal<String>,
     referrers: Rc<RefCell<Vec<LocationChange>>>,
@@ -66,6 +78,10 @@ pub(crate) struct RouterContextInner {
     set_state: WriteSignal<State>,
     pub(crate) is_back: RwSignal<bool>,
     pub(crate) path_stack: StoredValue<Vec<String>>,
+    pub(crate) routes_base: RwSignal<Option<String>>,
+    // results of `<ServerProtectedRoute/>` checks, by route path
+    pub(crate) guard_results: StoredValue<HashMap<String, bool>>,
+    pub(crate) navigations: NavigationTracker,
 }
 
 impl std::fmt::Debug for RouterContextInner {
@@ -152,22 +168,6 @@ impl RouterContext {
         let base_path = base_path.unwrap_or_default();
         let base = RouteContext::base(cx, &base_path, fallback);
 
-        // Every time the History gives us a new location,
-        // 1) start a transition
-        // 2) update the reference (URL)
-        // 3) update the state
-        // this will trigger the new route match below
-
-        create_render_effect(cx, move |_| {
-            let LocationChange { value, state, .. } = source.get();
-            cx.untrack(move || {
-                if value != reference.get() {
-                    set_reference.update(move |r| *r = value);
-                    set_state.update(move |s| *s = state);
-                }
-            });
-        });
-
         let inner = Rc::new(RouterContextInner {
             base_path: base_path.into_owned(),
             path_stack: store_value(
@@ -185,6 +185,41 @@ impl RouterContext {
             set_state,
             possible_routes: Default::default(),
             is_back: create_rw_signal(cx, false),
+            routes_base: create_rw_signal(cx, None),
+            guard_results: store_value(cx, HashMap::new()),
+            navigations: NavigationTracker::new(cx),
+        });
+
+        // the initial page load is ended by the `<Router/>`, once its children
+        // have rendered
+        inner.navigations.start(
+            None,
+            reference.get_untracked(),
+            cfg!(any(feature = "ssr", feature = "hydrate")),
+        );
+
+        // Every time the History gives us a new location,
+        // 1) start a transition
+        // 2) update the reference (URL)
+        // 3) update the state
+        // this will trigger the new route match below
+        create_render_effect(cx, {
+            let inner = Rc::clone(&inner);
+            move |_| {
+                let LocationChange { value, state, .. } = source.get();
+                cx.untrack(|| {
+                    if value != reference.get() {
+                        inner.navigations.start(
+                            Some(reference.get()),
+                            value.clone(),
+                            false,
+                        );
+                        set_reference.update(move |r| *r = value);
+                        set_state.update(move |s| *s = state);
+                        Rc::clone(&inner).end_navigation_when_loaded();
+                    }
+                });
+            }
         });
 
         // handle all click events on anchor tags
@@ -266,6 +301,12 @@ impl RouterContextInner {
                         let referrers = self.referrers.clone();
                         let this = Rc::clone(&self);
 
+                        self.navigations.start(
+                            Some(self.reference.get()),
+                            resolved_to.clone(),
+                            false,
+                        );
+
                         let resolved = resolved_to.to_string();
                         let state = options.state.clone();
                         set_reference.update(move |r| *r = resolved);
@@ -303,6 +344,7 @@ impl RouterContextInner {
                                 });
                             }
                         });
+                        Rc::clone(&self).end_navigation_when_loaded();
                     }
 
                     Ok(())
@@ -39,6 +39,7 @@ pub fn Routes(
     let base = base.unwrap_or_default();
 
     Branches::initialize(&base, children(cx));
+    router.inner.routes_base.set(Some(base.clone()));
 
     #[cfg(feature = "ssr")]
     if let Some(context) = use_context::<crate::PossibleBranchContext>(cx) {
@@ -115,6 +116,7 @@ pub fn AnimatedRoutes(
     let base = base.unwrap_or_default();
 
     Branches::initialize(&base, children(cx));
+    router.inner.routes_base.set(Some(base.clone()));
 
     #[cfg(feature = "ssr")]
     if let Some(context) = use_context::<crate::PossibleBranchContext>(cx) {
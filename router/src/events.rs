@@ -0,0 +1,226 @@
+use crate::{
+    matching::get_route_matches, use_router, ParamsMap, RouterContextInner, Url,
+};
+use leptos::*;
+use std::{rc::Rc, time::Duration};
+
+/// Something that happened while the router was navigating. The most recent
+/// event is available from [`use_router_events`], and every event is passed
+/// to the callbacks registered with [`on_router_event`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouterEvent {
+    /// A navigation has started.
+    NavigationStart(NavigationStart),
+    /// A navigation has finished.
+    NavigationEnd(NavigationEnd),
+}
+
+/// Describes a navigation that has just started.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NavigationStart {
+    /// The URL being navigated away from, or `None` for the initial page load.
+    pub from: Option<String>,
+    /// The URL being navigated to, including its query string and hash.
+    pub to: String,
+    /// Whether this is the initial load of a page that was rendered on the
+    /// server. This is `true` while rendering it on the server and while
+    /// hydrating it in the browser, and `false` for every navigation handled
+    /// in the browser after that.
+    pub server_rendered: bool,
+}
+
+/// Describes a navigation that has just finished.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NavigationEnd {
+    /// The URL that was navigated away from, or `None` for the initial page load.
+    pub from: Option<String>,
+    /// The URL that was navigated to, including its query string and hash.
+    pub to: String,
+    /// The params matched by the innermost route for the new URL.
+    pub params: ParamsMap,
+    /// How long the navigation took.
+    ///
+    /// In the browser, a navigation finishes once every resource read under
+    /// `<Suspense/>` or `<Transition/>` on the new page has loaded. On the server,
+    /// it finishes once the routes have rendered, before any of their resources
+    /// have loaded, as the response may already be streaming.
+    pub duration: Duration,
+    /// Whether this was the initial load of a page that was rendered on the
+    /// server. See [`NavigationStart::server_rendered`].
+    pub server_rendered: bool,
+}
+
+/// Returns a signal that holds the most recent [`RouterEvent`], which can be
+/// used to log navigations or report them to an analytics service.
+///
+/// This must be used beneath a [`<Router/>`](crate::Router).
+pub fn use_router_events(cx: Scope) -> ReadSignal<Option<RouterEvent>> {
+    use_router(cx).inner.navigations.event.read_only()
+}
+
+/// Calls `f` with every [`RouterEvent`] from now on, until `cx` is disposed.
+/// If the router has already started or finished a navigation, `f` is
+/// called with the most recent event right away, so that it sees the
+/// initial page load.
+///
+/// This must be used beneath a [`<Router/>`](crate::Router).
+///
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_router::*;
+/// #[component]
+/// pub fn Analytics(cx: Scope) -> impl IntoView {
+///     on_router_event(cx, |event| {
+///         if let RouterEvent::NavigationEnd(end) = event {
+///             log!("viewed {} in {:?}", end.to, end.duration);
+///         }
+///     });
+/// }
+/// ```
+pub fn on_router_event(cx: Scope, f: impl Fn(&RouterEvent) + 'static) {
+    let event = use_router_events(cx);
+    create_isomorphic_effect(cx, move |_| {
+        event.with(|event| {
+            if let Some(event) = event {
+                f(event)
+            }
+        })
+    });
+}
+
+// Keeps track of the navigation that is currently underway, if any, so
+// that it can be ended with the right start time, or skipped if a later
+// navigation has superseded it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct NavigationTracker {
+    event: RwSignal<Option<RouterEvent>>,
+    pending: StoredValue<Option<PendingNavigation>>,
+    next_id: StoredValue<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct PendingNavigation {
+    id: usize,
+    start: NavigationStart,
+    started_at: f64,
+}
+
+impl NavigationTracker {
+    pub fn new(cx: Scope) -> Self {
+        Self {
+            event: create_rw_signal(cx, None),
+            pending: store_value(cx, None),
+            next_id: store_value(cx, 0),
+        }
+    }
+
+    pub fn start(
+        &self,
+        from: Option<String>,
+        to: String,
+        server_rendered: bool,
+    ) {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        let start = NavigationStart {
+            from: from.map(without_origin),
+            to: without_origin(to),
+            server_rendered,
+        };
+        self.pending.set_value(Some(PendingNavigation {
+            id,
+            start: start.clone(),
+            started_at: now_ms(),
+        }));
+        self.event.set(Some(RouterEvent::NavigationStart(start)));
+    }
+
+    fn pending_id(&self) -> Option<usize> {
+        self.pending
+            .with_value(|pending| pending.as_ref().map(|pending| pending.id))
+    }
+
+    fn end(&self, params: ParamsMap) {
+        let Some(pending) =
+            self.pending.try_update_value(Option::take).flatten()
+        else {
+            return;
+        };
+        let elapsed = (now_ms() - pending.started_at).max(0.0);
+        let NavigationStart {
+            from,
+            to,
+            server_rendered,
+        } = pending.start;
+        self.event
+            .set(Some(RouterEvent::NavigationEnd(NavigationEnd {
+                from,
+                to,
+                params,
+                duration: Duration::from_secs_f64(elapsed / 1000.0),
+                server_rendered,
+            })));
+    }
+}
+
+impl RouterContextInner {
+    /// Ends the navigation that is underway, if any.
+    pub(crate) fn end_navigation(&self) {
+        self.navigations.end(self.current_params());
+    }
+
+    /// Ends the navigation that is underway once the resources read on the
+    /// new page have loaded, unless another navigation has started by then.
+    pub(crate) fn end_navigation_when_loaded(self: Rc<Self>) {
+        let Some(id) = self.navigations.pending_id() else {
+            return;
+        };
+        let loaded = expect_context::<GlobalSuspenseContext>(self.cx)
+            .with_inner(|s| s.to_future(self.cx));
+        spawn_local(async move {
+            loaded.await;
+            if self.navigations.pending_id() == Some(id) {
+                self.end_navigation();
+            }
+        });
+    }
+
+    // the params matched by the innermost route, if `<Routes/>` has rendered
+    fn current_params(&self) -> ParamsMap {
+        let Some(base) = self.routes_base.get_untracked() else {
+            return ParamsMap::new();
+        };
+        get_route_matches(&base, self.location.pathname.get_untracked())
+            .last()
+            .map(|route_match| route_match.path_match.params.clone())
+            .unwrap_or_default()
+    }
+}
+
+// the router's location may or may not include the origin, depending on
+// the integration that provides it
+fn without_origin(url: String) -> String {
+    match Url::try_from(url.as_str()) {
+        Ok(Url {
+            pathname,
+            search,
+            hash,
+            ..
+        }) if !search.is_empty() => format!("{pathname}?{search}{hash}"),
+        Ok(Url { pathname, hash, .. }) => pathname + &hash,
+        Err(_) => url,
+    }
+}
+
+fn now_ms() -> f64 {
+    cfg_if::cfg_if! {
+        if #[cfg(all(target_arch = "wasm32", not(feature = "ssr")))] {
+            js_sys::Date::now()
+        } else {
+            thread_local! {
+                static START: std::time::Instant = std::time::Instant::now();
+            }
+            START.with(|start| start.elapsed().as_secs_f64() * 1000.0)
+        }
+    }
+}
@@ -14,6 +14,7 @@ pub struct RouteListing {
     path: String,
     mode: SsrMode,
     methods: HashSet<Method>,
+    cache_control: Option<&'static str>,
 }
 
 impl RouteListing {
@@ -27,9 +28,19 @@ impl RouteListing {
             path: path.to_string(),
             mode,
             methods: methods.into_iter().collect(),
+            cache_control: None,
         }
     }
 
+    /// Sets the `Cache-Control` header that should be sent with responses for this route.
+    pub fn with_cache_control(
+        mut self,
+        cache_control: Option<&'static str>,
+    ) -> Self {
+        self.cache_control = cache_control;
+        self
+    }
+
     /// The path this route handles.
     pub fn path(&self) -> &str {
         &self.path
@@ -44,6 +55,11 @@ impl RouteListing {
     pub fn methods(&self) -> impl Iterator<Item = Method> + '_ {
         self.methods.iter().copied()
     }
+
+    /// The `Cache-Control` header declared by this route, if any.
+    pub fn cache_control(&self) -> Option<&'static str> {
+        self.cache_control
+    }
 }
 
 /// Generates a list of all routes this application could possibly serve. This returns the raw routes in the leptos_router
@@ -85,12 +101,19 @@ where
                     .flat_map(|route| route.key.methods)
                     .copied()
                     .collect::<HashSet<_>>();
+                // the most deeply nested route that declares a policy wins
+                let cache_control = branch
+                    .routes
+                    .iter()
+                    .rev()
+                    .find_map(|route| route.key.cache_control);
                 let pattern =
                     branch.routes.last().map(|route| route.pattern.clone());
                 pattern.map(|path| RouteListing {
                     path,
                     mode,
                     methods: methods.clone(),
+                    cache_control,
                 })
             })
             .collect()
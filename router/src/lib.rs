@@ -188,7 +188,9 @@
 #![cfg_attr(not(feature = "stable"), feature(type_name_of_val))]
 
 mod animation;
+mod breadcrumbs;
 mod components;
+mod events;
 #[cfg(any(feature = "ssr", doc))]
 mod extract_routes;
 mod history;
@@ -196,7 +198,9 @@ mod hooks;
 #[doc(hidden)]
 pub mod matching;
 mod render_mode;
+pub use breadcrumbs::*;
 pub use components::*;
+pub use events::*;
 #[cfg(any(feature = "ssr", doc))]
 pub use extract_routes::*;
 pub use history::*;
@@ -1,4 +1,4 @@
-use crate::{Method, SsrMode};
+use crate::{BreadcrumbLabel, Method, SsrMode};
 use leptos::{leptos_dom::View, *};
 use std::rc::Rc;
 
@@ -19,6 +19,10 @@ pub struct RouteDefinition {
     pub ssr_mode: SsrMode,
     /// The HTTP request methods this route is able to handle.
     pub methods: &'static [Method],
+    /// The label used for this route by [`use_breadcrumbs`](crate::use_breadcrumbs).
+    pub breadcrumb: Option<BreadcrumbLabel>,
+    /// The `Cache-Control` header sent with server-rendered responses for this route.
+    pub cache_control: Option<&'static str>,
 }
 
 impl std::fmt::Debug for RouteDefinition {
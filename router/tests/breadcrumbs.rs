@@ -0,0 +1,91 @@
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use leptos::*;
+        use leptos_router::*;
+        use std::{cell::RefCell, rc::Rc};
+
+        fn breadcrumbs_at(path: &str) -> Vec<Breadcrumb> {
+            let runtime = create_runtime();
+            let crumbs = Rc::new(RefCell::new(Vec::new()));
+            _ = run_scope(runtime, {
+                let crumbs = Rc::clone(&crumbs);
+                let path = format!("http://leptos.rs{path}");
+                move |cx| {
+                    provide_context(
+                        cx,
+                        RouterIntegrationContext::new(ServerIntegration { path }),
+                    );
+                    let memo = store_value(cx, None);
+                    _ = view! { cx,
+                        <Router>
+                            {memo.set_value(Some(use_breadcrumbs(cx)));}
+                            <Routes>
+                                <Route path="" view=|cx| view! { cx, <Outlet/> } breadcrumb="Home">
+                                    <Route
+                                        path="users/:id"
+                                        view=|cx| view! { cx, <Outlet/> }
+                                        breadcrumb=|params: &ParamsMap| {
+                                            format!("User {}", params.get("id").unwrap())
+                                        }
+                                    >
+                                        <Route path="posts" view=|_| () breadcrumb="Posts"/>
+                                        <Route path="" view=|_| () breadcrumb="Profile"/>
+                                    </Route>
+                                </Route>
+                            </Routes>
+                        </Router>
+                    }
+                    .into_view(cx)
+                    .render_to_string(cx);
+                    *crumbs.borrow_mut() = memo.get_value().unwrap().get();
+                }
+            });
+            runtime.dispose();
+            Rc::try_unwrap(crumbs).unwrap().into_inner()
+        }
+
+        fn trail(crumbs: &[Breadcrumb]) -> Vec<(&str, &str, Option<&str>)> {
+            crumbs
+                .iter()
+                .map(|crumb| {
+                    (
+                        crumb.path.as_str(),
+                        crumb.pattern.as_str(),
+                        crumb.label.as_deref(),
+                    )
+                })
+                .collect()
+        }
+
+        #[test]
+        fn breadcrumbs_follow_nested_routes() {
+            let crumbs = breadcrumbs_at("/users/42/posts");
+            assert_eq!(
+                trail(&crumbs),
+                vec![
+                    ("", "", Some("Home")),
+                    ("/users/42", "/users/:id", Some("User 42")),
+                    ("/users/42/posts", "/users/:id/posts", Some("Posts")),
+                ]
+            );
+            assert_eq!(
+                crumbs[2].params.get("id").map(String::as_str),
+                Some("42")
+            );
+        }
+
+        #[test]
+        fn breadcrumbs_merge_index_routes_into_their_parent() {
+            let crumbs = breadcrumbs_at("/users/7");
+            assert_eq!(
+                trail(&crumbs),
+                vec![
+                    ("", "", Some("Home")),
+                    ("/users/7", "/users/:id", Some("Profile")),
+                ]
+            );
+        }
+    }
+}
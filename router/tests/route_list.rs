@@ -0,0 +1,43 @@
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use leptos::*;
+        use leptos_router::*;
+
+        #[test]
+        fn route_list_includes_cache_control() {
+            let routes = generate_route_list_inner(|cx| {
+                view! { cx,
+                    <Router>
+                        <Routes>
+                            <Route path="" view=|_| ()/>
+                            <Route
+                                path="posts"
+                                view=|_| ()
+                                cache_control="s-maxage=300"
+                            >
+                                <Route path=":id" view=|_| ()/>
+                                <Route
+                                    path="drafts"
+                                    view=|_| ()
+                                    cache_control="no-store"
+                                />
+                            </Route>
+                        </Routes>
+                    </Router>
+                }
+            });
+            let cache_control = |path: &str| {
+                routes
+                    .iter()
+                    .find(|listing| listing.path() == path)
+                    .and_then(RouteListing::cache_control)
+            };
+
+            assert_eq!(cache_control(""), None);
+            assert_eq!(cache_control("/posts/:id"), Some("s-maxage=300"));
+            assert_eq!(cache_control("/posts/drafts"), Some("no-store"));
+        }
+    }
+}
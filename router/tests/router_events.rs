@@ -0,0 +1,60 @@
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use leptos::*;
+        use leptos_router::*;
+        use std::{cell::RefCell, rc::Rc};
+
+        #[test]
+        fn initial_page_load_emits_start_and_end() {
+            let runtime = create_runtime();
+            let events = Rc::new(RefCell::new(Vec::new()));
+            _ = run_scope(runtime, {
+                let events = Rc::clone(&events);
+                move |cx| {
+                    provide_context(
+                        cx,
+                        RouterIntegrationContext::new(ServerIntegration {
+                            path: "http://leptos.rs/users/42?tab=posts".to_string(),
+                        }),
+                    );
+                    view! { cx,
+                        <Router>
+                            {
+                                let events = Rc::clone(&events);
+                                on_router_event(cx, move |event| {
+                                    events.borrow_mut().push(event.clone())
+                                });
+                            }
+                            <Routes>
+                                <Route path="users/:id" view=|_| ()/>
+                            </Routes>
+                        </Router>
+                    }
+                    .into_view(cx)
+                    .render_to_string(cx)
+                }
+            });
+            runtime.dispose();
+
+            let events = events.borrow();
+            assert_eq!(events.len(), 2);
+            assert_eq!(
+                events[0],
+                RouterEvent::NavigationStart(NavigationStart {
+                    from: None,
+                    to: "/users/42?tab=posts".to_string(),
+                    server_rendered: true,
+                })
+            );
+            let RouterEvent::NavigationEnd(end) = &events[1] else {
+                panic!("expected the navigation to end, got {:?}", events[1]);
+            };
+            assert_eq!(end.from, None);
+            assert_eq!(end.to, "/users/42?tab=posts");
+            assert_eq!(end.params.get("id").map(String::as_str), Some("42"));
+            assert!(end.server_rendered);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use leptos::*;
+        use leptos_router::*;
+
+        #[test]
+        fn server_protected_routes_are_listed() {
+            let routes = generate_route_list_inner(|cx| {
+                view! { cx,
+                    <Router>
+                        <Routes>
+                            <ServerProtectedRoute
+                                path="admin"
+                                check=|_| async { Ok(false) }
+                                view=|cx| view! { cx, <p>"admin panel"</p> }
+                                unauthorized=|cx| view! { cx, <p>"not allowed"</p> }.into_view(cx)
+                                cache_control="private"
+                            >
+                                <Route path="users" view=|_| ()/>
+                            </ServerProtectedRoute>
+                            <ServerProtectedRoute
+                                path="settings"
+                                check=|_| async { Ok(true) }
+                                view=|_| ()
+                                redirect_path="/login"
+                            />
+                        </Routes>
+                    </Router>
+                }
+            });
+            let cache_control = |path: &str| {
+                routes
+                    .iter()
+                    .find(|listing| listing.path() == path)
+                    .map(RouteListing::cache_control)
+            };
+
+            assert_eq!(cache_control("/admin/users"), Some(Some("private")));
+            assert_eq!(cache_control("/settings"), Some(None));
+        }
+    }
+}
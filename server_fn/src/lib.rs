@@ -102,6 +102,7 @@ pub use xxhash_rust;
 
 /// Default server function registry
 pub mod default;
+pub mod middleware;
 
 /// Something that can register a server function.
 pub trait ServerFunctionRegistry<T> {
@@ -487,6 +488,7 @@ where
     T: serde::Serialize + serde::de::DeserializeOwned + Sized,
 {
     use ciborium::ser::into_writer;
+    use middleware::{FetchMethod, FetchRequest};
     use serde_json::Deserializer as JSONDeserializer;
     #[cfg(not(target_arch = "wasm32"))]
     let url = format!("{}{}", get_server_url(), url);
@@ -523,120 +525,45 @@ where
         Encoding::Cbor | Encoding::GetCBOR => "application/cbor",
     };
 
-    #[cfg(target_arch = "wasm32")]
-    let resp = match &enc {
+    let (method, url, body) = match &enc {
         Encoding::Url | Encoding::Cbor => match args_encoded {
-            Payload::Binary(b) => {
-                let slice_ref: &[u8] = &b;
-                let js_array = js_sys::Uint8Array::from(slice_ref).buffer();
-                gloo_net::http::Request::post(url)
-                    .header("Content-Type", content_type_header)
-                    .header("Accept", accept_header)
-                    .body(js_array)
-                    .send()
-                    .await
-                    .map_err(|e| ServerFnError::Request(e.to_string()))?
-            }
-            Payload::Url(s) => gloo_net::http::Request::post(url)
-                .header("Content-Type", content_type_header)
-                .header("Accept", accept_header)
-                .body(s)
-                .send()
-                .await
-                .map_err(|e| ServerFnError::Request(e.to_string()))?,
-        },
-        Encoding::GetCBOR | Encoding::GetJSON => match args_encoded {
-            Payload::Binary(_) => panic!(
-                "Binary data cannot be transferred via GET request in a query \
-                 string. Please try using the CBOR encoding."
-            ),
+            Payload::Binary(b) => (FetchMethod::Post, url.to_string(), b),
             Payload::Url(s) => {
-                let full_url = format!("{url}?{s}");
-                gloo_net::http::Request::get(&full_url)
-                    .header("Content-Type", content_type_header)
-                    .header("Accept", accept_header)
-                    .send()
-                    .await
-                    .map_err(|e| ServerFnError::Request(e.to_string()))?
+                (FetchMethod::Post, url.to_string(), s.into_bytes())
             }
         },
-    };
-    #[cfg(not(target_arch = "wasm32"))]
-    let resp = match &enc {
-        Encoding::Url | Encoding::Cbor => match args_encoded {
-            Payload::Binary(b) => CLIENT
-                .post(url)
-                .header("Content-Type", content_type_header)
-                .header("Accept", accept_header)
-                .body(b)
-                .send()
-                .await
-                .map_err(|e| ServerFnError::Request(e.to_string()))?,
-            Payload::Url(s) => CLIENT
-                .post(url)
-                .header("Content-Type", content_type_header)
-                .header("Accept", accept_header)
-                .body(s)
-                .send()
-                .await
-                .map_err(|e| ServerFnError::Request(e.to_string()))?,
-        },
-        Encoding::GetJSON | Encoding::GetCBOR => match args_encoded {
+        Encoding::GetCBOR | Encoding::GetJSON => match args_encoded {
             Payload::Binary(_) => panic!(
                 "Binary data cannot be transferred via GET request in a query \
                  string. Please try using the CBOR encoding."
             ),
-
-            Payload::Url(s) => {
-                let full_url = format!("{url}?{s}");
-                CLIENT
-                    .get(full_url)
-                    .header("Content-Type", content_type_header)
-                    .header("Accept", accept_header)
-                    .send()
-                    .await
-                    .map_err(|e| ServerFnError::Request(e.to_string()))?
-            }
+            Payload::Url(s) => (FetchMethod::Get, format!("{url}?{s}"), vec![]),
         },
     };
+    let resp = middleware::fetch(FetchRequest {
+        method,
+        url,
+        headers: vec![
+            ("Content-Type".to_string(), content_type_header.to_string()),
+            ("Accept".to_string(), accept_header.to_string()),
+        ],
+        body,
+    })
+    .await?;
 
     // check for error status
-    let status = resp.status();
-    #[cfg(not(target_arch = "wasm32"))]
-    let status = status.as_u16();
-    if (500..=599).contains(&status) {
-        let text = resp.text().await.unwrap_or_default();
-        #[cfg(target_arch = "wasm32")]
-        let status_text = resp.status_text();
-        #[cfg(not(target_arch = "wasm32"))]
-        let status_text = status.to_string();
+    if (500..=599).contains(&resp.status) {
+        let text = String::from_utf8(resp.body).unwrap_or_default();
         return Err(serde_json::from_str(&text)
-            .unwrap_or(ServerFnError::ServerError(status_text)));
+            .unwrap_or(ServerFnError::ServerError(resp.status_text)));
     }
 
     // Decoding the body of the request
     if (enc == Encoding::Cbor) || (enc == Encoding::GetCBOR) {
-        #[cfg(target_arch = "wasm32")]
-        let binary = resp
-            .binary()
-            .await
-            .map_err(|e| ServerFnError::Deserialization(e.to_string()))?;
-        #[cfg(target_arch = "wasm32")]
-        let binary = binary.as_slice();
-        #[cfg(not(target_arch = "wasm32"))]
-        let binary = resp
-            .bytes()
-            .await
-            .map_err(|e| ServerFnError::Deserialization(e.to_string()))?;
-        #[cfg(not(target_arch = "wasm32"))]
-        let binary = binary.as_ref();
-
-        ciborium::de::from_reader(binary)
+        ciborium::de::from_reader(resp.body.as_slice())
             .map_err(|e| ServerFnError::Deserialization(e.to_string()))
     } else {
-        let text = resp
-            .text()
-            .await
+        let text = String::from_utf8(resp.body)
             .map_err(|e| ServerFnError::Deserialization(e.to_string()))?;
 
         let mut deserializer = JSONDeserializer::from_str(&text);
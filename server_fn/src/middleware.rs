@@ -0,0 +1,440 @@
+//! Middleware for the requests the client makes to call server functions.
+//!
+//! Every server function call made from the client goes through the chain of
+//! [`FetchMiddleware`] added with [`add_fetch_middleware`], in the order they
+//! were added, before it reaches the network. Each middleware can change the
+//! request, look at or replace the response, call the rest of the chain more
+//! than once (to retry a request), or not call it at all.
+//!
+//! Resources do not make requests on their own: their fetchers can use any HTTP
+//! client. To have the requests a resource makes go through the same chain,
+//! send them with [`fetch`]. On the server, server functions are called
+//! directly, so the chain is only used on the client.
+//!
+//! ```rust
+//! use server_fn::middleware::*;
+//! use std::{cell::Cell, rc::Rc};
+//!
+//! // attach an auth header to every request
+//! add_fetch_middleware(|request: FetchRequest, next: Next| {
+//!     next.run(request.header("Authorization", "Bearer my-token"))
+//! });
+//!
+//! // retry requests that fail to reach the server
+//! add_fetch_middleware(|request: FetchRequest, next: Next| async move {
+//!     let mut attempts = 0;
+//!     loop {
+//!         attempts += 1;
+//!         match next.run(request.clone()).await {
+//!             Err(_) if attempts < 3 => continue,
+//!             result => return result,
+//!         }
+//!     }
+//! });
+//!
+//! // get a new token and try again if the old one has expired
+//! add_fetch_middleware(|request: FetchRequest, next: Next| async move {
+//!     let response = next.run(request.clone()).await?;
+//!     if response.status != 401 {
+//!         return Ok(response);
+//!     }
+//!     let token = refresh_token().await;
+//!     next.run(request.header("Authorization", format!("Bearer {token}")))
+//!         .await
+//! });
+//! # async fn refresh_token() -> String { String::new() }
+//!
+//! // count the requests that are in flight, to show a global loading indicator
+//! let in_flight = Rc::new(Cell::new(0));
+//! add_fetch_middleware({
+//!     let in_flight = Rc::clone(&in_flight);
+//!     move |request: FetchRequest, next: Next| {
+//!         let in_flight = Rc::clone(&in_flight);
+//!         async move {
+//!             in_flight.set(in_flight.get() + 1);
+//!             let result = next.run(request).await;
+//!             in_flight.set(in_flight.get() - 1);
+//!             result
+//!         }
+//!     }
+//! });
+//! # clear_fetch_middleware();
+//! ```
+
+use crate::ServerFnError;
+use std::{cell::RefCell, future::Future, pin::Pin, rc::Rc};
+
+/// The result of sending a [`FetchRequest`].
+pub type FetchResult = Result<FetchResponse, ServerFnError>;
+
+/// A boxed [`Future`] that resolves to a [`FetchResult`].
+pub type FetchFuture = Pin<Box<dyn Future<Output = FetchResult>>>;
+
+/// The HTTP method of a [`FetchRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FetchMethod {
+    /// A `GET` request, used by server functions with a `GetJSON` or `GetCBOR` encoding.
+    Get,
+    /// A `POST` request.
+    Post,
+}
+
+/// A request the client is about to make to call a server function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchRequest {
+    /// The HTTP method.
+    pub method: FetchMethod,
+    /// The URL, including the query string for `GET` requests.
+    pub url: String,
+    /// The request headers, as pairs of names and values.
+    pub headers: Vec<(String, String)>,
+    /// The body of the request. This is empty for `GET` requests.
+    pub body: Vec<u8>,
+}
+
+impl FetchRequest {
+    /// Sets a header, replacing any existing header with the same name.
+    pub fn header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        let name = name.into();
+        self.headers
+            .retain(|(existing, _)| !existing.eq_ignore_ascii_case(&name));
+        self.headers.push((name, value.into()));
+        self
+    }
+}
+
+/// The response to a [`FetchRequest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The HTTP status text.
+    pub status_text: String,
+    /// The response headers, as pairs of names and values.
+    pub headers: Vec<(String, String)>,
+    /// The body of the response.
+    pub body: Vec<u8>,
+}
+
+impl FetchResponse {
+    /// Returns the value of the first header with the given name, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(existing, _)| existing.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Handles a [`FetchRequest`] on its way to the server, usually by passing it
+/// on to the rest of the chain with [`Next::run`].
+///
+/// This is implemented for any function that takes a [`FetchRequest`] and a
+/// [`Next`] and returns a [`Future`] that resolves to a [`FetchResult`].
+pub trait FetchMiddleware: 'static {
+    /// Handles the request.
+    fn handle(&self, request: FetchRequest, next: Next) -> FetchFuture;
+}
+
+impl<F, Fu> FetchMiddleware for F
+where
+    F: Fn(FetchRequest, Next) -> Fu + 'static,
+    Fu: Future<Output = FetchResult> + 'static,
+{
+    fn handle(&self, request: FetchRequest, next: Next) -> FetchFuture {
+        Box::pin(self(request, next))
+    }
+}
+
+/// The rest of the middleware chain, which ends by sending the request to the
+/// server.
+#[derive(Clone)]
+pub struct Next {
+    chain: Rc<[Rc<dyn FetchMiddleware>]>,
+    index: usize,
+}
+
+impl Next {
+    /// Passes the request on to the next middleware, or sends it to the server
+    /// if this is the end of the chain. This can be called more than once.
+    pub fn run(&self, request: FetchRequest) -> FetchFuture {
+        match self.chain.get(self.index) {
+            Some(middleware) => middleware.handle(
+                request,
+                Next {
+                    chain: Rc::clone(&self.chain),
+                    index: self.index + 1,
+                },
+            ),
+            None => Box::pin(send(request)),
+        }
+    }
+}
+
+impl std::fmt::Debug for Next {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Next")
+            .field("remaining", &(self.chain.len() - self.index))
+            .finish()
+    }
+}
+
+thread_local! {
+    static MIDDLEWARE: RefCell<Vec<Rc<dyn FetchMiddleware>>> = Default::default();
+}
+
+/// Adds a [`FetchMiddleware`] to the end of the chain that every server
+/// function call made from the client goes through. Middleware runs in the
+/// order it was added, so the first one added sees the request first and the
+/// response last.
+///
+/// This should be done once, when the app starts.
+pub fn add_fetch_middleware(middleware: impl FetchMiddleware) {
+    MIDDLEWARE.with(|chain| chain.borrow_mut().push(Rc::new(middleware)));
+}
+
+/// Removes every [`FetchMiddleware`] added with [`add_fetch_middleware`].
+pub fn clear_fetch_middleware() {
+    MIDDLEWARE.with(|chain| chain.borrow_mut().clear());
+}
+
+/// Sends a request through the whole middleware chain, and then to the network.
+///
+/// Server function calls are sent this way. Other requests made from the client,
+/// such as those made by a resource's fetcher, can use this to go through the
+/// same middleware.
+/// ```rust,no_run
+/// use server_fn::middleware::*;
+///
+/// # async fn load_user() -> Result<Vec<u8>, server_fn::ServerFnError> {
+/// let response = fetch(FetchRequest {
+///     method: FetchMethod::Get,
+///     url: "https://example.com/api/user".to_string(),
+///     headers: vec![],
+///     body: vec![],
+/// })
+/// .await?;
+/// # Ok(response.body) }
+/// ```
+#[cfg(not(feature = "ssr"))]
+pub fn fetch(request: FetchRequest) -> FetchFuture {
+    let chain =
+        MIDDLEWARE.with(|chain| chain.borrow().iter().cloned().collect());
+    Next { chain, index: 0 }.run(request)
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn send(request: FetchRequest) -> FetchResult {
+    use gloo_net::http::{Method, Request};
+
+    let FetchRequest {
+        method,
+        url,
+        headers,
+        body,
+    } = request;
+    let mut builder = Request::new(&url).method(match method {
+        FetchMethod::Get => Method::GET,
+        FetchMethod::Post => Method::POST,
+    });
+    for (name, value) in &headers {
+        builder = builder.header(name, value);
+    }
+    if method != FetchMethod::Get {
+        builder = builder.body(js_sys::Uint8Array::from(body.as_slice()));
+    }
+
+    let response = builder
+        .send()
+        .await
+        .map_err(|e| ServerFnError::Request(e.to_string()))?;
+    Ok(FetchResponse {
+        status: response.status(),
+        status_text: response.status_text(),
+        headers: response.headers().entries().collect(),
+        body: response
+            .binary()
+            .await
+            .map_err(|e| ServerFnError::Deserialization(e.to_string()))?,
+    })
+}
+
+#[cfg(all(not(feature = "ssr"), not(target_arch = "wasm32")))]
+async fn send(request: FetchRequest) -> FetchResult {
+    let FetchRequest {
+        method,
+        url,
+        headers,
+        body,
+    } = request;
+    let mut builder = match method {
+        FetchMethod::Get => crate::CLIENT.get(url),
+        FetchMethod::Post => crate::CLIENT.post(url).body(body),
+    };
+    for (name, value) in &headers {
+        builder = builder.header(name, value);
+    }
+
+    let response = builder
+        .send()
+        .await
+        .map_err(|e| ServerFnError::Request(e.to_string()))?;
+    let status = response.status();
+    let headers = response
+        .headers()
+        .iter()
+        .filter_map(|(name, value)| {
+            Some((name.to_string(), value.to_str().ok()?.to_string()))
+        })
+        .collect();
+    Ok(FetchResponse {
+        status: status.as_u16(),
+        status_text: status.as_u16().to_string(),
+        headers,
+        body: response
+            .bytes()
+            .await
+            .map_err(|e| ServerFnError::Deserialization(e.to_string()))?
+            .to_vec(),
+    })
+}
+
+// On the server, server functions are called directly, without a request.
+#[cfg(all(feature = "ssr", not(target_arch = "wasm32")))]
+async fn send(_request: FetchRequest) -> FetchResult {
+    Err(ServerFnError::Request(
+        "server functions are not called over the network on the server"
+            .to_string(),
+    ))
+}
+
+#[cfg(all(test, not(feature = "ssr")))]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn request() -> FetchRequest {
+        FetchRequest {
+            method: FetchMethod::Post,
+            url: "/api/test".to_string(),
+            headers: vec![],
+            body: vec![],
+        }
+    }
+
+    fn response(status: u16) -> FetchResponse {
+        FetchResponse {
+            status,
+            status_text: status.to_string(),
+            headers: vec![],
+            body: vec![],
+        }
+    }
+
+    // the last middleware answers every request, so that nothing reaches the network
+    fn respond_with(
+        status: impl Fn(&FetchRequest) -> u16 + 'static,
+    ) -> impl FetchMiddleware {
+        move |request: FetchRequest, _next: Next| {
+            let status = status(&request);
+            async move { Ok(response(status)) }
+        }
+    }
+
+    #[test]
+    fn middleware_runs_in_the_order_it_was_added() {
+        clear_fetch_middleware();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        for name in ["first", "second"] {
+            let log = Rc::clone(&log);
+            add_fetch_middleware(move |request: FetchRequest, next: Next| {
+                let log = Rc::clone(&log);
+                async move {
+                    log.borrow_mut().push(format!("{name} request"));
+                    let result =
+                        next.run(request.header("X-Seen-By", name)).await;
+                    log.borrow_mut().push(format!("{name} response"));
+                    result
+                }
+            });
+        }
+        add_fetch_middleware(respond_with(|request| {
+            // the second middleware replaced the header set by the first
+            assert_eq!(
+                request.headers,
+                [("X-Seen-By".to_string(), "second".to_string())]
+            );
+            200
+        }));
+
+        let response = tokio_test::block_on(fetch(request())).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            *log.borrow(),
+            [
+                "first request",
+                "second request",
+                "second response",
+                "first response"
+            ]
+        );
+        clear_fetch_middleware();
+    }
+
+    #[test]
+    fn next_can_be_run_more_than_once() {
+        clear_fetch_middleware();
+        let attempts = Rc::new(Cell::new(0));
+        add_fetch_middleware(|request: FetchRequest, next: Next| async move {
+            loop {
+                let response = next.run(request.clone()).await?;
+                if response.status != 503 {
+                    return Ok(response);
+                }
+            }
+        });
+        add_fetch_middleware(respond_with({
+            let attempts = Rc::clone(&attempts);
+            move |_| {
+                attempts.set(attempts.get() + 1);
+                if attempts.get() < 3 {
+                    503
+                } else {
+                    200
+                }
+            }
+        }));
+
+        let response = tokio_test::block_on(fetch(request())).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(attempts.get(), 3);
+        clear_fetch_middleware();
+    }
+
+    #[test]
+    fn middleware_can_answer_without_running_the_rest_of_the_chain() {
+        clear_fetch_middleware();
+        let reached = Rc::new(Cell::new(false));
+        add_fetch_middleware(|_request: FetchRequest, _next: Next| async {
+            Err(ServerFnError::Request("offline".to_string()))
+        });
+        add_fetch_middleware(respond_with({
+            let reached = Rc::clone(&reached);
+            move |_| {
+                reached.set(true);
+                200
+            }
+        }));
+
+        let result = tokio_test::block_on(fetch(request()));
+        assert!(
+            matches!(result, Err(ServerFnError::Request(msg)) if msg == "offline")
+        );
+        assert!(!reached.get());
+        clear_fetch_middleware();
+    }
+}
@@ -0,0 +1,333 @@
+use leptos::leptos_dom::{a11y, renderer::Renderer};
+use std::{
+    cell::RefCell,
+    fmt,
+    rc::{Rc, Weak},
+};
+
+type Listener = Rc<RefCell<Box<dyn FnMut()>>>;
+
+/// A node in the in-memory DOM that a [`Screen`](crate::Screen) renders to:
+/// an element or a text node.
+///
+/// Nodes are cheap to clone, and two nodes are equal if they are the same
+/// node.
+#[derive(Clone)]
+pub struct TestNode(Rc<RefCell<NodeData>>);
+
+struct NodeData {
+    kind: NodeKind,
+    parent: Weak<RefCell<NodeData>>,
+    children: Vec<TestNode>,
+    listeners: Vec<(String, Listener)>,
+}
+
+enum NodeKind {
+    Element {
+        tag: String,
+        attrs: Vec<(String, String)>,
+    },
+    Text(String),
+}
+
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta",
+    "param", "source", "track", "wbr",
+];
+
+impl TestNode {
+    fn new(kind: NodeKind) -> Self {
+        Self(Rc::new(RefCell::new(NodeData {
+            kind,
+            parent: Weak::new(),
+            children: Vec::new(),
+            listeners: Vec::new(),
+        })))
+    }
+
+    pub(crate) fn element(tag: &str) -> Self {
+        Self::new(NodeKind::Element {
+            tag: tag.to_string(),
+            attrs: Vec::new(),
+        })
+    }
+
+    fn text(text: &str) -> Self {
+        Self::new(NodeKind::Text(text.to_string()))
+    }
+
+    /// The tag name of the element, or `None` for a text node.
+    pub fn tag(&self) -> Option<String> {
+        match &self.0.borrow().kind {
+            NodeKind::Element { tag, .. } => Some(tag.clone()),
+            NodeKind::Text(_) => None,
+        }
+    }
+
+    /// The value of an attribute of the element, if it is set.
+    pub fn attribute(&self, name: &str) -> Option<String> {
+        match &self.0.borrow().kind {
+            NodeKind::Element { attrs, .. } => attrs
+                .iter()
+                .find(|(attr, _)| attr == name)
+                .map(|(_, value)| value.clone()),
+            NodeKind::Text(_) => None,
+        }
+    }
+
+    /// Whether the element has an attribute, like `disabled` or `checked`.
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.attribute(name).is_some()
+    }
+
+    /// The text of the node and all of its descendants.
+    pub fn text_content(&self) -> String {
+        let mut text = String::new();
+        self.push_text(&mut text);
+        text
+    }
+
+    fn push_text(&self, text: &mut String) {
+        let data = self.0.borrow();
+        match &data.kind {
+            NodeKind::Text(content) => text.push_str(content),
+            NodeKind::Element { .. } => {
+                for child in &data.children {
+                    child.push_text(text);
+                }
+            }
+        }
+    }
+
+    /// The children of the node.
+    pub fn children(&self) -> Vec<TestNode> {
+        self.0.borrow().children.clone()
+    }
+
+    /// The parent of the node, if it is in a tree.
+    pub fn parent(&self) -> Option<TestNode> {
+        self.0.borrow().parent.upgrade().map(TestNode)
+    }
+
+    /// The HTML of the node’s children.
+    pub fn inner_html(&self) -> String {
+        let mut html = String::new();
+        for child in self.children() {
+            child.write_html(&mut html, None);
+        }
+        html
+    }
+
+    /// The HTML of the node.
+    pub fn outer_html(&self) -> String {
+        let mut html = String::new();
+        self.write_html(&mut html, None);
+        html
+    }
+
+    // `keys` gives each element an attribute with its index in the list, so
+    // that the element can be found from the HTML
+    pub(crate) fn write_html(
+        &self,
+        html: &mut String,
+        mut keys: Option<(&str, &mut Vec<TestNode>)>,
+    ) {
+        let data = self.0.borrow();
+        match &data.kind {
+            NodeKind::Text(text) => html.push_str(&escape(text, false)),
+            NodeKind::Element { tag, attrs } => {
+                html.push('<');
+                html.push_str(tag);
+                for (name, value) in attrs {
+                    html.push_str(&format!(
+                        " {name}=\"{}\"",
+                        escape(value, true)
+                    ));
+                }
+                if let Some((key, nodes)) = &mut keys {
+                    html.push_str(&format!(" {key}=\"{}\"", nodes.len()));
+                    nodes.push(self.clone());
+                }
+                html.push('>');
+                if !VOID_ELEMENTS.contains(&tag.as_str()) {
+                    for child in &data.children {
+                        child.write_html(
+                            html,
+                            keys.as_mut()
+                                .map(|(key, nodes)| (*key, &mut **nodes)),
+                        );
+                    }
+                    html.push_str(&format!("</{tag}>"));
+                }
+            }
+        }
+    }
+
+    /// The elements under this node, in document order.
+    pub fn descendants(&self) -> Vec<TestNode> {
+        let mut descendants = Vec::new();
+        self.push_descendants(&mut descendants);
+        descendants
+    }
+
+    fn push_descendants(&self, descendants: &mut Vec<TestNode>) {
+        for child in self.children() {
+            if child.tag().is_some() {
+                descendants.push(child.clone());
+                child.push_descendants(descendants);
+            }
+        }
+    }
+
+    /// The element and its ancestors, from the element up.
+    pub(crate) fn path(&self) -> Vec<TestNode> {
+        std::iter::successors(Some(self.clone()), TestNode::parent).collect()
+    }
+
+    /// The listeners for the event `name` on this node.
+    pub(crate) fn listeners(&self, name: &str) -> Vec<Listener> {
+        self.0
+            .borrow()
+            .listeners
+            .iter()
+            .filter(|(event, _)| event == name)
+            .map(|(_, listener)| Rc::clone(listener))
+            .collect()
+    }
+
+    fn set_attribute(&self, name: &str, value: &str) {
+        if let NodeKind::Element { attrs, .. } = &mut self.0.borrow_mut().kind {
+            match attrs.iter_mut().find(|(attr, _)| attr == name) {
+                Some((_, prev)) => *prev = value.to_string(),
+                None => attrs.push((name.to_string(), value.to_string())),
+            }
+        }
+    }
+
+    fn remove_attribute(&self, name: &str) {
+        if let NodeKind::Element { attrs, .. } = &mut self.0.borrow_mut().kind {
+            attrs.retain(|(attr, _)| attr != name);
+        }
+    }
+
+    fn remove(&self) {
+        if let Some(parent) = self.parent() {
+            parent.0.borrow_mut().children.retain(|child| child != self);
+        }
+        self.0.borrow_mut().parent = Weak::new();
+    }
+}
+
+impl PartialEq for TestNode {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for TestNode {}
+
+impl fmt::Debug for TestNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.outer_html())
+    }
+}
+
+fn escape(text: &str, in_attribute: bool) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' if in_attribute => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn from_parsed(node: &a11y::Node) -> TestNode {
+    match node {
+        a11y::Node::Text(text) => TestNode::text(text),
+        a11y::Node::Element {
+            name,
+            attrs,
+            children,
+        } => {
+            let el = TestNode::element(name);
+            for (name, value) in attrs {
+                el.set_attribute(name, value);
+            }
+            for child in children {
+                TestDom.append_child(&el, &from_parsed(child));
+            }
+            el
+        }
+    }
+}
+
+/// An in-memory DOM, which views are rendered to by a
+/// [`Screen`](crate::Screen).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TestDom;
+
+impl Renderer for TestDom {
+    type Node = TestNode;
+
+    fn create_element(&self, tag: &str) -> TestNode {
+        TestNode::element(tag)
+    }
+
+    fn create_text_node(&self, text: &str) -> TestNode {
+        TestNode::text(text)
+    }
+
+    fn set_attribute(&self, el: &TestNode, name: &str, value: &str) {
+        el.set_attribute(name, value);
+    }
+
+    fn remove_attribute(&self, el: &TestNode, name: &str) {
+        el.remove_attribute(name);
+    }
+
+    fn set_inner_html(&self, el: &TestNode, html: &str) {
+        for child in el.children() {
+            child.remove();
+        }
+        for node in a11y::parse_html(html) {
+            self.append_child(el, &from_parsed(&node));
+        }
+    }
+
+    fn insert_before(
+        &self,
+        parent: &TestNode,
+        child: &TestNode,
+        before: Option<&TestNode>,
+    ) {
+        child.remove();
+        child.0.borrow_mut().parent = Rc::downgrade(&parent.0);
+        let mut parent = parent.0.borrow_mut();
+        let index = before
+            .and_then(|before| {
+                parent.children.iter().position(|node| node == before)
+            })
+            .unwrap_or(parent.children.len());
+        parent.children.insert(index, child.clone());
+    }
+
+    fn remove(&self, node: &TestNode) {
+        node.remove();
+    }
+
+    fn add_event_listener(
+        &self,
+        el: &TestNode,
+        name: &str,
+        listener: Box<dyn FnMut()>,
+    ) {
+        el.0.borrow_mut()
+            .listeners
+            .push((name.to_string(), Rc::new(RefCell::new(listener))));
+    }
+}
@@ -0,0 +1,68 @@
+#![deny(missing_docs)]
+#![forbid(unsafe_code)]
+
+//! # Leptos Testing
+//!
+//! Renders Leptos components into an in-memory DOM, so that they can be
+//! tested with `cargo test`, without a browser.
+//!
+//! [`render`] builds a view and returns a [`Screen`], which can find
+//! elements the way a user would, by their ARIA role and accessible name,
+//! their text, or a `data-testid`, dispatch events to them, and wait for
+//! [resources](leptos::Resource) to load. The view keeps its state between
+//! interactions: its dynamic children, lists, and the attributes that are
+//! set with a closure are updated as the signals they read change.
+//!
+//! ```
+//! use leptos::*;
+//! use leptos_testing::render;
+//!
+//! #[component]
+//! fn Greeting(cx: Scope) -> impl IntoView {
+//!     let (name, set_name) = create_signal(cx, String::new());
+//!     view! { cx,
+//!         <label>
+//!             "Name"
+//!             <input on:input=move |ev| set_name.set(event_target_value(&ev))/>
+//!         </label>
+//!         <Show when=move || !name.with(String::is_empty) fallback=|_| ()>
+//!             <p data-testid="greeting">"Hello, " {name} "!"</p>
+//!         </Show>
+//!     }
+//! }
+//!
+//! let screen = render(|cx| view! { cx, <Greeting/> });
+//! assert!(screen.query_by_test_id("greeting").is_none());
+//!
+//! let input = screen.get_by_role("textbox", "Name");
+//! screen.input(&input, "Ferris");
+//! assert_eq!(
+//!     screen.get_by_test_id("greeting").text_content(),
+//!     "Hello, Ferris!"
+//! );
+//! ```
+//!
+//! ## Limitations
+//! The view is built the way it is for server-side rendering, and then
+//! rendered into a [`TestDom`] each time it is queried, so:
+//! - events are dispatched to the listeners of the `view` macro, but the
+//!   `web_sys` event they receive can’t be used: calling a method on it, like
+//!   `prevent_default()`, panics outside the browser. The value and checked
+//!   state of its target can be read with
+//!   [`event_target_value`](leptos::event_target_value) and
+//!   [`event_target_checked`](leptos::event_target_checked). Listeners for a
+//!   [`Custom`](leptos::ev::Custom) event that takes something other than a
+//!   `web_sys` event type aren’t called.
+//! - classes, styles and properties set with a closure, like
+//!   `class:active=move || ...` or `prop:value=...`, are only read when the
+//!   element is built.
+//! - [`NodeRef`](leptos::NodeRef)s are never loaded, and `web_sys` APIs like
+//!   timers can’t be used.
+//! - with the `ssr` feature, effects created with
+//!   [`create_effect`](leptos::create_effect) don’t run.
+
+mod dom;
+mod screen;
+
+pub use dom::*;
+pub use screen::*;
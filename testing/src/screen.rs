@@ -0,0 +1,368 @@
+use crate::{TestDom, TestNode};
+use leptos::{
+    create_runtime,
+    leptos_dom::{
+        a11y,
+        renderer::{set_reactive_views, with_event_target, EventTarget},
+    },
+    raw_scope_and_disposer, IntoView, RuntimeId, Scope, View,
+};
+use std::{
+    cell::{Cell, RefCell},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+thread_local! {
+    // views are kept up to date while any screen is alive on this thread
+    static SCREENS: Cell<usize> = Cell::new(0);
+}
+
+// identifies the elements in the HTML that is read for their roles
+const NODE_KEY: &str = "data-leptos-test-node";
+
+/// Renders a view into an in-memory DOM, so that it can be queried and
+/// interacted with in a test, without a browser.
+///
+/// ```
+/// use leptos::*;
+/// use leptos_testing::render;
+///
+/// #[component]
+/// fn Counter(cx: Scope) -> impl IntoView {
+///     let (count, set_count) = create_signal(cx, 0);
+///     view! { cx,
+///         <button on:click=move |_| set_count.update(|n| *n += 1)>
+///             "Clicked " {count} " times"
+///         </button>
+///     }
+/// }
+///
+/// let screen = render(|cx| view! { cx, <Counter/> });
+/// let button = screen.get_by_role("button", "Clicked 0 times");
+/// screen.click(&button);
+/// screen.get_by_role("button", "Clicked 1 times");
+/// ```
+pub fn render<F, N>(f: F) -> Screen
+where
+    F: FnOnce(Scope) -> N,
+    N: IntoView,
+{
+    SCREENS.with(|screens| screens.set(screens.get() + 1));
+    set_reactive_views(true);
+
+    let runtime = create_runtime();
+    let (cx, _) = raw_scope_and_disposer(runtime);
+    let view = f(cx).into_view(cx);
+    Screen {
+        runtime,
+        cx,
+        view: Some(view),
+        rendered: Default::default(),
+    }
+}
+
+/// A view that has been [rendered](render) into an in-memory DOM.
+///
+/// Each query renders the view as it is at that moment, so the nodes it
+/// returns are snapshots: after an interaction, query them again to see
+/// what has changed. Interacting with an older node still calls the event
+/// listeners it had.
+///
+/// The view stays alive, and keeps its state, until the screen is dropped.
+pub struct Screen {
+    runtime: RuntimeId,
+    cx: Scope,
+    view: Option<View>,
+    // nodes only hold on to their parents weakly, so every tree that has
+    // been rendered is kept until the screen is dropped
+    rendered: RefCell<Vec<TestNode>>,
+}
+
+impl Screen {
+    /// The reactive scope the view was rendered in.
+    pub fn scope(&self) -> Scope {
+        self.cx
+    }
+
+    /// Renders the view into a new `<body>` element, as it is now.
+    pub fn body(&self) -> TestNode {
+        let body = TestNode::element("body");
+        if let Some(view) = &self.view {
+            view.render_with(&TestDom, &body);
+        }
+        self.rendered.borrow_mut().push(body.clone());
+        body
+    }
+
+    /// The HTML of the view, as it is now.
+    pub fn html(&self) -> String {
+        self.body().inner_html()
+    }
+
+    /// The [accessibility snapshot](a11y::accessibility_snapshot) of the
+    /// view, as it is now.
+    pub fn accessibility_snapshot(&self) -> String {
+        a11y::accessibility_snapshot(&self.html())
+    }
+
+    /// Returns every element with the ARIA `role`, like `button` or
+    /// `heading`, whether it is set with the `role` attribute or implied by
+    /// the element, along with its accessible name.
+    pub fn query_all_by_role(&self, role: &str) -> Vec<(TestNode, String)> {
+        let mut html = String::new();
+        let mut nodes = Vec::new();
+        for child in self.body().children() {
+            child.write_html(&mut html, Some((NODE_KEY, &mut nodes)));
+        }
+        let mut found = a11y::element_roles(&html, NODE_KEY)
+            .into_iter()
+            .filter(|el| el.role == role)
+            .filter_map(|el| {
+                let index = el.key.parse::<usize>().ok()?;
+                Some((index, el.name.unwrap_or_default()))
+            })
+            .collect::<Vec<_>>();
+        found.sort_by_key(|(index, _)| *index);
+        found
+            .into_iter()
+            .map(|(index, name)| (nodes[index].clone(), name))
+            .collect()
+    }
+
+    /// Returns the element with the ARIA `role` and the accessible `name`,
+    /// if there is one.
+    ///
+    /// # Panics
+    /// Panics if there is more than one.
+    pub fn query_by_role(&self, role: &str, name: &str) -> Option<TestNode> {
+        let found = self
+            .query_all_by_role(role)
+            .into_iter()
+            .filter(|(_, el_name)| el_name == name)
+            .map(|(node, _)| node)
+            .collect();
+        self.at_most_one(found, || format!("role {role:?} named {name:?}"))
+    }
+
+    /// Returns the element with the ARIA `role` and the accessible `name`.
+    ///
+    /// # Panics
+    /// Panics if there isn’t exactly one.
+    #[track_caller]
+    pub fn get_by_role(&self, role: &str, name: &str) -> TestNode {
+        let found = self.query_by_role(role, name);
+        self.exactly_one(found, || format!("role {role:?} named {name:?}"))
+    }
+
+    /// Returns every element whose text, with whitespace collapsed, is
+    /// `text`, and doesn’t come from a child element with the same text.
+    pub fn query_all_by_text(&self, text: &str) -> Vec<TestNode> {
+        let has_text = |node: &TestNode| {
+            node.text_content().split_whitespace().collect::<Vec<_>>()
+                == text.split_whitespace().collect::<Vec<_>>()
+        };
+        self.body()
+            .descendants()
+            .into_iter()
+            .filter(|node| {
+                has_text(node)
+                    && !node
+                        .children()
+                        .iter()
+                        .any(|child| child.tag().is_some() && has_text(child))
+            })
+            .collect()
+    }
+
+    /// Returns the element whose text is `text`, if there is one.
+    ///
+    /// # Panics
+    /// Panics if there is more than one.
+    pub fn query_by_text(&self, text: &str) -> Option<TestNode> {
+        let found = self.query_all_by_text(text);
+        self.at_most_one(found, || format!("text {text:?}"))
+    }
+
+    /// Returns the element whose text is `text`.
+    ///
+    /// # Panics
+    /// Panics if there isn’t exactly one.
+    #[track_caller]
+    pub fn get_by_text(&self, text: &str) -> TestNode {
+        let found = self.query_by_text(text);
+        self.exactly_one(found, || format!("text {text:?}"))
+    }
+
+    /// Returns the element whose `data-testid` attribute is `id`, if there
+    /// is one.
+    ///
+    /// # Panics
+    /// Panics if there is more than one.
+    pub fn query_by_test_id(&self, id: &str) -> Option<TestNode> {
+        let found = self
+            .body()
+            .descendants()
+            .into_iter()
+            .filter(|node| node.attribute("data-testid").as_deref() == Some(id))
+            .collect();
+        self.at_most_one(found, || format!("data-testid {id:?}"))
+    }
+
+    /// Returns the element whose `data-testid` attribute is `id`.
+    ///
+    /// # Panics
+    /// Panics if there isn’t exactly one.
+    #[track_caller]
+    pub fn get_by_test_id(&self, id: &str) -> TestNode {
+        let found = self.query_by_test_id(id);
+        self.exactly_one(found, || format!("data-testid {id:?}"))
+    }
+
+    fn at_most_one(
+        &self,
+        mut found: Vec<TestNode>,
+        query: impl FnOnce() -> String,
+    ) -> Option<TestNode> {
+        if found.len() > 1 {
+            panic!(
+                "found {} elements with {}, in:\n{}",
+                found.len(),
+                query(),
+                self.html()
+            );
+        }
+        found.pop()
+    }
+
+    #[track_caller]
+    fn exactly_one(
+        &self,
+        found: Option<TestNode>,
+        query: impl FnOnce() -> String,
+    ) -> TestNode {
+        found.unwrap_or_else(|| {
+            panic!("found no element with {}, in:\n{}", query(), self.html())
+        })
+    }
+
+    /// Dispatches the event `name` on the element, and then on each of its
+    /// ancestors, as if it bubbled up the DOM.
+    ///
+    /// The event that listeners receive can’t be used outside the browser:
+    /// calling its methods, like `prevent_default()`, panics. Its target
+    /// is the element, whose `value` attribute and `checked` state are
+    /// returned by [`event_target_value`](leptos::event_target_value) and
+    /// [`event_target_checked`](leptos::event_target_checked).
+    pub fn fire(&self, el: &TestNode, name: &str) {
+        let target = EventTarget {
+            value: el.attribute("value").unwrap_or_default(),
+            checked: el.has_attribute("checked"),
+        };
+        self.dispatch(el, name, target);
+    }
+
+    fn dispatch(&self, el: &TestNode, name: &str, target: EventTarget) {
+        with_event_target(target, || {
+            for node in el.path() {
+                for listener in node.listeners(name) {
+                    (*listener.borrow_mut())();
+                }
+            }
+        });
+    }
+
+    /// Clicks the element.
+    ///
+    /// Clicking a checkbox or radio button toggles whether it is checked,
+    /// and also dispatches `input` and `change`. Clicking a submit button
+    /// also submits its form.
+    pub fn click(&self, el: &TestNode) {
+        let tag = el.tag().unwrap_or_default();
+        let kind = el.attribute("type").unwrap_or_default();
+        let mut target = EventTarget {
+            value: el.attribute("value").unwrap_or_default(),
+            checked: el.has_attribute("checked"),
+        };
+        if tag == "input" && (kind == "checkbox" || kind == "radio") {
+            target.checked = kind == "radio" || !target.checked;
+            self.dispatch(el, "click", target.clone());
+            self.dispatch(el, "input", target.clone());
+            self.dispatch(el, "change", target);
+            return;
+        }
+        self.dispatch(el, "click", target);
+        if tag == "button" && (kind.is_empty() || kind == "submit") {
+            if let Some(form) = el
+                .path()
+                .into_iter()
+                .find(|node| node.tag().as_deref() == Some("form"))
+            {
+                self.submit(&form);
+            }
+        }
+    }
+
+    /// Types `value` into the element, dispatching `input` and then
+    /// `change` with it as the value of the target.
+    pub fn input(&self, el: &TestNode, value: &str) {
+        let target = EventTarget {
+            value: value.to_string(),
+            checked: el.has_attribute("checked"),
+        };
+        self.dispatch(el, "input", target.clone());
+        self.dispatch(el, "change", target);
+    }
+
+    /// Submits the form.
+    pub fn submit(&self, form: &TestNode) {
+        self.fire(form, "submit");
+    }
+
+    /// Waits until none of the [resources](leptos::Resource) in the view’s
+    /// runtime are loading, so that the view shows what they loaded.
+    ///
+    /// Resources are loaded with [`spawn_local`](leptos::spawn_local), so
+    /// with the `ssr` feature, this has to be awaited inside a
+    /// [`LocalSet`](https://docs.rs/tokio/latest/tokio/task/struct.LocalSet.html).
+    pub async fn settle(&self) {
+        while !self.cx.loading_resources().is_empty() {
+            YieldNow(false).await;
+        }
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        // the view's items dispose of their scopes when they are dropped
+        self.view.take();
+        self.rendered.take();
+        self.runtime.dispose();
+
+        let screens = SCREENS.with(|screens| {
+            screens.set(screens.get() - 1);
+            screens.get()
+        });
+        if screens == 0 {
+            set_reactive_views(false);
+        }
+    }
+}
+
+// lets the executor run other tasks, like loading resources
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use leptos::*;
+use leptos_testing::render;
+use std::task::Poll;
+
+#[component]
+fn TodoList(cx: Scope) -> impl IntoView {
+    let (todos, set_todos) = create_signal(cx, Vec::<(usize, String)>::new());
+    let (draft, set_draft) = create_signal(cx, String::new());
+    let add = move || {
+        set_todos.update(|todos| todos.push((todos.len(), draft.get())));
+        set_draft.set(String::new());
+    };
+    view! { cx,
+        <label>
+            "New todo"
+            <input
+                value=draft
+                on:input=move |ev| set_draft.set(event_target_value(&ev))
+            />
+        </label>
+        <button on:click=move |_| add() disabled=move || draft.get().is_empty()>
+            "Add"
+        </button>
+        <ul>
+            <For
+                each=move || todos.get()
+                key=|(id, _)| *id
+                view=move |cx, (_, text)| view! { cx, <Todo text/> }
+            />
+        </ul>
+        <p data-testid="count">{move || todos.with(Vec::len)} " left"</p>
+    }
+}
+
+#[component]
+fn Todo(cx: Scope, text: String) -> impl IntoView {
+    let (done, set_done) = create_signal(cx, false);
+    view! { cx,
+        <li>
+            <label>
+                <input type="checkbox" on:change=move |ev| {
+                    set_done.set(event_target_checked(&ev))
+                }/>
+                {text}
+            </label>
+            {move || done.get().then_some("✓")}
+        </li>
+    }
+}
+
+#[test]
+fn finds_elements_by_role_text_and_test_id() {
+    let screen = render(|cx| view! { cx, <TodoList/> });
+
+    assert_eq!(
+        screen.get_by_role("textbox", "New todo").tag().unwrap(),
+        "input"
+    );
+    assert!(screen
+        .get_by_role("button", "Add")
+        .has_attribute("disabled"));
+    assert_eq!(screen.get_by_text("0 left").tag().unwrap(), "p");
+    assert_eq!(screen.get_by_test_id("count").text_content(), "0 left");
+    assert!(screen.query_by_role("button", "Remove").is_none());
+    assert!(screen.query_by_text("nothing").is_none());
+}
+
+#[test]
+fn updates_the_view_after_events() {
+    let screen = render(|cx| view! { cx, <TodoList/> });
+
+    let input = screen.get_by_role("textbox", "New todo");
+    screen.input(&input, "Write tests");
+    let add = screen.get_by_role("button", "Add");
+    assert!(!add.has_attribute("disabled"));
+    assert_eq!(
+        screen.get_by_role("textbox", "New todo").attribute("value"),
+        Some("Write tests".to_string())
+    );
+
+    screen.click(&add);
+    screen.input(&input, "Ship it");
+    screen.click(&add);
+    assert_eq!(screen.get_by_test_id("count").text_content(), "2 left");
+    assert_eq!(screen.query_all_by_role("listitem").len(), 2);
+
+    // the items keep their state when the list changes
+    screen.click(&screen.get_by_role("checkbox", "Write tests"));
+    assert_eq!(
+        screen.query_all_by_role("listitem")[0].0.text_content(),
+        "Write tests✓"
+    );
+    screen.input(&input, "Celebrate");
+    screen.click(&add);
+    let items = screen
+        .query_all_by_role("listitem")
+        .into_iter()
+        .map(|(item, _)| item.text_content())
+        .collect::<Vec<_>>();
+    assert_eq!(items, ["Write tests✓", "Ship it", "Celebrate"]);
+}
+
+#[test]
+fn clicking_a_submit_button_submits_its_form() {
+    let screen = render(|cx| {
+        let (submitted, set_submitted) = create_signal(cx, 0);
+        view! { cx,
+            <form on:submit=move |_| set_submitted.update(|n| *n += 1)>
+                <button>"Send"</button>
+                <button type="button">"Cancel"</button>
+            </form>
+            <output>{submitted}</output>
+        }
+    });
+
+    screen.click(&screen.get_by_role("button", "Cancel"));
+    assert_eq!(screen.get_by_role("status", "").text_content(), "0");
+    screen.click(&screen.get_by_role("button", "Send"));
+    assert_eq!(screen.get_by_role("status", "").text_content(), "1");
+}
+
+// doesn't depend on the executor, which is different with the `ssr` feature
+async fn yield_once() {
+    let mut yielded = false;
+    std::future::poll_fn(|cx| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+#[tokio::test]
+async fn settles_once_resources_have_loaded() {
+    tokio::task::LocalSet::new()
+        .run_until(async {
+            let screen = render(|cx| {
+                let (id, set_id) = create_signal(cx, 1);
+                let post = create_resource(
+                    cx,
+                    move || id.get(),
+                    |id| async move {
+                        yield_once().await;
+                        format!("Post {id}")
+                    },
+                );
+                view! { cx,
+                    <button on:click=move |_| set_id.set(2)>"Next"</button>
+                    <h1>{move || post.read(cx)}</h1>
+                }
+            });
+
+            screen.settle().await;
+            assert_eq!(
+                screen.get_by_role("heading", "Post 1").tag().unwrap(),
+                "h1"
+            );
+            screen.click(&screen.get_by_role("button", "Next"));
+            screen.settle().await;
+            screen.get_by_role("heading", "Post 2");
+        })
+        .await;
+}
+
+#[test]
+fn control_flow_only_rebuilds_when_the_branch_changes() {
+    use std::{cell::Cell, rc::Rc};
+
+    let builds = Rc::new(Cell::new(0));
+    let screen = render({
+        let builds = Rc::clone(&builds);
+        move |cx| {
+            let (count, set_count) = create_signal(cx, 0);
+            let (items, set_items) = create_signal(cx, vec![1, 2]);
+            let built = move || {
+                builds.set(builds.get() + 1);
+                "Big"
+            };
+            view! { cx,
+                <button on:click=move |_| set_count.update(|n| *n += 1)>
+                    "Add"
+                </button>
+                <button on:click=move |_| set_items.update(|items| items.push(3))>
+                    "More"
+                </button>
+                if count.get() > 1 {
+                    <p>{built()} " " {count}</p>
+                } else {
+                    <p>"Small"</p>
+                }
+                <ul>
+                    for n in items.get() {
+                        <li>{n}</li>
+                    }
+                </ul>
+            }
+        }
+    });
+
+    let add = screen.get_by_role("button", "Add");
+    screen.click(&add);
+    screen.get_by_text("Small");
+    screen.click(&add);
+    screen.click(&add);
+    assert_eq!(screen.get_by_text("Big 3").tag().unwrap(), "p");
+    assert_eq!(builds.get(), 1);
+
+    screen.click(&screen.get_by_role("button", "More"));
+    assert_eq!(screen.query_all_by_role("listitem").len(), 3);
+}
+
+#[test]
+fn renders_static_subtrees_with_their_attributes() {
+    let screen = render(|cx| {
+        view! { cx,
+            <nav aria-label="Steps">
+                <ol class="steps">
+                    <li data-testid="first">"Cart & checkout"</li>
+                    <li aria-current="step">"Pay" <br/> "now"</li>
+                </ol>
+            </nav>
+        }
+    });
+
+    assert_eq!(
+        screen.get_by_test_id("first").text_content(),
+        "Cart & checkout"
+    );
+    let list = screen.get_by_role("list", "");
+    assert_eq!(list.attribute("class"), Some("steps".to_string()));
+    assert_eq!(screen.query_all_by_role("listitem").len(), 2);
+    screen.get_by_role("navigation", "Steps");
+}
+
+#[test]
+fn control_flow_rebuilds_arms_with_the_values_they_bind() {
+    let screen = render(|cx| {
+        let (count, set_count) = create_signal(cx, 0);
+        view! { cx,
+            <button on:click=move |_| set_count.update(|n| *n += 1)>
+                "Add"
+            </button>
+            match count.get() {
+                0 => { <p>"None"</p> }
+                n => { <p>"Count: " {n}</p> }
+            }
+        }
+    });
+
+    let add = screen.get_by_role("button", "Add");
+    screen.get_by_text("None");
+    screen.click(&add);
+    screen.get_by_text("Count: 1");
+    screen.click(&add);
+    screen.get_by_text("Count: 2");
+}